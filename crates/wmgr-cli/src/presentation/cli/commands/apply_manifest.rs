@@ -1,35 +1,100 @@
 use anyhow::Result;
 use colored::Colorize;
-use std::env;
+use serde::Serialize;
 use std::path::Path;
 
 use wmgr::application::services::manifest_service::{ManifestProcessingOptions, ManifestService};
-use wmgr::domain::entities::workspace::Workspace;
+use wmgr::application::services::workspace_loader::WorkspaceLoader;
+use wmgr::common::error::WmgrError;
 use wmgr::infrastructure::filesystem::manifest_store::ManifestStore;
+use wmgr::infrastructure::filesystem::workspace_lock::WorkspaceLock;
+
+use crate::presentation::ui::{confirm, Verbosity};
 
 /// Handler for the apply-manifest command
 pub struct ApplyManifestCommand {
     pub manifest_file: String,
     pub force: bool,
     pub dry_run: bool,
-    pub verbose: bool,
+    pub verbosity: Verbosity,
+    pub backup: bool,
+    pub plan_format: Option<PlanFormat>,
+    pub yes: bool,
+    pub strict: bool,
+    pub workspace_loader: WorkspaceLoader,
+}
+
+/// Structured formats for the dry-run plan
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlanFormat {
+    Json,
+    Yaml,
 }
 
 impl ApplyManifestCommand {
-    pub fn new(manifest_file: String, force: bool, dry_run: bool, verbose: bool) -> Self {
+    pub fn new(manifest_file: String, force: bool, dry_run: bool, verbosity: Verbosity) -> Self {
         Self {
             manifest_file,
             force,
             dry_run,
-            verbose,
+            verbosity,
+            backup: true,
+            plan_format: None,
+            yes: false,
+            strict: false,
+            workspace_loader: WorkspaceLoader::new(),
         }
     }
 
+    /// Skip the confirmation prompt before the changes are applied
+    pub fn with_yes(mut self, yes: bool) -> Self {
+        self.yes = yes;
+        self
+    }
+
+    /// Fail on warnings while validating the new manifest
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Print the dry-run plan in a structured format instead of the human-readable summary
+    pub fn with_plan_format(mut self, plan_format: Option<PlanFormat>) -> Self {
+        self.plan_format = plan_format;
+        self
+    }
+
+    /// Enable or disable the backup of the manifest before it is overwritten
+    pub fn with_backup(mut self, backup: bool) -> Self {
+        self.backup = backup;
+        self
+    }
+
+    /// Load the workspace with `workspace_loader` instead of the default loader
+    pub fn with_workspace_loader(mut self, workspace_loader: WorkspaceLoader) -> Self {
+        self.workspace_loader = workspace_loader;
+        self
+    }
+
     pub async fn execute(&self) -> Result<()> {
         // Load workspace
-        let workspace = self.load_workspace().await?;
+        let workspace = self
+            .workspace_loader
+            .load_current()
+            .await
+            .map_err(WmgrError::from)?;
 
-        if self.verbose {
+        // Prevent concurrent mutating commands on the same workspace (dry runs only read)
+        let _lock = if self.dry_run {
+            None
+        } else {
+            Some(WorkspaceLock::acquire(&workspace.wmgr_dir()).map_err(WmgrError::from)?)
+        };
+
+        // Structured plans go to stdout alone so they can be piped into review tooling
+        let verbose = self.verbosity.is_verbose() && self.plan_format.is_none();
+
+        if verbose {
             println!(
                 "{} Applying manifest from: {}",
                 "::".blue().bold(),
@@ -47,13 +112,14 @@ impl ApplyManifestCommand {
         }
 
         // Load and parse the new manifest
-        let mut manifest_service = ManifestService::new(ManifestProcessingOptions::default());
+        let mut manifest_service =
+            ManifestService::new(ManifestProcessingOptions::from_env().with_strict(self.strict));
         let new_processed_manifest = manifest_service
             .parse_from_file(manifest_path)
             .await
             .map_err(|e| anyhow::anyhow!("Failed to parse new manifest: {}", e))?;
 
-        if self.verbose {
+        if verbose {
             println!("  {} Parsed new manifest successfully", "✓".green());
             println!(
                 "  {} Found {} repositories",
@@ -81,44 +147,61 @@ impl ApplyManifestCommand {
             &new_processed_manifest.manifest,
         );
 
-        if self.verbose || self.dry_run {
+        if let Some(format) = self.plan_format {
+            let plan = ApplyPlan::from_changes(&changes);
+            match format {
+                PlanFormat::Json => println!("{}", serde_json::to_string_pretty(&plan)?),
+                PlanFormat::Yaml => print!("{}", serde_yaml::to_string(&plan)?),
+            }
+            return Ok(());
+        }
+
+        if self.verbosity.is_verbose() || self.dry_run {
             self.print_changes(&changes);
         }
 
+        let quiet = self.verbosity.is_quiet();
         if changes.is_empty() {
-            println!("{} No changes detected in the manifest", "✓".green().bold());
+            if !quiet {
+                println!("{} No changes detected in the manifest", "✓".green().bold());
+            }
             return Ok(());
         }
 
         if self.dry_run {
-            println!(
-                "{} Dry run completed - no changes applied",
-                "::".blue().bold()
-            );
+            if !quiet {
+                println!(
+                    "{} Dry run completed - no changes applied",
+                    "::".blue().bold()
+                );
+            }
             return Ok(());
         }
 
-        if !self.force && !changes.is_empty() {
+        if !self.force && !self.confirm_changes(&changes)? {
             return Err(anyhow::anyhow!(
-                "Manifest changes detected. Use --force to apply changes or --dry-run to preview"
+                "Manifest changes detected. Use --yes or --force to apply changes or --dry-run to preview"
             ));
         }
 
         // Apply the new manifest
-        if self.verbose {
+        if self.verbosity.is_verbose() {
             println!("{} Applying manifest changes...", "::".blue().bold());
         }
 
         // Update the manifest file in the workspace
-        let manifest_store = ManifestStore::new();
+        let manifest_store = ManifestStore::new().with_backup(self.backup);
         manifest_store
             .write_manifest(&current_manifest_path, &new_processed_manifest.manifest)
             .await
             .map_err(|e| anyhow::anyhow!("Failed to save new manifest: {}", e))?;
 
+        if quiet {
+            return Ok(());
+        }
         println!("{} Manifest applied successfully!", "✓".green().bold());
 
-        if self.verbose {
+        if self.verbosity.is_verbose() {
             println!(
                 "  {} {} repositories added",
                 "->".green(),
@@ -155,6 +238,25 @@ impl ApplyManifestCommand {
         Ok(())
     }
 
+    /// Ask before overwriting the manifest, listing the repositories it drops
+    ///
+    /// Without a terminal this declines unless `--yes` was given.
+    fn confirm_changes(&self, changes: &ManifestChanges) -> Result<bool> {
+        if !(self.verbosity.is_verbose() || self.dry_run || self.yes) {
+            self.print_changes(changes);
+        }
+        let prompt = if changes.removed.is_empty() {
+            "Apply these manifest changes?".to_string()
+        } else {
+            let removed: Vec<&str> = changes.removed.iter().map(|r| r.dest.as_str()).collect();
+            format!(
+                "Apply these manifest changes, removing {} from the manifest?",
+                removed.join(", ")
+            )
+        };
+        Ok(confirm(&prompt, self.yes)?)
+    }
+
     fn analyze_manifest_changes(
         &self,
         current: Option<&wmgr::domain::entities::manifest::Manifest>,
@@ -303,45 +405,6 @@ impl ApplyManifestCommand {
 
         println!();
     }
-
-    /// Load workspace from the current directory
-    async fn load_workspace(&self) -> Result<Workspace> {
-        let current_dir = env::current_dir()?;
-        let workspace = Workspace::new(current_dir.clone(), WorkspaceConfig::default_local());
-
-        // Use workspace.manifest_file_path() to support wmgr.yml, wmgr.yaml, manifest.yml, manifest.yaml
-        let manifest_file = workspace.manifest_file_path();
-
-        if !manifest_file.exists() {
-            return Err(anyhow::anyhow!("Manifest file not found. Tried wmgr.yml, wmgr.yaml, manifest.yml, manifest.yaml in current directory and .wmgr/ subdirectory"));
-        }
-
-        // Load manifest file
-        use wmgr::domain::entities::workspace::{WorkspaceConfig, WorkspaceStatus};
-        use wmgr::infrastructure::filesystem::manifest_store::ManifestStore;
-        let mut manifest_store = ManifestStore::new();
-
-        let processed_manifest = manifest_store
-            .read_manifest(&manifest_file)
-            .await
-            .map_err(|e| anyhow::anyhow!("Failed to read manifest: {}", e))?;
-
-        // Create workspace config from manifest
-        let workspace_config = WorkspaceConfig::new(
-            "file://".to_string() + &manifest_file.to_string_lossy(),
-            processed_manifest
-                .manifest
-                .default_branch
-                .clone()
-                .unwrap_or_else(|| "main".to_string()),
-        );
-
-        let workspace = Workspace::new(current_dir, workspace_config)
-            .with_status(WorkspaceStatus::Initialized)
-            .with_manifest(processed_manifest.manifest);
-
-        Ok(workspace)
-    }
 }
 
 #[derive(Debug, Clone)]
@@ -363,3 +426,218 @@ impl ManifestChanges {
         self.added.is_empty() && self.modified.is_empty() && self.removed.is_empty()
     }
 }
+
+/// Serializable plan of what applying a manifest would change, for review and approval gates
+///
+/// A repository removed at one dest and added at another with the same URL is reported
+/// as a move rather than a remove plus an add. Every list is sorted by dest.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ApplyPlan {
+    pub adds: Vec<PlannedRepo>,
+    pub removes: Vec<PlannedRepo>,
+    pub moves: Vec<PlannedMove>,
+    pub url_changes: Vec<PlannedUrlChange>,
+    pub ref_changes: Vec<PlannedRefChange>,
+}
+
+/// A repository added to or removed from the workspace
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PlannedRepo {
+    pub dest: String,
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+}
+
+/// A repository whose dest changes while its URL stays the same
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PlannedMove {
+    pub from: String,
+    pub to: String,
+    pub url: String,
+}
+
+/// A repository whose URL changes at the same dest
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PlannedUrlChange {
+    pub dest: String,
+    pub from: String,
+    pub to: String,
+}
+
+/// A change to the branch, sha1, tag or remotes of a repository
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PlannedRefChange {
+    pub dest: String,
+    pub field: String,
+    pub from: Option<String>,
+    pub to: Option<String>,
+}
+
+impl PlannedRepo {
+    fn from_repo(repo: &wmgr::domain::entities::manifest::ManifestRepo) -> Self {
+        Self {
+            dest: repo.dest.clone(),
+            url: repo.url.clone(),
+            branch: repo.branch.clone(),
+        }
+    }
+}
+
+impl ApplyPlan {
+    fn from_changes(changes: &ManifestChanges) -> Self {
+        let mut plan = ApplyPlan::default();
+
+        // Pair removed and added repositories that share a URL into moves.
+        // Local-only repositories have no URL to match on.
+        let mut added: Vec<_> = changes.added.iter().collect();
+        for removed in &changes.removed {
+            let moved = added
+                .iter()
+                .position(|repo| !repo.is_local_only() && repo.url == removed.url);
+            match moved {
+                Some(index) => {
+                    let moved_to = added.remove(index);
+                    plan.moves.push(PlannedMove {
+                        from: removed.dest.clone(),
+                        to: moved_to.dest.clone(),
+                        url: removed.url.clone(),
+                    });
+                }
+                None => plan.removes.push(PlannedRepo::from_repo(removed)),
+            }
+        }
+        plan.adds = added.into_iter().map(PlannedRepo::from_repo).collect();
+
+        for change in &changes.modified {
+            let Some(old) = &change.old_repo else {
+                continue;
+            };
+            let new = &change.new_repo;
+            if old.url != new.url {
+                plan.url_changes.push(PlannedUrlChange {
+                    dest: change.dest.clone(),
+                    from: old.url.clone(),
+                    to: new.url.clone(),
+                });
+            }
+
+            let remotes = |repo: &wmgr::domain::entities::manifest::ManifestRepo| {
+                repo.remotes.as_ref().map(|remotes| {
+                    remotes
+                        .iter()
+                        .map(|remote| format!("{}={}", remote.name, remote.url))
+                        .collect::<Vec<_>>()
+                        .join(",")
+                })
+            };
+            let fields = [
+                ("branch", old.branch.clone(), new.branch.clone()),
+                ("sha1", old.sha1.clone(), new.sha1.clone()),
+                ("tag", old.tag.clone(), new.tag.clone()),
+                ("remotes", remotes(old), remotes(new)),
+            ];
+            for (field, from, to) in fields {
+                if from != to {
+                    plan.ref_changes.push(PlannedRefChange {
+                        dest: change.dest.clone(),
+                        field: field.to_string(),
+                        from,
+                        to,
+                    });
+                }
+            }
+        }
+
+        plan.adds.sort_by(|a, b| a.dest.cmp(&b.dest));
+        plan.removes.sort_by(|a, b| a.dest.cmp(&b.dest));
+        plan.moves.sort_by(|a, b| a.from.cmp(&b.from));
+        plan.url_changes.sort_by(|a, b| a.dest.cmp(&b.dest));
+        plan.ref_changes.sort_by(|a, b| a.dest.cmp(&b.dest));
+        plan
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wmgr::domain::entities::manifest::{Manifest, ManifestRepo};
+
+    fn plan(current: &Manifest, new: &Manifest) -> ApplyPlan {
+        let command =
+            ApplyManifestCommand::new("wmgr.yml".to_string(), false, true, Verbosity::Normal);
+        ApplyPlan::from_changes(&command.analyze_manifest_changes(Some(current), new))
+    }
+
+    #[test]
+    fn test_plan_json_for_added_and_removed_repo() {
+        let current = Manifest::new(vec![
+            ManifestRepo::new("https://github.com/example/app.git", "app"),
+            ManifestRepo::new("https://github.com/example/legacy.git", "legacy"),
+        ]);
+        let new = Manifest::new(vec![
+            ManifestRepo::new("https://github.com/example/app.git", "app"),
+            ManifestRepo::new("https://github.com/example/lib.git", "lib").with_branch("main"),
+        ]);
+
+        let json = serde_json::to_value(plan(&current, &new)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "adds": [
+                    {"dest": "lib", "url": "https://github.com/example/lib.git", "branch": "main"}
+                ],
+                "removes": [
+                    {"dest": "legacy", "url": "https://github.com/example/legacy.git"}
+                ],
+                "moves": [],
+                "url_changes": [],
+                "ref_changes": []
+            })
+        );
+    }
+
+    #[test]
+    fn test_plan_detects_moves_and_url_changes() {
+        let current = Manifest::new(vec![
+            ManifestRepo::new("https://github.com/example/app.git", "app"),
+            ManifestRepo::new("https://github.com/example/tools.git", "tools"),
+        ]);
+        let new = Manifest::new(vec![
+            ManifestRepo::new("https://mirror.example.com/app.git", "app").with_branch("develop"),
+            ManifestRepo::new("https://github.com/example/tools.git", "libs/tools"),
+        ]);
+
+        let plan = plan(&current, &new);
+        assert!(plan.adds.is_empty());
+        assert!(plan.removes.is_empty());
+        assert_eq!(
+            plan.moves,
+            vec![PlannedMove {
+                from: "tools".to_string(),
+                to: "libs/tools".to_string(),
+                url: "https://github.com/example/tools.git".to_string(),
+            }]
+        );
+        assert_eq!(
+            plan.url_changes,
+            vec![PlannedUrlChange {
+                dest: "app".to_string(),
+                from: "https://github.com/example/app.git".to_string(),
+                to: "https://mirror.example.com/app.git".to_string(),
+            }]
+        );
+        assert_eq!(
+            plan.ref_changes,
+            vec![PlannedRefChange {
+                dest: "app".to_string(),
+                field: "branch".to_string(),
+                from: None,
+                to: Some("develop".to_string()),
+            }]
+        );
+
+        let yaml = serde_yaml::to_string(&plan).unwrap();
+        assert!(yaml.contains("moves:\n- from: tools\n  to: libs/tools\n"));
+    }
+}
@@ -1,10 +1,24 @@
+use crate::presentation::ui::display::DisplayHelper;
+use crate::presentation::ui::sarif::audit_findings_to_sarif;
+use clap::{Args, ValueEnum};
+use std::path::{Path, PathBuf};
+use wmgr::application::services::workspace_loader::WorkspaceLoader;
 use wmgr::application::use_cases::security_audit::{
-    SecurityAuditConfig, SecurityAuditError, SecurityAuditUseCase,
+    SecurityAuditConfig, SecurityAuditError, SecurityAuditUseCase, WorkspaceAuditResult,
 };
 use wmgr::domain::entities::workspace::Workspace;
-use crate::presentation::ui::display::DisplayHelper;
-use clap::Args;
-use std::path::PathBuf;
+
+/// Output format for audit results
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum AuditOutputFormat {
+    /// Human-readable summary (default)
+    #[default]
+    Text,
+    /// wmgr audit report with per-repository counts and every finding
+    Json,
+    /// SARIF 2.1.0 log for code scanning tools
+    Sarif,
+}
 
 /// Security audit command arguments
 #[derive(Debug, Args)]
@@ -29,6 +43,10 @@ pub struct AuditArgs {
     #[arg(short, long)]
     pub verbose: bool,
 
+    /// Output format
+    #[arg(long, value_enum, default_value = "text")]
+    pub output: AuditOutputFormat,
+
     /// Workspace directory (current directory if not specified)
     #[arg(long)]
     pub workspace_dir: Option<PathBuf>,
@@ -37,6 +55,7 @@ pub struct AuditArgs {
 /// Security audit command implementation
 pub struct AuditCommand {
     display: DisplayHelper,
+    workspace_loader: WorkspaceLoader,
 }
 
 impl AuditCommand {
@@ -44,9 +63,16 @@ impl AuditCommand {
     pub fn new() -> Self {
         Self {
             display: DisplayHelper::new(true), // Enable colored output
+            workspace_loader: WorkspaceLoader::new(),
         }
     }
 
+    /// Load the workspace with `workspace_loader` instead of the default loader
+    pub fn with_workspace_loader(mut self, workspace_loader: WorkspaceLoader) -> Self {
+        self.workspace_loader = workspace_loader;
+        self
+    }
+
     /// Execute the audit command
     pub async fn execute(&self, args: AuditArgs) -> Result<(), Box<dyn std::error::Error>> {
         // 1. Determine workspace directory
@@ -58,33 +84,45 @@ impl AuditCommand {
         let workspace = self.load_workspace(&workspace_dir).await?;
 
         // 3. Create audit configuration
-        let config = SecurityAuditConfig::new()
-            .with_groups(args.groups.unwrap_or_default())
+        // Structured output must be the only thing on stdout
+        let verbose = args.verbose && args.output == AuditOutputFormat::Text;
+        let mut config = SecurityAuditConfig::new()
             .with_parallel(args.parallel, args.max_parallel)
             .with_fail_on_vulnerabilities(!args.continue_on_vulnerabilities)
-            .with_verbose(args.verbose);
+            .with_verbose(verbose);
+        if let Some(groups) = args.groups {
+            config = config.with_groups(groups);
+        }
 
         // 4. Execute audit
         let use_case = SecurityAuditUseCase::new(config);
 
-        if args.verbose {
+        if verbose {
             println!("Starting security audit...");
         }
 
         match use_case.execute(&workspace).await {
             Ok(result) => {
-                self.display_audit_result(&result, args.verbose);
+                match args.output {
+                    AuditOutputFormat::Text => self.display_audit_result(&result, verbose),
+                    format => self.print_structured_result(&result, format)?,
+                }
 
-                if result.has_critical_or_high_vulnerabilities()
-                    && !args.continue_on_vulnerabilities
-                {
-                    std::process::exit(1);
+                if result.exit_code != 0 {
+                    std::process::exit(result.exit_code);
                 }
 
                 Ok(())
             }
-            Err(SecurityAuditError::NoRustProjectsFound) => {
-                self.display.warning("No Rust projects found in workspace");
+            Err(SecurityAuditError::NoAuditableProjectsFound)
+                if args.output != AuditOutputFormat::Text =>
+            {
+                let result = WorkspaceAuditResult::new(false);
+                self.print_structured_result(&result, args.output)
+            }
+            Err(SecurityAuditError::NoAuditableProjectsFound) => {
+                self.display
+                    .warning("No Rust, npm, Yarn, Python or Go projects found in workspace");
                 Ok(())
             }
             Err(e) => {
@@ -94,61 +132,54 @@ impl AuditCommand {
         }
     }
 
-    /// Load workspace from directory
+    /// Load the workspace containing `workspace_dir`
     async fn load_workspace(
         &self,
-        workspace_dir: &PathBuf,
+        workspace_dir: &Path,
     ) -> Result<Workspace, Box<dyn std::error::Error>> {
-        let workspace = Workspace::new(workspace_dir.clone(), WorkspaceConfig::default_local());
-
-        // Use workspace.manifest_file_path() to support wmgr.yml, wmgr.yaml, manifest.yml, manifest.yaml
-        let manifest_file = workspace.manifest_file_path();
-
-        if !manifest_file.exists() {
-            return Err("Manifest file not found. Tried wmgr.yml, wmgr.yaml, manifest.yml, manifest.yaml in current directory and .wmgr/ subdirectory".into());
-        }
-
-        // Load manifest file
-        use wmgr::domain::entities::workspace::{WorkspaceConfig, WorkspaceStatus};
-        use wmgr::infrastructure::filesystem::manifest_store::ManifestStore;
-        let mut manifest_store = ManifestStore::new();
-
-        let processed_manifest = manifest_store
-            .read_manifest(&manifest_file)
-            .await
-            .map_err(|e| format!("Failed to read manifest: {}", e))?;
-
-        // Create workspace config from manifest
-        let workspace_config = WorkspaceConfig::new(
-            "file://".to_string() + &manifest_file.to_string_lossy(),
-            processed_manifest
-                .manifest
-                .default_branch
-                .clone()
-                .unwrap_or_else(|| "main".to_string()),
-        );
-
-        let workspace = Workspace::new(workspace_dir.clone(), workspace_config)
-            .with_status(WorkspaceStatus::Initialized)
-            .with_manifest(processed_manifest.manifest);
+        Ok(self.workspace_loader.load(workspace_dir).await?)
+    }
 
-        Ok(workspace)
+    /// Print audit results as a wmgr JSON report or a SARIF log
+    fn print_structured_result(
+        &self,
+        result: &WorkspaceAuditResult,
+        format: AuditOutputFormat,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let document = match format {
+            AuditOutputFormat::Sarif => audit_findings_to_sarif(&result.findings()),
+            _ => serde_json::to_value(result.report())?,
+        };
+        println!("{}", serde_json::to_string_pretty(&document)?);
+        Ok(())
     }
 
     /// Display audit results
-    fn display_audit_result(
-        &self,
-        result: &wmgr::application::use_cases::security_audit::WorkspaceAuditResult,
-        verbose: bool,
-    ) {
+    fn display_audit_result(&self, result: &WorkspaceAuditResult, verbose: bool) {
         // Summary
         println!("\n=== Security Audit Summary ===");
         println!("Total repositories: {}", result.total_count());
-        println!("Audited (Rust projects): {}", result.audited_count);
-        println!("Skipped (non-Rust): {}", result.skipped_count);
+        println!("Audited: {}", result.audited_count);
+        println!(
+            "Skipped (no recognized ecosystem): {}",
+            result.skipped_count
+        );
         println!("Errors: {}", result.error_count);
         println!("With vulnerabilities: {}", result.vulnerable_count);
 
+        // Per-repository vulnerability counts
+        println!("\n=== Repositories ===");
+        for repo_result in result.repo_results.iter().filter(|r| r.is_success()) {
+            if let Some(ecosystem) = repo_result.ecosystem {
+                println!(
+                    "{} ({}): {} vulnerabilities",
+                    repo_result.dest,
+                    ecosystem,
+                    repo_result.vulnerability_count()
+                );
+            }
+        }
+
         // Vulnerability details
         if result.has_vulnerabilities() {
             println!("\n=== Vulnerabilities Found ===");
@@ -215,8 +246,8 @@ impl AuditCommand {
                 println!("   • Monitor for security updates");
             }
 
-            println!("   • Run 'cargo audit' in individual repositories for more details");
-            println!("   • Use 'cargo audit fix' to attempt automatic fixes");
+            println!("   • Run the audit tool in individual repositories for more details");
+            println!("   • Use 'cargo audit fix' or 'npm audit fix' to attempt automatic fixes");
         } else if result.audited_count > 0 {
             self.display.success("✅ No vulnerabilities found!");
         }
@@ -269,6 +300,7 @@ mod tests {
             max_parallel: Some(4),
             continue_on_vulnerabilities: false,
             verbose: true,
+            output: AuditOutputFormat::Json,
             workspace_dir: None,
         };
 
@@ -280,6 +312,7 @@ mod tests {
         assert_eq!(args.max_parallel, Some(4));
         assert!(!args.continue_on_vulnerabilities);
         assert!(args.verbose);
+        assert_eq!(args.output, AuditOutputFormat::Json);
     }
 
     #[test]
@@ -294,12 +327,12 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let command = AuditCommand::new();
 
-        let result = command.load_workspace(&temp_dir.path().to_path_buf()).await;
+        let result = command.load_workspace(temp_dir.path()).await;
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
             .to_string()
-            .contains("Manifest file not found"));
+            .contains("No wmgr workspace found"));
     }
 
     #[tokio::test]
@@ -315,7 +348,7 @@ repos:
         fs::write(temp_dir.path().join("manifest.yml"), manifest_content).unwrap();
 
         let command = AuditCommand::new();
-        let result = command.load_workspace(&temp_dir.path().to_path_buf()).await;
+        let result = command.load_workspace(temp_dir.path()).await;
 
         assert!(result.is_ok());
         let workspace = result.unwrap();
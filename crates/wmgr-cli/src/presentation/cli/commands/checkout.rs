@@ -0,0 +1,158 @@
+use anyhow::Result;
+use colored::Colorize;
+
+use wmgr::application::services::workspace_loader::WorkspaceLoader;
+use wmgr::application::use_cases::checkout_branch::{
+    CheckoutBranchConfig, CheckoutBranchError, CheckoutBranchUseCase, CheckoutResult,
+    CheckoutStatus, RepoCheckoutResult,
+};
+use wmgr::common::error::WmgrError;
+use wmgr::infrastructure::filesystem::workspace_lock::WorkspaceLock;
+
+use crate::presentation::ui::Verbosity;
+
+/// Handler for the checkout command
+pub struct CheckoutCommand {
+    pub branch: String,
+    pub groups: Vec<String>,
+    pub create: bool,
+    pub force: bool,
+    pub verbosity: Verbosity,
+    pub workspace_loader: WorkspaceLoader,
+}
+
+impl CheckoutCommand {
+    pub fn new(
+        branch: String,
+        groups: Vec<String>,
+        create: bool,
+        force: bool,
+        verbosity: Verbosity,
+    ) -> Self {
+        Self {
+            branch,
+            groups,
+            create,
+            force,
+            verbosity,
+            workspace_loader: WorkspaceLoader::new(),
+        }
+    }
+
+    /// Load the workspace with `workspace_loader` instead of the default loader
+    pub fn with_workspace_loader(mut self, workspace_loader: WorkspaceLoader) -> Self {
+        self.workspace_loader = workspace_loader;
+        self
+    }
+
+    pub async fn execute(&self) -> Result<()> {
+        // Load workspace
+        let workspace = self
+            .workspace_loader
+            .load_current()
+            .await
+            .map_err(WmgrError::from)?;
+
+        // Switching branches rewrites working trees, so keep sync and apply-manifest out
+        let _lock = WorkspaceLock::acquire(&workspace.wmgr_dir()).map_err(WmgrError::from)?;
+
+        // Create configuration
+        let mut config = CheckoutBranchConfig::new(&self.branch)
+            .with_create(self.create)
+            .with_force(self.force)
+            .with_verbose(self.verbosity.is_verbose());
+        if !self.groups.is_empty() {
+            config = config.with_groups(self.groups.clone());
+        }
+
+        // Execute the use case
+        let use_case = CheckoutBranchUseCase::new(config);
+
+        if !self.verbosity.is_quiet() {
+            println!(
+                "{} Checking out {}...",
+                "::".blue().bold(),
+                self.branch.bold()
+            );
+        }
+
+        match use_case.execute(&workspace).await {
+            Ok(result) => {
+                for repo in &result.repositories {
+                    self.print_repo_result(repo);
+                }
+                self.print_summary(&result);
+
+                if result.is_success() {
+                    Ok(())
+                } else {
+                    Err(anyhow::anyhow!(
+                        "{} repositories could not be checked out",
+                        result.errors.len()
+                    ))
+                }
+            }
+            Err(CheckoutBranchError::WorkspaceNotInitialized(path)) => Err(anyhow::anyhow!(
+                "Workspace not initialized at: {}\nManifest file not found",
+                path
+            )),
+            Err(e) => Err(WmgrError::from(e).into()),
+        }
+    }
+
+    fn print_repo_result(&self, repo: &RepoCheckoutResult) {
+        match repo.status {
+            CheckoutStatus::Switched => {
+                if !self.verbosity.is_quiet() {
+                    println!("{} {} switched", "✓".green().bold(), repo.dest);
+                }
+            }
+            CheckoutStatus::Created => {
+                if !self.verbosity.is_quiet() {
+                    println!("{} {} created", "✓".green().bold(), repo.dest);
+                }
+            }
+            CheckoutStatus::AlreadyOnBranch => {
+                if self.verbosity.is_verbose() {
+                    println!("{} {} already on branch", "✓".dimmed(), repo.dest);
+                }
+            }
+            CheckoutStatus::Skipped => {
+                if !self.verbosity.is_quiet() {
+                    println!(
+                        "{} {} skipped: {}",
+                        "-".dimmed(),
+                        repo.dest,
+                        repo.message.as_deref().unwrap_or("")
+                    );
+                }
+            }
+            CheckoutStatus::Dirty => println!(
+                "{} {} has uncommitted changes (use --force to check out anyway)",
+                "✗".red().bold(),
+                repo.dest
+            ),
+            CheckoutStatus::Failed => println!(
+                "{} {} {}",
+                "✗".red().bold(),
+                repo.dest,
+                repo.message.as_deref().unwrap_or("").red()
+            ),
+        }
+    }
+
+    fn print_summary(&self, result: &CheckoutResult) {
+        if self.verbosity.is_quiet() {
+            return;
+        }
+        println!(
+            "{} {} switched, {} created, {} already on branch, {} skipped, {} failed",
+            "::".blue().bold(),
+            result.switched_count,
+            result.created_count,
+            result.already_on_branch_count,
+            result.skipped_count,
+            result.errors.len()
+        );
+    }
+}
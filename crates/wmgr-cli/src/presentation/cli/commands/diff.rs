@@ -0,0 +1,235 @@
+use anyhow::Result;
+use colored::Colorize;
+
+use wmgr::domain::entities::workspace::Workspace;
+
+use crate::presentation::ui::Verbosity;
+
+/// How the changes of each repository are shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffFormat {
+    /// Full patch with hunks
+    Patch,
+    /// Diffstat summary
+    Stat,
+    /// Changed paths only
+    NameOnly,
+}
+
+/// Handler for the diff command
+pub struct DiffCommand {
+    pub groups: Vec<String>,
+    pub format: DiffFormat,
+    pub verbosity: Verbosity,
+}
+
+/// Uncommitted changes of a repository, or `None` when it is clean
+///
+/// Compares HEAD with the working tree, so both staged and unstaged changes are
+/// included. Untracked files are not, as with `git diff HEAD`.
+pub fn working_tree_diff(
+    git_repo: &git2::Repository,
+    format: DiffFormat,
+) -> Result<Option<String>> {
+    // An unborn HEAD compares against the empty tree
+    let head_tree = match git_repo.head() {
+        Ok(head) => Some(head.peel_to_tree()?),
+        Err(_) => None,
+    };
+    let diff = git_repo.diff_tree_to_workdir_with_index(head_tree.as_ref(), None)?;
+    if diff.deltas().len() == 0 {
+        return Ok(None);
+    }
+
+    let output = match format {
+        DiffFormat::NameOnly => diff
+            .deltas()
+            .filter_map(|delta| delta.new_file().path().or_else(|| delta.old_file().path()))
+            .map(|path| format!("{}\n", path.display()))
+            .collect(),
+        DiffFormat::Stat => {
+            let stats = diff.stats()?;
+            let buf = stats.to_buf(git2::DiffStatsFormat::FULL, 80)?;
+            String::from_utf8_lossy(&buf).into_owned()
+        }
+        DiffFormat::Patch => {
+            let mut patch = String::new();
+            diff.print(git2::DiffFormat::Patch, |_, _, line| {
+                if matches!(line.origin(), '+' | '-' | ' ') {
+                    patch.push(line.origin());
+                }
+                patch.push_str(&String::from_utf8_lossy(line.content()));
+                true
+            })?;
+            patch
+        }
+    };
+    Ok(Some(output))
+}
+
+impl DiffCommand {
+    pub fn new(groups: Vec<String>, format: DiffFormat, verbosity: Verbosity) -> Self {
+        Self {
+            groups,
+            format,
+            verbosity,
+        }
+    }
+
+    pub async fn execute(&self, workspace: &Workspace) -> Result<()> {
+        let repositories = match &workspace.manifest {
+            Some(_) if self.groups.is_empty() => workspace.configured_repos(),
+            Some(manifest) => manifest.repos_in_groups(&self.groups),
+            None => Vec::new(),
+        };
+
+        let mut changed = 0;
+        for repo in repositories {
+            let repo_path = workspace.resolve_dest(&repo.dest)?;
+            let git_repo = match git2::Repository::open(&repo_path) {
+                Ok(git_repo) => git_repo,
+                Err(e) => {
+                    if self.verbosity.is_verbose() {
+                        println!(
+                            "{} {}: not a git repository - {}",
+                            "⚠".yellow(),
+                            repo.dest,
+                            e
+                        );
+                    }
+                    continue;
+                }
+            };
+
+            let Some(output) = working_tree_diff(&git_repo, self.format)? else {
+                continue;
+            };
+
+            changed += 1;
+            println!("{} {}", "Repository:".bold(), repo.dest.green());
+            self.print_output(&output);
+            println!();
+        }
+
+        if changed == 0 && !self.verbosity.is_quiet() {
+            println!("{} No uncommitted changes", "✓".green().bold());
+        }
+
+        Ok(())
+    }
+
+    fn print_output(&self, output: &str) {
+        for line in output.lines() {
+            if self.format != DiffFormat::Patch {
+                println!("{}", line);
+            } else if line.starts_with("diff --git")
+                || line.starts_with("+++")
+                || line.starts_with("---")
+            {
+                println!("{}", line.bold());
+            } else if line.starts_with("@@") {
+                println!("{}", line.cyan());
+            } else if line.starts_with('+') {
+                println!("{}", line.green());
+            } else if line.starts_with('-') {
+                println!("{}", line.red());
+            } else {
+                println!("{}", line);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    /// A repository with `notes.txt` and `README.md` committed
+    fn committed_repo() -> (TempDir, git2::Repository) {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = git2::Repository::init(temp_dir.path()).unwrap();
+        fs::write(
+            temp_dir.path().join("notes.txt"),
+            "first line\nsecond line\n",
+        )
+        .unwrap();
+        fs::write(temp_dir.path().join("README.md"), "# Project\n").unwrap();
+
+        let mut index = repo.index().unwrap();
+        index
+            .add_all(["*"].iter(), git2::IndexAddOption::DEFAULT, None)
+            .unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let signature = git2::Signature::now("Dev", "dev@example.com").unwrap();
+        repo.commit(Some("HEAD"), &signature, &signature, "initial", &tree, &[])
+            .unwrap();
+        drop(tree);
+        (temp_dir, repo)
+    }
+
+    #[test]
+    fn test_clean_repository_has_no_diff() {
+        let (_temp_dir, repo) = committed_repo();
+        for format in [DiffFormat::Patch, DiffFormat::Stat, DiffFormat::NameOnly] {
+            assert!(working_tree_diff(&repo, format).unwrap().is_none());
+        }
+    }
+
+    #[test]
+    fn test_dirty_file_appears_in_every_format() {
+        let (temp_dir, repo) = committed_repo();
+        fs::write(
+            temp_dir.path().join("notes.txt"),
+            "first line\nchanged line\n",
+        )
+        .unwrap();
+
+        let patch = working_tree_diff(&repo, DiffFormat::Patch)
+            .unwrap()
+            .unwrap();
+        assert!(patch.contains("diff --git a/notes.txt b/notes.txt"));
+        assert!(patch.contains("@@ -1,2 +1,2 @@"));
+        assert!(patch.contains("\n first line\n"));
+        assert!(patch.contains("\n-second line\n"));
+        assert!(patch.contains("\n+changed line\n"));
+        assert!(!patch.contains("README.md"));
+
+        let stat = working_tree_diff(&repo, DiffFormat::Stat).unwrap().unwrap();
+        assert!(stat.contains("notes.txt"));
+        assert!(stat.contains("1 file changed, 1 insertion(+), 1 deletion(-)"));
+
+        let names = working_tree_diff(&repo, DiffFormat::NameOnly)
+            .unwrap()
+            .unwrap();
+        assert_eq!(names, "notes.txt\n");
+    }
+
+    #[test]
+    fn test_staged_changes_are_included() {
+        let (temp_dir, repo) = committed_repo();
+        fs::write(temp_dir.path().join("added.txt"), "new file\n").unwrap();
+        fs::remove_file(temp_dir.path().join("README.md")).unwrap();
+
+        // Untracked files are not part of the diff until staged
+        assert!(working_tree_diff(&repo, DiffFormat::NameOnly)
+            .unwrap()
+            .unwrap()
+            .contains("README.md"));
+        assert!(!working_tree_diff(&repo, DiffFormat::NameOnly)
+            .unwrap()
+            .unwrap()
+            .contains("added.txt"));
+
+        let mut index = repo.index().unwrap();
+        index.add_path(std::path::Path::new("added.txt")).unwrap();
+        index.write().unwrap();
+
+        let names = working_tree_diff(&repo, DiffFormat::NameOnly)
+            .unwrap()
+            .unwrap();
+        assert_eq!(names, "README.md\nadded.txt\n");
+    }
+}
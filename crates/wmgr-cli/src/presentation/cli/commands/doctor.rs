@@ -0,0 +1,117 @@
+use anyhow::Result;
+use colored::Colorize;
+
+use wmgr::application::use_cases::doctor::{
+    DoctorConfig, DoctorIssue, DoctorResult, DoctorUseCase, FixStatus,
+};
+use wmgr::common::error::WmgrError;
+use wmgr::domain::entities::workspace::Workspace;
+
+use crate::presentation::ui::Verbosity;
+
+/// Handler for the doctor command
+pub struct DoctorCommand {
+    pub groups: Vec<String>,
+    pub fix: bool,
+    pub force: bool,
+    pub verbosity: Verbosity,
+}
+
+impl DoctorCommand {
+    pub fn new(groups: Vec<String>, fix: bool, force: bool, verbosity: Verbosity) -> Self {
+        Self {
+            groups,
+            fix,
+            force,
+            verbosity,
+        }
+    }
+
+    pub async fn execute(&self, workspace: &Workspace) -> Result<()> {
+        let config = DoctorConfig::default()
+            .with_groups(self.groups.clone())
+            .with_fix(self.fix)
+            .with_force(self.force)
+            .with_verbose(self.verbosity.is_verbose());
+
+        let result = DoctorUseCase::new(config)
+            .execute(workspace)
+            .await
+            .map_err(WmgrError::from)?;
+
+        for issue in &result.issues {
+            self.print_issue(issue);
+        }
+        self.print_summary(&result);
+
+        if result.skipped_count > 0 && !self.force {
+            println!(
+                "{} Skipped fixes would discard local changes: re-run with --fix --force to apply them",
+                "hint:".yellow().bold()
+            );
+        }
+
+        if result.is_healthy() {
+            Ok(())
+        } else {
+            let outstanding = result
+                .issues
+                .iter()
+                .filter(|issue| issue.is_outstanding())
+                .count();
+            Err(anyhow::anyhow!(
+                "{} issues remain in the workspace",
+                outstanding
+            ))
+        }
+    }
+
+    fn print_issue(&self, issue: &DoctorIssue) {
+        let found = issue.found.as_deref().unwrap_or("(none)");
+        let (marker, outcome) = match issue.fix {
+            None => ("✗".red().bold(), String::new()),
+            Some(FixStatus::Fixed) => ("✓".green().bold(), "fixed".green().to_string()),
+            Some(FixStatus::Skipped) => ("-".yellow().bold(), "skipped".yellow().to_string()),
+            Some(FixStatus::Failed) => ("✗".red().bold(), "fix failed".red().to_string()),
+        };
+
+        println!("{} {}: {}", marker, issue.dest.bold(), issue.kind);
+        println!("    {} -> {}", found.red(), issue.expected.green());
+        match (&issue.fix, &issue.message) {
+            (Some(_), Some(message)) => println!("    {}: {}", outcome, message),
+            (Some(_), None) => println!("    {}", outcome),
+            (None, Some(message)) => println!("    {}", message.dimmed()),
+            (None, None) => {}
+        }
+    }
+
+    fn print_summary(&self, result: &DoctorResult) {
+        if self.verbosity.is_quiet() {
+            return;
+        }
+        if result.issues.is_empty() {
+            println!(
+                "{} {} repositories checked, no issues found",
+                "✓".green().bold(),
+                result.checked_count
+            );
+        } else if self.fix {
+            println!(
+                "{} {} issues in {} repositories: {} fixed, {} skipped, {} failed",
+                "::".blue().bold(),
+                result.issues.len(),
+                result.checked_count,
+                result.fixed_count,
+                result.skipped_count,
+                result.failed_count
+            );
+        } else {
+            println!(
+                "{} {} issues in {} repositories (run with --fix to repair them)",
+                "::".blue().bold(),
+                result.issues.len(),
+                result.checked_count
+            );
+        }
+    }
+}
@@ -1,22 +1,34 @@
 use anyhow::Result;
 use colored::Colorize;
-use std::env;
+use std::path::Path;
 
 use wmgr::application::services::manifest_service::{ManifestProcessingOptions, ManifestService};
-use wmgr::domain::entities::workspace::Workspace;
+use wmgr::application::services::workspace_loader::WorkspaceLoader;
+use wmgr::common::error::WmgrError;
+use wmgr::domain::entities::manifest::Manifest;
+use wmgr::domain::entities::workspace::resolve_dest_in;
+use wmgr::domain::value_objects::scm_type::ScmType;
+use wmgr::infrastructure::git::repository::GitRepository;
+
+use crate::presentation::ui::Verbosity;
 
 /// Handler for the dump-manifest command
 pub struct DumpManifestCommand {
     pub output_format: OutputFormat,
     pub output_file: Option<String>,
     pub pretty: bool,
-    pub verbose: bool,
+    pub verbosity: Verbosity,
+    pub groups: Vec<String>,
+    pub freeze: bool,
+    pub strict: bool,
+    pub workspace_loader: WorkspaceLoader,
 }
 
 #[derive(Clone, Debug)]
 pub enum OutputFormat {
     Yaml,
     Json,
+    Toml,
 }
 
 impl DumpManifestCommand {
@@ -24,21 +36,49 @@ impl DumpManifestCommand {
         output_format: OutputFormat,
         output_file: Option<String>,
         pretty: bool,
-        verbose: bool,
+        verbosity: Verbosity,
     ) -> Self {
         Self {
             output_format,
             output_file,
             pretty,
-            verbose,
+            verbosity,
+            groups: Vec::new(),
+            freeze: false,
+            strict: false,
+            workspace_loader: WorkspaceLoader::new(),
         }
     }
 
+    /// Pin each git repository to its checked-out commit, failing on missing
+    /// repositories when `strict` is set instead of leaving them unpinned
+    pub fn with_freeze(mut self, freeze: bool, strict: bool) -> Self {
+        self.freeze = freeze;
+        self.strict = strict;
+        self
+    }
+
+    /// Only dump the repositories and group definitions of these groups
+    pub fn with_groups(mut self, groups: Vec<String>) -> Self {
+        self.groups = groups;
+        self
+    }
+
+    /// Load the workspace with `workspace_loader` instead of the default loader
+    pub fn with_workspace_loader(mut self, workspace_loader: WorkspaceLoader) -> Self {
+        self.workspace_loader = workspace_loader;
+        self
+    }
+
     pub async fn execute(&self) -> Result<()> {
         // Load workspace
-        let workspace = self.load_workspace().await?;
+        let workspace = self
+            .workspace_loader
+            .load_current()
+            .await
+            .map_err(WmgrError::from)?;
 
-        if self.verbose {
+        if self.verbosity.is_verbose() {
             println!("{} Loading manifest from workspace", "::".blue().bold());
         }
 
@@ -53,28 +93,91 @@ impl DumpManifestCommand {
         }
 
         // Load and parse the manifest
-        let mut manifest_service = ManifestService::new(ManifestProcessingOptions::default());
+        let mut manifest_service = ManifestService::new(ManifestProcessingOptions::from_env());
         let processed_manifest = manifest_service
             .parse_from_file(&manifest_path)
             .await
             .map_err(|e| anyhow::anyhow!("Failed to parse manifest: {}", e))?;
 
+        let output_content = self.render(
+            &manifest_service,
+            &processed_manifest.manifest,
+            &workspace.root_path,
+        )?;
+
+        // Output the content
+        match &self.output_file {
+            Some(file_path) => {
+                // Write to file
+                std::fs::write(file_path, &output_content)?;
+                if !self.verbosity.is_quiet() {
+                    println!(
+                        "{} Manifest dumped to: {}",
+                        "✓".green().bold(),
+                        file_path.bold()
+                    );
+                }
+
+                if self.verbosity.is_verbose() {
+                    println!("  File size: {} bytes", output_content.len());
+                }
+            }
+            None => {
+                // Print to stdout
+                if self.verbosity.is_verbose() {
+                    println!("{} Manifest content:", "::".blue().bold());
+                    println!();
+                }
+                println!("{}", output_content);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Filter the manifest by the requested groups, freeze it if requested and
+    /// serialize it to the output format
+    fn render(
+        &self,
+        manifest_service: &ManifestService,
+        manifest: &Manifest,
+        workspace_root: &Path,
+    ) -> Result<String> {
+        let mut manifest = if self.groups.is_empty() {
+            manifest.clone()
+        } else {
+            if self.verbosity.is_verbose() {
+                println!(
+                    "  {} Keeping groups: {}",
+                    "->".blue(),
+                    self.groups.join(", ")
+                );
+            }
+            manifest_service
+                .filter_by_groups(manifest, &self.groups)
+                .map_err(|e| anyhow::anyhow!("Failed to filter manifest: {}", e))?
+        };
+        if self.freeze {
+            self.freeze_revisions(&mut manifest, workspace_root)?;
+        }
+        let manifest = &manifest;
+
         // Serialize the manifest to the requested format
         let output_content = match self.output_format {
             OutputFormat::Yaml => {
-                if self.verbose {
+                if self.verbosity.is_verbose() {
                     println!("  {} Dumping manifest as YAML", "->".blue());
                 }
                 manifest_service
-                    .serialize_to_yaml(&processed_manifest.manifest)
+                    .serialize_to_yaml(manifest)
                     .map_err(|e| anyhow::anyhow!("Failed to serialize to YAML: {}", e))?
             }
             OutputFormat::Json => {
-                if self.verbose {
+                if self.verbosity.is_verbose() {
                     println!("  {} Dumping manifest as JSON", "->".blue());
                 }
                 let json_str = manifest_service
-                    .serialize_to_json(&processed_manifest.manifest)
+                    .serialize_to_json(manifest)
                     .map_err(|e| anyhow::anyhow!("Failed to serialize to JSON: {}", e))?;
 
                 if self.pretty {
@@ -85,66 +188,48 @@ impl DumpManifestCommand {
                     json_str
                 }
             }
+            OutputFormat::Toml => {
+                if self.verbosity.is_verbose() {
+                    println!("  {} Dumping manifest as TOML", "->".blue());
+                }
+                manifest_service
+                    .serialize_to_toml(manifest)
+                    .map_err(|e| anyhow::anyhow!("Failed to serialize to TOML: {}", e))?
+            }
         };
 
-        // Output the content
-        match &self.output_file {
-            Some(file_path) => {
-                // Write to file
-                std::fs::write(file_path, &output_content)?;
-                println!(
-                    "{} Manifest dumped to: {}",
-                    "✓".green().bold(),
-                    file_path.bold()
-                );
+        Ok(output_content)
+    }
 
-                if self.verbose {
-                    println!("  File size: {} bytes", output_content.len());
+    /// Write the checked-out commit of every git repository into its `revision`
+    fn freeze_revisions(&self, manifest: &mut Manifest, workspace_root: &Path) -> Result<()> {
+        for repo in manifest.repos.iter_mut().filter(|r| r.scm == ScmType::Git) {
+            let head = resolve_dest_in(workspace_root, &repo.dest)
+                .map_err(anyhow::Error::from)
+                .and_then(|path| {
+                    GitRepository::open(path)
+                        .and_then(|git_repo| git_repo.head_commit_id())
+                        .map_err(anyhow::Error::from)
+                });
+
+            match head {
+                Ok(sha) => repo.revision = Some(sha),
+                Err(e) if self.strict => {
+                    return Err(anyhow::anyhow!("Cannot freeze '{}': {}", repo.dest, e));
                 }
-            }
-            None => {
-                // Print to stdout
-                if self.verbose {
-                    println!("{} Manifest content:", "::".blue().bold());
-                    println!();
+                Err(e) => {
+                    eprintln!(
+                        "{} Not freezing '{}': {}",
+                        "⚠".yellow().bold(),
+                        repo.dest,
+                        e
+                    );
                 }
-                println!("{}", output_content);
             }
         }
 
         Ok(())
     }
-
-    /// Load workspace from the current directory
-    async fn load_workspace(&self) -> Result<Workspace> {
-        let current_dir = env::current_dir()?;
-
-        let workspace = Workspace::new(current_dir.clone(), WorkspaceConfig::default_local());
-
-        // Use workspace.manifest_file_path() to support wmgr.yml, wmgr.yaml, manifest.yml, manifest.yaml
-        let manifest_file = workspace.manifest_file_path();
-
-        if !manifest_file.exists() {
-            return Err(anyhow::anyhow!("Manifest file not found. Tried wmgr.yml, wmgr.yaml, manifest.yml, manifest.yaml in current directory and .wmgr/ subdirectory"));
-        }
-
-        // Load manifest file
-        use wmgr::domain::entities::workspace::{WorkspaceConfig, WorkspaceStatus};
-        use wmgr::infrastructure::filesystem::manifest_store::ManifestStore;
-        let mut manifest_store = ManifestStore::new();
-        let processed_manifest = manifest_store
-            .read_manifest(&manifest_file)
-            .await
-            .map_err(|e| anyhow::anyhow!("Failed to load manifest: {}", e))?;
-
-        // Create a simple workspace configuration
-        let workspace_config = WorkspaceConfig::new(&manifest_file.display().to_string(), "main");
-
-        let workspace = Workspace::new(current_dir, workspace_config)
-            .with_status(WorkspaceStatus::Initialized)
-            .with_manifest(processed_manifest.manifest);
-        Ok(workspace)
-    }
 }
 
 impl std::str::FromStr for OutputFormat {
@@ -154,8 +239,9 @@ impl std::str::FromStr for OutputFormat {
         match s.to_lowercase().as_str() {
             "yaml" | "yml" => Ok(OutputFormat::Yaml),
             "json" => Ok(OutputFormat::Json),
+            "toml" => Ok(OutputFormat::Toml),
             _ => Err(anyhow::anyhow!(
-                "Invalid output format: {}. Supported formats: yaml, json",
+                "Invalid output format: {}. Supported formats: yaml, json, toml",
                 s
             )),
         }
@@ -167,6 +253,165 @@ impl std::fmt::Display for OutputFormat {
         match self {
             OutputFormat::Yaml => write!(f, "yaml"),
             OutputFormat::Json => write!(f, "json"),
+            OutputFormat::Toml => write!(f, "toml"),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use wmgr::domain::entities::manifest::{Group, ManifestRepo};
+
+    fn manifest() -> Manifest {
+        let mut groups = HashMap::new();
+        groups.insert(
+            "web".to_string(),
+            Group::new(vec!["frontend".to_string(), "shared".to_string()]),
+        );
+        groups.insert(
+            "api".to_string(),
+            Group::new(vec!["backend".to_string(), "shared".to_string()]),
+        );
+        Manifest::new(vec![
+            ManifestRepo::new("https://github.com/example/frontend.git", "frontend"),
+            ManifestRepo::new("https://github.com/example/backend.git", "backend"),
+            ManifestRepo::new("https://github.com/example/shared.git", "shared"),
+        ])
+        .with_groups(groups)
+    }
+
+    #[test]
+    fn test_dump_filtered_by_group_as_yaml() {
+        let command = DumpManifestCommand::new(OutputFormat::Yaml, None, false, Verbosity::Normal)
+            .with_groups(vec!["web".to_string()]);
+
+        let output = command
+            .render(&ManifestService::default(), &manifest(), Path::new("."))
+            .unwrap();
+        let dumped: Manifest = serde_yaml::from_str(&output).unwrap();
+
+        let dests: Vec<&str> = dumped.repos.iter().map(|r| r.dest.as_str()).collect();
+        assert_eq!(dests, ["frontend", "shared"]);
+        let groups = dumped.groups.unwrap();
+        assert_eq!(groups.keys().collect::<Vec<_>>(), ["web"]);
+        assert_eq!(groups["web"].repos, ["frontend", "shared"]);
+    }
+
+    #[test]
+    fn test_dump_filtered_by_group_as_pretty_json() {
+        let command = DumpManifestCommand::new(OutputFormat::Json, None, true, Verbosity::Normal)
+            .with_groups(vec!["api".to_string()]);
+
+        let output = command
+            .render(&ManifestService::default(), &manifest(), Path::new("."))
+            .unwrap();
+        let dumped: Manifest = serde_json::from_str(&output).unwrap();
+
+        let dests: Vec<&str> = dumped.repos.iter().map(|r| r.dest.as_str()).collect();
+        assert_eq!(dests, ["backend", "shared"]);
+        assert!(dumped.groups.unwrap().contains_key("api"));
+        assert!(output.contains("\n  "));
+    }
+
+    #[test]
+    fn test_dump_as_toml() {
+        let command =
+            DumpManifestCommand::new("toml".parse().unwrap(), None, false, Verbosity::Normal)
+                .with_groups(vec!["web".to_string()]);
+
+        let output = command
+            .render(&ManifestService::default(), &manifest(), Path::new("."))
+            .unwrap();
+        let dumped: Manifest = toml::from_str(&output).unwrap();
+
+        let dests: Vec<&str> = dumped.repos.iter().map(|r| r.dest.as_str()).collect();
+        assert_eq!(dests, ["frontend", "shared"]);
+    }
+
+    #[test]
+    fn test_dump_unknown_group_fails() {
+        let command = DumpManifestCommand::new(OutputFormat::Yaml, None, false, Verbosity::Normal)
+            .with_groups(vec!["missing".to_string()]);
+
+        assert!(command
+            .render(&ManifestService::default(), &manifest(), Path::new("."))
+            .is_err());
+    }
+
+    #[test]
+    fn test_dump_without_groups_keeps_everything() {
+        let command = DumpManifestCommand::new(OutputFormat::Yaml, None, false, Verbosity::Normal);
+
+        let output = command
+            .render(&ManifestService::default(), &manifest(), Path::new("."))
+            .unwrap();
+        let dumped: Manifest = serde_yaml::from_str(&output).unwrap();
+        assert_eq!(dumped.repos.len(), 3);
+        assert_eq!(dumped.groups.unwrap().len(), 2);
+    }
+
+    /// Initialize a repository at `path` with one commit and return its SHA
+    fn seed_repo(path: &Path, message: &str) -> String {
+        let repo = git2::Repository::init(path).unwrap();
+        let tree = repo
+            .find_tree(repo.index().unwrap().write_tree().unwrap())
+            .unwrap();
+        let signature = git2::Signature::now("Dev", "dev@example.com").unwrap();
+        repo.commit(Some("HEAD"), &signature, &signature, message, &tree, &[])
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn test_freeze_writes_checked_out_commits() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let frontend = seed_repo(&temp_dir.path().join("frontend"), "frontend");
+        let backend = seed_repo(&temp_dir.path().join("backend"), "backend");
+        let shared = seed_repo(&temp_dir.path().join("shared"), "shared");
+        let command = DumpManifestCommand::new(OutputFormat::Yaml, None, false, Verbosity::Normal)
+            .with_freeze(true, true);
+
+        let output = command
+            .render(&ManifestService::default(), &manifest(), temp_dir.path())
+            .unwrap();
+        let dumped: Manifest = serde_yaml::from_str(&output).unwrap();
+
+        let revisions: Vec<(&str, &str)> = dumped
+            .repos
+            .iter()
+            .map(|r| (r.dest.as_str(), r.revision.as_deref().unwrap()))
+            .collect();
+        assert_eq!(
+            revisions,
+            [
+                ("frontend", frontend.as_str()),
+                ("backend", backend.as_str()),
+                ("shared", shared.as_str())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_freeze_missing_repository_skips_or_fails_when_strict() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let frontend = seed_repo(&temp_dir.path().join("frontend"), "frontend");
+
+        let lenient = DumpManifestCommand::new(OutputFormat::Yaml, None, false, Verbosity::Normal)
+            .with_freeze(true, false);
+        let output = lenient
+            .render(&ManifestService::default(), &manifest(), temp_dir.path())
+            .unwrap();
+        let dumped: Manifest = serde_yaml::from_str(&output).unwrap();
+        assert_eq!(dumped.repos[0].revision.as_deref(), Some(frontend.as_str()));
+        assert_eq!(dumped.repos[1].revision, None);
+
+        let strict = DumpManifestCommand::new(OutputFormat::Yaml, None, false, Verbosity::Normal)
+            .with_freeze(true, true);
+        let error = strict
+            .render(&ManifestService::default(), &manifest(), temp_dir.path())
+            .unwrap_err();
+        assert!(error.to_string().contains("backend"));
+    }
+}
@@ -1,47 +1,102 @@
 use anyhow::Result;
 use colored::Colorize;
-use std::env;
+use std::collections::HashMap;
 
+use wmgr::application::services::workspace_loader::WorkspaceLoader;
 use wmgr::application::use_cases::foreach_command::{
-    ForeachCommandConfig, ForeachCommandError, ForeachCommandUseCase,
+    CommandStatus, ForeachCommandConfig, ForeachCommandError, ForeachCommandUseCase, ForeachResult,
 };
-use wmgr::domain::entities::workspace::Workspace;
+use wmgr::common::error::WmgrError;
+
+use crate::presentation::ui::Verbosity;
 
 /// Handler for the foreach command
 pub struct ForeachCommand {
     pub command: String,
     pub args: Vec<String>,
+    pub git: bool,
     pub groups: Vec<String>,
+    pub tags: Vec<String>,
+    pub exclude_tags: Vec<String>,
+    pub repo_patterns: Vec<String>,
     pub parallel: bool,
     pub jobs: Option<usize>,
     pub continue_on_error: bool,
-    pub verbose: bool,
+    /// Print a table of every repository's exit code at the end
+    pub summary: bool,
+    pub verbosity: Verbosity,
+    /// Extra environment variables (already merged from --env-file and --env)
+    pub environment: HashMap<String, String>,
+    /// Per-repository timeouts in seconds (from --timeout-for)
+    pub repo_timeouts: HashMap<String, u64>,
+    pub workspace_loader: WorkspaceLoader,
 }
 
 impl ForeachCommand {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         command: String,
         args: Vec<String>,
+        git: bool,
         groups: Vec<String>,
         parallel: bool,
         jobs: Option<usize>,
         continue_on_error: bool,
-        verbose: bool,
+        verbosity: Verbosity,
+        environment: HashMap<String, String>,
+        repo_timeouts: HashMap<String, u64>,
     ) -> Self {
         Self {
             command,
             args,
+            git,
             groups,
+            tags: Vec::new(),
+            exclude_tags: Vec::new(),
+            repo_patterns: Vec::new(),
             parallel,
             jobs,
             continue_on_error,
-            verbose,
+            summary: false,
+            verbosity,
+            environment,
+            repo_timeouts,
+            workspace_loader: WorkspaceLoader::new(),
         }
     }
 
+    /// Restrict the selection to repositories with one of `tags` and none of `exclude_tags`
+    pub fn with_tags(mut self, tags: Vec<String>, exclude_tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self.exclude_tags = exclude_tags;
+        self
+    }
+
+    /// Restrict the selection to repositories whose dest matches one of `repo_patterns`
+    pub fn with_repo_patterns(mut self, repo_patterns: Vec<String>) -> Self {
+        self.repo_patterns = repo_patterns;
+        self
+    }
+
+    /// Print a table of every repository's exit code at the end
+    pub fn with_summary(mut self, summary: bool) -> Self {
+        self.summary = summary;
+        self
+    }
+
+    /// Load the workspace with `workspace_loader` instead of the default loader
+    pub fn with_workspace_loader(mut self, workspace_loader: WorkspaceLoader) -> Self {
+        self.workspace_loader = workspace_loader;
+        self
+    }
+
     pub async fn execute(&self) -> Result<()> {
         // Load workspace
-        let workspace = self.load_workspace().await?;
+        let workspace = self
+            .workspace_loader
+            .load_current()
+            .await
+            .map_err(WmgrError::from)?;
 
         // Prepare groups list
         let groups_list = if self.groups.is_empty() {
@@ -61,22 +116,36 @@ impl ForeachCommand {
         let config = ForeachCommandConfig {
             command: full_command,
             groups: groups_list,
+            tags: self.tags.clone(),
+            exclude_tags: self.exclude_tags.clone(),
+            repo_patterns: self.repo_patterns.clone(),
             parallel: self.parallel,
             max_parallel: self.jobs,
             continue_on_error: self.continue_on_error,
-            verbose: self.verbose,
+            verbose: self.verbosity.is_verbose(),
+            git: self.git,
+            environment_variables: self.environment.clone(),
+            repo_timeouts: self.repo_timeouts.clone(),
             ..Default::default()
         };
 
+        if !self.verbosity.is_quiet() {
+            println!(
+                "{} Running command: {}",
+                "::".blue().bold(),
+                config.effective_command()
+            );
+        }
+
         // Execute the use case
         let use_case = ForeachCommandUseCase::new(config);
 
-        println!("{} Running command: {}", "::".blue().bold(), self.command);
-
         match use_case.execute(&workspace).await {
             Ok(result) => {
-                println!("{} Command execution completed!", "✓".green().bold());
-                if self.verbose {
+                if !self.verbosity.is_quiet() {
+                    println!("{} Command execution completed!", "✓".green().bold());
+                }
+                if self.verbosity.is_verbose() {
                     println!("  Successful executions: {}", result.success_count);
                     println!("  Failed executions: {}", result.failure_count);
                     println!("  Skipped executions: {}", result.skipped_count);
@@ -96,7 +165,11 @@ impl ForeachCommand {
                     }
                 }
 
-                Ok(())
+                if self.summary {
+                    print_summary(&result);
+                }
+
+                check_result(&result)
             }
             Err(ForeachCommandError::WorkspaceNotInitialized(path)) => {
                 Err(anyhow::anyhow!("Workspace not initialized at: {}\nInitialize with 'wmgr init' or ensure manifest.yml exists", path))
@@ -106,44 +179,117 @@ impl ForeachCommand {
             }
         }
     }
+}
 
-    /// Load workspace from the current directory
-    async fn load_workspace(&self) -> Result<Workspace> {
-        let current_dir = env::current_dir()?;
+/// Table of `dest  exit code  status` for every targeted repository, in target order
+///
+/// Repositories that never ran the command (skipped, or failed before starting)
+/// show `-` as their exit code.
+pub fn format_summary(result: &ForeachResult) -> Vec<String> {
+    let width = result
+        .results
+        .iter()
+        .map(|r| r.dest.len())
+        .max()
+        .unwrap_or(0);
 
-        let workspace = Workspace::new(current_dir.clone(), WorkspaceConfig::default_local());
+    result
+        .results
+        .iter()
+        .map(|r| {
+            let exit_code = r
+                .exit_code
+                .map(|code| code.to_string())
+                .unwrap_or_else(|| "-".to_string());
+            let status = match r.status {
+                CommandStatus::Success => "ok",
+                CommandStatus::Failed => "failed",
+                CommandStatus::Timeout => "timeout",
+                CommandStatus::Skipped => "skipped",
+                CommandStatus::Running => "running",
+            };
+            format!("{:<width$}  {:>4}  {}", r.dest, exit_code, status)
+        })
+        .collect()
+}
 
-        // Use workspace.manifest_file_path() to support wmgr.yml, wmgr.yaml, manifest.yml, manifest.yaml
-        let manifest_file = workspace.manifest_file_path();
+/// Print the `--summary` table
+pub fn print_summary(result: &ForeachResult) {
+    println!("{} Summary:", "::".blue().bold());
+    for (line, r) in format_summary(result).iter().zip(&result.results) {
+        if r.is_failure() {
+            println!("  {}", line.red());
+        } else {
+            println!("  {}", line);
+        }
+    }
+}
 
-        if !manifest_file.exists() {
-            return Err(anyhow::anyhow!("Manifest file not found. Tried wmgr.yml, wmgr.yaml, manifest.yml, manifest.yaml in current directory and .wmgr/ subdirectory"));
+/// Turn a finished foreach run into the command's outcome
+///
+/// Fails with [`WmgrError::ForeachIncomplete`] when the command failed or timed
+/// out in any repository, even under `--continue-on-error`, so that scripts see
+/// a nonzero exit code. Skipped repositories do not count as failures.
+pub fn check_result(result: &ForeachResult) -> Result<()> {
+    if result.failure_count > 0 {
+        return Err(WmgrError::ForeachIncomplete {
+            failed: result.failure_count,
         }
+        .into());
+    }
+    Ok(())
+}
 
-        // Load manifest file
-        use wmgr::domain::entities::workspace::{WorkspaceConfig, WorkspaceStatus};
-        use wmgr::infrastructure::filesystem::manifest_store::ManifestStore;
-        let mut manifest_store = ManifestStore::new();
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wmgr::application::use_cases::foreach_command::CommandResult;
 
-        let processed_manifest = manifest_store
-            .read_manifest(&manifest_file)
-            .await
-            .map_err(|e| anyhow::anyhow!("Failed to read manifest: {}", e))?;
-
-        // Create workspace config from manifest
-        let workspace_config = WorkspaceConfig::new(
-            "file://".to_string() + &manifest_file.to_string_lossy(),
-            processed_manifest
-                .manifest
-                .default_branch
-                .clone()
-                .unwrap_or_else(|| "main".to_string()),
+    fn mixed_result() -> ForeachResult {
+        let mut result = ForeachResult::new(false);
+        result.add_result(CommandResult::new("app".to_string()).with_success(
+            0,
+            String::new(),
+            String::new(),
+            5,
+        ));
+        result.add_result(CommandResult::new("libs/core".to_string()).with_failure(
+            Some(2),
+            "boom".to_string(),
+            5,
+        ));
+        result.add_result(CommandResult::new("docs".to_string()).with_skip("missing".to_string()));
+        result
+    }
+
+    #[test]
+    fn test_format_summary() {
+        assert_eq!(
+            format_summary(&mixed_result()),
+            vec![
+                "app           0  ok",
+                "libs/core     2  failed",
+                "docs          -  skipped",
+            ]
         );
+    }
 
-        let workspace = Workspace::new(current_dir, workspace_config)
-            .with_status(WorkspaceStatus::Initialized)
-            .with_manifest(processed_manifest.manifest);
+    #[test]
+    fn test_check_result_fails_on_any_failure() {
+        let error = check_result(&mixed_result()).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<WmgrError>(),
+            Some(WmgrError::ForeachIncomplete { failed: 1 })
+        ));
 
-        Ok(workspace)
+        let mut result = ForeachResult::new(true);
+        result.add_result(CommandResult::new("app".to_string()).with_success(
+            0,
+            String::new(),
+            String::new(),
+            5,
+        ));
+        result.add_result(CommandResult::new("docs".to_string()).with_skip("missing".to_string()));
+        assert!(check_result(&result).is_ok());
     }
 }
@@ -1,8 +1,15 @@
-use wmgr::common::templates::TemplateProcessor;
 use anyhow::Result;
 use std::env;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use wmgr::application::services::manifest_service::ManifestService;
+use wmgr::application::use_cases::generate_manifest::{
+    GenerateManifestConfig, GenerateManifestUseCase,
+};
+use wmgr::common::templates::TemplateProcessor;
+use wmgr::infrastructure::filesystem::manifest_store::ManifestStore;
+
+use crate::presentation::ui::{confirm, Verbosity};
 
 /// Initialize a new wmgr workspace
 pub struct InitCommand {
@@ -12,6 +19,16 @@ pub struct InitCommand {
     pub force: bool,
     /// Use manifest.yml instead of wmgr.yml
     pub use_manifest_name: bool,
+    /// Back up an existing file before overwriting it
+    pub backup: bool,
+    /// Generate the manifest from the repositories already checked out
+    pub from_existing: bool,
+    /// Group the found repositories by their top-level directory
+    pub group_by_dir: bool,
+    /// Accept the grouping offer without prompting
+    pub assume_yes: bool,
+    /// Output level
+    pub verbosity: Verbosity,
 }
 
 impl InitCommand {
@@ -20,9 +37,44 @@ impl InitCommand {
             path,
             force,
             use_manifest_name,
+            backup: true,
+            from_existing: false,
+            group_by_dir: false,
+            assume_yes: false,
+            verbosity: Verbosity::Normal,
         }
     }
 
+    /// Generate the manifest from the git repositories under the target directory
+    pub fn with_from_existing(mut self, from_existing: bool) -> Self {
+        self.from_existing = from_existing;
+        self
+    }
+
+    /// Group the found repositories by top-level directory without asking
+    pub fn with_group_by_dir(mut self, group_by_dir: bool) -> Self {
+        self.group_by_dir = group_by_dir;
+        self
+    }
+
+    /// Answer the grouping offer with yes (`--yes`)
+    pub fn with_yes(mut self, assume_yes: bool) -> Self {
+        self.assume_yes = assume_yes;
+        self
+    }
+
+    /// Enable or disable the backup of an existing file overwritten by --force
+    pub fn with_backup(mut self, backup: bool) -> Self {
+        self.backup = backup;
+        self
+    }
+
+    /// Set the output level; `Quiet` skips the next-steps message
+    pub fn with_verbosity(mut self, verbosity: Verbosity) -> Self {
+        self.verbosity = verbosity;
+        self
+    }
+
     /// Execute the init command
     pub async fn execute(&self) -> Result<()> {
         let current_dir = env::current_dir()?;
@@ -50,6 +102,30 @@ impl InitCommand {
             fs::create_dir_all(parent)?;
         }
 
+        // Keep a backup of the file being overwritten, like other manifest writes
+        if target_file.exists() {
+            ManifestStore::new()
+                .with_backup(self.backup)
+                .backup_file(&target_file)
+                .await?;
+        }
+
+        if self.from_existing {
+            let repo_count = self.write_existing_manifest(target_dir, &target_file)?;
+            if !self.verbosity.is_quiet() {
+                println!(
+                    "✅ Successfully created {} with {} existing repositories",
+                    filename, repo_count
+                );
+                println!("📁 Location: {}", target_file.display());
+                println!();
+                println!("📝 Next steps:");
+                println!("   1. Review the {} file", filename);
+                println!("   2. Use 'wmgr status' to check repository status");
+            }
+            return Ok(());
+        }
+
         // Generate template content
         let processor = TemplateProcessor::new();
         let template_content = processor.get_default_wmgr_template();
@@ -57,6 +133,10 @@ impl InitCommand {
         // Write template to file
         fs::write(&target_file, template_content)?;
 
+        if self.verbosity.is_quiet() {
+            return Ok(());
+        }
+
         println!("✅ Successfully created {} template file", filename);
         println!("📁 Location: {}", target_file.display());
         println!();
@@ -70,4 +150,40 @@ impl InitCommand {
 
         Ok(())
     }
+
+    /// Write a manifest listing the git repositories under `root`, returning how many were found
+    ///
+    /// Without --group-by-dir, grouping by top-level directory is offered when
+    /// some repositories are nested in one.
+    fn write_existing_manifest(&self, root: &Path, target_file: &Path) -> Result<usize> {
+        let use_case = GenerateManifestUseCase::new(GenerateManifestConfig::default());
+        let repositories = use_case.discover(root)?;
+        if repositories.is_empty() {
+            return Err(anyhow::anyhow!(
+                "No git repositories found under {}",
+                root.display()
+            ));
+        }
+
+        let nested = repositories
+            .iter()
+            .any(|repo| repo.top_level_dir().is_some());
+        let prompt = "Group repositories by top-level directory?";
+        let group_by_dir = self.group_by_dir || (nested && confirm(prompt, self.assume_yes)?);
+        let manifest = use_case.build_manifest(&repositories, group_by_dir);
+
+        if self.verbosity.is_verbose() {
+            for repo in &repositories {
+                println!(
+                    "  {} {}",
+                    repo.dest,
+                    repo.url.as_deref().unwrap_or("(no remote, local-only)")
+                );
+            }
+        }
+
+        let content = ManifestService::default().serialize_to_yaml(&manifest)?;
+        fs::write(target_file, content)?;
+        Ok(repositories.len())
+    }
 }
@@ -1,9 +1,14 @@
 use anyhow::Result;
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, TimeZone};
 use colored::Colorize;
-use std::env;
+use regex::Regex;
 
+use wmgr::application::services::workspace_loader::WorkspaceLoader;
+use wmgr::common::error::WmgrError;
 use wmgr::domain::entities::workspace::Workspace;
 
+use crate::presentation::ui::Verbosity;
+
 /// Handler for the log command
 pub struct LogCommand {
     pub groups: Vec<String>,
@@ -11,17 +16,320 @@ pub struct LogCommand {
     pub max_count: Option<usize>,
     pub since: Option<String>,
     pub until: Option<String>,
-    pub verbose: bool,
+    pub author: Option<String>,
+    pub grep: Option<String>,
+    pub graph: bool,
+    pub merged: bool,
+    pub verbosity: Verbosity,
+    pub workspace_loader: WorkspaceLoader,
+}
+
+/// Commit filters applied while walking a repository's history
+///
+/// All filters must match for a commit to be shown, mirroring `git log`.
+#[derive(Debug, Default, Clone)]
+pub struct CommitFilter {
+    /// Regex matched against `Name <email>` of the commit author
+    pub author: Option<Regex>,
+    /// Regex matched against the full commit message
+    pub grep: Option<Regex>,
+    /// Only commits at or after this Unix timestamp
+    pub since: Option<i64>,
+    /// Only commits at or before this Unix timestamp
+    pub until: Option<i64>,
+}
+
+impl CommitFilter {
+    /// Build a filter from the raw command-line values
+    pub fn new(
+        author: Option<&str>,
+        grep: Option<&str>,
+        since: Option<&str>,
+        until: Option<&str>,
+    ) -> std::result::Result<Self, WmgrError> {
+        Ok(Self {
+            author: author.map(|p| compile_pattern("author", p)).transpose()?,
+            grep: grep.map(|p| compile_pattern("grep", p)).transpose()?,
+            since: since.map(|d| parse_date("since", d, false)).transpose()?,
+            until: until.map(|d| parse_date("until", d, true)).transpose()?,
+        })
+    }
+
+    /// Whether a commit passes every filter
+    pub fn matches(&self, commit: &git2::Commit) -> bool {
+        let time = commit.time().seconds();
+        if self.since.is_some_and(|since| time < since)
+            || self.until.is_some_and(|until| time > until)
+        {
+            return false;
+        }
+
+        if let Some(author) = &self.author {
+            let signature = commit.author();
+            let identity = format!(
+                "{} <{}>",
+                signature.name().unwrap_or(""),
+                signature.email().unwrap_or("")
+            );
+            if !author.is_match(&identity) {
+                return false;
+            }
+        }
+
+        if let Some(grep) = &self.grep {
+            if !grep.is_match(commit.message().unwrap_or("")) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+fn compile_pattern(field: &str, pattern: &str) -> std::result::Result<Regex, WmgrError> {
+    Regex::new(pattern).map_err(|e| {
+        WmgrError::validation_error(
+            field,
+            format!("invalid pattern: {}", e),
+            Some(pattern.to_string()),
+        )
+    })
+}
+
+/// Parse a `--since`/`--until` date into a Unix timestamp
+///
+/// Accepts RFC 3339 (`2024-05-01T12:00:00+02:00`), `YYYY-MM-DD HH:MM:SS` and
+/// `YYYY-MM-DD` in local time. A bare date means the start of that day, or its
+/// end when `end_of_day` is set, so `--until 2024-05-01` includes that day.
+pub(crate) fn parse_date(
+    field: &str,
+    value: &str,
+    end_of_day: bool,
+) -> std::result::Result<i64, WmgrError> {
+    let value = value.trim();
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+        return Ok(datetime.timestamp());
+    }
+
+    let naive = NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S")
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| {
+                    if end_of_day {
+                        date.and_hms_opt(23, 59, 59)
+                    } else {
+                        date.and_hms_opt(0, 0, 0)
+                    }
+                })
+        });
+
+    naive
+        .and_then(|naive| Local.from_local_datetime(&naive).earliest())
+        .map(|datetime| datetime.timestamp())
+        .ok_or_else(|| {
+            WmgrError::validation_error(
+                field,
+                "expected YYYY-MM-DD, YYYY-MM-DD HH:MM:SS or an RFC 3339 date",
+                Some(value.to_string()),
+            )
+        })
+}
+
+/// Commits of a repository that pass the filter, newest first
+fn matching_commits(
+    git_repo: &git2::Repository,
+    filter: &CommitFilter,
+    max_count: usize,
+) -> Result<Vec<git2::Oid>> {
+    let mut revwalk = git_repo.revwalk()?;
+    revwalk.push_head()?;
+    revwalk.set_sorting(git2::Sort::TIME)?;
+
+    let mut commits = Vec::new();
+    for oid in revwalk {
+        if commits.len() >= max_count {
+            break;
+        }
+        let oid = oid?;
+        if filter.matches(&git_repo.find_commit(oid)?) {
+            commits.push(oid);
+        }
+    }
+    Ok(commits)
+}
+
+/// Commits of several repositories as one stream, newest first
+///
+/// Each repository contributes at most `max_count` commits, so truncating the
+/// merged stream to `max_count` yields the newest commits of the workspace.
+/// Commits with the same timestamp keep the order of `repos`.
+fn merged_log(
+    repos: &[(String, git2::Repository)],
+    filter: &CommitFilter,
+    max_count: usize,
+) -> Result<Vec<(i64, String, git2::Oid)>> {
+    let mut commits = Vec::new();
+    for (dest, git_repo) in repos {
+        for oid in matching_commits(git_repo, filter, max_count)? {
+            let time = git_repo.find_commit(oid)?.time().seconds();
+            commits.push((time, dest.clone(), oid));
+        }
+    }
+
+    commits.sort_by_key(|(time, _, _)| std::cmp::Reverse(*time));
+    commits.truncate(max_count);
+    Ok(commits)
+}
+
+/// Graph rows drawn for one commit
+#[derive(Debug, Default, PartialEq)]
+struct GraphRows {
+    /// Connector rows above the commit row, where lanes join (`|/`)
+    before: Vec<String>,
+    /// Graph columns of the commit row, with `*` in the commit's lane
+    commit: String,
+    /// Connector rows below the commit row, where a merge opens lanes (`|\`)
+    after: Vec<String>,
+    /// Graph columns for message lines below the commit
+    continuation: String,
+}
+
+/// Commits passing the filter, in topological order, with their graph rows
+///
+/// The graph is drawn over the full history so lanes stay connected when the
+/// filter hides commits.
+fn graph_log(
+    git_repo: &git2::Repository,
+    filter: &CommitFilter,
+    max_count: usize,
+) -> Result<Vec<(git2::Oid, GraphRows)>> {
+    let mut revwalk = git_repo.revwalk()?;
+    revwalk.push_head()?;
+    revwalk.set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::TIME)?;
+
+    let mut graph = GraphRenderer::default();
+    let mut entries = Vec::new();
+    for oid in revwalk {
+        if entries.len() >= max_count {
+            break;
+        }
+        let commit = git_repo.find_commit(oid?)?;
+        let parents: Vec<git2::Oid> = commit.parent_ids().collect();
+        let rows = graph.next(commit.id(), &parents);
+        if filter.matches(&commit) {
+            entries.push((commit.id(), rows));
+        }
+    }
+    Ok(entries)
+}
+
+/// Incremental ASCII commit graph in the style of `git log --graph`
+///
+/// Each lane holds the commit it is waiting for. Commits must be fed children
+/// before parents.
+#[derive(Debug, Default)]
+struct GraphRenderer {
+    lanes: Vec<Option<git2::Oid>>,
+}
+
+impl GraphRenderer {
+    /// Graph rows for the next commit
+    fn next(&mut self, id: git2::Oid, parents: &[git2::Oid]) -> GraphRows {
+        let mut rows = GraphRows::default();
+
+        // Lanes waiting for this commit converge on the leftmost one
+        let waiting: Vec<usize> = (0..self.lanes.len())
+            .filter(|&i| self.lanes[i] == Some(id))
+            .collect();
+        let lane = match waiting.first() {
+            Some(&lane) => lane,
+            None => self.free_lane(),
+        };
+        if waiting.len() > 1 {
+            rows.before.push(self.connector(&waiting[1..], '/'));
+            for &i in &waiting[1..] {
+                self.lanes[i] = None;
+            }
+        }
+
+        self.lanes[lane] = Some(id);
+        rows.commit = self.row(Some(lane));
+
+        // The first parent continues this lane, further parents branch off
+        self.lanes[lane] = parents.first().copied();
+        let mut opened = Vec::new();
+        for parent in parents.iter().skip(1) {
+            if !self.lanes.contains(&Some(*parent)) {
+                let new_lane = self.free_lane();
+                self.lanes[new_lane] = Some(*parent);
+                opened.push(new_lane);
+            }
+        }
+        if !opened.is_empty() {
+            rows.after.push(self.connector(&opened, '\\'));
+        }
+
+        while self.lanes.last() == Some(&None) {
+            self.lanes.pop();
+        }
+        rows.continuation = self.row(None);
+        rows
+    }
+
+    fn free_lane(&mut self) -> usize {
+        match self.lanes.iter().position(Option::is_none) {
+            Some(lane) => lane,
+            None => {
+                self.lanes.push(None);
+                self.lanes.len() - 1
+            }
+        }
+    }
+
+    /// `|` for every occupied lane, with `*` in the commit's lane
+    fn row(&self, commit_lane: Option<usize>) -> String {
+        let cells: Vec<&str> = self
+            .lanes
+            .iter()
+            .enumerate()
+            .map(|(i, lane)| match lane {
+                _ if Some(i) == commit_lane => "*",
+                Some(_) => "|",
+                None => " ",
+            })
+            .collect();
+        cells.join(" ").trim_end().to_string()
+    }
+
+    /// A row where `lanes` bend into the gap on their left with `symbol`
+    fn connector(&self, lanes: &[usize], symbol: char) -> String {
+        let mut chars: Vec<char> = self.row(None).chars().collect();
+        chars.resize(self.lanes.len() * 2, ' ');
+        for &lane in lanes {
+            chars[lane * 2] = ' ';
+            if lane > 0 {
+                chars[lane * 2 - 1] = symbol;
+            }
+        }
+        chars.into_iter().collect::<String>().trim_end().to_string()
+    }
 }
 
 impl LogCommand {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         groups: Vec<String>,
         oneline: bool,
         max_count: Option<usize>,
         since: Option<String>,
         until: Option<String>,
-        verbose: bool,
+        author: Option<String>,
+        grep: Option<String>,
+        graph: bool,
+        merged: bool,
+        verbosity: Verbosity,
     ) -> Self {
         Self {
             groups,
@@ -29,13 +337,35 @@ impl LogCommand {
             max_count,
             since,
             until,
-            verbose,
+            author,
+            grep,
+            graph,
+            merged,
+            verbosity,
+            workspace_loader: WorkspaceLoader::new(),
         }
     }
 
+    /// Load the workspace with `workspace_loader` instead of the default loader
+    pub fn with_workspace_loader(mut self, workspace_loader: WorkspaceLoader) -> Self {
+        self.workspace_loader = workspace_loader;
+        self
+    }
+
     pub async fn execute(&self) -> Result<()> {
+        let filter = CommitFilter::new(
+            self.author.as_deref(),
+            self.grep.as_deref(),
+            self.since.as_deref(),
+            self.until.as_deref(),
+        )?;
+
         // Load workspace
-        let workspace = self.load_workspace().await?;
+        let workspace = self
+            .workspace_loader
+            .load_current()
+            .await
+            .map_err(WmgrError::from)?;
 
         // Get repositories to check
         let repositories = self.get_repositories_to_check(&workspace)?;
@@ -45,15 +375,75 @@ impl LogCommand {
             return Ok(());
         }
 
-        println!(
-            "{} Showing commit log for {} repositories",
-            "::".blue().bold(),
-            repositories.len()
-        );
+        if !self.verbosity.is_quiet() {
+            println!(
+                "{} Showing commit log for {} repositories",
+                "::".blue().bold(),
+                repositories.len()
+            );
+        }
+
+        if self.merged {
+            return self.show_merged_log(&repositories, &workspace, &filter);
+        }
 
         // Show log for each repository
         for repo in repositories {
-            self.show_repository_log(&repo, &workspace).await?;
+            self.show_repository_log(&repo, &workspace, &filter).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Show the commits of all repositories interleaved by commit date
+    fn show_merged_log(
+        &self,
+        repositories: &[wmgr::domain::entities::repository::Repository],
+        workspace: &Workspace,
+        filter: &CommitFilter,
+    ) -> Result<()> {
+        let mut repos = Vec::new();
+        for repo in repositories {
+            match git2::Repository::open(workspace.resolve_dest(&repo.dest)?) {
+                Ok(git_repo) if git_repo.head().is_ok() => {
+                    repos.push((repo.dest.clone(), git_repo))
+                }
+                Ok(_) => {
+                    if self.verbosity.is_verbose() {
+                        println!("{} {}: no commits yet", "⚠".yellow(), repo.dest);
+                    }
+                }
+                Err(e) => {
+                    if self.verbosity.is_verbose() {
+                        println!(
+                            "{} {}: not a git repository - {}",
+                            "⚠".yellow(),
+                            repo.dest,
+                            e
+                        );
+                    }
+                }
+            }
+        }
+
+        let commits = merged_log(&repos, filter, self.max_count.unwrap_or(10))?;
+        println!();
+        if commits.is_empty() {
+            println!("  {}", "No commits found".dimmed());
+            return Ok(());
+        }
+
+        let width = commits
+            .iter()
+            .map(|(_, dest, _)| dest.len())
+            .max()
+            .unwrap_or(0);
+        for (_, dest, oid) in &commits {
+            let git_repo = &repos.iter().find(|(d, _)| d == dest).unwrap().1;
+            let label = format!("{:<width$} ", dest, width = width)
+                .green()
+                .to_string();
+            self.print_commit(&git_repo.find_commit(*oid)?, &GraphRows::default(), &label);
         }
 
         Ok(())
@@ -63,11 +453,12 @@ impl LogCommand {
         &self,
         repo: &wmgr::domain::entities::repository::Repository,
         workspace: &Workspace,
+        filter: &CommitFilter,
     ) -> Result<()> {
-        let repo_path = workspace.root_path.join(&repo.dest);
+        let repo_path = workspace.resolve_dest(&repo.dest)?;
 
         if !repo_path.exists() {
-            if self.verbose {
+            if self.verbosity.is_verbose() {
                 println!("{} {}: repository not found", "⚠".yellow(), repo.dest);
             }
             return Ok(());
@@ -80,7 +471,7 @@ impl LogCommand {
         let git_repo = match git2::Repository::open(&repo_path) {
             Ok(repo) => repo,
             Err(e) => {
-                if self.verbose {
+                if self.verbosity.is_verbose() {
                     println!("  {}: not a git repository - {}", "Error".red(), e);
                 }
                 return Ok(());
@@ -91,7 +482,7 @@ impl LogCommand {
         let head_ref = match git_repo.head() {
             Ok(head) => head,
             Err(e) => {
-                if self.verbose {
+                if self.verbosity.is_verbose() {
                     println!("  {}: failed to get HEAD - {}", "Error".red(), e);
                 }
                 return Ok(());
@@ -99,150 +490,401 @@ impl LogCommand {
         };
 
         let branch_name = head_ref.shorthand().unwrap_or("unknown");
-        if self.verbose {
+        if self.verbosity.is_verbose() {
             println!("  {}: {}", "Branch".blue(), branch_name);
         }
 
-        // Walk commits
-        let mut revwalk = git_repo.revwalk()?;
-        revwalk.push_head()?;
-        revwalk.set_sorting(git2::Sort::TIME)?;
-
-        let mut count = 0;
         let max_count = self.max_count.unwrap_or(10);
-
-        for commit_oid in revwalk {
-            if count >= max_count {
-                break;
-            }
-
-            let commit_oid = commit_oid?;
-            let commit = git_repo.find_commit(commit_oid)?;
-
-            // Check date filters
-            let commit_time = commit.time();
-            let commit_timestamp = commit_time.seconds();
-
-            if let Some(ref since) = self.since {
-                // For simplicity, we'll just show a placeholder for date parsing
-                // In a real implementation, you'd parse the date string
-                if self.verbose {
-                    println!(
-                        "  (Date filtering with 'since: {}' not fully implemented)",
-                        since
-                    );
+        let count = if self.graph {
+            let entries = graph_log(&git_repo, filter, max_count)?;
+            for (oid, rows) in &entries {
+                for row in &rows.before {
+                    println!("  {}", row);
                 }
+                self.print_commit(&git_repo.find_commit(*oid)?, rows, "");
             }
-
-            if let Some(ref until) = self.until {
-                // For simplicity, we'll just show a placeholder for date parsing
-                // In a real implementation, you'd parse the date string
-                if self.verbose {
-                    println!(
-                        "  (Date filtering with 'until: {}' not fully implemented)",
-                        until
-                    );
-                }
+            entries.len()
+        } else {
+            let commits = matching_commits(&git_repo, filter, max_count)?;
+            for oid in &commits {
+                self.print_commit(&git_repo.find_commit(*oid)?, &GraphRows::default(), "");
             }
+            commits.len()
+        };
 
-            // Display commit info
-            let commit_hash = commit.id().to_string();
-            let short_hash = &commit_hash[..7];
-            let message = commit.message().unwrap_or("(no message)");
-            let summary = message.lines().next().unwrap_or("(no message)");
+        if count == 0 {
+            println!("  {}", "No commits found".dimmed());
+        }
 
-            if self.oneline {
-                println!("  {} {}", short_hash.yellow(), summary);
-            } else {
-                let author = commit.author();
-                let author_name = author.name().unwrap_or("unknown");
-                let author_email = author.email().unwrap_or("unknown");
-
-                // Format time
-                let datetime = chrono::DateTime::from_timestamp(commit_timestamp, 0)
-                    .unwrap_or_else(|| chrono::DateTime::from_timestamp(0, 0).unwrap());
-                let formatted_time = datetime.format("%Y-%m-%d %H:%M:%S");
-
-                println!("  {} {}", "commit".yellow(), commit_hash.yellow());
-                println!("  {}: {} <{}>", "Author".blue(), author_name, author_email);
-                println!("  {}: {}", "Date".blue(), formatted_time);
-                println!();
+        Ok(())
+    }
 
-                // Show full commit message with indentation
-                for line in message.lines() {
-                    println!("      {}", line);
-                }
+    /// Print one commit with its graph rows; without a graph all rows are empty
+    ///
+    /// `label` (the padded repository dest in merged mode) precedes the hash.
+    fn print_commit(&self, commit: &git2::Commit, rows: &GraphRows, label: &str) {
+        let lead = |columns: &str| {
+            if columns.is_empty() {
+                String::new()
+            } else {
+                format!("{} ", columns)
+            }
+        };
+        let (graph, continuation) = (lead(&rows.commit), lead(&rows.continuation));
+        let blank = || {
+            if continuation.is_empty() {
                 println!();
+            } else {
+                println!("  {}", continuation.trim_end());
             }
+        };
 
-            count += 1;
+        for row in &rows.before {
+            println!("  {}", row);
         }
 
-        if count == 0 {
-            println!("  {}", "No commits found".dimmed());
+        let commit_hash = commit.id().to_string();
+        let short_hash = &commit_hash[..7];
+        let message = commit.message().unwrap_or("(no message)");
+        let summary = message.lines().next().unwrap_or("(no message)");
+
+        if self.oneline {
+            println!("  {}{}{} {}", graph, label, short_hash.yellow(), summary);
+            for row in &rows.after {
+                println!("  {}", row);
+            }
+            return;
         }
 
-        Ok(())
+        let author = commit.author();
+        let author_name = author.name().unwrap_or("unknown");
+        let author_email = author.email().unwrap_or("unknown");
+
+        // Format time
+        let datetime = chrono::DateTime::from_timestamp(commit.time().seconds(), 0)
+            .unwrap_or_else(|| chrono::DateTime::from_timestamp(0, 0).unwrap());
+        let formatted_time = datetime.format("%Y-%m-%d %H:%M:%S");
+
+        println!(
+            "  {}{}{} {}",
+            graph,
+            label,
+            "commit".yellow(),
+            commit_hash.yellow()
+        );
+        for row in &rows.after {
+            println!("  {}", row);
+        }
+        println!(
+            "  {}{}: {} <{}>",
+            continuation,
+            "Author".blue(),
+            author_name,
+            author_email
+        );
+        println!("  {}{}: {}", continuation, "Date".blue(), formatted_time);
+        blank();
+
+        // Show full commit message with indentation
+        for line in message.lines() {
+            println!("  {}    {}", continuation, line);
+        }
+        blank();
     }
 
     fn get_repositories_to_check(
         &self,
         workspace: &Workspace,
     ) -> Result<Vec<wmgr::domain::entities::repository::Repository>> {
-        // For now, return a sample repository
-        // In a real implementation, this would load from the workspace manifest
-        let mut repositories = Vec::new();
+        let Some(manifest) = &workspace.manifest else {
+            return Ok(Vec::new());
+        };
 
-        // This is a placeholder implementation
-        // In a real implementation, you would:
-        // 1. Load the manifest from the workspace
-        // 2. Filter repositories by groups if specified
-        // 3. Return the filtered list
+        let repos = if self.groups.is_empty() {
+            workspace.configured_repos()
+        } else {
+            manifest.repos_in_groups(&self.groups)
+        };
+        Ok(repos.into_iter().map(|repo| repo.to_repository()).collect())
+    }
+}
 
-        if self.verbose {
-            println!("  (Repository filtering by groups not fully implemented)");
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    /// Create a commit with an empty tree; the last one created becomes HEAD
+    fn seed_commit(
+        repo: &git2::Repository,
+        author: (&str, &str),
+        message: &str,
+        time: i64,
+        parents: &[git2::Oid],
+    ) -> git2::Oid {
+        let signature =
+            git2::Signature::new(author.0, author.1, &git2::Time::new(time, 0)).unwrap();
+        let tree_id = repo.treebuilder(None).unwrap().write().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+        let parents: Vec<git2::Commit> = parents
+            .iter()
+            .map(|oid| repo.find_commit(*oid).unwrap())
+            .collect();
+        let parents: Vec<&git2::Commit> = parents.iter().collect();
+        let oid = repo
+            .commit(None, &signature, &signature, message, &tree, &parents)
+            .unwrap();
+        repo.reference("refs/heads/main", oid, true, "seed")
+            .unwrap();
+        repo.set_head("refs/heads/main").unwrap();
+        oid
+    }
 
-        Ok(repositories)
+    fn summaries(repo: &git2::Repository, oids: &[git2::Oid]) -> Vec<String> {
+        oids.iter()
+            .map(|oid| {
+                repo.find_commit(*oid)
+                    .unwrap()
+                    .summary()
+                    .unwrap()
+                    .to_string()
+            })
+            .collect()
     }
 
-    /// Load workspace from the current directory
-    async fn load_workspace(&self) -> Result<Workspace> {
-        let current_dir = env::current_dir()?;
+    fn seeded_linear_repo() -> (TempDir, git2::Repository) {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = git2::Repository::init(temp_dir.path()).unwrap();
+        let alice = ("Alice", "alice@example.com");
+        let bob = ("Bob", "bob@example.org");
+        let first = seed_commit(&repo, alice, "Add login form", 1_000, &[]);
+        let second = seed_commit(&repo, bob, "Fix login bug", 2_000, &[first]);
+        seed_commit(&repo, alice, "Update docs", 3_000, &[second]);
+        (temp_dir, repo)
+    }
 
-        let workspace = Workspace::new(current_dir.clone(), WorkspaceConfig::default_local());
+    #[test]
+    fn test_author_and_grep_filters() {
+        let (_temp_dir, repo) = seeded_linear_repo();
+        let log =
+            |filter: CommitFilter| summaries(&repo, &matching_commits(&repo, &filter, 10).unwrap());
 
-        // Use workspace.manifest_file_path() to support wmgr.yml, wmgr.yaml, manifest.yml, manifest.yaml
-        let manifest_file = workspace.manifest_file_path();
+        assert_eq!(log(CommitFilter::default()).len(), 3);
+        assert_eq!(
+            log(CommitFilter::new(Some("Alice"), None, None, None).unwrap()),
+            vec!["Update docs", "Add login form"]
+        );
+        assert_eq!(
+            log(CommitFilter::new(Some("@example\\.org>$"), None, None, None).unwrap()),
+            vec!["Fix login bug"]
+        );
+        assert_eq!(
+            log(CommitFilter::new(None, Some("login"), None, None).unwrap()),
+            vec!["Fix login bug", "Add login form"]
+        );
+        assert_eq!(
+            log(CommitFilter::new(Some("Alice"), Some("(?i)LOGIN"), None, None).unwrap()),
+            vec!["Add login form"]
+        );
+    }
 
-        if !manifest_file.exists() {
-            return Err(anyhow::anyhow!("Manifest file not found. Tried wmgr.yml, wmgr.yaml, manifest.yml, manifest.yaml in current directory and .wmgr/ subdirectory"));
-        }
+    #[test]
+    fn test_filters_compose_with_date_range() {
+        let (_temp_dir, repo) = seeded_linear_repo();
+        let log =
+            |filter: CommitFilter| summaries(&repo, &matching_commits(&repo, &filter, 10).unwrap());
+
+        let grep_login = CommitFilter::new(None, Some("login"), None, None).unwrap();
+        assert_eq!(
+            log(CommitFilter {
+                since: Some(1_500),
+                ..grep_login.clone()
+            }),
+            vec!["Fix login bug"]
+        );
+        assert_eq!(
+            log(CommitFilter {
+                until: Some(1_500),
+                ..grep_login
+            }),
+            vec!["Add login form"]
+        );
 
-        // Load manifest file
-        use wmgr::domain::entities::workspace::{WorkspaceConfig, WorkspaceStatus};
-        use wmgr::infrastructure::filesystem::manifest_store::ManifestStore;
-        let mut manifest_store = ManifestStore::new();
+        let range = CommitFilter::new(
+            Some("Alice"),
+            None,
+            Some("1970-01-01T00:25:00Z"),
+            Some("1970-01-01T01:00:00Z"),
+        )
+        .unwrap();
+        assert_eq!(log(range), vec!["Update docs"]);
+
+        // The filter applies before --max-count
+        let filter = CommitFilter::new(Some("Alice"), None, None, None).unwrap();
+        assert_eq!(
+            summaries(&repo, &matching_commits(&repo, &filter, 1).unwrap()),
+            vec!["Update docs"]
+        );
+    }
 
-        let processed_manifest = manifest_store
-            .read_manifest(&manifest_file)
-            .await
-            .map_err(|e| anyhow::anyhow!("Failed to read manifest: {}", e))?;
+    #[test]
+    fn test_invalid_filters_are_rejected() {
+        assert!(matches!(
+            CommitFilter::new(Some("(unclosed"), None, None, None),
+            Err(WmgrError::ValidationError { ref field, .. }) if field == "author"
+        ));
+        assert!(matches!(
+            CommitFilter::new(None, None, Some("last tuesday"), None),
+            Err(WmgrError::ValidationError { ref field, .. }) if field == "since"
+        ));
+    }
+
+    #[test]
+    fn test_parse_date_formats() {
+        assert_eq!(
+            parse_date("since", "2024-05-01T12:00:00Z", false).unwrap(),
+            1_714_564_800
+        );
+        assert_eq!(
+            parse_date("since", "2024-05-01T14:00:00+02:00", false).unwrap(),
+            1_714_564_800
+        );
+
+        // Bare dates cover the whole day
+        let start = parse_date("since", "2024-05-01", false).unwrap();
+        let end = parse_date("until", "2024-05-01", true).unwrap();
+        assert_eq!(end - start, 24 * 60 * 60 - 1);
+        assert_eq!(
+            parse_date("since", "2024-05-01 00:00:00", false).unwrap(),
+            start
+        );
+    }
 
-        // Create workspace config from manifest
-        let workspace_config = WorkspaceConfig::new(
-            "file://".to_string() + &manifest_file.to_string_lossy(),
-            processed_manifest
-                .manifest
-                .default_branch
-                .clone()
-                .unwrap_or_else(|| "main".to_string()),
+    #[test]
+    fn test_merged_log_interleaves_repositories_by_date() {
+        let (_app_dir, app) = {
+            let temp_dir = TempDir::new().unwrap();
+            let repo = git2::Repository::init(temp_dir.path()).unwrap();
+            let dev = ("Alice", "alice@example.com");
+            let first = seed_commit(&repo, dev, "app: scaffold", 1_000, &[]);
+            let second = seed_commit(&repo, dev, "app: login page", 3_000, &[first]);
+            seed_commit(&repo, dev, "app: release", 5_000, &[second]);
+            (temp_dir, repo)
+        };
+        let (_lib_dir, lib) = {
+            let temp_dir = TempDir::new().unwrap();
+            let repo = git2::Repository::init(temp_dir.path()).unwrap();
+            let dev = ("Bob", "bob@example.org");
+            let first = seed_commit(&repo, dev, "lib: init", 2_000, &[]);
+            seed_commit(&repo, dev, "lib: auth api", 4_000, &[first]);
+            (temp_dir, repo)
+        };
+        let repos = vec![("app".to_string(), app), ("lib".to_string(), lib)];
+        let stream = |filter: &CommitFilter, max_count: usize| -> Vec<(i64, String, String)> {
+            merged_log(&repos, filter, max_count)
+                .unwrap()
+                .into_iter()
+                .map(|(time, dest, oid)| {
+                    let repo = &repos.iter().find(|(d, _)| *d == dest).unwrap().1;
+                    let summary = repo
+                        .find_commit(oid)
+                        .unwrap()
+                        .summary()
+                        .unwrap()
+                        .to_string();
+                    (time, dest, summary)
+                })
+                .collect()
+        };
+
+        let all = stream(&CommitFilter::default(), 10);
+        assert_eq!(
+            all.iter().map(|(time, _, _)| *time).collect::<Vec<_>>(),
+            vec![5_000, 4_000, 3_000, 2_000, 1_000]
+        );
+        assert_eq!(
+            all.iter()
+                .map(|(_, dest, _)| dest.as_str())
+                .collect::<Vec<_>>(),
+            vec!["app", "lib", "app", "lib", "app"]
+        );
+        assert_eq!(all[1].2, "lib: auth api");
+
+        // --max-count caps the whole stream, not each repository
+        let newest = stream(&CommitFilter::default(), 3);
+        assert_eq!(
+            newest
+                .iter()
+                .map(|(_, _, s)| s.as_str())
+                .collect::<Vec<_>>(),
+            vec!["app: release", "lib: auth api", "app: login page"]
         );
 
-        let workspace = Workspace::new(current_dir, workspace_config)
-            .with_status(WorkspaceStatus::Initialized)
-            .with_manifest(processed_manifest.manifest);
+        // The date range and filters apply before interleaving
+        let range = CommitFilter {
+            since: Some(1_500),
+            until: Some(4_500),
+            ..CommitFilter::default()
+        };
+        assert_eq!(
+            stream(&range, 10)
+                .iter()
+                .map(|(_, _, s)| s.as_str())
+                .collect::<Vec<_>>(),
+            vec!["lib: auth api", "app: login page", "lib: init"]
+        );
+        let bob = CommitFilter::new(Some("Bob"), None, None, None).unwrap();
+        assert!(stream(&bob, 10).iter().all(|(_, dest, _)| dest == "lib"));
+    }
 
-        Ok(workspace)
+    #[test]
+    fn test_graph_of_merged_branch() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = git2::Repository::init(temp_dir.path()).unwrap();
+        let dev = ("Dev", "dev@example.com");
+        let base = seed_commit(&repo, dev, "base", 100, &[]);
+        let feature = seed_commit(&repo, dev, "feature work", 200, &[base]);
+        let main = seed_commit(&repo, dev, "main work", 300, &[base]);
+        seed_commit(&repo, dev, "merge feature", 400, &[main, feature]);
+
+        let entries = graph_log(&repo, &CommitFilter::default(), 10).unwrap();
+        let drawn: Vec<String> = entries
+            .iter()
+            .flat_map(|(oid, rows)| {
+                let summary = repo
+                    .find_commit(*oid)
+                    .unwrap()
+                    .summary()
+                    .unwrap()
+                    .to_string();
+                rows.before
+                    .iter()
+                    .cloned()
+                    .chain(std::iter::once(format!("{} {}", rows.commit, summary)))
+                    .chain(rows.after.iter().cloned())
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        assert_eq!(
+            drawn,
+            vec![
+                "* merge feature",
+                "|\\",
+                "* | main work",
+                "| * feature work",
+                "|/",
+                "* base",
+            ]
+        );
+        assert_eq!(entries[0].1.continuation, "| |");
+        assert_eq!(entries[3].1.continuation, "");
+
+        // Hidden commits still advance the graph, so lanes stay in place
+        let filter = CommitFilter::new(None, Some("feature work"), None, None).unwrap();
+        let entries = graph_log(&repo, &filter, 10).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].0, feature);
+        assert_eq!(entries[0].1.commit, "| *");
     }
 }
@@ -1,17 +1,25 @@
 pub mod apply_manifest;
 pub mod audit;
+pub mod checkout;
+pub mod diff;
+pub mod doctor;
 pub mod dump_manifest;
 pub mod foreach;
 pub mod init;
 pub mod log;
+pub mod push;
 pub mod status;
 pub mod sync;
 
 pub use apply_manifest::*;
 pub use audit::*;
+pub use checkout::*;
+pub use diff::*;
+pub use doctor::*;
 pub use dump_manifest::*;
 pub use foreach::*;
 pub use init::*;
 pub use log::*;
+pub use push::*;
 pub use status::*;
 pub use sync::*;
@@ -0,0 +1,201 @@
+use anyhow::Result;
+use colored::Colorize;
+
+use wmgr::application::services::workspace_loader::WorkspaceLoader;
+use wmgr::application::use_cases::push_repositories::{
+    PushRepositoriesConfig, PushRepositoriesError, PushRepositoriesUseCase, PushResult, PushStatus,
+    RepoPushResult,
+};
+use wmgr::common::error::WmgrError;
+
+use crate::presentation::ui::Verbosity;
+
+/// Handler for the push command
+pub struct PushCommand {
+    pub groups: Vec<String>,
+    pub dry_run: bool,
+    pub force: bool,
+    pub jobs: Option<usize>,
+    pub verbosity: Verbosity,
+    pub credential_profile: Option<String>,
+    pub credential_file: Option<std::path::PathBuf>,
+    pub workspace_loader: WorkspaceLoader,
+}
+
+impl PushCommand {
+    pub fn new(
+        groups: Vec<String>,
+        dry_run: bool,
+        force: bool,
+        jobs: Option<usize>,
+        verbosity: Verbosity,
+        credential_profile: Option<String>,
+        credential_file: Option<std::path::PathBuf>,
+    ) -> Self {
+        Self {
+            groups,
+            dry_run,
+            force,
+            jobs,
+            verbosity,
+            credential_profile,
+            credential_file,
+            workspace_loader: WorkspaceLoader::new(),
+        }
+    }
+
+    /// Load the workspace with `workspace_loader` instead of the default loader
+    pub fn with_workspace_loader(mut self, workspace_loader: WorkspaceLoader) -> Self {
+        self.workspace_loader = workspace_loader;
+        self
+    }
+
+    pub async fn execute(&self) -> Result<()> {
+        // Load workspace
+        let workspace = self
+            .workspace_loader
+            .load_current()
+            .await
+            .map_err(WmgrError::from)?;
+
+        // Create configuration
+        let config = PushRepositoriesConfig {
+            groups: if self.groups.is_empty() {
+                None
+            } else {
+                Some(self.groups.clone())
+            },
+            dry_run: self.dry_run,
+            force: self.force,
+            parallel_jobs: self.jobs,
+            verbose: self.verbosity.is_verbose(),
+            credential_profile: self.credential_profile.clone(),
+            credential_file: self.credential_file.clone(),
+        };
+
+        // Execute the use case
+        let use_case = PushRepositoriesUseCase::new(config);
+
+        if !self.verbosity.is_quiet() {
+            if self.dry_run {
+                println!("{} Checking repositories to push...", "::".blue().bold());
+            } else {
+                println!("{} Pushing repositories...", "::".blue().bold());
+            }
+        }
+
+        match use_case.execute(&workspace).await {
+            Ok(result) => {
+                for repo in &result.repositories {
+                    self.print_repo_result(repo);
+                }
+                self.print_summary(&result);
+
+                if result.rejected_count > 0 {
+                    println!(
+                        "{} Rejected pushes are not fast-forward: run 'wmgr sync' to integrate remote changes, or use --force to overwrite them",
+                        "hint:".yellow().bold()
+                    );
+                }
+
+                if result.is_success() {
+                    Ok(())
+                } else {
+                    Err(anyhow::anyhow!(
+                        "{} repositories failed to push",
+                        result.errors.len()
+                    ))
+                }
+            }
+            Err(PushRepositoriesError::WorkspaceNotInitialized(path)) => Err(anyhow::anyhow!(
+                "Workspace not initialized at: {}\nManifest file not found",
+                path
+            )),
+            Err(e) => Err(anyhow::anyhow!("Failed to push repositories: {}", e)),
+        }
+    }
+
+    fn print_repo_result(&self, repo: &RepoPushResult) {
+        let target = match (&repo.remote, &repo.branch) {
+            (Some(remote), Some(branch)) => format!("{} -> {}", branch, remote),
+            _ => String::new(),
+        };
+        let commits = match repo.commits {
+            Some(1) => "1 commit".to_string(),
+            Some(n) => format!("{} commits", n),
+            None => "new branch".to_string(),
+        };
+
+        match repo.status {
+            PushStatus::Pushed => {
+                if self.verbosity.is_quiet() {
+                    return;
+                }
+                println!(
+                    "{} {} {} ({})",
+                    "✓".green().bold(),
+                    repo.dest,
+                    target,
+                    commits
+                )
+            }
+            PushStatus::WouldPush => println!(
+                "{} {} {} (would push {})",
+                "→".cyan().bold(),
+                repo.dest,
+                target,
+                commits
+            ),
+            PushStatus::UpToDate => {
+                if self.verbosity.is_verbose() {
+                    println!("{} {} up to date", "✓".dimmed(), repo.dest);
+                }
+            }
+            PushStatus::Skipped => {
+                if self.verbosity.is_verbose() {
+                    println!(
+                        "{} {} skipped: {}",
+                        "-".dimmed(),
+                        repo.dest,
+                        repo.message.as_deref().unwrap_or("")
+                    );
+                }
+            }
+            PushStatus::Rejected => println!(
+                "{} {} {} rejected (non-fast-forward)",
+                "✗".red().bold(),
+                repo.dest,
+                target
+            ),
+            PushStatus::Failed => println!(
+                "{} {} {}",
+                "✗".red().bold(),
+                repo.dest,
+                repo.message.as_deref().unwrap_or("").red()
+            ),
+        }
+    }
+
+    fn print_summary(&self, result: &PushResult) {
+        if self.verbosity.is_quiet() {
+            return;
+        }
+        if self.dry_run {
+            println!(
+                "{} {} repositories would be pushed, {} up to date",
+                "::".blue().bold(),
+                result.would_push_count,
+                result.up_to_date_count
+            );
+        } else {
+            println!(
+                "{} {} pushed, {} up to date, {} rejected, {} failed",
+                "::".blue().bold(),
+                result.pushed_count,
+                result.up_to_date_count,
+                result.rejected_count,
+                result.errors.len() - result.rejected_count
+            );
+        }
+    }
+}
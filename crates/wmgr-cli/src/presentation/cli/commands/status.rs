@@ -1,33 +1,118 @@
 use anyhow::Result;
 use colored::Colorize;
-use std::env;
 
+use wmgr::application::services::workspace_loader::WorkspaceLoader;
 use wmgr::application::use_cases::status_check::{
-    RepositoryState, StatusCheckConfig, StatusCheckError, StatusCheckUseCase,
+    ChangedSince, RepositoryState, StatusCheckConfig, StatusCheckError, StatusCheckUseCase,
+    StatusSortKey,
+};
+use wmgr::common::error::WmgrError;
+
+use crate::presentation::ui::{
+    print_status_summary, untracked_breakdown_summary, StatusTable, Verbosity,
 };
-use wmgr::domain::entities::workspace::Workspace;
 
 /// Handler for the status command
 pub struct StatusCommand {
     pub groups: Vec<String>,
+    pub tags: Vec<String>,
+    pub exclude_tags: Vec<String>,
+    pub repo_patterns: Vec<String>,
     pub show_branch: bool,
     pub compact: bool,
-    pub verbose: bool,
+    pub verbosity: Verbosity,
+    pub jobs: Option<usize>,
+    pub plain: bool,
+    pub sort: StatusSortKey,
+    pub reverse: bool,
+    pub changed_since: Option<ChangedSince>,
+    pub only_changed: bool,
+    pub untracked_summary: bool,
+    pub ahead_behind_remote: Option<String>,
+    pub workspace_loader: WorkspaceLoader,
 }
 
 impl StatusCommand {
-    pub fn new(groups: Vec<String>, show_branch: bool, compact: bool, verbose: bool) -> Self {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        groups: Vec<String>,
+        show_branch: bool,
+        compact: bool,
+        verbosity: Verbosity,
+        jobs: Option<usize>,
+        plain: bool,
+        sort: StatusSortKey,
+        reverse: bool,
+    ) -> Self {
         Self {
             groups,
+            tags: Vec::new(),
+            exclude_tags: Vec::new(),
+            repo_patterns: Vec::new(),
             show_branch,
             compact,
-            verbose,
+            verbosity,
+            jobs,
+            plain,
+            sort,
+            reverse,
+            changed_since: None,
+            only_changed: false,
+            untracked_summary: false,
+            ahead_behind_remote: None,
+            workspace_loader: WorkspaceLoader::new(),
         }
     }
 
+    /// Restrict the selection to repositories with one of `tags` and none of `exclude_tags`
+    pub fn with_tags(mut self, tags: Vec<String>, exclude_tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self.exclude_tags = exclude_tags;
+        self
+    }
+
+    /// Restrict the selection to repositories whose dest matches one of `repo_patterns`
+    pub fn with_repo_patterns(mut self, repo_patterns: Vec<String>) -> Self {
+        self.repo_patterns = repo_patterns;
+        self
+    }
+
+    /// Count commits after `changed_since`, optionally hiding repositories without any
+    pub fn with_changed_since(
+        mut self,
+        changed_since: Option<ChangedSince>,
+        only_changed: bool,
+    ) -> Self {
+        self.changed_since = changed_since;
+        self.only_changed = only_changed;
+        self
+    }
+
+    /// Break down untracked files of dirty repositories by kind
+    pub fn with_untracked_summary(mut self, untracked_summary: bool) -> Self {
+        self.untracked_summary = untracked_summary;
+        self
+    }
+
+    /// Count commits ahead/behind `<remote>/<current branch>` instead of the tracking branch
+    pub fn with_ahead_behind_remote(mut self, ahead_behind_remote: Option<String>) -> Self {
+        self.ahead_behind_remote = ahead_behind_remote;
+        self
+    }
+
+    /// Load the workspace with `workspace_loader` instead of the default loader
+    pub fn with_workspace_loader(mut self, workspace_loader: WorkspaceLoader) -> Self {
+        self.workspace_loader = workspace_loader;
+        self
+    }
+
     pub async fn execute(&self) -> Result<()> {
         // Load workspace
-        let workspace = self.load_workspace().await?;
+        let workspace = self
+            .workspace_loader
+            .load_current()
+            .await
+            .map_err(WmgrError::from)?;
 
         // Prepare groups list
         let groups_list = if self.groups.is_empty() {
@@ -39,9 +124,20 @@ impl StatusCommand {
         // Create configuration
         let config = StatusCheckConfig {
             groups: groups_list,
+            tags: self.tags.clone(),
+            exclude_tags: self.exclude_tags.clone(),
+            repo_patterns: self.repo_patterns.clone(),
             show_branch: self.show_branch,
             compact: self.compact,
-            verbose: self.verbose,
+            verbose: self.verbosity.is_verbose(),
+            jobs: self.jobs,
+            sort: self.sort,
+            reverse: self.reverse,
+            changed_since: self.changed_since.clone(),
+            only_changed: self.only_changed,
+            untracked_summary: self.untracked_summary,
+            ahead_behind_remote: self.ahead_behind_remote.clone(),
+            ..Default::default()
         };
 
         // Execute the use case
@@ -51,8 +147,16 @@ impl StatusCommand {
             Ok(status) => {
                 if self.compact {
                     self.print_compact_status(&status);
-                } else {
+                } else if self.plain || !atty::is(atty::Stream::Stdout) {
                     self.print_detailed_status(&status);
+                } else {
+                    let use_color = colored::control::SHOULD_COLORIZE.should_colorize();
+                    let table = StatusTable::new(use_color, self.show_branch)
+                        .with_commits_since(self.changed_since.is_some());
+                    for line in table.render(&status.repositories) {
+                        println!("{}", line);
+                    }
+                    print_status_summary(&status);
                 }
                 Ok(())
             }
@@ -77,7 +181,10 @@ impl StatusCommand {
                 RepositoryState::OutOfSync => "S".magenta(),
                 RepositoryState::Error => "E".red(),
             };
-            println!("{} {}", state_char, repo_status.dest);
+            match repo_status.commits_since.filter(|count| *count > 0) {
+                Some(count) => println!("{} {} +{}", state_char, repo_status.dest, count),
+                None => println!("{} {}", state_char, repo_status.dest),
+            }
         }
     }
 
@@ -119,38 +226,16 @@ impl StatusCommand {
                 }
             }
 
-            println!();
-        }
-    }
-
-    /// Load workspace from the current directory
-    async fn load_workspace(&self) -> Result<Workspace> {
-        let current_dir = env::current_dir()?;
-
-        let workspace = Workspace::new(current_dir.clone(), WorkspaceConfig::default_local());
+            if let Some(count) = repo_status.commits_since.filter(|count| *count > 0) {
+                print!(" {}", format!("+{} commits", count).green());
+            }
 
-        // Use workspace.manifest_file_path() to support wmgr.yml, wmgr.yaml, manifest.yml, manifest.yaml
-        let manifest_file = workspace.manifest_file_path();
+            println!();
 
-        if !manifest_file.exists() {
-            return Err(anyhow::anyhow!("Manifest file not found. Tried wmgr.yml, wmgr.yaml, manifest.yml, manifest.yaml in current directory and .wmgr/ subdirectory"));
+            if let Some(breakdown) = untracked_breakdown_summary(repo_status) {
+                println!("  untracked: {}", breakdown);
+            }
         }
-
-        // Load manifest file
-        use wmgr::domain::entities::workspace::{WorkspaceConfig, WorkspaceStatus};
-        use wmgr::infrastructure::filesystem::manifest_store::ManifestStore;
-        let mut manifest_store = ManifestStore::new();
-        let processed_manifest = manifest_store
-            .read_manifest(&manifest_file)
-            .await
-            .map_err(|e| anyhow::anyhow!("Failed to load manifest: {}", e))?;
-
-        // Create a simple workspace configuration
-        let workspace_config = WorkspaceConfig::new(&manifest_file.display().to_string(), "main");
-
-        let workspace = Workspace::new(current_dir, workspace_config)
-            .with_status(WorkspaceStatus::Initialized)
-            .with_manifest(processed_manifest.manifest);
-        Ok(workspace)
+        print_status_summary(status);
     }
 }
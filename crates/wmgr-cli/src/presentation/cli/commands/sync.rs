@@ -1,22 +1,47 @@
 use anyhow::Result;
 use colored::Colorize;
-use std::env;
 
+use crate::presentation::ui::{confirm, ProgressRenderer, Verbosity};
+use wmgr::application::services::workspace_loader::WorkspaceLoader;
+use wmgr::application::use_cases::prune_repositories::{PruneConfig, PruneRepositoriesUseCase};
 use wmgr::application::use_cases::sync_repositories::{
-    SyncRepositoriesConfig, SyncRepositoriesError, SyncRepositoriesUseCase,
+    SyncRepositoriesConfig, SyncRepositoriesError, SyncRepositoriesUseCase, SyncResult,
 };
+use wmgr::common::cancellation::CancellationToken;
+use wmgr::common::error::WmgrError;
 use wmgr::domain::entities::workspace::Workspace;
+use wmgr::infrastructure::filesystem::workspace_lock::WorkspaceLock;
 
 /// Handler for the sync command
 pub struct SyncCommand {
     pub groups: Vec<String>,
+    pub tags: Vec<String>,
+    pub exclude_tags: Vec<String>,
+    pub repo_patterns: Vec<String>,
     pub force: bool,
     pub no_correct_branch: bool,
     pub jobs: Option<usize>,
-    pub verbose: bool,
+    pub verbosity: Verbosity,
     pub no_recursive: bool,
+    pub recurse_submodules: bool,
+    pub lfs: bool,
+    pub clone_filter: Option<String>,
+    pub reference: Option<std::path::PathBuf>,
+    pub rebase: bool,
+    pub only_changed: bool,
+    pub resume: bool,
+    pub since: Option<std::time::Duration>,
+    pub update_manifest: bool,
+    pub manifest_branch: Option<String>,
+    pub strict_manifest: bool,
+    pub hooks_fatal: bool,
+    pub prune: bool,
+    pub prune_dirty: bool,
+    pub yes: bool,
     pub credential_profile: Option<String>,
     pub credential_file: Option<std::path::PathBuf>,
+    pub workspace_loader: WorkspaceLoader,
+    pub cancellation: CancellationToken,
 }
 
 impl SyncCommand {
@@ -25,26 +50,150 @@ impl SyncCommand {
         force: bool,
         no_correct_branch: bool,
         jobs: Option<usize>,
-        verbose: bool,
+        verbosity: Verbosity,
         no_recursive: bool,
+        rebase: bool,
+        only_changed: bool,
+        resume: bool,
+        update_manifest: bool,
         credential_profile: Option<String>,
         credential_file: Option<std::path::PathBuf>,
     ) -> Self {
         Self {
             groups,
+            tags: Vec::new(),
+            exclude_tags: Vec::new(),
+            repo_patterns: Vec::new(),
             force,
             no_correct_branch,
             jobs,
-            verbose,
+            verbosity,
             no_recursive,
+            recurse_submodules: false,
+            lfs: false,
+            clone_filter: None,
+            reference: None,
+            rebase,
+            only_changed,
+            resume,
+            since: None,
+            update_manifest,
+            manifest_branch: None,
+            strict_manifest: false,
+            hooks_fatal: false,
+            prune: false,
+            prune_dirty: false,
+            yes: false,
             credential_profile,
             credential_file,
+            workspace_loader: WorkspaceLoader::new(),
+            cancellation: CancellationToken::new(),
         }
     }
 
+    /// Restrict the selection to repositories with one of `tags` and none of `exclude_tags`
+    pub fn with_tags(mut self, tags: Vec<String>, exclude_tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self.exclude_tags = exclude_tags;
+        self
+    }
+
+    /// Restrict the selection to repositories whose dest matches one of `repo_patterns`
+    pub fn with_repo_patterns(mut self, repo_patterns: Vec<String>) -> Self {
+        self.repo_patterns = repo_patterns;
+        self
+    }
+
+    /// Use the manifest of `manifest_branch` instead of the recorded manifest branch for this run
+    pub fn with_manifest_branch(mut self, manifest_branch: Option<String>) -> Self {
+        self.manifest_branch = manifest_branch;
+        self
+    }
+
+    /// Fail on warnings in the manifests read during the sync, e.g. after `--update-manifest`
+    pub fn with_strict_manifest(mut self, strict_manifest: bool) -> Self {
+        self.strict_manifest = strict_manifest;
+        self
+    }
+
+    /// Skip git repositories fetched within `since`
+    pub fn with_since(mut self, since: Option<std::time::Duration>) -> Self {
+        self.since = since;
+        self
+    }
+
+    /// Fail the sync when a repository or workspace hook fails
+    pub fn with_hooks_fatal(mut self, hooks_fatal: bool) -> Self {
+        self.hooks_fatal = hooks_fatal;
+        self
+    }
+
+    /// Initialize and update git submodules of every git repository
+    pub fn with_recurse_submodules(mut self, recurse_submodules: bool) -> Self {
+        self.recurse_submodules = recurse_submodules;
+        self
+    }
+
+    /// Pull Git LFS files in repositories that use Git LFS
+    pub fn with_lfs(mut self, lfs: bool) -> Self {
+        self.lfs = lfs;
+        self
+    }
+
+    /// Use a partial clone filter such as `blob:none` for new git clones
+    pub fn with_clone_filter(mut self, clone_filter: Option<String>) -> Self {
+        self.clone_filter = clone_filter;
+        self
+    }
+
+    /// Borrow objects for new git clones from bare mirrors under this directory
+    pub fn with_reference(mut self, reference: Option<std::path::PathBuf>) -> Self {
+        self.reference = reference;
+        self
+    }
+
+    /// Remove repository directories that are no longer in the manifest after syncing
+    pub fn with_prune(mut self, prune: bool) -> Self {
+        self.prune = prune;
+        self
+    }
+
+    /// Also prune repositories with uncommitted changes, unpushed commits or stashes
+    ///
+    /// Removing them is always confirmed on a terminal; `--yes` does not apply.
+    pub fn with_prune_dirty(mut self, prune_dirty: bool) -> Self {
+        self.prune_dirty = prune_dirty;
+        self
+    }
+
+    /// Skip the confirmation prompt before pruning repositories without local work
+    pub fn with_yes(mut self, yes: bool) -> Self {
+        self.yes = yes;
+        self
+    }
+
+    /// Load the workspace with `workspace_loader` instead of the default loader
+    pub fn with_workspace_loader(mut self, workspace_loader: WorkspaceLoader) -> Self {
+        self.workspace_loader = workspace_loader;
+        self
+    }
+
+    /// Stop the sync cleanly when `cancellation` is cancelled (e.g. on Ctrl-C)
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> Self {
+        self.cancellation = cancellation;
+        self
+    }
+
     pub async fn execute(&self) -> Result<()> {
         // Load workspace
-        let mut workspace = self.load_workspace().await?;
+        let mut workspace = self
+            .workspace_loader
+            .load_current()
+            .await
+            .map_err(WmgrError::from)?;
+
+        // Prevent concurrent mutating commands on the same workspace
+        let _lock = WorkspaceLock::acquire(&workspace.wmgr_dir()).map_err(WmgrError::from)?;
 
         // Prepare groups list
         let groups_list = if self.groups.is_empty() {
@@ -56,11 +205,26 @@ impl SyncCommand {
         // Create configuration
         let config = SyncRepositoriesConfig {
             groups: groups_list,
+            tags: self.tags.clone(),
+            exclude_tags: self.exclude_tags.clone(),
+            repo_patterns: self.repo_patterns.clone(),
             force: self.force,
             no_correct_branch: self.no_correct_branch,
             parallel_jobs: self.jobs,
-            verbose: self.verbose,
+            verbose: self.verbosity.is_verbose(),
             recursive: !self.no_recursive,
+            recurse_submodules: self.recurse_submodules,
+            lfs: self.lfs,
+            clone_filter: self.clone_filter.clone(),
+            reference: self.reference.clone(),
+            rebase: self.rebase,
+            only_changed: self.only_changed,
+            resume: self.resume,
+            since: self.since,
+            update_manifest: self.update_manifest,
+            manifest_branch: self.manifest_branch.clone(),
+            strict_manifest: self.strict_manifest,
+            hooks_fatal: self.hooks_fatal,
             credential_profile: self.credential_profile.clone(),
             credential_file: self.credential_file.clone(),
         };
@@ -68,71 +232,231 @@ impl SyncCommand {
         // Execute the use case
         let use_case = SyncRepositoriesUseCase::new(config);
 
-        println!("{} Synchronizing repositories...", "::".blue().bold());
+        if !self.verbosity.is_quiet() {
+            println!("{} Synchronizing repositories...", "::".blue().bold());
+        }
 
-        match use_case.execute(&mut workspace).await {
+        match execute_with_progress(
+            use_case,
+            &mut workspace,
+            self.verbosity,
+            self.cancellation.clone(),
+        )
+        .await
+        {
+            Ok(result) if result.is_interrupted() => Err(report_interruption(&result)),
             Ok(result) => {
-                println!("{} Synchronization completed!", "✓".green().bold());
-                if self.verbose {
+                if !self.verbosity.is_quiet() {
+                    println!("{} Synchronization completed!", "✓".green().bold());
+                }
+                if self.verbosity.is_verbose() {
                     println!("  Repositories synced: {}", result.synced_count);
                     println!("  New repositories cloned: {}", result.cloned_count);
                     println!("  Repositories updated: {}", result.updated_count);
+                    if result.recovered_count > 0 {
+                        println!(
+                            "  Incomplete clones cloned again: {}",
+                            result.recovered_count
+                        );
+                    }
+                    if result.up_to_date_count > 0 {
+                        println!("  Repositories up to date: {}", result.up_to_date_count);
+                    }
+                    if result.resumed_count > 0 {
+                        println!(
+                            "  Repositories completed by the previous sync: {}",
+                            result.resumed_count
+                        );
+                    }
+                    if result.recent_count > 0 {
+                        println!("  Repositories fetched recently: {}", result.recent_count);
+                    }
                     if result.skipped_count > 0 {
                         println!("  Repositories skipped: {}", result.skipped_count);
                     }
                 }
 
-                // Show any errors
+                if !self.verbosity.is_quiet() {
+                    for warning in &result.warnings {
+                        eprintln!("{} {}", "⚠".yellow().bold(), warning.yellow());
+                    }
+                }
+
+                // Show any errors (even with --quiet, on stderr)
                 if !result.errors.is_empty() {
-                    println!("{} Some errors occurred:", "⚠".yellow().bold());
-                    for error in result.errors {
-                        println!("  {}", error.red());
+                    eprintln!("{} Some errors occurred:", "⚠".yellow().bold());
+                    for error in &result.errors {
+                        eprintln!("  {}", error.red());
                     }
+                    return Err(WmgrError::SyncIncomplete {
+                        failed: result.errors.len(),
+                    }
+                    .into());
+                }
+
+                if self.prune {
+                    prune_orphaned_repositories(
+                        &workspace,
+                        self.prune_dirty,
+                        self.yes,
+                        self.verbosity,
+                    )?;
                 }
 
                 Ok(())
             }
-            Err(SyncRepositoriesError::WorkspaceNotInitialized(path)) => Err(anyhow::anyhow!(
-                "Workspace not initialized at: {}\nManifest file not found",
-                path
-            )),
-            Err(e) => Err(anyhow::anyhow!("Failed to synchronize repositories: {}", e)),
+            Err(e) => Err(WmgrError::from(e).into()),
         }
     }
+}
 
-    /// Load workspace from the current directory or any parent directory
-    async fn load_workspace(&self) -> Result<Workspace> {
-        let current_dir = env::current_dir()?;
+/// Run a sync, rendering the use case's progress events with [`ProgressRenderer`]
+///
+/// Cancelling `cancellation` stops the sync after cleaning up the repository in
+/// progress; the result then lists the repositories that were not synced.
+pub(crate) async fn execute_with_progress(
+    use_case: SyncRepositoriesUseCase,
+    workspace: &mut Workspace,
+    verbosity: Verbosity,
+    cancellation: CancellationToken,
+) -> Result<SyncResult, SyncRepositoriesError> {
+    let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+    let printer = tokio::spawn(ProgressRenderer::for_stdout(verbosity).run(receiver));
 
-        // Discover workspace root by searching upward for manifest files
-        let workspace_root = if let Some(root) = Workspace::discover_workspace_root(&current_dir) {
-            root
-        } else {
-            return Err(anyhow::anyhow!(
-                "No wmgr workspace found. Searched upward from {} for wmgr.yml, wmgr.yaml, manifest.yml, or manifest.yaml files.",
-                current_dir.display()
-            ));
-        };
+    let use_case = use_case
+        .with_progress(sender)
+        .with_cancellation(cancellation);
+    let result = use_case.execute(workspace).await;
 
-        // Create workspace and find manifest file
-        let workspace = Workspace::new(workspace_root.clone(), WorkspaceConfig::default_local());
-        let manifest_file = workspace.manifest_file_path();
+    // Dropping the use case closes the channel, so the printer drains and stops
+    drop(use_case);
+    let _ = printer.await;
+    result
+}
 
-        // Load manifest file
-        use wmgr::domain::entities::workspace::{WorkspaceConfig, WorkspaceStatus};
-        use wmgr::infrastructure::filesystem::manifest_store::ManifestStore;
-        let mut manifest_store = ManifestStore::new();
-        let processed_manifest = manifest_store
-            .read_manifest(&manifest_file)
-            .await
-            .map_err(|e| anyhow::anyhow!("Failed to load manifest: {}", e))?;
+/// Print what an interrupted sync left undone and return the error to exit with
+///
+/// The interruption is reported even with `--quiet`, since the workspace is
+/// only partly synchronized.
+pub(crate) fn report_interruption(result: &SyncResult) -> anyhow::Error {
+    for warning in &result.warnings {
+        eprintln!("{} {}", "⚠".yellow().bold(), warning.yellow());
+    }
+    eprintln!(
+        "{} Synchronization interrupted; {} repositories were not synced:",
+        "⚠".yellow().bold(),
+        result.interrupted.len()
+    );
+    for dest in &result.interrupted {
+        eprintln!("  {}", dest);
+    }
+    eprintln!("Run 'wmgr sync --continue' to sync the remaining repositories");
+    WmgrError::Cancelled.into()
+}
 
-        // Create a workspace configuration
-        let workspace_config = WorkspaceConfig::new(&manifest_file.display().to_string(), "main");
+/// Remove repository directories that wmgr synced before but the manifest no longer lists
+///
+/// Only destinations recorded by earlier syncs are considered, so clones the user
+/// placed in the workspace are never touched. Repositories with local work
+/// (uncommitted changes, unpushed commits or stashes) are kept unless
+/// `remove_dirty` is set, and removing them must be confirmed on a terminal even
+/// with `--yes`. Removing the others is confirmed too; without a terminal that
+/// requires `--yes`.
+pub(crate) fn prune_orphaned_repositories(
+    workspace: &Workspace,
+    remove_dirty: bool,
+    assume_yes: bool,
+    verbosity: Verbosity,
+) -> Result<()> {
+    let use_case = PruneRepositoriesUseCase::new(
+        PruneConfig::default()
+            .with_remove_dirty(remove_dirty)
+            .with_verbose(verbosity.is_verbose()),
+    );
+    let orphans = use_case.find_orphans(workspace).map_err(WmgrError::from)?;
+    if orphans.is_empty() {
+        if verbosity.is_verbose() {
+            println!("No repositories to prune");
+        }
+        return Ok(());
+    }
+
+    // The confirmation prompt names the directories, so --quiet skips the listing
+    if !verbosity.is_quiet() {
+        println!(
+            "{} Repositories no longer in the manifest:",
+            "::".blue().bold()
+        );
+        for orphan in &orphans {
+            if orphan.is_clean {
+                println!("  {} {}", "-".red(), orphan.dest);
+            } else if remove_dirty {
+                println!(
+                    "  {} {} {}",
+                    "-".red(),
+                    orphan.dest,
+                    "(local work will be lost)".red()
+                );
+            } else {
+                println!(
+                    "  {} {} {}",
+                    "~".yellow(),
+                    orphan.dest,
+                    "(uncommitted changes, unpushed commits or stashes; kept, use --prune-dirty to remove)"
+                        .yellow()
+                );
+            }
+        }
+    }
+
+    let dirty: Vec<&str> = orphans
+        .iter()
+        .filter(|orphan| !orphan.is_clean && remove_dirty)
+        .map(|orphan| orphan.dest.as_str())
+        .collect();
+    if !dirty.is_empty() {
+        // Local work would be lost for good, so --yes does not answer this prompt
+        let prompt = format!("Remove {} and the local work in them?", dirty.join(", "));
+        if !confirm(&prompt, false)? {
+            return Err(anyhow::anyhow!(
+                "Prune of repositories with local work not confirmed. It must be confirmed on a terminal; --yes does not apply"
+            ));
+        }
+    }
 
-        let workspace = Workspace::new(workspace_root, workspace_config)
-            .with_status(WorkspaceStatus::Initialized)
-            .with_manifest(processed_manifest.manifest);
-        Ok(workspace)
+    let clean: Vec<&str> = orphans
+        .iter()
+        .filter(|orphan| orphan.is_clean)
+        .map(|orphan| orphan.dest.as_str())
+        .collect();
+    if !clean.is_empty() {
+        let prompt = format!("Remove {}?", clean.join(", "));
+        if !confirm(&prompt, assume_yes)? {
+            return Err(anyhow::anyhow!(
+                "Prune not confirmed. Use --yes to remove the directories without a prompt"
+            ));
+        }
+    }
+
+    let result = use_case
+        .prune(workspace, &orphans)
+        .map_err(WmgrError::from)?;
+    if !result.removed.is_empty() && !verbosity.is_quiet() {
+        println!(
+            "{} Pruned {} repositories",
+            "✓".green().bold(),
+            result.removed.len()
+        );
+    }
+    if !result.errors.is_empty() {
+        for error in &result.errors {
+            println!("  {}", error.red());
+        }
+        return Err(WmgrError::filesystem_error(
+            format!("Failed to prune {} repositories", result.errors.len()),
+            Some(workspace.root_path.clone()),
+        )
+        .into());
     }
+    Ok(())
 }
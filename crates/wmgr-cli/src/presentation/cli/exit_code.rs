@@ -0,0 +1,95 @@
+use wmgr::common::error::WmgrError;
+
+/// Any failure without a more specific code
+///
+/// Code 2 is left to clap, which exits with it on invalid command-line usage.
+pub const GENERIC_FAILURE: i32 = 1;
+
+/// No wmgr workspace was found in the current directory or its parents
+pub const WORKSPACE_NOT_INITIALIZED: i32 = 3;
+
+/// The manifest could not be read, parsed or validated
+pub const MANIFEST_ERROR: i32 = 4;
+
+/// Sync finished, but some repositories failed to synchronize
+pub const PARTIAL_SYNC_FAILURE: i32 = 5;
+
+/// Another wmgr process holds the workspace lock
+pub const WORKSPACE_LOCKED: i32 = 6;
+
+/// foreach finished, but the command failed in some repositories
+pub const PARTIAL_FOREACH_FAILURE: i32 = 7;
+
+/// The command was interrupted with Ctrl-C (128 + SIGINT, as shells report it)
+pub const INTERRUPTED: i32 = 130;
+
+/// Exit code for an error returned by a command handler
+///
+/// Typed [`WmgrError`]s map to their category; anything else is a generic failure.
+pub fn for_error(error: &anyhow::Error) -> i32 {
+    match error.downcast_ref::<WmgrError>() {
+        Some(WmgrError::WorkspaceNotInitialized { .. }) => WORKSPACE_NOT_INITIALIZED,
+        Some(WmgrError::ManifestError { .. }) => MANIFEST_ERROR,
+        Some(WmgrError::SyncIncomplete { .. }) => PARTIAL_SYNC_FAILURE,
+        Some(WmgrError::WorkspaceLocked { .. }) => WORKSPACE_LOCKED,
+        Some(WmgrError::ForeachIncomplete { .. }) => PARTIAL_FOREACH_FAILURE,
+        Some(WmgrError::Cancelled) => INTERRUPTED,
+        _ => GENERIC_FAILURE,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use wmgr::application::use_cases::status_check::StatusCheckError;
+    use wmgr::infrastructure::filesystem::manifest_store::ManifestStoreError;
+
+    #[test]
+    fn test_exit_code_per_category() {
+        let not_initialized: anyhow::Error =
+            WmgrError::from(StatusCheckError::WorkspaceNotInitialized("/ws".to_string())).into();
+        assert_eq!(for_error(&not_initialized), WORKSPACE_NOT_INITIALIZED);
+
+        let manifest: anyhow::Error =
+            WmgrError::from(ManifestStoreError::YamlParsingFailed("bad".to_string())).into();
+        assert_eq!(for_error(&manifest), MANIFEST_ERROR);
+
+        let partial: anyhow::Error = WmgrError::SyncIncomplete { failed: 2 }.into();
+        assert_eq!(for_error(&partial), PARTIAL_SYNC_FAILURE);
+
+        let locked: anyhow::Error = WmgrError::WorkspaceLocked {
+            message: "locked".to_string(),
+            lock_path: PathBuf::from(".wmgr/lock"),
+        }
+        .into();
+        assert_eq!(for_error(&locked), WORKSPACE_LOCKED);
+
+        let foreach: anyhow::Error = WmgrError::ForeachIncomplete { failed: 1 }.into();
+        assert_eq!(for_error(&foreach), PARTIAL_FOREACH_FAILURE);
+
+        let interrupted: anyhow::Error = WmgrError::Cancelled.into();
+        assert_eq!(for_error(&interrupted), INTERRUPTED);
+
+        let repository: anyhow::Error = WmgrError::repository_error("failed", None).into();
+        assert_eq!(for_error(&repository), GENERIC_FAILURE);
+        assert_eq!(for_error(&anyhow::anyhow!("untyped")), GENERIC_FAILURE);
+    }
+
+    #[test]
+    fn test_codes_are_distinct() {
+        let mut codes = vec![
+            GENERIC_FAILURE,
+            2,
+            WORKSPACE_NOT_INITIALIZED,
+            MANIFEST_ERROR,
+            PARTIAL_SYNC_FAILURE,
+            WORKSPACE_LOCKED,
+            PARTIAL_FOREACH_FAILURE,
+            INTERRUPTED,
+        ];
+        codes.sort();
+        codes.dedup();
+        assert_eq!(codes.len(), 8);
+    }
+}
@@ -0,0 +1,35 @@
+use super::LogFormat;
+use crate::presentation::ui::Verbosity;
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::EnvFilter;
+
+/// Install the global tracing subscriber.
+///
+/// Logs go to stderr so they never mix with command output on stdout. `RUST_LOG`
+/// takes precedence; otherwise `--verbose` enables wmgr's info-level progress logs
+/// and `--quiet` limits logging to errors.
+/// Closing a per-repository span logs its `elapsed_ms` field.
+pub fn init(format: LogFormat, verbosity: Verbosity) {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| {
+        EnvFilter::new(match verbosity {
+            Verbosity::Quiet => "error",
+            Verbosity::Normal => "warn",
+            Verbosity::Verbose => "warn,wmgr=info",
+        })
+    });
+
+    let builder = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .with_span_events(FmtSpan::CLOSE);
+
+    // A second initialisation (e.g. in tests) is not an error worth reporting
+    let _ = match format {
+        LogFormat::Text => builder.with_target(false).try_init(),
+        LogFormat::Json => builder
+            .json()
+            .with_current_span(true)
+            .with_span_list(true)
+            .try_init(),
+    };
+}
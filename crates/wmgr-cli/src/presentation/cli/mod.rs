@@ -1,20 +1,36 @@
 pub mod commands;
+pub mod exit_code;
+pub mod logging;
 
 use anyhow::Result;
 use clap::{Parser, Subcommand, ValueEnum};
 use colored::Colorize;
+use std::collections::HashMap;
 use std::env;
+use std::path::{Path, PathBuf};
 use std::process::exit;
+use std::time::Duration;
 
+use wmgr::application::services::workspace_loader::WorkspaceLoader;
 use wmgr::application::use_cases::{
-    foreach_command::{ForeachCommandConfig, ForeachCommandError, ForeachCommandUseCase},
-    status_check::{StatusCheckConfig, StatusCheckError, StatusCheckUseCase},
-    sync_repositories::{SyncRepositoriesConfig, SyncRepositoriesError, SyncRepositoriesUseCase},
+    foreach_command::{ForeachCommandConfig, ForeachCommandUseCase},
+    status_check::{
+        ChangedSince, RepositoryState, StatusCheckConfig, StatusCheckUseCase, StatusSortKey,
+    },
+    sync_repositories::{SyncRepositoriesConfig, SyncRepositoriesUseCase},
 };
 
-use wmgr::domain::entities::workspace::Workspace;
+use wmgr::common::cancellation::CancellationToken;
+use wmgr::common::error::WmgrError;
+
+use wmgr::domain::value_objects::{branch_name::BranchName, file_path::FilePath, git_url::GitUrl};
 
-use wmgr::domain::value_objects::{file_path::FilePath, git_url::GitUrl};
+use wmgr::infrastructure::process::load_dotenv;
+
+use crate::presentation::cli::commands::audit::AuditOutputFormat;
+use crate::presentation::ui::{
+    print_status_summary, untracked_breakdown_summary, ProgressRenderer, Verbosity,
+};
 
 /// Output format options for status command
 #[derive(Debug, Clone, ValueEnum)]
@@ -27,6 +43,170 @@ pub enum OutputFormat {
     Yaml,
 }
 
+/// Log output format
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum LogFormat {
+    /// Human-readable log lines (default)
+    Text,
+    /// One JSON object per line, including the per-repository span fields
+    Json,
+}
+
+/// Sort keys for status output
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum StatusSort {
+    /// Sort by repository path (default)
+    Dest,
+    /// Sort by state, most severe first
+    State,
+    /// Sort by current branch name
+    Branch,
+}
+
+impl From<StatusSort> for StatusSortKey {
+    fn from(sort: StatusSort) -> Self {
+        match sort {
+            StatusSort::Dest => StatusSortKey::Dest,
+            StatusSort::State => StatusSortKey::State,
+            StatusSort::Branch => StatusSortKey::Branch,
+        }
+    }
+}
+
+/// Repository states accepted by `foreach --on`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum StatusState {
+    /// No local changes
+    Clean,
+    /// Uncommitted changes
+    Dirty,
+    /// Not cloned
+    Missing,
+    /// On a different branch than the manifest expects
+    WrongBranch,
+    /// Ahead of or behind its remote
+    OutOfSync,
+    /// The status could not be determined
+    Error,
+}
+
+impl From<StatusState> for RepositoryState {
+    fn from(state: StatusState) -> Self {
+        match state {
+            StatusState::Clean => RepositoryState::Clean,
+            StatusState::Dirty => RepositoryState::Dirty,
+            StatusState::Missing => RepositoryState::Missing,
+            StatusState::WrongBranch => RepositoryState::WrongBranch,
+            StatusState::OutOfSync => RepositoryState::OutOfSync,
+            StatusState::Error => RepositoryState::Error,
+        }
+    }
+}
+
+/// Parse a `--jobs` value, rejecting anything that is not a non-negative integer
+fn parse_jobs(value: &str) -> Result<usize, String> {
+    value.trim().parse::<usize>().map_err(|_| {
+        format!(
+            "invalid job count '{}': expected a non-negative integer (0 means unbounded)",
+            value
+        )
+    })
+}
+
+/// Parse a branch name, rejecting names git does not allow
+fn parse_branch_name(value: &str) -> Result<String, String> {
+    BranchName::new(value)
+        .map(|branch| branch.to_string())
+        .map_err(|e| e.to_string())
+}
+
+/// Parse an `--env` value of the form `KEY=VALUE`
+fn parse_env_var(value: &str) -> Result<(String, String), String> {
+    match value.split_once('=') {
+        Some((key, val)) if !key.is_empty() => Ok((key.to_string(), val.to_string())),
+        _ => Err(format!(
+            "invalid environment variable '{}': expected KEY=VALUE",
+            value
+        )),
+    }
+}
+
+/// Parse a `--timeout-for` value of the form `DEST=SECONDS`
+fn parse_repo_timeout(value: &str) -> Result<(String, u64), String> {
+    let invalid = || {
+        format!(
+            "invalid repository timeout '{}': expected DEST=SECONDS",
+            value
+        )
+    };
+    let (dest, seconds) = value.rsplit_once('=').ok_or_else(invalid)?;
+    if dest.is_empty() {
+        return Err(invalid());
+    }
+    let seconds = seconds.trim().parse::<u64>().map_err(|_| invalid())?;
+    Ok((dest.to_string(), seconds))
+}
+
+/// Parse a `--since` duration: a number followed by `s`, `m`, `h` or `d`
+fn parse_since(value: &str) -> Result<Duration, String> {
+    let invalid = || {
+        format!(
+            "invalid duration '{}': expected a number followed by s, m, h or d (e.g. 30m)",
+            value
+        )
+    };
+    let value = value.trim();
+    let unit = value.chars().last().ok_or_else(invalid)?;
+    let seconds = match unit {
+        's' => 1,
+        'm' => 60,
+        'h' => 60 * 60,
+        'd' => 24 * 60 * 60,
+        _ => return Err(invalid()),
+    };
+    let count = value[..value.len() - 1]
+        .parse::<u64>()
+        .map_err(|_| invalid())?;
+    count
+        .checked_mul(seconds)
+        .map(Duration::from_secs)
+        .ok_or_else(invalid)
+}
+
+/// Parse a `--changed-since` value: a date when it looks like one, otherwise a revision
+fn parse_changed_since(value: &str) -> Result<ChangedSince, String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err("expected a revision (tag, branch, commit) or a date".to_string());
+    }
+    Ok(
+        match crate::presentation::cli::commands::log::parse_date("changed-since", value, false) {
+            Ok(timestamp) => ChangedSince::Date(timestamp),
+            Err(_) => ChangedSince::Revision(value.to_string()),
+        },
+    )
+}
+
+/// Environment for foreach commands: `--env-file` entries overridden by `--env` flags
+fn foreach_environment(
+    env_file: Option<&Path>,
+    env: &[(String, String)],
+) -> Result<HashMap<String, String>, WmgrError> {
+    let mut environment = HashMap::new();
+    if let Some(path) = env_file {
+        environment.extend(load_dotenv(path)?);
+    }
+    environment.extend(env.iter().cloned());
+    Ok(environment)
+}
+
+/// Actionable hint for an error raised by a command handler
+///
+/// Only typed [`WmgrError`]s carry hints; plain `anyhow` messages have none.
+fn error_hint(error: &anyhow::Error) -> Option<&'static str> {
+    error.downcast_ref::<WmgrError>().and_then(WmgrError::hint)
+}
+
 /// wmgr - A tool for managing multiple git repositories
 #[derive(Parser)]
 #[command(name = "wmgr")]
@@ -39,17 +219,29 @@ pub struct Cli {
     pub version: (),
 
     /// Enable verbose output
-    #[arg(long, global = true)]
+    #[arg(long, global = true, conflicts_with = "quiet")]
     pub verbose: bool,
 
+    /// Only print errors and requested output (status, dumps, logs)
+    #[arg(short = 'q', long, global = true)]
+    pub quiet: bool,
+
     /// Disable colored output
     #[arg(long, global = true)]
     pub no_color: bool,
 
+    /// Format of log output on stderr
+    #[arg(long, global = true, value_enum, default_value = "text")]
+    pub log_format: LogFormat,
+
     /// Working directory (defaults to current directory)
     #[arg(short = 'C', long, global = true)]
     pub directory: Option<String>,
 
+    /// Workspace config file to read instead of .wmgr/config.yml
+    #[arg(long, global = true, value_name = "PATH")]
+    pub config: Option<std::path::PathBuf>,
+
     /// Credential profile name (overrides WMGR_PROFILE and per-repo profile settings)
     #[arg(long, global = true, env = "WMGR_PROFILE")]
     pub profile: Option<String>,
@@ -58,10 +250,45 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub credential_file: Option<std::path::PathBuf>,
 
+    /// Don't create .bak_* backups of files before overwriting or deleting them
+    #[arg(long, global = true)]
+    pub no_backup: bool,
+
+    /// Answer yes to confirmation prompts for destructive operations (required without a TTY)
+    #[arg(short = 'y', long, global = true)]
+    pub yes: bool,
+
+    /// Fail on manifest warnings, e.g. destinations that differ only in letter case
+    #[arg(long, global = true)]
+    pub strict_manifest: bool,
+
     #[command(subcommand)]
     pub command: Commands,
 }
 
+impl Cli {
+    /// Output level selected by `--quiet` / `--verbose`
+    pub fn verbosity(&self) -> Verbosity {
+        Verbosity::from_flags(self.quiet, self.verbose)
+    }
+
+    /// Workspace loader honoring `--config` and `--strict-manifest`
+    pub fn workspace_loader(&self) -> WorkspaceLoader {
+        WorkspaceLoader::new()
+            .with_config_path(self.config.clone())
+            .with_strict(self.strict_manifest)
+    }
+}
+
+impl Commands {
+    /// Whether the command stops by itself when Ctrl-C is pressed
+    ///
+    /// Other commands are abandoned on Ctrl-C instead.
+    fn handles_cancellation(&self) -> bool {
+        matches!(self, Commands::Sync { .. })
+    }
+}
+
 #[derive(Subcommand)]
 pub enum Commands {
     /// Initialize a new wmgr workspace
@@ -77,14 +304,38 @@ pub enum Commands {
         /// Use manifest.yaml instead of wmgr.yaml
         #[arg(long)]
         manifest: bool,
+
+        /// Generate the manifest from the git repositories already checked out under the directory
+        #[arg(long)]
+        from_existing: bool,
+
+        /// With --from-existing, add a group for each top-level directory holding repositories
+        #[arg(long, requires = "from_existing")]
+        group_by_dir: bool,
     },
 
     /// Synchronize repositories
     Sync {
-        /// Groups to sync (if not specified, all groups will be synced)
+        /// Groups to sync (defaults to the groups configured for the workspace)
         #[arg(short, long)]
         group: Vec<String>,
 
+        /// Only include repositories with one of these tags (combined with --group)
+        #[arg(long = "tag", value_name = "TAG")]
+        tag: Vec<String>,
+
+        /// Exclude repositories with any of these tags
+        #[arg(long = "exclude-tag", value_name = "TAG")]
+        exclude_tag: Vec<String>,
+
+        /// Only include repositories whose dest matches one of these glob patterns
+        #[arg(long = "repo", value_name = "GLOB")]
+        repo: Vec<String>,
+
+        /// Pick the repositories from a checklist when neither --group nor --repo is given
+        #[arg(short = 'i', long)]
+        interactive: bool,
+
         /// Force sync, ignoring local changes
         #[arg(short, long)]
         force: bool,
@@ -93,13 +344,106 @@ pub enum Commands {
         #[arg(long)]
         no_correct_branch: bool,
 
-        /// Number of parallel jobs
-        #[arg(short, long)]
+        /// Number of parallel jobs (accepted for compatibility; repositories are synced one at a time)
+        #[arg(short, long, value_parser = parse_jobs)]
         jobs: Option<usize>,
 
         /// Disable recursive sync of child workspaces
         #[arg(long)]
         no_recursive: bool,
+
+        /// Initialize and update git submodules recursively (per-repo `submodules` takes precedence)
+        #[arg(long)]
+        recurse_submodules: bool,
+
+        /// Pull Git LFS files in repositories that use Git LFS (per-repo `lfs` takes precedence)
+        #[arg(long)]
+        lfs: bool,
+
+        /// Partial clone filter for new git clones, e.g. blob:none (per-repo `partial_clone` takes precedence)
+        #[arg(long, value_name = "SPEC")]
+        filter: Option<String>,
+
+        /// Share objects with local mirrors at <DIR>/<repo path>.git when cloning (per-repo `reference` takes precedence)
+        #[arg(long, value_name = "DIR")]
+        reference: Option<PathBuf>,
+
+        /// Rebase local commits onto the upstream when the branch has diverged
+        #[arg(long)]
+        rebase: bool,
+
+        /// Skip repositories whose local tip already matches the remote tip
+        #[arg(long)]
+        only_changed: bool,
+
+        /// Resume a failed sync, skipping repositories it already completed
+        #[arg(long = "continue")]
+        resume: bool,
+
+        /// Skip git repositories fetched within this duration, e.g. 30m, 2h or 1d
+        #[arg(long, value_name = "DURATION", value_parser = parse_since)]
+        since: Option<Duration>,
+
+        /// Fetch and fast-forward the manifest repository before reloading the manifest
+        #[arg(long)]
+        update_manifest: bool,
+
+        /// Fetch the manifest repository and use the manifest of this branch for this run only
+        #[arg(long, value_name = "BRANCH", value_parser = parse_branch_name)]
+        manifest_branch: Option<String>,
+
+        /// Fail the sync when a repository or workspace hook fails
+        #[arg(long)]
+        hooks_fatal: bool,
+
+        /// Remove repositories synced before that are no longer in the manifest (asks first)
+        #[arg(long)]
+        prune: bool,
+
+        /// With --prune, also remove repositories with uncommitted changes, unpushed commits or stashes (asks on a terminal even with --yes)
+        #[arg(long, requires = "prune")]
+        prune_dirty: bool,
+
+        /// Clone and fetch every git repository from this mirror base URL instead of its manifest URL
+        #[arg(long, value_name = "URL")]
+        singular_remote: Option<String>,
+    },
+
+    /// Push the current branch of each repository to its upstream
+    Push {
+        /// Groups to push (defaults to the groups configured for the workspace)
+        #[arg(short, long)]
+        group: Vec<String>,
+
+        /// Show which repositories would be pushed without pushing
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Force push, overwriting remote history
+        #[arg(short, long)]
+        force: bool,
+
+        /// Number of parallel jobs (0 = unbounded, default: number of CPUs)
+        #[arg(short, long, value_parser = parse_jobs)]
+        jobs: Option<usize>,
+    },
+
+    /// Check out a branch in each repository
+    Checkout {
+        /// Branch to check out
+        branch: String,
+
+        /// Groups to check out (defaults to the groups configured for the workspace)
+        #[arg(short, long)]
+        group: Vec<String>,
+
+        /// Create the branch from the current HEAD in repositories that do not have it
+        #[arg(short, long)]
+        create: bool,
+
+        /// Check out even in repositories with uncommitted changes
+        #[arg(short, long)]
+        force: bool,
     },
 
     /// Show repository status
@@ -112,43 +456,212 @@ pub enum Commands {
         #[arg(short, long)]
         compact: bool,
 
-        /// Groups to check (if not specified, all groups will be checked)
+        /// Groups to check (defaults to the groups configured for the workspace)
         #[arg(short, long)]
         group: Vec<String>,
 
+        /// Only include repositories with one of these tags (combined with --group)
+        #[arg(long = "tag", value_name = "TAG")]
+        tag: Vec<String>,
+
+        /// Exclude repositories with any of these tags
+        #[arg(long = "exclude-tag", value_name = "TAG")]
+        exclude_tag: Vec<String>,
+
+        /// Only include repositories whose dest matches one of these glob patterns
+        #[arg(long = "repo", value_name = "GLOB")]
+        repo: Vec<String>,
+
+        /// Pick the repositories from a checklist when neither --group nor --repo is given
+        #[arg(short = 'i', long)]
+        interactive: bool,
+
         /// Output format (text, json, yaml)
         #[arg(short, long, value_enum, default_value = "text")]
         output: OutputFormat,
+
+        /// Number of parallel jobs (0 = unbounded, default: number of CPUs)
+        #[arg(short, long, value_parser = parse_jobs)]
+        jobs: Option<usize>,
+
+        /// Print one line per repository instead of an aligned table
+        #[arg(long)]
+        plain: bool,
+
+        /// Sort repositories by the given key
+        #[arg(long, value_enum, default_value = "dest")]
+        sort: StatusSort,
+
+        /// Reverse the sort order
+        #[arg(long)]
+        reverse: bool,
+
+        /// Count commits after a revision (tag, branch, commit) or date in each git repository
+        #[arg(long, value_name = "REF|DATE", value_parser = parse_changed_since)]
+        changed_since: Option<ChangedSince>,
+
+        /// Only show repositories with commits after --changed-since
+        #[arg(long, requires = "changed_since")]
+        only_changed: bool,
+
+        /// Break down untracked files of dirty repositories by top-level directory or extension
+        #[arg(long)]
+        untracked_summary: bool,
+
+        /// Count commits ahead/behind <REMOTE>/<current branch> instead of the tracking branch
+        #[arg(long, value_name = "REMOTE")]
+        ahead_behind_remote: Option<String>,
+
+        /// Add last fetch time, estimated disk size and local branch count (json/yaml output)
+        #[arg(long)]
+        extended: bool,
+
+        /// Fetch from the remotes first so ahead/behind counts are current (working trees are not touched)
+        #[arg(long)]
+        fetch: bool,
     },
 
     /// Run a command in each repository
     Foreach {
-        /// Command to run
+        /// Command to run (the git subcommand with --git)
         command: String,
 
         /// Arguments for the command
+        #[arg(allow_hyphen_values = true)]
         args: Vec<String>,
 
-        /// Groups to run command in (if not specified, all groups will be used)
+        /// Run a git subcommand, skipping repositories that are not git
+        #[arg(long)]
+        git: bool,
+
+        /// Run the command in this subdirectory of each repository, skipping repositories without it
+        #[arg(long = "cd", value_name = "SUBDIR")]
+        cd: Option<PathBuf>,
+
+        /// Groups to run command in (defaults to the groups configured for the workspace)
         #[arg(short, long)]
         group: Vec<String>,
 
+        /// Only include repositories with one of these tags (combined with --group)
+        #[arg(long = "tag", value_name = "TAG")]
+        tag: Vec<String>,
+
+        /// Exclude repositories with any of these tags
+        #[arg(long = "exclude-tag", value_name = "TAG")]
+        exclude_tag: Vec<String>,
+
+        /// Only include repositories whose dest matches one of these glob patterns
+        #[arg(long = "repo", value_name = "GLOB")]
+        repo: Vec<String>,
+
+        /// Pick the repositories from a checklist when neither --group nor --repo is given
+        #[arg(short = 'i', long)]
+        interactive: bool,
+
+        /// Only run in repositories whose status is one of these states (comma-separated)
+        #[arg(long = "on", value_name = "STATE", value_enum, value_delimiter = ',')]
+        on: Vec<StatusState>,
+
+        /// Skip repositories whose status is one of these states (comma-separated)
+        #[arg(
+            long,
+            value_name = "STATE",
+            value_enum,
+            value_delimiter = ',',
+            conflicts_with = "on"
+        )]
+        unless: Vec<StatusState>,
+
         /// Run commands in parallel
         #[arg(short, long)]
         parallel: bool,
 
-        /// Maximum number of parallel jobs
-        #[arg(short, long)]
+        /// Maximum number of parallel jobs (0 = unbounded, default: number of CPUs)
+        #[arg(short, long, value_parser = parse_jobs)]
         jobs: Option<usize>,
 
-        /// Continue execution even if some commands fail
-        #[arg(long)]
+        /// Continue execution even if some commands fail (the exit code still reports failures)
+        #[arg(long, visible_alias = "keep-going")]
         continue_on_error: bool,
+
+        /// Print a table of every repository's exit code at the end
+        #[arg(long)]
+        summary: bool,
+
+        /// Set an environment variable for the command (KEY=VALUE, repeatable)
+        #[arg(long = "env", value_name = "KEY=VALUE", value_parser = parse_env_var)]
+        env: Vec<(String, String)>,
+
+        /// Load environment variables from a dotenv file (--env takes precedence)
+        #[arg(long, value_name = "PATH")]
+        env_file: Option<PathBuf>,
+
+        /// Timeout for one repository, overriding its manifest foreach_timeout (repeatable)
+        #[arg(long, value_name = "DEST=SECS", value_parser = parse_repo_timeout)]
+        timeout_for: Vec<(String, u64)>,
+    },
+
+    /// Run a command in each repository in parallel, continuing past failures
+    ExecParallel {
+        /// Command to run (the git subcommand with --git)
+        command: String,
+
+        /// Arguments for the command
+        #[arg(allow_hyphen_values = true)]
+        args: Vec<String>,
+
+        /// Run a git subcommand, skipping repositories that are not git
+        #[arg(long)]
+        git: bool,
+
+        /// Groups to run command in (defaults to the groups configured for the workspace)
+        #[arg(short, long)]
+        group: Vec<String>,
+
+        /// Only include repositories with one of these tags (combined with --group)
+        #[arg(long = "tag", value_name = "TAG")]
+        tag: Vec<String>,
+
+        /// Exclude repositories with any of these tags
+        #[arg(long = "exclude-tag", value_name = "TAG")]
+        exclude_tag: Vec<String>,
+
+        /// Only include repositories whose dest matches one of these glob patterns
+        #[arg(long = "repo", value_name = "GLOB")]
+        repo: Vec<String>,
+
+        /// Pick the repositories from a checklist when neither --group nor --repo is given
+        #[arg(short = 'i', long)]
+        interactive: bool,
+
+        /// Run commands one repository at a time
+        #[arg(long)]
+        sequential: bool,
+
+        /// Print a table of every repository's exit code at the end
+        #[arg(long)]
+        summary: bool,
+
+        /// Maximum number of parallel jobs (0 = unbounded, default: number of CPUs)
+        #[arg(short, long, value_parser = parse_jobs)]
+        jobs: Option<usize>,
+
+        /// Set an environment variable for the command (KEY=VALUE, repeatable)
+        #[arg(long = "env", value_name = "KEY=VALUE", value_parser = parse_env_var)]
+        env: Vec<(String, String)>,
+
+        /// Load environment variables from a dotenv file (--env takes precedence)
+        #[arg(long, value_name = "PATH")]
+        env_file: Option<PathBuf>,
+
+        /// Timeout for one repository, overriding its manifest foreach_timeout (repeatable)
+        #[arg(long, value_name = "DEST=SECS", value_parser = parse_repo_timeout)]
+        timeout_for: Vec<(String, u64)>,
     },
 
-    /// Security audit for dependencies
+    /// Security audit for dependencies (cargo, npm, yarn, pip-audit, govulncheck)
     Audit {
-        /// Groups to audit (if not specified, all groups will be audited)
+        /// Groups to audit (defaults to the groups configured for the workspace)
         #[arg(short, long)]
         group: Vec<String>,
 
@@ -156,18 +669,22 @@ pub enum Commands {
         #[arg(short, long, default_value = "true")]
         parallel: bool,
 
-        /// Maximum number of parallel audits
-        #[arg(short, long)]
+        /// Maximum number of parallel audits (0 = unbounded, default: number of CPUs)
+        #[arg(short, long, value_parser = parse_jobs)]
         jobs: Option<usize>,
 
         /// Continue on vulnerabilities instead of failing
         #[arg(short, long)]
         continue_on_vulnerabilities: bool,
+
+        /// Output format (json: wmgr audit report, sarif: SARIF 2.1.0 log)
+        #[arg(long, value_enum, default_value = "text")]
+        output: AuditOutputFormat,
     },
 
     /// Show commit log for repositories
     Log {
-        /// Groups to show log for (if not specified, all groups will be used)
+        /// Groups to show log for (defaults to the groups configured for the workspace)
         #[arg(short, long)]
         group: Vec<String>,
 
@@ -186,11 +703,57 @@ pub enum Commands {
         /// Show commits until date
         #[arg(long)]
         until: Option<String>,
+
+        /// Only show commits whose author ("Name <email>") matches this regex
+        #[arg(long)]
+        author: Option<String>,
+
+        /// Only show commits whose message matches this regex
+        #[arg(long)]
+        grep: Option<String>,
+
+        /// Draw an ASCII commit graph for each repository
+        #[arg(long)]
+        graph: bool,
+
+        /// Interleave the commits of all repositories by date, prefixed with the repository
+        #[arg(long, conflicts_with = "graph")]
+        merged: bool,
+    },
+
+    /// Show uncommitted changes across repositories
+    Diff {
+        /// Groups to show changes for (defaults to the groups configured for the workspace)
+        #[arg(short, long)]
+        group: Vec<String>,
+
+        /// Show a diffstat summary instead of the patch
+        #[arg(long, conflicts_with = "name_only")]
+        stat: bool,
+
+        /// Show only the names of changed files
+        #[arg(long)]
+        name_only: bool,
+    },
+
+    /// Check repositories against the manifest and optionally repair them
+    Doctor {
+        /// Groups to check (defaults to the groups configured for the workspace)
+        #[arg(short, long)]
+        group: Vec<String>,
+
+        /// Repair origin URLs, missing clones and checked-out branches
+        #[arg(long)]
+        fix: bool,
+
+        /// Allow fixes that discard uncommitted changes
+        #[arg(long, requires = "fix")]
+        force: bool,
     },
 
     /// Dump the workspace manifest
     DumpManifest {
-        /// Output format (yaml or json)
+        /// Output format (yaml, json or toml)
         #[arg(short, long, default_value = "yaml")]
         format: String,
 
@@ -201,6 +764,18 @@ pub enum Commands {
         /// Pretty print JSON output
         #[arg(long)]
         pretty: bool,
+
+        /// Only dump the repositories and group definitions of these groups
+        #[arg(short, long)]
+        group: Vec<String>,
+
+        /// Pin each git repository to its checked-out commit in the `revision` field
+        #[arg(long)]
+        freeze: bool,
+
+        /// With --freeze, fail on repositories that are missing instead of leaving them unpinned
+        #[arg(long, requires = "freeze")]
+        strict: bool,
     },
 
     /// Apply a new manifest to the workspace
@@ -208,27 +783,37 @@ pub enum Commands {
         /// Path to the new manifest file
         manifest_file: String,
 
-        /// Force apply changes without confirmation
+        /// Apply changes without confirmation (same as --yes)
         #[arg(short, long)]
         force: bool,
 
         /// Show what would be changed without applying
         #[arg(long)]
         dry_run: bool,
+
+        /// Print the dry-run plan as json or yaml instead of the human-readable summary
+        #[arg(short, long, value_enum, requires = "dry_run")]
+        output: Option<OutputFormat>,
     },
 }
 
 /// CLI application runner
 pub struct CliApp {
     cli: Cli,
+    cancellation: CancellationToken,
 }
 
 impl CliApp {
     pub fn new() -> Self {
-        Self { cli: Cli::parse() }
+        Self {
+            cli: Cli::parse(),
+            cancellation: CancellationToken::new(),
+        }
     }
 
     pub async fn run(self) -> anyhow::Result<()> {
+        logging::init(self.cli.log_format, self.cli.verbosity());
+
         // Set up colored output
         if !self.cli.no_color {
             colored::control::set_override(true);
@@ -241,12 +826,35 @@ impl CliApp {
             env::set_current_dir(dir)?;
         }
 
+        // Ctrl-C stops a sync after it cleans up the repository in progress. Other
+        // commands are dropped, which kills their child processes and releases the
+        // workspace lock before exiting.
+        let signal = self.cancellation.clone();
+        tokio::spawn(async move {
+            if tokio::signal::ctrl_c().await.is_ok() {
+                signal.cancel();
+            }
+        });
+
         // Handle the command
-        match self.handle_command().await {
+        let outcome = {
+            let command = self.handle_command();
+            tokio::pin!(command);
+            tokio::select! {
+                outcome = &mut command => outcome,
+                _ = self.cancellation.cancelled(), if !self.cli.command.handles_cancellation() => {
+                    Err(WmgrError::Cancelled.into())
+                }
+            }
+        };
+        match outcome {
             Ok(_) => Ok(()),
             Err(e) => {
                 eprintln!("{} {}", "Error:".red().bold(), e);
-                exit(1);
+                if let Some(hint) = error_hint(&e) {
+                    eprintln!("{} {}", "hint:".yellow().bold(), hint);
+                }
+                exit(exit_code::for_error(&e));
             }
         }
     }
@@ -257,41 +865,216 @@ impl CliApp {
                 path,
                 force,
                 manifest,
+                from_existing,
+                group_by_dir,
             } => {
-                self.handle_init_command(path.as_ref(), *force, *manifest)
-                    .await
+                self.handle_init_command(
+                    path.as_ref(),
+                    *force,
+                    *manifest,
+                    *from_existing,
+                    *group_by_dir,
+                )
+                .await
             }
             Commands::Sync {
                 group,
+                tag,
+                exclude_tag,
+                repo,
+                interactive,
                 force,
                 no_correct_branch,
                 jobs,
                 no_recursive,
+                recurse_submodules,
+                lfs,
+                filter,
+                reference,
+                rebase,
+                only_changed,
+                resume,
+                since,
+                update_manifest,
+                manifest_branch,
+                hooks_fatal,
+                prune,
+                prune_dirty,
+                singular_remote,
+            } => {
+                let repo = self
+                    .repo_patterns(*interactive, group, tag, exclude_tag, repo)
+                    .await?;
+                self.handle_sync_command(
+                    group,
+                    tag,
+                    exclude_tag,
+                    &repo,
+                    *force,
+                    *no_correct_branch,
+                    *jobs,
+                    *no_recursive,
+                    *recurse_submodules,
+                    *lfs,
+                    filter.as_deref(),
+                    reference.as_deref(),
+                    *rebase,
+                    *only_changed,
+                    *resume,
+                    *since,
+                    *update_manifest,
+                    manifest_branch.as_deref(),
+                    *hooks_fatal,
+                    *prune,
+                    *prune_dirty,
+                    singular_remote.as_deref(),
+                )
+                .await
+            }
+            Commands::Push {
+                group,
+                dry_run,
+                force,
+                jobs,
+            } => {
+                self.handle_push_command(group, *dry_run, *force, *jobs)
+                    .await
+            }
+            Commands::Checkout {
+                branch,
+                group,
+                create,
+                force,
             } => {
-                self.handle_sync_command(group, *force, *no_correct_branch, *jobs, *no_recursive)
+                self.handle_checkout_command(branch, group, *create, *force)
                     .await
             }
             Commands::Status {
                 branch,
                 compact,
                 group,
+                tag,
+                exclude_tag,
+                repo,
+                interactive,
                 output,
-            } => self.handle_status_command(*branch, *compact, group, output.clone()).await,
+                jobs,
+                plain,
+                sort,
+                reverse,
+                changed_since,
+                only_changed,
+                untracked_summary,
+                ahead_behind_remote,
+                extended,
+                fetch,
+            } => {
+                let repo = self
+                    .repo_patterns(*interactive, group, tag, exclude_tag, repo)
+                    .await?;
+                self.handle_status_command(
+                    *branch,
+                    *compact,
+                    group,
+                    tag,
+                    exclude_tag,
+                    &repo,
+                    output.clone(),
+                    *jobs,
+                    *plain,
+                    *sort,
+                    *reverse,
+                    changed_since.clone(),
+                    *only_changed,
+                    *untracked_summary,
+                    ahead_behind_remote.clone(),
+                    *extended,
+                    *fetch,
+                )
+                .await
+            }
             Commands::Foreach {
                 command,
                 args,
+                git,
+                cd,
                 group,
+                tag,
+                exclude_tag,
+                repo,
+                interactive,
+                on,
+                unless,
                 parallel,
                 jobs,
                 continue_on_error,
+                summary,
+                env,
+                env_file,
+                timeout_for,
             } => {
+                let repo = self
+                    .repo_patterns(*interactive, group, tag, exclude_tag, repo)
+                    .await?;
                 self.handle_foreach_command(
                     command,
                     args,
+                    *git,
+                    cd.as_deref(),
                     group,
+                    tag,
+                    exclude_tag,
+                    &repo,
+                    on,
+                    unless,
                     *parallel,
                     *jobs,
                     *continue_on_error,
+                    *summary,
+                    env,
+                    env_file.as_deref(),
+                    timeout_for,
+                )
+                .await
+            }
+            Commands::ExecParallel {
+                command,
+                args,
+                git,
+                group,
+                tag,
+                exclude_tag,
+                repo,
+                interactive,
+                sequential,
+                summary,
+                jobs,
+                env,
+                env_file,
+                timeout_for,
+            } => {
+                let repo = self
+                    .repo_patterns(*interactive, group, tag, exclude_tag, repo)
+                    .await?;
+                // Unlike foreach, one failing repository does not discard the others' results
+                self.handle_foreach_command(
+                    command,
+                    args,
+                    *git,
+                    None,
+                    group,
+                    tag,
+                    exclude_tag,
+                    &repo,
+                    &[],
+                    &[],
+                    !*sequential,
+                    *jobs,
+                    true,
+                    *summary,
+                    env,
+                    env_file.as_deref(),
+                    timeout_for,
                 )
                 .await
             }
@@ -300,9 +1083,16 @@ impl CliApp {
                 parallel,
                 jobs,
                 continue_on_vulnerabilities,
+                output,
             } => {
-                self.handle_audit_command(group, *parallel, *jobs, *continue_on_vulnerabilities)
-                    .await
+                self.handle_audit_command(
+                    group,
+                    *parallel,
+                    *jobs,
+                    *continue_on_vulnerabilities,
+                    *output,
+                )
+                .await
             }
             Commands::Log {
                 group,
@@ -310,24 +1100,42 @@ impl CliApp {
                 max_count,
                 since,
                 until,
+                author,
+                grep,
+                graph,
+                merged,
             } => {
-                self.handle_log_command(group, *oneline, *max_count, since, until)
-                    .await
+                self.handle_log_command(
+                    group, *oneline, *max_count, since, until, author, grep, *graph, *merged,
+                )
+                .await
+            }
+            Commands::Diff {
+                group,
+                stat,
+                name_only,
+            } => self.handle_diff_command(group, *stat, *name_only).await,
+            Commands::Doctor { group, fix, force } => {
+                self.handle_doctor_command(group, *fix, *force).await
             }
             Commands::DumpManifest {
                 format,
                 output,
                 pretty,
+                group,
+                freeze,
+                strict,
             } => {
-                self.handle_dump_manifest_command(format, output, *pretty)
+                self.handle_dump_manifest_command(format, output, *pretty, group, *freeze, *strict)
                     .await
             }
             Commands::ApplyManifest {
                 manifest_file,
                 force,
                 dry_run,
+                output,
             } => {
-                self.handle_apply_manifest_command(manifest_file, *force, *dry_run)
+                self.handle_apply_manifest_command(manifest_file, *force, *dry_run, output.as_ref())
                     .await
             }
         }
@@ -338,24 +1146,62 @@ impl CliApp {
         path: Option<&String>,
         force: bool,
         use_manifest_name: bool,
+        from_existing: bool,
+        group_by_dir: bool,
     ) -> anyhow::Result<()> {
         use crate::presentation::cli::commands::init::InitCommand;
 
         let target_path = path.map(|p| std::path::PathBuf::from(p));
-        let init_cmd = InitCommand::new(target_path, force, use_manifest_name);
+        let init_cmd = InitCommand::new(target_path, force, use_manifest_name)
+            .with_backup(!self.cli.no_backup)
+            .with_from_existing(from_existing)
+            .with_group_by_dir(group_by_dir)
+            .with_yes(self.cli.yes)
+            .with_verbosity(self.cli.verbosity());
         init_cmd.execute().await
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn handle_sync_command(
         &self,
         groups: &[String],
+        tags: &[String],
+        exclude_tags: &[String],
+        repo_patterns: &[String],
         force: bool,
         no_correct_branch: bool,
         jobs: Option<usize>,
         no_recursive: bool,
+        recurse_submodules: bool,
+        lfs: bool,
+        clone_filter: Option<&str>,
+        reference: Option<&Path>,
+        rebase: bool,
+        only_changed: bool,
+        resume: bool,
+        since: Option<Duration>,
+        update_manifest: bool,
+        manifest_branch: Option<&str>,
+        hooks_fatal: bool,
+        prune: bool,
+        prune_dirty: bool,
+        singular_remote: Option<&str>,
     ) -> anyhow::Result<()> {
+        use wmgr::infrastructure::filesystem::workspace_lock::WorkspaceLock;
+
         // Load workspace
-        let mut workspace = self.load_workspace().await?;
+        let mut workspace = self
+            .cli
+            .workspace_loader()
+            .load_current()
+            .await
+            .map_err(WmgrError::from)?;
+        if let Some(remote) = singular_remote {
+            workspace.config.singular_remote = Some(remote.to_string());
+        }
+
+        // Prevent concurrent mutating commands on the same workspace
+        let _lock = WorkspaceLock::acquire(&workspace.wmgr_dir()).map_err(WmgrError::from)?;
 
         // Prepare groups list
         let groups_list = if groups.is_empty() {
@@ -367,11 +1213,26 @@ impl CliApp {
         // Create configuration
         let config = SyncRepositoriesConfig {
             groups: groups_list,
+            tags: tags.to_vec(),
+            exclude_tags: exclude_tags.to_vec(),
+            repo_patterns: repo_patterns.to_vec(),
             force,
             no_correct_branch,
             parallel_jobs: jobs,
-            verbose: self.cli.verbose,
+            verbose: self.cli.verbosity().is_verbose(),
             recursive: !no_recursive,
+            recurse_submodules,
+            lfs,
+            clone_filter: clone_filter.map(str::to_string),
+            reference: reference.map(Path::to_path_buf),
+            rebase,
+            only_changed,
+            resume,
+            since,
+            update_manifest,
+            manifest_branch: manifest_branch.map(str::to_string),
+            strict_manifest: self.cli.strict_manifest,
+            hooks_fatal,
             credential_profile: self.cli.profile.clone(),
             credential_file: self.cli.credential_file.clone(),
         };
@@ -379,47 +1240,158 @@ impl CliApp {
         // Execute the use case
         let use_case = SyncRepositoriesUseCase::new(config);
 
-        println!("{} Synchronizing repositories...", "::".blue().bold());
+        let verbosity = self.cli.verbosity();
+        if !verbosity.is_quiet() {
+            println!("{} Synchronizing repositories...", "::".blue().bold());
+        }
 
-        match use_case.execute(&mut workspace).await {
+        match crate::presentation::cli::commands::sync::execute_with_progress(
+            use_case,
+            &mut workspace,
+            verbosity,
+            self.cancellation.clone(),
+        )
+        .await
+        {
+            Ok(result) if result.is_interrupted() => {
+                Err(crate::presentation::cli::commands::sync::report_interruption(&result))
+            }
             Ok(result) => {
-                println!("{} Synchronization completed!", "✓".green().bold());
-                if self.cli.verbose {
+                if !verbosity.is_quiet() {
+                    println!("{} Synchronization completed!", "✓".green().bold());
+                }
+                if verbosity.is_verbose() {
                     println!("  Repositories synced: {}", result.synced_count);
                     println!("  New repositories cloned: {}", result.cloned_count);
                     println!("  Repositories updated: {}", result.updated_count);
+                    if result.recovered_count > 0 {
+                        println!(
+                            "  Incomplete clones cloned again: {}",
+                            result.recovered_count
+                        );
+                    }
+                    if result.up_to_date_count > 0 {
+                        println!("  Repositories up to date: {}", result.up_to_date_count);
+                    }
+                    if result.resumed_count > 0 {
+                        println!(
+                            "  Repositories completed by the previous sync: {}",
+                            result.resumed_count
+                        );
+                    }
+                    if result.recent_count > 0 {
+                        println!("  Repositories fetched recently: {}", result.recent_count);
+                    }
                     if result.skipped_count > 0 {
                         println!("  Repositories skipped: {}", result.skipped_count);
                     }
                 }
 
-                // Show any errors
+                if !verbosity.is_quiet() {
+                    for warning in &result.warnings {
+                        eprintln!("{} {}", "⚠".yellow().bold(), warning.yellow());
+                    }
+                }
+
+                // Show any errors (even with --quiet, on stderr)
                 if !result.errors.is_empty() {
-                    println!("{} Some errors occurred:", "⚠".yellow().bold());
-                    for error in result.errors {
-                        println!("  {}", error.red());
+                    eprintln!("{} Some errors occurred:", "⚠".yellow().bold());
+                    for error in &result.errors {
+                        eprintln!("  {}", error.red());
+                    }
+                    return Err(WmgrError::SyncIncomplete {
+                        failed: result.errors.len(),
                     }
+                    .into());
+                }
+
+                if prune {
+                    crate::presentation::cli::commands::sync::prune_orphaned_repositories(
+                        &workspace,
+                        prune_dirty,
+                        self.cli.yes,
+                        verbosity,
+                    )?;
                 }
 
                 Ok(())
             }
-            Err(SyncRepositoriesError::WorkspaceNotInitialized(path)) => Err(anyhow::anyhow!(
-                "Workspace not initialized at: {}\nManifest file not found",
-                path
-            )),
-            Err(e) => Err(anyhow::anyhow!("Failed to synchronize repositories: {}", e)),
+            Err(e) => Err(WmgrError::from(e).into()),
         }
     }
 
+    async fn handle_push_command(
+        &self,
+        groups: &[String],
+        dry_run: bool,
+        force: bool,
+        jobs: Option<usize>,
+    ) -> anyhow::Result<()> {
+        use crate::presentation::cli::commands::push::PushCommand;
+
+        let command = PushCommand::new(
+            groups.to_vec(),
+            dry_run,
+            force,
+            jobs,
+            self.cli.verbosity(),
+            self.cli.profile.clone(),
+            self.cli.credential_file.clone(),
+        )
+        .with_workspace_loader(self.cli.workspace_loader());
+
+        command.execute().await
+    }
+
+    async fn handle_checkout_command(
+        &self,
+        branch: &str,
+        groups: &[String],
+        create: bool,
+        force: bool,
+    ) -> anyhow::Result<()> {
+        use crate::presentation::cli::commands::checkout::CheckoutCommand;
+
+        let command = CheckoutCommand::new(
+            branch.to_string(),
+            groups.to_vec(),
+            create,
+            force,
+            self.cli.verbosity(),
+        )
+        .with_workspace_loader(self.cli.workspace_loader());
+
+        command.execute().await
+    }
+
+    #[allow(clippy::too_many_arguments)]
     async fn handle_status_command(
         &self,
         show_branch: bool,
         compact: bool,
         groups: &[String],
+        tags: &[String],
+        exclude_tags: &[String],
+        repo_patterns: &[String],
         output_format: OutputFormat,
+        jobs: Option<usize>,
+        plain: bool,
+        sort: StatusSort,
+        reverse: bool,
+        changed_since: Option<ChangedSince>,
+        only_changed: bool,
+        untracked_summary: bool,
+        ahead_behind_remote: Option<String>,
+        extended: bool,
+        fetch: bool,
     ) -> anyhow::Result<()> {
         // Load workspace
-        let workspace = self.load_workspace().await?;
+        let workspace = self
+            .cli
+            .workspace_loader()
+            .load_current()
+            .await
+            .map_err(WmgrError::from)?;
 
         // Prepare groups list
         let groups_list = if groups.is_empty() {
@@ -431,15 +1403,34 @@ impl CliApp {
         // Create configuration
         let config = StatusCheckConfig {
             groups: groups_list,
+            tags: tags.to_vec(),
+            exclude_tags: exclude_tags.to_vec(),
+            repo_patterns: repo_patterns.to_vec(),
             show_branch,
             compact,
-            verbose: self.cli.verbose,
+            verbose: self.cli.verbosity().is_verbose(),
+            jobs,
+            sort: sort.into(),
+            reverse,
+            changed_since,
+            only_changed,
+            untracked_summary,
+            ahead_behind_remote,
+            extended,
+            fetch,
         };
-
-        // Execute the use case
-        let use_case = StatusCheckUseCase::new(config);
-
-        match use_case.execute(&workspace).await {
+        let show_commits_since = config.changed_since.is_some();
+
+        // Execute the use case; progress goes to stderr so stdout holds only the report
+        let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+        let printer = tokio::spawn(ProgressRenderer::for_stderr(self.cli.verbosity()).run(receiver));
+        let use_case = StatusCheckUseCase::new(config).with_progress(sender);
+        let result = use_case.execute(&workspace).await;
+        // Dropping the use case closes the channel, so the printer drains and stops
+        drop(use_case);
+        let _ = printer.await;
+
+        match result {
             Ok(status) => {
                 match output_format {
                     OutputFormat::Json => self.print_json_status(&status)?,
@@ -447,32 +1438,77 @@ impl CliApp {
                     OutputFormat::Text => {
                         if compact {
                             self.print_compact_status(&status);
-                        } else {
+                        } else if plain || !atty::is(atty::Stream::Stdout) {
                             self.print_detailed_status(&status, show_branch);
+                        } else {
+                            self.print_status_table(&status, show_branch, show_commits_since);
                         }
                     }
                 }
                 Ok(())
             }
-            Err(StatusCheckError::WorkspaceNotInitialized(path)) => Err(anyhow::anyhow!(
-                "Workspace not initialized at: {}\nManifest file not found",
-                path
-            )),
-            Err(e) => Err(anyhow::anyhow!("Failed to check status: {}", e)),
+            Err(e) => Err(WmgrError::from(e).into()),
+        }
+    }
+
+    /// `--repo` patterns for a command, picked interactively with `--interactive`
+    ///
+    /// The checklist only appears when neither `--group` nor `--repo` narrows the
+    /// selection; it offers the workspace's configured repositories that pass the
+    /// tag filters, and the picks are passed on as exact `--repo` patterns.
+    async fn repo_patterns(
+        &self,
+        interactive: bool,
+        groups: &[String],
+        tags: &[String],
+        exclude_tags: &[String],
+        repo_patterns: &[String],
+    ) -> anyhow::Result<Vec<String>> {
+        use crate::presentation::ui::repo_picker::{pick_repo_patterns, picker_candidates};
+
+        if !interactive || !groups.is_empty() || !repo_patterns.is_empty() {
+            return Ok(repo_patterns.to_vec());
         }
+
+        let workspace = self
+            .cli
+            .workspace_loader()
+            .load_current()
+            .await
+            .map_err(WmgrError::from)?;
+        pick_repo_patterns(&picker_candidates(&workspace, tags, exclude_tags)?)
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn handle_foreach_command(
         &self,
         command: &str,
         args: &[String],
+        git: bool,
+        subdir: Option<&Path>,
         groups: &[String],
+        tags: &[String],
+        exclude_tags: &[String],
+        repo_patterns: &[String],
+        states: &[StatusState],
+        excluded_states: &[StatusState],
         parallel: bool,
         jobs: Option<usize>,
         continue_on_error: bool,
+        summary: bool,
+        env: &[(String, String)],
+        env_file: Option<&Path>,
+        timeout_for: &[(String, u64)],
     ) -> anyhow::Result<()> {
+        use crate::presentation::cli::commands::foreach::{check_result, print_summary};
+
         // Load workspace
-        let workspace = self.load_workspace().await?;
+        let workspace = self
+            .cli
+            .workspace_loader()
+            .load_current()
+            .await
+            .map_err(WmgrError::from)?;
 
         // Prepare groups list
         let groups_list = if groups.is_empty() {
@@ -481,6 +1517,8 @@ impl CliApp {
             Some(groups.to_vec())
         };
 
+        let environment_variables = foreach_environment(env_file, env)?;
+
         // Build the full command
         let full_command = if args.is_empty() {
             command.to_string()
@@ -492,22 +1530,49 @@ impl CliApp {
         let config = ForeachCommandConfig {
             command: full_command,
             groups: groups_list,
+            tags: tags.to_vec(),
+            exclude_tags: exclude_tags.to_vec(),
+            repo_patterns: repo_patterns.to_vec(),
             parallel,
             max_parallel: jobs,
             continue_on_error,
-            verbose: self.cli.verbose,
+            verbose: self.cli.verbosity().is_verbose(),
+            subdir: subdir.map(Path::to_path_buf),
+            git,
+            environment_variables,
+            repo_timeouts: timeout_for.iter().cloned().collect(),
+            states: states.iter().map(|state| (*state).into()).collect(),
+            excluded_states: excluded_states
+                .iter()
+                .map(|state| (*state).into())
+                .collect(),
             ..Default::default()
         };
 
-        // Execute the use case
-        let use_case = ForeachCommandUseCase::new(config);
-
-        println!("{} Running command: {}", "::".blue().bold(), command);
+        let verbosity = self.cli.verbosity();
+        if !verbosity.is_quiet() {
+            println!(
+                "{} Running command: {}",
+                "::".blue().bold(),
+                config.effective_command()
+            );
+        }
 
-        match use_case.execute(&workspace).await {
+        // Execute the use case
+        let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+        let printer = tokio::spawn(ProgressRenderer::for_stdout(verbosity).run(receiver));
+        let use_case = ForeachCommandUseCase::new(config).with_progress(sender);
+        let result = use_case.execute(&workspace).await;
+        // Dropping the use case closes the channel, so the printer drains and stops
+        drop(use_case);
+        let _ = printer.await;
+
+        match result {
             Ok(result) => {
-                println!("{} Command execution completed!", "✓".green().bold());
-                if self.cli.verbose {
+                if !verbosity.is_quiet() {
+                    println!("{} Command execution completed!", "✓".green().bold());
+                }
+                if verbosity.is_verbose() {
                     println!("  Successful executions: {}", result.success_count);
                     println!("  Failed executions: {}", result.failure_count);
                     println!("  Skipped executions: {}", result.skipped_count);
@@ -524,13 +1589,13 @@ impl CliApp {
                     }
                 }
 
-                Ok(())
+                if summary {
+                    print_summary(&result);
+                }
+
+                check_result(&result)
             }
-            Err(ForeachCommandError::WorkspaceNotInitialized(path)) => Err(anyhow::anyhow!(
-                "Workspace not initialized at: {}\nManifest file not found",
-                path
-            )),
-            Err(e) => Err(anyhow::anyhow!("Failed to execute command: {}", e)),
+            Err(e) => Err(WmgrError::from(e).into()),
         }
     }
 
@@ -540,9 +1605,9 @@ impl CliApp {
         parallel: bool,
         jobs: Option<usize>,
         continue_on_vulnerabilities: bool,
+        output: AuditOutputFormat,
     ) -> anyhow::Result<()> {
         use crate::presentation::cli::commands::audit::{AuditArgs, AuditCommand};
-        use std::path::PathBuf;
 
         let args = AuditArgs {
             groups: if groups.is_empty() {
@@ -553,17 +1618,19 @@ impl CliApp {
             parallel,
             max_parallel: jobs,
             continue_on_vulnerabilities,
-            verbose: self.cli.verbose,
+            verbose: self.cli.verbosity().is_verbose(),
+            output,
             workspace_dir: None, // Use current directory
         };
 
-        let command = AuditCommand::new();
+        let command = AuditCommand::new().with_workspace_loader(self.cli.workspace_loader());
         command
             .execute(args)
             .await
             .map_err(|e| anyhow::anyhow!("{}", e))
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn handle_log_command(
         &self,
         groups: &[String],
@@ -571,6 +1638,10 @@ impl CliApp {
         max_count: Option<usize>,
         since: &Option<String>,
         until: &Option<String>,
+        author: &Option<String>,
+        grep: &Option<String>,
+        graph: bool,
+        merged: bool,
     ) -> anyhow::Result<()> {
         use crate::presentation::cli::commands::log::LogCommand;
 
@@ -580,17 +1651,78 @@ impl CliApp {
             max_count,
             since.clone(),
             until.clone(),
-            self.cli.verbose,
-        );
+            author.clone(),
+            grep.clone(),
+            graph,
+            merged,
+            self.cli.verbosity(),
+        )
+        .with_workspace_loader(self.cli.workspace_loader());
 
         command.execute().await
     }
 
+    async fn handle_diff_command(
+        &self,
+        groups: &[String],
+        stat: bool,
+        name_only: bool,
+    ) -> anyhow::Result<()> {
+        use crate::presentation::cli::commands::diff::{DiffCommand, DiffFormat};
+
+        let workspace = self
+            .cli
+            .workspace_loader()
+            .load_current()
+            .await
+            .map_err(WmgrError::from)?;
+        let format = if stat {
+            DiffFormat::Stat
+        } else if name_only {
+            DiffFormat::NameOnly
+        } else {
+            DiffFormat::Patch
+        };
+
+        DiffCommand::new(groups.to_vec(), format, self.cli.verbosity())
+            .execute(&workspace)
+            .await
+    }
+
+    async fn handle_doctor_command(
+        &self,
+        groups: &[String],
+        fix: bool,
+        force: bool,
+    ) -> anyhow::Result<()> {
+        use crate::presentation::cli::commands::doctor::DoctorCommand;
+        use wmgr::infrastructure::filesystem::workspace_lock::WorkspaceLock;
+
+        let workspace = self
+            .cli
+            .workspace_loader()
+            .load_current()
+            .await
+            .map_err(WmgrError::from)?;
+        // Fixes modify working trees; a plain diagnosis does not need the lock
+        let _lock = if fix {
+            Some(WorkspaceLock::acquire(&workspace.wmgr_dir()).map_err(WmgrError::from)?)
+        } else {
+            None
+        };
+        DoctorCommand::new(groups.to_vec(), fix, force, self.cli.verbosity())
+            .execute(&workspace)
+            .await
+    }
+
     async fn handle_dump_manifest_command(
         &self,
         format: &str,
         output_file: &Option<String>,
         pretty: bool,
+        groups: &[String],
+        freeze: bool,
+        strict: bool,
     ) -> anyhow::Result<()> {
         use crate::presentation::cli::commands::dump_manifest::{
             DumpManifestCommand, OutputFormat,
@@ -600,8 +1732,15 @@ impl CliApp {
             .parse()
             .map_err(|e| anyhow::anyhow!("Invalid format: {}", e))?;
 
-        let command =
-            DumpManifestCommand::new(output_format, output_file.clone(), pretty, self.cli.verbose);
+        let command = DumpManifestCommand::new(
+            output_format,
+            output_file.clone(),
+            pretty,
+            self.cli.verbosity(),
+        )
+        .with_groups(groups.to_vec())
+        .with_freeze(freeze, strict)
+        .with_workspace_loader(self.cli.workspace_loader());
 
         command.execute().await
     }
@@ -611,49 +1750,30 @@ impl CliApp {
         manifest_file: &str,
         force: bool,
         dry_run: bool,
+        output: Option<&OutputFormat>,
     ) -> anyhow::Result<()> {
-        use crate::presentation::cli::commands::apply_manifest::ApplyManifestCommand;
-
-        let command =
-            ApplyManifestCommand::new(manifest_file.to_string(), force, dry_run, self.cli.verbose);
-
-        command.execute().await
-    }
-
-    /// Load workspace from the current directory or any parent directory
-    async fn load_workspace(&self) -> anyhow::Result<Workspace> {
-        let current_dir = env::current_dir()?;
-
-        // Discover workspace root by searching upward for manifest files
-        let workspace_root = if let Some(root) = Workspace::discover_workspace_root(&current_dir) {
-            root
-        } else {
-            return Err(anyhow::anyhow!(
-                "No wmgr workspace found. Searched upward from {} for wmgr.yml, wmgr.yaml, manifest.yml, or manifest.yaml files.",
-                current_dir.display()
-            ));
+        use crate::presentation::cli::commands::apply_manifest::{
+            ApplyManifestCommand, PlanFormat,
         };
 
-        // Create workspace and find manifest file
-        let workspace = Workspace::new(workspace_root.clone(), WorkspaceConfig::default_local());
-        let manifest_file = workspace.manifest_file_path();
-
-        // Load manifest file
-        use wmgr::domain::entities::workspace::{WorkspaceConfig, WorkspaceStatus};
-        use wmgr::infrastructure::filesystem::manifest_store::ManifestStore;
-        let mut manifest_store = ManifestStore::new();
-        let processed_manifest = manifest_store
-            .read_manifest(&manifest_file)
-            .await
-            .map_err(|e| anyhow::anyhow!("Failed to load manifest: {}", e))?;
-
-        // Create a workspace configuration
-        let workspace_config = WorkspaceConfig::new(&manifest_file.display().to_string(), "main");
+        let plan_format = match output {
+            Some(OutputFormat::Json) => Some(PlanFormat::Json),
+            Some(OutputFormat::Yaml) => Some(PlanFormat::Yaml),
+            Some(OutputFormat::Text) | None => None,
+        };
+        let command = ApplyManifestCommand::new(
+            manifest_file.to_string(),
+            force,
+            dry_run,
+            self.cli.verbosity(),
+        )
+        .with_backup(!self.cli.no_backup)
+        .with_plan_format(plan_format)
+        .with_yes(self.cli.yes)
+        .with_strict(self.cli.strict_manifest)
+        .with_workspace_loader(self.cli.workspace_loader());
 
-        let workspace = Workspace::new(workspace_root, workspace_config)
-            .with_status(WorkspaceStatus::Initialized)
-            .with_manifest(processed_manifest.manifest);
-        Ok(workspace)
+        command.execute().await
     }
 
     fn print_compact_status(
@@ -673,10 +1793,42 @@ impl CliApp {
                 }
                 wmgr::application::use_cases::status_check::RepositoryState::Error => "E".red(),
             };
-            println!("{} {}", state_char, repo_status.dest);
+            match repo_status.commits_since.filter(|count| *count > 0) {
+                Some(count) => println!("{} {} +{}", state_char, repo_status.dest, count),
+                None => println!("{} {}", state_char, repo_status.dest),
+            }
         }
     }
 
+    fn print_status_table(
+        &self,
+        status: &wmgr::application::use_cases::status_check::StatusResult,
+        show_branch: bool,
+        show_commits_since: bool,
+    ) {
+        use crate::presentation::ui::StatusTable;
+
+        let table = StatusTable::new(!self.cli.no_color, show_branch)
+            .with_commits_since(show_commits_since);
+        for line in table.render(&status.repositories) {
+            println!("{}", line);
+        }
+
+        let breakdowns: Vec<(&str, String)> = status
+            .repositories
+            .iter()
+            .filter_map(|repo| Some((repo.dest.as_str(), untracked_breakdown_summary(repo)?)))
+            .collect();
+        if !breakdowns.is_empty() {
+            println!();
+            println!("{}", "Untracked files:".bold());
+            for (dest, breakdown) in breakdowns {
+                println!("  {}: {}", dest.bold(), breakdown);
+            }
+        }
+        print_status_summary(status);
+    }
+
     fn print_detailed_status(
         &self,
         status: &wmgr::application::use_cases::status_check::StatusResult,
@@ -699,9 +1851,7 @@ impl CliApp {
                 wmgr::application::use_cases::status_check::RepositoryState::OutOfSync => {
                     "out of sync".magenta()
                 }
-                wmgr::application::use_cases::status_check::RepositoryState::Error => {
-                    "error".red()
-                }
+                wmgr::application::use_cases::status_check::RepositoryState::Error => "error".red(),
             };
 
             print!("{}: {}", repo_status.dest.bold(), state_text);
@@ -730,8 +1880,17 @@ impl CliApp {
                 }
             }
 
+            if let Some(count) = repo_status.commits_since.filter(|count| *count > 0) {
+                print!(" {}", format!("+{} commits", count).green());
+            }
+
             println!();
+
+            if let Some(breakdown) = untracked_breakdown_summary(repo_status) {
+                println!("  untracked: {}", breakdown);
+            }
         }
+        print_status_summary(status);
     }
 
     fn print_json_status(
@@ -752,3 +1911,336 @@ impl CliApp {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_jobs() {
+        assert_eq!(parse_jobs("0"), Ok(0));
+        assert_eq!(parse_jobs("8"), Ok(8));
+        assert!(parse_jobs("-1")
+            .unwrap_err()
+            .contains("non-negative integer"));
+        assert!(parse_jobs("many").is_err());
+        assert!(parse_jobs("").is_err());
+    }
+
+    #[test]
+    fn test_parse_changed_since() {
+        assert!(matches!(
+            parse_changed_since("2024-05-01"),
+            Ok(ChangedSince::Date(_))
+        ));
+        assert!(matches!(
+            parse_changed_since("2024-05-01T12:00:00+00:00"),
+            Ok(ChangedSince::Date(1_714_564_800))
+        ));
+        assert_eq!(
+            parse_changed_since("v1.2.0"),
+            Ok(ChangedSince::Revision("v1.2.0".to_string()))
+        );
+        assert!(parse_changed_since(" ").is_err());
+    }
+
+    #[test]
+    fn test_error_hint_for_typed_errors() {
+        use wmgr::application::use_cases::status_check::StatusCheckError;
+        use wmgr::application::use_cases::sync_repositories::SyncRepositoriesError;
+
+        let error: anyhow::Error = WmgrError::from(StatusCheckError::WorkspaceNotInitialized(
+            "/tmp/ws".to_string(),
+        ))
+        .into();
+        assert_eq!(error.to_string(), "Workspace not initialized at: /tmp/ws");
+        assert!(error_hint(&error).unwrap().contains("wmgr init"));
+
+        let error: anyhow::Error = WmgrError::from(SyncRepositoriesError::MergeConflict {
+            repo: "frontend".to_string(),
+            files: "src/main.rs".to_string(),
+        })
+        .into();
+        assert!(error
+            .to_string()
+            .contains("Merge conflict in repo 'frontend'"));
+        assert!(error_hint(&error).unwrap().contains("wmgr status"));
+
+        assert!(error_hint(&anyhow::anyhow!("untyped failure")).is_none());
+    }
+
+    #[test]
+    fn test_foreach_git_accepts_hyphenated_arguments() {
+        let cli = Cli::try_parse_from(["wmgr", "foreach", "--git", "status", "-s"]).unwrap();
+        match cli.command {
+            Commands::Foreach {
+                command, args, git, ..
+            } => {
+                assert!(git);
+                assert_eq!(command, "status");
+                assert_eq!(args, vec!["-s".to_string()]);
+            }
+            _ => panic!("expected foreach"),
+        }
+
+        // Options of foreach itself still work after the command
+        let cli = Cli::try_parse_from(["wmgr", "foreach", "make test", "--parallel"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Foreach { parallel: true, ref args, .. } if args.is_empty()
+        ));
+    }
+
+    #[test]
+    fn test_yes_is_a_global_flag() {
+        for args in [
+            ["wmgr", "-y", "apply-manifest", "new.yml"],
+            ["wmgr", "apply-manifest", "new.yml", "--yes"],
+        ] {
+            assert!(Cli::try_parse_from(args).unwrap().yes);
+        }
+        assert!(
+            !Cli::try_parse_from(["wmgr", "apply-manifest", "new.yml"])
+                .unwrap()
+                .yes
+        );
+    }
+
+    #[test]
+    fn test_log_format_is_a_global_option() {
+        let cli = Cli::try_parse_from(["wmgr", "status"]).unwrap();
+        assert!(matches!(cli.log_format, LogFormat::Text));
+
+        let cli = Cli::try_parse_from(["wmgr", "sync", "--log-format", "json"]).unwrap();
+        assert!(matches!(cli.log_format, LogFormat::Json));
+
+        assert!(Cli::try_parse_from(["wmgr", "--log-format", "xml", "status"]).is_err());
+    }
+
+    #[test]
+    fn test_exec_parallel_is_parallel_by_default() {
+        let cli = Cli::try_parse_from(["wmgr", "foreach", "make", "test"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Foreach {
+                parallel: false,
+                continue_on_error: false,
+                ..
+            }
+        ));
+
+        let cli =
+            Cli::try_parse_from(["wmgr", "exec-parallel", "-j", "2", "make", "test"]).unwrap();
+        match cli.command {
+            Commands::ExecParallel {
+                command,
+                args,
+                sequential,
+                jobs,
+                ..
+            } => {
+                assert_eq!(command, "make");
+                assert_eq!(args, vec!["test".to_string()]);
+                assert!(!sequential);
+                assert_eq!(jobs, Some(2));
+            }
+            _ => panic!("expected exec-parallel"),
+        }
+
+        let cli = Cli::try_parse_from(["wmgr", "exec-parallel", "--sequential", "make"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::ExecParallel {
+                sequential: true,
+                ..
+            }
+        ));
+        // Failures never stop exec-parallel, so there is no flag for it
+        assert!(
+            Cli::try_parse_from(["wmgr", "exec-parallel", "--continue-on-error", "make"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_foreach_on_states() {
+        let cli = Cli::try_parse_from([
+            "wmgr",
+            "foreach",
+            "--on",
+            "dirty,wrong-branch",
+            "--on",
+            "out-of-sync",
+            "--",
+            "git",
+            "add",
+            "-A",
+        ])
+        .unwrap();
+        let Commands::Foreach {
+            on, command, args, ..
+        } = cli.command
+        else {
+            panic!("expected foreach");
+        };
+        assert_eq!(
+            on,
+            vec![
+                StatusState::Dirty,
+                StatusState::WrongBranch,
+                StatusState::OutOfSync
+            ]
+        );
+        assert_eq!(command, "git");
+        assert_eq!(args, vec!["add".to_string(), "-A".to_string()]);
+
+        assert!(Cli::try_parse_from(["wmgr", "foreach", "--on", "stale", "ls"]).is_err());
+    }
+
+    #[test]
+    fn test_foreach_unless_states() {
+        let cli =
+            Cli::try_parse_from(["wmgr", "foreach", "--unless", "dirty,missing", "make"]).unwrap();
+        let Commands::Foreach { on, unless, .. } = cli.command else {
+            panic!("expected foreach");
+        };
+        assert!(on.is_empty());
+        assert_eq!(unless, vec![StatusState::Dirty, StatusState::Missing]);
+
+        let error = Cli::try_parse_from([
+            "wmgr", "foreach", "--on", "clean", "--unless", "dirty", "make",
+        ])
+        .err()
+        .unwrap();
+        assert_eq!(error.kind(), clap::error::ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn test_parse_env_var() {
+        assert_eq!(
+            parse_env_var("A=b=c"),
+            Ok(("A".to_string(), "b=c".to_string()))
+        );
+        assert_eq!(
+            parse_env_var("EMPTY="),
+            Ok(("EMPTY".to_string(), String::new()))
+        );
+        assert!(parse_env_var("NOVALUE").is_err());
+        assert!(parse_env_var("=value").is_err());
+    }
+
+    #[test]
+    fn test_parse_since() {
+        assert_eq!(parse_since("30m"), Ok(Duration::from_secs(30 * 60)));
+        assert_eq!(parse_since("2h"), Ok(Duration::from_secs(2 * 60 * 60)));
+        assert_eq!(parse_since("1d"), Ok(Duration::from_secs(24 * 60 * 60)));
+        assert_eq!(parse_since("45s"), Ok(Duration::from_secs(45)));
+        for invalid in ["", "30", "m", "-1h", "1.5h", "2w"] {
+            assert!(parse_since(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_parse_repo_timeout() {
+        assert_eq!(
+            parse_repo_timeout("libs/core=120"),
+            Ok(("libs/core".to_string(), 120))
+        );
+        assert!(parse_repo_timeout("core").is_err());
+        assert!(parse_repo_timeout("=10").is_err());
+        assert!(parse_repo_timeout("core=-1").is_err());
+
+        let cli = Cli::try_parse_from([
+            "wmgr",
+            "foreach",
+            "--timeout-for",
+            "app=5",
+            "--timeout-for",
+            "docs=60",
+            "make",
+        ])
+        .unwrap();
+        let Commands::Foreach { timeout_for, .. } = cli.command else {
+            panic!("expected foreach");
+        };
+        assert_eq!(
+            timeout_for,
+            vec![("app".to_string(), 5), ("docs".to_string(), 60)]
+        );
+    }
+
+    #[test]
+    fn test_foreach_env_flags_override_env_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let env_file = temp_dir.path().join(".env");
+        std::fs::write(
+            &env_file,
+            "# team defaults\nSTAGE=dev\nREGION=\"eu west\"\nSTAGE=staging\n",
+        )
+        .unwrap();
+
+        let cli = Cli::try_parse_from([
+            "wmgr",
+            "foreach",
+            "--env-file",
+            env_file.to_str().unwrap(),
+            "--env",
+            "STAGE=prod",
+            "--env",
+            "DEBUG=1",
+            "make deploy",
+        ])
+        .unwrap();
+        let Commands::Foreach { env, env_file, .. } = cli.command else {
+            panic!("expected foreach");
+        };
+
+        let environment = foreach_environment(env_file.as_deref(), &env).unwrap();
+        assert_eq!(environment.len(), 3);
+        assert_eq!(environment["STAGE"], "prod");
+        assert_eq!(environment["REGION"], "eu west");
+        assert_eq!(environment["DEBUG"], "1");
+
+        // Without flags, the last assignment in the file wins
+        let environment = foreach_environment(env_file.as_deref(), &[]).unwrap();
+        assert_eq!(environment["STAGE"], "staging");
+
+        let missing = temp_dir.path().join("missing.env");
+        assert!(matches!(
+            foreach_environment(Some(&missing), &[]),
+            Err(WmgrError::ConfigError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_diff_formats_are_exclusive() {
+        let cli = Cli::try_parse_from(["wmgr", "diff", "--stat", "-g", "web"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Diff { stat: true, name_only: false, ref group } if group == &["web"]
+        ));
+        assert!(Cli::try_parse_from(["wmgr", "diff", "--stat", "--name-only"]).is_err());
+    }
+
+    #[test]
+    fn test_doctor_force_requires_fix() {
+        let cli = Cli::try_parse_from(["wmgr", "doctor", "--fix", "--force"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Doctor {
+                fix: true,
+                force: true,
+                ..
+            }
+        ));
+        assert!(Cli::try_parse_from(["wmgr", "doctor", "--force"]).is_err());
+    }
+
+    #[test]
+    fn test_jobs_argument_is_validated_at_parse_time() {
+        let cli = Cli::try_parse_from(["wmgr", "sync", "--jobs", "0"]).unwrap();
+        assert!(matches!(cli.command, Commands::Sync { jobs: Some(0), .. }));
+
+        assert!(Cli::try_parse_from(["wmgr", "status", "--jobs", "-2"]).is_err());
+        assert!(Cli::try_parse_from(["wmgr", "push", "--jobs", "abc"]).is_err());
+    }
+}
@@ -0,0 +1,83 @@
+use colored::Colorize;
+use std::io::{self, BufRead, Write};
+
+/// Ask the user to confirm a destructive operation
+///
+/// Returns true without prompting when `assume_yes` is set (`--yes`). When stdin
+/// is not a terminal nobody can answer, so this returns false instead of
+/// blocking; automation has to pass `--yes`.
+pub fn confirm(prompt: &str, assume_yes: bool) -> io::Result<bool> {
+    confirm_with(
+        prompt,
+        assume_yes,
+        atty::is(atty::Stream::Stdin),
+        &mut io::stdin().lock(),
+        &mut io::stdout(),
+    )
+}
+
+/// [`confirm`] with the terminal check and the streams supplied by the caller
+fn confirm_with(
+    prompt: &str,
+    assume_yes: bool,
+    interactive: bool,
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+) -> io::Result<bool> {
+    if assume_yes {
+        return Ok(true);
+    }
+    if !interactive {
+        return Ok(false);
+    }
+
+    write!(output, "{} {} [y/N]: ", "?".yellow().bold(), prompt)?;
+    output.flush()?;
+
+    let mut answer = String::new();
+    input.read_line(&mut answer)?;
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ask(assume_yes: bool, interactive: bool, answer: &str) -> (bool, String) {
+        let mut input = answer.as_bytes();
+        let mut output = Vec::new();
+        let confirmed =
+            confirm_with("Delete?", assume_yes, interactive, &mut input, &mut output).unwrap();
+        (confirmed, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn test_non_tty_declines_without_prompting() {
+        // Input is never read, so a non-interactive run cannot block on it
+        let (confirmed, output) = ask(false, false, "y\n");
+        assert!(!confirmed);
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn test_yes_bypasses_prompt() {
+        for interactive in [false, true] {
+            let (confirmed, output) = ask(true, interactive, "");
+            assert!(confirmed);
+            assert!(output.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_interactive_answers() {
+        assert!(ask(false, true, "y\n").0);
+        assert!(ask(false, true, " YES \n").0);
+        assert!(!ask(false, true, "\n").0);
+        assert!(!ask(false, true, "no\n").0);
+        // EOF is treated as declining
+        assert!(!ask(false, true, "").0);
+
+        let (_, output) = ask(false, true, "n\n");
+        assert!(output.contains("Delete? [y/N]: "));
+    }
+}
@@ -1,3 +1,13 @@
+pub mod confirm;
 pub mod display;
+pub mod progress;
+pub mod repo_picker;
+pub mod sarif;
+pub mod status_table;
+pub mod verbosity;
 
+pub use confirm::confirm;
 pub use display::{helpers, DisplayHelper, StatusType};
+pub use progress::ProgressRenderer;
+pub use status_table::{print_status_summary, untracked_breakdown_summary, StatusTable};
+pub use verbosity::Verbosity;
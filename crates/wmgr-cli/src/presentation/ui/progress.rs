@@ -0,0 +1,332 @@
+use std::collections::HashMap;
+use std::io::Write;
+use std::time::Duration;
+
+use colored::Colorize;
+use indicatif::{MultiProgress, ProgressBar, ProgressDrawTarget, ProgressStyle};
+use tokio::sync::mpsc::UnboundedReceiver;
+use wmgr::common::progress::{ProgressEvent, RepoOutcome};
+
+use super::Verbosity;
+
+/// Renders the progress events of an operation over many repositories
+///
+/// Used by `sync`, `status` and `foreach`/`exec-parallel`. On a terminal each
+/// repository being processed gets a spinner line below an overall bar, and a
+/// line is printed above them as each one finishes. Without a terminal (pipes,
+/// CI logs) every event is printed as a plain line instead, so the output stays
+/// stable and does not interleave.
+pub struct ProgressRenderer {
+    verbosity: Verbosity,
+    output: Output,
+}
+
+enum Output {
+    Lines(Box<dyn Write + Send>),
+    Bars(Bars),
+}
+
+/// Bars drawn while the operation runs
+struct Bars {
+    multi: MultiProgress,
+    overall: ProgressBar,
+    active: HashMap<String, ProgressBar>,
+    /// Repositories of the current batch that have not started yet
+    unstarted: usize,
+}
+
+impl ProgressRenderer {
+    /// Bars when stdout is a terminal and output is not `--quiet`, lines on stdout otherwise
+    pub fn for_stdout(verbosity: Verbosity) -> Self {
+        if uses_bars(verbosity, atty::is(atty::Stream::Stdout)) {
+            Self::bars(verbosity, ProgressDrawTarget::stdout())
+        } else {
+            Self::lines(verbosity, Box::new(std::io::stdout()))
+        }
+    }
+
+    /// Like [`Self::for_stdout`] on stderr, for commands whose stdout is their report
+    pub fn for_stderr(verbosity: Verbosity) -> Self {
+        if uses_bars(verbosity, atty::is(atty::Stream::Stderr)) {
+            Self::bars(verbosity, ProgressDrawTarget::stderr())
+        } else {
+            Self::lines(verbosity, Box::new(std::io::stderr()))
+        }
+    }
+
+    /// Print every event as a line to `writer`
+    pub fn lines(verbosity: Verbosity, writer: Box<dyn Write + Send>) -> Self {
+        Self {
+            verbosity,
+            output: Output::Lines(writer),
+        }
+    }
+
+    /// Draw an overall bar and one spinner per active repository on `target`
+    pub fn bars(verbosity: Verbosity, target: ProgressDrawTarget) -> Self {
+        let multi = MultiProgress::with_draw_target(target);
+        let overall = multi.add(ProgressBar::new(0));
+        overall.set_style(
+            ProgressStyle::default_bar()
+                .template("[{elapsed_precise}] [{wide_bar:.cyan/blue}] {pos}/{len}")
+                .unwrap()
+                .progress_chars("#>-"),
+        );
+        Self {
+            verbosity,
+            output: Output::Bars(Bars {
+                multi,
+                overall,
+                active: HashMap::new(),
+                unstarted: 0,
+            }),
+        }
+    }
+
+    /// Render events until the sender is dropped, then clear the bars
+    pub async fn run(mut self, mut receiver: UnboundedReceiver<ProgressEvent>) {
+        while let Some(event) = receiver.recv().await {
+            self.render(event);
+        }
+        self.finish();
+    }
+
+    /// Render a single event
+    pub fn render(&mut self, event: ProgressEvent) {
+        if self.verbosity.is_quiet() {
+            return;
+        }
+        match &mut self.output {
+            Output::Lines(writer) => {
+                let line = match event {
+                    ProgressEvent::RepoStarted { dest, index, total }
+                        if self.verbosity.is_verbose() =>
+                    {
+                        format!("  {} [{}/{}] {}", "->".blue(), index + 1, total, dest)
+                    }
+                    ProgressEvent::RepoProgress { dest, message }
+                        if self.verbosity.is_verbose() =>
+                    {
+                        format!("     {}", format!("{}: {}", dest, message).dimmed())
+                    }
+                    ProgressEvent::RepoFinished { dest, outcome } => finished_line(&dest, &outcome),
+                    _ => return,
+                };
+                // A closed stdout (e.g. `| head`) must not abort the operation
+                let _ = writeln!(writer, "{}", line);
+            }
+            Output::Bars(bars) => bars.render(event),
+        }
+    }
+
+    /// Remove the bars from the terminal
+    pub fn finish(self) {
+        if let Output::Bars(bars) = self.output {
+            for bar in bars.active.values() {
+                bar.finish_and_clear();
+            }
+            bars.overall.finish_and_clear();
+            let _ = bars.multi.clear();
+        }
+    }
+}
+
+impl Bars {
+    fn render(&mut self, event: ProgressEvent) {
+        match event {
+            ProgressEvent::RepoStarted { dest, total, .. } => {
+                // Parallel runs start repositories in any order, and child
+                // workspaces of a sync start a new batch once the last one started
+                if self.unstarted == 0 {
+                    self.overall.inc_length(total as u64);
+                    self.unstarted = total;
+                }
+                self.unstarted -= 1;
+                let bar = self.multi.add(ProgressBar::new_spinner());
+                bar.set_style(
+                    ProgressStyle::default_spinner()
+                        .tick_strings(&["⠁", "⠂", "⠄", "⡀", "⢀", "⠠", "⠐", "⠈", "✓"])
+                        .template("  {spinner:.green} {prefix} {msg:.dim}")
+                        .unwrap(),
+                );
+                bar.set_prefix(dest.clone());
+                bar.enable_steady_tick(Duration::from_millis(120));
+                self.active.insert(dest, bar);
+            }
+            ProgressEvent::RepoProgress { dest, message } => {
+                if let Some(bar) = self.active.get(&dest) {
+                    bar.set_message(message);
+                }
+            }
+            ProgressEvent::RepoFinished { dest, outcome } => {
+                if let Some(bar) = self.active.remove(&dest) {
+                    bar.finish_and_clear();
+                    self.multi.remove(&bar);
+                }
+                self.overall.inc(1);
+                let _ = self.multi.println(finished_line(&dest, &outcome));
+            }
+        }
+    }
+}
+
+/// Whether progress is drawn as bars rather than printed as lines
+fn uses_bars(verbosity: Verbosity, terminal: bool) -> bool {
+    terminal && !verbosity.is_quiet()
+}
+
+/// The line printed when a repository finishes
+fn finished_line(dest: &str, outcome: &RepoOutcome) -> String {
+    match outcome {
+        RepoOutcome::Failed(_) => format!("  {} {}", "✗".red().bold(), dest),
+        RepoOutcome::Interrupted => format!(
+            "  {} {} {}",
+            "!".yellow().bold(),
+            dest,
+            format!("({})", outcome.as_str()).yellow()
+        ),
+        RepoOutcome::Skipped | RepoOutcome::Resumed | RepoOutcome::Recent => format!(
+            "  {} {} {}",
+            "-".dimmed(),
+            dest,
+            format!("({})", outcome.as_str()).dimmed()
+        ),
+        _ => format!("  {} {} ({})", "✓".green(), dest, outcome.as_str()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    /// Writer whose contents the test can read after the renderer took it
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Buffer {
+        fn text(&self) -> String {
+            console::strip_ansi_codes(&String::from_utf8_lossy(&self.0.lock().unwrap())).to_string()
+        }
+    }
+
+    fn events() -> Vec<ProgressEvent> {
+        let started = |dest: &str, index| ProgressEvent::RepoStarted {
+            dest: dest.to_string(),
+            index,
+            total: 3,
+        };
+        let finished = |dest: &str, outcome| ProgressEvent::RepoFinished {
+            dest: dest.to_string(),
+            outcome,
+        };
+        vec![
+            started("app", 0),
+            ProgressEvent::RepoProgress {
+                dest: "app".to_string(),
+                message: "cloning".to_string(),
+            },
+            started("lib", 1),
+            finished("lib", RepoOutcome::Recent),
+            finished("app", RepoOutcome::Cloned),
+            started("docs", 2),
+            finished("docs", RepoOutcome::Failed("network error".to_string())),
+        ]
+    }
+
+    fn render(verbosity: Verbosity) -> String {
+        let buffer = Buffer::default();
+        let mut renderer = ProgressRenderer::lines(verbosity, Box::new(buffer.clone()));
+        for event in events() {
+            renderer.render(event);
+        }
+        renderer.finish();
+        buffer.text()
+    }
+
+    #[test]
+    fn test_uses_bars_only_on_a_terminal_without_quiet() {
+        assert!(uses_bars(Verbosity::Normal, true));
+        assert!(uses_bars(Verbosity::Verbose, true));
+        assert!(!uses_bars(Verbosity::Quiet, true));
+        assert!(!uses_bars(Verbosity::Normal, false));
+    }
+
+    #[test]
+    fn test_lines_print_one_line_per_finished_repository() {
+        assert_eq!(
+            render(Verbosity::Normal),
+            "  - lib (fetched recently)\n  ✓ app (cloned)\n  ✗ docs\n"
+        );
+    }
+
+    #[test]
+    fn test_verbose_lines_include_started_and_progress_events() {
+        assert_eq!(
+            render(Verbosity::Verbose),
+            concat!(
+                "  -> [1/3] app\n",
+                "     app: cloning\n",
+                "  -> [2/3] lib\n",
+                "  - lib (fetched recently)\n",
+                "  ✓ app (cloned)\n",
+                "  -> [3/3] docs\n",
+                "  ✗ docs\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_bars_count_each_batch_once_whatever_the_start_order() {
+        let mut renderer = ProgressRenderer::bars(Verbosity::Normal, ProgressDrawTarget::hidden());
+        let started = |dest: &str, index, total| ProgressEvent::RepoStarted {
+            dest: dest.to_string(),
+            index,
+            total,
+        };
+        // A parallel batch of three, then a child workspace batch of two
+        for event in [
+            started("lib", 1, 3),
+            started("app", 0, 3),
+            started("docs", 2, 3),
+            started("child/a", 0, 2),
+        ] {
+            renderer.render(event);
+        }
+
+        let Output::Bars(bars) = &renderer.output else {
+            panic!("expected bars");
+        };
+        assert_eq!(bars.overall.length(), Some(5));
+        assert_eq!(bars.active.len(), 4);
+    }
+
+    #[test]
+    fn test_lines_describe_status_and_foreach_outcomes() {
+        let buffer = Buffer::default();
+        let mut renderer = ProgressRenderer::lines(Verbosity::Normal, Box::new(buffer.clone()));
+        let finished = |dest: &str, outcome| ProgressEvent::RepoFinished {
+            dest: dest.to_string(),
+            outcome,
+        };
+        renderer.render(finished("app", RepoOutcome::Checked("dirty".to_string())));
+        renderer.render(finished("lib", RepoOutcome::Succeeded));
+        renderer.finish();
+        assert_eq!(buffer.text(), "  ✓ app (dirty)\n  ✓ lib (succeeded)\n");
+    }
+
+    #[test]
+    fn test_quiet_lines_print_nothing() {
+        assert_eq!(render(Verbosity::Quiet), "");
+    }
+}
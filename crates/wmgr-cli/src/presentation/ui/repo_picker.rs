@@ -0,0 +1,270 @@
+use std::ops::Range;
+
+use wmgr::domain::entities::repo_selector::RepoSelector;
+use wmgr::domain::entities::workspace::Workspace;
+
+/// Repositories offered by the picker: the workspace's configured repositories
+/// that pass the tag filters, in manifest order
+pub fn picker_candidates(
+    workspace: &Workspace,
+    tags: &[String],
+    exclude_tags: &[String],
+) -> anyhow::Result<Vec<String>> {
+    let manifest = workspace
+        .manifest
+        .as_ref()
+        .ok_or_else(|| anyhow::anyhow!("Manifest not loaded"))?;
+
+    Ok(RepoSelector::new(None)
+        .with_tags(tags.to_vec())
+        .with_exclude_tags(exclude_tags.to_vec())
+        .select(manifest, workspace.configured_repos())?
+        .into_iter()
+        .map(|repo| repo.dest.clone())
+        .collect())
+}
+
+/// `--repo` patterns that select exactly the picked repositories
+///
+/// Each dest becomes a glob matching only itself, so the selection goes
+/// through the same target determination as patterns typed on the command line.
+pub fn selection_to_patterns(candidates: &[String], picked: &[usize]) -> Vec<String> {
+    picked
+        .iter()
+        .filter_map(|&index| candidates.get(index))
+        .map(|dest| escape_glob(dest))
+        .collect()
+}
+
+/// Glob pattern that matches `dest` literally
+fn escape_glob(dest: &str) -> String {
+    dest.chars()
+        .map(|c| match c {
+            '*' | '?' | '[' | ']' | '{' | '}' => format!("[{}]", c),
+            _ => c.to_string(),
+        })
+        .collect()
+}
+
+/// Cursor and check marks of the checklist, independent of the terminal
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(not(feature = "interactive"), allow(dead_code))]
+pub struct PickerState {
+    /// Index of the highlighted item
+    pub cursor: usize,
+    /// Whether each item is checked
+    pub checked: Vec<bool>,
+}
+
+#[cfg_attr(not(feature = "interactive"), allow(dead_code))]
+impl PickerState {
+    /// A checklist of `len` unchecked items with the cursor on the first
+    pub fn new(len: usize) -> Self {
+        Self {
+            cursor: 0,
+            checked: vec![false; len],
+        }
+    }
+
+    /// Move the cursor up, wrapping to the last item
+    pub fn move_up(&mut self) {
+        let len = self.checked.len();
+        if len > 0 {
+            self.cursor = (self.cursor + len - 1) % len;
+        }
+    }
+
+    /// Move the cursor down, wrapping to the first item
+    pub fn move_down(&mut self) {
+        let len = self.checked.len();
+        if len > 0 {
+            self.cursor = (self.cursor + 1) % len;
+        }
+    }
+
+    /// Check or uncheck the highlighted item
+    pub fn toggle(&mut self) {
+        if let Some(checked) = self.checked.get_mut(self.cursor) {
+            *checked = !*checked;
+        }
+    }
+
+    /// Check every item, or uncheck them all when all are already checked
+    pub fn toggle_all(&mut self) {
+        let all = self.checked.iter().all(|checked| *checked);
+        self.checked.fill(!all);
+    }
+
+    /// Indices of the checked items, in order
+    pub fn picked(&self) -> Vec<usize> {
+        self.checked
+            .iter()
+            .enumerate()
+            .filter(|(_, checked)| **checked)
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Items to draw when only `height` lines fit, keeping the cursor visible
+    pub fn visible_range(&self, height: usize) -> Range<usize> {
+        let len = self.checked.len();
+        let height = height.max(1).min(len);
+        let start = self
+            .cursor
+            .saturating_sub(height / 2)
+            .min(len.saturating_sub(height));
+        start..start + height
+    }
+}
+
+/// Let the user pick repositories from `candidates` and return `--repo` patterns for them
+///
+/// Fails when stdin or stderr is not a terminal, when the user cancels, and
+/// when nothing was picked.
+pub fn pick_repo_patterns(candidates: &[String]) -> anyhow::Result<Vec<String>> {
+    if candidates.is_empty() {
+        anyhow::bail!("No repositories to pick from");
+    }
+    if !atty::is(atty::Stream::Stdin) || !atty::is(atty::Stream::Stderr) {
+        anyhow::bail!(
+            "--interactive needs a terminal; select repositories with --group or --repo instead"
+        );
+    }
+
+    let picked = match tui::run_checklist(candidates)? {
+        Some(picked) => picked,
+        None => anyhow::bail!("Repository selection cancelled"),
+    };
+    if picked.is_empty() {
+        anyhow::bail!("No repositories selected");
+    }
+    Ok(selection_to_patterns(candidates, &picked))
+}
+
+#[cfg(feature = "interactive")]
+mod tui {
+    use super::PickerState;
+    use colored::Colorize;
+    use console::{Key, Term};
+
+    /// Draw the checklist on stderr until the user confirms (Some) or cancels (None)
+    pub fn run_checklist(items: &[String]) -> std::io::Result<Option<Vec<usize>>> {
+        let term = Term::stderr();
+        let mut state = PickerState::new(items.len());
+        // Leave room for the help line and the shell prompt
+        let height = (term.size().0 as usize).saturating_sub(2).max(1);
+
+        term.hide_cursor()?;
+        let result = (|| loop {
+            let range = state.visible_range(height);
+            let lines = range.len() + 1;
+            term.write_line(&format!(
+                "{} Select repositories {}",
+                "?".yellow().bold(),
+                "(space: toggle, a: all, enter: confirm, esc: cancel)".dimmed()
+            ))?;
+            for index in range {
+                let mark = if state.checked[index] { "[x]" } else { "[ ]" };
+                let line = format!("{} {}", mark, items[index]);
+                if index == state.cursor {
+                    term.write_line(&format!("{} {}", ">".cyan().bold(), line.bold()))?;
+                } else {
+                    term.write_line(&format!("  {}", line))?;
+                }
+            }
+
+            let key = term.read_key();
+            term.clear_last_lines(lines)?;
+            match key? {
+                Key::ArrowUp | Key::Char('k') => state.move_up(),
+                Key::ArrowDown | Key::Char('j') => state.move_down(),
+                Key::Char(' ') => state.toggle(),
+                Key::Char('a') => state.toggle_all(),
+                Key::Enter => return Ok(Some(state.picked())),
+                Key::Escape | Key::Char('q') => return Ok(None),
+                _ => {}
+            }
+        })();
+        // Restore the cursor even when reading from the terminal failed
+        term.show_cursor()?;
+
+        result
+    }
+}
+
+#[cfg(not(feature = "interactive"))]
+mod tui {
+    /// Without the `interactive` feature there is no picker to run
+    pub fn run_checklist(_items: &[String]) -> anyhow::Result<Option<Vec<usize>>> {
+        anyhow::bail!("wmgr was built without the `interactive` feature")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wmgr::domain::entities::manifest::{Manifest, ManifestRepo};
+
+    fn dests(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_picked_repositories_become_exact_patterns() {
+        let candidates = dests(&["app", "libs/core", "libs/core-extra", "weird[1]*"]);
+        let patterns = selection_to_patterns(&candidates, &[1, 3]);
+        assert_eq!(patterns, vec!["libs/core", "weird[[]1[]][*]"]);
+
+        // The patterns select exactly the picked repositories, nothing that merely matches a glob
+        let repos: Vec<ManifestRepo> = candidates
+            .iter()
+            .map(|dest| ManifestRepo::new(format!("https://example.com/{}.git", dest), dest))
+            .collect();
+        let manifest = Manifest::new(repos);
+        let selected: Vec<&str> = RepoSelector::new(None)
+            .with_repo_patterns(patterns)
+            .select(&manifest, manifest.repos.iter().collect())
+            .unwrap()
+            .into_iter()
+            .map(|repo| repo.dest.as_str())
+            .collect();
+        assert_eq!(selected, vec!["libs/core", "weird[1]*"]);
+    }
+
+    #[test]
+    fn test_out_of_range_picks_are_ignored() {
+        let candidates = dests(&["app"]);
+        assert_eq!(selection_to_patterns(&candidates, &[0, 5]), vec!["app"]);
+        assert!(selection_to_patterns(&candidates, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_picker_state_navigation_and_toggling() {
+        let mut state = PickerState::new(3);
+        state.move_up();
+        assert_eq!(state.cursor, 2);
+        state.toggle();
+        state.move_down();
+        assert_eq!(state.cursor, 0);
+        state.toggle();
+        assert_eq!(state.picked(), vec![0, 2]);
+
+        state.toggle_all();
+        assert_eq!(state.picked(), vec![0, 1, 2]);
+        state.toggle_all();
+        assert!(state.picked().is_empty());
+    }
+
+    #[test]
+    fn test_visible_range_follows_cursor() {
+        let mut state = PickerState::new(10);
+        assert_eq!(state.visible_range(4), 0..4);
+        assert_eq!(state.visible_range(20), 0..10);
+
+        state.cursor = 5;
+        assert_eq!(state.visible_range(4), 3..7);
+
+        state.cursor = 9;
+        assert_eq!(state.visible_range(4), 6..10);
+    }
+}
@@ -0,0 +1,179 @@
+use serde_json::{json, Value};
+
+use wmgr::application::services::security_service::VulnerabilitySeverity;
+use wmgr::application::use_cases::security_audit::AuditFinding;
+
+/// SARIF schema the generated log conforms to
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// SARIF specification version
+const SARIF_VERSION: &str = "2.1.0";
+
+/// Build a SARIF 2.1.0 log with one run whose results are the audit findings
+///
+/// Each distinct advisory becomes a rule; each finding becomes a result located
+/// at the repository's dependency file (e.g. `api/Cargo.lock`).
+pub fn audit_findings_to_sarif(findings: &[AuditFinding]) -> Value {
+    let mut rule_ids: Vec<&str> = Vec::new();
+    let mut rules = Vec::new();
+    for finding in findings {
+        if rule_ids.contains(&finding.advisory_id.as_str()) {
+            continue;
+        }
+        rule_ids.push(&finding.advisory_id);
+        rules.push(rule(finding));
+    }
+
+    let results: Vec<Value> = findings
+        .iter()
+        .map(|finding| result(finding, rule_index(&rule_ids, &finding.advisory_id)))
+        .collect();
+
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": SARIF_VERSION,
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "wmgr",
+                    "informationUri": env!("CARGO_PKG_REPOSITORY"),
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules,
+                }
+            },
+            "results": results,
+        }]
+    })
+}
+
+fn rule_index(rule_ids: &[&str], advisory_id: &str) -> usize {
+    rule_ids
+        .iter()
+        .position(|id| *id == advisory_id)
+        .unwrap_or_default()
+}
+
+fn rule(finding: &AuditFinding) -> Value {
+    let mut rule = json!({
+        "id": finding.advisory_id,
+        "shortDescription": { "text": finding.description },
+        "defaultConfiguration": { "level": level(&finding.severity) },
+        "properties": {
+            "security-severity": security_severity(&finding.severity),
+            "tags": ["security", finding.ecosystem.name()],
+        },
+    });
+    if let Some(url) = &finding.url {
+        rule["helpUri"] = json!(url);
+    }
+    rule
+}
+
+fn result(finding: &AuditFinding, rule_index: usize) -> Value {
+    let mut message = format!(
+        "{} {} is affected by {}: {}",
+        finding.package, finding.version, finding.advisory_id, finding.description
+    );
+    if !finding.patched_versions.is_empty() {
+        message.push_str(&format!(
+            " (patched: {})",
+            finding.patched_versions.join(", ")
+        ));
+    }
+
+    let uri = finding
+        .manifest_path
+        .clone()
+        .unwrap_or_else(|| finding.repo.clone());
+
+    json!({
+        "ruleId": finding.advisory_id,
+        "ruleIndex": rule_index,
+        "level": level(&finding.severity),
+        "message": { "text": message },
+        "locations": [{
+            "physicalLocation": {
+                "artifactLocation": { "uri": uri }
+            }
+        }],
+        "properties": {
+            "repository": finding.repo,
+            "package": finding.package,
+            "version": finding.version,
+            "severity": finding.severity,
+        },
+    })
+}
+
+/// SARIF result level for a vulnerability severity
+fn level(severity: &VulnerabilitySeverity) -> &'static str {
+    match severity {
+        VulnerabilitySeverity::Critical | VulnerabilitySeverity::High => "error",
+        VulnerabilitySeverity::Medium => "warning",
+        VulnerabilitySeverity::Low => "note",
+    }
+}
+
+/// Numeric score used by code scanning UIs to rank security results
+fn security_severity(severity: &VulnerabilitySeverity) -> &'static str {
+    match severity {
+        VulnerabilitySeverity::Critical => "9.5",
+        VulnerabilitySeverity::High => "8.0",
+        VulnerabilitySeverity::Medium => "5.5",
+        VulnerabilitySeverity::Low => "2.0",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wmgr::application::services::security_service::AuditEcosystem;
+
+    fn finding(repo: &str, id: &str, severity: VulnerabilitySeverity) -> AuditFinding {
+        AuditFinding {
+            repo: repo.to_string(),
+            ecosystem: AuditEcosystem::Cargo,
+            manifest_path: Some(format!("{}/Cargo.lock", repo)),
+            package: "time".to_string(),
+            version: "0.1.0".to_string(),
+            advisory_id: id.to_string(),
+            severity,
+            description: "Potential segfault".to_string(),
+            url: None,
+            patched_versions: vec![">=0.2.23".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_rules_are_shared_between_results() {
+        let findings = vec![
+            finding("api", "RUSTSEC-2020-0071", VulnerabilitySeverity::High),
+            finding("web", "RUSTSEC-2020-0071", VulnerabilitySeverity::High),
+            finding("web", "RUSTSEC-2021-0001", VulnerabilitySeverity::Low),
+        ];
+
+        let sarif = audit_findings_to_sarif(&findings);
+        let run = &sarif["runs"][0];
+
+        assert_eq!(sarif["version"], "2.1.0");
+        assert_eq!(run["tool"]["driver"]["rules"].as_array().unwrap().len(), 2);
+        assert_eq!(run["results"].as_array().unwrap().len(), 3);
+        assert_eq!(run["results"][1]["ruleIndex"], 0);
+        assert_eq!(run["results"][2]["ruleIndex"], 1);
+        assert_eq!(run["results"][2]["level"], "note");
+        assert_eq!(
+            run["results"][1]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "web/Cargo.lock"
+        );
+    }
+
+    #[test]
+    fn test_empty_findings() {
+        let sarif = audit_findings_to_sarif(&[]);
+        assert!(sarif["runs"][0]["results"].as_array().unwrap().is_empty());
+        assert!(sarif["runs"][0]["tool"]["driver"]["rules"]
+            .as_array()
+            .unwrap()
+            .is_empty());
+    }
+}
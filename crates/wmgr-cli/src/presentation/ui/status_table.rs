@@ -0,0 +1,310 @@
+use colored::{ColoredString, Colorize};
+use console::measure_text_width;
+
+use wmgr::application::use_cases::status_check::{RepositoryState, RepositoryStatus, StatusResult};
+
+/// Separator between table columns
+const COLUMN_GAP: &str = "  ";
+
+/// Renders repository statuses as an aligned table
+pub struct StatusTable {
+    use_color: bool,
+    show_branch: bool,
+    show_commits_since: bool,
+}
+
+impl StatusTable {
+    /// Create a new table renderer
+    pub fn new(use_color: bool, show_branch: bool) -> Self {
+        Self {
+            use_color,
+            show_branch,
+            show_commits_since: false,
+        }
+    }
+
+    /// Add a column with the number of commits after `--changed-since`
+    pub fn with_commits_since(mut self, show_commits_since: bool) -> Self {
+        self.show_commits_since = show_commits_since;
+        self
+    }
+
+    /// Render the header and one line per repository
+    pub fn render(&self, repositories: &[RepositoryStatus]) -> Vec<String> {
+        let mut headers = vec!["STATE", "REPOSITORY"];
+        if self.show_branch {
+            headers.push("BRANCH");
+        }
+        headers.push("CHANGES");
+        if self.show_commits_since {
+            headers.push("SINCE");
+        }
+
+        let rows: Vec<Vec<String>> = repositories.iter().map(|r| self.cells(r)).collect();
+
+        // Column widths are computed from the plain text so color codes never skew alignment
+        let mut widths: Vec<usize> = headers.iter().map(|h| measure_text_width(h)).collect();
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(measure_text_width(cell));
+            }
+        }
+
+        let header_cells: Vec<String> = headers.iter().map(|h| h.to_string()).collect();
+        let mut lines =
+            vec![self.render_row(&header_cells, &widths, |_, text| text.bold().dimmed())];
+
+        for (repo, row) in repositories.iter().zip(&rows) {
+            lines.push(self.render_row(row, &widths, |column, text| match column {
+                0 => Self::state_color(&repo.state, text),
+                1 => text.bold(),
+                _ if self.show_branch && column == 2 => text.blue(),
+                _ => text.normal(),
+            }));
+        }
+
+        lines
+    }
+
+    /// Plain text cells for a repository
+    fn cells(&self, repo: &RepositoryStatus) -> Vec<String> {
+        let mut cells = vec![
+            Self::state_label(&repo.state).to_string(),
+            repo.dest.clone(),
+        ];
+        if self.show_branch {
+            cells.push(
+                repo.current_branch
+                    .clone()
+                    .unwrap_or_else(|| "-".to_string()),
+            );
+        }
+        cells.push(Self::changes_summary(repo));
+        if self.show_commits_since {
+            // `-` when the reference does not exist in the repository or it is not git
+            cells.push(
+                repo.commits_since
+                    .map(|count| format!("+{}", count))
+                    .unwrap_or_else(|| "-".to_string()),
+            );
+        }
+        cells
+    }
+
+    /// Pad each cell to its column width, then apply color
+    fn render_row<F>(&self, cells: &[String], widths: &[usize], style: F) -> String
+    where
+        F: Fn(usize, &str) -> ColoredString,
+    {
+        let last = cells.len().saturating_sub(1);
+        cells
+            .iter()
+            .enumerate()
+            .map(|(column, cell)| {
+                // The last column is not padded to avoid trailing whitespace
+                let padding = if column == last {
+                    0
+                } else {
+                    widths[column].saturating_sub(measure_text_width(cell))
+                };
+                let text = if self.use_color {
+                    style(column, cell).to_string()
+                } else {
+                    cell.clone()
+                };
+                format!("{}{}", text, " ".repeat(padding))
+            })
+            .collect::<Vec<_>>()
+            .join(COLUMN_GAP)
+    }
+
+    fn state_label(state: &RepositoryState) -> &'static str {
+        match state {
+            RepositoryState::Clean => "clean",
+            RepositoryState::Dirty => "dirty",
+            RepositoryState::Missing => "missing",
+            RepositoryState::WrongBranch => "wrong branch",
+            RepositoryState::OutOfSync => "out of sync",
+            RepositoryState::Error => "error",
+        }
+    }
+
+    fn state_color(state: &RepositoryState, text: &str) -> ColoredString {
+        match state {
+            RepositoryState::Clean => text.green(),
+            RepositoryState::Dirty => text.yellow(),
+            RepositoryState::Missing => text.red(),
+            RepositoryState::WrongBranch => text.cyan(),
+            RepositoryState::OutOfSync => text.magenta(),
+            RepositoryState::Error => text.red(),
+        }
+    }
+
+    /// Change counts such as `2M 1S 3U`, or `-` when there is nothing to report
+    fn changes_summary(repo: &RepositoryStatus) -> String {
+        let mut changes = Vec::new();
+        if repo.modified_files > 0 {
+            changes.push(format!("{}M", repo.modified_files));
+        }
+        if repo.staged_files > 0 {
+            changes.push(format!("{}S", repo.staged_files));
+        }
+        if repo.untracked_files > 0 {
+            changes.push(format!("{}U", repo.untracked_files));
+        }
+        if changes.is_empty() {
+            "-".to_string()
+        } else {
+            changes.join(" ")
+        }
+    }
+}
+
+/// Untracked file counts per kind such as `build/ 3, *.log 1`, largest first
+///
+/// Returns `None` when the status was checked without `--untracked-summary`
+/// or the repository has no untracked files.
+pub fn untracked_breakdown_summary(repo: &RepositoryStatus) -> Option<String> {
+    let mut counts: Vec<(&String, &usize)> = repo.untracked_breakdown.as_ref()?.iter().collect();
+    counts.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+    Some(
+        counts
+            .iter()
+            .map(|(kind, count)| format!("{} {}", kind, count))
+            .collect::<Vec<_>>()
+            .join(", "),
+    )
+}
+
+/// Print the per-state counts after the text status, e.g. `12 clean, 2 dirty, 1 missing`
+pub fn print_status_summary(status: &StatusResult) {
+    if !status.repositories.is_empty() {
+        println!();
+        println!("{}", status.summary);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wmgr::domain::value_objects::scm_type::ScmType;
+
+    fn repo(dest: &str, state: RepositoryState) -> RepositoryStatus {
+        let mut status = RepositoryStatus::new(dest.to_string(), ScmType::Git);
+        status.state = state;
+        status
+    }
+
+    /// Byte offset at which each column starts, based on the header line
+    fn column_starts(header: &str, names: &[&str]) -> Vec<usize> {
+        names
+            .iter()
+            .map(|name| header.find(name).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_untracked_breakdown_summary_lists_largest_first() {
+        let mut dirty = repo("app", RepositoryState::Dirty);
+        assert_eq!(untracked_breakdown_summary(&dirty), None);
+
+        dirty.untracked_breakdown = Some(
+            [("*.log", 1), ("build/", 3), ("src/", 1)]
+                .into_iter()
+                .map(|(kind, count)| (kind.to_string(), count))
+                .collect(),
+        );
+        assert_eq!(
+            untracked_breakdown_summary(&dirty).unwrap(),
+            "build/ 3, *.log 1, src/ 1"
+        );
+    }
+
+    #[test]
+    fn test_columns_align_for_varying_dest_lengths() {
+        let mut dirty = repo("a-much-longer-repository-name", RepositoryState::Dirty);
+        dirty.modified_files = 2;
+        dirty.untracked_files = 1;
+        let repositories = vec![
+            repo("ui", RepositoryState::Clean),
+            dirty,
+            repo("lib/core", RepositoryState::WrongBranch),
+        ];
+
+        let lines = StatusTable::new(false, false).render(&repositories);
+        assert_eq!(lines.len(), 4);
+
+        let starts = column_starts(&lines[0], &["STATE", "REPOSITORY", "CHANGES"]);
+        let dest_width = "a-much-longer-repository-name".len();
+        assert_eq!(starts[1], "wrong branch".len() + COLUMN_GAP.len());
+        assert_eq!(starts[2], starts[1] + dest_width + COLUMN_GAP.len());
+
+        assert_eq!(&lines[1][starts[1]..starts[1] + 2], "ui");
+        assert_eq!(&lines[2][starts[2]..], "2M 1U");
+        assert_eq!(&lines[3][starts[1]..starts[1] + 8], "lib/core");
+        assert!(lines[1].starts_with("clean "));
+        assert!(lines[1].ends_with(" -"));
+    }
+
+    #[test]
+    fn test_branch_column() {
+        let mut main = repo("frontend", RepositoryState::Clean);
+        main.current_branch = Some("main".to_string());
+        let mut feature = repo("api", RepositoryState::Clean);
+        feature.current_branch = Some("feature/long-branch".to_string());
+
+        let lines = StatusTable::new(false, true).render(&[main, feature]);
+        let starts = column_starts(&lines[0], &["STATE", "REPOSITORY", "BRANCH", "CHANGES"]);
+
+        assert_eq!(&lines[1][starts[2]..starts[2] + 4], "main");
+        assert_eq!(
+            &lines[2][starts[2]..starts[3] - COLUMN_GAP.len()],
+            "feature/long-branch"
+        );
+        assert_eq!(&lines[1][starts[3]..], "-");
+    }
+
+    #[test]
+    fn test_no_trailing_whitespace() {
+        let lines = StatusTable::new(false, false).render(&[
+            repo("short", RepositoryState::Missing),
+            repo("a-longer-name", RepositoryState::Clean),
+        ]);
+        for line in lines {
+            assert_eq!(line, line.trim_end());
+        }
+    }
+
+    #[test]
+    fn test_color_does_not_affect_alignment() {
+        colored::control::set_override(true);
+        let repositories = vec![
+            repo("x", RepositoryState::Error),
+            repo("much-longer-dest", RepositoryState::Clean),
+        ];
+
+        let plain = StatusTable::new(false, false).render(&repositories);
+        let colored = StatusTable::new(true, false).render(&repositories);
+
+        assert_ne!(plain, colored);
+        for (plain_line, colored_line) in plain.iter().zip(&colored) {
+            assert_eq!(&console::strip_ansi_codes(colored_line), plain_line);
+        }
+    }
+
+    #[test]
+    fn test_commits_since_column() {
+        let mut advanced = repo("advanced", RepositoryState::Clean);
+        advanced.commits_since = Some(2);
+        let untagged = repo("untagged", RepositoryState::Clean);
+
+        let lines = StatusTable::new(false, false)
+            .with_commits_since(true)
+            .render(&[advanced, untagged]);
+        let starts = column_starts(&lines[0], &["STATE", "REPOSITORY", "CHANGES", "SINCE"]);
+
+        assert_eq!(&lines[1][starts[3]..], "+2");
+        assert_eq!(&lines[2][starts[3]..], "-");
+        assert!(!StatusTable::new(false, false).render(&[])[0].contains("SINCE"));
+    }
+}
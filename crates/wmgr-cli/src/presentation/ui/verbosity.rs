@@ -0,0 +1,53 @@
+/// How much informational output a command prints
+///
+/// Errors and output the user explicitly asked for (status tables, dumped
+/// manifests, logs) are printed at every level; only progress lines such as
+/// `:: Synchronizing repositories...` and `✓ ... completed!` are suppressed
+/// by `--quiet`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Verbosity {
+    /// Only errors and requested output (`--quiet`)
+    Quiet,
+    /// Progress and summary lines (default)
+    #[default]
+    Normal,
+    /// Additional detail (`--verbose`)
+    Verbose,
+}
+
+impl Verbosity {
+    /// Build the level from the global `--quiet` and `--verbose` flags
+    pub fn from_flags(quiet: bool, verbose: bool) -> Self {
+        if quiet {
+            Self::Quiet
+        } else if verbose {
+            Self::Verbose
+        } else {
+            Self::Normal
+        }
+    }
+
+    /// Whether informational output is suppressed
+    pub fn is_quiet(self) -> bool {
+        self == Self::Quiet
+    }
+
+    /// Whether detailed output is enabled
+    pub fn is_verbose(self) -> bool {
+        self == Self::Verbose
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_flags() {
+        assert_eq!(Verbosity::from_flags(false, false), Verbosity::Normal);
+        assert_eq!(Verbosity::from_flags(false, true), Verbosity::Verbose);
+        assert_eq!(Verbosity::from_flags(true, false), Verbosity::Quiet);
+        assert!(Verbosity::Quiet.is_quiet() && !Verbosity::Quiet.is_verbose());
+        assert!(!Verbosity::Normal.is_quiet() && !Verbosity::Normal.is_verbose());
+    }
+}
@@ -0,0 +1,87 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn commit(repo: &git2::Repository, name: &str) -> git2::Oid {
+    let workdir = repo.workdir().unwrap().to_path_buf();
+    fs::write(workdir.join(name), name).unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new(name)).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+    let parents = match repo.head() {
+        Ok(head) => vec![head.peel_to_commit().unwrap()],
+        Err(_) => vec![],
+    };
+    let parents: Vec<&git2::Commit> = parents.iter().collect();
+    repo.commit(Some("HEAD"), &sig, &sig, name, &tree, &parents)
+        .unwrap()
+}
+
+/// A workspace with a git repository `app` on `main` whose `origin` is one
+/// commit behind and whose `upstream` is one commit ahead
+fn workspace() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("wmgr.yml"),
+        "repos:\n  - dest: app\n    url: https://example.com/app.git\n",
+    )
+    .unwrap();
+    let repo = git2::Repository::init(temp_dir.path().join("app")).unwrap();
+    repo.set_head("refs/heads/main").unwrap();
+    let first = commit(&repo, "a.txt");
+    let second = commit(&repo, "b.txt");
+    let third = commit(&repo, "c.txt");
+
+    for (remote, oid) in [("origin", first), ("upstream", third)] {
+        repo.remote(remote, &format!("https://example.com/{}.git", remote))
+            .unwrap();
+        repo.reference(&format!("refs/remotes/{}/main", remote), oid, false, "test")
+            .unwrap();
+    }
+    repo.reference("refs/heads/main", second, true, "test")
+        .unwrap();
+    repo.checkout_head(Some(git2::build::CheckoutBuilder::new().force()))
+        .unwrap();
+    temp_dir
+}
+
+fn status_json(workspace: &TempDir, remote: &str) -> serde_json::Value {
+    let output = Command::new(env!("CARGO_BIN_EXE_wmgr"))
+        .args(["--no-color", "status", "--output", "json"])
+        .args(["--ahead-behind-remote", remote])
+        .current_dir(workspace.path())
+        .env_remove("RUST_LOG")
+        .output()
+        .expect("failed to run wmgr");
+    assert!(output.status.success(), "{:?}", output);
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    json["repositories"][0].clone()
+}
+
+#[test]
+fn test_ahead_behind_against_each_remote() {
+    let workspace = workspace();
+
+    let app = status_json(&workspace, "origin");
+    assert_eq!(app["ahead_behind_remote"], "origin");
+    assert_eq!(app["commits_ahead"], 1);
+    assert_eq!(app["commits_behind"], 0);
+
+    let app = status_json(&workspace, "upstream");
+    assert_eq!(app["ahead_behind_remote"], "upstream");
+    assert_eq!(app["commits_ahead"], 0);
+    assert_eq!(app["commits_behind"], 1);
+}
+
+#[test]
+fn test_unknown_remote_is_reported() {
+    let workspace = workspace();
+
+    let app = status_json(&workspace, "fork");
+    assert_eq!(app["state"], "Error");
+    assert_eq!(app["error_message"], "Remote not found: fork");
+    assert!(app.get("ahead_behind_remote").is_none());
+}
@@ -0,0 +1,169 @@
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+/// A cargo-audit style report with one high and one low advisory
+const CARGO_AUDIT_REPORT: &str = r#"{
+  "vulnerabilities": {
+    "found": true,
+    "count": 2,
+    "list": [
+      {
+        "advisory": {
+          "id": "RUSTSEC-2020-0071",
+          "title": "Potential segfault in the time crate",
+          "url": "https://rustsec.org/advisories/RUSTSEC-2020-0071",
+          "cvss": {"severity": "high"}
+        },
+        "package": {"name": "time", "version": "0.1.45"},
+        "versions": {"patched": [">=0.2.23"]}
+      },
+      {
+        "advisory": {
+          "id": "RUSTSEC-2021-0139",
+          "title": "ansi_term is unmaintained",
+          "cvss": {"severity": "low"}
+        },
+        "package": {"name": "ansi_term", "version": "0.12.1"}
+      }
+    ]
+  }
+}"#;
+
+/// Write an audit tool that prints `report.json` when the project has one
+fn fake_audit_tool(dir: &Path) -> String {
+    let path = dir.join("fake-cargo-audit");
+    fs::write(
+        &path,
+        "#!/bin/sh\n[ -f report.json ] || { echo '{\"vulnerabilities\":{\"list\":[]}}'; exit 0; }\ncat report.json\nexit 1\n",
+    )
+    .unwrap();
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+    path.display().to_string()
+}
+
+/// A workspace with a vulnerable Rust repository `api`, a clean Rust
+/// repository `core` and a repository `docs` without a recognized ecosystem
+fn workspace(tools: &TempDir) -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("wmgr.yml"),
+        format!(
+            "repos:\n  - dest: api\n    url: https://example.com/api.git\n  - dest: core\n    url: https://example.com/core.git\n  - dest: docs\n    url: https://example.com/docs.git\naudit_commands:\n  cargo: {}\n",
+            fake_audit_tool(tools.path())
+        ),
+    )
+    .unwrap();
+    for (dest, file) in [
+        ("api", "Cargo.lock"),
+        ("core", "Cargo.toml"),
+        ("docs", "README.md"),
+    ] {
+        fs::create_dir_all(temp_dir.path().join(dest)).unwrap();
+        fs::write(temp_dir.path().join(dest).join(file), "").unwrap();
+    }
+    fs::write(temp_dir.path().join("api/Cargo.toml"), "").unwrap();
+    fs::write(temp_dir.path().join("api/report.json"), CARGO_AUDIT_REPORT).unwrap();
+    temp_dir
+}
+
+fn audit(workspace: &TempDir, output: &str, extra: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_wmgr"))
+        .args(["--no-color", "audit", "--output", output])
+        .args(extra)
+        .current_dir(workspace.path())
+        .env_remove("RUST_LOG")
+        .output()
+        .expect("failed to run wmgr")
+}
+
+#[test]
+fn test_audit_json_report() {
+    let tools = TempDir::new().unwrap();
+    let workspace = workspace(&tools);
+
+    let output = audit(&workspace, "json", &["--continue-on-vulnerabilities"]);
+    assert!(output.status.success(), "{:?}", output);
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    assert_eq!(report["exit_code"], 0);
+    assert_eq!(report["summary"]["total"], 3);
+    assert_eq!(report["summary"]["audited"], 2);
+    assert_eq!(report["summary"]["skipped"], 1);
+    assert_eq!(report["summary"]["vulnerable"], 1);
+    assert_eq!(report["summary"]["vulnerabilities"]["high"], 1);
+    assert_eq!(report["summary"]["vulnerabilities"]["low"], 1);
+
+    let repositories = report["repositories"].as_array().unwrap();
+    let docs = repositories.iter().find(|r| r["dest"] == "docs").unwrap();
+    assert_eq!(docs["status"], "skipped");
+    assert!(docs["ecosystem"].is_null());
+    let core = repositories.iter().find(|r| r["dest"] == "core").unwrap();
+    assert_eq!(core["status"], "audited");
+    assert_eq!(core["ecosystem"], "cargo");
+
+    let findings = report["findings"].as_array().unwrap();
+    assert_eq!(findings.len(), 2);
+    assert_eq!(findings[0]["repo"], "api");
+    assert_eq!(findings[0]["ecosystem"], "cargo");
+    assert_eq!(findings[0]["manifest_path"], "api/Cargo.lock");
+    assert_eq!(findings[0]["package"], "time");
+    assert_eq!(findings[0]["version"], "0.1.45");
+    assert_eq!(findings[0]["advisory_id"], "RUSTSEC-2020-0071");
+    assert_eq!(findings[0]["severity"], "high");
+    assert_eq!(findings[0]["patched_versions"][0], ">=0.2.23");
+    assert_eq!(findings[1]["advisory_id"], "RUSTSEC-2021-0139");
+    assert_eq!(findings[1]["severity"], "low");
+}
+
+#[test]
+fn test_audit_sarif_log() {
+    let tools = TempDir::new().unwrap();
+    let workspace = workspace(&tools);
+
+    let output = audit(&workspace, "sarif", &[]);
+    // High severity findings still fail the audit
+    assert_eq!(output.status.code(), Some(1), "{:?}", output);
+    let sarif: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    assert_eq!(sarif["version"], "2.1.0");
+    let run = &sarif["runs"][0];
+    assert_eq!(run["tool"]["driver"]["name"], "wmgr");
+    let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+    assert_eq!(rules.len(), 2);
+    assert_eq!(rules[0]["id"], "RUSTSEC-2020-0071");
+    assert_eq!(
+        rules[0]["helpUri"],
+        "https://rustsec.org/advisories/RUSTSEC-2020-0071"
+    );
+
+    let results = run["results"].as_array().unwrap();
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0]["ruleId"], "RUSTSEC-2020-0071");
+    assert_eq!(results[0]["level"], "error");
+    assert_eq!(results[1]["level"], "note");
+    assert_eq!(
+        results[0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+        "api/Cargo.lock"
+    );
+    assert_eq!(results[0]["properties"]["repository"], "api");
+}
+
+#[test]
+fn test_audit_json_without_auditable_projects() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("wmgr.yml"),
+        "repos:\n  - dest: docs\n    url: https://example.com/docs.git\n",
+    )
+    .unwrap();
+    fs::create_dir_all(temp_dir.path().join("docs")).unwrap();
+
+    let output = audit(&temp_dir, "json", &[]);
+    assert!(output.status.success(), "{:?}", output);
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["summary"]["total"], 0);
+    assert!(report["findings"].as_array().unwrap().is_empty());
+}
@@ -0,0 +1,95 @@
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+/// A workspace with the committed git repositories `app` and `lib` on `main`;
+/// only `app` has a `feature` branch
+fn workspace() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let mut manifest = String::from("repos:\n");
+    for dest in ["app", "lib"] {
+        let repo = git2::Repository::init(temp_dir.path().join(dest)).unwrap();
+        repo.set_head("refs/heads/main").unwrap();
+        fs::write(temp_dir.path().join(dest).join("README"), dest).unwrap();
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new("README")).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+        let commit = repo
+            .commit(Some("HEAD"), &sig, &sig, "initial", &tree, &[])
+            .unwrap();
+        if dest == "app" {
+            repo.branch("feature", &repo.find_commit(commit).unwrap(), false)
+                .unwrap();
+        }
+        manifest.push_str(&format!(
+            "  - dest: {}\n    url: https://example.com/{}.git\n",
+            dest, dest
+        ));
+    }
+    fs::write(temp_dir.path().join("wmgr.yml"), manifest).unwrap();
+    temp_dir
+}
+
+fn checkout(workspace: &TempDir, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_wmgr"))
+        .arg("--no-color")
+        .arg("checkout")
+        .args(args)
+        .current_dir(workspace.path())
+        .env_remove("RUST_LOG")
+        .output()
+        .expect("failed to run wmgr")
+}
+
+fn current_branch(workspace: &TempDir, dest: &str) -> String {
+    let repo = git2::Repository::open(workspace.path().join(dest)).unwrap();
+    let head = repo.head().unwrap();
+    head.shorthand().unwrap().to_string()
+}
+
+#[test]
+fn test_checkout_existing_branch() {
+    let workspace = workspace();
+    let output = checkout(&workspace, &["feature"]);
+
+    assert!(output.status.success(), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("app switched"), "{}", stdout);
+    assert!(
+        stdout.contains("lib skipped: Branch 'feature' not found"),
+        "{}",
+        stdout
+    );
+    assert_eq!(current_branch(&workspace, "app"), "feature");
+    assert_eq!(current_branch(&workspace, "lib"), "main");
+}
+
+#[test]
+fn test_checkout_create() {
+    let workspace = workspace();
+    let output = checkout(&workspace, &["feature", "--create"]);
+
+    assert!(output.status.success(), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("lib created"), "{}", stdout);
+    assert_eq!(current_branch(&workspace, "app"), "feature");
+    assert_eq!(current_branch(&workspace, "lib"), "feature");
+}
+
+#[test]
+fn test_checkout_refuses_dirty_repository() {
+    let workspace = workspace();
+    fs::write(workspace.path().join("app/README"), "local change").unwrap();
+    let output = checkout(&workspace, &["feature"]);
+
+    assert!(!output.status.success(), "{:?}", output);
+    assert!(String::from_utf8_lossy(&output.stdout).contains("app has uncommitted changes"));
+    assert_eq!(current_branch(&workspace, "app"), "main");
+
+    let output = checkout(&workspace, &["feature", "--force"]);
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(current_branch(&workspace, "app"), "feature");
+}
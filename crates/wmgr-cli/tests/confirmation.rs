@@ -0,0 +1,139 @@
+use std::fs;
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+const CURRENT: &str = "repos:\n  - dest: app\n    url: https://github.com/example/app.git\n  - dest: legacy\n    url: https://github.com/example/legacy.git\n";
+const NEW: &str = "repos:\n  - dest: app\n    url: https://github.com/example/app.git\n";
+
+/// Run `wmgr apply-manifest new.yaml` without a terminal on stdin
+fn apply_manifest(extra_args: &[&str]) -> (TempDir, Output) {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("wmgr.yaml"), CURRENT).unwrap();
+    fs::write(temp_dir.path().join("new.yaml"), NEW).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_wmgr"))
+        .args(["--no-backup", "apply-manifest", "new.yaml"])
+        .args(extra_args)
+        .current_dir(temp_dir.path())
+        .env("NO_COLOR", "1")
+        .output()
+        .expect("failed to run wmgr");
+    (temp_dir, output)
+}
+
+#[test]
+fn test_destructive_apply_without_tty_requires_yes() {
+    // stdin is not a terminal, so the command fails instead of waiting for an answer
+    let (temp_dir, output) = apply_manifest(&[]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--yes"));
+    assert_eq!(
+        fs::read_to_string(temp_dir.path().join("wmgr.yaml")).unwrap(),
+        CURRENT
+    );
+}
+
+#[test]
+fn test_yes_applies_without_prompting() {
+    for flag in ["--yes", "-y", "--force"] {
+        let (temp_dir, output) = apply_manifest(&[flag]);
+        assert!(output.status.success(), "{} failed: {:?}", flag, output);
+        let applied = fs::read_to_string(temp_dir.path().join("wmgr.yaml")).unwrap();
+        assert!(!applied.contains("legacy"));
+    }
+}
+
+fn wmgr(workspace: &TempDir, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_wmgr"))
+        .args(args)
+        .current_dir(workspace.path())
+        .env("NO_COLOR", "1")
+        .output()
+        .expect("failed to run wmgr")
+}
+
+/// Run `wmgr sync --prune` after `old` was dropped from the manifest
+///
+/// `old` was synced while it was listed, so wmgr manages it; `mine` is a clone
+/// the user placed in the workspace and must never be pruned.
+fn sync_prune(extra_args: &[&str]) -> (TempDir, Output) {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("wmgr.yaml"),
+        "repos:\n  - dest: scratch\n  - dest: old\n",
+    )
+    .unwrap();
+    fs::create_dir(temp_dir.path().join("scratch")).unwrap();
+    git2::Repository::init(temp_dir.path().join("old")).unwrap();
+    let output = wmgr(&temp_dir, &["sync"]);
+    assert!(output.status.success(), "{:?}", output);
+
+    fs::write(
+        temp_dir.path().join("wmgr.yaml"),
+        "repos:\n  - dest: scratch\n",
+    )
+    .unwrap();
+    git2::Repository::init(temp_dir.path().join("mine")).unwrap();
+    let mut args = vec!["sync", "--prune"];
+    args.extend_from_slice(extra_args);
+    let output = wmgr(&temp_dir, &args);
+    (temp_dir, output)
+}
+
+#[test]
+fn test_prune_without_tty_requires_yes() {
+    let (temp_dir, output) = sync_prune(&[]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("old"));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--yes"));
+    assert!(temp_dir.path().join("old").exists());
+    assert!(temp_dir.path().join("scratch").exists());
+}
+
+#[test]
+fn test_prune_with_yes_removes_orphaned_repo() {
+    let (temp_dir, output) = sync_prune(&["--yes"]);
+    assert!(output.status.success(), "{:?}", output);
+    assert!(!temp_dir.path().join("old").exists());
+    assert!(temp_dir.path().join("scratch").exists());
+    // Never managed by wmgr, so not a prune candidate
+    assert!(!String::from_utf8_lossy(&output.stdout).contains("mine"));
+    assert!(temp_dir.path().join("mine").exists());
+}
+
+#[test]
+fn test_prune_keeps_repos_with_local_work_even_with_force_and_yes() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("wmgr.yaml"),
+        "repos:\n  - dest: scratch\n  - dest: old\n",
+    )
+    .unwrap();
+    fs::create_dir(temp_dir.path().join("scratch")).unwrap();
+    git2::Repository::init(temp_dir.path().join("old")).unwrap();
+    assert!(wmgr(&temp_dir, &["sync"]).status.success());
+
+    fs::write(
+        temp_dir.path().join("wmgr.yaml"),
+        "repos:\n  - dest: scratch\n",
+    )
+    .unwrap();
+    fs::write(temp_dir.path().join("old/notes.txt"), "wip").unwrap();
+    let output = wmgr(&temp_dir, &["sync", "--force", "--prune", "--yes"]);
+    assert!(output.status.success(), "{:?}", output);
+    assert!(String::from_utf8_lossy(&output.stdout).contains("--prune-dirty"));
+    assert!(temp_dir.path().join("old/notes.txt").exists());
+
+    // --yes does not confirm removing local work; that needs a terminal
+    let output = wmgr(&temp_dir, &["sync", "--prune", "--prune-dirty", "--yes"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--yes does not apply"));
+    assert!(temp_dir.path().join("old/notes.txt").exists());
+}
+
+#[test]
+fn test_prune_dirty_requires_prune() {
+    let temp_dir = TempDir::new().unwrap();
+    let output = wmgr(&temp_dir, &["sync", "--prune-dirty"]);
+    assert!(!output.status.success());
+}
@@ -0,0 +1,68 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tempfile::TempDir;
+
+/// A workspace nested one level inside a temporary directory, so that
+/// destinations escaping it still land in the temporary directory
+fn workspace(dest: &str) -> (TempDir, PathBuf) {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path().join("workspace");
+    fs::create_dir(&root).unwrap();
+    fs::write(
+        root.join("wmgr.yml"),
+        format!(
+            "repos:\n  - dest: {}\n    url: https://example.com/app.git\n",
+            dest
+        ),
+    )
+    .unwrap();
+    (temp_dir, root)
+}
+
+fn wmgr(root: &Path, args: &[&str]) -> (bool, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_wmgr"))
+        .arg("--no-color")
+        .args(args)
+        .current_dir(root)
+        .env_remove("RUST_LOG")
+        .output()
+        .expect("failed to run wmgr");
+    (
+        output.status.success(),
+        String::from_utf8_lossy(&output.stderr).into_owned(),
+    )
+}
+
+#[test]
+fn test_dest_outside_workspace_is_rejected() {
+    let (temp_dir, root) = workspace("../escape");
+
+    for args in [
+        &["status"][..],
+        &["sync"][..],
+        &["foreach", "--", "pwd"][..],
+    ] {
+        let (success, stderr) = wmgr(&root, args);
+        assert!(!success, "{:?} accepted ../escape", args);
+        assert!(
+            stderr.contains("points outside the workspace"),
+            "{}",
+            stderr
+        );
+    }
+    assert!(!temp_dir.path().join("escape").exists());
+}
+
+#[test]
+fn test_absolute_dest_is_rejected() {
+    let (_temp_dir, root) = workspace("/tmp/wmgr-absolute-dest");
+
+    let (success, stderr) = wmgr(&root, &["status"]);
+    assert!(!success);
+    assert!(
+        stderr.contains("must be relative to the workspace"),
+        "{}",
+        stderr
+    );
+}
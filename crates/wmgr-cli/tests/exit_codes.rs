@@ -0,0 +1,61 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+/// Run the wmgr binary in `dir` and return its exit code
+fn wmgr_exit_code(dir: &Path, args: &[&str]) -> i32 {
+    Command::new(env!("CARGO_BIN_EXE_wmgr"))
+        .args(args)
+        .current_dir(dir)
+        .env("NO_COLOR", "1")
+        .output()
+        .expect("failed to run wmgr")
+        .status
+        .code()
+        .expect("wmgr was terminated by a signal")
+}
+
+#[test]
+fn test_usage_error_exits_with_2() {
+    let temp_dir = TempDir::new().unwrap();
+    assert_eq!(wmgr_exit_code(temp_dir.path(), &["--no-such-flag"]), 2);
+}
+
+#[test]
+fn test_missing_workspace_exits_with_3() {
+    let temp_dir = TempDir::new().unwrap();
+    assert_eq!(wmgr_exit_code(temp_dir.path(), &["status"]), 3);
+}
+
+#[test]
+fn test_invalid_manifest_exits_with_4() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("wmgr.yaml"), "repos: [\n  - oops").unwrap();
+    assert_eq!(wmgr_exit_code(temp_dir.path(), &["status"]), 4);
+}
+
+#[test]
+fn test_partial_sync_failure_exits_with_5() {
+    let temp_dir = TempDir::new().unwrap();
+    // The .invalid TLD never resolves, so the clone fails without network access
+    fs::write(
+        temp_dir.path().join("wmgr.yaml"),
+        "repos:\n  - dest: missing\n    url: https://wmgr-test.invalid/none.git\n",
+    )
+    .unwrap();
+    assert_eq!(wmgr_exit_code(temp_dir.path(), &["sync"]), 5);
+}
+
+#[test]
+fn test_lock_contention_exits_with_6() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("wmgr.yaml"), "repos: []\n").unwrap();
+    fs::create_dir_all(temp_dir.path().join(".wmgr")).unwrap();
+    fs::write(temp_dir.path().join(".wmgr").join("lock"), "1\n").unwrap();
+    assert_eq!(wmgr_exit_code(temp_dir.path(), &["sync"]), 6);
+    assert_eq!(wmgr_exit_code(temp_dir.path(), &["checkout", "main"]), 6);
+    assert_eq!(wmgr_exit_code(temp_dir.path(), &["doctor", "--fix"]), 6);
+    // A diagnosis without --fix changes nothing and does not need the lock
+    assert_ne!(wmgr_exit_code(temp_dir.path(), &["doctor"]), 6);
+}
@@ -0,0 +1,59 @@
+use std::fs;
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+/// A workspace where only `web` has a `frontend` directory
+fn workspace() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let mut manifest = String::from("repos:\n");
+    for dest in ["api", "web"] {
+        git2::Repository::init(temp_dir.path().join(dest)).unwrap();
+        manifest.push_str(&format!(
+            "  - dest: {}\n    url: https://example.com/{}.git\n",
+            dest, dest
+        ));
+    }
+    fs::create_dir_all(temp_dir.path().join("web/frontend")).unwrap();
+    fs::write(temp_dir.path().join("wmgr.yml"), manifest).unwrap();
+    temp_dir
+}
+
+fn foreach(workspace: &TempDir, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_wmgr"))
+        .arg("--no-color")
+        .arg("foreach")
+        .args(args)
+        .current_dir(workspace.path())
+        .env_remove("RUST_LOG")
+        .output()
+        .expect("failed to run wmgr")
+}
+
+#[test]
+fn test_command_runs_in_subdirectory_and_skips_repositories_without_it() {
+    let workspace = workspace();
+    let output = foreach(
+        &workspace,
+        &["--cd", "frontend", "--summary", "touch", "ran"],
+    );
+    assert!(output.status.success(), "{:?}", output);
+
+    assert!(workspace.path().join("web/frontend/ran").exists());
+    assert!(!workspace.path().join("web/ran").exists());
+    assert!(!workspace.path().join("api/ran").exists());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("api     -  skipped"), "{}", stdout);
+    assert!(stdout.contains("web     0  ok"), "{}", stdout);
+}
+
+#[test]
+fn test_subdirectory_outside_repository_is_rejected() {
+    let workspace = workspace();
+    let output = foreach(&workspace, &["--cd", "../api", "touch", "ran"]);
+    assert!(!output.status.success(), "{:?}", output);
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("inside the repository"), "{}", stderr);
+    assert!(!workspace.path().join("api/ran").exists());
+}
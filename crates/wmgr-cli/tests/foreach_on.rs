@@ -0,0 +1,106 @@
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+fn init_committed_repo(path: &Path) {
+    let repo = git2::Repository::init(path).unwrap();
+    fs::write(path.join("README.md"), "readme").unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new("README.md")).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+    repo.commit(Some("HEAD"), &sig, &sig, "init", &tree, &[])
+        .unwrap();
+}
+
+/// A workspace with the clean repositories `app` and `tools` and the
+/// repository `lib` with an uncommitted change
+fn workspace() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let mut manifest = String::from("repos:\n");
+    for dest in ["app", "lib", "tools"] {
+        init_committed_repo(&temp_dir.path().join(dest));
+        manifest.push_str(&format!(
+            "  - dest: {}\n    url: https://example.com/{}.git\n",
+            dest, dest
+        ));
+    }
+    fs::write(temp_dir.path().join("wmgr.yml"), manifest).unwrap();
+    fs::write(temp_dir.path().join("lib/README.md"), "changed").unwrap();
+    temp_dir
+}
+
+fn foreach(workspace: &TempDir, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_wmgr"))
+        .arg("--no-color")
+        .arg("foreach")
+        .args(args)
+        .current_dir(workspace.path())
+        .env_remove("RUST_LOG")
+        .output()
+        .expect("failed to run wmgr")
+}
+
+#[test]
+fn test_command_runs_only_in_dirty_repository() {
+    let workspace = workspace();
+    let output = foreach(&workspace, &["--on", "dirty", "--", "git", "add", "-A"]);
+    assert!(output.status.success(), "{:?}", output);
+
+    let lib = git2::Repository::open(workspace.path().join("lib")).unwrap();
+    let status = lib.status_file(Path::new("README.md")).unwrap();
+    assert_eq!(status, git2::Status::INDEX_MODIFIED);
+
+    // A marker file shows which repositories the command ran in
+    let output = foreach(&workspace, &["--on", "dirty", "touch", "ran"]);
+    assert!(output.status.success(), "{:?}", output);
+    assert!(workspace.path().join("lib/ran").exists());
+    assert!(!workspace.path().join("app/ran").exists());
+    assert!(!workspace.path().join("tools/ran").exists());
+}
+
+#[test]
+fn test_command_runs_in_repositories_matching_any_state() {
+    let workspace = workspace();
+    let output = foreach(&workspace, &["--on", "clean", "--summary", "true"]);
+    assert!(output.status.success(), "{:?}", output);
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("app "), "{}", stdout);
+    assert!(stdout.contains("tools "), "{}", stdout);
+    assert!(!stdout.contains("lib "), "{}", stdout);
+
+    let output = foreach(&workspace, &["--on", "clean,dirty", "touch", "ran"]);
+    assert!(output.status.success(), "{:?}", output);
+    for dest in ["app", "lib", "tools"] {
+        assert!(workspace.path().join(dest).join("ran").exists());
+    }
+}
+
+#[test]
+fn test_command_skips_repositories_in_excluded_state() {
+    let workspace = workspace();
+    let output = foreach(&workspace, &["--unless", "dirty", "touch", "built"]);
+    assert!(output.status.success(), "{:?}", output);
+
+    assert!(workspace.path().join("app/built").exists());
+    assert!(workspace.path().join("tools/built").exists());
+    assert!(!workspace.path().join("lib/built").exists());
+}
+
+#[test]
+fn test_on_and_unless_are_mutually_exclusive() {
+    let workspace = workspace();
+    let output = foreach(
+        &workspace,
+        &["--on", "clean", "--unless", "dirty", "touch", "ran"],
+    );
+    assert_eq!(output.status.code(), Some(2), "{:?}", output);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("cannot be used with"), "{}", stderr);
+    for dest in ["app", "lib", "tools"] {
+        assert!(!workspace.path().join(dest).join("ran").exists());
+    }
+}
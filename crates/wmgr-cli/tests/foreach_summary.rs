@@ -0,0 +1,88 @@
+use std::fs;
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+/// A workspace with the repository directories `app`, `lib` and `tools`
+fn workspace() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let mut manifest = String::from("repos:\n");
+    for dest in ["app", "lib", "tools"] {
+        fs::create_dir(temp_dir.path().join(dest)).unwrap();
+        manifest.push_str(&format!(
+            "  - dest: {}\n    url: https://example.com/{}.git\n",
+            dest, dest
+        ));
+    }
+    fs::write(temp_dir.path().join("wmgr.yml"), manifest).unwrap();
+    temp_dir
+}
+
+fn foreach(workspace: &TempDir, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_wmgr"))
+        .arg("--no-color")
+        .arg("foreach")
+        .args(args)
+        .current_dir(workspace.path())
+        .env_remove("RUST_LOG")
+        .output()
+        .expect("failed to run wmgr")
+}
+
+#[test]
+fn test_summary_lists_exit_code_per_repository() {
+    let workspace = workspace();
+    let output = foreach(
+        &workspace,
+        &[
+            "exit $(test \"$WMGR_REPO_DEST\" = lib && echo 3 || echo 0)",
+            "--continue-on-error",
+            "--summary",
+        ],
+    );
+
+    assert_eq!(output.status.code(), Some(7), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("app       0  ok"), "{}", stdout);
+    assert!(stdout.contains("lib       3  failed"), "{}", stdout);
+    assert!(stdout.contains("tools     0  ok"), "{}", stdout);
+}
+
+#[test]
+fn test_keep_going_runs_every_repository_but_reports_failure() {
+    let workspace = workspace();
+    let output = foreach(
+        &workspace,
+        &[
+            "touch ran && test \"$WMGR_REPO_DEST\" != app",
+            "--keep-going",
+        ],
+    );
+
+    assert_eq!(output.status.code(), Some(7), "{:?}", output);
+    for dest in ["app", "lib", "tools"] {
+        assert!(workspace.path().join(dest).join("ran").exists());
+    }
+    assert!(
+        String::from_utf8_lossy(&output.stderr).contains("The command failed in 1 repositories")
+    );
+}
+
+#[test]
+fn test_all_successful_exits_with_0() {
+    let workspace = workspace();
+    let output = foreach(&workspace, &["true", "--summary"]);
+
+    assert!(output.status.success(), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!stdout.contains("failed"), "{}", stdout);
+}
+
+#[test]
+fn test_skipped_repositories_do_not_fail() {
+    let workspace = workspace();
+    fs::remove_dir(workspace.path().join("tools")).unwrap();
+    let output = foreach(&workspace, &["true", "--summary"]);
+
+    assert!(output.status.success(), "{:?}", output);
+    assert!(String::from_utf8_lossy(&output.stdout).contains("tools     -  skipped"));
+}
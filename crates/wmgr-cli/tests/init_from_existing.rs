@@ -0,0 +1,112 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn init_repo(path: &Path, origin: &str) {
+    let repo = git2::Repository::init(path).unwrap();
+    fs::write(path.join("README.md"), "readme").unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new("README.md")).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+    let oid = repo
+        .commit(Some("HEAD"), &sig, &sig, "init", &tree, &[])
+        .unwrap();
+    repo.branch("develop", &repo.find_commit(oid).unwrap(), false)
+        .unwrap();
+    repo.set_head("refs/heads/develop").unwrap();
+    repo.remote("origin", origin).unwrap();
+}
+
+/// A directory holding the checked-out repositories `app` and `libs/core`
+fn checkouts() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    init_repo(
+        &temp_dir.path().join("app"),
+        "https://example.com/org/app.git",
+    );
+    init_repo(
+        &temp_dir.path().join("libs/core"),
+        "https://example.com/org/core.git",
+    );
+    temp_dir
+}
+
+fn init(dir: &Path, args: &[&str]) -> serde_yaml::Value {
+    let output = Command::new(env!("CARGO_BIN_EXE_wmgr"))
+        .args(["--no-color", "init", "--from-existing"])
+        .args(args)
+        .current_dir(dir)
+        .env_remove("RUST_LOG")
+        .output()
+        .expect("failed to run wmgr");
+    assert!(output.status.success(), "{:?}", output);
+    serde_yaml::from_str(&fs::read_to_string(dir.join("wmgr.yml")).unwrap()).unwrap()
+}
+
+#[test]
+fn test_generated_manifest_lists_existing_repositories() {
+    let temp_dir = checkouts();
+
+    let manifest = init(temp_dir.path(), &[]);
+    let repos = manifest["repos"].as_sequence().unwrap();
+    let entries: Vec<(&str, &str, &str)> = repos
+        .iter()
+        .map(|repo| {
+            (
+                repo["dest"].as_str().unwrap(),
+                repo["url"].as_str().unwrap(),
+                repo["branch"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        entries,
+        vec![
+            ("app", "https://example.com/org/app.git", "develop"),
+            ("libs/core", "https://example.com/org/core.git", "develop"),
+        ]
+    );
+    // Without a terminal the grouping offer is declined
+    assert!(manifest["groups"].is_null());
+
+    // The generated manifest is usable as is
+    let output = Command::new(env!("CARGO_BIN_EXE_wmgr"))
+        .args(["--no-color", "status", "--output", "json"])
+        .current_dir(temp_dir.path())
+        .env_remove("RUST_LOG")
+        .output()
+        .expect("failed to run wmgr");
+    assert!(output.status.success(), "{:?}", output);
+}
+
+#[test]
+fn test_group_by_dir_groups_nested_repositories() {
+    let temp_dir = checkouts();
+
+    let manifest = init(temp_dir.path(), &["--group-by-dir"]);
+    let group: Vec<&str> = manifest["groups"]["libs"]["repos"]
+        .as_sequence()
+        .unwrap()
+        .iter()
+        .map(|dest| dest.as_str().unwrap())
+        .collect();
+    assert_eq!(group, vec!["libs/core"]);
+}
+
+#[test]
+fn test_from_existing_without_repositories_fails() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_wmgr"))
+        .args(["--no-color", "init", "--from-existing"])
+        .current_dir(temp_dir.path())
+        .env_remove("RUST_LOG")
+        .output()
+        .expect("failed to run wmgr");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("No git repositories found"));
+    assert!(!temp_dir.path().join("wmgr.yml").exists());
+}
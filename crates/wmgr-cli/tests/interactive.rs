@@ -0,0 +1,50 @@
+use std::fs;
+use std::process::{Command, Output, Stdio};
+use tempfile::TempDir;
+
+fn workspace() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    for dest in ["app", "lib"] {
+        fs::create_dir(temp_dir.path().join(dest)).unwrap();
+    }
+    fs::write(
+        temp_dir.path().join("wmgr.yml"),
+        "repos:\n  - dest: app\n    url: https://example.com/app.git\n  - dest: lib\n    url: https://example.com/lib.git\n",
+    )
+    .unwrap();
+    temp_dir
+}
+
+fn wmgr(workspace: &TempDir, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_wmgr"))
+        .arg("--no-color")
+        .args(args)
+        .current_dir(workspace.path())
+        .stdin(Stdio::null())
+        .env_remove("RUST_LOG")
+        .output()
+        .expect("failed to run wmgr")
+}
+
+#[test]
+fn test_interactive_without_terminal_fails() {
+    let workspace = workspace();
+    let output = wmgr(&workspace, &["foreach", "--interactive", "touch ran"]);
+
+    assert!(!output.status.success(), "{:?}", output);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--interactive needs a terminal"));
+    assert!(!workspace.path().join("app/ran").exists());
+}
+
+#[test]
+fn test_explicit_selection_skips_the_picker() {
+    let workspace = workspace();
+    let output = wmgr(
+        &workspace,
+        &["foreach", "--interactive", "--repo", "lib", "touch ran"],
+    );
+
+    assert!(output.status.success(), "{:?}", output);
+    assert!(workspace.path().join("lib/ran").exists());
+    assert!(!workspace.path().join("app/ran").exists());
+}
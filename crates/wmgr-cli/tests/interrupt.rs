@@ -0,0 +1,74 @@
+use std::fs;
+use std::net::TcpListener;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+use tempfile::TempDir;
+
+/// A git server that accepts connections and never answers, so a clone from it
+/// stays in progress until it is interrupted
+fn stalled_git_server() -> u16 {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    thread::spawn(move || {
+        let connections: Vec<_> = listener.incoming().collect();
+        drop(connections);
+    });
+    port
+}
+
+fn wait_for(path: &Path) {
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while !path.exists() {
+        assert!(
+            Instant::now() < deadline,
+            "{} never appeared",
+            path.display()
+        );
+        thread::sleep(Duration::from_millis(20));
+    }
+}
+
+#[test]
+fn test_ctrl_c_during_clone_leaves_no_partial_clone() {
+    let workspace = TempDir::new().unwrap();
+    let port = stalled_git_server();
+    fs::write(
+        workspace.path().join("wmgr.yml"),
+        format!(
+            "repos:\n  - dest: app\n    url: git://127.0.0.1:{}/app.git\n    branch: main\n  - dest: lib\n    url: https://example.com/lib.git\n",
+            port
+        ),
+    )
+    .unwrap();
+
+    let child = Command::new(env!("CARGO_BIN_EXE_wmgr"))
+        .args(["--no-color", "sync"])
+        .current_dir(workspace.path())
+        .env_remove("RUST_LOG")
+        .env("WMGR_ALLOW_PRIVATE_HOSTS", "1")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("failed to run wmgr");
+
+    // Interrupt once git has created the clone directory
+    wait_for(&workspace.path().join("app"));
+    let status = Command::new("kill")
+        .args(["-INT", &child.id().to_string()])
+        .status()
+        .unwrap();
+    assert!(status.success());
+    let output = child.wait_with_output().unwrap();
+
+    assert_eq!(output.status.code(), Some(130), "{:?}", output);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Synchronization interrupted"), "{}", stderr);
+    assert!(stderr.contains("  app"), "{}", stderr);
+    assert!(stderr.contains("  lib"), "{}", stderr);
+
+    assert!(!workspace.path().join("app").exists());
+    assert!(!workspace.path().join("lib").exists());
+    assert!(!workspace.path().join(".wmgr/lock").exists());
+}
@@ -0,0 +1,115 @@
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+const MAIN_MANIFEST: &str = "repos:\n  - dest: app\n    url: https://example.com/app.git\n";
+const RELEASE_MANIFEST: &str = "repos:\n  - dest: app\n    url: https://example.com/app.git\n  - dest: lib\n    url: https://example.com/lib.git\n";
+
+fn commit_file(repo: &git2::Repository, name: &str, content: &str) {
+    fs::write(repo.workdir().unwrap().join(name), content).unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new(name)).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+    let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+    let parents: Vec<&git2::Commit> = parent.iter().collect();
+    repo.commit(Some("HEAD"), &sig, &sig, name, &tree, &parents)
+        .unwrap();
+}
+
+/// A workspace initialized from a manifest repository whose `main` branch
+/// lists `app` and whose `release` branch lists `app` and `lib`
+///
+/// Manifests only accept remote URLs, so both repositories are cloned up front
+/// from local sources and sync updates them from there.
+fn workspace() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+
+    for name in ["app", "lib"] {
+        let source = git2::Repository::init(root.join("sources").join(name)).unwrap();
+        commit_file(&source, "README", name);
+        git2::Repository::clone(
+            root.join("sources").join(name).to_str().unwrap(),
+            root.join("workspace").join(name),
+        )
+        .unwrap();
+    }
+
+    let remote_path = root.join("manifest.git");
+    git2::Repository::init_bare(&remote_path)
+        .unwrap()
+        .set_head("refs/heads/main")
+        .unwrap();
+    let seed = git2::Repository::init(root.join("seed")).unwrap();
+    seed.set_head("refs/heads/main").unwrap();
+    commit_file(&seed, "wmgr.yml", MAIN_MANIFEST);
+    let head = seed.head().unwrap().peel_to_commit().unwrap();
+    seed.branch("release", &head, false).unwrap();
+    seed.set_head("refs/heads/release").unwrap();
+    commit_file(&seed, "wmgr.yml", RELEASE_MANIFEST);
+    seed.remote("origin", remote_path.to_str().unwrap())
+        .unwrap()
+        .push(
+            &[
+                "refs/heads/main:refs/heads/main",
+                "refs/heads/release:refs/heads/release",
+            ],
+            None,
+        )
+        .unwrap();
+
+    let workspace = root.join("workspace");
+    git2::Repository::clone(
+        remote_path.to_str().unwrap(),
+        workspace.join(".wmgr").join("manifest"),
+    )
+    .unwrap();
+    fs::write(workspace.join("wmgr.yml"), MAIN_MANIFEST).unwrap();
+    temp_dir
+}
+
+fn sync(workspace: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_wmgr"))
+        .args(["--no-color", "--verbose", "sync"])
+        .args(args)
+        .current_dir(workspace)
+        .env_remove("RUST_LOG")
+        .output()
+        .expect("failed to run wmgr")
+}
+
+#[test]
+fn test_manifest_branch_override_uses_its_repositories() {
+    let temp_dir = workspace();
+    let workspace = temp_dir.path().join("workspace");
+
+    let output = sync(&workspace, &[]);
+    assert!(output.status.success(), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Repositories synced: 1"), "{}", stdout);
+
+    let output = sync(&workspace, &["--manifest-branch", "release"]);
+    assert!(output.status.success(), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Repositories synced: 2"), "{}", stdout);
+
+    // The override lasts for one run: the manifest clone stays on main
+    let manifest = git2::Repository::open(workspace.join(".wmgr/manifest")).unwrap();
+    assert_eq!(manifest.head().unwrap().name(), Some("refs/heads/main"));
+    let output = sync(&workspace, &[]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Repositories synced: 1"), "{}", stdout);
+}
+
+#[test]
+fn test_invalid_manifest_branch_is_rejected() {
+    let temp_dir = workspace();
+    let workspace = temp_dir.path().join("workspace");
+
+    let output = sync(&workspace, &["--manifest-branch", "bad..name"]);
+    assert_eq!(output.status.code(), Some(2), "{:?}", output);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--manifest-branch"));
+}
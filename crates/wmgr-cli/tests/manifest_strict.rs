@@ -0,0 +1,62 @@
+use std::fs;
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+/// A workspace whose destinations differ only in letter case
+fn workspace() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("wmgr.yml"),
+        "repos:\n  - dest: Foo\n    url: https://example.com/foo.git\n  - dest: foo\n    url: https://example.com/foo2.git\n",
+    )
+    .unwrap();
+    temp_dir
+}
+
+fn wmgr(workspace: &TempDir, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_wmgr"))
+        .arg("--no-color")
+        .args(args)
+        .current_dir(workspace.path())
+        .env_remove("RUST_LOG")
+        .output()
+        .expect("failed to run wmgr")
+}
+
+#[test]
+fn test_case_insensitive_dest_collision_is_a_warning() {
+    let workspace = workspace();
+    let output = wmgr(&workspace, &["dump-manifest"]);
+    assert!(output.status.success(), "{:?}", output);
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("'Foo' and 'foo' collide on case-insensitive filesystems"),
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn test_case_insensitive_dest_collision_fails_with_strict_manifest() {
+    let workspace = workspace();
+    let output = wmgr(&workspace, &["--strict-manifest", "dump-manifest"]);
+    assert!(!output.status.success(), "{:?}", output);
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("collide on case-insensitive filesystems"),
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn test_dump_manifest_strict_requires_freeze() {
+    let workspace = workspace();
+    let output = wmgr(&workspace, &["dump-manifest", "--strict"]);
+    assert!(!output.status.success(), "{:?}", output);
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--freeze"), "{}", stderr);
+}
@@ -0,0 +1,74 @@
+use std::fs;
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+/// A workspace with the repository directories `app` and `lib`
+fn workspace() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let mut manifest = String::from("repos:\n");
+    for dest in ["app", "lib"] {
+        git2::Repository::init(temp_dir.path().join(dest)).unwrap();
+        manifest.push_str(&format!(
+            "  - dest: {}\n    url: https://example.com/{}.git\n",
+            dest, dest
+        ));
+    }
+    fs::write(temp_dir.path().join("wmgr.yml"), manifest).unwrap();
+    temp_dir
+}
+
+fn wmgr(workspace: &TempDir, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_wmgr"))
+        .arg("--no-color")
+        .args(args)
+        .current_dir(workspace.path())
+        .env_remove("RUST_LOG")
+        .output()
+        .expect("failed to run wmgr")
+}
+
+#[test]
+fn test_exec_parallel_prints_a_line_per_finished_repository() {
+    let workspace = workspace();
+    let output = wmgr(
+        &workspace,
+        &["exec-parallel", "test \"$WMGR_REPO_DEST\" = app"],
+    );
+
+    assert_eq!(output.status.code(), Some(7), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("  ✓ app (succeeded)\n"), "{}", stdout);
+    assert!(stdout.contains("  ✗ lib\n"), "{}", stdout);
+}
+
+#[test]
+fn test_status_progress_goes_to_stderr_and_keeps_json_clean() {
+    let workspace = workspace();
+    let output = wmgr(&workspace, &["status", "--output", "json"]);
+
+    assert!(output.status.success(), "{:?}", output);
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["repositories"].as_array().unwrap().len(), 2);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    for dest in ["app", "lib"] {
+        assert_eq!(
+            stderr.lines().filter(|line| line.contains(dest)).count(),
+            1,
+            "{}",
+            stderr
+        );
+    }
+}
+
+#[test]
+fn test_quiet_suppresses_progress_lines() {
+    let workspace = workspace();
+
+    let output = wmgr(&workspace, &["--quiet", "foreach", "true"]);
+    assert!(output.status.success(), "{:?}", output);
+    assert!(output.stdout.is_empty(), "{:?}", output);
+
+    let output = wmgr(&workspace, &["--quiet", "status", "--output", "json"]);
+    assert!(output.status.success(), "{:?}", output);
+    assert!(output.stderr.is_empty(), "{:?}", output);
+}
@@ -0,0 +1,133 @@
+use std::fs;
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+/// Run `wmgr <args>` in a workspace whose only repository is local-only, so sync needs no network
+fn run_in_workspace(args: &[&str]) -> (TempDir, Output) {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("wmgr.yaml"),
+        "repos:\n  - dest: scratch\n",
+    )
+    .unwrap();
+    fs::create_dir(temp_dir.path().join("scratch")).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_wmgr"))
+        .args(args)
+        .current_dir(temp_dir.path())
+        .env("NO_COLOR", "1")
+        .env_remove("RUST_LOG")
+        .output()
+        .expect("failed to run wmgr");
+    (temp_dir, output)
+}
+
+#[test]
+fn test_sync_prints_progress_by_default() {
+    let (_temp_dir, output) = run_in_workspace(&["sync"]);
+    assert!(output.status.success(), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Synchronization completed"));
+    // One line per repository, driven by the use case's progress events
+    assert!(
+        stdout
+            .lines()
+            .any(|line| line.contains("scratch") && line.contains("skipped")),
+        "{}",
+        stdout
+    );
+}
+
+#[test]
+fn test_quiet_sync_emits_nothing_on_success() {
+    for flag in ["--quiet", "-q"] {
+        let (_temp_dir, output) = run_in_workspace(&[flag, "sync"]);
+        assert!(output.status.success(), "{} failed: {:?}", flag, output);
+        assert!(output.stdout.is_empty(), "{}: {:?}", flag, output);
+        assert!(output.stderr.is_empty(), "{}: {:?}", flag, output);
+    }
+}
+
+#[test]
+fn test_sync_warnings_go_to_stderr_and_are_silenced_by_quiet() {
+    let run = |args: &[&str]| {
+        let temp_dir = TempDir::new().unwrap();
+        // A missing local-only repository is reported as a warning without failing the sync
+        fs::write(
+            temp_dir.path().join("wmgr.yaml"),
+            "repos:\n  - dest: scratch\n",
+        )
+        .unwrap();
+        Command::new(env!("CARGO_BIN_EXE_wmgr"))
+            .args(args)
+            .current_dir(temp_dir.path())
+            .env("NO_COLOR", "1")
+            .env_remove("RUST_LOG")
+            .output()
+            .expect("failed to run wmgr")
+    };
+
+    let output = run(&["sync"]);
+    assert!(output.status.success(), "{:?}", output);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("does not exist"), "{}", stderr);
+    assert!(
+        !String::from_utf8_lossy(&output.stdout).contains("does not exist"),
+        "{:?}",
+        output
+    );
+
+    let output = run(&["--quiet", "sync"]);
+    assert!(output.status.success(), "{:?}", output);
+    assert!(output.stdout.is_empty(), "{:?}", output);
+    assert!(output.stderr.is_empty(), "{:?}", output);
+}
+
+#[test]
+fn test_quiet_sync_prune_emits_nothing_on_success() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("wmgr.yaml"),
+        "repos:\n  - dest: scratch\n  - dest: old\n",
+    )
+    .unwrap();
+    fs::create_dir(temp_dir.path().join("scratch")).unwrap();
+    git2::Repository::init(temp_dir.path().join("old")).unwrap();
+    // Sync while `old` is listed so it is recorded as managed, then drop it
+    let output = Command::new(env!("CARGO_BIN_EXE_wmgr"))
+        .args(["sync", "--quiet"])
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("failed to run wmgr");
+    assert!(output.status.success(), "{:?}", output);
+    fs::write(
+        temp_dir.path().join("wmgr.yaml"),
+        "repos:\n  - dest: scratch\n",
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_wmgr"))
+        .args(["sync", "--prune", "--yes", "--quiet"])
+        .current_dir(temp_dir.path())
+        .env("NO_COLOR", "1")
+        .env_remove("RUST_LOG")
+        .output()
+        .expect("failed to run wmgr");
+    assert!(output.status.success(), "{:?}", output);
+    assert!(output.stdout.is_empty(), "{:?}", output);
+    assert!(!temp_dir.path().join("old").exists());
+}
+
+#[test]
+fn test_quiet_still_prints_requested_output() {
+    let (_temp_dir, output) = run_in_workspace(&["--quiet", "status"]);
+    assert!(output.status.success(), "{:?}", output);
+    assert!(String::from_utf8_lossy(&output.stdout).contains("scratch"));
+}
+
+#[test]
+fn test_quiet_and_verbose_are_rejected_together() {
+    let (_temp_dir, output) = run_in_workspace(&["--quiet", "--verbose", "sync"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("cannot be used with"));
+}
@@ -0,0 +1,71 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+/// A workspace with a git repository `app` that has one commit, two local
+/// branches and a `FETCH_HEAD`
+fn workspace() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("wmgr.yml"),
+        "repos:\n  - dest: app\n    url: https://example.com/app.git\n",
+    )
+    .unwrap();
+
+    let app = temp_dir.path().join("app");
+    let repo = git2::Repository::init(&app).unwrap();
+    fs::write(app.join("README.md"), "x".repeat(4096)).unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new("README.md")).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+    let oid = repo
+        .commit(Some("HEAD"), &sig, &sig, "init", &tree, &[])
+        .unwrap();
+    repo.branch("feature", &repo.find_commit(oid).unwrap(), false)
+        .unwrap();
+    fs::write(app.join(".git/FETCH_HEAD"), "").unwrap();
+    temp_dir
+}
+
+fn status(workspace: &TempDir, args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_wmgr"))
+        .args(["--no-color", "status"])
+        .args(args)
+        .current_dir(workspace.path())
+        .env_remove("RUST_LOG")
+        .output()
+        .expect("failed to run wmgr");
+    assert!(output.status.success(), "{:?}", output);
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_extended_fields_in_json() {
+    let workspace = workspace();
+
+    let stdout = status(&workspace, &["--output", "json", "--extended"]);
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    let health = &json["repositories"][0]["health"];
+    assert_eq!(health["local_branches"], 2);
+    assert!(health["disk_size_bytes"].as_u64().unwrap() >= 4096);
+    assert!(health["last_fetch"].is_string(), "{}", health);
+
+    let stdout = status(&workspace, &["--output", "json"]);
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert!(json["repositories"][0].get("health").is_none());
+}
+
+#[test]
+fn test_extended_fields_in_yaml() {
+    let workspace = workspace();
+
+    let stdout = status(&workspace, &["--output", "yaml", "--extended"]);
+    let yaml: serde_yaml::Value = serde_yaml::from_str(&stdout).unwrap();
+    let health = &yaml["repositories"][0]["health"];
+    assert_eq!(health["local_branches"].as_u64(), Some(2));
+    assert!(health["disk_size_bytes"].as_u64().unwrap() >= 4096);
+    assert!(health["last_fetch"].as_str().is_some());
+}
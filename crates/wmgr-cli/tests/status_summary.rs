@@ -0,0 +1,84 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+/// A workspace with clean `app`, dirty `lib` and missing `docs` repositories
+fn workspace() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("wmgr.yml"),
+        "repos:\n  - dest: app\n    url: https://example.com/app.git\n  - dest: lib\n    url: https://example.com/lib.git\n  - dest: docs\n    url: https://example.com/docs.git\n",
+    )
+    .unwrap();
+
+    for name in ["app", "lib"] {
+        let path = temp_dir.path().join(name);
+        let repo = git2::Repository::init(&path).unwrap();
+        fs::write(path.join("README.md"), name).unwrap();
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new("README.md")).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+        repo.commit(Some("HEAD"), &sig, &sig, "init", &tree, &[])
+            .unwrap();
+    }
+    fs::write(temp_dir.path().join("lib/README.md"), "changed").unwrap();
+    temp_dir
+}
+
+fn status(workspace: &TempDir, args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_wmgr"))
+        .args(["--no-color", "status"])
+        .args(args)
+        .current_dir(workspace.path())
+        .env_remove("RUST_LOG")
+        .output()
+        .expect("failed to run wmgr");
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_summary_in_json_matches_repositories() {
+    let workspace = workspace();
+
+    let stdout = status(&workspace, &["--output", "json"]);
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    let summary = &json["summary"];
+    assert_eq!(summary["total"], 3);
+    assert_eq!(summary["clean"], 1);
+    assert_eq!(summary["dirty"], 1);
+    assert_eq!(summary["missing"], 1);
+    assert_eq!(summary["error"], 0);
+    assert_eq!(json["repositories"].as_array().unwrap().len(), 3);
+}
+
+#[test]
+fn test_summary_in_yaml() {
+    let workspace = workspace();
+
+    let stdout = status(&workspace, &["--output", "yaml"]);
+    let yaml: serde_yaml::Value = serde_yaml::from_str(&stdout).unwrap();
+    assert_eq!(yaml["summary"]["total"].as_u64(), Some(3));
+    assert_eq!(yaml["summary"]["clean"].as_u64(), Some(1));
+}
+
+#[test]
+fn test_summary_line_ends_text_output() {
+    let workspace = workspace();
+
+    for args in [&[][..], &["--plain"][..]] {
+        let stdout = status(&workspace, args);
+        assert_eq!(
+            stdout.lines().last(),
+            Some("1 clean, 1 dirty, 1 missing"),
+            "{}",
+            stdout
+        );
+    }
+
+    // The compact format stays one line per repository
+    let stdout = status(&workspace, &["--compact"]);
+    assert_eq!(stdout.lines().count(), 3, "{}", stdout);
+}
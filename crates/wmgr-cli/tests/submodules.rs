@@ -0,0 +1,106 @@
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+fn commit_all(repo: &git2::Repository, message: &str) {
+    let mut index = repo.index().unwrap();
+    index
+        .add_all(["*"], git2::IndexAddOption::DEFAULT, None)
+        .unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+    let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+    let parents: Vec<&git2::Commit> = parent.iter().collect();
+    repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &parents)
+        .unwrap();
+}
+
+/// A workspace whose `app` repository has a submodule `lib` that has not been cloned yet
+///
+/// Manifests only accept remote URLs, so `app` is cloned up front from a local
+/// source and sync updates it from there.
+fn workspace(manifest_options: &str) -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+
+    let lib = git2::Repository::init(root.join("sources/lib")).unwrap();
+    fs::write(root.join("sources/lib/LIB"), "lib").unwrap();
+    commit_all(&lib, "lib");
+
+    let app = git2::Repository::init(root.join("sources/app")).unwrap();
+    fs::write(root.join("sources/app/README"), "app").unwrap();
+    commit_all(&app, "app");
+    let lib_url = format!("file://{}", root.join("sources/lib").display());
+    let mut submodule = app.submodule(&lib_url, Path::new("lib"), true).unwrap();
+    submodule.clone(None).unwrap();
+    submodule.add_finalize().unwrap();
+    commit_all(&app, "add lib");
+
+    git2::Repository::clone(
+        root.join("sources/app").to_str().unwrap(),
+        root.join("workspace/app"),
+    )
+    .unwrap();
+    fs::write(
+        root.join("workspace/wmgr.yml"),
+        format!(
+            "repos:\n  - dest: app\n    url: https://example.com/app.git\n{}",
+            manifest_options
+        ),
+    )
+    .unwrap();
+    temp_dir
+}
+
+fn sync(workspace: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_wmgr"))
+        .arg("sync")
+        .args(args)
+        .current_dir(workspace)
+        .env_remove("RUST_LOG")
+        .output()
+        .expect("failed to run wmgr")
+}
+
+#[test]
+fn test_recurse_submodules_flag_populates_submodules() {
+    let temp_dir = workspace("");
+    let workspace = temp_dir.path().join("workspace");
+
+    let output = sync(&workspace, &[]);
+    assert!(output.status.success(), "{:?}", output);
+    assert!(!workspace.join("app/lib/LIB").exists());
+
+    let output = sync(&workspace, &["--recurse-submodules"]);
+    assert!(output.status.success(), "{:?}", output);
+    assert!(workspace.join("app/lib/LIB").exists());
+}
+
+#[test]
+fn test_manifest_submodules_field_populates_submodules() {
+    let temp_dir = workspace("    submodules: true\n");
+    let workspace = temp_dir.path().join("workspace");
+
+    let output = sync(&workspace, &[]);
+    assert!(output.status.success(), "{:?}", output);
+    assert!(workspace.join("app/lib/LIB").exists());
+}
+
+#[test]
+fn test_submodule_failure_is_reported_per_repository() {
+    let temp_dir = workspace("    submodules: true\n");
+    let workspace = temp_dir.path().join("workspace");
+    fs::remove_dir_all(temp_dir.path().join("sources/lib")).unwrap();
+
+    let output = sync(&workspace, &[]);
+    assert!(!output.status.success(), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        format!("{}{}", stdout, stderr).contains("Submodule update failed for repo 'app'"),
+        "{:?}",
+        output
+    );
+}
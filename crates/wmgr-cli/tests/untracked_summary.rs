@@ -0,0 +1,76 @@
+use std::fs;
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+/// A workspace with a committed git repository `app` holding untracked files
+/// in several directories
+fn workspace() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("wmgr.yml"), "repos:\n  - dest: app\n").unwrap();
+    let app = temp_dir.path().join("app");
+    let repo = git2::Repository::init(&app).unwrap();
+    let tree = repo
+        .find_tree(repo.index().unwrap().write_tree().unwrap())
+        .unwrap();
+    let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+    repo.commit(Some("HEAD"), &sig, &sig, "initial", &tree, &[])
+        .unwrap();
+
+    fs::create_dir_all(app.join("target/debug")).unwrap();
+    for name in ["app", "app.d", "build.log"] {
+        fs::write(app.join("target/debug").join(name), "out").unwrap();
+    }
+    fs::create_dir_all(app.join("src")).unwrap();
+    fs::write(app.join("src/new.rs"), "fn main() {}").unwrap();
+    fs::write(app.join("debug.log"), "log").unwrap();
+    temp_dir
+}
+
+fn status(temp_dir: &TempDir, args: &[&str]) -> Output {
+    let output = Command::new(env!("CARGO_BIN_EXE_wmgr"))
+        .arg("--no-color")
+        .arg("status")
+        .args(args)
+        .current_dir(temp_dir.path())
+        .env_remove("RUST_LOG")
+        .output()
+        .expect("failed to run wmgr");
+    assert!(output.status.success(), "{:?}", output);
+    output
+}
+
+#[test]
+fn test_json_output_has_untracked_breakdown() {
+    let temp_dir = workspace();
+    let output = status(&temp_dir, &["--untracked-summary", "--output", "json"]);
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let app = &json["repositories"][0];
+    assert_eq!(app["untracked_files"], 5);
+    assert_eq!(
+        app["untracked_breakdown"],
+        serde_json::json!({"target/": 3, "src/": 1, "*.log": 1})
+    );
+}
+
+#[test]
+fn test_plain_output_lists_untracked_breakdown() {
+    let temp_dir = workspace();
+    let output = status(&temp_dir, &["--untracked-summary", "--plain"]);
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("  untracked: target/ 3, *.log 1, src/ 1"),
+        "{}",
+        stdout
+    );
+}
+
+#[test]
+fn test_breakdown_is_omitted_without_the_flag() {
+    let temp_dir = workspace();
+    let output = status(&temp_dir, &["--output", "json"]);
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(json["repositories"][0].get("untracked_breakdown").is_none());
+}
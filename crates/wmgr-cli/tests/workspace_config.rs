@@ -0,0 +1,110 @@
+use std::fs;
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+const MANIFEST: &str = "repos:
+  - dest: frontend
+  - dest: backend
+groups:
+  web:
+    repos: [frontend]
+";
+
+const CONFIG: &str = "manifest_url: https://example.com/manifest.git
+manifest_branch: develop
+repo_groups: [web]
+";
+
+/// A workspace whose .wmgr/config.yml selects the `web` group on the `develop` branch
+fn configured_workspace() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("wmgr.yml"), MANIFEST).unwrap();
+    fs::create_dir(temp_dir.path().join(".wmgr")).unwrap();
+    fs::write(temp_dir.path().join(".wmgr/config.yml"), CONFIG).unwrap();
+    for dest in ["frontend", "backend"] {
+        git2::Repository::init(temp_dir.path().join(dest)).unwrap();
+    }
+    temp_dir
+}
+
+fn run(temp_dir: &TempDir, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_wmgr"))
+        .args(args)
+        .current_dir(temp_dir.path())
+        .env_remove("RUST_LOG")
+        .output()
+        .expect("failed to run wmgr")
+}
+
+#[test]
+fn test_commands_default_to_configured_groups() {
+    let temp_dir = configured_workspace();
+
+    let output = run(&temp_dir, &["status", "--compact"]);
+    assert!(output.status.success(), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("frontend"), "{}", stdout);
+    assert!(!stdout.contains("backend"), "{}", stdout);
+
+    let output = run(&temp_dir, &["foreach", "touch", "visited"]);
+    assert!(output.status.success(), "{:?}", output);
+    assert!(temp_dir.path().join("frontend/visited").exists());
+    assert!(!temp_dir.path().join("backend/visited").exists());
+}
+
+#[test]
+fn test_explicit_group_overrides_configured_groups() {
+    let temp_dir = configured_workspace();
+    let manifest = format!("{}  api:\n    repos: [backend]\n", MANIFEST);
+    fs::write(temp_dir.path().join("wmgr.yml"), manifest).unwrap();
+
+    let output = run(&temp_dir, &["status", "--compact", "--group", "api"]);
+    assert!(output.status.success(), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("backend"), "{}", stdout);
+    assert!(!stdout.contains("frontend"), "{}", stdout);
+}
+
+#[test]
+fn test_configured_manifest_branch_is_used() {
+    let temp_dir = configured_workspace();
+
+    let output = run(
+        &temp_dir,
+        &["foreach", "echo $WMGR_MANIFEST_BRANCH > branch"],
+    );
+    assert!(output.status.success(), "{:?}", output);
+    let branch = fs::read_to_string(temp_dir.path().join("frontend/branch")).unwrap();
+    assert_eq!(branch.trim(), "develop");
+}
+
+#[test]
+fn test_config_option_overrides_default_config_file() {
+    let temp_dir = configured_workspace();
+    let manifest = format!("{}  api:\n    repos: [backend]\n", MANIFEST);
+    fs::write(temp_dir.path().join("wmgr.yml"), manifest).unwrap();
+    fs::write(
+        temp_dir.path().join(".wmgr/config.ci.yml"),
+        "manifest_url: https://example.com/manifest.git\nmanifest_branch: ci\nrepo_groups: [api]\n",
+    )
+    .unwrap();
+
+    let output = run(
+        &temp_dir,
+        &[
+            "--config",
+            ".wmgr/config.ci.yml",
+            "foreach",
+            "echo $WMGR_MANIFEST_BRANCH > branch",
+        ],
+    );
+    assert!(output.status.success(), "{:?}", output);
+    let branch = fs::read_to_string(temp_dir.path().join("backend/branch")).unwrap();
+    assert_eq!(branch.trim(), "ci");
+    assert!(!temp_dir.path().join("frontend/branch").exists());
+
+    // A missing override is an error rather than a silent fallback
+    let output = run(&temp_dir, &["status", "--config", "missing.yml"]);
+    assert!(!output.status.success(), "{:?}", output);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("missing.yml"));
+}
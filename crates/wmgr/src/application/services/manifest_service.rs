@@ -1,9 +1,14 @@
 use crate::domain::entities::manifest::{Group, Manifest, ManifestRepo};
+use crate::domain::entities::workspace::{normalize_dest, resolve_dest_in};
 use crate::domain::value_objects::git_url::GitUrl;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 use thiserror::Error;
+use unicode_normalization::UnicodeNormalization;
 
 /// ManifestService関連のエラー
 #[derive(Debug, Error)]
@@ -17,6 +22,15 @@ pub enum ManifestServiceError {
     #[error("Invalid YAML format: {0}")]
     YamlError(#[from] serde_yaml::Error),
 
+    #[error("Invalid JSON format: {0}")]
+    JsonParseError(serde_json::Error),
+
+    #[error("Invalid TOML format: {0}")]
+    TomlError(#[from] toml::de::Error),
+
+    #[error("TOML serialization error: {0}")]
+    TomlSerializeError(#[from] toml::ser::Error),
+
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
 
@@ -38,10 +52,63 @@ pub enum ManifestServiceError {
     #[error("Failed to fetch remote manifest from {url}: {reason}")]
     RemoteManifestFetchFailed { url: String, reason: String },
 
+    #[error("SHA-256 mismatch for manifest {url}: expected {expected}, got {actual}")]
+    ChecksumMismatch {
+        url: String,
+        expected: String,
+        actual: String,
+    },
+
     #[error("JSON serialization error: {0}")]
     JsonError(#[from] serde_json::Error),
+
+    #[error("Undefined environment variable '{var}' in {field}")]
+    UndefinedEnvVar { var: String, field: String },
+
+    #[error("Invalid environment variable reference in {field}: {value}")]
+    InvalidEnvReference { field: String, value: String },
+}
+
+/// マニフェストファイルの形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ManifestFormat {
+    /// YAML（デフォルト）
+    #[default]
+    Yaml,
+
+    /// TOML
+    Toml,
+
+    /// JSON
+    Json,
+}
+
+impl ManifestFormat {
+    /// ファイルの拡張子から形式を判定（`.toml`・`.json`以外はYAML）
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("toml") => ManifestFormat::Toml,
+            Some(ext) if ext.eq_ignore_ascii_case("json") => ManifestFormat::Json,
+            _ => ManifestFormat::Yaml,
+        }
+    }
+
+    /// 内容から形式を判定（`{`で始まればJSON、それ以外はYAML）
+    pub fn detect(content: &str) -> Self {
+        if content.trim_start().starts_with('{') {
+            ManifestFormat::Json
+        } else {
+            ManifestFormat::Yaml
+        }
+    }
 }
 
+/// リモートマニフェストの取得時に`Authorization: Bearer`で送るトークンを指定する環境変数
+pub const MANIFEST_TOKEN_ENV: &str = "WMGR_MANIFEST_TOKEN";
+
+/// `WMGR_MANIFEST_TOKEN`を送るホストを指定する環境変数
+pub const MANIFEST_TOKEN_HOST_ENV: &str = "WMGR_MANIFEST_TOKEN_HOST";
+
 /// DeepManifest/FutureManifestのサポート設定
 #[derive(Debug, Clone)]
 pub struct ManifestProcessingOptions {
@@ -56,6 +123,33 @@ pub struct ManifestProcessingOptions {
 
     /// タイムアウト設定（秒）
     pub timeout_seconds: u64,
+
+    /// HTTP 5xx応答時の最大リトライ回数
+    pub max_retries: u32,
+
+    /// 最初のリトライまでの待機時間（ミリ秒）。リトライごとに倍になる
+    pub retry_backoff_ms: u64,
+
+    /// 追従するリダイレクトの最大数
+    pub max_redirects: usize,
+
+    /// HTTPリクエストに付与するヘッダー（プライベートなマニフェストサーバーの認証トークンなど）
+    ///
+    /// Bearerトークンと同じく`bearer_token_host`（未設定なら`parse_from_url`に渡したURLのホスト）
+    /// へのリクエストにだけ付与する。
+    pub http_headers: HashMap<String, String>,
+
+    /// `Authorization: Bearer`で送るトークン
+    pub bearer_token: Option<String>,
+
+    /// トークンと`http_headers`を送るホスト（`host`または`host:port`）
+    ///
+    /// 未設定なら`parse_from_url`に渡したURLのホストにだけ送る。インクルード先の
+    /// 別ホストにトークンが漏れないよう、他のホストには送らない。
+    pub bearer_token_host: Option<String>,
+
+    /// 検証の警告（大文字小文字やUnicodeの正規化だけが異なるdestなど）をエラーにするか
+    pub strict: bool,
 }
 
 impl Default for ManifestProcessingOptions {
@@ -65,6 +159,44 @@ impl Default for ManifestProcessingOptions {
             enable_remote_fetch: true,
             detect_circular_dependencies: true,
             timeout_seconds: 30,
+            max_retries: 2,
+            retry_backoff_ms: 500,
+            max_redirects: 10,
+            http_headers: HashMap::new(),
+            bearer_token: None,
+            bearer_token_host: None,
+            strict: false,
+        }
+    }
+}
+
+impl ManifestProcessingOptions {
+    /// 既定の設定に`WMGR_MANIFEST_TOKEN`と`WMGR_MANIFEST_TOKEN_HOST`のトークンを加えて作成
+    pub fn from_env() -> Self {
+        Self::default().with_bearer_token_from(&|name| std::env::var(name).ok())
+    }
+
+    /// Bearerトークンと送り先のホストを設定
+    pub fn with_bearer_token(mut self, token: impl Into<String>, host: Option<String>) -> Self {
+        self.bearer_token = Some(token.into());
+        self.bearer_token_host = host;
+        self
+    }
+
+    /// 検証の警告をエラーにするか設定（`--strict-manifest`）
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// 環境変数の値からBearerトークンを設定（空のトークンは無視）
+    fn with_bearer_token_from(self, env: &dyn Fn(&str) -> Option<String>) -> Self {
+        match env(MANIFEST_TOKEN_ENV).filter(|token| !token.trim().is_empty()) {
+            Some(token) => {
+                let host = env(MANIFEST_TOKEN_HOST_ENV).filter(|host| !host.trim().is_empty());
+                self.with_bearer_token(token.trim(), host.map(|host| host.trim().to_string()))
+            }
+            None => self,
         }
     }
 }
@@ -102,6 +234,10 @@ pub struct ManifestInclude {
     /// インクルードの優先度（高い方が優先）
     #[serde(default)]
     pub priority: i32,
+
+    /// 取得したマニフェストの期待するSHA-256（16進数）。一致しなければエラー
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
 }
 
 /// Future Manifest設定
@@ -160,6 +296,35 @@ pub struct IncludeInfo {
     pub repo_count: usize,
 }
 
+/// ファイルから読み込んだマニフェストのキャッシュエントリ
+#[derive(Debug, Clone)]
+struct FileCacheEntry {
+    /// 読み込み時のファイル更新日時
+    modified: Option<SystemTime>,
+
+    /// 読み込み時のファイルサイズ
+    len: u64,
+
+    /// ファイル内容のハッシュ
+    content_hash: u64,
+
+    /// 解析・検証済みのマニフェスト
+    processed: ProcessedManifest,
+}
+
+/// ファイルキャッシュの統計情報（デバッグ用）
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ManifestCacheStats {
+    /// 再解析せずにキャッシュから返した回数
+    pub hits: usize,
+
+    /// ファイルを解析した回数
+    pub misses: usize,
+
+    /// キャッシュされているファイル数
+    pub entries: usize,
+}
+
 /// マニフェストサービス
 pub struct ManifestService {
     /// 処理オプション
@@ -170,6 +335,18 @@ pub struct ManifestService {
 
     /// 処理済みマニフェストのキャッシュ
     cache: HashMap<String, ExtendedManifest>,
+
+    /// ローカルファイルから解析したマニフェストのキャッシュ（パスごと）
+    file_cache: HashMap<PathBuf, FileCacheEntry>,
+
+    /// ファイルキャッシュのヒット数
+    cache_hits: usize,
+
+    /// ファイルキャッシュのミス数
+    cache_misses: usize,
+
+    /// `bearer_token_host`が未設定のときにトークンを送るホスト（最後に`parse_from_url`したURLのもの）
+    token_host: Option<String>,
 }
 
 impl ManifestService {
@@ -177,6 +354,7 @@ impl ManifestService {
     pub fn new(options: ManifestProcessingOptions) -> Self {
         let http_client = reqwest::Client::builder()
             .timeout(std::time::Duration::from_secs(options.timeout_seconds))
+            .redirect(reqwest::redirect::Policy::limited(options.max_redirects))
             .build()
             .expect("Failed to create HTTP client");
 
@@ -184,28 +362,102 @@ impl ManifestService {
             options,
             http_client,
             cache: HashMap::new(),
+            file_cache: HashMap::new(),
+            cache_hits: 0,
+            cache_misses: 0,
+            token_host: None,
         }
     }
 
-    /// デフォルト設定でManifestServiceを作成
+    /// デフォルト設定（環境変数のトークンを含む）でManifestServiceを作成
     pub fn default() -> Self {
-        Self::new(ManifestProcessingOptions::default())
+        Self::new(ManifestProcessingOptions::from_env())
     }
 
     /// ローカルファイルからマニフェストを読み込み・解析
+    ///
+    /// 同じ内容のファイルは再解析しない。更新日時とサイズが前回と同じなら
+    /// キャッシュをそのまま使い、異なる場合は内容のハッシュを比較する。
     pub async fn parse_from_file(
         &mut self,
         path: &Path,
     ) -> Result<ProcessedManifest, ManifestServiceError> {
-        let content = tokio::fs::read_to_string(path).await?;
-        self.parse_from_string(&content, Some(path.to_path_buf()))
-            .await
+        let metadata = tokio::fs::metadata(path).await?;
+        let modified = metadata.modified().ok();
+        let len = metadata.len();
+
+        if let Some(entry) = self.file_cache.get(path) {
+            if modified.is_some() && entry.modified == modified && entry.len == len {
+                self.cache_hits += 1;
+                return Ok(entry.processed.clone());
+            }
+        }
+
+        let bytes = tokio::fs::read(path).await?;
+        let content_hash = hash_content(&bytes);
+
+        if let Some(entry) = self.file_cache.get_mut(path) {
+            if entry.content_hash == content_hash {
+                // 内容は変わっていないので、次回のために更新日時だけ記録し直す
+                entry.modified = modified;
+                entry.len = len;
+                self.cache_hits += 1;
+                return Ok(entry.processed.clone());
+            }
+        }
+
+        let content = String::from_utf8(bytes)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        self.cache_misses += 1;
+        let processed = self
+            .parse_from_string_with_format(
+                &content,
+                ManifestFormat::from_path(path),
+                Some(path.to_path_buf()),
+            )
+            .await?;
+
+        self.file_cache.insert(
+            path.to_path_buf(),
+            FileCacheEntry {
+                modified,
+                len,
+                content_hash,
+                processed: processed.clone(),
+            },
+        );
+
+        Ok(processed)
+    }
+
+    /// ファイルキャッシュの統計情報を取得
+    pub fn cache_stats(&self) -> ManifestCacheStats {
+        ManifestCacheStats {
+            hits: self.cache_hits,
+            misses: self.cache_misses,
+            entries: self.file_cache.len(),
+        }
+    }
+
+    /// キャッシュをすべて破棄
+    pub fn clear_cache(&mut self) {
+        self.cache.clear();
+        self.file_cache.clear();
     }
 
     /// URL からマニフェストを読み込み・解析
     pub async fn parse_from_url(
         &mut self,
         url: &str,
+    ) -> Result<ProcessedManifest, ManifestServiceError> {
+        self.parse_from_url_with_sha256(url, None).await
+    }
+
+    /// URL からマニフェストを読み込み、`sha256`が指定されていれば内容を検証してから解析
+    pub async fn parse_from_url_with_sha256(
+        &mut self,
+        url: &str,
+        sha256: Option<&str>,
     ) -> Result<ProcessedManifest, ManifestServiceError> {
         // キャッシュをチェック
         if let Some(cached) = self.cache.get(url) {
@@ -216,48 +468,127 @@ impl ManifestService {
             });
         }
 
+        self.token_host = url::Url::parse(url)
+            .ok()
+            .and_then(|parsed| Self::host_with_port(&parsed));
         let content = self.fetch_remote_content(url).await?;
+        if let Some(expected) = sha256 {
+            Self::verify_sha256(url, &content, expected)?;
+        }
         let result = self.parse_from_string(&content, None).await?;
 
         Ok(result)
     }
 
-    /// 文字列からマニフェストを解析
+    /// YAMLまたはJSON文字列からマニフェストを解析
     pub async fn parse_from_string(
         &mut self,
         content: &str,
         base_path: Option<PathBuf>,
     ) -> Result<ProcessedManifest, ManifestServiceError> {
-        // 基本のYAMLパース
-        let extended_manifest: ExtendedManifest = serde_yaml::from_str(content)?;
+        self.parse_from_string_with_format(content, ManifestFormat::detect(content), base_path)
+            .await
+    }
+
+    /// 指定した形式の文字列からマニフェストを解析
+    pub async fn parse_from_string_with_format(
+        &mut self,
+        content: &str,
+        format: ManifestFormat,
+        base_path: Option<PathBuf>,
+    ) -> Result<ProcessedManifest, ManifestServiceError> {
+        // 基本のパース
+        let mut extended_manifest = Self::deserialize_extended(content, format)?;
+
+        // 環境変数の展開（URL検証の前に行う）
+        Self::expand_env_in_manifest(&mut extended_manifest, &|name| std::env::var(name).ok())?;
 
         // バリデーション
-        self.validate_manifest(&extended_manifest.manifest)?;
+        let validation_warnings = self.validate_manifest(&extended_manifest.manifest)?;
+        for warning in &validation_warnings {
+            tracing::warn!("{}", warning);
+        }
 
         // Deep manifest処理
-        let processed = self
+        let mut processed = self
             .process_deep_manifest(extended_manifest, base_path, 0, &mut Vec::new())
             .await?;
+        processed.warnings.splice(0..0, validation_warnings);
 
         Ok(processed)
     }
 
-    /// マニフェストを検証
-    pub fn validate_manifest(&self, manifest: &Manifest) -> Result<(), ManifestServiceError> {
-        // 重複するdestの検証
+    /// マニフェストを検証し、エラーにしなかった警告を返す
+    ///
+    /// 大文字小文字やUnicodeの正規化（NFC/NFD）だけが異なるdestは、macOSやWindowsの
+    /// ファイルシステムで同じディレクトリになるため警告する（`strict`の場合はエラー）。
+    pub fn validate_manifest(
+        &self,
+        manifest: &Manifest,
+    ) -> Result<Vec<String>, ManifestServiceError> {
+        let mut warnings = Vec::new();
+
+        // destはワークスペース内の相対パスで、正規化後に重複しないこと
         let mut dest_set = std::collections::HashSet::new();
-        for repo in &manifest.repos {
-            if !dest_set.insert(&repo.dest) {
+        let mut folded_dests: HashMap<String, &str> = HashMap::new();
+        for (index, repo) in manifest.repos.iter().enumerate() {
+            let dest = normalize_dest(&repo.dest).map_err(|e| {
+                ManifestServiceError::ValidationError(format!(
+                    "Repository #{} ({}): {}",
+                    index + 1,
+                    if repo.is_local_only() {
+                        "no url"
+                    } else {
+                        &repo.url
+                    },
+                    e
+                ))
+            })?;
+            let folded = fold_dest(&dest);
+            if !dest_set.insert(dest) {
                 return Err(ManifestServiceError::ValidationError(format!(
                     "Duplicate destination path: {}",
                     repo.dest
                 )));
             }
+            if let Some(other) = folded_dests.insert(folded, &repo.dest) {
+                let message = format!(
+                    "Destination paths '{}' and '{}' collide on case-insensitive filesystems",
+                    other, repo.dest
+                );
+                if self.options.strict {
+                    return Err(ManifestServiceError::ValidationError(message));
+                }
+                warnings.push(message);
+            }
 
-            // URL検証 (Git リポジトリの場合のみ Git URL バリデーション)
-            if repo.scm == crate::domain::value_objects::scm_type::ScmType::Git {
+            // URL検証 (Git リポジトリの場合のみ Git URL バリデーション、ローカル専用は対象外)
+            if repo.scm == crate::domain::value_objects::scm_type::ScmType::Git
+                && !repo.is_local_only()
+            {
                 GitUrl::new(&repo.url)?;
             }
+
+            // clone_argsはオプションのみ（URLとdestはマニフェストから渡す）
+            if let Some(arg) = repo
+                .clone_args
+                .iter()
+                .flatten()
+                .find(|arg| !arg.starts_with('-'))
+            {
+                return Err(ManifestServiceError::ValidationError(format!(
+                    "Repository '{}' has clone_args entry '{}' that is not an option (options start with '-')",
+                    repo.dest, arg
+                )));
+            }
+
+            // タグは空でない文字列のみ
+            if repo.tags.iter().flatten().any(|tag| tag.trim().is_empty()) {
+                return Err(ManifestServiceError::ValidationError(format!(
+                    "Repository '{}' has an empty tag",
+                    repo.dest
+                )));
+            }
         }
 
         // グループ検証
@@ -274,7 +605,35 @@ impl ManifestService {
             }
         }
 
-        Ok(())
+        Ok(warnings)
+    }
+
+    /// URLのないローカル専用リポジトリのディレクトリがワークスペースに存在するか検証
+    ///
+    /// ローカル専用のリポジトリはクローンできないため、事前に作成されている必要がある。
+    pub fn validate_local_repos(
+        &self,
+        manifest: &Manifest,
+        workspace_root: &Path,
+    ) -> Result<(), ManifestServiceError> {
+        let missing: Vec<&str> = manifest
+            .repos
+            .iter()
+            .filter(|repo| {
+                repo.is_local_only()
+                    && !resolve_dest_in(workspace_root, &repo.dest).is_ok_and(|path| path.is_dir())
+            })
+            .map(|repo| repo.dest.as_str())
+            .collect();
+
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(ManifestServiceError::ValidationError(format!(
+                "Local-only repositories (no url) must already exist: {}",
+                missing.join(", ")
+            )))
+        }
     }
 
     /// 指定されたグループでマニフェストをフィルタリング
@@ -405,8 +764,16 @@ impl ManifestService {
 
                     // インクルードマニフェストを取得・処理
                     let included_content = self.fetch_remote_content(&include_url).await?;
-                    let included_extended: ExtendedManifest =
-                        serde_yaml::from_str(&included_content)?;
+                    if let Some(expected) = &include.sha256 {
+                        Self::verify_sha256(&include_url, &included_content, expected)?;
+                    }
+                    let mut included_extended = Self::deserialize_extended(
+                        &included_content,
+                        ManifestFormat::from_path(Path::new(&include_url)),
+                    )?;
+                    Self::expand_env_in_manifest(&mut included_extended, &|name| {
+                        std::env::var(name).ok()
+                    })?;
 
                     let included_processed = self
                         .process_deep_manifest(included_extended, None, depth + 1, visited)
@@ -451,6 +818,55 @@ impl ManifestService {
         })
     }
 
+    /// 形式に応じて拡張マニフェストをデシリアライズ
+    fn deserialize_extended(
+        content: &str,
+        format: ManifestFormat,
+    ) -> Result<ExtendedManifest, ManifestServiceError> {
+        Ok(match format {
+            ManifestFormat::Yaml => serde_yaml::from_str(content)?,
+            ManifestFormat::Toml => toml::from_str(content)?,
+            ManifestFormat::Json => {
+                serde_json::from_str(content).map_err(ManifestServiceError::JsonParseError)?
+            }
+        })
+    }
+
+    /// マニフェスト内のURL・dest・インクルードURLの環境変数（`${VAR}` / `${VAR:-default}`）を展開
+    fn expand_env_in_manifest(
+        manifest: &mut ExtendedManifest,
+        lookup: &dyn Fn(&str) -> Option<String>,
+    ) -> Result<(), ManifestServiceError> {
+        for repo in &mut manifest.manifest.repos {
+            repo.url = expand_env_vars(&repo.url, &format!("url of '{}'", repo.dest), lookup)?;
+
+            let dest = expand_env_vars(&repo.dest, "dest", lookup)?;
+            if dest != repo.dest {
+                // 展開結果でワークスペース外を指せないようにする
+                let path = Path::new(&dest);
+                if path.is_absolute()
+                    || path
+                        .components()
+                        .any(|c| matches!(c, std::path::Component::ParentDir))
+                {
+                    return Err(ManifestServiceError::ValidationError(format!(
+                        "Destination '{}' expands to a path outside the workspace: {}",
+                        repo.dest, dest
+                    )));
+                }
+                repo.dest = dest;
+            }
+        }
+
+        if let Some(includes) = &mut manifest.includes {
+            for include in includes {
+                include.url = expand_env_vars(&include.url, "include url", lookup)?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Future manifest設定を処理
     fn process_future_config(&self, future_config: &FutureManifestConfig) -> Vec<String> {
         let mut warnings = Vec::new();
@@ -500,6 +916,88 @@ impl ManifestService {
         Ok(url.to_string())
     }
 
+    /// 取得した内容のSHA-256を期待値（大文字・小文字は区別しない）と比較
+    fn verify_sha256(url: &str, content: &str, expected: &str) -> Result<(), ManifestServiceError> {
+        let actual = hex::encode(Sha256::digest(content.as_bytes()));
+        if actual.eq_ignore_ascii_case(expected.trim()) {
+            Ok(())
+        } else {
+            Err(ManifestServiceError::ChecksumMismatch {
+                url: url.to_string(),
+                expected: expected.trim().to_string(),
+                actual,
+            })
+        }
+    }
+
+    /// `url`のホストがトークンの送り先ならBearerトークンを返す
+    fn bearer_token_for(&self, url: &str) -> Option<&str> {
+        let token = self.options.bearer_token.as_deref()?;
+        self.sends_credentials_to(url).then_some(token)
+    }
+
+    /// `url`のホストにトークンと`http_headers`を送ってよいか
+    fn sends_credentials_to(&self, url: &str) -> bool {
+        let Some(allowed) = self
+            .options
+            .bearer_token_host
+            .as_deref()
+            .or(self.token_host.as_deref())
+        else {
+            return false;
+        };
+        let Ok(parsed) = url::Url::parse(url) else {
+            return false;
+        };
+        let Some(host) = parsed.host_str() else {
+            return false;
+        };
+
+        allowed.eq_ignore_ascii_case(host)
+            || Self::host_with_port(&parsed).is_some_and(|h| allowed.eq_ignore_ascii_case(&h))
+    }
+
+    /// URLの`host:port`（既定のポートなら`host`のみ）
+    fn host_with_port(url: &url::Url) -> Option<String> {
+        let host = url.host_str()?;
+        Some(match url.port() {
+            Some(port) => format!("{}:{}", host, port),
+            None => host.to_string(),
+        })
+    }
+
+    /// GETリクエストを送信し、5xx応答なら待機時間を倍にしながらリトライ
+    async fn send_with_retry(&self, url: &str) -> Result<reqwest::Response, ManifestServiceError> {
+        let mut backoff = std::time::Duration::from_millis(self.options.retry_backoff_ms);
+        let mut attempt = 0;
+        loop {
+            let mut request = self.http_client.get(url);
+            if self.sends_credentials_to(url) {
+                for (name, value) in &self.options.http_headers {
+                    request = request.header(name.as_str(), value.as_str());
+                }
+            }
+            if let Some(token) = self.bearer_token_for(url) {
+                request = request.bearer_auth(token);
+            }
+            let response = request.send().await?;
+
+            if !response.status().is_server_error() || attempt >= self.options.max_retries {
+                return Ok(response);
+            }
+
+            tracing::warn!(
+                "Fetching manifest {} failed with HTTP {}, retrying in {:?}",
+                url,
+                response.status(),
+                backoff
+            );
+            tokio::time::sleep(backoff).await;
+            backoff *= 2;
+            attempt += 1;
+        }
+    }
+
     /// リモートコンテンツを取得
     async fn fetch_remote_content(&self, url: &str) -> Result<String, ManifestServiceError> {
         if !self.options.enable_remote_fetch {
@@ -510,7 +1008,7 @@ impl ManifestService {
         }
 
         if url.starts_with("http://") || url.starts_with("https://") {
-            let response = self.http_client.get(url).send().await?;
+            let response = self.send_with_retry(url).await?;
 
             if response.status().is_success() {
                 Ok(response.text().await?)
@@ -583,12 +1081,103 @@ impl ManifestService {
         Ok(serde_yaml::to_string(manifest)?)
     }
 
+    /// マニフェストをTOML文字列にシリアライズ
+    pub fn serialize_to_toml(&self, manifest: &Manifest) -> Result<String, ManifestServiceError> {
+        Ok(toml::to_string_pretty(manifest)?)
+    }
+
+    /// マニフェストを指定した形式の文字列にシリアライズ
+    pub fn serialize(
+        &self,
+        manifest: &Manifest,
+        format: ManifestFormat,
+    ) -> Result<String, ManifestServiceError> {
+        match format {
+            ManifestFormat::Yaml => self.serialize_to_yaml(manifest),
+            ManifestFormat::Toml => self.serialize_to_toml(manifest),
+            ManifestFormat::Json => self.serialize_to_json(manifest),
+        }
+    }
+
     /// マニフェストをJSON文字列にシリアライズ
     pub fn serialize_to_json(&self, manifest: &Manifest) -> Result<String, ManifestServiceError> {
         Ok(serde_json::to_string_pretty(manifest)?)
     }
 }
 
+/// ファイル内容のハッシュを計算
+fn hash_content(bytes: &[u8]) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// 大文字小文字とUnicodeの正規化を区別しないファイルシステムで同じになるdestのキー
+fn fold_dest(dest: &Path) -> String {
+    dest.to_string_lossy()
+        .nfc()
+        .collect::<String>()
+        .to_lowercase()
+}
+
+/// 文字列中の `${VAR}` と `${VAR:-default}` を展開
+///
+/// 未定義の変数はデフォルト値がない限りエラーとする。`$` 単体はそのまま残す。
+fn expand_env_vars(
+    value: &str,
+    field: &str,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> Result<String, ManifestServiceError> {
+    let mut result = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(start) = rest.find("${") {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let end = after
+            .find('}')
+            .ok_or_else(|| ManifestServiceError::InvalidEnvReference {
+                field: field.to_string(),
+                value: value.to_string(),
+            })?;
+        let expr = &after[..end];
+
+        let (name, default) = match expr.split_once(":-") {
+            Some((name, default)) => (name, Some(default)),
+            None => (expr, None),
+        };
+
+        let valid_name = name
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !valid_name {
+            return Err(ManifestServiceError::InvalidEnvReference {
+                field: field.to_string(),
+                value: value.to_string(),
+            });
+        }
+
+        // シェルと同様に、空文字の変数もデフォルト値で置き換える
+        let resolved = match (lookup(name).filter(|v| !v.is_empty()), default) {
+            (Some(v), _) => v,
+            (None, Some(default)) => default.to_string(),
+            (None, None) => {
+                return Err(ManifestServiceError::UndefinedEnvVar {
+                    var: name.to_string(),
+                    field: field.to_string(),
+                })
+            }
+        };
+        result.push_str(&resolved);
+        rest = &after[end + 1..];
+    }
+
+    result.push_str(rest);
+    Ok(result)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -651,6 +1240,216 @@ repos:
         ));
     }
 
+    #[tokio::test]
+    async fn test_local_only_repo_validation() {
+        let yaml_content = r#"
+repos:
+  - dest: scratch
+  - dest: app
+    url: https://github.com/example/app.git
+"#;
+
+        let mut service = ManifestService::default();
+        let result = service.parse_from_string(yaml_content, None).await.unwrap();
+        assert!(result.manifest.repos[0].is_local_only());
+
+        let temp_dir = TempDir::new().unwrap();
+        let err = service
+            .validate_local_repos(&result.manifest, temp_dir.path())
+            .unwrap_err();
+        assert!(err.to_string().contains("scratch"));
+
+        // クローン対象のリポジトリは存在しなくてもよい
+        std::fs::create_dir(temp_dir.path().join("scratch")).unwrap();
+        service
+            .validate_local_repos(&result.manifest, temp_dir.path())
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_parse_sync_strategy() {
+        use crate::domain::entities::manifest::SyncStrategy;
+
+        let yaml_content = r#"
+default_sync_strategy: rebase
+repos:
+  - dest: repo1
+    url: https://github.com/example/repo1.git
+    sync_strategy: merge
+  - dest: repo2
+    url: https://github.com/example/repo2.git
+"#;
+
+        let mut service = ManifestService::default();
+        let result = service.parse_from_string(yaml_content, None).await.unwrap();
+        let manifest = result.manifest;
+
+        assert_eq!(manifest.default_sync_strategy, Some(SyncStrategy::Rebase));
+        assert_eq!(
+            manifest.sync_strategy_for(&manifest.repos[0]),
+            SyncStrategy::Merge
+        );
+        assert_eq!(
+            manifest.sync_strategy_for(&manifest.repos[1]),
+            SyncStrategy::Rebase
+        );
+
+        let invalid = yaml_content.replace("sync_strategy: merge", "sync_strategy: squash");
+        let result = service.parse_from_string(&invalid, None).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_parse_repo_tags() {
+        let yaml_content = r#"
+repos:
+  - dest: repo1
+    url: https://github.com/example/repo1.git
+    tags: [rust, service]
+  - dest: repo2
+    url: https://github.com/example/repo2.git
+"#;
+
+        let mut service = ManifestService::default();
+        let result = service.parse_from_string(yaml_content, None).await.unwrap();
+        let manifest = result.manifest;
+
+        assert!(manifest.repos[0].has_tag("rust"));
+        assert!(manifest.repos[0].has_tag("service"));
+        assert_eq!(manifest.repos[1].tags, None);
+
+        for invalid in ["tags: [rust, \"\"]", "tags: [\"  \"]"] {
+            let content = yaml_content.replace("tags: [rust, service]", invalid);
+            let result = service.parse_from_string(&content, None).await;
+            assert!(matches!(
+                result,
+                Err(ManifestServiceError::ValidationError(msg)) if msg.contains("empty tag")
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_parse_repo_clone_args() {
+        let yaml_content = r#"
+repos:
+  - dest: monorepo
+    url: https://github.com/example/monorepo.git
+    clone_args: ["--filter=blob:none", "--single-branch"]
+  - dest: lib
+    url: https://github.com/example/lib.git
+"#;
+
+        let mut service = ManifestService::default();
+        let manifest = service
+            .parse_from_string(yaml_content, None)
+            .await
+            .unwrap()
+            .manifest;
+        assert_eq!(
+            manifest.repos[0].to_clone_options().clone_args,
+            vec!["--filter=blob:none", "--single-branch"]
+        );
+        assert!(manifest.repos[1].to_clone_options().clone_args.is_empty());
+
+        // A positional argument would be taken as the URL or the destination
+        let content = yaml_content.replace("\"--single-branch\"", "\"elsewhere\"");
+        let result = service.parse_from_string(&content, None).await;
+        assert!(matches!(
+            result,
+            Err(ManifestServiceError::ValidationError(msg)) if msg.contains("'elsewhere'")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_parse_rejects_unsafe_dests() {
+        let yaml_content = r#"
+repos:
+  - dest: app
+    url: https://github.com/example/app.git
+  - dest: DEST
+    url: https://github.com/example/lib.git
+"#;
+
+        let mut service = ManifestService::default();
+        for (dest, expected) in [
+            ("/etc", "must be relative to the workspace"),
+            ("../x", "points outside the workspace"),
+            ("\"\"", "destination is empty"),
+        ] {
+            let content = yaml_content.replace("DEST", dest);
+            let result = service.parse_from_string(&content, None).await;
+            assert!(
+                matches!(
+                    &result,
+                    Err(ManifestServiceError::ValidationError(msg))
+                        if msg.contains("Repository #2 (https://github.com/example/lib.git)")
+                            && msg.contains(expected)
+                ),
+                "{}: {:?}",
+                dest,
+                result.map(|_| ())
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_normalized_duplicate_dests() {
+        let manifest = Manifest::new(vec![
+            ManifestRepo::new("https://github.com/example/app.git", "libs/app"),
+            ManifestRepo::new("https://github.com/example/app2.git", "./libs//app"),
+        ]);
+
+        let result = ManifestService::default().validate_manifest(&manifest);
+        assert!(matches!(
+            result,
+            Err(ManifestServiceError::ValidationError(msg)) if msg.contains("Duplicate destination")
+        ));
+    }
+
+    #[test]
+    fn test_validate_case_insensitive_dest_collisions() {
+        let manifest = Manifest::new(vec![
+            ManifestRepo::new("https://github.com/example/foo.git", "libs/Foo"),
+            ManifestRepo::new("https://github.com/example/foo2.git", "libs/foo"),
+            ManifestRepo::new("https://github.com/example/bar.git", "libs/bar"),
+        ]);
+
+        let warnings = ManifestService::default()
+            .validate_manifest(&manifest)
+            .unwrap();
+        assert_eq!(
+            warnings,
+            vec![
+                "Destination paths 'libs/Foo' and 'libs/foo' collide on case-insensitive filesystems"
+            ]
+        );
+
+        let strict = ManifestService::new(ManifestProcessingOptions::default().with_strict(true));
+        assert!(matches!(
+            strict.validate_manifest(&manifest),
+            Err(ManifestServiceError::ValidationError(msg)) if msg.contains("'libs/Foo' and 'libs/foo'")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_unicode_normalization_dest_collisions() {
+        // NFC（U+00E9）とNFD（e + U+0301）の「café」
+        let yaml_content = "repos:\n  - dest: caf\u{e9}\n    url: https://github.com/example/a.git\n  - dest: cafe\u{301}\n    url: https://github.com/example/b.git\n";
+
+        let mut service = ManifestService::default();
+        let processed = service.parse_from_string(yaml_content, None).await.unwrap();
+        assert_eq!(processed.manifest.repos.len(), 2);
+        assert_eq!(processed.warnings.len(), 1, "{:?}", processed.warnings);
+        assert!(processed.warnings[0].contains("collide on case-insensitive filesystems"));
+
+        let mut strict =
+            ManifestService::new(ManifestProcessingOptions::default().with_strict(true));
+        assert!(matches!(
+            strict.parse_from_string(yaml_content, None).await,
+            Err(ManifestServiceError::ValidationError(_))
+        ));
+    }
+
     #[tokio::test]
     async fn test_filter_by_groups() {
         let repos = vec![
@@ -790,6 +1589,145 @@ future:
         assert!(result.is_err());
     }
 
+    fn env(name: &str) -> Option<String> {
+        match name {
+            "MIRROR" => Some("https://mirror.example.com".to_string()),
+            "TEAM" => Some("platform".to_string()),
+            "EMPTY" => Some(String::new()),
+            "UP" => Some("..".to_string()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_expand_env_vars() {
+        assert_eq!(
+            expand_env_vars("${MIRROR}/repo.git", "url", &env).unwrap(),
+            "https://mirror.example.com/repo.git"
+        );
+        assert_eq!(
+            expand_env_vars("${TEAM}/${TEAM}-api", "dest", &env).unwrap(),
+            "platform/platform-api"
+        );
+        assert_eq!(
+            expand_env_vars("no variables $HOME", "url", &env).unwrap(),
+            "no variables $HOME"
+        );
+    }
+
+    #[test]
+    fn test_expand_env_vars_default() {
+        assert_eq!(
+            expand_env_vars("${UNSET:-https://github.com}/x.git", "url", &env).unwrap(),
+            "https://github.com/x.git"
+        );
+        assert_eq!(
+            expand_env_vars("${EMPTY:-fallback}", "url", &env).unwrap(),
+            "fallback"
+        );
+        assert_eq!(
+            expand_env_vars("${TEAM:-fallback}", "url", &env).unwrap(),
+            "platform"
+        );
+    }
+
+    #[test]
+    fn test_expand_env_vars_errors() {
+        assert!(matches!(
+            expand_env_vars("${UNSET}/x.git", "url", &env),
+            Err(ManifestServiceError::UndefinedEnvVar { var, .. }) if var == "UNSET"
+        ));
+        assert!(matches!(
+            expand_env_vars("${MIRROR/x.git", "url", &env),
+            Err(ManifestServiceError::InvalidEnvReference { .. })
+        ));
+        assert!(matches!(
+            expand_env_vars("${1BAD}", "url", &env),
+            Err(ManifestServiceError::InvalidEnvReference { .. })
+        ));
+    }
+
+    #[test]
+    fn test_expand_env_rejects_dest_traversal() {
+        let repos = vec![ManifestRepo::new(
+            "https://github.com/example/repo.git",
+            "${UP}/outside",
+        )];
+        let mut manifest = ExtendedManifest {
+            manifest: Manifest::new(repos),
+            includes: None,
+            future: None,
+        };
+
+        let result = ManifestService::expand_env_in_manifest(&mut manifest, &env);
+        assert!(matches!(
+            result,
+            Err(ManifestServiceError::ValidationError(msg)) if msg.contains("outside the workspace")
+        ));
+    }
+
+    #[test]
+    fn test_expand_env_in_manifest_fields() {
+        let repos = vec![ManifestRepo::new("${MIRROR}/api.git", "${TEAM}/api")];
+        let mut manifest = ExtendedManifest {
+            manifest: Manifest::new(repos),
+            includes: Some(vec![ManifestInclude {
+                url: "${MIRROR}/manifest.yml".to_string(),
+                revision: None,
+                groups: None,
+                priority: 0,
+                sha256: None,
+            }]),
+            future: None,
+        };
+
+        ManifestService::expand_env_in_manifest(&mut manifest, &env).unwrap();
+        assert_eq!(
+            manifest.manifest.repos[0].url,
+            "https://mirror.example.com/api.git"
+        );
+        assert_eq!(manifest.manifest.repos[0].dest, "platform/api");
+        assert_eq!(
+            manifest.includes.unwrap()[0].url,
+            "https://mirror.example.com/manifest.yml"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_parse_expands_env_before_url_validation() {
+        std::env::set_var("WMGR_TEST_MANIFEST_MIRROR", "https://github.com/example");
+        let yaml_content = r#"
+repos:
+  - dest: repo1
+    url: ${WMGR_TEST_MANIFEST_MIRROR}/repo1.git
+  - dest: repo2
+    url: ${WMGR_TEST_MANIFEST_UNSET:-https://gitlab.com/example}/repo2.git
+"#;
+
+        let mut service = ManifestService::default();
+        let result = service.parse_from_string(yaml_content, None).await.unwrap();
+        assert_eq!(
+            result.manifest.repos[0].url,
+            "https://github.com/example/repo1.git"
+        );
+        assert_eq!(
+            result.manifest.repos[1].url,
+            "https://gitlab.com/example/repo2.git"
+        );
+
+        let missing = r#"
+repos:
+  - dest: repo1
+    url: ${WMGR_TEST_MANIFEST_UNSET}/repo1.git
+"#;
+        let result = service.parse_from_string(missing, None).await;
+        assert!(matches!(
+            result,
+            Err(ManifestServiceError::UndefinedEnvVar { var, .. })
+                if var == "WMGR_TEST_MANIFEST_UNSET"
+        ));
+    }
+
     #[test]
     fn test_serialize_manifest() {
         let repos = vec![ManifestRepo::new(
@@ -809,6 +1747,159 @@ future:
         assert!(json_output.contains("\"dest\": \"repo1\""));
     }
 
+    const EQUIVALENT_YAML: &str = r#"
+default_branch: main
+repos:
+  - dest: app
+    url: https://github.com/example/app.git
+    branch: develop
+    shallow: true
+    copy:
+      - file: config.yml
+        dest: shared/config.yml
+  - dest: lib
+    url: https://github.com/example/lib.git
+groups:
+  core:
+    repos: [app, lib]
+    description: Core repositories
+"#;
+
+    const EQUIVALENT_TOML: &str = r#"
+default_branch = "main"
+
+[[repos]]
+dest = "app"
+url = "https://github.com/example/app.git"
+branch = "develop"
+shallow = true
+
+[[repos.copy]]
+file = "config.yml"
+dest = "shared/config.yml"
+
+[[repos]]
+dest = "lib"
+url = "https://github.com/example/lib.git"
+
+[groups.core]
+repos = ["app", "lib"]
+description = "Core repositories"
+"#;
+
+    #[tokio::test]
+    async fn test_toml_round_trip_matches_yaml() {
+        let mut service = ManifestService::default();
+        let from_yaml = service
+            .parse_from_string(EQUIVALENT_YAML, None)
+            .await
+            .unwrap();
+        let from_toml = service
+            .parse_from_string_with_format(EQUIVALENT_TOML, ManifestFormat::Toml, None)
+            .await
+            .unwrap();
+
+        let serialized = service.serialize_to_toml(&from_toml.manifest).unwrap();
+        let reparsed = service
+            .parse_from_string_with_format(&serialized, ManifestFormat::Toml, None)
+            .await
+            .unwrap();
+
+        let as_json = |manifest: &Manifest| serde_json::to_value(manifest).unwrap();
+        assert_eq!(as_json(&from_toml.manifest), as_json(&from_yaml.manifest));
+        assert_eq!(as_json(&reparsed.manifest), as_json(&from_yaml.manifest));
+    }
+
+    #[tokio::test]
+    async fn test_parse_from_file_selects_format_by_extension() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("wmgr.toml");
+        std::fs::write(&path, EQUIVALENT_TOML).unwrap();
+
+        let mut service = ManifestService::default();
+        let processed = service.parse_from_file(&path).await.unwrap();
+
+        assert_eq!(processed.manifest.repos.len(), 2);
+        assert_eq!(
+            processed.manifest.repos[0].branch.as_deref(),
+            Some("develop")
+        );
+        assert_eq!(
+            ManifestFormat::from_path(Path::new("a/WMGR.TOML")),
+            ManifestFormat::Toml
+        );
+        assert_eq!(
+            ManifestFormat::from_path(Path::new("wmgr.yml")),
+            ManifestFormat::Yaml
+        );
+    }
+
+    const FULL_JSON: &str = r#"{
+  "repos": [
+    {
+      "dest": "app",
+      "url": "https://github.com/example/app.git",
+      "branch": "develop",
+      "shallow": true
+    },
+    {
+      "dest": "lib",
+      "url": "https://github.com/example/lib.git"
+    }
+  ],
+  "groups": {
+    "core": {
+      "repos": ["app", "lib"],
+      "description": "Core repositories"
+    }
+  },
+  "default_branch": "main"
+}"#;
+
+    #[tokio::test]
+    async fn test_parse_json_manifest() {
+        let mut service = ManifestService::default();
+        let result = service.parse_from_string(FULL_JSON, None).await.unwrap();
+
+        let manifest = result.manifest;
+        assert_eq!(manifest.repos.len(), 2);
+        assert_eq!(manifest.repos[0].dest, "app");
+        assert_eq!(manifest.repos[0].branch.as_deref(), Some("develop"));
+        assert_eq!(manifest.repos[0].shallow, Some(true));
+        assert_eq!(manifest.repos[1].dest, "lib");
+        assert_eq!(manifest.groups.unwrap()["core"].repos, ["app", "lib"]);
+        assert_eq!(manifest.default_branch.as_deref(), Some("main"));
+    }
+
+    #[tokio::test]
+    async fn test_json_parse_errors_are_reported_as_json() {
+        let mut service = ManifestService::default();
+        let result = service
+            .parse_from_string(r#"{"repos": [{"dest": "app",}]}"#, None)
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            ManifestServiceError::JsonParseError(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_parse_from_file_routes_json_extension() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("wmgr.json");
+        // 先頭の空白があってもJSONとして扱う
+        std::fs::write(&path, format!("\n  {}", FULL_JSON)).unwrap();
+
+        let mut service = ManifestService::default();
+        let processed = service.parse_from_file(&path).await.unwrap();
+        assert_eq!(processed.manifest.repos.len(), 2);
+        assert_eq!(
+            ManifestFormat::from_path(Path::new("wmgr.JSON")),
+            ManifestFormat::Json
+        );
+    }
+
     #[test]
     fn test_depth_limit() {
         let mut options = ManifestProcessingOptions::default();
@@ -844,4 +1935,347 @@ future:
         assert!(merged.repos.iter().any(|r| r.dest == "repo1"));
         assert!(merged.repos.iter().any(|r| r.dest == "repo2"));
     }
+
+    #[tokio::test]
+    async fn test_parse_from_file_caches_unchanged_manifest() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("wmgr.yml");
+        let content = "repos:\n  - dest: app\n    url: https://github.com/example/app.git\n";
+        std::fs::write(&path, content).unwrap();
+
+        let mut service = ManifestService::default();
+        let first = service.parse_from_file(&path).await.unwrap();
+        let second = service.parse_from_file(&path).await.unwrap();
+
+        assert_eq!(first.manifest.repos.len(), second.manifest.repos.len());
+        assert_eq!(
+            service.cache_stats(),
+            ManifestCacheStats {
+                hits: 1,
+                misses: 1,
+                entries: 1,
+            }
+        );
+
+        // 同じ内容で書き直しても再解析しない
+        std::fs::write(&path, content).unwrap();
+        service.parse_from_file(&path).await.unwrap();
+        assert_eq!(service.cache_stats().misses, 1);
+    }
+
+    #[tokio::test]
+    async fn test_parse_from_file_reparses_modified_manifest() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("wmgr.yml");
+        std::fs::write(
+            &path,
+            "repos:\n  - dest: app\n    url: https://github.com/example/app.git\n",
+        )
+        .unwrap();
+
+        let mut service = ManifestService::default();
+        service.parse_from_file(&path).await.unwrap();
+
+        std::fs::write(
+            &path,
+            "repos:\n  - dest: app\n    url: https://github.com/example/app.git\n  - dest: lib\n    url: https://github.com/example/lib.git\n",
+        )
+        .unwrap();
+        let reloaded = service.parse_from_file(&path).await.unwrap();
+
+        assert_eq!(reloaded.manifest.repos.len(), 2);
+        assert_eq!(service.cache_stats().hits, 0);
+        assert_eq!(service.cache_stats().misses, 2);
+
+        service.clear_cache();
+        assert_eq!(service.cache_stats().entries, 0);
+    }
+
+    #[tokio::test]
+    async fn test_include_sha256_is_verified() {
+        let temp_dir = TempDir::new().unwrap();
+        let included = "repos:\n  - dest: lib\n    url: https://github.com/example/lib.git\n";
+        let included_path = temp_dir.path().join("included.yml");
+        std::fs::write(&included_path, included).unwrap();
+        let digest = hex::encode(Sha256::digest(included.as_bytes()));
+
+        let manifest = |sha256: &str| {
+            format!(
+                "repos:\n  - dest: app\n    url: https://github.com/example/app.git\nincludes:\n  - url: {}\n    sha256: {}\n",
+                included_path.display(),
+                sha256
+            )
+        };
+
+        let mut service = ManifestService::default();
+        let result = service
+            .parse_from_string(&manifest(&digest.to_uppercase()), None)
+            .await
+            .unwrap();
+        assert_eq!(result.manifest.repos.len(), 2);
+
+        let wrong = "0".repeat(64);
+        let result = service.parse_from_string(&manifest(&wrong), None).await;
+        assert!(matches!(
+            result,
+            Err(ManifestServiceError::ChecksumMismatch { expected, actual, .. })
+                if expected == wrong && actual == digest
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_parse_from_url_with_sha256() {
+        let temp_dir = TempDir::new().unwrap();
+        let content = "repos:\n  - dest: app\n    url: https://github.com/example/app.git\n";
+        let path = temp_dir.path().join("wmgr.yml");
+        std::fs::write(&path, content).unwrap();
+        let url = path.to_str().unwrap();
+        let digest = hex::encode(Sha256::digest(content.as_bytes()));
+
+        let mut service = ManifestService::default();
+        let result = service
+            .parse_from_url_with_sha256(url, Some(&digest))
+            .await
+            .unwrap();
+        assert_eq!(result.manifest.repos[0].dest, "app");
+
+        let result = service
+            .parse_from_url_with_sha256(url, Some(&"f".repeat(64)))
+            .await;
+        assert!(matches!(
+            result,
+            Err(ManifestServiceError::ChecksumMismatch { url: failed, .. }) if failed == url
+        ));
+    }
+
+    /// 用意した応答を順に1つずつ返すHTTPサーバーを起動し、受け取ったリクエストを記録する
+    fn serve_responses(
+        responses: Vec<String>,
+    ) -> (String, std::sync::Arc<std::sync::Mutex<Vec<String>>>) {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let recorded = requests.clone();
+
+        std::thread::spawn(move || {
+            for response in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = Vec::new();
+                let mut buf = [0u8; 1024];
+                while !request.ends_with(b"\r\n\r\n") {
+                    let n = stream.read(&mut buf).unwrap();
+                    if n == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&buf[..n]);
+                }
+                recorded
+                    .lock()
+                    .unwrap()
+                    .push(String::from_utf8_lossy(&request).to_lowercase());
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        (url, requests)
+    }
+
+    fn http_response(status: &str, extra_headers: &str, body: &str) -> String {
+        format!(
+            "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n{}\r\n{}",
+            status,
+            body.len(),
+            extra_headers,
+            body
+        )
+    }
+
+    const REMOTE_MANIFEST: &str =
+        "repos:\n  - dest: app\n    url: https://github.com/example/app.git\n";
+
+    #[tokio::test]
+    async fn test_remote_fetch_retries_server_errors() {
+        let (url, requests) = serve_responses(vec![
+            http_response("503 Service Unavailable", "", ""),
+            http_response("502 Bad Gateway", "", ""),
+            http_response("200 OK", "", REMOTE_MANIFEST),
+        ]);
+
+        let mut service = ManifestService::new(ManifestProcessingOptions {
+            max_retries: 2,
+            retry_backoff_ms: 10,
+            ..Default::default()
+        });
+        let result = service
+            .parse_from_url(&format!("{}/wmgr.yml", url))
+            .await
+            .unwrap();
+
+        assert_eq!(result.manifest.repos[0].dest, "app");
+        assert_eq!(requests.lock().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_remote_fetch_gives_up_after_max_retries() {
+        let (url, requests) = serve_responses(vec![
+            http_response("500 Internal Server Error", "", ""),
+            http_response("500 Internal Server Error", "", ""),
+        ]);
+
+        let mut service = ManifestService::new(ManifestProcessingOptions {
+            max_retries: 1,
+            retry_backoff_ms: 10,
+            ..Default::default()
+        });
+        let result = service.parse_from_url(&format!("{}/wmgr.yml", url)).await;
+
+        assert!(matches!(
+            result,
+            Err(ManifestServiceError::RemoteManifestFetchFailed { reason, .. }) if reason.contains("500")
+        ));
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_remote_fetch_sends_configured_headers() {
+        let (url, requests) = serve_responses(vec![http_response("200 OK", "", REMOTE_MANIFEST)]);
+
+        let mut http_headers = HashMap::new();
+        http_headers.insert(
+            "Authorization".to_string(),
+            "Bearer secret-token".to_string(),
+        );
+        let mut service = ManifestService::new(ManifestProcessingOptions {
+            http_headers,
+            ..Default::default()
+        });
+        service
+            .parse_from_url(&format!("{}/wmgr.yml", url))
+            .await
+            .unwrap();
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].contains("authorization: bearer secret-token"));
+    }
+
+    #[tokio::test]
+    async fn test_remote_fetch_respects_max_redirects() {
+        let (url, _) = serve_responses(vec![http_response(
+            "302 Found",
+            "Location: /moved.yml\r\n",
+            "",
+        )]);
+
+        let mut service = ManifestService::new(ManifestProcessingOptions {
+            max_redirects: 0,
+            ..Default::default()
+        });
+        let result = service.parse_from_url(&format!("{}/wmgr.yml", url)).await;
+
+        assert!(matches!(
+            result,
+            Err(ManifestServiceError::HttpError(e)) if e.is_redirect()
+        ));
+    }
+
+    /// `REMOTE_MANIFEST`をインクルードするマニフェスト
+    fn manifest_including(include_url: &str) -> String {
+        format!(
+            "repos:\n  - dest: lib\n    url: https://github.com/example/lib.git\nincludes:\n  - url: {}/included.yml\n",
+            include_url
+        )
+    }
+
+    #[tokio::test]
+    async fn test_bearer_token_is_sent_only_to_top_level_host_by_default() {
+        let (include_url, include_requests) =
+            serve_responses(vec![http_response("200 OK", "", REMOTE_MANIFEST)]);
+        let (url, requests) = serve_responses(vec![http_response(
+            "200 OK",
+            "",
+            &manifest_including(&include_url),
+        )]);
+
+        let mut service = ManifestService::new(
+            ManifestProcessingOptions::default().with_bearer_token("secret-token", None),
+        );
+        let result = service
+            .parse_from_url(&format!("{}/wmgr.yml", url))
+            .await
+            .unwrap();
+        assert_eq!(result.manifest.repos.len(), 2);
+
+        assert!(requests.lock().unwrap()[0].contains("authorization: bearer secret-token"));
+        assert!(!include_requests.lock().unwrap()[0].contains("authorization"));
+    }
+
+    #[tokio::test]
+    async fn test_configured_headers_are_not_sent_to_other_hosts() {
+        let (include_url, include_requests) =
+            serve_responses(vec![http_response("200 OK", "", REMOTE_MANIFEST)]);
+        let (url, requests) = serve_responses(vec![http_response(
+            "200 OK",
+            "",
+            &manifest_including(&include_url),
+        )]);
+
+        let mut http_headers = HashMap::new();
+        http_headers.insert("X-Api-Key".to_string(), "secret-key".to_string());
+        let mut service = ManifestService::new(ManifestProcessingOptions {
+            http_headers,
+            ..Default::default()
+        });
+        service
+            .parse_from_url(&format!("{}/wmgr.yml", url))
+            .await
+            .unwrap();
+
+        assert!(requests.lock().unwrap()[0].contains("x-api-key: secret-key"));
+        assert!(!include_requests.lock().unwrap()[0].contains("x-api-key"));
+    }
+
+    #[tokio::test]
+    async fn test_bearer_token_is_sent_only_to_configured_host() {
+        let (include_url, include_requests) =
+            serve_responses(vec![http_response("200 OK", "", REMOTE_MANIFEST)]);
+        let (url, requests) = serve_responses(vec![http_response(
+            "200 OK",
+            "",
+            &manifest_including(&include_url),
+        )]);
+
+        let include_host = include_url.trim_start_matches("http://").to_string();
+        let mut service = ManifestService::new(
+            ManifestProcessingOptions::default()
+                .with_bearer_token("secret-token", Some(include_host)),
+        );
+        service
+            .parse_from_url(&format!("{}/wmgr.yml", url))
+            .await
+            .unwrap();
+
+        assert!(!requests.lock().unwrap()[0].contains("authorization"));
+        assert!(include_requests.lock().unwrap()[0].contains("authorization: bearer secret-token"));
+    }
+
+    #[test]
+    fn test_bearer_token_from_env() {
+        let env = |name: &str| match name {
+            MANIFEST_TOKEN_ENV => Some(" secret-token ".to_string()),
+            MANIFEST_TOKEN_HOST_ENV => Some("manifests.corp.internal".to_string()),
+            _ => None,
+        };
+        let options = ManifestProcessingOptions::default().with_bearer_token_from(&env);
+        assert_eq!(options.bearer_token.as_deref(), Some("secret-token"));
+        assert_eq!(
+            options.bearer_token_host.as_deref(),
+            Some("manifests.corp.internal")
+        );
+
+        let options = ManifestProcessingOptions::default()
+            .with_bearer_token_from(&|name| (name == MANIFEST_TOKEN_ENV).then(String::new));
+        assert_eq!(options.bearer_token, None);
+    }
 }
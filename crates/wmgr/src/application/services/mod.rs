@@ -1,3 +1,4 @@
 pub mod credential_service;
 pub mod manifest_service;
 pub mod security_service;
+pub mod workspace_loader;
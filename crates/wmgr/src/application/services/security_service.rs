@@ -1,4 +1,6 @@
+use crate::infrastructure::process::command_executor::ExecutionResult;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::Path;
 use std::process::Command;
 use thiserror::Error;
@@ -22,6 +24,109 @@ pub enum SecurityError {
     Utf8Error(#[from] std::str::Utf8Error),
 }
 
+/// 依存関係を監査するエコシステム
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditEcosystem {
+    /// `Cargo.toml`を持つRustプロジェクト（`cargo audit`）
+    Cargo,
+    /// `package.json`を持つnpmプロジェクト（`npm audit`）
+    Npm,
+    /// `package.json`と`yarn.lock`を持つYarnプロジェクト（`yarn audit`）
+    Yarn,
+    /// `requirements.txt`または`poetry.lock`を持つPythonプロジェクト（`pip-audit`）
+    Pip,
+    /// `go.mod`を持つGoモジュール（`govulncheck`）
+    Go,
+}
+
+impl AuditEcosystem {
+    /// すべてのエコシステム（判定の優先順）
+    pub const ALL: [Self; 5] = [Self::Cargo, Self::Yarn, Self::Npm, Self::Pip, Self::Go];
+
+    /// プロジェクトのエコシステムを判定
+    ///
+    /// 複数のエコシステムのファイルがある場合はCargo、Yarn、npm、pip、Goの順に優先する。
+    pub fn detect(project_path: &Path) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|ecosystem| ecosystem.matches(project_path))
+    }
+
+    /// プロジェクトがこのエコシステムのファイルを持つか
+    fn matches(&self, project_path: &Path) -> bool {
+        let has = |file: &str| project_path.join(file).exists();
+        match self {
+            Self::Cargo => has("Cargo.toml"),
+            Self::Yarn => has("package.json") && has("yarn.lock"),
+            Self::Npm => has("package.json"),
+            Self::Pip => has("requirements.txt") || has("poetry.lock"),
+            Self::Go => has("go.mod"),
+        }
+    }
+
+    /// 依存関係を記述したファイル（プロジェクトに存在する最初の候補）
+    pub fn manifest_file(&self, project_path: &Path) -> Option<&'static str> {
+        let candidates: &[&'static str] = match self {
+            Self::Cargo => &["Cargo.lock", "Cargo.toml"],
+            Self::Npm => &["package-lock.json", "package.json"],
+            Self::Yarn => &["yarn.lock"],
+            Self::Pip => &["requirements.txt", "poetry.lock"],
+            Self::Go => &["go.mod"],
+        };
+        candidates
+            .iter()
+            .copied()
+            .find(|file| project_path.join(file).exists())
+    }
+
+    /// エコシステム名
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Cargo => "cargo",
+            Self::Npm => "npm",
+            Self::Yarn => "yarn",
+            Self::Pip => "pip",
+            Self::Go => "go",
+        }
+    }
+
+    /// デフォルトの監査コマンド（JSONで結果を出力する）
+    pub fn default_command(&self) -> &'static str {
+        match self {
+            Self::Cargo => "cargo audit --json",
+            Self::Npm => "npm audit --json",
+            Self::Yarn => "yarn audit --json",
+            Self::Pip => "pip-audit --format json",
+            Self::Go => "govulncheck -json ./...",
+        }
+    }
+}
+
+impl std::str::FromStr for AuditEcosystem {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|ecosystem| ecosystem.name() == s)
+            .ok_or_else(|| {
+                let names: Vec<_> = Self::ALL.iter().map(|e| e.name()).collect();
+                format!(
+                    "Unknown audit ecosystem '{}' (expected one of: {})",
+                    s,
+                    names.join(", ")
+                )
+            })
+    }
+}
+
+impl std::fmt::Display for AuditEcosystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
 /// 脆弱性の重要度レベル
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -105,6 +210,12 @@ impl AuditSummary {
 pub struct SecurityService {
     /// cargo-auditのパス（デフォルトは"cargo"）
     cargo_path: String,
+
+    /// npmのパス（デフォルトは"npm"）
+    npm_path: String,
+
+    /// エコシステムごとの監査コマンドの上書き
+    audit_commands: HashMap<AuditEcosystem, String>,
 }
 
 impl SecurityService {
@@ -112,6 +223,8 @@ impl SecurityService {
     pub fn new() -> Self {
         Self {
             cargo_path: "cargo".to_string(),
+            npm_path: "npm".to_string(),
+            audit_commands: HashMap::new(),
         }
     }
 
@@ -121,6 +234,74 @@ impl SecurityService {
         self
     }
 
+    /// カスタムのnpmパスを指定
+    pub fn with_npm_path(mut self, npm_path: impl Into<String>) -> Self {
+        self.npm_path = npm_path.into();
+        self
+    }
+
+    /// エコシステムの監査コマンドを上書き
+    pub fn with_audit_command(
+        mut self,
+        ecosystem: AuditEcosystem,
+        command: impl Into<String>,
+    ) -> Self {
+        self.audit_commands.insert(ecosystem, command.into());
+        self
+    }
+
+    /// エコシステムの監査コマンド（上書き > cargo/npmのパス > デフォルト）
+    pub fn audit_command(&self, ecosystem: AuditEcosystem) -> String {
+        if let Some(command) = self.audit_commands.get(&ecosystem) {
+            return command.clone();
+        }
+        match ecosystem {
+            AuditEcosystem::Cargo => format!("{} audit --json", self.cargo_path),
+            AuditEcosystem::Npm => format!("{} audit --json", self.npm_path),
+            _ => ecosystem.default_command().to_string(),
+        }
+    }
+
+    /// 監査コマンドの実行結果から監査結果を作成
+    ///
+    /// 監査ツールは脆弱性が見つかると非ゼロで終了するため、
+    /// 標準出力がJSONであれば終了コードにかかわらず監査できたものとして扱う。
+    pub fn parse_audit_report(
+        &self,
+        ecosystem: AuditEcosystem,
+        project_path: &Path,
+        output: &ExecutionResult,
+    ) -> Result<AuditResult, SecurityError> {
+        let documents = json_documents(&output.stdout);
+        if !output.success && documents.is_empty() {
+            let stderr = output.stderr.trim();
+            return Err(SecurityError::AuditFailed(if stderr.is_empty() {
+                format!("{} audit exited with code {}", ecosystem, output.exit_code)
+            } else {
+                stderr.to_string()
+            }));
+        }
+
+        let vulnerabilities = match ecosystem {
+            AuditEcosystem::Cargo => documents
+                .iter()
+                .flat_map(|json| self.extract_vulnerabilities_from_json(json))
+                .collect(),
+            AuditEcosystem::Npm => self.parse_npm_audit_output(&documents),
+            AuditEcosystem::Yarn => self.parse_yarn_audit_output(&documents),
+            AuditEcosystem::Pip => self.parse_pip_audit_output(&documents),
+            AuditEcosystem::Go => self.parse_govulncheck_output(&documents),
+        };
+        let warning_count = self.calculate_summary(&vulnerabilities);
+
+        Ok(AuditResult {
+            vulnerabilities,
+            timestamp: chrono::Utc::now(),
+            project_path: project_path.display().to_string(),
+            warning_count,
+        })
+    }
+
     /// 依存関係の脆弱性監査を実行
     pub async fn audit_dependencies(
         &self,
@@ -220,9 +401,7 @@ impl SecurityService {
             // JSONをパースして脆弱性情報を抽出
             match serde_json::from_str::<serde_json::Value>(line) {
                 Ok(json) => {
-                    if let Some(vuln) = self.extract_vulnerability_from_json(&json)? {
-                        vulnerabilities.push(vuln);
-                    }
+                    vulnerabilities.extend(self.extract_vulnerabilities_from_json(&json));
                 }
                 Err(_) => {
                     // JSON形式でない行はスキップ（警告メッセージなど）
@@ -235,13 +414,18 @@ impl SecurityService {
     }
 
     /// JSONから脆弱性情報を抽出
-    fn extract_vulnerability_from_json(
-        &self,
-        json: &serde_json::Value,
-    ) -> Result<Option<Vulnerability>, SecurityError> {
+    ///
+    /// cargo-auditは`vulnerabilities.list`に、古い形式では`vulnerabilities`に配列で出力する。
+    fn extract_vulnerabilities_from_json(&self, json: &serde_json::Value) -> Vec<Vulnerability> {
+        let mut found = Vec::new();
+
         // cargo-auditのJSON形式に従って脆弱性情報を抽出
         if let Some(vulnerabilities) = json.get("vulnerabilities") {
-            if let Some(vuln_array) = vulnerabilities.as_array() {
+            let vuln_array = vulnerabilities
+                .get("list")
+                .and_then(|list| list.as_array())
+                .or_else(|| vulnerabilities.as_array());
+            if let Some(vuln_array) = vuln_array {
                 for vuln_obj in vuln_array {
                     if let Some(advisory) = vuln_obj.get("advisory") {
                         let vulnerability = Vulnerability {
@@ -271,11 +455,15 @@ impl SecurityService {
                             ),
                             description: advisory
                                 .get("description")
+                                .or_else(|| advisory.get("title"))
                                 .and_then(|v| v.as_str())
                                 .unwrap_or("No description available")
                                 .to_string(),
-                            patched_versions: advisory
-                                .get("patched_versions")
+                            // cargo-auditは`versions.patched`に修正版を出力する
+                            patched_versions: vuln_obj
+                                .get("versions")
+                                .and_then(|v| v.get("patched"))
+                                .or_else(|| advisory.get("patched_versions"))
                                 .and_then(|v| v.as_array())
                                 .map(|arr| {
                                     arr.iter()
@@ -289,20 +477,180 @@ impl SecurityService {
                                 .and_then(|v| v.as_str())
                                 .map(|s| s.to_string()),
                         };
-                        return Ok(Some(vulnerability));
+                        found.push(vulnerability);
                     }
                 }
             }
         }
 
-        Ok(None)
+        found
+    }
+
+    /// `npm audit --json`の結果をパース
+    ///
+    /// `vulnerabilities`はパッケージ名ごとのオブジェクトで、`via`に原因となった勧告が含まれる。
+    fn parse_npm_audit_output(&self, documents: &[serde_json::Value]) -> Vec<Vulnerability> {
+        let packages = documents
+            .iter()
+            .filter_map(|json| json.get("vulnerabilities").and_then(|v| v.as_object()))
+            .flatten();
+
+        let mut vulnerabilities = Vec::new();
+        for (name, package) in packages {
+            // 他のパッケージ経由の場合は文字列、勧告そのものの場合はオブジェクト
+            let advisory = package
+                .get("via")
+                .and_then(|via| via.as_array())
+                .and_then(|via| via.iter().find(|v| v.is_object()));
+
+            vulnerabilities.push(Vulnerability {
+                id: advisory
+                    .and_then(|a| a.get("source"))
+                    .map(json_to_string)
+                    .unwrap_or_else(|| name.clone()),
+                package: name.clone(),
+                version: str_of(Some(package), "range").unwrap_or_else(|| "unknown".to_string()),
+                severity: self.parse_severity(
+                    package
+                        .get("severity")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("medium"),
+                ),
+                description: str_of(advisory, "title")
+                    .unwrap_or_else(|| "No description available".to_string()),
+                patched_versions: Vec::new(),
+                url: str_of(advisory, "url"),
+            });
+        }
+
+        vulnerabilities
+    }
+
+    /// `yarn audit --json`の結果をパース
+    ///
+    /// 1行に1つのイベントが出力され、`auditAdvisory`の`data.advisory`が脆弱性を表す。
+    fn parse_yarn_audit_output(&self, documents: &[serde_json::Value]) -> Vec<Vulnerability> {
+        let advisories = documents
+            .iter()
+            .filter(|json| json.get("type").and_then(|t| t.as_str()) == Some("auditAdvisory"))
+            .filter_map(|json| json.get("data").and_then(|d| d.get("advisory")));
+
+        // 同じ勧告は依存経路ごとに出力されるため、IDで重複を除く
+        let mut seen = std::collections::HashSet::new();
+        advisories
+            .filter(|advisory| seen.insert(advisory.get("id").map(json_to_string)))
+            .map(|advisory| Vulnerability {
+                id: advisory
+                    .get("id")
+                    .map(json_to_string)
+                    .unwrap_or_else(|| "unknown".to_string()),
+                package: str_of(Some(advisory), "module_name")
+                    .unwrap_or_else(|| "unknown".to_string()),
+                version: str_of(Some(advisory), "vulnerable_versions")
+                    .unwrap_or_else(|| "unknown".to_string()),
+                severity: self.parse_severity(
+                    advisory
+                        .get("severity")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("medium"),
+                ),
+                description: str_of(Some(advisory), "title")
+                    .unwrap_or_else(|| "No description available".to_string()),
+                patched_versions: str_of(Some(advisory), "patched_versions")
+                    .into_iter()
+                    .collect(),
+                url: str_of(Some(advisory), "url"),
+            })
+            .collect()
+    }
+
+    /// `pip-audit --format json`の結果をパース
+    ///
+    /// `dependencies`（古い形式では最上位の配列）の各パッケージの`vulns`が脆弱性を表す。
+    /// pip-auditは重要度を出力しないため、すべてMediumとして扱う。
+    fn parse_pip_audit_output(&self, documents: &[serde_json::Value]) -> Vec<Vulnerability> {
+        let dependencies = documents
+            .iter()
+            .filter_map(|json| json.get("dependencies").or(Some(json)))
+            .filter_map(|deps| deps.as_array())
+            .flatten();
+
+        let mut vulnerabilities = Vec::new();
+        for dependency in dependencies {
+            let vulns = dependency.get("vulns").and_then(|v| v.as_array());
+            for vuln in vulns.into_iter().flatten() {
+                vulnerabilities.push(Vulnerability {
+                    id: str_of(Some(vuln), "id").unwrap_or_else(|| "unknown".to_string()),
+                    package: str_of(Some(dependency), "name")
+                        .unwrap_or_else(|| "unknown".to_string()),
+                    version: str_of(Some(dependency), "version")
+                        .unwrap_or_else(|| "unknown".to_string()),
+                    severity: VulnerabilitySeverity::Medium,
+                    description: str_of(Some(vuln), "description")
+                        .unwrap_or_else(|| "No description available".to_string()),
+                    patched_versions: vuln
+                        .get("fix_versions")
+                        .and_then(|v| v.as_array())
+                        .map(|arr| {
+                            arr.iter()
+                                .filter_map(|v| v.as_str())
+                                .map(|s| s.to_string())
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                    url: None,
+                });
+            }
+        }
+
+        vulnerabilities
+    }
+
+    /// `govulncheck -json`の結果をパース
+    ///
+    /// `finding`メッセージが見つかった脆弱性（OSV ID）を、`osv`メッセージがその詳細を表す。
+    /// govulncheckは重要度を出力しないため、すべてMediumとして扱う。
+    fn parse_govulncheck_output(&self, documents: &[serde_json::Value]) -> Vec<Vulnerability> {
+        let entries: HashMap<&str, &serde_json::Value> = documents
+            .iter()
+            .filter_map(|json| json.get("osv"))
+            .filter_map(|osv| Some((osv.get("id")?.as_str()?, osv)))
+            .collect();
+
+        // 同じ脆弱性は呼び出し経路ごとに報告されるため、IDで重複を除く
+        let mut seen = std::collections::HashSet::new();
+        documents
+            .iter()
+            .filter_map(|json| json.get("finding"))
+            .filter_map(|finding| {
+                let id = finding.get("osv")?.as_str()?;
+                seen.insert(id).then_some((id, finding))
+            })
+            .map(|(id, finding)| {
+                let module = finding
+                    .get("trace")
+                    .and_then(|trace| trace.as_array())
+                    .and_then(|trace| trace.first());
+                let entry = entries.get(id).copied();
+                Vulnerability {
+                    id: id.to_string(),
+                    package: str_of(module, "module").unwrap_or_else(|| "unknown".to_string()),
+                    version: str_of(module, "version").unwrap_or_else(|| "unknown".to_string()),
+                    severity: VulnerabilitySeverity::Medium,
+                    description: str_of(entry, "summary")
+                        .unwrap_or_else(|| "No description available".to_string()),
+                    patched_versions: str_of(Some(finding), "fixed_version").into_iter().collect(),
+                    url: Some(format!("https://pkg.go.dev/vuln/{}", id)),
+                }
+            })
+            .collect()
     }
 
     /// 重要度文字列をパース
     fn parse_severity(&self, severity_str: &str) -> VulnerabilitySeverity {
         match severity_str.to_lowercase().as_str() {
-            "low" => VulnerabilitySeverity::Low,
-            "medium" => VulnerabilitySeverity::Medium,
+            "low" | "info" => VulnerabilitySeverity::Low,
+            "medium" | "moderate" => VulnerabilitySeverity::Medium,
             "high" => VulnerabilitySeverity::High,
             "critical" => VulnerabilitySeverity::Critical,
             _ => VulnerabilitySeverity::Medium,
@@ -326,6 +674,38 @@ impl SecurityService {
     }
 }
 
+/// 監査ツールの出力に含まれるJSONドキュメント
+///
+/// 連続したJSON（複数行にわたるものも含む）として読めない場合は、JSONとして読める行だけを使う。
+fn json_documents(output: &str) -> Vec<serde_json::Value> {
+    let documents: Vec<serde_json::Value> = serde_json::Deserializer::from_str(output)
+        .into_iter()
+        .map_while(Result::ok)
+        .collect();
+    if !documents.is_empty() {
+        return documents;
+    }
+    output
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+/// JSONオブジェクトの文字列フィールド
+fn str_of(value: Option<&serde_json::Value>, key: &str) -> Option<String> {
+    value
+        .and_then(|v| v.get(key))
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string())
+}
+
+/// 文字列または数値のIDを文字列にする
+fn json_to_string(value: &serde_json::Value) -> String {
+    value
+        .as_str()
+        .map_or_else(|| value.to_string(), str::to_string)
+}
+
 impl Default for SecurityService {
     fn default() -> Self {
         Self::new()
@@ -390,6 +770,253 @@ mod tests {
         assert!(result.is_empty());
     }
 
+    fn execution(exit_code: i32, stdout: &str, stderr: &str) -> ExecutionResult {
+        ExecutionResult::new(exit_code, stdout.to_string(), stderr.to_string(), 0)
+    }
+
+    #[test]
+    fn test_parse_cargo_audit_report() {
+        let service = SecurityService::new();
+        let stdout = r#"{"vulnerabilities":{"found":true,"count":2,"list":[
+            {"advisory":{"id":"RUSTSEC-2024-0001","title":"Use after free","cvss":{"severity":"high"},"url":"https://rustsec.org/advisories/RUSTSEC-2024-0001"},"package":{"name":"foo","version":"0.1.0"},"versions":{"patched":[">=0.1.1"]}},
+            {"advisory":{"id":"RUSTSEC-2024-0002"},"package":{"name":"bar","version":"1.2.0"}}
+        ]}}"#
+            .replace('\n', "");
+
+        // 脆弱性があるとcargo auditは非ゼロで終了する
+        let result = service
+            .parse_audit_report(
+                AuditEcosystem::Cargo,
+                Path::new("app"),
+                &execution(1, &stdout, ""),
+            )
+            .unwrap();
+        assert_eq!(result.vulnerabilities.len(), 2);
+        assert_eq!(result.vulnerabilities[0].id, "RUSTSEC-2024-0001");
+        assert_eq!(result.vulnerabilities[0].description, "Use after free");
+        assert_eq!(result.vulnerabilities[0].patched_versions, vec![">=0.1.1"]);
+        assert_eq!(result.vulnerabilities[1].package, "bar");
+        assert_eq!(result.warning_count.high, 1);
+        assert_eq!(result.warning_count.medium, 1);
+    }
+
+    #[test]
+    fn test_parse_npm_audit_report() {
+        let service = SecurityService::new();
+        let stdout = r#"{
+            "vulnerabilities": {
+                "lodash": {"name": "lodash", "severity": "critical", "range": "<4.17.21",
+                    "via": [{"source": 1523, "title": "Prototype Pollution", "url": "https://github.com/advisories/GHSA-1"}]},
+                "left-pad": {"name": "left-pad", "severity": "moderate", "range": "*", "via": ["lodash"]}
+            },
+            "metadata": {"vulnerabilities": {"critical": 1, "moderate": 1, "total": 2}}
+        }"#;
+
+        let result = service
+            .parse_audit_report(
+                AuditEcosystem::Npm,
+                Path::new("web"),
+                &execution(1, stdout, ""),
+            )
+            .unwrap();
+        assert_eq!(result.warning_count.critical, 1);
+        assert_eq!(result.warning_count.medium, 1);
+        let lodash = result
+            .vulnerabilities
+            .iter()
+            .find(|v| v.package == "lodash")
+            .unwrap();
+        assert_eq!(lodash.id, "1523");
+        assert_eq!(lodash.description, "Prototype Pollution");
+    }
+
+    #[test]
+    fn test_parse_audit_report_failure() {
+        let service = SecurityService::new();
+        let result = service.parse_audit_report(
+            AuditEcosystem::Cargo,
+            Path::new("app"),
+            &execution(101, "", "error: no such command: `audit`\n"),
+        );
+        assert!(
+            matches!(result, Err(SecurityError::AuditFailed(message)) if message == "error: no such command: `audit`")
+        );
+
+        // 脆弱性がなければ空の結果
+        let result = service
+            .parse_audit_report(
+                AuditEcosystem::Cargo,
+                Path::new("app"),
+                &execution(0, "", ""),
+            )
+            .unwrap();
+        assert_eq!(result.warning_count.total(), 0);
+    }
+
+    /// 指定したファイルだけを持つプロジェクトのエコシステム
+    fn detect_with(files: &[&str]) -> Option<AuditEcosystem> {
+        let temp_dir = TempDir::new().unwrap();
+        for file in files {
+            fs::write(temp_dir.path().join(file), "").unwrap();
+        }
+        AuditEcosystem::detect(temp_dir.path())
+    }
+
+    #[test]
+    fn test_detect_ecosystem() {
+        assert_eq!(detect_with(&[]), None);
+        assert_eq!(detect_with(&["README.md", "Makefile"]), None);
+        assert_eq!(detect_with(&["Cargo.toml"]), Some(AuditEcosystem::Cargo));
+        assert_eq!(detect_with(&["package.json"]), Some(AuditEcosystem::Npm));
+        assert_eq!(
+            detect_with(&["package.json", "yarn.lock"]),
+            Some(AuditEcosystem::Yarn)
+        );
+        assert_eq!(
+            detect_with(&["requirements.txt"]),
+            Some(AuditEcosystem::Pip)
+        );
+        assert_eq!(detect_with(&["poetry.lock"]), Some(AuditEcosystem::Pip));
+        assert_eq!(detect_with(&["go.mod"]), Some(AuditEcosystem::Go));
+
+        // 複数ある場合はCargoが優先される
+        assert_eq!(
+            detect_with(&["go.mod", "package.json", "Cargo.toml"]),
+            Some(AuditEcosystem::Cargo)
+        );
+        // yarn.lockだけではYarnプロジェクトとみなさない
+        assert_eq!(detect_with(&["yarn.lock"]), None);
+    }
+
+    #[test]
+    fn test_audit_command_overrides() {
+        let service = SecurityService::new();
+        assert_eq!(
+            service.audit_command(AuditEcosystem::Pip),
+            "pip-audit --format json"
+        );
+        assert_eq!(
+            service.audit_command(AuditEcosystem::Go),
+            "govulncheck -json ./..."
+        );
+
+        let service = SecurityService::new()
+            .with_cargo_path("/opt/cargo")
+            .with_audit_command(AuditEcosystem::Yarn, "yarn npm audit --json");
+        assert_eq!(
+            service.audit_command(AuditEcosystem::Cargo),
+            "/opt/cargo audit --json"
+        );
+        assert_eq!(
+            service.audit_command(AuditEcosystem::Yarn),
+            "yarn npm audit --json"
+        );
+
+        assert_eq!("go".parse(), Ok(AuditEcosystem::Go));
+        assert!("maven"
+            .parse::<AuditEcosystem>()
+            .unwrap_err()
+            .contains("expected one of: cargo, yarn, npm, pip, go"));
+    }
+
+    #[test]
+    fn test_parse_yarn_audit_report() {
+        let service = SecurityService::new();
+        let stdout = [
+            r#"{"type":"auditAdvisory","data":{"resolution":{"path":"a>minimist"},"advisory":{"id":1179,"module_name":"minimist","severity":"low","title":"Prototype Pollution","vulnerable_versions":"<0.2.1","patched_versions":">=0.2.1","url":"https://npmjs.com/advisories/1179"}}}"#,
+            r#"{"type":"auditAdvisory","data":{"resolution":{"path":"b>minimist"},"advisory":{"id":1179,"module_name":"minimist","severity":"low","title":"Prototype Pollution","vulnerable_versions":"<0.2.1","patched_versions":">=0.2.1"}}}"#,
+            r#"{"type":"auditAdvisory","data":{"advisory":{"id":1500,"module_name":"yargs-parser","severity":"high","title":"Prototype Pollution"}}}"#,
+            r#"{"type":"auditSummary","data":{"vulnerabilities":{"low":2,"high":1}}}"#,
+        ]
+        .join("\n");
+
+        let result = service
+            .parse_audit_report(
+                AuditEcosystem::Yarn,
+                Path::new("web"),
+                &execution(12, &stdout, ""),
+            )
+            .unwrap();
+        assert_eq!(result.vulnerabilities.len(), 2);
+        assert_eq!(result.vulnerabilities[0].id, "1179");
+        assert_eq!(result.vulnerabilities[0].patched_versions, vec![">=0.2.1"]);
+        assert_eq!(
+            (result.warning_count.high, result.warning_count.low),
+            (1, 1)
+        );
+    }
+
+    #[test]
+    fn test_parse_pip_audit_report() {
+        let service = SecurityService::new();
+        let stdout = r#"{"dependencies": [
+            {"name": "flask", "version": "0.5", "vulns": [
+                {"id": "PYSEC-2019-179", "fix_versions": ["1.0"], "description": "Flask before 1.0 ..."},
+                {"id": "PYSEC-2018-66", "fix_versions": ["0.12.3"], "description": "DoS"}
+            ]},
+            {"name": "requests", "version": "2.31.0", "vulns": []}
+        ], "fixes": []}"#;
+
+        let result = service
+            .parse_audit_report(
+                AuditEcosystem::Pip,
+                Path::new("ml"),
+                &execution(1, stdout, ""),
+            )
+            .unwrap();
+        assert_eq!(result.vulnerabilities.len(), 2);
+        assert_eq!(result.vulnerabilities[0].package, "flask");
+        assert_eq!(result.vulnerabilities[0].patched_versions, vec!["1.0"]);
+        assert_eq!(result.warning_count.medium, 2);
+
+        // 古いpip-auditは依存関係の配列だけを出力する
+        let stdout =
+            r#"[{"name": "flask", "version": "0.5", "vulns": [{"id": "PYSEC-2019-179"}]}]"#;
+        let result = service
+            .parse_audit_report(
+                AuditEcosystem::Pip,
+                Path::new("ml"),
+                &execution(1, stdout, ""),
+            )
+            .unwrap();
+        assert_eq!(result.warning_count.total(), 1);
+    }
+
+    #[test]
+    fn test_parse_govulncheck_report() {
+        let service = SecurityService::new();
+        // govulncheckは整形されたJSONオブジェクトを続けて出力する
+        let stdout = r#"{
+  "config": {"scanner_name": "govulncheck"}
+}
+{
+  "osv": {"id": "GO-2023-1571", "summary": "Denial of service in net/http"}
+}
+{
+  "finding": {"osv": "GO-2023-1571", "fixed_version": "v0.7.0",
+    "trace": [{"module": "golang.org/x/net", "version": "v0.1.0"}]}
+}
+{
+  "finding": {"osv": "GO-2023-1571", "fixed_version": "v0.7.0",
+    "trace": [{"module": "golang.org/x/net", "version": "v0.1.0", "function": "Get"}]}
+}
+"#;
+
+        let result = service
+            .parse_audit_report(
+                AuditEcosystem::Go,
+                Path::new("svc"),
+                &execution(3, stdout, ""),
+            )
+            .unwrap();
+        assert_eq!(result.vulnerabilities.len(), 1);
+        let vuln = &result.vulnerabilities[0];
+        assert_eq!(vuln.id, "GO-2023-1571");
+        assert_eq!(vuln.package, "golang.org/x/net");
+        assert_eq!(vuln.description, "Denial of service in net/http");
+        assert_eq!(vuln.patched_versions, vec!["v0.7.0"]);
+    }
+
     #[test]
     fn test_calculate_summary() {
         let service = SecurityService::new();
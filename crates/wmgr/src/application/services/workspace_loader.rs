@@ -0,0 +1,240 @@
+use crate::application::services::manifest_service::{ManifestProcessingOptions, ManifestService};
+use crate::common::error::WmgrError;
+use crate::domain::entities::workspace::{Workspace, WorkspaceConfig, WorkspaceStatus};
+use crate::infrastructure::filesystem::config_store::{ConfigStore, ConfigStoreError};
+use crate::infrastructure::filesystem::manifest_store::{ManifestStore, ManifestStoreError};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// WorkspaceLoader関連のエラー
+#[derive(Debug, Error)]
+pub enum WorkspaceLoaderError {
+    #[error("No wmgr workspace found. Searched upward from {} for wmgr.yml, wmgr.yaml, manifest.yml, or manifest.yaml", .0.display())]
+    WorkspaceNotFound(PathBuf),
+
+    #[error("Failed to read workspace configuration {}: {source}", .path.display())]
+    ConfigReadFailed {
+        path: PathBuf,
+        #[source]
+        source: ConfigStoreError,
+    },
+
+    #[error("Failed to read manifest: {0}")]
+    ManifestReadFailed(#[from] ManifestStoreError),
+
+    #[error("Failed to get current directory: {0}")]
+    CurrentDirUnavailable(#[source] std::io::Error),
+}
+
+impl From<WorkspaceLoaderError> for WmgrError {
+    fn from(error: WorkspaceLoaderError) -> Self {
+        match error {
+            WorkspaceLoaderError::WorkspaceNotFound(path) => {
+                WmgrError::workspace_not_initialized(path)
+            }
+            WorkspaceLoaderError::ConfigReadFailed { .. } => {
+                WmgrError::config_error_with_source(error.to_string(), error)
+            }
+            WorkspaceLoaderError::ManifestReadFailed(source) => WmgrError::from(source),
+            WorkspaceLoaderError::CurrentDirUnavailable(source) => WmgrError::from(source),
+        }
+    }
+}
+
+/// 既存のワークスペースを読み込むサービス
+///
+/// 開始ディレクトリから上位に向かってマニフェストファイルを探してルートを決め、
+/// `.wmgr/config.yml`があればConfigStoreで、マニフェストはManifestStoreで読み込む。
+/// 全てのコマンドはこのサービスでワークスペースを読み込む。
+#[derive(Debug, Clone, Default)]
+pub struct WorkspaceLoader {
+    /// `.wmgr/config.yml`の代わりに読み込む設定ファイル
+    config_path: Option<PathBuf>,
+
+    /// マニフェストの検証の警告をエラーにするか
+    strict: bool,
+}
+
+impl WorkspaceLoader {
+    /// 新しいWorkspaceLoaderインスタンスを作成
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `.wmgr/config.yml`の代わりに読み込む設定ファイルを指定（`--config`）
+    ///
+    /// 指定したファイルは存在しなければならない。
+    pub fn with_config_path(mut self, config_path: Option<PathBuf>) -> Self {
+        self.config_path = config_path;
+        self
+    }
+
+    /// マニフェストの検証の警告をエラーにする（`--strict-manifest`）
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// カレントディレクトリを含むワークスペースを読み込む
+    pub async fn load_current(&self) -> Result<Workspace, WorkspaceLoaderError> {
+        let current_dir =
+            std::env::current_dir().map_err(WorkspaceLoaderError::CurrentDirUnavailable)?;
+        self.load(&current_dir).await
+    }
+
+    /// `start`またはその親ディレクトリにあるワークスペースを読み込む
+    pub async fn load(&self, start: &Path) -> Result<Workspace, WorkspaceLoaderError> {
+        let root = Workspace::discover_workspace_root(start)
+            .ok_or_else(|| WorkspaceLoaderError::WorkspaceNotFound(start.to_path_buf()))?;
+
+        let manifest_file =
+            Workspace::new(root.clone(), WorkspaceConfig::default_local()).manifest_file_path();
+        let manifest_service =
+            ManifestService::new(ManifestProcessingOptions::from_env().with_strict(self.strict));
+        let manifest =
+            ManifestStore::with_service_and_options(manifest_service, Default::default())
+                .read_manifest(&manifest_file)
+                .await?
+                .manifest;
+
+        let config_path = match &self.config_path {
+            Some(path) => path.clone(),
+            None => root.join(".wmgr").join("config.yml"),
+        };
+        let mut config = if self.config_path.is_some() || config_path.exists() {
+            ConfigStore::new()
+                .read_workspace_config(&config_path)
+                .map_err(|source| WorkspaceLoaderError::ConfigReadFailed {
+                    path: config_path.clone(),
+                    source,
+                })?
+        } else {
+            // 設定ファイルのないローカルのワークスペースはマニフェストファイル自体を取得元とする
+            WorkspaceConfig::new(
+                manifest_file.display().to_string(),
+                manifest
+                    .default_branch
+                    .clone()
+                    .unwrap_or_else(|| "main".to_string()),
+            )
+        };
+
+        // マニフェストリポジトリから初期化されたワークスペースは.wmgr/manifestにクローンを持つ
+        let manifest_repo = PathBuf::from(".wmgr").join("manifest");
+        if config.manifest_repo.is_none() && root.join(&manifest_repo).join(".git").exists() {
+            config.manifest_repo = Some(manifest_repo);
+        }
+
+        Ok(Workspace::new(root, config)
+            .with_status(WorkspaceStatus::Initialized)
+            .with_manifest(manifest))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const MANIFEST: &str = "repos:\n  - dest: app\n    url: https://github.com/example/app.git\ndefault_branch: develop\n";
+
+    #[tokio::test]
+    async fn test_load_discovers_root_from_nested_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        std::fs::write(root.join("wmgr.yml"), MANIFEST).unwrap();
+        let nested = root.join("app").join("src");
+        std::fs::create_dir_all(&nested).unwrap();
+
+        let workspace = WorkspaceLoader::new().load(&nested).await.unwrap();
+        assert_eq!(workspace.root_path, root);
+        assert!(workspace.is_initialized());
+        assert_eq!(workspace.manifest.as_ref().unwrap().repos[0].dest, "app");
+        assert_eq!(workspace.config.manifest_branch, "develop");
+        assert!(workspace.config.manifest_repo.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_load_reads_workspace_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        std::fs::write(root.join("wmgr.yml"), MANIFEST).unwrap();
+        let config = WorkspaceConfig::new("https://github.com/example/manifest.git", "release")
+            .with_singular_remote("https://mirror.example.com/git");
+        ConfigStore::new()
+            .write_workspace_config(root.join(".wmgr/config.yml"), &config)
+            .unwrap();
+        git2::Repository::init(root.join(".wmgr/manifest")).unwrap();
+
+        let workspace = WorkspaceLoader::new().load(root).await.unwrap();
+        assert_eq!(
+            workspace.config.manifest_url,
+            "https://github.com/example/manifest.git"
+        );
+        assert_eq!(workspace.config.manifest_branch, "release");
+        assert_eq!(
+            workspace.config.singular_remote.as_deref(),
+            Some("https://mirror.example.com/git")
+        );
+        assert_eq!(
+            workspace.config.manifest_repo,
+            Some(PathBuf::from(".wmgr/manifest"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_config_path_overrides_default_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        std::fs::write(root.join("wmgr.yml"), MANIFEST).unwrap();
+        let store = ConfigStore::new();
+        let default = WorkspaceConfig::new("https://github.com/example/manifest.git", "main")
+            .with_repo_groups(vec!["web".to_string()]);
+        store
+            .write_workspace_config(root.join(".wmgr/config.yml"), &default)
+            .unwrap();
+        let ci = WorkspaceConfig::new("https://github.com/example/manifest.git", "release")
+            .with_repo_groups(vec!["api".to_string()]);
+        store
+            .write_workspace_config(root.join(".wmgr/config.ci.yml"), &ci)
+            .unwrap();
+
+        let workspace = WorkspaceLoader::new()
+            .with_config_path(Some(root.join(".wmgr/config.ci.yml")))
+            .load(root)
+            .await
+            .unwrap();
+        assert_eq!(workspace.config.manifest_branch, "release");
+        assert_eq!(workspace.config.repo_groups, vec!["api".to_string()]);
+
+        // 指定したファイルがない場合はデフォルトに戻さずエラー
+        let error = WorkspaceLoader::new()
+            .with_config_path(Some(root.join(".wmgr/missing.yml")))
+            .load(root)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            WorkspaceLoaderError::ConfigReadFailed { ref path, .. }
+                if path == &root.join(".wmgr/missing.yml")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_load_without_workspace_is_an_error() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let error = WorkspaceLoader::new()
+            .load(temp_dir.path())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            WorkspaceLoaderError::WorkspaceNotFound(ref path) if path == temp_dir.path()
+        ));
+        assert!(matches!(
+            WmgrError::from(error),
+            WmgrError::WorkspaceNotInitialized { .. }
+        ));
+    }
+}
@@ -0,0 +1,525 @@
+use crate::common::error::WmgrError;
+use crate::domain::entities::{
+    manifest::ManifestRepo,
+    repo_selector::{RepoSelector, RepoSelectorError},
+    workspace::{DestPathError, Workspace},
+};
+use crate::domain::value_objects::branch_name::{BranchName, BranchNameError};
+use crate::domain::value_objects::scm_type::ScmType;
+use crate::infrastructure::git::repository::{GitBranchType, GitRepository, GitRepositoryError};
+use std::path::Path;
+use thiserror::Error;
+
+/// CheckoutBranch関連のエラー
+#[derive(Debug, Error)]
+pub enum CheckoutBranchError {
+    #[error("Workspace not initialized: {0}")]
+    WorkspaceNotInitialized(String),
+
+    #[error("Manifest not loaded: {0}")]
+    ManifestNotLoaded(String),
+
+    #[error("Invalid branch name: {0}")]
+    InvalidBranchName(#[from] BranchNameError),
+
+    #[error("Repository selection failed: {0}")]
+    SelectionFailed(#[from] RepoSelectorError),
+
+    #[error("Invalid repository destination: {0}")]
+    InvalidDest(#[from] DestPathError),
+}
+
+impl From<CheckoutBranchError> for WmgrError {
+    fn from(error: CheckoutBranchError) -> Self {
+        match &error {
+            CheckoutBranchError::WorkspaceNotInitialized(path) => {
+                WmgrError::workspace_not_initialized(path)
+            }
+            CheckoutBranchError::ManifestNotLoaded(_) => {
+                WmgrError::manifest_error_with_source(error.to_string(), None, error)
+            }
+            CheckoutBranchError::InvalidBranchName(_) => {
+                WmgrError::validation_error("branch", error.to_string(), None)
+            }
+            CheckoutBranchError::SelectionFailed(_) => {
+                WmgrError::validation_error("repo", error.to_string(), None)
+            }
+            CheckoutBranchError::InvalidDest(_) => {
+                WmgrError::validation_error("dest", error.to_string(), None)
+            }
+        }
+    }
+}
+
+/// ブランチ切り替えの設定
+#[derive(Debug, Clone, Default)]
+pub struct CheckoutBranchConfig {
+    /// 切り替え先のブランチ
+    pub branch: String,
+
+    /// 特定のグループのみを切り替えるか（Noneの場合は全て）
+    pub groups: Option<Vec<String>>,
+
+    /// ブランチがないリポジトリでは現在のHEADから作成するか
+    pub create: bool,
+
+    /// 未コミットの変更があるリポジトリも切り替えるか
+    pub force: bool,
+
+    /// 詳細ログを出力するか
+    pub verbose: bool,
+}
+
+impl CheckoutBranchConfig {
+    /// 切り替え先のブランチを指定して作成
+    pub fn new(branch: impl Into<String>) -> Self {
+        Self {
+            branch: branch.into(),
+            ..Self::default()
+        }
+    }
+
+    /// 対象グループを設定
+    pub fn with_groups(mut self, groups: Vec<String>) -> Self {
+        self.groups = Some(groups);
+        self
+    }
+
+    /// ブランチがない場合に作成するかを設定
+    pub fn with_create(mut self, create: bool) -> Self {
+        self.create = create;
+        self
+    }
+
+    /// 未コミットの変更があっても切り替えるかを設定
+    pub fn with_force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
+    /// 詳細ログ出力を設定
+    pub fn with_verbose(mut self, verbose: bool) -> Self {
+        self.verbose = verbose;
+        self
+    }
+}
+
+/// リポジトリごとの切り替え状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckoutStatus {
+    /// 既存のブランチに切り替えた（リモートのブランチから作成した場合を含む）
+    Switched,
+    /// 現在のHEADからブランチを作成して切り替えた
+    Created,
+    /// 既にそのブランチにいる
+    AlreadyOnBranch,
+    /// 未コミットの変更があるため切り替えなかった
+    Dirty,
+    /// 対象外（ブランチがない、Git以外のSCM、未クローンなど）
+    Skipped,
+    /// エラーが発生
+    Failed,
+}
+
+/// リポジトリごとの切り替え結果
+#[derive(Debug, Clone)]
+pub struct RepoCheckoutResult {
+    /// リポジトリのdest
+    pub dest: String,
+
+    /// 切り替え状態
+    pub status: CheckoutStatus,
+
+    /// 補足メッセージ（エラー内容やスキップ理由）
+    pub message: Option<String>,
+}
+
+impl RepoCheckoutResult {
+    /// 新しい結果を作成
+    pub fn new(dest: impl Into<String>, status: CheckoutStatus) -> Self {
+        Self {
+            dest: dest.into(),
+            status,
+            message: None,
+        }
+    }
+
+    /// メッセージを設定
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+}
+
+/// ブランチ切り替えの結果
+#[derive(Debug, Clone, Default)]
+pub struct CheckoutResult {
+    /// 各リポジトリの結果
+    pub repositories: Vec<RepoCheckoutResult>,
+
+    /// 既存のブランチに切り替えたリポジトリの数
+    pub switched_count: usize,
+
+    /// ブランチを作成したリポジトリの数
+    pub created_count: usize,
+
+    /// 既にそのブランチにいたリポジトリの数
+    pub already_on_branch_count: usize,
+
+    /// スキップされたリポジトリの数
+    pub skipped_count: usize,
+
+    /// 発生したエラーのリスト（未コミットの変更による拒否を含む）
+    pub errors: Vec<String>,
+}
+
+impl CheckoutResult {
+    /// 新しいCheckoutResultを作成
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// リポジトリの結果を追加して集計を更新
+    pub fn add_repository(&mut self, result: RepoCheckoutResult) {
+        match result.status {
+            CheckoutStatus::Switched => self.switched_count += 1,
+            CheckoutStatus::Created => self.created_count += 1,
+            CheckoutStatus::AlreadyOnBranch => self.already_on_branch_count += 1,
+            CheckoutStatus::Skipped => self.skipped_count += 1,
+            CheckoutStatus::Dirty => self.errors.push(format!(
+                "{} has uncommitted changes (use --force to check out anyway)",
+                result.dest
+            )),
+            CheckoutStatus::Failed => self.errors.push(format!(
+                "Failed to check out in {}: {}",
+                result.dest,
+                result.message.as_deref().unwrap_or("unknown error")
+            )),
+        }
+        self.repositories.push(result);
+    }
+
+    /// 全ての切り替えが成功したか
+    pub fn is_success(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// ワークスペース全体のブランチ切り替えのユースケース
+pub struct CheckoutBranchUseCase {
+    /// 切り替え設定
+    config: CheckoutBranchConfig,
+}
+
+impl CheckoutBranchUseCase {
+    /// 新しいCheckoutBranchUseCaseインスタンスを作成
+    pub fn new(config: CheckoutBranchConfig) -> Self {
+        Self { config }
+    }
+
+    /// ブランチの切り替えを実行
+    pub async fn execute(
+        &self,
+        workspace: &Workspace,
+    ) -> Result<CheckoutResult, CheckoutBranchError> {
+        // 1. ワークスペースの初期化チェックとブランチ名の検証
+        if !workspace.is_initialized() {
+            return Err(CheckoutBranchError::WorkspaceNotInitialized(
+                workspace.root_path.display().to_string(),
+            ));
+        }
+        let branch = BranchName::new(&self.config.branch)?;
+
+        // 2. 対象リポジトリの決定
+        let target_repos = self.determine_target_repositories(workspace)?;
+
+        // 3. リポジトリごとに切り替え（作業ツリーを書き換えるため1つずつ実行）
+        let mut result = CheckoutResult::new();
+        for repo in &target_repos {
+            let repo_path = workspace.resolve_dest(&repo.dest)?;
+            if self.config.verbose {
+                println!("Checking out {} in {}", branch, repo.dest);
+            }
+            result.add_repository(self.checkout_single_repository(repo, &repo_path, &branch));
+        }
+
+        Ok(result)
+    }
+
+    /// 切り替え対象リポジトリの決定
+    fn determine_target_repositories(
+        &self,
+        workspace: &Workspace,
+    ) -> Result<Vec<ManifestRepo>, CheckoutBranchError> {
+        let manifest = workspace.manifest.as_ref().ok_or_else(|| {
+            CheckoutBranchError::ManifestNotLoaded(workspace.root_path.display().to_string())
+        })?;
+
+        Ok(RepoSelector::new(self.config.groups.clone())
+            .select(manifest, workspace.configured_repos())?
+            .into_iter()
+            .cloned()
+            .collect())
+    }
+
+    /// 単一リポジトリのブランチ切り替え
+    fn checkout_single_repository(
+        &self,
+        repo: &ManifestRepo,
+        repo_path: &Path,
+        branch: &BranchName,
+    ) -> RepoCheckoutResult {
+        if repo.scm != ScmType::Git {
+            return RepoCheckoutResult::new(&repo.dest, CheckoutStatus::Skipped)
+                .with_message(format!("{} repositories have no branches", repo.scm));
+        }
+
+        if !repo_path.exists() {
+            return RepoCheckoutResult::new(&repo.dest, CheckoutStatus::Skipped)
+                .with_message("Repository not cloned");
+        }
+
+        match self.perform_checkout(repo_path, branch) {
+            Ok(status) => RepoCheckoutResult::new(&repo.dest, status),
+            Err(GitRepositoryError::BranchNotFound(_)) => {
+                RepoCheckoutResult::new(&repo.dest, CheckoutStatus::Skipped)
+                    .with_message(format!("Branch '{}' not found", branch))
+            }
+            Err(e) => RepoCheckoutResult::new(&repo.dest, CheckoutStatus::Failed)
+                .with_message(e.to_string()),
+        }
+    }
+
+    /// ブランチを切り替える
+    ///
+    /// ローカルのブランチ、syncのfetch元リモートのブランチ（追跡ブランチとして作成）の順に探し、
+    /// どちらもない場合は`create`の指定時のみ現在のHEADから作成する。
+    fn perform_checkout(
+        &self,
+        repo_path: &Path,
+        branch: &BranchName,
+    ) -> Result<CheckoutStatus, GitRepositoryError> {
+        let git_repo = GitRepository::open(repo_path)?;
+        let name = branch.as_str();
+
+        if git_repo.get_current_branch()? == name {
+            return Ok(CheckoutStatus::AlreadyOnBranch);
+        }
+
+        if !self.config.force && !git_repo.is_working_directory_clean()? {
+            return Ok(CheckoutStatus::Dirty);
+        }
+
+        let remote_branch = format!("{}/{}", git_repo.fetch_remote(), name);
+        if git_repo.has_branch(name, GitBranchType::Local) {
+            git_repo.checkout(name)?;
+            Ok(CheckoutStatus::Switched)
+        } else if git_repo.has_branch(&remote_branch, GitBranchType::Remote) {
+            git_repo.create_branch(branch, Some(&format!("refs/remotes/{}", remote_branch)))?;
+            git_repo.set_upstream(name, &remote_branch)?;
+            Ok(CheckoutStatus::Switched)
+        } else if self.config.create {
+            git_repo.create_branch(branch, None)?;
+            Ok(CheckoutStatus::Created)
+        } else {
+            Err(GitRepositoryError::BranchNotFound(name.to_string()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::manifest::Manifest;
+    use crate::domain::entities::workspace::{WorkspaceConfig, WorkspaceStatus};
+    use tempfile::TempDir;
+
+    fn commit_file(repo: &git2::Repository, name: &str, content: &str) {
+        std::fs::write(repo.workdir().unwrap().join(name), content).unwrap();
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new(name)).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let signature = git2::Signature::now("wmgr", "wmgr@example.com").unwrap();
+        let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+        let parents: Vec<&git2::Commit> = parent.iter().collect();
+        repo.commit(Some("HEAD"), &signature, &signature, name, &tree, &parents)
+            .unwrap();
+    }
+
+    /// `main`にコミットのあるリポジトリ`app`と`lib`を持つワークスペース
+    fn workspace(root: &Path) -> Workspace {
+        let mut repos = Vec::new();
+        for dest in ["app", "lib"] {
+            let repo = git2::Repository::init(root.join(dest)).unwrap();
+            repo.set_head("refs/heads/main").unwrap();
+            commit_file(&repo, "README", dest);
+            repos.push(ManifestRepo::new(
+                format!("https://example.com/{}.git", dest),
+                dest,
+            ));
+        }
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let mut workspace = Workspace::new(root.to_path_buf(), workspace_config)
+            .with_manifest(Manifest::new(repos));
+        workspace.status = WorkspaceStatus::Initialized;
+        workspace
+    }
+
+    fn current_branch(path: &Path) -> String {
+        GitRepository::open(path)
+            .unwrap()
+            .get_current_branch()
+            .unwrap()
+    }
+
+    fn status_of(result: &CheckoutResult, dest: &str) -> CheckoutStatus {
+        result
+            .repositories
+            .iter()
+            .find(|repo| repo.dest == dest)
+            .unwrap()
+            .status
+    }
+
+    #[tokio::test]
+    async fn test_checkout_existing_branch_skips_repos_without_it() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = workspace(temp_dir.path());
+        let app = git2::Repository::open(temp_dir.path().join("app")).unwrap();
+        let head = app.head().unwrap().peel_to_commit().unwrap();
+        app.branch("feature", &head, false).unwrap();
+
+        let use_case = CheckoutBranchUseCase::new(CheckoutBranchConfig::new("feature"));
+        let result = use_case.execute(&workspace).await.unwrap();
+
+        assert_eq!(status_of(&result, "app"), CheckoutStatus::Switched);
+        assert_eq!(status_of(&result, "lib"), CheckoutStatus::Skipped);
+        assert!(result.is_success());
+        assert_eq!(current_branch(&temp_dir.path().join("app")), "feature");
+        assert_eq!(current_branch(&temp_dir.path().join("lib")), "main");
+
+        // 2回目は既にそのブランチにいる
+        let result = use_case.execute(&workspace).await.unwrap();
+        assert_eq!(status_of(&result, "app"), CheckoutStatus::AlreadyOnBranch);
+    }
+
+    #[tokio::test]
+    async fn test_checkout_create_makes_branch_from_head() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = workspace(temp_dir.path());
+        let app = git2::Repository::open(temp_dir.path().join("app")).unwrap();
+        let head = app.head().unwrap().peel_to_commit().unwrap();
+        app.branch("feature", &head, false).unwrap();
+
+        let config = CheckoutBranchConfig::new("feature").with_create(true);
+        let result = CheckoutBranchUseCase::new(config)
+            .execute(&workspace)
+            .await
+            .unwrap();
+
+        assert_eq!(status_of(&result, "app"), CheckoutStatus::Switched);
+        assert_eq!(status_of(&result, "lib"), CheckoutStatus::Created);
+        assert_eq!(result.created_count, 1);
+        let lib = git2::Repository::open(temp_dir.path().join("lib")).unwrap();
+        assert_eq!(lib.head().unwrap().shorthand(), Some("feature"));
+        assert!(lib.find_branch("main", git2::BranchType::Local).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_checkout_tracks_remote_branch() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = workspace(temp_dir.path());
+        let lib = git2::Repository::open(temp_dir.path().join("lib")).unwrap();
+        lib.remote("origin", "https://example.com/lib.git").unwrap();
+        let head = lib.head().unwrap().peel_to_commit().unwrap();
+        lib.reference("refs/remotes/origin/feature", head.id(), false, "test")
+            .unwrap();
+
+        let result = CheckoutBranchUseCase::new(CheckoutBranchConfig::new("feature"))
+            .execute(&workspace)
+            .await
+            .unwrap();
+
+        assert_eq!(status_of(&result, "lib"), CheckoutStatus::Switched);
+        let lib = GitRepository::open(temp_dir.path().join("lib")).unwrap();
+        assert_eq!(lib.get_current_branch().unwrap(), "feature");
+        assert_eq!(lib.upstream_branch().as_deref(), Some("origin/feature"));
+    }
+
+    #[tokio::test]
+    async fn test_checkout_tracks_branch_on_upstream_remote() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = workspace(temp_dir.path());
+        let lib = git2::Repository::open(temp_dir.path().join("lib")).unwrap();
+        lib.remote("origin", "https://example.com/lib.git").unwrap();
+        lib.remote("upstream", "https://mirror.example.com/lib.git")
+            .unwrap();
+        let head = lib.head().unwrap().peel_to_commit().unwrap();
+        for name in [
+            "refs/remotes/upstream/main",
+            "refs/remotes/upstream/feature",
+        ] {
+            lib.reference(name, head.id(), false, "test").unwrap();
+        }
+        lib.find_branch("main", git2::BranchType::Local)
+            .unwrap()
+            .set_upstream(Some("upstream/main"))
+            .unwrap();
+
+        let result = CheckoutBranchUseCase::new(CheckoutBranchConfig::new("feature"))
+            .execute(&workspace)
+            .await
+            .unwrap();
+
+        // syncと同じく現在のブランチの上流リモートからブランチを探す
+        assert_eq!(status_of(&result, "lib"), CheckoutStatus::Switched);
+        let lib = GitRepository::open(temp_dir.path().join("lib")).unwrap();
+        assert_eq!(lib.upstream_branch().as_deref(), Some("upstream/feature"));
+    }
+
+    #[tokio::test]
+    async fn test_checkout_refuses_dirty_repos_unless_forced() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = workspace(temp_dir.path());
+        std::fs::write(temp_dir.path().join("app/README"), "local change").unwrap();
+
+        let config = CheckoutBranchConfig::new("feature").with_create(true);
+        let result = CheckoutBranchUseCase::new(config.clone())
+            .execute(&workspace)
+            .await
+            .unwrap();
+
+        assert_eq!(status_of(&result, "app"), CheckoutStatus::Dirty);
+        assert_eq!(status_of(&result, "lib"), CheckoutStatus::Created);
+        assert!(!result.is_success());
+        assert_eq!(current_branch(&temp_dir.path().join("app")), "main");
+
+        // --forceでは変更を持ったまま切り替える
+        let result = CheckoutBranchUseCase::new(config.with_force(true))
+            .execute(&workspace)
+            .await
+            .unwrap();
+        assert_eq!(status_of(&result, "app"), CheckoutStatus::Created);
+        assert!(result.is_success());
+        assert_eq!(current_branch(&temp_dir.path().join("app")), "feature");
+        assert_eq!(
+            std::fs::read_to_string(temp_dir.path().join("app/README")).unwrap(),
+            "local change"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_checkout_rejects_invalid_branch_name() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = workspace(temp_dir.path());
+
+        let result = CheckoutBranchUseCase::new(CheckoutBranchConfig::new("bad..name"))
+            .execute(&workspace)
+            .await;
+        assert!(matches!(
+            result,
+            Err(CheckoutBranchError::InvalidBranchName(_))
+        ));
+    }
+}
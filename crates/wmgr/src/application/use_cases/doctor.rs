@@ -0,0 +1,652 @@
+use crate::common::error::WmgrError;
+use crate::domain::entities::{
+    manifest::ManifestRepo,
+    workspace::{DestPathError, Workspace},
+};
+use crate::domain::value_objects::{branch_name::BranchName, git_url::GitUrl, scm_type::ScmType};
+use crate::infrastructure::git::remote::GitRemoteManager;
+use crate::infrastructure::git::repository::{GitRepository, ResetMode};
+use crate::infrastructure::scm::scm_factory::ScmFactory;
+use std::path::Path;
+use thiserror::Error;
+
+/// Doctor関連のエラー
+#[derive(Debug, Error)]
+pub enum DoctorError {
+    #[error("Workspace not initialized: {0}")]
+    WorkspaceNotInitialized(String),
+
+    #[error("Manifest not loaded: {0}")]
+    ManifestNotLoaded(String),
+
+    #[error("Invalid repository destination: {0}")]
+    InvalidDest(#[from] DestPathError),
+}
+
+impl From<DoctorError> for WmgrError {
+    fn from(error: DoctorError) -> Self {
+        match &error {
+            DoctorError::WorkspaceNotInitialized(path) => {
+                WmgrError::workspace_not_initialized(path)
+            }
+            DoctorError::ManifestNotLoaded(_) => {
+                WmgrError::manifest_error_with_source(error.to_string(), None, error)
+            }
+            DoctorError::InvalidDest(_) => {
+                WmgrError::validation_error("dest", error.to_string(), None)
+            }
+        }
+    }
+}
+
+/// Doctorの設定
+#[derive(Debug, Clone, Default)]
+pub struct DoctorConfig {
+    /// 特定のグループのみを診断するか（空の場合は全て）
+    pub groups: Vec<String>,
+
+    /// 検出した問題の修復を試みるか
+    pub fix: bool,
+
+    /// データ損失を伴う修復も行うか（未コミットの変更を破棄するなど）
+    pub force: bool,
+
+    /// 詳細ログを出力するか
+    pub verbose: bool,
+}
+
+impl DoctorConfig {
+    /// 対象グループを設定
+    pub fn with_groups(mut self, groups: Vec<String>) -> Self {
+        self.groups = groups;
+        self
+    }
+
+    /// 修復モードを設定
+    pub fn with_fix(mut self, fix: bool) -> Self {
+        self.fix = fix;
+        self
+    }
+
+    /// 強制修復を設定
+    pub fn with_force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
+    /// 詳細ログ出力を設定
+    pub fn with_verbose(mut self, verbose: bool) -> Self {
+        self.verbose = verbose;
+        self
+    }
+}
+
+/// 検出した問題の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueKind {
+    /// マニフェストにあるリポジトリがクローンされていない
+    MissingRepository,
+    /// originのURLがマニフェストと異なる
+    OriginMismatch,
+    /// マニフェストで指定されたブランチがチェックアウトされていない
+    WrongBranch,
+}
+
+impl std::fmt::Display for IssueKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IssueKind::MissingRepository => write!(f, "missing repository"),
+            IssueKind::OriginMismatch => write!(f, "origin URL mismatch"),
+            IssueKind::WrongBranch => write!(f, "wrong branch"),
+        }
+    }
+}
+
+/// 修復の結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FixStatus {
+    /// 修復に成功
+    Fixed,
+    /// 修復を行わなかった（データ損失の恐れがあるなど）
+    Skipped,
+    /// 修復に失敗
+    Failed,
+}
+
+/// 検出した問題
+#[derive(Debug, Clone)]
+pub struct DoctorIssue {
+    /// リポジトリのdest
+    pub dest: String,
+
+    /// 問題の種類
+    pub kind: IssueKind,
+
+    /// マニフェストが期待する値
+    pub expected: String,
+
+    /// 実際の値（Noneの場合は存在しない）
+    pub found: Option<String>,
+
+    /// 修復の結果（Noneの場合は修復を試みていない）
+    pub fix: Option<FixStatus>,
+
+    /// 補足メッセージ（エラー内容やスキップ理由）
+    pub message: Option<String>,
+}
+
+impl DoctorIssue {
+    /// 新しい問題を作成
+    pub fn new(
+        dest: impl Into<String>,
+        kind: IssueKind,
+        expected: impl Into<String>,
+        found: Option<String>,
+    ) -> Self {
+        Self {
+            dest: dest.into(),
+            kind,
+            expected: expected.into(),
+            found,
+            fix: None,
+            message: None,
+        }
+    }
+
+    /// 修復の結果を設定
+    pub fn with_fix(mut self, fix: FixStatus) -> Self {
+        self.fix = Some(fix);
+        self
+    }
+
+    /// メッセージを設定
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// 問題が解消されていないか
+    pub fn is_outstanding(&self) -> bool {
+        self.fix != Some(FixStatus::Fixed)
+    }
+}
+
+/// 診断の結果
+#[derive(Debug, Clone, Default)]
+pub struct DoctorResult {
+    /// 検出した問題のリスト
+    pub issues: Vec<DoctorIssue>,
+
+    /// 診断したリポジトリの数
+    pub checked_count: usize,
+
+    /// 修復された問題の数
+    pub fixed_count: usize,
+
+    /// 修復を見送った問題の数
+    pub skipped_count: usize,
+
+    /// 修復に失敗した問題の数
+    pub failed_count: usize,
+}
+
+impl DoctorResult {
+    /// 新しいDoctorResultを作成
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 問題を追加して集計を更新
+    pub fn add_issue(&mut self, issue: DoctorIssue) {
+        match issue.fix {
+            Some(FixStatus::Fixed) => self.fixed_count += 1,
+            Some(FixStatus::Skipped) => self.skipped_count += 1,
+            Some(FixStatus::Failed) => self.failed_count += 1,
+            None => {}
+        }
+        self.issues.push(issue);
+    }
+
+    /// 未解消の問題がないか
+    pub fn is_healthy(&self) -> bool {
+        self.issues.iter().all(|issue| !issue.is_outstanding())
+    }
+}
+
+/// ワークスペース診断のユースケース
+pub struct DoctorUseCase {
+    /// 診断設定
+    config: DoctorConfig,
+}
+
+impl DoctorUseCase {
+    /// 新しいDoctorUseCaseインスタンスを作成
+    pub fn new(config: DoctorConfig) -> Self {
+        Self { config }
+    }
+
+    /// ワークスペースの診断（と修復）を実行
+    pub async fn execute(&self, workspace: &Workspace) -> Result<DoctorResult, DoctorError> {
+        // 1. ワークスペースの初期化チェック
+        if !workspace.is_initialized() {
+            return Err(DoctorError::WorkspaceNotInitialized(
+                workspace.root_path.display().to_string(),
+            ));
+        }
+
+        let manifest = workspace.manifest.as_ref().ok_or_else(|| {
+            DoctorError::ManifestNotLoaded(workspace.root_path.display().to_string())
+        })?;
+
+        // 2. 対象リポジトリの決定
+        let target_repos = if self.config.groups.is_empty() {
+            workspace.configured_repos()
+        } else {
+            manifest.repos_in_groups(&self.config.groups)
+        };
+
+        // 3. リポジトリごとに診断
+        let mut result = DoctorResult::new();
+        for repo in target_repos {
+            // Git以外のSCMはリモートやブランチの概念が異なるため対象外
+            // ローカル専用のリポジトリは比較するoriginがない
+            if repo.scm != ScmType::Git || repo.is_local_only() {
+                continue;
+            }
+            result.checked_count += 1;
+
+            if self.config.verbose {
+                println!("Checking {}", repo.dest);
+            }
+
+            let repo_path = workspace.resolve_dest(&repo.dest)?;
+            let expected_url = Self::expected_url(repo, workspace);
+
+            if !repo_path.exists() {
+                result.add_issue(self.check_missing(repo, &expected_url, &repo_path).await);
+                continue;
+            }
+
+            let git_repo = match GitRepository::open(&repo_path) {
+                Ok(git_repo) => git_repo,
+                Err(e) => {
+                    result.add_issue(
+                        DoctorIssue::new(
+                            &repo.dest,
+                            IssueKind::MissingRepository,
+                            &expected_url,
+                            None,
+                        )
+                        .with_message(format!("Not a git repository: {}", e)),
+                    );
+                    continue;
+                }
+            };
+
+            if let Some(issue) = self.check_origin(repo, &expected_url, &git_repo) {
+                result.add_issue(issue);
+            }
+
+            let expected_branch = repo.branch.as_ref().or(manifest.default_branch.as_ref());
+            if let Some(branch) = expected_branch {
+                // リビジョンで固定されたリポジトリはdetached HEADが正常な状態
+                if repo.get_effective_revision().is_none() {
+                    if let Some(issue) = self.check_branch(repo, branch, &git_repo) {
+                        result.add_issue(issue);
+                    }
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// マニフェスト上のURL（singular_remoteが設定されていればミラー上のURL）
+    fn expected_url(repo: &ManifestRepo, workspace: &Workspace) -> String {
+        workspace
+            .config
+            .singular_remote
+            .as_ref()
+            .and_then(|base| GitUrl::new(&repo.url).ok()?.rebased_onto(base).ok())
+            .map(|url| url.to_clone_url())
+            .unwrap_or_else(|| repo.url.clone())
+    }
+
+    /// 未クローンのリポジトリの診断と再クローン
+    async fn check_missing(
+        &self,
+        repo: &ManifestRepo,
+        expected_url: &str,
+        repo_path: &Path,
+    ) -> DoctorIssue {
+        let issue = DoctorIssue::new(&repo.dest, IssueKind::MissingRepository, expected_url, None);
+        if !self.config.fix {
+            return issue;
+        }
+
+        let scm = match ScmFactory::create_scm_for_url(repo.scm.clone(), &repo.url) {
+            Ok(scm) => scm,
+            Err(e) => {
+                return issue
+                    .with_fix(FixStatus::Failed)
+                    .with_message(e.to_string())
+            }
+        };
+
+        match scm
+            .clone_repository(expected_url, repo_path, &repo.to_clone_options())
+            .await
+        {
+            Ok(()) => issue
+                .with_fix(FixStatus::Fixed)
+                .with_message(format!("Cloned into {}", repo_path.display())),
+            Err(e) => issue
+                .with_fix(FixStatus::Failed)
+                .with_message(e.to_string()),
+        }
+    }
+
+    /// originのURLの診断と修正
+    fn check_origin(
+        &self,
+        repo: &ManifestRepo,
+        expected_url: &str,
+        git_repo: &GitRepository,
+    ) -> Option<DoctorIssue> {
+        let remote_manager = GitRemoteManager::new(git_repo.git2_repo());
+        let found = if remote_manager.remote_exists("origin") {
+            git_repo
+                .git2_repo()
+                .find_remote("origin")
+                .ok()
+                .and_then(|remote| remote.url().map(str::to_string))
+        } else {
+            None
+        };
+
+        let expected = GitUrl::new(expected_url).ok();
+        let matches = match (&expected, &found) {
+            (Some(expected), Some(found)) => GitUrl::new(found)
+                .map(|found| found.is_same_repo(expected))
+                .unwrap_or(false),
+            (None, Some(found)) => found == expected_url,
+            (_, None) => false,
+        };
+        if matches {
+            return None;
+        }
+
+        let issue = DoctorIssue::new(
+            &repo.dest,
+            IssueKind::OriginMismatch,
+            expected_url,
+            found.clone(),
+        );
+        if !self.config.fix {
+            return Some(issue);
+        }
+
+        // GitUrlとして解釈できないURLは書き換え後の形が予測できないため修正しない
+        let Some(expected) = expected else {
+            return Some(
+                issue
+                    .with_fix(FixStatus::Skipped)
+                    .with_message("Manifest URL cannot be normalized"),
+            );
+        };
+
+        let fixed = if found.is_some() {
+            remote_manager.set_remote_url("origin", &expected)
+        } else {
+            remote_manager.add_remote("origin", &expected).map(|_| ())
+        };
+        Some(match fixed {
+            Ok(()) => issue.with_fix(FixStatus::Fixed),
+            Err(e) => issue
+                .with_fix(FixStatus::Failed)
+                .with_message(e.to_string()),
+        })
+    }
+
+    /// チェックアウト中のブランチの診断と切り替え
+    fn check_branch(
+        &self,
+        repo: &ManifestRepo,
+        branch: &str,
+        git_repo: &GitRepository,
+    ) -> Option<DoctorIssue> {
+        let found = git_repo.get_current_branch().ok();
+        if found.as_deref() == Some(branch) {
+            return None;
+        }
+
+        let issue = DoctorIssue::new(&repo.dest, IssueKind::WrongBranch, branch, found);
+        if !self.config.fix {
+            return Some(issue);
+        }
+
+        // 未コミットの変更を破棄する切り替えは--forceの場合のみ行う
+        match git_repo.is_working_directory_clean() {
+            Ok(true) => {}
+            Ok(false) if self.config.force => {
+                if let Err(e) = git_repo.reset("HEAD", ResetMode::Hard) {
+                    return Some(
+                        issue
+                            .with_fix(FixStatus::Failed)
+                            .with_message(e.to_string()),
+                    );
+                }
+            }
+            Ok(false) => {
+                return Some(issue.with_fix(FixStatus::Skipped).with_message(
+                    "Working tree has uncommitted changes (use --force to discard them)",
+                ));
+            }
+            Err(e) => {
+                return Some(
+                    issue
+                        .with_fix(FixStatus::Failed)
+                        .with_message(e.to_string()),
+                )
+            }
+        }
+
+        Some(match Self::checkout_branch(git_repo, branch) {
+            Ok(()) => issue.with_fix(FixStatus::Fixed),
+            Err(message) => issue.with_fix(FixStatus::Failed).with_message(message),
+        })
+    }
+
+    /// ブランチをチェックアウト（ローカルにない場合はoriginから作成）
+    fn checkout_branch(git_repo: &GitRepository, branch: &str) -> Result<(), String> {
+        let has_local = git_repo
+            .git2_repo()
+            .find_branch(branch, git2::BranchType::Local)
+            .is_ok();
+        if has_local {
+            return git_repo.checkout(branch).map_err(|e| e.to_string());
+        }
+
+        let branch_name = BranchName::new(branch).map_err(|e| e.to_string())?;
+        git_repo
+            .create_branch(&branch_name, Some(branch))
+            .map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::manifest::Manifest;
+    use crate::domain::entities::workspace::{WorkspaceConfig, WorkspaceStatus};
+    use tempfile::TempDir;
+
+    const APP_URL: &str = "https://github.com/example/app.git";
+
+    fn workspace_with(root: &Path, repos: Vec<ManifestRepo>) -> Workspace {
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        Workspace::new(root.to_path_buf(), workspace_config)
+            .with_manifest(Manifest::new(repos))
+            .with_status(WorkspaceStatus::Initialized)
+    }
+
+    /// `app`にoriginを設定し、mainに1コミットしたリポジトリを作成
+    fn init_app(root: &Path, origin: &str) -> git2::Repository {
+        let repo = git2::Repository::init(root.join("app")).unwrap();
+        repo.set_head("refs/heads/main").unwrap();
+        repo.remote("origin", origin).unwrap();
+
+        std::fs::write(root.join("app").join("README.md"), "# app\n").unwrap();
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new("README.md")).unwrap();
+        index.write().unwrap();
+        let tree_id = index.write_tree().unwrap();
+        {
+            let tree = repo.find_tree(tree_id).unwrap();
+            let signature = git2::Signature::now("wmgr", "wmgr@example.com").unwrap();
+            repo.commit(Some("HEAD"), &signature, &signature, "initial", &tree, &[])
+                .unwrap();
+        }
+        repo
+    }
+
+    fn origin_url(repo: &git2::Repository) -> String {
+        repo.find_remote("origin")
+            .unwrap()
+            .url()
+            .unwrap()
+            .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_wrong_origin_url_is_reported_and_restored_with_fix() {
+        let temp_dir = TempDir::new().unwrap();
+        let git_repo = init_app(temp_dir.path(), "https://github.com/someone/fork.git");
+        let workspace = workspace_with(
+            temp_dir.path(),
+            vec![ManifestRepo::new(APP_URL, "app").with_branch("main")],
+        );
+
+        // --fixなしでは報告のみ
+        let result = DoctorUseCase::new(DoctorConfig::default())
+            .execute(&workspace)
+            .await
+            .unwrap();
+        assert_eq!(result.checked_count, 1);
+        assert_eq!(result.issues.len(), 1);
+        let issue = &result.issues[0];
+        assert_eq!(issue.kind, IssueKind::OriginMismatch);
+        assert_eq!(issue.expected, APP_URL);
+        assert_eq!(
+            issue.found.as_deref(),
+            Some("https://github.com/someone/fork.git")
+        );
+        assert_eq!(issue.fix, None);
+        assert!(!result.is_healthy());
+        assert_eq!(origin_url(&git_repo), "https://github.com/someone/fork.git");
+
+        // --fixでマニフェストのURLに戻る
+        let result = DoctorUseCase::new(DoctorConfig::default().with_fix(true))
+            .execute(&workspace)
+            .await
+            .unwrap();
+        assert_eq!(result.fixed_count, 1);
+        assert_eq!(result.issues[0].fix, Some(FixStatus::Fixed));
+        assert!(result.is_healthy());
+        assert_eq!(origin_url(&git_repo), APP_URL);
+
+        // 修復後は問題が検出されない
+        let result = DoctorUseCase::new(DoctorConfig::default())
+            .execute(&workspace)
+            .await
+            .unwrap();
+        assert!(result.issues.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_equivalent_origin_url_is_not_an_issue() {
+        let temp_dir = TempDir::new().unwrap();
+        init_app(temp_dir.path(), "git@github.com:example/app.git");
+        let workspace = workspace_with(temp_dir.path(), vec![ManifestRepo::new(APP_URL, "app")]);
+
+        let result = DoctorUseCase::new(DoctorConfig::default())
+            .execute(&workspace)
+            .await
+            .unwrap();
+        assert!(result.issues.is_empty());
+        assert!(result.is_healthy());
+    }
+
+    #[tokio::test]
+    async fn test_branch_switch_requires_force_when_tree_is_dirty() {
+        let temp_dir = TempDir::new().unwrap();
+        let git_repo = init_app(temp_dir.path(), APP_URL);
+        {
+            let head = git_repo.head().unwrap().peel_to_commit().unwrap();
+            git_repo.branch("feature", &head, false).unwrap();
+        }
+        git_repo.set_head("refs/heads/feature").unwrap();
+        let readme = temp_dir.path().join("app").join("README.md");
+        std::fs::write(&readme, "# local edits\n").unwrap();
+
+        let workspace = workspace_with(
+            temp_dir.path(),
+            vec![ManifestRepo::new(APP_URL, "app").with_branch("main")],
+        );
+
+        // 未コミットの変更があるため--fixだけでは切り替えない
+        let result = DoctorUseCase::new(DoctorConfig::default().with_fix(true))
+            .execute(&workspace)
+            .await
+            .unwrap();
+        let issue = &result.issues[0];
+        assert_eq!(issue.kind, IssueKind::WrongBranch);
+        assert_eq!(issue.found.as_deref(), Some("feature"));
+        assert_eq!(issue.fix, Some(FixStatus::Skipped));
+        assert_eq!(std::fs::read_to_string(&readme).unwrap(), "# local edits\n");
+
+        let result = DoctorUseCase::new(DoctorConfig::default().with_fix(true).with_force(true))
+            .execute(&workspace)
+            .await
+            .unwrap();
+        assert_eq!(result.issues[0].fix, Some(FixStatus::Fixed));
+        assert_eq!(
+            GitRepository::open(temp_dir.path().join("app"))
+                .unwrap()
+                .get_current_branch()
+                .unwrap(),
+            "main"
+        );
+        assert_eq!(std::fs::read_to_string(&readme).unwrap(), "# app\n");
+    }
+
+    #[tokio::test]
+    async fn test_missing_repository_is_reported() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = workspace_with(temp_dir.path(), vec![ManifestRepo::new(APP_URL, "app")]);
+
+        let result = DoctorUseCase::new(DoctorConfig::default())
+            .execute(&workspace)
+            .await
+            .unwrap();
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].kind, IssueKind::MissingRepository);
+        assert_eq!(result.issues[0].found, None);
+        assert!(!result.is_healthy());
+    }
+
+    #[tokio::test]
+    async fn test_uninitialized_workspace_is_rejected() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let workspace = Workspace::new(temp_dir.path().to_path_buf(), workspace_config);
+
+        let result = DoctorUseCase::new(DoctorConfig::default())
+            .execute(&workspace)
+            .await;
+        assert!(matches!(
+            result,
+            Err(DoctorError::WorkspaceNotInitialized(_))
+        ));
+    }
+}
@@ -1,11 +1,26 @@
-use crate::domain::entities::{manifest::ManifestRepo, workspace::Workspace};
+use crate::application::use_cases::status_check::{
+    RepositoryState, StatusCheckConfig, StatusCheckError, StatusCheckUseCase,
+};
+use crate::common::error::WmgrError;
+use crate::common::executor::resolve_parallelism;
+use crate::common::instrument::instrument_repo;
+use crate::common::progress::{ProgressEvent, ProgressReporter, RepoOutcome};
+use crate::domain::entities::{
+    manifest::ManifestRepo,
+    repo_selector::{RepoSelector, RepoSelectorError},
+    workspace::{DestPathError, Workspace},
+};
+use crate::domain::value_objects::scm_type::ScmType;
+use crate::infrastructure::process::{CommandExecutor, CommandExecutorError, ExecutionConfig};
 use futures::future::join_all;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Component, Path, PathBuf};
 use std::sync::Arc;
 use thiserror::Error;
+use tokio::sync::mpsc::UnboundedSender;
 use tokio::sync::Semaphore;
+use tracing::{debug, info};
 
 /// ForeachCommand関連のエラー
 #[derive(Debug, Error)]
@@ -33,6 +48,60 @@ pub enum ForeachCommandError {
 
     #[error("Process execution error: {0}")]
     ProcessError(String),
+
+    #[error("Repository selection failed: {0}")]
+    SelectionFailed(#[from] RepoSelectorError),
+
+    #[error("Placeholder {{{placeholder}}} has no value for repo '{repo}'")]
+    UndefinedPlaceholder { repo: String, placeholder: String },
+
+    #[error("Status check failed: {0}")]
+    StatusCheckFailed(#[from] StatusCheckError),
+
+    #[error("Invalid repository destination: {0}")]
+    InvalidDest(#[from] DestPathError),
+
+    #[error("Subdirectory '{0}' must be a relative path inside the repository")]
+    InvalidSubdir(String),
+}
+
+impl From<ForeachCommandError> for WmgrError {
+    fn from(error: ForeachCommandError) -> Self {
+        match &error {
+            ForeachCommandError::WorkspaceNotInitialized(path) => {
+                WmgrError::workspace_not_initialized(path)
+            }
+            ForeachCommandError::CommandFailed { repo, .. }
+            | ForeachCommandError::RepositoryNotFound(repo)
+            | ForeachCommandError::UndefinedPlaceholder { repo, .. } => {
+                let repo = repo.clone();
+                WmgrError::repository_error_with_source(error.to_string(), Some(repo), error)
+            }
+            ForeachCommandError::InvalidCommand => {
+                WmgrError::validation_error("command", error.to_string(), None)
+            }
+            ForeachCommandError::EnvironmentVariableError { key, .. } => {
+                WmgrError::validation_error("environment", error.to_string(), Some(key.clone()))
+            }
+            ForeachCommandError::SelectionFailed(_) => {
+                WmgrError::validation_error("repo", error.to_string(), None)
+            }
+            ForeachCommandError::InvalidDest(_) => {
+                WmgrError::validation_error("dest", error.to_string(), None)
+            }
+            ForeachCommandError::InvalidSubdir(subdir) => {
+                WmgrError::validation_error("cd", error.to_string(), Some(subdir.clone()))
+            }
+            ForeachCommandError::StatusCheckFailed(_) => {
+                WmgrError::repository_error_with_source(error.to_string(), None, error)
+            }
+            ForeachCommandError::ParallelExecutionFailed(_)
+            | ForeachCommandError::IoError(_)
+            | ForeachCommandError::ProcessError(_) => {
+                WmgrError::internal_error_with_source(error.to_string(), error)
+            }
+        }
+    }
 }
 
 /// Foreach実行の設定
@@ -44,6 +113,15 @@ pub struct ForeachCommandConfig {
     /// 特定のグループのみを対象にするか（Noneの場合は全て）
     pub groups: Option<Vec<String>>,
 
+    /// いずれかを持つリポジトリのみを対象にするタグ（空の場合は絞り込まない）
+    pub tags: Vec<String>,
+
+    /// ひとつでも持つリポジトリを対象から除外するタグ
+    pub exclude_tags: Vec<String>,
+
+    /// destに一致させるglobパターン（空の場合は絞り込まない）
+    pub repo_patterns: Vec<String>,
+
     /// 並列実行するか
     pub parallel: bool,
 
@@ -62,8 +140,23 @@ pub struct ForeachCommandConfig {
     /// コマンドのタイムアウト（秒）
     pub timeout_seconds: Option<u64>,
 
+    /// リポジトリ（dest）ごとのタイムアウト（秒）。マニフェストの`foreach_timeout`より優先
+    pub repo_timeouts: HashMap<String, u64>,
+
     /// 作業ディレクトリをリポジトリルートに変更するか
     pub change_dir: bool,
+
+    /// 各リポジトリ内でコマンドを実行するサブディレクトリ（存在しないリポジトリはスキップ）
+    pub subdir: Option<PathBuf>,
+
+    /// gitのサブコマンドとして実行するか（先頭にgitを付け、Git以外のリポジトリはスキップ）
+    pub git: bool,
+
+    /// いずれかの状態にあるリポジトリのみを対象にする（空の場合は絞り込まない）
+    pub states: Vec<RepositoryState>,
+
+    /// いずれかの状態にあるリポジトリを対象から除外する（`states`より優先）
+    pub excluded_states: Vec<RepositoryState>,
 }
 
 impl Default for ForeachCommandConfig {
@@ -71,13 +164,21 @@ impl Default for ForeachCommandConfig {
         Self {
             command: String::new(),
             groups: None,
+            tags: Vec::new(),
+            exclude_tags: Vec::new(),
+            repo_patterns: Vec::new(),
             parallel: false,
             max_parallel: None,
             continue_on_error: false,
             verbose: false,
             environment_variables: HashMap::new(),
             timeout_seconds: None,
+            repo_timeouts: HashMap::new(),
             change_dir: true,
+            subdir: None,
+            git: false,
+            states: Vec::new(),
+            excluded_states: Vec::new(),
         }
     }
 }
@@ -104,6 +205,32 @@ impl ForeachCommandConfig {
         self
     }
 
+    /// 対象にするタグを設定
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// 除外するタグを設定
+    pub fn with_exclude_tags(mut self, exclude_tags: Vec<String>) -> Self {
+        self.exclude_tags = exclude_tags;
+        self
+    }
+
+    /// destに一致させるglobパターンを設定
+    pub fn with_repo_patterns(mut self, repo_patterns: Vec<String>) -> Self {
+        self.repo_patterns = repo_patterns;
+        self
+    }
+
+    /// グループ・タグ・globの指定から対象リポジトリの選択条件を作成
+    pub fn repo_selector(&self) -> RepoSelector {
+        RepoSelector::new(self.groups.clone())
+            .with_tags(self.tags.clone())
+            .with_exclude_tags(self.exclude_tags.clone())
+            .with_repo_patterns(self.repo_patterns.clone())
+    }
+
     /// エラー継続フラグを設定
     pub fn with_continue_on_error(mut self, continue_on_error: bool) -> Self {
         self.continue_on_error = continue_on_error;
@@ -132,11 +259,69 @@ impl ForeachCommandConfig {
         self
     }
 
+    /// 特定リポジトリのタイムアウトを設定
+    pub fn with_repo_timeout(mut self, dest: impl Into<String>, timeout_seconds: u64) -> Self {
+        self.repo_timeouts.insert(dest.into(), timeout_seconds);
+        self
+    }
+
+    /// リポジトリに適用するタイムアウト
+    ///
+    /// `repo_timeouts`、マニフェストの`foreach_timeout`、全体の`timeout_seconds`の順に優先する。
+    pub fn timeout_for(&self, repo: &ManifestRepo) -> Option<u64> {
+        self.repo_timeouts
+            .get(&repo.dest)
+            .copied()
+            .or(repo.foreach_timeout)
+            .or(self.timeout_seconds)
+    }
+
     /// 作業ディレクトリ変更フラグを設定
     pub fn with_change_dir(mut self, change_dir: bool) -> Self {
         self.change_dir = change_dir;
         self
     }
+
+    /// コマンドを実行するリポジトリ内のサブディレクトリを設定
+    pub fn with_subdir(mut self, subdir: impl Into<PathBuf>) -> Self {
+        self.subdir = Some(subdir.into());
+        self
+    }
+
+    /// gitサブコマンドモードを設定
+    pub fn with_git(mut self, git: bool) -> Self {
+        self.git = git;
+        self
+    }
+
+    /// 対象にするリポジトリの状態を設定
+    pub fn with_states(mut self, states: Vec<RepositoryState>) -> Self {
+        self.states = states;
+        self
+    }
+
+    /// 対象から除外するリポジトリの状態を設定
+    pub fn with_excluded_states(mut self, excluded_states: Vec<RepositoryState>) -> Self {
+        self.excluded_states = excluded_states;
+        self
+    }
+
+    /// 状態による絞り込みで対象になるか
+    ///
+    /// `states`のいずれかに一致し（空の場合は全て）、`excluded_states`のいずれにも一致しない場合に対象になる。
+    pub fn selects_state(&self, state: &RepositoryState) -> bool {
+        (self.states.is_empty() || self.states.contains(state))
+            && !self.excluded_states.contains(state)
+    }
+
+    /// 各リポジトリで実際に実行するコマンド文字列
+    pub fn effective_command(&self) -> String {
+        if self.git {
+            format!("git {}", self.command.trim())
+        } else {
+            self.command.clone()
+        }
+    }
 }
 
 /// コマンド実行の状態
@@ -247,12 +432,25 @@ impl CommandResult {
     pub fn is_failure(&self) -> bool {
         matches!(self.status, CommandStatus::Failed | CommandStatus::Timeout)
     }
+
+    /// 進捗イベントで報告する実行結果
+    fn outcome(&self) -> RepoOutcome {
+        match self.status {
+            CommandStatus::Success => RepoOutcome::Succeeded,
+            CommandStatus::Failed | CommandStatus::Timeout => RepoOutcome::Failed(
+                self.error_message
+                    .clone()
+                    .unwrap_or_else(|| "failed".to_string()),
+            ),
+            CommandStatus::Skipped | CommandStatus::Running => RepoOutcome::Skipped,
+        }
+    }
 }
 
 /// 全体の実行結果
 #[derive(Debug, Clone)]
 pub struct ForeachResult {
-    /// 各リポジトリでの実行結果
+    /// 各リポジトリでの実行結果（並列実行でも完了順ではなく対象リポジトリの順）
     pub results: Vec<CommandResult>,
 
     /// 成功したリポジトリ数
@@ -315,16 +513,48 @@ impl ForeachResult {
     }
 }
 
+/// シェルのコマンドラインにひとつの引数として埋め込めるよう値をクォートする
+///
+/// 英数字と`_@+=:,./-`だけの値はそのまま返す。それ以外はUnixでは単一引用符、
+/// Windows（cmd）では二重引用符で囲む。
+fn shell_quote(value: &str) -> String {
+    let is_safe = |c: char| c.is_ascii_alphanumeric() || "_@+=:,./-".contains(c);
+    if !value.is_empty() && value.chars().all(is_safe) {
+        return value.to_string();
+    }
+
+    if cfg!(target_os = "windows") {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        format!("'{}'", value.replace('\'', "'\\''"))
+    }
+}
+
 /// Foreach実行のユースケース
 pub struct ForeachCommandUseCase {
     /// 設定
     config: ForeachCommandConfig,
+
+    /// 進捗イベントの送信先
+    progress: ProgressReporter,
 }
 
 impl ForeachCommandUseCase {
     /// 新しいForeachCommandUseCaseインスタンスを作成
     pub fn new(config: ForeachCommandConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            progress: ProgressReporter::default(),
+        }
+    }
+
+    /// リポジトリごとの進捗イベントを`sender`に送る
+    ///
+    /// 各リポジトリについてコマンドの開始時に`RepoStarted`、終了時に結果を添えた
+    /// `RepoFinished`を送る。並列実行では完了順に届く。
+    pub fn with_progress(mut self, sender: UnboundedSender<ProgressEvent>) -> Self {
+        self.progress = ProgressReporter::new(sender);
+        self
     }
 
     /// Foreach実行を実行
@@ -334,12 +564,14 @@ impl ForeachCommandUseCase {
     ) -> Result<ForeachResult, ForeachCommandError> {
         // 1. 入力検証
         self.validate_command()?;
+        self.validate_subdir()?;
 
         // 2. ワークスペースの初期化チェック
         self.check_workspace_initialized(workspace)?;
 
         // 3. 実行対象リポジトリの決定
         let target_repos = self.determine_target_repositories(workspace)?;
+        let target_repos = self.filter_by_state(target_repos, workspace).await?;
 
         // 4. 環境変数の準備
         let env_vars = self.prepare_environment_variables(workspace)?;
@@ -364,6 +596,26 @@ impl ForeachCommandUseCase {
         Ok(())
     }
 
+    /// サブディレクトリの検証
+    ///
+    /// 絶対パスと`..`を含むパスはリポジトリの外を指しうるため拒否する。
+    fn validate_subdir(&self) -> Result<(), ForeachCommandError> {
+        if let Some(subdir) = &self.config.subdir {
+            let escapes = subdir.components().any(|component| {
+                matches!(
+                    component,
+                    Component::ParentDir | Component::RootDir | Component::Prefix(_)
+                )
+            });
+            if escapes || subdir.as_os_str().is_empty() {
+                return Err(ForeachCommandError::InvalidSubdir(
+                    subdir.display().to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
     /// ワークスペースが初期化済みかチェック
     fn check_workspace_initialized(
         &self,
@@ -387,20 +639,55 @@ impl ForeachCommandUseCase {
             .as_ref()
             .ok_or_else(|| ForeachCommandError::ProcessError("Manifest not loaded".to_string()))?;
 
-        let mut target_repos = Vec::new();
+        let target_repos = self
+            .config
+            .repo_selector()
+            .select(manifest, workspace.configured_repos())?
+            .into_iter()
+            .cloned()
+            .collect();
 
-        if let Some(groups) = &self.config.groups {
-            // 指定されたグループのリポジトリのみ
-            for group_name in groups {
-                let repos_in_group = manifest.get_repos_in_group(group_name);
-                target_repos.extend(repos_in_group.into_iter().cloned());
-            }
-        } else {
-            // 全てのリポジトリ
-            target_repos = manifest.repos.clone();
+        Ok(target_repos)
+    }
+
+    /// ステータスを確認し、指定された状態のリポジトリのみに絞り込む（除外する状態も適用）
+    async fn filter_by_state(
+        &self,
+        target_repos: Vec<ManifestRepo>,
+        workspace: &Workspace,
+    ) -> Result<Vec<ManifestRepo>, ForeachCommandError> {
+        if self.config.states.is_empty() && self.config.excluded_states.is_empty() {
+            return Ok(target_repos);
         }
 
-        Ok(target_repos)
+        let status_config = StatusCheckConfig {
+            groups: self.config.groups.clone(),
+            tags: self.config.tags.clone(),
+            exclude_tags: self.config.exclude_tags.clone(),
+            repo_patterns: self.config.repo_patterns.clone(),
+            jobs: self.config.max_parallel,
+            ..Default::default()
+        };
+        let status = StatusCheckUseCase::new(status_config)
+            .execute(workspace)
+            .await?;
+
+        let matching: Vec<&str> = status
+            .repositories
+            .iter()
+            .filter(|repo| self.config.selects_state(&repo.state))
+            .map(|repo| repo.dest.as_str())
+            .collect();
+        debug!(
+            "{} of {} repositories match the requested states",
+            matching.len(),
+            target_repos.len()
+        );
+
+        Ok(target_repos
+            .into_iter()
+            .filter(|repo| matching.contains(&repo.dest.as_str()))
+            .collect())
     }
 
     /// 環境変数の準備
@@ -411,20 +698,21 @@ impl ForeachCommandUseCase {
         let mut env_vars = self.config.environment_variables.clone();
 
         // ワークスペース関連の環境変数を設定
+        let mut workspace_vars =
+            vec![("WORKSPACE_ROOT", workspace.root_path.display().to_string())];
+        if workspace.manifest.is_some() {
+            workspace_vars.push(("MANIFEST_URL", workspace.config.manifest_url.clone()));
+            workspace_vars.push(("MANIFEST_BRANCH", workspace.config.manifest_branch.clone()));
+        }
+
+        // 後方互換のためTSRC_*も同じ値で設定する（TSRC_WORKSPACE_PATHは旧ドキュメントの名前）
         env_vars.insert(
-            "WMGR_WORKSPACE_ROOT".to_string(),
+            "TSRC_WORKSPACE_PATH".to_string(),
             workspace.root_path.display().to_string(),
         );
-
-        if let Some(_manifest) = &workspace.manifest {
-            env_vars.insert(
-                "WMGR_MANIFEST_URL".to_string(),
-                workspace.config.manifest_url.clone(),
-            );
-            env_vars.insert(
-                "WMGR_MANIFEST_BRANCH".to_string(),
-                workspace.config.manifest_branch.clone(),
-            );
+        for (name, value) in workspace_vars {
+            env_vars.insert(format!("TSRC_{}", name), value.clone());
+            env_vars.insert(format!("WMGR_{}", name), value);
         }
 
         Ok(env_vars)
@@ -439,13 +727,11 @@ impl ForeachCommandUseCase {
     ) -> Result<ForeachResult, ForeachCommandError> {
         let mut result = ForeachResult::new(false);
 
-        for repo in target_repos {
-            if self.config.verbose {
-                println!("Executing command in repository: {}", repo.dest);
-            }
+        for (index, repo) in target_repos.iter().enumerate() {
+            info!("Executing command in repository: {}", repo.dest);
 
             let command_result = self
-                .execute_command_in_repo(repo, workspace, env_vars)
+                .run_in_repo(repo, workspace, env_vars, index, target_repos.len())
                 .await;
 
             match command_result {
@@ -476,31 +762,29 @@ impl ForeachCommandUseCase {
     ) -> Result<ForeachResult, ForeachCommandError> {
         let mut result = ForeachResult::new(true);
 
-        if self.config.verbose {
-            println!(
-                "Executing commands in {} repositories in parallel",
-                target_repos.len()
-            );
-        }
+        info!(
+            "Executing commands in {} repositories in parallel",
+            target_repos.len()
+        );
 
         let start_time = std::time::Instant::now();
 
         // 並列度を制限するためのセマフォ
-        let max_parallel = self
-            .config
-            .max_parallel
-            .unwrap_or_else(|| std::cmp::min(target_repos.len(), num_cpus::get()));
+        let max_parallel = resolve_parallelism(self.config.max_parallel, target_repos.len());
         let semaphore = Arc::new(Semaphore::new(max_parallel));
 
         // 各リポジトリのタスクを作成
+        let count = target_repos.len();
         let tasks: Vec<_> = target_repos
             .iter()
-            .map(|repo| {
+            .enumerate()
+            .map(|(index, repo)| {
                 let repo = repo.clone();
                 let workspace = workspace.clone();
                 let env_vars = env_vars.clone();
                 let semaphore = semaphore.clone();
                 let config = self.config.clone();
+                let progress = self.progress.clone();
 
                 tokio::spawn(async move {
                     let _permit = semaphore.acquire().await.map_err(|e| {
@@ -510,9 +794,9 @@ impl ForeachCommandUseCase {
                         ))
                     })?;
 
-                    let use_case = ForeachCommandUseCase { config };
+                    let use_case = ForeachCommandUseCase { config, progress };
                     use_case
-                        .execute_command_in_repo(&repo, &workspace, &env_vars)
+                        .run_in_repo(&repo, &workspace, &env_vars, index, count)
                         .await
                 })
             })
@@ -564,20 +848,59 @@ impl ForeachCommandUseCase {
             }
         }
 
+        // スケジューリングに左右されないよう、対象リポジトリの順に並べ直す
+        let order: HashMap<&str, usize> = target_repos
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(index, repo)| (repo.dest.as_str(), index))
+            .collect();
+        result
+            .results
+            .sort_by_key(|r| order.get(r.dest.as_str()).copied().unwrap_or(usize::MAX));
+
         // 並列実行の場合は全体の実行時間を設定
         result.total_execution_time_ms = start_time.elapsed().as_millis() as u64;
 
         Ok(result)
     }
 
+    /// 単一リポジトリでコマンドを実行し、その開始と終了を進捗イベントとして送る
+    async fn run_in_repo(
+        &self,
+        repo: &ManifestRepo,
+        workspace: &Workspace,
+        env_vars: &HashMap<String, String>,
+        index: usize,
+        count: usize,
+    ) -> Result<CommandResult, ForeachCommandError> {
+        self.progress.started(&repo.dest, index, count);
+        let result = instrument_repo(
+            "foreach",
+            &repo.dest,
+            self.execute_command_in_repo(repo, workspace, env_vars, index, count),
+        )
+        .await;
+        let outcome = match &result {
+            Ok(command_result) => command_result.outcome(),
+            Err(e) => RepoOutcome::Failed(e.to_string()),
+        };
+        self.progress.finished(&repo.dest, outcome);
+        result
+    }
+
     /// 単一リポジトリでコマンド実行
+    ///
+    /// `index`は対象リポジトリ内での0始まりの位置、`count`は対象リポジトリ数。
     async fn execute_command_in_repo(
         &self,
         repo: &ManifestRepo,
         workspace: &Workspace,
         env_vars: &HashMap<String, String>,
+        index: usize,
+        count: usize,
     ) -> Result<CommandResult, ForeachCommandError> {
-        let repo_path = workspace.repo_path(&repo.dest);
+        let repo_path = workspace.resolve_dest(&repo.dest)?;
 
         // リポジトリが存在しない場合はスキップ
         if !repo_path.exists() {
@@ -585,8 +908,49 @@ impl ForeachCommandUseCase {
                 .with_skip("Repository directory does not exist".to_string()));
         }
 
+        // gitモードではGit以外のリポジトリをスキップし、GIT_DIRを解決する
+        let git_dir = if self.config.git {
+            if repo.scm != ScmType::Git {
+                return Ok(CommandResult::new(repo.dest.clone()).with_skip(format!(
+                    "Not a git repository ({}), skipped by --git",
+                    repo.scm
+                )));
+            }
+            match git2::Repository::open(&repo_path) {
+                Ok(git_repo) => Some(git_repo.path().to_path_buf()),
+                Err(e) => {
+                    return Ok(CommandResult::new(repo.dest.clone()).with_skip(format!(
+                        "Cannot open git repository: {}",
+                        e.message()
+                    )));
+                }
+            }
+        } else {
+            None
+        };
+
         // 作業ディレクトリの決定
-        let working_dir = if self.config.change_dir {
+        let working_dir = if let Some(subdir) = &self.config.subdir {
+            let dir = repo_path.join(subdir);
+            if !dir.is_dir() {
+                return Ok(CommandResult::new(repo.dest.clone()).with_skip(format!(
+                    "Subdirectory '{}' does not exist",
+                    subdir.display()
+                )));
+            }
+            // シンボリックリンクでリポジトリの外に出る場合も実行しない
+            let inside = match (dir.canonicalize(), repo_path.canonicalize()) {
+                (Ok(dir), Ok(root)) => dir.starts_with(root),
+                _ => false,
+            };
+            if !inside {
+                return Ok(CommandResult::new(repo.dest.clone()).with_skip(format!(
+                    "Subdirectory '{}' points outside the repository",
+                    subdir.display()
+                )));
+            }
+            dir
+        } else if self.config.change_dir {
             repo_path.clone()
         } else {
             workspace.root_path.clone()
@@ -594,70 +958,183 @@ impl ForeachCommandUseCase {
 
         // リポジトリ固有の環境変数を追加
         let mut repo_env_vars = env_vars.clone();
-        repo_env_vars.insert("WMGR_REPO_DEST".to_string(), repo.dest.clone());
-        repo_env_vars.insert("WMGR_REPO_URL".to_string(), repo.url.clone());
-        repo_env_vars.insert(
-            "WMGR_REPO_PATH".to_string(),
-            repo_path.display().to_string(),
-        );
-
-        if let Some(branch) = &repo.branch {
-            repo_env_vars.insert("WMGR_REPO_BRANCH".to_string(), branch.clone());
+        repo_env_vars.extend(Self::repository_environment(
+            repo, workspace, &repo_path, index, count,
+        ));
+
+        // ワークツリーやサブモジュールでも正しいリポジトリを指すようにする
+        if let Some(git_dir) = git_dir {
+            repo_env_vars.insert("GIT_DIR".to_string(), git_dir.display().to_string());
+            repo_env_vars.insert(
+                "GIT_WORK_TREE".to_string(),
+                repo_path.display().to_string(),
+            );
         }
 
         // コマンド実行
+        let command = Self::expand_placeholders(
+            &self.config.effective_command(),
+            &repo.dest,
+            &repo_env_vars,
+        )?;
         self.perform_command_execution(
-            &self.config.command,
+            &command,
             &working_dir,
             &repo_env_vars,
             &repo.dest,
+            self.config.timeout_for(repo),
         )
         .await
     }
 
-    /// 実際のコマンド実行（疑似実装）
+    /// リポジトリごとに注入する環境変数
+    ///
+    /// 全ての`WMGR_REPO_*`には後方互換のため同じ値の`TSRC_REPO_*`も設定する。
+    /// `WMGR_REPO_INDEX`は1始まりで、`WMGR_REPO_COUNT`と組み合わせて進捗表示に使える。
+    /// syncのフック実行でも同じ変数を設定する。
+    pub(crate) fn repository_environment(
+        repo: &ManifestRepo,
+        workspace: &Workspace,
+        repo_path: &Path,
+        index: usize,
+        count: usize,
+    ) -> HashMap<String, String> {
+        let groups = workspace
+            .manifest
+            .as_ref()
+            .map(|manifest| manifest.groups_of(&repo.dest))
+            .unwrap_or_default();
+
+        let mut vars = HashMap::new();
+        vars.insert("WMGR_REPO_DEST".to_string(), repo.dest.clone());
+        vars.insert("WMGR_REPO_URL".to_string(), repo.url.clone());
+        vars.insert(
+            "WMGR_REPO_PATH".to_string(),
+            repo_path.display().to_string(),
+        );
+        vars.insert("WMGR_REPO_SCM".to_string(), repo.scm.to_string());
+        vars.insert("WMGR_REPO_GROUPS".to_string(), groups.join(","));
+        vars.insert("WMGR_REPO_INDEX".to_string(), (index + 1).to_string());
+        vars.insert("WMGR_REPO_COUNT".to_string(), count.to_string());
+        if let Some(branch) = &repo.branch {
+            vars.insert("WMGR_REPO_BRANCH".to_string(), branch.clone());
+        }
+
+        let legacy: Vec<(String, String)> = vars
+            .iter()
+            .filter_map(|(key, value)| {
+                key.strip_prefix("WMGR_REPO_")
+                    .map(|suffix| (format!("TSRC_REPO_{}", suffix), value.clone()))
+            })
+            .collect();
+        vars.extend(legacy);
+        vars.insert("TSRC_REPO_NAME".to_string(), repo.dest.clone());
+
+        vars
+    }
+
+    /// コマンド中の`{dest}`・`{url}`・`{branch}`・`{path}`などをリポジトリごとの値に置き換える
+    ///
+    /// 使える名前は`WMGR_REPO_*`環境変数の接尾辞を小文字にしたもの（`{scm}`、`{groups}`、
+    /// `{index}`、`{count}`も可）。コマンドはシェル経由で実行するため、値は必要に応じて
+    /// クォートし、空白やメタ文字を含んでもひとつの引数のまま渡す。`${dest}`のような
+    /// シェル変数や未知の名前の波括弧（awkの`{print $1}`など）はそのまま残す。
+    /// 値のないプレースホルダ（ブランチ未指定のリポジトリの`{branch}`など）はエラー。
+    pub(crate) fn expand_placeholders(
+        command: &str,
+        repo_dest: &str,
+        repo_env_vars: &HashMap<String, String>,
+    ) -> Result<String, ForeachCommandError> {
+        const PLACEHOLDERS: [&str; 8] = [
+            "dest", "url", "branch", "path", "scm", "groups", "index", "count",
+        ];
+
+        let mut expanded = String::with_capacity(command.len());
+        let mut rest = command;
+        while let Some(start) = rest.find('{') {
+            let (before, after) = rest.split_at(start);
+            expanded.push_str(before);
+
+            let name = after[1..].find('}').map(|end| &after[1..1 + end]);
+            match name {
+                Some(name) if PLACEHOLDERS.contains(&name) && !before.ends_with('$') => {
+                    let key = format!("WMGR_REPO_{}", name.to_ascii_uppercase());
+                    let value = repo_env_vars.get(&key).ok_or_else(|| {
+                        ForeachCommandError::UndefinedPlaceholder {
+                            repo: repo_dest.to_string(),
+                            placeholder: name.to_string(),
+                        }
+                    })?;
+                    expanded.push_str(&shell_quote(value));
+                    rest = &after[name.len() + 2..];
+                }
+                _ => {
+                    expanded.push('{');
+                    rest = &after[1..];
+                }
+            }
+        }
+        expanded.push_str(rest);
+
+        Ok(expanded)
+    }
+
+    /// コマンドをシェル経由で実行し、結果をCommandResultに変換する
+    ///
+    /// タイムアウトは`CommandExecutor`が実行中のプロセスに対して適用する。
     async fn perform_command_execution(
         &self,
         command: &str,
-        working_dir: &PathBuf,
+        working_dir: &Path,
         env_vars: &HashMap<String, String>,
         repo_dest: &str,
+        timeout_seconds: Option<u64>,
     ) -> Result<CommandResult, ForeachCommandError> {
-        let start_time = std::time::Instant::now();
-
-        if self.config.verbose {
-            println!("Running '{}' in {}", command, working_dir.display());
-            println!("Environment variables: {:?}", env_vars);
+        if command.trim().is_empty() {
+            return Err(ForeachCommandError::InvalidCommand);
         }
 
-        // TODO: 実際のプロセス実行はインフラストラクチャ層で実装
-        // ここでは疑似的な実装
+        info!("Running '{}' in {}", command, working_dir.display());
+        debug!("Environment variables: {:?}", env_vars);
 
-        // コマンド解析（簡易版）
-        let parts: Vec<&str> = command.split_whitespace().collect();
-        if parts.is_empty() {
-            return Err(ForeachCommandError::InvalidCommand);
+        let mut execution_config = ExecutionConfig::new()
+            .with_working_directory(working_dir)
+            .with_environment_variables(env_vars.clone())
+            .with_shell(true);
+        if let Some(timeout) = timeout_seconds {
+            execution_config = execution_config.with_timeout(timeout);
         }
 
-        // タイムアウト処理のシミュレーション
-        if let Some(timeout) = self.config.timeout_seconds {
-            let elapsed = start_time.elapsed().as_secs();
-            if elapsed >= timeout {
-                return Ok(CommandResult::new(repo_dest.to_string())
-                    .with_timeout(start_time.elapsed().as_millis() as u64));
+        let start_time = std::time::Instant::now();
+        let result = CommandResult::new(repo_dest.to_string());
+
+        match CommandExecutor::execute(command, &execution_config).await {
+            Ok(output) if output.success => Ok(result.with_success(
+                output.exit_code,
+                output.stdout,
+                output.stderr,
+                output.execution_time_ms,
+            )),
+            Ok(output) => {
+                let error = match output.stderr.trim() {
+                    "" => format!("Command exited with code {}", output.exit_code),
+                    stderr => stderr.to_string(),
+                };
+                let mut failed =
+                    result.with_failure(Some(output.exit_code), error, output.execution_time_ms);
+                failed.stdout = output.stdout;
+                failed.stderr = output.stderr;
+                Ok(failed)
             }
+            Err(CommandExecutorError::Timeout { .. }) => {
+                Ok(result.with_timeout(start_time.elapsed().as_millis() as u64))
+            }
+            Err(e) => Ok(result.with_failure(
+                None,
+                e.to_string(),
+                start_time.elapsed().as_millis() as u64,
+            )),
         }
-
-        // 疑似的な成功結果を返す
-        let execution_time = start_time.elapsed().as_millis() as u64;
-        let result = CommandResult::new(repo_dest.to_string()).with_success(
-            0,
-            format!("Mock output from '{}' in {}", command, repo_dest),
-            String::new(),
-            execution_time,
-        );
-
-        Ok(result)
     }
 }
 
@@ -829,6 +1306,10 @@ mod tests {
         assert!(env_vars.contains_key("WMGR_WORKSPACE_ROOT"));
         assert!(env_vars.contains_key("WMGR_MANIFEST_URL"));
         assert!(env_vars.contains_key("WMGR_MANIFEST_BRANCH"));
+        assert_eq!(
+            env_vars.get("TSRC_WORKSPACE_PATH"),
+            env_vars.get("WMGR_WORKSPACE_ROOT")
+        );
         assert_eq!(
             env_vars.get("CUSTOM_VAR"),
             Some(&"custom_value".to_string())
@@ -838,4 +1319,628 @@ mod tests {
             Some(&"https://example.com/manifest.git".to_string())
         );
     }
+
+    #[test]
+    fn test_repository_environment_variables() {
+        let temp_dir = TempDir::new().unwrap();
+        let repos = vec![
+            ManifestRepo::new("https://example.com/app.git", "app").with_branch("develop"),
+            ManifestRepo::with_scm("svn://example.com/legacy", "legacy", ScmType::Svn),
+        ];
+        let mut groups = HashMap::new();
+        groups.insert(
+            "web".to_string(),
+            crate::domain::entities::manifest::Group::new(vec!["app".to_string()]),
+        );
+        groups.insert(
+            "all".to_string(),
+            crate::domain::entities::manifest::Group::new(vec![
+                "app".to_string(),
+                "legacy".to_string(),
+            ]),
+        );
+        let manifest = Manifest::new(repos.clone()).with_groups(groups);
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let workspace =
+            Workspace::new(temp_dir.path().to_path_buf(), workspace_config).with_manifest(manifest);
+
+        let app_path = temp_dir.path().join("app");
+        let vars =
+            ForeachCommandUseCase::repository_environment(&repos[0], &workspace, &app_path, 0, 2);
+        let get = |key: &str| vars.get(key).map(String::as_str);
+
+        assert_eq!(get("WMGR_REPO_DEST"), Some("app"));
+        assert_eq!(get("WMGR_REPO_GROUPS"), Some("all,web"));
+        assert_eq!(get("WMGR_REPO_SCM"), Some("git"));
+        assert_eq!(get("WMGR_REPO_INDEX"), Some("1"));
+        assert_eq!(get("WMGR_REPO_COUNT"), Some("2"));
+        assert_eq!(get("WMGR_REPO_BRANCH"), Some("develop"));
+        assert_eq!(
+            get("WMGR_REPO_PATH"),
+            Some(app_path.display().to_string().as_str())
+        );
+
+        // 旧名のTSRC_*も同じ値で設定される
+        assert_eq!(get("TSRC_REPO_NAME"), Some("app"));
+        assert_eq!(get("TSRC_REPO_DEST"), Some("app"));
+        assert_eq!(get("TSRC_REPO_GROUPS"), Some("all,web"));
+        assert_eq!(get("TSRC_REPO_PATH"), get("WMGR_REPO_PATH"));
+
+        let legacy_path = temp_dir.path().join("legacy");
+        let vars = ForeachCommandUseCase::repository_environment(
+            &repos[1],
+            &workspace,
+            &legacy_path,
+            1,
+            2,
+        );
+        assert_eq!(vars.get("WMGR_REPO_GROUPS"), Some(&"all".to_string()));
+        assert_eq!(vars.get("WMGR_REPO_SCM"), Some(&"svn".to_string()));
+        assert_eq!(vars.get("WMGR_REPO_INDEX"), Some(&"2".to_string()));
+        assert!(!vars.contains_key("WMGR_REPO_BRANCH"));
+    }
+
+    #[test]
+    fn test_repo_timeout_precedence() {
+        let mut slow = ManifestRepo::new("https://example.com/slow.git", "slow");
+        slow.foreach_timeout = Some(600);
+        let fast = ManifestRepo::new("https://example.com/fast.git", "fast");
+
+        let config = ForeachCommandConfig::new("make").with_timeout(30);
+        assert_eq!(config.timeout_for(&slow), Some(600));
+        assert_eq!(config.timeout_for(&fast), Some(30));
+
+        let config = config
+            .with_repo_timeout("slow", 900)
+            .with_repo_timeout("fast", 5);
+        assert_eq!(config.timeout_for(&slow), Some(900));
+        assert_eq!(config.timeout_for(&fast), Some(5));
+
+        let config = ForeachCommandConfig::new("make");
+        assert_eq!(config.timeout_for(&slow), Some(600));
+        assert_eq!(config.timeout_for(&fast), None);
+    }
+
+    #[tokio::test]
+    async fn test_repo_timeout_overrides_global_timeout() {
+        let temp_dir = TempDir::new().unwrap();
+        for dest in ["app", "docs", "infra"] {
+            std::fs::create_dir_all(temp_dir.path().join(dest)).unwrap();
+        }
+
+        let mut docs = ManifestRepo::new("https://example.com/docs.git", "docs");
+        docs.foreach_timeout = Some(60);
+        let repos = vec![
+            ManifestRepo::new("https://example.com/app.git", "app"),
+            docs,
+            ManifestRepo::new("https://example.com/infra.git", "infra"),
+        ];
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let workspace = Workspace::new(temp_dir.path().to_path_buf(), workspace_config)
+            .with_status(crate::domain::entities::workspace::WorkspaceStatus::Initialized)
+            .with_manifest(Manifest::new(repos));
+
+        // 全体のタイムアウトより長いコマンドは、上書きのないリポジトリだけがタイムアウトする
+        let config = ForeachCommandConfig::new("sleep 2")
+            .with_parallel(true, Some(0))
+            .with_timeout(1)
+            .with_repo_timeout("infra", 60)
+            .with_continue_on_error(true);
+        let result = ForeachCommandUseCase::new(config)
+            .execute(&workspace)
+            .await
+            .unwrap();
+
+        let status = |dest: &str| {
+            result
+                .results
+                .iter()
+                .find(|r| r.dest == dest)
+                .map(|r| r.status.clone())
+                .unwrap()
+        };
+        assert_eq!(status("app"), CommandStatus::Timeout);
+        assert_eq!(status("docs"), CommandStatus::Success);
+        assert_eq!(status("infra"), CommandStatus::Success);
+    }
+
+    #[tokio::test]
+    async fn test_command_runs_in_repository_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("app")).unwrap();
+        std::fs::write(temp_dir.path().join("app").join("marker.txt"), "").unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("lib")).unwrap();
+
+        let repos = vec![
+            ManifestRepo::new("https://example.com/app.git", "app"),
+            ManifestRepo::new("https://example.com/lib.git", "lib"),
+        ];
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let workspace = Workspace::new(temp_dir.path().to_path_buf(), workspace_config)
+            .with_status(crate::domain::entities::workspace::WorkspaceStatus::Initialized)
+            .with_manifest(Manifest::new(repos));
+
+        let config = ForeachCommandConfig::new("echo \"$WMGR_REPO_DEST\" && test -f marker.txt")
+            .with_continue_on_error(true);
+        let result = ForeachCommandUseCase::new(config)
+            .execute(&workspace)
+            .await
+            .unwrap();
+
+        let app = &result.results[0];
+        assert!(app.is_success());
+        assert_eq!(app.stdout.trim(), "app");
+
+        let lib = &result.results[1];
+        assert_eq!(lib.status, CommandStatus::Failed);
+        assert_eq!(lib.exit_code, Some(1));
+        assert_eq!(lib.stdout.trim(), "lib");
+    }
+
+    #[tokio::test]
+    async fn test_command_runs_in_local_only_repository() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("scratch")).unwrap();
+
+        let repos = vec![ManifestRepo::new("", "scratch")];
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let workspace = Workspace::new(temp_dir.path().to_path_buf(), workspace_config)
+            .with_status(crate::domain::entities::workspace::WorkspaceStatus::Initialized)
+            .with_manifest(Manifest::new(repos));
+
+        let config = ForeachCommandConfig::new("echo \"$WMGR_REPO_DEST:$WMGR_REPO_URL\"");
+        let result = ForeachCommandUseCase::new(config)
+            .execute(&workspace)
+            .await
+            .unwrap();
+
+        let scratch = &result.results[0];
+        assert!(scratch.is_success());
+        assert_eq!(scratch.stdout.trim(), "scratch:");
+    }
+
+    #[tokio::test]
+    async fn test_parallel_results_follow_manifest_order() {
+        let temp_dir = TempDir::new().unwrap();
+        let dests = ["core", "api", "web", "docs", "infra"];
+        for dest in dests {
+            std::fs::create_dir_all(temp_dir.path().join(dest)).unwrap();
+        }
+
+        let repos = dests
+            .iter()
+            .map(|dest| ManifestRepo::new(format!("https://example.com/{}.git", dest), *dest))
+            .collect();
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let workspace = Workspace::new(temp_dir.path().to_path_buf(), workspace_config)
+            .with_status(crate::domain::entities::workspace::WorkspaceStatus::Initialized)
+            .with_manifest(Manifest::new(repos));
+
+        // 先頭のリポジトリほど遅く終わるので、完了順はマニフェストの逆順になる
+        let config = ForeachCommandConfig::new(
+            "sleep 0.$(( (WMGR_REPO_COUNT - WMGR_REPO_INDEX) * 2 )) && echo \"$WMGR_REPO_DEST\"",
+        )
+        .with_parallel(true, Some(0));
+        let use_case = ForeachCommandUseCase::new(config);
+
+        for _ in 0..2 {
+            let result = use_case.execute(&workspace).await.unwrap();
+            let order: Vec<&str> = result.results.iter().map(|r| r.dest.as_str()).collect();
+            assert_eq!(order, dests);
+
+            for r in &result.results {
+                assert!(r.is_success());
+                assert_eq!(r.stdout.trim(), r.dest);
+            }
+            // 各リポジトリの実行時間は並べ替え後もそのリポジトリのもの
+            assert!(result.results[0].execution_time_ms >= 700);
+            assert!(result.results[4].execution_time_ms < 700);
+            assert!(result.total_execution_time_ms >= result.results[0].execution_time_ms);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_parallel_run_reports_progress_in_completion_order() {
+        let temp_dir = TempDir::new().unwrap();
+        let dests = ["slow", "failing", "fast"];
+        for dest in dests {
+            std::fs::create_dir_all(temp_dir.path().join(dest)).unwrap();
+        }
+        let mut repos: Vec<ManifestRepo> = dests
+            .iter()
+            .map(|dest| ManifestRepo::new(format!("https://example.com/{}.git", dest), *dest))
+            .collect();
+        repos.push(ManifestRepo::new(
+            "https://example.com/missing.git",
+            "missing",
+        ));
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let workspace = Workspace::new(temp_dir.path().to_path_buf(), workspace_config)
+            .with_status(crate::domain::entities::workspace::WorkspaceStatus::Initialized)
+            .with_manifest(Manifest::new(repos));
+
+        let config = ForeachCommandConfig::new(
+            "case \"$WMGR_REPO_DEST\" in slow) sleep 0.5 ;; failing) exit 3 ;; esac",
+        )
+        .with_parallel(true, Some(0))
+        .with_continue_on_error(true);
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        ForeachCommandUseCase::new(config)
+            .with_progress(sender)
+            .execute(&workspace)
+            .await
+            .unwrap();
+
+        let mut started = 0;
+        let mut finished = Vec::new();
+        while let Some(event) = receiver.recv().await {
+            match event {
+                ProgressEvent::RepoStarted { total, .. } => {
+                    assert_eq!(total, 4);
+                    started += 1;
+                }
+                ProgressEvent::RepoFinished { dest, outcome } => finished.push((dest, outcome)),
+                ProgressEvent::RepoProgress { .. } => {}
+            }
+        }
+
+        assert_eq!(started, 4);
+        // 遅いリポジトリは最後に終わる
+        assert_eq!(
+            finished.last(),
+            Some(&("slow".to_string(), RepoOutcome::Succeeded))
+        );
+        assert!(finished.contains(&("fast".to_string(), RepoOutcome::Succeeded)));
+        assert!(finished.contains(&("missing".to_string(), RepoOutcome::Skipped)));
+        assert!(finished
+            .iter()
+            .any(|(dest, outcome)| dest == "failing" && outcome.is_failure()));
+    }
+
+    #[tokio::test]
+    async fn test_command_exceeding_timeout_is_reported_as_timeout() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("app")).unwrap();
+
+        let repos = vec![ManifestRepo::new("https://example.com/app.git", "app")];
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let workspace = Workspace::new(temp_dir.path().to_path_buf(), workspace_config)
+            .with_status(crate::domain::entities::workspace::WorkspaceStatus::Initialized)
+            .with_manifest(Manifest::new(repos));
+
+        let config = ForeachCommandConfig::new("sleep 5").with_timeout(1);
+        let started = std::time::Instant::now();
+        let result = ForeachCommandUseCase::new(config)
+            .execute(&workspace)
+            .await
+            .unwrap();
+
+        let app = &result.results[0];
+        assert_eq!(app.status, CommandStatus::Timeout);
+        assert!(!app.is_success());
+        assert!(app.execution_time_ms >= 1000);
+        assert!(started.elapsed() < std::time::Duration::from_secs(5));
+        assert_eq!(result.failure_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_subdir_runs_in_subdirectory_and_skips_repositories_without_it() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("app").join("frontend")).unwrap();
+        std::fs::write(
+            temp_dir
+                .path()
+                .join("app")
+                .join("frontend")
+                .join("package.json"),
+            "{}",
+        )
+        .unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("lib")).unwrap();
+
+        let repos = vec![
+            ManifestRepo::new("https://example.com/app.git", "app"),
+            ManifestRepo::new("https://example.com/lib.git", "lib"),
+        ];
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let workspace = Workspace::new(temp_dir.path().to_path_buf(), workspace_config)
+            .with_status(crate::domain::entities::workspace::WorkspaceStatus::Initialized)
+            .with_manifest(Manifest::new(repos));
+
+        let config =
+            ForeachCommandConfig::new("test -f package.json && pwd").with_subdir("frontend");
+        let result = ForeachCommandUseCase::new(config)
+            .execute(&workspace)
+            .await
+            .unwrap();
+
+        let app = &result.results[0];
+        assert!(app.is_success());
+        let frontend = std::fs::canonicalize(temp_dir.path().join("app").join("frontend")).unwrap();
+        assert_eq!(std::fs::canonicalize(app.stdout.trim()).unwrap(), frontend);
+
+        let lib = &result.results[1];
+        assert_eq!(lib.status, CommandStatus::Skipped);
+        assert_eq!(
+            lib.error_message.as_deref(),
+            Some("Subdirectory 'frontend' does not exist")
+        );
+        assert_eq!(result.success_count, 1);
+        assert_eq!(result.skipped_count, 1);
+    }
+
+    #[test]
+    fn test_subdir_outside_repository_is_rejected() {
+        for subdir in ["../other", "frontend/../../other", "/tmp", ""] {
+            let config = ForeachCommandConfig::new("pwd").with_subdir(subdir);
+            let result = ForeachCommandUseCase::new(config).validate_subdir();
+            assert!(
+                matches!(result, Err(ForeachCommandError::InvalidSubdir(_))),
+                "{}",
+                subdir
+            );
+        }
+
+        let config = ForeachCommandConfig::new("pwd").with_subdir("./packages/web");
+        assert!(ForeachCommandUseCase::new(config).validate_subdir().is_ok());
+    }
+
+    #[test]
+    fn test_git_mode_prepends_git() {
+        let config = ForeachCommandConfig::new("status -s").with_git(true);
+        assert_eq!(config.effective_command(), "git status -s");
+
+        let config = ForeachCommandConfig::new("make test");
+        assert_eq!(config.effective_command(), "make test");
+    }
+
+    #[tokio::test]
+    async fn test_git_mode_skips_non_git_repositories() {
+        let temp_dir = TempDir::new().unwrap();
+        git2::Repository::init(temp_dir.path().join("app")).unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("legacy")).unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("assets")).unwrap();
+
+        let repos = vec![
+            ManifestRepo::new("https://example.com/app.git", "app"),
+            ManifestRepo::with_scm("svn://example.com/legacy", "legacy", ScmType::Svn),
+            ManifestRepo::with_scm("p4://example.com/assets", "assets", ScmType::P4),
+        ];
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let workspace = Workspace::new(temp_dir.path().to_path_buf(), workspace_config)
+            .with_status(crate::domain::entities::workspace::WorkspaceStatus::Initialized)
+            .with_manifest(Manifest::new(repos));
+
+        let config = ForeachCommandConfig::new("rev-parse --absolute-git-dir").with_git(true);
+        let result = ForeachCommandUseCase::new(config)
+            .execute(&workspace)
+            .await
+            .unwrap();
+
+        assert_eq!(result.success_count, 1);
+        assert_eq!(result.skipped_count, 2);
+
+        let app = &result.results[0];
+        assert!(app.is_success());
+        // GIT_DIRがリポジトリの.gitを指している
+        let git_dir = std::fs::canonicalize(temp_dir.path().join("app").join(".git")).unwrap();
+        assert_eq!(std::fs::canonicalize(app.stdout.trim()).unwrap(), git_dir);
+
+        for skipped in &result.results[1..] {
+            assert_eq!(skipped.status, CommandStatus::Skipped);
+            assert!(skipped
+                .error_message
+                .as_deref()
+                .unwrap()
+                .contains("skipped by --git"));
+        }
+        assert!(result.results[1]
+            .error_message
+            .as_deref()
+            .unwrap()
+            .contains("svn"));
+    }
+
+    /// ファイルを1つコミットしたGitリポジトリを作成
+    fn init_committed_repo(path: &Path) {
+        let repo = git2::Repository::init(path).unwrap();
+        std::fs::write(path.join("README.md"), "readme").unwrap();
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new("README.md")).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+        repo.commit(Some("HEAD"), &sig, &sig, "init", &tree, &[])
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_states_filter_runs_only_in_matching_repositories() {
+        let temp_dir = TempDir::new().unwrap();
+        for dest in ["api", "web", "docs"] {
+            init_committed_repo(&temp_dir.path().join(dest));
+        }
+        std::fs::write(temp_dir.path().join("web").join("README.md"), "changed").unwrap();
+
+        let repos = ["api", "web", "docs", "missing"]
+            .iter()
+            .map(|dest| ManifestRepo::new(format!("https://example.com/{}.git", dest), *dest))
+            .collect();
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let workspace = Workspace::new(temp_dir.path().to_path_buf(), workspace_config)
+            .with_status(crate::domain::entities::workspace::WorkspaceStatus::Initialized)
+            .with_manifest(Manifest::new(repos));
+
+        let config =
+            ForeachCommandConfig::new("touch ran").with_states(vec![RepositoryState::Dirty]);
+        let result = ForeachCommandUseCase::new(config)
+            .execute(&workspace)
+            .await
+            .unwrap();
+
+        assert_eq!(result.total_count(), 1);
+        assert_eq!(result.results[0].dest, "web");
+        assert!(temp_dir.path().join("web/ran").exists());
+        assert!(!temp_dir.path().join("api/ran").exists());
+        assert!(!temp_dir.path().join("docs/ran").exists());
+
+        // 複数の状態はいずれかに一致すればよい
+        let config = ForeachCommandConfig::new("true")
+            .with_states(vec![RepositoryState::Dirty, RepositoryState::Missing]);
+        let result = ForeachCommandUseCase::new(config)
+            .execute(&workspace)
+            .await
+            .unwrap();
+        let dests: Vec<&str> = result.results.iter().map(|r| r.dest.as_str()).collect();
+        assert_eq!(dests, vec!["web", "missing"]);
+
+        // 除外する状態のリポジトリ以外で実行する
+        let config = ForeachCommandConfig::new("touch built")
+            .with_excluded_states(vec![RepositoryState::Dirty, RepositoryState::Missing]);
+        let result = ForeachCommandUseCase::new(config)
+            .execute(&workspace)
+            .await
+            .unwrap();
+        let dests: Vec<&str> = result.results.iter().map(|r| r.dest.as_str()).collect();
+        assert_eq!(dests, vec!["api", "docs"]);
+        assert!(!temp_dir.path().join("web/built").exists());
+    }
+
+    #[test]
+    fn test_excluded_states_take_precedence() {
+        let config = ForeachCommandConfig::new("true")
+            .with_states(vec![RepositoryState::Clean, RepositoryState::Dirty])
+            .with_excluded_states(vec![RepositoryState::Dirty]);
+        assert!(config.selects_state(&RepositoryState::Clean));
+        assert!(!config.selects_state(&RepositoryState::Dirty));
+        assert!(!config.selects_state(&RepositoryState::Missing));
+
+        let config =
+            ForeachCommandConfig::new("true").with_excluded_states(vec![RepositoryState::Dirty]);
+        assert!(config.selects_state(&RepositoryState::Missing));
+        assert!(!config.selects_state(&RepositoryState::Dirty));
+    }
+
+    fn placeholder_vars() -> HashMap<String, String> {
+        [
+            ("WMGR_REPO_DEST", "libs/core"),
+            ("WMGR_REPO_URL", "https://example.com/core.git"),
+            ("WMGR_REPO_BRANCH", "develop"),
+            ("WMGR_REPO_PATH", "/ws/libs/core"),
+            ("WMGR_REPO_SCM", "git"),
+            ("WMGR_REPO_GROUPS", "all,libs"),
+            ("WMGR_REPO_INDEX", "2"),
+            ("WMGR_REPO_COUNT", "5"),
+        ]
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+    }
+
+    #[test]
+    fn test_expand_each_placeholder() {
+        let vars = placeholder_vars();
+        let expand =
+            |command: &str| ForeachCommandUseCase::expand_placeholders(command, "libs/core", &vars);
+
+        assert_eq!(
+            expand("git tag release-{dest}").unwrap(),
+            "git tag release-libs/core"
+        );
+        assert_eq!(
+            expand("echo {url}").unwrap(),
+            "echo https://example.com/core.git"
+        );
+        assert_eq!(
+            expand("git checkout {branch}").unwrap(),
+            "git checkout develop"
+        );
+        assert_eq!(expand("ls {path}").unwrap(), "ls /ws/libs/core");
+        assert_eq!(expand("echo {scm}").unwrap(), "echo git");
+        assert_eq!(expand("echo {groups}").unwrap(), "echo all,libs");
+        assert_eq!(expand("echo {index}/{count}").unwrap(), "echo 2/5");
+    }
+
+    #[test]
+    fn test_expand_placeholders_leaves_other_braces_alone() {
+        let vars = placeholder_vars();
+        let expand =
+            |command: &str| ForeachCommandUseCase::expand_placeholders(command, "libs/core", &vars);
+
+        assert_eq!(expand("echo ${dest}").unwrap(), "echo ${dest}");
+        assert_eq!(
+            expand("git log | awk '{print $1}'").unwrap(),
+            "git log | awk '{print $1}'"
+        );
+        assert_eq!(expand("echo {unknown} {").unwrap(), "echo {unknown} {");
+        assert_eq!(expand("echo {{dest}}").unwrap(), "echo {libs/core}");
+    }
+
+    #[test]
+    fn test_expand_placeholders_quotes_unsafe_values() {
+        let mut vars = placeholder_vars();
+        vars.insert("WMGR_REPO_DEST".to_string(), "my app".to_string());
+        vars.insert("WMGR_REPO_URL".to_string(), "x; rm -rf ~".to_string());
+        vars.insert("WMGR_REPO_BRANCH".to_string(), "it's".to_string());
+
+        let expanded = ForeachCommandUseCase::expand_placeholders(
+            "echo {dest} {url} {branch}",
+            "my app",
+            &vars,
+        )
+        .unwrap();
+        if cfg!(target_os = "windows") {
+            assert_eq!(expanded, "echo \"my app\" \"x; rm -rf ~\" \"it's\"");
+        } else {
+            assert_eq!(expanded, "echo 'my app' 'x; rm -rf ~' 'it'\\''s'");
+        }
+    }
+
+    #[test]
+    fn test_expand_placeholder_without_value_fails() {
+        let mut vars = placeholder_vars();
+        vars.remove("WMGR_REPO_BRANCH");
+
+        let result =
+            ForeachCommandUseCase::expand_placeholders("git checkout {branch}", "app", &vars);
+        assert!(matches!(
+            result,
+            Err(ForeachCommandError::UndefinedPlaceholder { repo, placeholder })
+                if repo == "app" && placeholder == "branch"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_placeholders_are_substituted_per_repository() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("app")).unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("lib")).unwrap();
+
+        let repos = vec![
+            ManifestRepo::new("https://example.com/app.git", "app").with_branch("main"),
+            // シェルのメタ文字を含む値もひとつの引数として渡る
+            ManifestRepo::new("https://example.com/lib.git;touch injected", "lib")
+                .with_branch("develop"),
+        ];
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let workspace = Workspace::new(temp_dir.path().to_path_buf(), workspace_config)
+            .with_status(crate::domain::entities::workspace::WorkspaceStatus::Initialized)
+            .with_manifest(Manifest::new(repos));
+
+        let config = ForeachCommandConfig::new("echo {dest} {branch} {url}");
+        let result = ForeachCommandUseCase::new(config)
+            .execute(&workspace)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result.results[0].stdout.trim(),
+            "app main https://example.com/app.git"
+        );
+        assert_eq!(
+            result.results[1].stdout.trim(),
+            "lib develop https://example.com/lib.git;touch injected"
+        );
+        assert!(!temp_dir.path().join("lib").join("injected").exists());
+    }
 }
@@ -0,0 +1,280 @@
+use crate::common::error::WmgrError;
+use crate::domain::entities::manifest::{Group, Manifest, ManifestRepo};
+use crate::infrastructure::git::repository::GitRepository;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// GenerateManifest関連のエラー
+#[derive(Debug, Error)]
+pub enum GenerateManifestError {
+    #[error("Failed to scan directory {path}: {source}")]
+    ScanFailed {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+impl From<GenerateManifestError> for WmgrError {
+    fn from(error: GenerateManifestError) -> Self {
+        match error {
+            GenerateManifestError::ScanFailed { path, source } => {
+                WmgrError::filesystem_error_with_source(
+                    "Failed to scan directory",
+                    Some(path),
+                    source,
+                )
+            }
+        }
+    }
+}
+
+/// マニフェスト生成の設定
+#[derive(Debug, Clone)]
+pub struct GenerateManifestConfig {
+    /// リポジトリを探すディレクトリの深さ（1の場合は直下のみ）
+    pub max_depth: usize,
+}
+
+impl Default for GenerateManifestConfig {
+    fn default() -> Self {
+        Self { max_depth: 3 }
+    }
+}
+
+impl GenerateManifestConfig {
+    /// 探索する深さを設定
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+}
+
+/// ディレクトリツリーで見つかったGitリポジトリ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiscoveredRepository {
+    /// 探索したディレクトリからの相対パス（`/`区切り）
+    pub dest: String,
+
+    /// 上流またはoriginのリモートのURL（リモートがない場合はNone）
+    pub url: Option<String>,
+
+    /// 現在のブランチ（detached HEADの場合はNone）
+    pub branch: Option<String>,
+}
+
+impl DiscoveredRepository {
+    /// destが最上位のディレクトリの下にある場合、そのディレクトリ名
+    pub fn top_level_dir(&self) -> Option<&str> {
+        self.dest.split_once('/').map(|(dir, _)| dir)
+    }
+}
+
+/// チェックアウト済みのリポジトリからマニフェストを生成するユースケース
+pub struct GenerateManifestUseCase {
+    /// 生成設定
+    config: GenerateManifestConfig,
+}
+
+impl GenerateManifestUseCase {
+    /// 新しいGenerateManifestUseCaseインスタンスを作成
+    pub fn new(config: GenerateManifestConfig) -> Self {
+        Self { config }
+    }
+
+    /// ディレクトリ以下のGitリポジトリをdest順に探す
+    ///
+    /// 隠しディレクトリ（`.wmgr`など）とシンボリックリンクは対象外。リポジトリの内側
+    /// （サブモジュールなど）には降りない。
+    pub fn discover(
+        &self,
+        root: &Path,
+    ) -> Result<Vec<DiscoveredRepository>, GenerateManifestError> {
+        let mut repositories = Vec::new();
+        self.scan(
+            root,
+            Path::new(""),
+            self.config.max_depth,
+            &mut repositories,
+        )?;
+        repositories.sort_by(|a, b| a.dest.cmp(&b.dest));
+        Ok(repositories)
+    }
+
+    /// 見つかったリポジトリからマニフェストを組み立てる
+    ///
+    /// リモートがないリポジトリはURLなしのローカル専用リポジトリになる。`group_by_dir`の場合、
+    /// 最上位のディレクトリの下にあるリポジトリをそのディレクトリ名のグループにまとめる。
+    pub fn build_manifest(
+        &self,
+        repositories: &[DiscoveredRepository],
+        group_by_dir: bool,
+    ) -> Manifest {
+        let repos = repositories
+            .iter()
+            .map(|repo| {
+                let mut manifest_repo =
+                    ManifestRepo::new(repo.url.clone().unwrap_or_default(), repo.dest.clone());
+                manifest_repo.branch = repo.branch.clone();
+                manifest_repo
+            })
+            .collect();
+        let manifest = Manifest::new(repos);
+        if !group_by_dir {
+            return manifest;
+        }
+
+        let mut groups: HashMap<String, Group> = HashMap::new();
+        for repo in repositories {
+            if let Some(dir) = repo.top_level_dir() {
+                groups
+                    .entry(dir.to_string())
+                    .or_insert_with(|| Group::new(Vec::new()))
+                    .repos
+                    .push(repo.dest.clone());
+            }
+        }
+        if groups.is_empty() {
+            manifest
+        } else {
+            manifest.with_groups(groups)
+        }
+    }
+
+    fn scan(
+        &self,
+        dir: &Path,
+        relative: &Path,
+        remaining_depth: usize,
+        repositories: &mut Vec<DiscoveredRepository>,
+    ) -> Result<(), GenerateManifestError> {
+        let entries =
+            std::fs::read_dir(dir).map_err(|source| GenerateManifestError::ScanFailed {
+                path: dir.to_path_buf(),
+                source,
+            })?;
+
+        for entry in entries.flatten() {
+            let is_dir = entry.file_type().map(|t| t.is_dir()).unwrap_or(false);
+            let name = entry.file_name();
+            if !is_dir || name.to_string_lossy().starts_with('.') {
+                continue;
+            }
+
+            let path = entry.path();
+            let child_relative = relative.join(&name);
+            if path.join(".git").exists() {
+                repositories.push(Self::describe(&path, &child_relative));
+            } else if remaining_depth > 1 {
+                self.scan(&path, &child_relative, remaining_depth - 1, repositories)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// リポジトリのリモートURLと現在のブランチを読み取る（開けない場合はどちらもNone）
+    fn describe(path: &Path, relative: &Path) -> DiscoveredRepository {
+        let git_repo = GitRepository::open(path).ok();
+        let url = git_repo.as_ref().and_then(|git_repo| {
+            let remote_name = git_repo
+                .upstream_remote()
+                .unwrap_or_else(|| "origin".to_string());
+            let remote = git_repo.git2_repo().find_remote(&remote_name).ok()?;
+            remote.url().map(str::to_string)
+        });
+        let branch = git_repo
+            .as_ref()
+            .and_then(|git_repo| git_repo.head_name().ok())
+            .and_then(|name| name.strip_prefix("refs/heads/").map(str::to_string));
+
+        DiscoveredRepository {
+            dest: relative.to_string_lossy().replace('\\', "/"),
+            url,
+            branch,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn init_repo(path: &Path, origin: Option<&str>) {
+        let repo = git2::Repository::init(path).unwrap();
+        std::fs::write(path.join("README"), "readme").unwrap();
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new("README")).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+        repo.commit(Some("HEAD"), &sig, &sig, "initial", &tree, &[])
+            .unwrap();
+        if let Some(origin) = origin {
+            repo.remote("origin", origin).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_discover_reads_urls_and_branches() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        init_repo(&root.join("app"), Some("https://example.com/app.git"));
+        init_repo(
+            &root.join("libs/core"),
+            Some("https://example.com/core.git"),
+        );
+        init_repo(&root.join("scratch"), None);
+        // リポジトリでないディレクトリ、隠しディレクトリ、リポジトリの内側は対象外
+        std::fs::create_dir_all(root.join("docs")).unwrap();
+        init_repo(&root.join(".wmgr/manifest"), None);
+        init_repo(&root.join("app/vendor/dep"), None);
+
+        let use_case = GenerateManifestUseCase::new(GenerateManifestConfig::default());
+        let repositories = use_case.discover(root).unwrap();
+
+        let dests: Vec<&str> = repositories.iter().map(|r| r.dest.as_str()).collect();
+        assert_eq!(dests, vec!["app", "libs/core", "scratch"]);
+        assert_eq!(
+            repositories[0].url.as_deref(),
+            Some("https://example.com/app.git")
+        );
+        assert_eq!(repositories[2].url, None);
+        let head = git2::Repository::open(root.join("app")).unwrap();
+        let branch = head.head().unwrap().shorthand().unwrap().to_string();
+        assert_eq!(repositories[0].branch, Some(branch));
+    }
+
+    #[test]
+    fn test_build_manifest_groups_by_top_level_dir() {
+        let repositories = vec![
+            DiscoveredRepository {
+                dest: "app".to_string(),
+                url: Some("https://example.com/app.git".to_string()),
+                branch: Some("main".to_string()),
+            },
+            DiscoveredRepository {
+                dest: "libs/core".to_string(),
+                url: Some("https://example.com/core.git".to_string()),
+                branch: None,
+            },
+            DiscoveredRepository {
+                dest: "libs/util".to_string(),
+                url: None,
+                branch: None,
+            },
+        ];
+
+        let use_case = GenerateManifestUseCase::new(GenerateManifestConfig::default());
+        let manifest = use_case.build_manifest(&repositories, false);
+        assert_eq!(manifest.repos.len(), 3);
+        assert_eq!(manifest.repos[0].branch.as_deref(), Some("main"));
+        assert!(manifest.repos[2].is_local_only());
+        assert!(manifest.groups.is_none());
+
+        let groups = use_case.build_manifest(&repositories, true).groups.unwrap();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups["libs"].repos, vec!["libs/core", "libs/util"]);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::common::error::WmgrError;
 use crate::domain::entities::{
     manifest::Manifest,
     workspace::{Workspace, WorkspaceConfig},
@@ -35,6 +36,37 @@ pub enum InitWorkspaceError {
     FilePathError(#[from] crate::domain::value_objects::file_path::FilePathError),
 }
 
+impl From<InitWorkspaceError> for WmgrError {
+    fn from(error: InitWorkspaceError) -> Self {
+        match &error {
+            InitWorkspaceError::WorkspaceAlreadyExists(path) => {
+                WmgrError::workspace_error(error.to_string(), Some(PathBuf::from(path)))
+            }
+            InitWorkspaceError::GitCloneFailed(_) => {
+                WmgrError::repository_error_with_source(error.to_string(), None, error)
+            }
+            InitWorkspaceError::ConfigCreationFailed(_) => {
+                WmgrError::config_error_with_source(error.to_string(), error)
+            }
+            InitWorkspaceError::InvalidManifestUrl(url) => {
+                WmgrError::validation_error("manifest_url", error.to_string(), Some(url.clone()))
+            }
+            InitWorkspaceError::ManifestReadFailed(_) => {
+                WmgrError::manifest_error_with_source(error.to_string(), None, error)
+            }
+            InitWorkspaceError::GitUrlError(_) => {
+                WmgrError::validation_error("url", error.to_string(), None)
+            }
+            InitWorkspaceError::FilePathError(_) => {
+                WmgrError::validation_error("path", error.to_string(), None)
+            }
+            InitWorkspaceError::IoError(_) => {
+                WmgrError::internal_error_with_source(error.to_string(), error)
+            }
+        }
+    }
+}
+
 /// ワークスペース初期化の設定
 #[derive(Debug, Clone)]
 pub struct InitWorkspaceConfig {
@@ -231,7 +263,8 @@ shallow: {}
         let workspace_config = WorkspaceConfig::new(
             self.config.manifest_url.as_str(),
             self.config.branch.as_deref().unwrap_or("main"),
-        );
+        )
+        .with_manifest_repo(PathBuf::from(".wmgr").join("manifest"));
 
         let workspace = Workspace::new(self.config.workspace_path.to_path_buf(), workspace_config)
             .with_manifest(manifest);
@@ -1,5 +1,10 @@
+pub mod checkout_branch;
+pub mod doctor;
 pub mod foreach_command;
+pub mod generate_manifest;
 pub mod init_workspace;
+pub mod prune_repositories;
+pub mod push_repositories;
 pub mod security_audit;
 pub mod status_check;
 pub mod sync_repositories;
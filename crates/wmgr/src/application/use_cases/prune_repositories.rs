@@ -0,0 +1,286 @@
+use crate::common::error::WmgrError;
+use crate::domain::entities::workspace::Workspace;
+use crate::infrastructure::filesystem::managed_repos::{dest_key, ManagedRepos, ManagedReposError};
+use crate::infrastructure::git::repository::GitRepository;
+use std::collections::HashSet;
+use std::path::PathBuf;
+use thiserror::Error;
+
+/// PruneRepositories関連のエラー
+#[derive(Debug, Error)]
+pub enum PruneRepositoriesError {
+    #[error("Manifest not loaded: {0}")]
+    ManifestNotLoaded(String),
+
+    #[error("Managed repository record error: {0}")]
+    RecordFailed(#[from] ManagedReposError),
+}
+
+impl From<PruneRepositoriesError> for WmgrError {
+    fn from(error: PruneRepositoriesError) -> Self {
+        match error {
+            PruneRepositoriesError::ManifestNotLoaded(_) => {
+                WmgrError::manifest_error_with_source(error.to_string(), None, error)
+            }
+            PruneRepositoriesError::RecordFailed(_) => {
+                WmgrError::internal_error_with_source(error.to_string(), error)
+            }
+        }
+    }
+}
+
+/// Pruneの設定
+#[derive(Debug, Clone, Default)]
+pub struct PruneConfig {
+    /// 他にない作業（未コミットの変更・未pushのコミット・stash）があるディレクトリも削除するか
+    pub remove_dirty: bool,
+
+    /// 詳細ログを出力するか
+    pub verbose: bool,
+}
+
+impl PruneConfig {
+    /// 他にない作業があるディレクトリの削除を設定
+    pub fn with_remove_dirty(mut self, remove_dirty: bool) -> Self {
+        self.remove_dirty = remove_dirty;
+        self
+    }
+
+    /// 詳細ログ出力を設定
+    pub fn with_verbose(mut self, verbose: bool) -> Self {
+        self.verbose = verbose;
+        self
+    }
+}
+
+/// マニフェストから外れたリポジトリのディレクトリ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrphanedRepository {
+    /// ワークスペースからの相対パス
+    pub dest: String,
+
+    /// ディレクトリの絶対パス
+    pub path: PathBuf,
+
+    /// 未コミットの変更・未追跡ファイル・未pushのコミット・stashがないか（開けない場合はfalse）
+    pub is_clean: bool,
+}
+
+/// Pruneの結果
+#[derive(Debug, Clone, Default)]
+pub struct PruneResult {
+    /// 削除したディレクトリのdest
+    pub removed: Vec<String>,
+
+    /// 他にない作業があるため残したディレクトリのdest
+    pub kept_dirty: Vec<String>,
+
+    /// 削除に失敗したディレクトリとエラー内容
+    pub errors: Vec<String>,
+}
+
+/// マニフェストにないリポジトリのディレクトリを削除するユースケース
+pub struct PruneRepositoriesUseCase {
+    /// Prune設定
+    config: PruneConfig,
+}
+
+impl PruneRepositoriesUseCase {
+    /// 新しいPruneRepositoriesUseCaseインスタンスを作成
+    pub fn new(config: PruneConfig) -> Self {
+        Self { config }
+    }
+
+    /// 以前wmgrが管理していて、今のマニフェストのdestにないGitリポジトリのディレクトリを探す
+    ///
+    /// 対象は`.wmgr/managed-repos.json`に記録されたdestのみで、ワークスペースを走査しないため、
+    /// マニフェスト外に利用者が置いたリポジトリは対象にならない。シンボリックリンクも対象外。
+    pub fn find_orphans(
+        &self,
+        workspace: &Workspace,
+    ) -> Result<Vec<OrphanedRepository>, PruneRepositoriesError> {
+        let manifest = workspace.manifest.as_ref().ok_or_else(|| {
+            PruneRepositoriesError::ManifestNotLoaded(workspace.root_path.display().to_string())
+        })?;
+
+        // 別のグループのリポジトリも管理対象なので、全てのdestと比較する
+        let dests: HashSet<String> = manifest
+            .repos
+            .iter()
+            .filter_map(|repo| dest_key(&repo.dest))
+            .collect();
+
+        let managed = ManagedRepos::load(&workspace.wmgr_dir())?;
+        let mut orphans = Vec::new();
+        for dest in managed.dests.iter().filter(|dest| !dests.contains(*dest)) {
+            let Ok(path) = workspace.resolve_dest(dest) else {
+                continue;
+            };
+            let is_dir = std::fs::symlink_metadata(&path)
+                .map(|metadata| metadata.is_dir())
+                .unwrap_or(false);
+            if !is_dir || !path.join(".git").exists() {
+                continue;
+            }
+
+            let is_clean = GitRepository::open(&path)
+                .and_then(|repo| {
+                    Ok(repo.is_working_directory_clean()? && !repo.has_unpushed_work()?)
+                })
+                .unwrap_or(false);
+            orphans.push(OrphanedRepository {
+                dest: dest.clone(),
+                path,
+                is_clean,
+            });
+        }
+        Ok(orphans)
+    }
+
+    /// ディレクトリを削除（他にない作業がある場合は`remove_dirty`の時のみ）
+    ///
+    /// 削除したdestは記録から外す。
+    pub fn prune(
+        &self,
+        workspace: &Workspace,
+        orphans: &[OrphanedRepository],
+    ) -> Result<PruneResult, PruneRepositoriesError> {
+        let mut result = PruneResult::default();
+        for orphan in orphans {
+            if !orphan.is_clean && !self.config.remove_dirty {
+                result.kept_dirty.push(orphan.dest.clone());
+                continue;
+            }
+
+            if self.config.verbose {
+                println!("Removing {}", orphan.path.display());
+            }
+            match std::fs::remove_dir_all(&orphan.path) {
+                Ok(()) => result.removed.push(orphan.dest.clone()),
+                Err(e) => result
+                    .errors
+                    .push(format!("Failed to remove {}: {}", orphan.dest, e)),
+            }
+        }
+
+        let mut managed = ManagedRepos::load(&workspace.wmgr_dir())?;
+        for dest in &result.removed {
+            managed.remove(dest);
+        }
+        managed.save()?;
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::manifest::{Manifest, ManifestRepo};
+    use crate::domain::entities::workspace::WorkspaceConfig;
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    /// A repository whose commit is also on `origin/main`
+    fn init_pushed_repo(path: &Path) -> git2::Repository {
+        let repo = git2::Repository::init(path).unwrap();
+        std::fs::write(path.join("README"), "readme").unwrap();
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new("README")).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+        let oid = repo
+            .commit(Some("HEAD"), &sig, &sig, "initial", &tree, &[])
+            .unwrap();
+        repo.reference("refs/remotes/origin/main", oid, true, "pushed")
+            .unwrap();
+        drop(tree);
+        repo
+    }
+
+    fn workspace(root: &Path, dests: &[&str], managed: &[&str]) -> Workspace {
+        let mut record = ManagedRepos::new(&root.join(".wmgr"));
+        for dest in managed {
+            record.insert(dest);
+        }
+        record.save().unwrap();
+
+        let repos = dests
+            .iter()
+            .map(|dest| ManifestRepo::new(format!("https://example.com/{}.git", dest), *dest))
+            .collect();
+        Workspace::new(
+            root.to_path_buf(),
+            WorkspaceConfig::new("https://example.com/manifest.git", "main"),
+        )
+        .with_manifest(Manifest::new(repos))
+    }
+
+    #[test]
+    fn test_find_orphans_targets_previously_managed_repos() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        init_pushed_repo(&root.join("app"));
+        init_pushed_repo(&root.join("libs/core"));
+        init_pushed_repo(&root.join("old"));
+        init_pushed_repo(&root.join("libs/legacy"));
+        // 記録にないリポジトリ（利用者自身のクローン）と、リポジトリでないディレクトリは対象外
+        init_pushed_repo(&root.join("libs/my-experiment"));
+        std::fs::create_dir_all(root.join("docs")).unwrap();
+
+        let use_case = PruneRepositoriesUseCase::new(PruneConfig::default());
+        let orphans = use_case
+            .find_orphans(&workspace(
+                root,
+                &["app", "./libs/core/"],
+                &["app", "libs/core", "old", "libs/legacy", "docs", "gone"],
+            ))
+            .unwrap();
+
+        let dests: Vec<&str> = orphans.iter().map(|o| o.dest.as_str()).collect();
+        assert_eq!(dests, vec!["libs/legacy", "old"]);
+        assert!(orphans.iter().all(|o| o.is_clean));
+    }
+
+    #[test]
+    fn test_prune_keeps_repos_with_local_work() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        init_pushed_repo(&root.join("clean"));
+        init_pushed_repo(&root.join("dirty"));
+        std::fs::write(root.join("dirty/notes.txt"), "wip").unwrap();
+        // ローカルにしかないブランチのコミット
+        let unpushed = init_pushed_repo(&root.join("unpushed"));
+        let head = unpushed.head().unwrap().peel_to_commit().unwrap();
+        let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+        let local = unpushed
+            .commit(None, &sig, &sig, "local", &head.tree().unwrap(), &[&head])
+            .unwrap();
+        unpushed
+            .branch("wip", &unpushed.find_commit(local).unwrap(), false)
+            .unwrap();
+        let workspace = workspace(root, &["app"], &["clean", "dirty", "unpushed"]);
+
+        let use_case = PruneRepositoriesUseCase::new(PruneConfig::default());
+        let orphans = use_case.find_orphans(&workspace).unwrap();
+        let result = use_case.prune(&workspace, &orphans).unwrap();
+        assert_eq!(result.removed, vec!["clean"]);
+        assert_eq!(result.kept_dirty, vec!["dirty", "unpushed"]);
+        assert!(!root.join("clean").exists());
+        assert!(root.join("dirty/notes.txt").exists());
+        assert!(root.join("unpushed").exists());
+
+        // 削除したdestは記録から外れ、残したものは次回も対象になる
+        let managed = ManagedRepos::load(&workspace.wmgr_dir()).unwrap();
+        assert!(!managed.contains("clean"));
+        assert!(managed.contains("dirty"));
+
+        let use_case =
+            PruneRepositoriesUseCase::new(PruneConfig::default().with_remove_dirty(true));
+        let orphans = use_case.find_orphans(&workspace).unwrap();
+        let result = use_case.prune(&workspace, &orphans).unwrap();
+        assert_eq!(result.removed, vec!["dirty", "unpushed"]);
+        assert!(!root.join("dirty").exists());
+        assert!(!root.join("unpushed").exists());
+    }
+}
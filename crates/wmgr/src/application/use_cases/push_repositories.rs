@@ -0,0 +1,539 @@
+use crate::application::services::credential_service::CredentialService;
+use crate::common::error::WmgrError;
+use crate::common::executor::resolve_parallelism;
+use crate::domain::entities::{
+    manifest::ManifestRepo,
+    workspace::{DestPathError, Workspace},
+};
+use crate::domain::value_objects::scm_type::ScmType;
+use crate::infrastructure::git::repository::{
+    GitRepository, GitRepositoryError, PushConfig, PushTarget,
+};
+use futures::future::join_all;
+use std::path::PathBuf;
+use std::sync::Arc;
+use thiserror::Error;
+use tokio::sync::Semaphore;
+
+/// PushRepositories関連のエラー
+#[derive(Debug, Error)]
+pub enum PushRepositoriesError {
+    #[error("Workspace not initialized: {0}")]
+    WorkspaceNotInitialized(String),
+
+    #[error("Manifest not loaded: {0}")]
+    ManifestNotLoaded(String),
+
+    #[error("Parallel execution failed: {0}")]
+    ParallelExecutionFailed(String),
+
+    #[error("Invalid repository destination: {0}")]
+    InvalidDest(#[from] DestPathError),
+}
+
+impl From<PushRepositoriesError> for WmgrError {
+    fn from(error: PushRepositoriesError) -> Self {
+        match &error {
+            PushRepositoriesError::WorkspaceNotInitialized(path) => {
+                WmgrError::workspace_not_initialized(path)
+            }
+            PushRepositoriesError::ManifestNotLoaded(_) => {
+                WmgrError::manifest_error_with_source(error.to_string(), None, error)
+            }
+            PushRepositoriesError::ParallelExecutionFailed(_) => {
+                WmgrError::internal_error_with_source(error.to_string(), error)
+            }
+            PushRepositoriesError::InvalidDest(_) => {
+                WmgrError::validation_error("dest", error.to_string(), None)
+            }
+        }
+    }
+}
+
+/// リポジトリpushの設定
+#[derive(Debug, Clone, Default)]
+pub struct PushRepositoriesConfig {
+    /// 特定のグループのみをpushするか（Noneの場合は全て）
+    pub groups: Option<Vec<String>>,
+
+    /// 実際にはpushせず、pushされる内容のみを報告するか
+    pub dry_run: bool,
+
+    /// 強制的にpushするか（リモートの履歴を上書き）
+    pub force: bool,
+
+    /// 並列実行の最大数（Noneの場合はCPU数）
+    pub parallel_jobs: Option<usize>,
+
+    /// 詳細ログを出力するか
+    pub verbose: bool,
+
+    /// クレデンシャルプロファイル名（CLIから）
+    pub credential_profile: Option<String>,
+
+    /// クレデンシャルファイルパス（CLIから）
+    pub credential_file: Option<PathBuf>,
+}
+
+impl PushRepositoriesConfig {
+    /// 対象グループを設定
+    pub fn with_groups(mut self, groups: Vec<String>) -> Self {
+        self.groups = Some(groups);
+        self
+    }
+
+    /// dry-runモードを設定
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// 強制pushを設定
+    pub fn with_force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
+    /// 並列実行数を設定
+    pub fn with_parallel_jobs(mut self, parallel_jobs: usize) -> Self {
+        self.parallel_jobs = Some(parallel_jobs);
+        self
+    }
+
+    /// 詳細ログ出力を設定
+    pub fn with_verbose(mut self, verbose: bool) -> Self {
+        self.verbose = verbose;
+        self
+    }
+
+    /// クレデンシャルの指定を設定
+    pub fn with_credentials(
+        mut self,
+        credential_profile: Option<String>,
+        credential_file: Option<PathBuf>,
+    ) -> Self {
+        self.credential_profile = credential_profile;
+        self.credential_file = credential_file;
+        self
+    }
+}
+
+/// リポジトリごとのpush状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PushStatus {
+    /// pushに成功
+    Pushed,
+    /// pushするコミットがない
+    UpToDate,
+    /// dry-runでpush対象と判定
+    WouldPush,
+    /// リモートに拒否された（non-fast-forward）
+    Rejected,
+    /// 対象外（Git以外のSCM、未クローンなど）
+    Skipped,
+    /// エラーが発生
+    Failed,
+}
+
+/// リポジトリごとのpush結果
+#[derive(Debug, Clone)]
+pub struct RepoPushResult {
+    /// リポジトリのdest
+    pub dest: String,
+
+    /// push状態
+    pub status: PushStatus,
+
+    /// push先のリモート名
+    pub remote: Option<String>,
+
+    /// pushしたブランチ名
+    pub branch: Option<String>,
+
+    /// pushされるコミット数（Noneの場合はリモートにブランチが存在しない）
+    pub commits: Option<usize>,
+
+    /// 補足メッセージ（エラー内容やスキップ理由）
+    pub message: Option<String>,
+}
+
+impl RepoPushResult {
+    /// 新しい結果を作成
+    pub fn new(dest: impl Into<String>, status: PushStatus) -> Self {
+        Self {
+            dest: dest.into(),
+            status,
+            remote: None,
+            branch: None,
+            commits: None,
+            message: None,
+        }
+    }
+
+    /// push先を設定
+    pub fn with_target(mut self, target: &PushTarget) -> Self {
+        self.remote = Some(target.remote_name.clone());
+        self.branch = Some(target.local_branch.clone());
+        self
+    }
+
+    /// コミット数を設定
+    pub fn with_commits(mut self, commits: Option<usize>) -> Self {
+        self.commits = commits;
+        self
+    }
+
+    /// メッセージを設定
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+}
+
+/// push操作の結果
+#[derive(Debug, Clone, Default)]
+pub struct PushResult {
+    /// 各リポジトリの結果
+    pub repositories: Vec<RepoPushResult>,
+
+    /// pushされたリポジトリの数
+    pub pushed_count: usize,
+
+    /// 最新だったリポジトリの数
+    pub up_to_date_count: usize,
+
+    /// dry-runでpush対象となったリポジトリの数
+    pub would_push_count: usize,
+
+    /// pushを拒否されたリポジトリの数
+    pub rejected_count: usize,
+
+    /// スキップされたリポジトリの数
+    pub skipped_count: usize,
+
+    /// 発生したエラーのリスト
+    pub errors: Vec<String>,
+}
+
+impl PushResult {
+    /// 新しいPushResultを作成
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// リポジトリの結果を追加して集計を更新
+    pub fn add_repository(&mut self, result: RepoPushResult) {
+        match result.status {
+            PushStatus::Pushed => self.pushed_count += 1,
+            PushStatus::UpToDate => self.up_to_date_count += 1,
+            PushStatus::WouldPush => self.would_push_count += 1,
+            PushStatus::Rejected => {
+                self.rejected_count += 1;
+                self.errors.push(format!(
+                    "Push rejected for {}: {}",
+                    result.dest,
+                    result.message.as_deref().unwrap_or("non-fast-forward")
+                ));
+            }
+            PushStatus::Skipped => self.skipped_count += 1,
+            PushStatus::Failed => {
+                self.errors.push(format!(
+                    "Failed to push {}: {}",
+                    result.dest,
+                    result.message.as_deref().unwrap_or("unknown error")
+                ));
+            }
+        }
+        self.repositories.push(result);
+    }
+
+    /// 全てのpushが成功したか
+    pub fn is_success(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// リポジトリpushのユースケース
+pub struct PushRepositoriesUseCase {
+    /// push設定
+    config: PushRepositoriesConfig,
+}
+
+impl PushRepositoriesUseCase {
+    /// 新しいPushRepositoriesUseCaseインスタンスを作成
+    pub fn new(config: PushRepositoriesConfig) -> Self {
+        Self { config }
+    }
+
+    /// リポジトリのpushを実行
+    pub async fn execute(
+        &self,
+        workspace: &Workspace,
+    ) -> Result<PushResult, PushRepositoriesError> {
+        // 1. ワークスペースの初期化チェック
+        if !workspace.is_initialized() {
+            return Err(PushRepositoriesError::WorkspaceNotInitialized(
+                workspace.root_path.display().to_string(),
+            ));
+        }
+
+        // 2. 対象リポジトリの決定
+        let target_repos = self.determine_target_repositories(workspace)?;
+
+        // 3. 並列にpushを実行
+        let max_parallel = resolve_parallelism(self.config.parallel_jobs, target_repos.len());
+        let semaphore = Arc::new(Semaphore::new(max_parallel));
+
+        let repo_paths = target_repos
+            .iter()
+            .map(|repo| workspace.resolve_dest(&repo.dest))
+            .collect::<Result<Vec<_>, _>>()?;
+        let tasks: Vec<_> = target_repos
+            .iter()
+            .zip(repo_paths)
+            .map(|(repo, repo_path)| {
+                let repo = repo.clone();
+                let semaphore = semaphore.clone();
+                let config = self.config.clone();
+
+                tokio::spawn(async move {
+                    let _permit = semaphore.acquire().await.map_err(|e| {
+                        PushRepositoriesError::ParallelExecutionFailed(format!(
+                            "Failed to acquire semaphore: {}",
+                            e
+                        ))
+                    })?;
+
+                    Ok::<_, PushRepositoriesError>(
+                        Self::push_single_repository(&config, &repo, repo_path).await,
+                    )
+                })
+            })
+            .collect();
+
+        let results = join_all(tasks).await;
+
+        // 4. 結果をまとめる
+        let mut result = PushResult::new();
+        for (repo, join_result) in target_repos.iter().zip(results) {
+            let repo_result = match join_result {
+                Ok(Ok(repo_result)) => repo_result,
+                Ok(Err(e)) => {
+                    RepoPushResult::new(&repo.dest, PushStatus::Failed).with_message(e.to_string())
+                }
+                Err(join_err) => RepoPushResult::new(&repo.dest, PushStatus::Failed)
+                    .with_message(format!("Task join error: {}", join_err)),
+            };
+            result.add_repository(repo_result);
+        }
+
+        Ok(result)
+    }
+
+    /// push対象リポジトリの決定
+    fn determine_target_repositories(
+        &self,
+        workspace: &Workspace,
+    ) -> Result<Vec<ManifestRepo>, PushRepositoriesError> {
+        let manifest = workspace.manifest.as_ref().ok_or_else(|| {
+            PushRepositoriesError::ManifestNotLoaded(workspace.root_path.display().to_string())
+        })?;
+
+        let target_repos = if let Some(groups) = &self.config.groups {
+            groups
+                .iter()
+                .flat_map(|group_name| manifest.get_repos_in_group(group_name))
+                .cloned()
+                .collect()
+        } else {
+            workspace.configured_repos().into_iter().cloned().collect()
+        };
+
+        Ok(target_repos)
+    }
+
+    /// 単一リポジトリのpush
+    async fn push_single_repository(
+        config: &PushRepositoriesConfig,
+        repo: &ManifestRepo,
+        repo_path: PathBuf,
+    ) -> RepoPushResult {
+        if repo.scm != ScmType::Git {
+            return RepoPushResult::new(&repo.dest, PushStatus::Skipped)
+                .with_message(format!("{} repositories are not pushed", repo.scm));
+        }
+
+        if !repo_path.exists() {
+            return RepoPushResult::new(&repo.dest, PushStatus::Skipped)
+                .with_message("Repository not cloned");
+        }
+
+        // クレデンシャルの解決
+        let credential_service = CredentialService::new(
+            config.credential_profile.clone(),
+            config.credential_file.clone(),
+        );
+        let creds = credential_service
+            .get_credentials_for_repo(repo, None)
+            .await;
+        let push_config = PushConfig {
+            force: config.force,
+            username: creds.profile.username,
+            password: creds.profile.password.or(creds.profile.token),
+        };
+
+        if config.verbose {
+            println!("Pushing {}", repo.dest);
+        }
+
+        // libgit2はブロッキングAPIのため専用スレッドで実行
+        let dest = repo.dest.clone();
+        let dry_run = config.dry_run;
+        tokio::task::spawn_blocking(move || {
+            Self::perform_git_push(&dest, &repo_path, &push_config, dry_run)
+        })
+        .await
+        .unwrap_or_else(|e| {
+            RepoPushResult::new(&repo.dest, PushStatus::Failed)
+                .with_message(format!("Task join error: {}", e))
+        })
+    }
+
+    /// Gitリポジトリのpush実行
+    fn perform_git_push(
+        dest: &str,
+        repo_path: &std::path::Path,
+        push_config: &PushConfig,
+        dry_run: bool,
+    ) -> RepoPushResult {
+        let failed = |e: GitRepositoryError| {
+            RepoPushResult::new(dest, PushStatus::Failed).with_message(e.to_string())
+        };
+
+        let git_repo = match GitRepository::open(repo_path) {
+            Ok(git_repo) => git_repo,
+            Err(e) => return failed(e),
+        };
+
+        let target = match git_repo.push_target() {
+            Ok(target) => target,
+            Err(GitRepositoryError::BranchNotFound(reason)) => {
+                return RepoPushResult::new(dest, PushStatus::Skipped).with_message(reason)
+            }
+            Err(e) => return failed(e),
+        };
+
+        let commits = match git_repo.count_unpushed_commits(&target) {
+            Ok(commits) => commits,
+            Err(e) => return failed(e).with_target(&target),
+        };
+
+        if commits == Some(0) {
+            return RepoPushResult::new(dest, PushStatus::UpToDate)
+                .with_target(&target)
+                .with_commits(commits);
+        }
+
+        if dry_run {
+            return RepoPushResult::new(dest, PushStatus::WouldPush)
+                .with_target(&target)
+                .with_commits(commits);
+        }
+
+        match git_repo.push(&target, push_config) {
+            Ok(()) => RepoPushResult::new(dest, PushStatus::Pushed)
+                .with_target(&target)
+                .with_commits(commits),
+            Err(GitRepositoryError::PushRejected(reason)) => {
+                RepoPushResult::new(dest, PushStatus::Rejected)
+                    .with_target(&target)
+                    .with_commits(commits)
+                    .with_message(reason)
+            }
+            Err(e) => failed(e).with_target(&target).with_commits(commits),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::manifest::Manifest;
+    use crate::domain::entities::workspace::{WorkspaceConfig, WorkspaceStatus};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_push_config_default() {
+        let config = PushRepositoriesConfig::default();
+        assert!(config.groups.is_none());
+        assert!(!config.dry_run);
+        assert!(!config.force);
+        assert!(config.parallel_jobs.is_none());
+        assert!(!config.verbose);
+    }
+
+    #[test]
+    fn test_push_config_with_options() {
+        let config = PushRepositoriesConfig::default()
+            .with_groups(vec!["group1".to_string()])
+            .with_dry_run(true)
+            .with_force(true)
+            .with_parallel_jobs(2);
+
+        assert_eq!(config.groups, Some(vec!["group1".to_string()]));
+        assert!(config.dry_run);
+        assert!(config.force);
+        assert_eq!(config.parallel_jobs, Some(2));
+    }
+
+    #[test]
+    fn test_push_result_counts() {
+        let mut result = PushResult::new();
+        result.add_repository(RepoPushResult::new("a", PushStatus::Pushed));
+        result.add_repository(RepoPushResult::new("b", PushStatus::UpToDate));
+        result.add_repository(RepoPushResult::new("c", PushStatus::Skipped));
+        assert!(result.is_success());
+
+        result.add_repository(
+            RepoPushResult::new("d", PushStatus::Rejected).with_message("non-fast-forward"),
+        );
+        assert_eq!(result.pushed_count, 1);
+        assert_eq!(result.up_to_date_count, 1);
+        assert_eq!(result.skipped_count, 1);
+        assert_eq!(result.rejected_count, 1);
+        assert!(!result.is_success());
+        assert_eq!(result.repositories.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_push_requires_initialized_workspace() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let workspace = Workspace::new(temp_dir.path().to_path_buf(), workspace_config);
+
+        let use_case = PushRepositoriesUseCase::new(PushRepositoriesConfig::default());
+        let result = use_case.execute(&workspace).await;
+        assert!(matches!(
+            result,
+            Err(PushRepositoriesError::WorkspaceNotInitialized(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_push_skips_missing_and_non_git_repositories() {
+        let temp_dir = TempDir::new().unwrap();
+        let manifest = Manifest::new(vec![
+            ManifestRepo::new("https://github.com/example/missing.git", "missing"),
+            ManifestRepo::with_scm("https://example.com/file.zip", "archive", ScmType::Http),
+        ]);
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let mut workspace =
+            Workspace::new(temp_dir.path().to_path_buf(), workspace_config).with_manifest(manifest);
+        workspace.status = WorkspaceStatus::Initialized;
+
+        let use_case = PushRepositoriesUseCase::new(PushRepositoriesConfig::default());
+        let result = use_case.execute(&workspace).await.unwrap();
+        assert_eq!(result.skipped_count, 2);
+        assert!(result.is_success());
+    }
+}
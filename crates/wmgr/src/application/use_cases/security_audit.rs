@@ -1,6 +1,16 @@
-use crate::application::services::security_service::{AuditResult, SecurityError, SecurityService};
+use crate::application::services::security_service::{
+    AuditEcosystem, AuditResult, AuditSummary, SecurityError, SecurityService,
+    VulnerabilitySeverity,
+};
+use crate::common::error::WmgrError;
+use crate::common::executor::resolve_parallelism;
 use crate::domain::entities::manifest::ManifestRepo;
-use crate::domain::entities::workspace::Workspace;
+use crate::domain::entities::workspace::{DestPathError, Workspace};
+use crate::infrastructure::process::command_executor::{
+    CommandExecutor, ExecutionConfig, ExecutionTask, ParallelConfig,
+};
+use serde::Serialize;
+use std::collections::HashMap;
 use std::path::PathBuf;
 use thiserror::Error;
 
@@ -19,8 +29,40 @@ pub enum SecurityAuditError {
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
 
-    #[error("No Rust projects found in workspace")]
-    NoRustProjectsFound,
+    #[error("No projects with a supported ecosystem found in workspace")]
+    NoAuditableProjectsFound,
+
+    #[error("Invalid audit_commands: {0}")]
+    InvalidAuditCommands(String),
+
+    #[error("Invalid repository destination: {0}")]
+    InvalidDest(#[from] DestPathError),
+}
+
+impl From<SecurityAuditError> for WmgrError {
+    fn from(error: SecurityAuditError) -> Self {
+        match &error {
+            SecurityAuditError::WorkspaceNotInitialized(path) => {
+                WmgrError::workspace_not_initialized(path)
+            }
+            SecurityAuditError::RepositoryNotFound(repo) => {
+                let repo = repo.clone();
+                WmgrError::repository_error_with_source(error.to_string(), Some(repo), error)
+            }
+            SecurityAuditError::NoAuditableProjectsFound => {
+                WmgrError::UnsupportedOperation(error.to_string())
+            }
+            SecurityAuditError::InvalidAuditCommands(_) => {
+                WmgrError::validation_error("audit_commands", error.to_string(), None)
+            }
+            SecurityAuditError::InvalidDest(_) => {
+                WmgrError::validation_error("dest", error.to_string(), None)
+            }
+            SecurityAuditError::SecurityServiceError(_) | SecurityAuditError::IoError(_) => {
+                WmgrError::internal_error_with_source(error.to_string(), error)
+            }
+        }
+    }
 }
 
 /// セキュリティ監査の設定
@@ -40,6 +82,9 @@ pub struct SecurityAuditConfig {
 
     /// 詳細ログを出力するか
     pub verbose: bool,
+
+    /// エコシステムごとの監査コマンドの上書き（マニフェストの`audit_commands`より優先）
+    pub audit_commands: HashMap<AuditEcosystem, String>,
 }
 
 impl Default for SecurityAuditConfig {
@@ -50,6 +95,7 @@ impl Default for SecurityAuditConfig {
             max_parallel: None,
             fail_on_vulnerabilities: true,
             verbose: false,
+            audit_commands: HashMap::new(),
         }
     }
 }
@@ -84,6 +130,16 @@ impl SecurityAuditConfig {
         self.verbose = verbose;
         self
     }
+
+    /// エコシステムの監査コマンドを設定
+    pub fn with_audit_command(
+        mut self,
+        ecosystem: AuditEcosystem,
+        command: impl Into<String>,
+    ) -> Self {
+        self.audit_commands.insert(ecosystem, command.into());
+        self
+    }
 }
 
 /// 単一リポジトリの監査結果
@@ -98,11 +154,27 @@ pub struct RepoAuditResult {
     /// エラーメッセージ（監査失敗時）
     pub error: Option<String>,
 
-    /// RustプロジェクトかどうかThe
-    pub is_rust_project: bool,
+    /// 監査したエコシステム（監査対象外の場合はNone）
+    pub ecosystem: Option<AuditEcosystem>,
+
+    /// 依存関係を記述したファイルのリポジトリ内のパス（`Cargo.lock`など）
+    pub manifest_file: Option<String>,
 }
 
 impl RepoAuditResult {
+    /// 監査対象のプロジェクトか
+    pub fn is_auditable(&self) -> bool {
+        self.ecosystem.is_some()
+    }
+
+    /// 見つかった脆弱性の数
+    pub fn vulnerability_count(&self) -> usize {
+        self.audit_result
+            .as_ref()
+            .map(|r| r.warning_count.total())
+            .unwrap_or(0)
+    }
+
     /// 監査に成功したかチェック
     pub fn is_success(&self) -> bool {
         self.audit_result.is_some() && self.error.is_none()
@@ -134,7 +206,7 @@ pub struct WorkspaceAuditResult {
     /// 監査されたリポジトリ数
     pub audited_count: usize,
 
-    /// スキップされたリポジトリ数（非Rustプロジェクト）
+    /// スキップされたリポジトリ数（エコシステムを判定できないプロジェクト）
     pub skipped_count: usize,
 
     /// エラーが発生したリポジトリ数
@@ -145,6 +217,9 @@ pub struct WorkspaceAuditResult {
 
     /// 並列実行されたか
     pub was_parallel: bool,
+
+    /// 全体の終了コード（監査の失敗、または`fail_on_vulnerabilities`時のCritical/High脆弱性で1）
+    pub exit_code: i32,
 }
 
 impl WorkspaceAuditResult {
@@ -156,12 +231,13 @@ impl WorkspaceAuditResult {
             error_count: 0,
             vulnerable_count: 0,
             was_parallel,
+            exit_code: 0,
         }
     }
 
     /// 監査結果を追加
     pub fn add_result(&mut self, result: RepoAuditResult) {
-        if result.is_rust_project {
+        if result.is_auditable() {
             if result.is_success() {
                 self.audited_count += 1;
                 if result.has_vulnerabilities() {
@@ -203,10 +279,26 @@ impl WorkspaceAuditResult {
     pub fn failed_results(&self) -> Vec<&RepoAuditResult> {
         self.repo_results
             .iter()
-            .filter(|r| !r.is_success() && r.is_rust_project)
+            .filter(|r| !r.is_success() && r.is_auditable())
             .collect()
     }
 
+    /// 全リポジトリの脆弱性数を重要度別に合計
+    pub fn total_vulnerabilities(&self) -> AuditSummary {
+        let mut total = AuditSummary::new();
+        for audit_result in self
+            .repo_results
+            .iter()
+            .filter_map(|r| r.audit_result.as_ref())
+        {
+            total.critical += audit_result.warning_count.critical;
+            total.high += audit_result.warning_count.high;
+            total.medium += audit_result.warning_count.medium;
+            total.low += audit_result.warning_count.low;
+        }
+        total
+    }
+
     /// 脆弱性のある結果のみを取得
     pub fn vulnerable_results(&self) -> Vec<&RepoAuditResult> {
         self.repo_results
@@ -216,6 +308,182 @@ impl WorkspaceAuditResult {
     }
 }
 
+/// 監査結果のレポート（JSON出力用）
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditReport {
+    /// 全体の集計
+    pub summary: AuditReportSummary,
+
+    /// リポジトリごとの結果
+    pub repositories: Vec<RepoAuditReport>,
+
+    /// 見つかった脆弱性
+    pub findings: Vec<AuditFinding>,
+
+    /// 全体の終了コード
+    pub exit_code: i32,
+}
+
+/// レポートの全体の集計
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditReportSummary {
+    /// 合計リポジトリ数
+    pub total: usize,
+
+    /// 監査されたリポジトリ数
+    pub audited: usize,
+
+    /// スキップされたリポジトリ数
+    pub skipped: usize,
+
+    /// エラーが発生したリポジトリ数
+    pub errors: usize,
+
+    /// 脆弱性が見つかったリポジトリ数
+    pub vulnerable: usize,
+
+    /// 重要度別の脆弱性数の合計
+    pub vulnerabilities: AuditSummary,
+}
+
+/// リポジトリの監査状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RepoAuditStatus {
+    /// 監査した
+    Audited,
+    /// エコシステムを判定できず監査しなかった
+    Skipped,
+    /// 監査に失敗した
+    Error,
+}
+
+/// レポートのリポジトリごとの結果
+#[derive(Debug, Clone, Serialize)]
+pub struct RepoAuditReport {
+    /// リポジトリの相対パス
+    pub dest: String,
+
+    /// 監査したエコシステム
+    pub ecosystem: Option<AuditEcosystem>,
+
+    /// 監査状態
+    pub status: RepoAuditStatus,
+
+    /// エラーメッセージ（監査失敗時）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+
+    /// 重要度別の脆弱性数
+    pub vulnerabilities: AuditSummary,
+}
+
+/// 見つかった脆弱性1件
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditFinding {
+    /// リポジトリの相対パス
+    pub repo: String,
+
+    /// エコシステム
+    pub ecosystem: AuditEcosystem,
+
+    /// 依存関係を記述したファイルのワークスペース内のパス（`api/Cargo.lock`など）
+    pub manifest_path: Option<String>,
+
+    /// パッケージ名
+    pub package: String,
+
+    /// 影響を受けるバージョン
+    pub version: String,
+
+    /// 勧告ID（`RUSTSEC-2024-0001`など）
+    pub advisory_id: String,
+
+    /// 重要度
+    pub severity: VulnerabilitySeverity,
+
+    /// 説明
+    pub description: String,
+
+    /// 勧告のURL
+    pub url: Option<String>,
+
+    /// 修正版
+    pub patched_versions: Vec<String>,
+}
+
+impl WorkspaceAuditResult {
+    /// 見つかった脆弱性の一覧（リポジトリ順）
+    pub fn findings(&self) -> Vec<AuditFinding> {
+        let mut findings = Vec::new();
+        for repo in &self.repo_results {
+            let (Some(audit_result), Some(ecosystem)) = (&repo.audit_result, repo.ecosystem) else {
+                continue;
+            };
+            for vuln in &audit_result.vulnerabilities {
+                findings.push(AuditFinding {
+                    repo: repo.dest.clone(),
+                    ecosystem,
+                    manifest_path: repo
+                        .manifest_file
+                        .as_ref()
+                        .map(|file| format!("{}/{}", repo.dest, file)),
+                    package: vuln.package.clone(),
+                    version: vuln.version.clone(),
+                    advisory_id: vuln.id.clone(),
+                    severity: vuln.severity.clone(),
+                    description: vuln.description.clone(),
+                    url: vuln.url.clone(),
+                    patched_versions: vuln.patched_versions.clone(),
+                });
+            }
+        }
+        findings
+    }
+
+    /// JSON出力用のレポートを作成
+    pub fn report(&self) -> AuditReport {
+        let repositories = self
+            .repo_results
+            .iter()
+            .map(|repo| RepoAuditReport {
+                dest: repo.dest.clone(),
+                ecosystem: repo.ecosystem,
+                status: if !repo.is_auditable() {
+                    RepoAuditStatus::Skipped
+                } else if repo.is_success() {
+                    RepoAuditStatus::Audited
+                } else {
+                    RepoAuditStatus::Error
+                },
+                error: repo.error.clone(),
+                vulnerabilities: repo
+                    .audit_result
+                    .as_ref()
+                    .map(|r| r.warning_count.clone())
+                    .unwrap_or_else(AuditSummary::new),
+            })
+            .collect();
+
+        AuditReport {
+            summary: AuditReportSummary {
+                total: self.total_count(),
+                audited: self.audited_count,
+                skipped: self.skipped_count,
+                errors: self.error_count,
+                vulnerable: self.vulnerable_count,
+                vulnerabilities: self.total_vulnerabilities(),
+            },
+            repositories,
+            findings: self.findings(),
+            exit_code: self.exit_code,
+        }
+    }
+}
+
+/// 監査対象のリポジトリ、判定したエコシステム、リポジトリのパス
+type AuditableRepo<'a> = (&'a ManifestRepo, AuditEcosystem, PathBuf);
+
 /// セキュリティ監査のユースケース
 pub struct SecurityAuditUseCase {
     /// 設定
@@ -250,29 +518,35 @@ impl SecurityAuditUseCase {
 
         // 2. 監査対象リポジトリの決定
         let target_repos = self.determine_target_repositories(workspace)?;
+        let audit_commands = self.resolve_audit_commands(workspace)?;
 
-        // 3. Rustプロジェクトのフィルタリング
-        let rust_repos = self
-            .filter_rust_repositories(workspace, &target_repos)
-            .await?;
-
-        if rust_repos.is_empty() {
-            return Err(SecurityAuditError::NoRustProjectsFound);
+        // 3. エコシステムを判定できたリポジトリだけを監査する
+        let (auditable, skipped) = self.partition_auditable(workspace, &target_repos)?;
+        if auditable.is_empty() {
+            return Err(SecurityAuditError::NoAuditableProjectsFound);
         }
 
-        // 4. 監査実行（並列または順次）
-        let result = if self.config.parallel {
-            self.execute_parallel(workspace, &rust_repos).await?
-        } else {
-            self.execute_sequential(workspace, &rust_repos).await?
-        };
+        // 4. 監査実行（並列実行しない場合は同時実行数1）
+        let mut result = self.run_audits(&auditable, &audit_commands).await;
+        for repo in skipped {
+            result.add_result(RepoAuditResult {
+                dest: repo.dest.clone(),
+                audit_result: None,
+                error: None,
+                ecosystem: None,
+                manifest_file: None,
+            });
+        }
 
         // 5. 結果の検証
-        if self.config.fail_on_vulnerabilities && result.has_critical_or_high_vulnerabilities() {
-            // Critical/High脆弱性が見つかった場合、詳細を出力してからエラーを返す
-            if self.config.verbose {
-                self.print_vulnerability_summary(&result);
-            }
+        let fails_on_vulnerabilities =
+            self.config.fail_on_vulnerabilities && result.has_critical_or_high_vulnerabilities();
+        if fails_on_vulnerabilities && self.config.verbose {
+            // Critical/High脆弱性が見つかった場合、詳細を出力する
+            self.print_vulnerability_summary(&result);
+        }
+        if fails_on_vulnerabilities || !result.is_success() {
+            result.exit_code = 1;
         }
 
         Ok(result)
@@ -306,177 +580,113 @@ impl SecurityAuditUseCase {
                 target_repos.extend(repos_in_group.into_iter().cloned());
             }
         } else {
-            // 全てのリポジトリ
-            target_repos = manifest.repos.clone();
+            // ワークスペースに設定されたグループのリポジトリ
+            target_repos = workspace.configured_repos().into_iter().cloned().collect();
         }
 
         Ok(target_repos)
     }
 
-    /// Rustプロジェクトをフィルタリング
-    async fn filter_rust_repositories(
+    /// エコシステムごとの監査コマンド（設定 > マニフェストの`audit_commands` > デフォルト）
+    fn resolve_audit_commands(
         &self,
         workspace: &Workspace,
-        repos: &[ManifestRepo],
-    ) -> Result<Vec<ManifestRepo>, SecurityAuditError> {
-        let mut rust_repos = Vec::new();
+    ) -> Result<HashMap<AuditEcosystem, String>, SecurityAuditError> {
+        let mut commands: HashMap<_, _> = AuditEcosystem::ALL
+            .into_iter()
+            .map(|ecosystem| (ecosystem, self.security_service.audit_command(ecosystem)))
+            .collect();
 
-        for repo in repos {
-            let repo_path = workspace.repo_path(&repo.dest);
-            if self.is_rust_project(&repo_path).await {
-                rust_repos.push(repo.clone());
-            }
+        let manifest_commands = workspace
+            .manifest
+            .as_ref()
+            .and_then(|manifest| manifest.audit_commands.as_ref());
+        for (name, command) in manifest_commands.into_iter().flatten() {
+            let ecosystem = name
+                .parse::<AuditEcosystem>()
+                .map_err(SecurityAuditError::InvalidAuditCommands)?;
+            commands.insert(ecosystem, command.clone());
         }
 
-        Ok(rust_repos)
-    }
-
-    /// Rustプロジェクトかどうかを判定
-    async fn is_rust_project(&self, repo_path: &PathBuf) -> bool {
-        repo_path.join("Cargo.toml").exists()
+        commands.extend(self.config.audit_commands.clone());
+        Ok(commands)
     }
 
-    /// 順次監査実行
-    async fn execute_sequential(
+    /// 監査するリポジトリ（エコシステム付き）と監査対象外のリポジトリに分ける
+    fn partition_auditable<'a>(
         &self,
         workspace: &Workspace,
-        target_repos: &[ManifestRepo],
-    ) -> Result<WorkspaceAuditResult, SecurityAuditError> {
-        let mut result = WorkspaceAuditResult::new(false);
+        repos: &'a [ManifestRepo],
+    ) -> Result<(Vec<AuditableRepo<'a>>, Vec<&'a ManifestRepo>), SecurityAuditError> {
+        let mut auditable = Vec::new();
+        let mut skipped = Vec::new();
 
-        for repo in target_repos {
-            if self.config.verbose {
-                println!("Auditing dependencies in repository: {}", repo.dest);
+        for repo in repos {
+            let repo_path = workspace.resolve_dest(&repo.dest)?;
+            match AuditEcosystem::detect(&repo_path) {
+                Some(ecosystem) => auditable.push((repo, ecosystem, repo_path)),
+                None => skipped.push(repo),
             }
-
-            let audit_result = self.audit_repository(workspace, repo).await;
-            result.add_result(audit_result);
         }
 
-        Ok(result)
+        Ok((auditable, skipped))
     }
 
-    /// 並列監査実行
-    async fn execute_parallel(
+    /// 監査コマンドをCommandExecutorで並列に実行し、結果をまとめる
+    async fn run_audits(
         &self,
-        workspace: &Workspace,
-        target_repos: &[ManifestRepo],
-    ) -> Result<WorkspaceAuditResult, SecurityAuditError> {
-        let mut result = WorkspaceAuditResult::new(true);
-
+        repos: &[AuditableRepo<'_>],
+        audit_commands: &HashMap<AuditEcosystem, String>,
+    ) -> WorkspaceAuditResult {
+        let max_concurrency = if self.config.parallel {
+            resolve_parallelism(self.config.max_parallel, repos.len())
+        } else {
+            1
+        };
         if self.config.verbose {
             println!(
-                "Auditing dependencies in {} repositories in parallel",
-                target_repos.len()
+                "Auditing dependencies in {} repositories ({} at a time)",
+                repos.len(),
+                max_concurrency
             );
         }
 
-        // 並列度を制限するためのセマフォ
-        let max_parallel = self
-            .config
-            .max_parallel
-            .unwrap_or_else(|| std::cmp::min(target_repos.len(), num_cpus::get()));
-        let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(max_parallel));
-
-        // 各リポジトリのタスクを作成
-        let tasks: Vec<_> = target_repos
+        let tasks = repos
             .iter()
-            .map(|repo| {
-                let repo = repo.clone();
-                let workspace = workspace.clone();
-                let semaphore = semaphore.clone();
-                let security_service = SecurityService::new(); // 各タスクで独立したインスタンスを使用
-
-                tokio::spawn(async move {
-                    let _permit = semaphore.acquire().await.map_err(|e| {
-                        SecurityAuditError::SecurityServiceError(
-                            SecurityError::CommandExecutionFailed(format!(
-                                "Failed to acquire semaphore: {}",
-                                e
-                            )),
-                        )
-                    })?;
-
-                    let use_case = SecurityAuditUseCase {
-                        config: SecurityAuditConfig::new(),
-                        security_service,
-                    };
-
-                    Ok::<RepoAuditResult, SecurityAuditError>(
-                        use_case.audit_repository(&workspace, &repo).await,
-                    )
-                })
+            .map(|(repo, ecosystem, repo_path)| {
+                ExecutionTask::new(repo.dest.clone(), audit_commands[ecosystem].clone())
+                    .with_config(ExecutionConfig::new().with_working_directory(repo_path.clone()))
             })
             .collect();
-
-        // すべてのタスクを並列実行
-        let results = futures::future::join_all(tasks).await;
-
-        // 結果をまとめる
-        for join_result in results {
-            match join_result {
-                Ok(Ok(repo_result)) => {
-                    result.add_result(repo_result);
-                }
-                Ok(Err(_)) | Err(_) => {
-                    // タスクの実行に失敗した場合のエラー処理
-                    result.add_result(RepoAuditResult {
-                        dest: "unknown".to_string(),
-                        audit_result: None,
-                        error: Some("Task execution failed".to_string()),
-                        is_rust_project: true,
-                    });
-                }
-            }
-        }
-
-        Ok(result)
-    }
-
-    /// 単一リポジトリの監査
-    async fn audit_repository(
-        &self,
-        workspace: &Workspace,
-        repo: &ManifestRepo,
-    ) -> RepoAuditResult {
-        let repo_path = workspace.repo_path(&repo.dest);
-
-        // リポジトリが存在するかチェック
-        if !repo_path.exists() {
-            return RepoAuditResult {
-                dest: repo.dest.clone(),
-                audit_result: None,
-                error: Some("Repository directory does not exist".to_string()),
-                is_rust_project: false,
+        let parallel_config = ParallelConfig::new().with_max_concurrency(max_concurrency);
+        let mut outputs = CommandExecutor::execute_parallel(tasks, &parallel_config)
+            .await
+            .task_results;
+
+        let mut result = WorkspaceAuditResult::new(max_concurrency > 1);
+        for (repo, ecosystem, repo_path) in repos {
+            let audit = match outputs.remove(&repo.dest) {
+                Some(Ok(output)) => self
+                    .security_service
+                    .parse_audit_report(*ecosystem, repo_path, &output)
+                    .map_err(|e| e.to_string()),
+                Some(Err(e)) => Err(format!("Failed to run {} audit: {}", ecosystem, e)),
+                None => Err("Audit was not run".to_string()),
             };
-        }
-
-        // Rustプロジェクトかチェック
-        let is_rust_project = self.is_rust_project(&repo_path).await;
-        if !is_rust_project {
-            return RepoAuditResult {
-                dest: repo.dest.clone(),
-                audit_result: None,
-                error: None,
-                is_rust_project: false,
+            let (audit_result, error) = match audit {
+                Ok(audit_result) => (Some(audit_result), None),
+                Err(error) => (None, Some(error)),
             };
-        }
-
-        // 監査実行
-        match self.security_service.audit_dependencies(&repo_path).await {
-            Ok(audit_result) => RepoAuditResult {
-                dest: repo.dest.clone(),
-                audit_result: Some(audit_result),
-                error: None,
-                is_rust_project: true,
-            },
-            Err(e) => RepoAuditResult {
+            result.add_result(RepoAuditResult {
                 dest: repo.dest.clone(),
-                audit_result: None,
-                error: Some(e.to_string()),
-                is_rust_project: true,
-            },
+                audit_result,
+                error,
+                ecosystem: Some(*ecosystem),
+                manifest_file: ecosystem.manifest_file(repo_path).map(str::to_string),
+            });
         }
+
+        result
     }
 
     /// 脆弱性の概要を表示
@@ -484,7 +694,10 @@ impl SecurityAuditUseCase {
         println!("\n=== Security Audit Summary ===");
         println!("Total repositories: {}", result.total_count());
         println!("Audited: {}", result.audited_count);
-        println!("Skipped (non-Rust): {}", result.skipped_count);
+        println!(
+            "Skipped (no recognized ecosystem): {}",
+            result.skipped_count
+        );
         println!("Errors: {}", result.error_count);
         println!("With vulnerabilities: {}", result.vulnerable_count);
 
@@ -544,7 +757,8 @@ mod tests {
             dest: "test-repo".to_string(),
             audit_result: None,
             error: None,
-            is_rust_project: false,
+            ecosystem: None,
+            manifest_file: None,
         };
 
         assert!(!result.is_success());
@@ -563,7 +777,8 @@ mod tests {
             dest: "test-repo".to_string(),
             audit_result: None,
             error: None,
-            is_rust_project: true,
+            ecosystem: Some(AuditEcosystem::Cargo),
+            manifest_file: None,
         };
 
         result.add_result(repo_result);
@@ -572,24 +787,212 @@ mod tests {
         assert!(!result.is_success());
     }
 
-    #[tokio::test]
-    async fn test_is_rust_project() {
-        let temp_dir = TempDir::new().unwrap();
-        let config = SecurityAuditConfig::new();
-        let use_case = SecurityAuditUseCase::new(config);
-
-        // Non-Rust project
-        let non_rust_path = temp_dir.path().to_path_buf();
-        assert!(!use_case.is_rust_project(&non_rust_path).await);
+    /// 作業ディレクトリの`report.json`を出力し、あれば脆弱性ありとして終了する偽の監査コマンド
+    fn fake_audit_command(dir: &std::path::Path, name: &str) -> String {
+        use std::os::unix::fs::PermissionsExt;
 
-        // Rust project
-        let cargo_toml_path = non_rust_path.join("Cargo.toml");
+        let path = dir.join(name);
         fs::write(
-            cargo_toml_path,
-            "[package]\nname = \"test\"\nversion = \"0.1.0\"\nedition = \"2021\"",
+            &path,
+            "#!/bin/sh\n[ -f report.json ] || exit 0\ncat report.json\nexit 1\n",
         )
         .unwrap();
-        assert!(use_case.is_rust_project(&non_rust_path).await);
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+        path.display().to_string()
+    }
+
+    /// Rust（脆弱性あり・なし）、npm、監査対象外のリポジトリを持つワークスペース
+    fn audit_workspace(root: &std::path::Path) -> Workspace {
+        for (dest, manifest) in [
+            ("api", "Cargo.toml"),
+            ("core", "Cargo.toml"),
+            ("web", "package.json"),
+            ("docs", "README.md"),
+        ] {
+            fs::create_dir_all(root.join(dest)).unwrap();
+            fs::write(root.join(dest).join(manifest), "").unwrap();
+        }
+        fs::write(
+            root.join("api/report.json"),
+            r#"{"vulnerabilities":{"found":true,"count":1,"list":[{"advisory":{"id":"RUSTSEC-2024-0001","cvss":{"severity":"high"}},"package":{"name":"foo","version":"0.1.0"}}]}}"#,
+        )
+        .unwrap();
+        fs::write(
+            root.join("web/report.json"),
+            r#"{"vulnerabilities":{"lodash":{"severity":"low","range":"*","via":["x"]},"minimist":{"severity":"moderate","range":"*","via":["y"]}}}"#,
+        )
+        .unwrap();
+
+        let repos = ["api", "core", "docs", "web"]
+            .iter()
+            .map(|dest| ManifestRepo::new(format!("https://example.com/{}.git", dest), *dest))
+            .collect();
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        Workspace::new(root.to_path_buf(), workspace_config)
+            .with_status(crate::domain::entities::workspace::WorkspaceStatus::Initialized)
+            .with_manifest(Manifest::new(repos))
+    }
+
+    fn fake_security_service(dir: &std::path::Path) -> SecurityService {
+        SecurityService::new()
+            .with_cargo_path(fake_audit_command(dir, "fake-cargo"))
+            .with_npm_path(fake_audit_command(dir, "fake-npm"))
+    }
+
+    #[tokio::test]
+    async fn test_parallel_audit_aggregates_vulnerabilities() {
+        let temp_dir = TempDir::new().unwrap();
+        let tools = TempDir::new().unwrap();
+        let workspace = audit_workspace(temp_dir.path());
+
+        let config = SecurityAuditConfig::new().with_parallel(true, Some(2));
+        let result = SecurityAuditUseCase::new(config)
+            .with_security_service(fake_security_service(tools.path()))
+            .execute(&workspace)
+            .await
+            .unwrap();
+
+        assert!(result.was_parallel);
+        assert_eq!(result.total_count(), 4);
+        assert_eq!(result.audited_count, 3);
+        assert_eq!(result.skipped_count, 1);
+        assert_eq!(result.error_count, 0);
+        assert_eq!(result.vulnerable_count, 2);
+
+        let count = |dest: &str| {
+            let repo = result.repo_results.iter().find(|r| r.dest == dest).unwrap();
+            (repo.ecosystem, repo.vulnerability_count())
+        };
+        assert_eq!(count("api"), (Some(AuditEcosystem::Cargo), 1));
+        assert_eq!(count("core"), (Some(AuditEcosystem::Cargo), 0));
+        assert_eq!(count("web"), (Some(AuditEcosystem::Npm), 2));
+        assert_eq!(count("docs"), (None, 0));
+
+        let total = result.total_vulnerabilities();
+        assert_eq!((total.high, total.medium, total.low), (1, 1, 1));
+
+        // High脆弱性があるため失敗扱い
+        assert_eq!(result.exit_code, 1);
+    }
+
+    #[tokio::test]
+    async fn test_continue_on_vulnerabilities() {
+        let temp_dir = TempDir::new().unwrap();
+        let tools = TempDir::new().unwrap();
+        let workspace = audit_workspace(temp_dir.path());
+
+        let config = SecurityAuditConfig::new()
+            .with_parallel(false, None)
+            .with_fail_on_vulnerabilities(false);
+        let result = SecurityAuditUseCase::new(config)
+            .with_security_service(fake_security_service(tools.path()))
+            .execute(&workspace)
+            .await
+            .unwrap();
+
+        assert!(!result.was_parallel);
+        assert!(result.has_critical_or_high_vulnerabilities());
+        assert_eq!(result.exit_code, 0);
+    }
+
+    #[tokio::test]
+    async fn test_manifest_audit_commands_override_defaults() {
+        let temp_dir = TempDir::new().unwrap();
+        let tools = TempDir::new().unwrap();
+        let mut workspace = audit_workspace(temp_dir.path());
+        fs::create_dir_all(temp_dir.path().join("svc")).unwrap();
+        fs::write(temp_dir.path().join("svc/go.mod"), "module svc").unwrap();
+        let manifest = workspace.manifest.take().unwrap();
+        let mut repos = manifest.repos.clone();
+        repos.push(ManifestRepo::new("https://example.com/svc.git", "svc"));
+        workspace.manifest = Some(
+            Manifest::new(repos)
+                .with_audit_command("go", fake_audit_command(tools.path(), "fake-govulncheck"))
+                .with_audit_command("cargo", "wmgr-test-missing-cargo audit --json"),
+        );
+
+        // 設定での指定はマニフェストより優先される
+        let config = SecurityAuditConfig::new().with_audit_command(
+            AuditEcosystem::Cargo,
+            fake_audit_command(tools.path(), "fake-cargo"),
+        );
+        let result = SecurityAuditUseCase::new(config)
+            .with_security_service(
+                SecurityService::new().with_npm_path(fake_audit_command(tools.path(), "fake-npm")),
+            )
+            .execute(&workspace)
+            .await
+            .unwrap();
+
+        assert_eq!(result.error_count, 0);
+        let svc = result
+            .repo_results
+            .iter()
+            .find(|r| r.dest == "svc")
+            .unwrap();
+        assert_eq!(svc.ecosystem, Some(AuditEcosystem::Go));
+        assert!(svc.is_success());
+    }
+
+    #[tokio::test]
+    async fn test_unknown_audit_command_ecosystem() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut workspace = audit_workspace(temp_dir.path());
+        workspace.manifest = workspace
+            .manifest
+            .take()
+            .map(|manifest| manifest.with_audit_command("maven", "mvn verify"));
+
+        let result = SecurityAuditUseCase::new(SecurityAuditConfig::new())
+            .execute(&workspace)
+            .await;
+        assert!(matches!(
+            result,
+            Err(SecurityAuditError::InvalidAuditCommands(message)) if message.contains("maven")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_audit_command_failure_is_reported() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = audit_workspace(temp_dir.path());
+
+        let service = SecurityService::new()
+            .with_cargo_path("wmgr-test-missing-cargo")
+            .with_npm_path("wmgr-test-missing-npm");
+        let result = SecurityAuditUseCase::new(SecurityAuditConfig::new())
+            .with_security_service(service)
+            .execute(&workspace)
+            .await
+            .unwrap();
+
+        assert_eq!(result.error_count, 3);
+        assert!(result.failed_results()[0]
+            .error
+            .as_deref()
+            .unwrap()
+            .starts_with("Failed to run cargo audit"));
+        assert_eq!(result.exit_code, 1);
+    }
+
+    #[tokio::test]
+    async fn test_no_auditable_projects() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let workspace = Workspace::new(temp_dir.path().to_path_buf(), workspace_config)
+            .with_status(crate::domain::entities::workspace::WorkspaceStatus::Initialized)
+            .with_manifest(Manifest::new(vec![ManifestRepo::new(
+                "https://example.com/docs.git",
+                "docs",
+            )]));
+
+        let result = SecurityAuditUseCase::new(SecurityAuditConfig::new())
+            .execute(&workspace)
+            .await;
+        assert!(matches!(
+            result,
+            Err(SecurityAuditError::NoAuditableProjectsFound)
+        ));
     }
 
     #[tokio::test]
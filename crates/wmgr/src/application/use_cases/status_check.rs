@@ -1,10 +1,26 @@
-use crate::domain::entities::{manifest::ManifestRepo, workspace::Workspace};
+use crate::common::error::WmgrError;
+use crate::common::executor::resolve_parallelism;
+use crate::common::instrument::instrument_repo;
+use crate::common::progress::{ProgressEvent, ProgressReporter, RepoOutcome};
+use crate::domain::entities::{
+    manifest::ManifestRepo,
+    repo_selector::{RepoSelector, RepoSelectorError},
+    workspace::{DestPathError, Workspace},
+};
 use crate::domain::value_objects::scm_type::ScmType;
+use crate::infrastructure::filesystem::status_ignore::{StatusIgnore, StatusIgnoreError};
 use crate::infrastructure::git::repository::{GitRepository, GitRepositoryError};
 use crate::infrastructure::scm::{ScmFactory, ScmError, StatusResult as ScmStatusResult};
+use chrono::{DateTime, Utc};
+use futures::future::join_all;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
+use std::sync::Arc;
 use thiserror::Error;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::sync::Semaphore;
+use tracing::{info, warn};
 
 /// StatusCheck関連のエラー
 #[derive(Debug, Error)]
@@ -32,6 +48,53 @@ pub enum StatusCheckError {
 
     #[error("Git repository error: {0}")]
     GitRepositoryError(#[from] GitRepositoryError),
+
+    #[error("Parallel execution failed: {0}")]
+    ParallelExecutionFailed(String),
+
+    #[error("Status ignore error: {0}")]
+    IgnoreFailed(#[from] StatusIgnoreError),
+
+    #[error("Repository selection failed: {0}")]
+    SelectionFailed(#[from] RepoSelectorError),
+
+    #[error("Invalid repository destination: {0}")]
+    InvalidDest(#[from] DestPathError),
+}
+
+impl From<StatusCheckError> for WmgrError {
+    fn from(error: StatusCheckError) -> Self {
+        match &error {
+            StatusCheckError::WorkspaceNotInitialized(path) => {
+                WmgrError::workspace_not_initialized(path)
+            }
+            StatusCheckError::GitStatusFailed { repo, .. }
+            | StatusCheckError::RepositoryNotFound(repo) => {
+                let repo = repo.clone();
+                WmgrError::repository_error_with_source(error.to_string(), Some(repo), error)
+            }
+            StatusCheckError::GitOperationFailed(_)
+            | StatusCheckError::ScmOperationFailed(_)
+            | StatusCheckError::GitRepositoryError(_) => {
+                WmgrError::repository_error_with_source(error.to_string(), None, error)
+            }
+            StatusCheckError::BranchNameError(_) => {
+                WmgrError::validation_error("branch", error.to_string(), None)
+            }
+            StatusCheckError::IgnoreFailed(_) => {
+                WmgrError::config_error_with_source(error.to_string(), error)
+            }
+            StatusCheckError::SelectionFailed(_) => {
+                WmgrError::validation_error("repo", error.to_string(), None)
+            }
+            StatusCheckError::InvalidDest(_) => {
+                WmgrError::validation_error("dest", error.to_string(), None)
+            }
+            StatusCheckError::IoError(_) | StatusCheckError::ParallelExecutionFailed(_) => {
+                WmgrError::internal_error_with_source(error.to_string(), error)
+            }
+        }
+    }
 }
 
 /// ステータス確認の設定
@@ -40,6 +103,15 @@ pub struct StatusCheckConfig {
     /// 特定のグループのみをチェックするか（Noneの場合は全て）
     pub groups: Option<Vec<String>>,
 
+    /// いずれかを持つリポジトリのみを対象にするタグ（空の場合は絞り込まない）
+    pub tags: Vec<String>,
+
+    /// ひとつでも持つリポジトリを対象から除外するタグ
+    pub exclude_tags: Vec<String>,
+
+    /// destに一致させるglobパターン（空の場合は絞り込まない）
+    pub repo_patterns: Vec<String>,
+
     /// ブランチ情報を表示するか
     pub show_branch: bool,
 
@@ -48,19 +120,145 @@ pub struct StatusCheckConfig {
 
     /// 詳細ログを出力するか
     pub verbose: bool,
+
+    /// 並列実行数（Noneの場合はCPU数）
+    pub jobs: Option<usize>,
+
+    /// 結果の並び順
+    pub sort: StatusSortKey,
+
+    /// 並び順を逆にするか
+    pub reverse: bool,
+
+    /// この参照・日時より後のコミットを数える（Gitのみ）
+    pub changed_since: Option<ChangedSince>,
+
+    /// `changed_since`より後のコミットがあるリポジトリのみを表示するか
+    pub only_changed: bool,
+
+    /// 未追跡ファイルを種類（トップレベルのディレクトリ・拡張子）ごとに数えるか
+    pub untracked_summary: bool,
+
+    /// 追跡ブランチではなく`<remote>/<現在のブランチ>`との差分を数えるリモート（Gitのみ）
+    pub ahead_behind_remote: Option<String>,
+
+    /// 最終fetch日時・ディスクサイズ・ローカルブランチ数も調べるか（Gitのみ）
+    pub extended: bool,
+
+    /// 調べる前にリモートからfetchするか（作業ツリーは変更しない）
+    pub fetch: bool,
 }
 
 impl Default for StatusCheckConfig {
     fn default() -> Self {
         Self {
             groups: None,
+            tags: Vec::new(),
+            exclude_tags: Vec::new(),
+            repo_patterns: Vec::new(),
             show_branch: false,
             compact: false,
             verbose: false,
+            jobs: None,
+            sort: StatusSortKey::Dest,
+            reverse: false,
+            changed_since: None,
+            only_changed: false,
+            untracked_summary: false,
+            ahead_behind_remote: None,
+            extended: false,
+            fetch: false,
         }
     }
 }
 
+impl StatusCheckConfig {
+    /// 対象にするタグを設定
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// 除外するタグを設定
+    pub fn with_exclude_tags(mut self, exclude_tags: Vec<String>) -> Self {
+        self.exclude_tags = exclude_tags;
+        self
+    }
+
+    /// destに一致させるglobパターンを設定
+    pub fn with_repo_patterns(mut self, repo_patterns: Vec<String>) -> Self {
+        self.repo_patterns = repo_patterns;
+        self
+    }
+
+    /// グループ・タグ・globの指定から対象リポジトリの選択条件を作成
+    pub fn repo_selector(&self) -> RepoSelector {
+        RepoSelector::new(self.groups.clone())
+            .with_tags(self.tags.clone())
+            .with_exclude_tags(self.exclude_tags.clone())
+            .with_repo_patterns(self.repo_patterns.clone())
+    }
+
+    /// 基準より後のコミットを数える参照・日時を設定
+    pub fn with_changed_since(mut self, changed_since: ChangedSince) -> Self {
+        self.changed_since = Some(changed_since);
+        self
+    }
+
+    /// 基準より後のコミットがあるリポジトリのみを表示するかを設定
+    pub fn with_only_changed(mut self, only_changed: bool) -> Self {
+        self.only_changed = only_changed;
+        self
+    }
+
+    /// 未追跡ファイルを種類ごとに数えるかを設定
+    pub fn with_untracked_summary(mut self, untracked_summary: bool) -> Self {
+        self.untracked_summary = untracked_summary;
+        self
+    }
+
+    /// ahead/behindを数える対象のリモートを設定
+    pub fn with_ahead_behind_remote(mut self, remote: impl Into<String>) -> Self {
+        self.ahead_behind_remote = Some(remote.into());
+        self
+    }
+
+    /// 拡張ステータス（リポジトリの健全性情報）を設定
+    pub fn with_extended(mut self, extended: bool) -> Self {
+        self.extended = extended;
+        self
+    }
+
+    /// 調べる前にリモートからfetchするかを設定
+    pub fn with_fetch(mut self, fetch: bool) -> Self {
+        self.fetch = fetch;
+        self
+    }
+}
+
+/// 変更の有無を判定する基準
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChangedSince {
+    /// 各リポジトリで解決する参照（タグ・ブランチ・コミット）。HEADから到達でき、
+    /// 参照から到達できないコミットを数える
+    Revision(String),
+    /// Unix時刻（秒）。これより後にコミットされたHEADの履歴を数える
+    Date(i64),
+}
+
+/// ステータス結果の並び替えキー
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum StatusSortKey {
+    /// リポジトリのパス順
+    #[default]
+    Dest,
+    /// 状態順（問題のあるリポジトリが先）
+    State,
+    /// 現在のブランチ名順（ブランチ不明は最後）
+    Branch,
+}
+
 /// リポジトリの状態
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum RepositoryState {
@@ -78,6 +276,20 @@ pub enum RepositoryState {
     Error,
 }
 
+impl RepositoryState {
+    /// 状態順で並べる際の順位（問題が深刻なものほど小さい）
+    fn sort_rank(&self) -> u8 {
+        match self {
+            RepositoryState::Error => 0,
+            RepositoryState::Missing => 1,
+            RepositoryState::Dirty => 2,
+            RepositoryState::WrongBranch => 3,
+            RepositoryState::OutOfSync => 4,
+            RepositoryState::Clean => 5,
+        }
+    }
+}
+
 /// 単一リポジトリのステータス
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RepositoryStatus {
@@ -119,6 +331,52 @@ pub struct RepositoryStatus {
 
     /// SCM固有の追加情報
     pub scm_extra_info: std::collections::HashMap<String, String>,
+
+    /// `changed_since`より後のコミット数（未指定・非Git・参照が解決できない場合はNone）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commits_since: Option<usize>,
+
+    /// 種類ごとの未追跡ファイル数（`untracked_summary`の指定時、未追跡ファイルがある場合のみ）
+    ///
+    /// キーはトップレベルのディレクトリ（`build/`）、直下のファイルは拡張子（`*.log`）、
+    /// 拡張子のないファイルはファイル名そのもの。
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub untracked_breakdown: Option<BTreeMap<String, usize>>,
+
+    /// ahead/behindを数えたリモート（`ahead_behind_remote`の指定時のみ）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ahead_behind_remote: Option<String>,
+
+    /// リポジトリの健全性情報（`extended`の指定時、Gitのみ）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<RepositoryHealth>,
+}
+
+/// リポジトリの健全性情報（整理・レポート用）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepositoryHealth {
+    /// 最後にfetchした日時（`FETCH_HEAD`の更新日時。fetchしたことがない場合はNone）
+    pub last_fetch: Option<DateTime<Utc>>,
+
+    /// ディスク上のサイズの概算（バイト）
+    ///
+    /// Gitディレクトリの実サイズに、インデックスに記録された追跡ファイルのサイズを加えたもの。
+    /// 作業ツリーは走査しないため、未追跡・無視されたファイルは含まない。
+    pub disk_size_bytes: u64,
+
+    /// ローカルブランチ数
+    pub local_branches: usize,
+}
+
+impl RepositoryHealth {
+    /// Gitリポジトリの健全性情報を調べる
+    pub fn of_git_repository(git_repo: &GitRepository) -> Result<Self, GitRepositoryError> {
+        Ok(Self {
+            last_fetch: git_repo.last_fetch_time().map(DateTime::<Utc>::from),
+            disk_size_bytes: git_repo.estimated_disk_size()?,
+            local_branches: git_repo.local_branch_count()?,
+        })
+    }
 }
 
 impl RepositoryStatus {
@@ -138,6 +396,10 @@ impl RepositoryStatus {
             commits_behind: 0,
             error_message: None,
             scm_extra_info: std::collections::HashMap::new(),
+            commits_since: None,
+            untracked_breakdown: None,
+            ahead_behind_remote: None,
+            health: None,
         }
     }
 
@@ -202,6 +464,30 @@ impl RepositoryStatus {
     pub fn has_issues(&self) -> bool {
         !matches!(self.state, RepositoryState::Clean)
     }
+
+    /// `changed_since`より後のコミットがあるか
+    pub fn has_commits_since(&self) -> bool {
+        self.commits_since.is_some_and(|count| count > 0)
+    }
+
+    /// 進捗イベントで報告する確認結果
+    fn outcome(&self) -> RepoOutcome {
+        let state = match self.state {
+            RepositoryState::Clean => "clean",
+            RepositoryState::Dirty => "dirty",
+            RepositoryState::Missing => "missing",
+            RepositoryState::WrongBranch => "wrong branch",
+            RepositoryState::OutOfSync => "out of sync",
+            RepositoryState::Error => {
+                return RepoOutcome::Failed(
+                    self.error_message
+                        .clone()
+                        .unwrap_or_else(|| "error".to_string()),
+                );
+            }
+        };
+        RepoOutcome::Checked(state.to_string())
+    }
 }
 
 /// 全体のステータス結果
@@ -221,6 +507,10 @@ pub struct StatusResult {
 
     /// エラーのあるリポジトリ数
     pub error_count: usize,
+
+    /// 状態ごとのリポジトリ数
+    #[serde(default)]
+    pub summary: StatusSummary,
 }
 
 impl StatusResult {
@@ -231,6 +521,7 @@ impl StatusResult {
             dirty_count: 0,
             missing_count: 0,
             error_count: 0,
+            summary: StatusSummary::default(),
         }
     }
 
@@ -254,18 +545,119 @@ impl StatusResult {
     pub fn total_count(&self) -> usize {
         self.repositories.len()
     }
+
+    /// リポジトリを並び替える（安定ソート、同順位は現在の順序を保つ）
+    pub fn sort_repositories(&mut self, key: StatusSortKey, reverse: bool) {
+        self.repositories.sort_by(|a, b| {
+            let ordering = match key {
+                StatusSortKey::Dest => a.dest.cmp(&b.dest),
+                StatusSortKey::State => a.state.sort_rank().cmp(&b.state.sort_rank()),
+                StatusSortKey::Branch => {
+                    let branch = |r: &RepositoryStatus| {
+                        (r.current_branch.is_none(), r.current_branch.clone())
+                    };
+                    branch(a).cmp(&branch(b))
+                }
+            };
+            if reverse {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+    }
+}
+
+/// 状態ごとのリポジトリ数（JSON/YAML出力の`summary`）
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusSummary {
+    /// 合計
+    pub total: usize,
+    /// クリーン
+    pub clean: usize,
+    /// ダーティ
+    pub dirty: usize,
+    /// ブランチが期待と異なる
+    pub wrong_branch: usize,
+    /// リモートと差分あり
+    pub out_of_sync: usize,
+    /// 存在しない
+    pub missing: usize,
+    /// エラー
+    pub error: usize,
+}
+
+impl StatusSummary {
+    /// リポジトリの一覧から集計
+    pub fn from_repositories(repositories: &[RepositoryStatus]) -> Self {
+        let mut summary = Self {
+            total: repositories.len(),
+            ..Self::default()
+        };
+        for repo in repositories {
+            match repo.state {
+                RepositoryState::Clean => summary.clean += 1,
+                RepositoryState::Dirty => summary.dirty += 1,
+                RepositoryState::WrongBranch => summary.wrong_branch += 1,
+                RepositoryState::OutOfSync => summary.out_of_sync += 1,
+                RepositoryState::Missing => summary.missing += 1,
+                RepositoryState::Error => summary.error += 1,
+            }
+        }
+        summary
+    }
+}
+
+/// 1件以上ある状態のみを`12 clean, 2 dirty, 1 missing`の形式で表示
+impl std::fmt::Display for StatusSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let errors = if self.error == 1 { "error" } else { "errors" };
+        let counts = [
+            (self.clean, "clean"),
+            (self.dirty, "dirty"),
+            (self.wrong_branch, "on the wrong branch"),
+            (self.out_of_sync, "out of sync"),
+            (self.missing, "missing"),
+            (self.error, errors),
+        ];
+        let parts: Vec<String> = counts
+            .iter()
+            .filter(|(count, _)| *count > 0)
+            .map(|(count, label)| format!("{} {}", count, label))
+            .collect();
+        if parts.is_empty() {
+            write!(f, "no repositories")
+        } else {
+            write!(f, "{}", parts.join(", "))
+        }
+    }
 }
 
 /// ステータス確認のユースケース
 pub struct StatusCheckUseCase {
     /// 設定
     config: StatusCheckConfig,
+
+    /// 進捗イベントの送信先
+    progress: ProgressReporter,
 }
 
 impl StatusCheckUseCase {
     /// 新しいStatusCheckUseCaseインスタンスを作成
     pub fn new(config: StatusCheckConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            progress: ProgressReporter::default(),
+        }
+    }
+
+    /// リポジトリごとの進捗イベントを`sender`に送る
+    ///
+    /// 各リポジトリについて確認の開始時に`RepoStarted`、終了時に状態を添えた
+    /// `RepoFinished`を送る。並列実行では完了順に届く。
+    pub fn with_progress(mut self, sender: UnboundedSender<ProgressEvent>) -> Self {
+        self.progress = ProgressReporter::new(sender);
+        self
     }
 
     /// ステータス確認を実行
@@ -276,16 +668,146 @@ impl StatusCheckUseCase {
         // 2. ステータス確認対象リポジトリの決定
         let target_repos = self.determine_target_repositories(workspace)?;
 
-        // 3. 各リポジトリのステータス確認
+        // 3. ワークスペース共通の無視パターン（.wmgrignore）を読み込む
+        let ignore_patterns = StatusIgnore::load_patterns(&workspace.root_path)?;
+
+        // 4. 各リポジトリのステータス確認
+        let mut statuses = if self.max_parallel(target_repos.len()) <= 1 {
+            self.check_sequential(&target_repos, workspace, &ignore_patterns)
+                .await?
+        } else {
+            self.check_parallel(&target_repos, workspace, ignore_patterns)
+                .await?
+        };
+
+        // 基準より後のコミットがないリポジトリを除く
+        if self.config.only_changed && self.config.changed_since.is_some() {
+            statuses.retain(RepositoryStatus::has_commits_since);
+        }
+
+        // 出力を安定させるためdest順に並べ、その上で指定のキーで並び替える
+        statuses.sort_by(|a, b| a.dest.cmp(&b.dest));
+
         let mut result = StatusResult::new();
-        for repo in target_repos {
-            let status = self.check_repository_status(&repo, workspace).await?;
+        for status in statuses {
             result.add_repository(status);
         }
+        result.sort_repositories(self.config.sort, self.config.reverse);
+        result.summary = StatusSummary::from_repositories(&result.repositories);
 
         Ok(result)
     }
 
+    /// 同時に確認するリポジトリ数を決定
+    fn max_parallel(&self, repo_count: usize) -> usize {
+        resolve_parallelism(self.config.jobs, repo_count)
+    }
+
+    /// リポジトリを1つずつ確認
+    async fn check_sequential(
+        &self,
+        target_repos: &[ManifestRepo],
+        workspace: &Workspace,
+        ignore_patterns: &[String],
+    ) -> Result<Vec<RepositoryStatus>, StatusCheckError> {
+        let mut statuses = Vec::with_capacity(target_repos.len());
+        for (index, repo) in target_repos.iter().enumerate() {
+            let repo_path = workspace.resolve_dest(&repo.dest)?;
+            let status = Self::check_with_progress(
+                &self.config,
+                &self.progress,
+                repo,
+                repo_path,
+                ignore_patterns,
+                (index, target_repos.len()),
+            )
+            .await?;
+            statuses.push(status);
+        }
+        Ok(statuses)
+    }
+
+    /// リポジトリを並列に確認
+    ///
+    /// リポジトリハンドルはタスクごとに開き直し、スレッド間で共有しない。
+    async fn check_parallel(
+        &self,
+        target_repos: &[ManifestRepo],
+        workspace: &Workspace,
+        ignore_patterns: Vec<String>,
+    ) -> Result<Vec<RepositoryStatus>, StatusCheckError> {
+        let semaphore = Arc::new(Semaphore::new(self.max_parallel(target_repos.len())));
+        let ignore_patterns = Arc::new(ignore_patterns);
+
+        let total = target_repos.len();
+        let tasks = target_repos
+            .iter()
+            .enumerate()
+            .map(|(index, repo)| {
+                let repo = repo.clone();
+                let repo_path = workspace.resolve_dest(&repo.dest)?;
+                let semaphore = semaphore.clone();
+                let config = self.config.clone();
+                let progress = self.progress.clone();
+                let ignore_patterns = ignore_patterns.clone();
+
+                Ok(tokio::spawn(async move {
+                    let _permit = semaphore.acquire().await.map_err(|e| {
+                        StatusCheckError::ParallelExecutionFailed(format!(
+                            "Failed to acquire semaphore: {}",
+                            e
+                        ))
+                    })?;
+
+                    Self::check_with_progress(
+                        &config,
+                        &progress,
+                        &repo,
+                        repo_path,
+                        &ignore_patterns,
+                        (index, total),
+                    )
+                    .await
+                }))
+            })
+            .collect::<Result<Vec<_>, StatusCheckError>>()?;
+
+        let mut statuses = Vec::with_capacity(tasks.len());
+        for join_result in join_all(tasks).await {
+            let status = join_result.map_err(|e| {
+                StatusCheckError::ParallelExecutionFailed(format!("Task join error: {}", e))
+            })??;
+            statuses.push(status);
+        }
+        Ok(statuses)
+    }
+
+    /// 1つのリポジトリを確認し、その開始と終了を`progress`に送る
+    ///
+    /// `position`は対象リポジトリ内での0始まりの位置と対象リポジトリ数。
+    async fn check_with_progress(
+        config: &StatusCheckConfig,
+        progress: &ProgressReporter,
+        repo: &ManifestRepo,
+        repo_path: PathBuf,
+        ignore_patterns: &[String],
+        position: (usize, usize),
+    ) -> Result<RepositoryStatus, StatusCheckError> {
+        progress.started(&repo.dest, position.0, position.1);
+        let result = instrument_repo(
+            "status",
+            &repo.dest,
+            Self::check_repository_status(config, repo, repo_path, ignore_patterns),
+        )
+        .await;
+        let outcome = match &result {
+            Ok(status) => status.outcome(),
+            Err(e) => RepoOutcome::Failed(e.to_string()),
+        };
+        progress.finished(&repo.dest, outcome);
+        result
+    }
+
     /// ワークスペースが初期化済みかチェック
     fn check_workspace_initialized(&self, workspace: &Workspace) -> Result<(), StatusCheckError> {
         if !workspace.is_initialized() {
@@ -305,29 +827,27 @@ impl StatusCheckUseCase {
             StatusCheckError::GitOperationFailed("Manifest not loaded".to_string())
         })?;
 
-        let mut target_repos = Vec::new();
-
-        if let Some(groups) = &self.config.groups {
-            // 指定されたグループのリポジトリのみ
-            for group_name in groups {
-                let repos_in_group = manifest.get_repos_in_group(group_name);
-                target_repos.extend(repos_in_group.into_iter().cloned());
-            }
-        } else {
-            // 全てのリポジトリ
-            target_repos = manifest.repos.clone();
-        }
+        let target_repos = self
+            .config
+            .repo_selector()
+            .select(manifest, workspace.configured_repos())?
+            .into_iter()
+            .cloned()
+            .collect();
 
         Ok(target_repos)
     }
 
     /// 単一リポジトリのステータス確認（SCM対応）
+    ///
+    /// `ignore_patterns`（.wmgrignore）とリポジトリの`status_ignore`に一致する
+    /// 未追跡ファイルは数えない。
     async fn check_repository_status(
-        &self,
+        config: &StatusCheckConfig,
         repo: &ManifestRepo,
-        workspace: &Workspace,
+        repo_path: PathBuf,
+        ignore_patterns: &[String],
     ) -> Result<RepositoryStatus, StatusCheckError> {
-        let repo_path = workspace.repo_path(&repo.dest);
         let mut status = RepositoryStatus::new(repo.dest.clone(), repo.scm.clone());
 
         if !repo_path.exists() {
@@ -363,8 +883,22 @@ impl StatusCheckUseCase {
         }
 
         // SCM操作を実行してステータスを取得
-        match self.perform_scm_status_check(&repo_path, repo).await {
+        match Self::perform_scm_status_check(&repo_path, repo, config.fetch).await {
             Ok(scm_status) => {
+                // 無視パターンに一致しない未追跡ファイルだけを数える
+                let untracked = Self::filter_untracked(repo, &scm_status, ignore_patterns)?;
+                let untracked_files = if scm_status.untracked_files.is_empty() {
+                    usize::from(scm_status.has_untracked)
+                } else {
+                    untracked.len()
+                };
+                if config.untracked_summary && !untracked.is_empty() {
+                    status.untracked_breakdown = Some(untracked_breakdown(&untracked));
+                }
+                // 未追跡ファイルの一覧が得られるSCMでは、それも変更として扱う
+                let has_untracked_changes =
+                    !scm_status.untracked_files.is_empty() && untracked_files > 0;
+
                 status.current_revision = Some(scm_status.current_revision);
                 status.current_branch = scm_status.current_branch;
                 status.expected_branch = repo.branch.clone();
@@ -372,24 +906,58 @@ impl StatusCheckUseCase {
                 // 変更情報を設定（SCMから取得できた場合）
                 if scm_status.has_changes {
                     status.modified_files = 1; // 詳細な数は後で実装
+                }
+                status.untracked_files = untracked_files;
+
+                if scm_status.has_changes || has_untracked_changes {
                     status.state = RepositoryState::Dirty;
                 } else {
                     status.state = RepositoryState::Clean;
                 }
 
-                if scm_status.has_untracked {
-                    status.untracked_files = 1; // 詳細な数は後で実装
-                }
-
                 // ahead/behind情報（Gitのみ）
                 if let (Some(ahead), Some(behind)) = (scm_status.ahead_count, scm_status.behind_count) {
                     status.commits_ahead = ahead;
                     status.commits_behind = behind;
                 }
 
+                // 指定されたリモートとのahead/behind（Gitのみ）
+                if let (Some(remote), ScmType::Git) = (&config.ahead_behind_remote, &repo.scm) {
+                    match GitRepository::open(&repo_path)
+                        .and_then(|git_repo| git_repo.ahead_behind_remote(remote))
+                    {
+                        Ok((ahead, behind)) => {
+                            status.commits_ahead = ahead;
+                            status.commits_behind = behind;
+                            status.ahead_behind_remote = Some(remote.clone());
+                        }
+                        Err(e) => return Ok(status.with_error(e.to_string())),
+                    }
+                }
+
                 // SCM固有の追加情報
                 status.scm_extra_info = scm_status.extra_info;
 
+                // 健全性情報（Gitのみ）。調べられなくてもステータス自体は有効なので警告に留める
+                if config.extended && repo.scm == ScmType::Git {
+                    match GitRepository::open(&repo_path)
+                        .and_then(|git_repo| RepositoryHealth::of_git_repository(&git_repo))
+                    {
+                        Ok(health) => status.health = Some(health),
+                        Err(e) => {
+                            warn!("Failed to collect health metadata for {}: {}", repo.dest, e)
+                        }
+                    }
+                }
+
+                // 基準より後のコミット数（Gitのみ）
+                if let (Some(changed_since), ScmType::Git) = (&config.changed_since, &repo.scm) {
+                    match Self::count_commits_since(&repo_path, changed_since) {
+                        Ok(count) => status.commits_since = count,
+                        Err(e) => return Ok(status.with_error(e.to_string())),
+                    }
+                }
+
                 // ブランチチェック
                 if let (Some(current), Some(expected)) = (&status.current_branch, &status.expected_branch) {
                     if current != expected && status.state != RepositoryState::Dirty {
@@ -405,18 +973,53 @@ impl StatusCheckUseCase {
         Ok(status)
     }
 
-    /// SCMステータス情報を取得
+    /// 基準より後のコミット数（参照が解決できない場合はNone）
+    fn count_commits_since(
+        repo_path: &std::path::Path,
+        changed_since: &ChangedSince,
+    ) -> Result<Option<usize>, StatusCheckError> {
+        let git_repo = GitRepository::open(repo_path)?;
+        let count = match changed_since {
+            ChangedSince::Revision(revision) => git_repo.count_commits_since_revision(revision)?,
+            ChangedSince::Date(timestamp) => Some(git_repo.count_commits_since_time(*timestamp)?),
+        };
+        Ok(count)
+    }
+
+    /// 無視パターンに一致しない未追跡ファイルの一覧
+    ///
+    /// 一覧を返さないSCMでは空になるため、呼び出し側で未追跡ファイルの有無を1件として数える。
+    fn filter_untracked<'a>(
+        repo: &ManifestRepo,
+        scm_status: &'a ScmStatusResult,
+        ignore_patterns: &[String],
+    ) -> Result<Vec<&'a str>, StatusCheckError> {
+        if scm_status.untracked_files.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut patterns = ignore_patterns.to_vec();
+        patterns.extend(repo.status_ignore.iter().flatten().cloned());
+        let ignore = StatusIgnore::new(&patterns)?;
+
+        Ok(scm_status
+            .untracked_files
+            .iter()
+            .filter(|path| !ignore.is_ignored(path))
+            .map(String::as_str)
+            .collect())
+    }
+
+    /// SCMステータス情報を取得（`fetch`の場合は先にリモートの変更を取り込む）
     async fn perform_scm_status_check(
-        &self,
         repo_path: &PathBuf,
         repo: &ManifestRepo,
+        fetch: bool,
     ) -> Result<ScmStatusResult, StatusCheckError> {
-        if self.config.verbose {
-            println!("Checking {} status for {}", repo.scm, repo_path.display());
-        }
+        info!("Checking {} status for {}", repo.scm, repo_path.display());
 
         // SCM操作インスタンスを作成
-        let scm = ScmFactory::create_scm(repo.scm.clone())?;
+        let scm = ScmFactory::create_scm_for_url(repo.scm.clone(), &repo.url)?;
 
         // リポジトリの種別を確認
         if !scm.is_repository(repo_path) {
@@ -426,12 +1029,14 @@ impl StatusCheckUseCase {
             )));
         }
 
+        if fetch {
+            scm.fetch(repo_path).await?;
+        }
+
         // SCMステータスを取得
         let status = scm.get_status(repo_path).await?;
 
-        if self.config.verbose {
-            println!("Status check completed for {} ({})", repo_path.display(), repo.scm);
-        }
+        info!("Status check completed for {} ({})", repo_path.display(), repo.scm);
 
         Ok(status)
     }
@@ -442,9 +1047,7 @@ impl StatusCheckUseCase {
         repo_path: &PathBuf,
         _repo: &ManifestRepo,
     ) -> Result<GitStatusInfo, StatusCheckError> {
-        if self.config.verbose {
-            println!("Checking status for {}", repo_path.display());
-        }
+        info!("Checking status for {}", repo_path.display());
 
         let git_repo = GitRepository::open(repo_path)?;
         let repo_status = git_repo.status()?;
@@ -460,6 +1063,26 @@ impl StatusCheckUseCase {
     }
 }
 
+/// 未追跡ファイルを種類ごとに数える
+///
+/// サブディレクトリ内のファイルはトップレベルのディレクトリ（`build/`）、直下のファイルは
+/// 拡張子（`*.log`）、拡張子のないファイルはファイル名でまとめる。
+pub fn untracked_breakdown(paths: &[&str]) -> BTreeMap<String, usize> {
+    let mut breakdown = BTreeMap::new();
+    for path in paths {
+        let path = path.trim_start_matches("./");
+        let key = match path.split_once('/') {
+            Some((dir, _)) => format!("{}/", dir),
+            None => match std::path::Path::new(path).extension() {
+                Some(ext) => format!("*.{}", ext.to_string_lossy()),
+                None => path.to_string(),
+            },
+        };
+        *breakdown.entry(key).or_insert(0) += 1;
+    }
+    breakdown
+}
+
 /// Git status情報
 #[derive(Debug)]
 struct GitStatusInfo {
@@ -485,6 +1108,9 @@ mod tests {
         assert!(!config.show_branch);
         assert!(!config.compact);
         assert!(!config.verbose);
+        assert!(config.jobs.is_none());
+        assert_eq!(config.sort, StatusSortKey::Dest);
+        assert!(!config.reverse);
     }
 
     #[test]
@@ -549,6 +1175,637 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_status_check_emits_span_per_repository() {
+        let temp_dir = TempDir::new().unwrap();
+        let repos = vec![
+            ManifestRepo::new("https://example.com/app.git", "app"),
+            ManifestRepo::new("https://example.com/lib.git", "lib"),
+        ];
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let workspace = Workspace::new(temp_dir.path().to_path_buf(), workspace_config)
+            .with_status(crate::domain::entities::workspace::WorkspaceStatus::Initialized)
+            .with_manifest(Manifest::new(repos));
+
+        for jobs in [1, 2] {
+            let (_guard, spans) = crate::common::instrument::capture::capture_repo_spans();
+            let config = StatusCheckConfig {
+                jobs: Some(jobs),
+                ..StatusCheckConfig::default()
+            };
+            StatusCheckUseCase::new(config).execute(&workspace).await.unwrap();
+
+            let spans = spans.lock().unwrap();
+            let mut dests: Vec<&str> = spans.iter().map(|s| s["dest"].as_str()).collect();
+            dests.sort();
+            assert_eq!(dests, vec!["app", "lib"], "jobs = {}", jobs);
+            assert!(spans.iter().all(|s| s["operation"] == "status"));
+        }
+    }
+
+    fn status_with(dest: &str, state: RepositoryState, branch: Option<&str>) -> RepositoryStatus {
+        let mut status = RepositoryStatus::new(dest.to_string(), ScmType::Git);
+        status.state = state;
+        status.current_branch = branch.map(|b| b.to_string());
+        status
+    }
+
+    fn sample_result() -> StatusResult {
+        let mut result = StatusResult::new();
+        for status in [
+            status_with("c", RepositoryState::Clean, Some("main")),
+            status_with("a", RepositoryState::Dirty, Some("develop")),
+            status_with("d", RepositoryState::Missing, None),
+            status_with("b", RepositoryState::Clean, Some("develop")),
+            status_with("e", RepositoryState::Dirty, Some("main")),
+        ] {
+            result.add_repository(status);
+        }
+        result
+    }
+
+    fn dests(result: &StatusResult) -> Vec<&str> {
+        result.repositories.iter().map(|r| r.dest.as_str()).collect()
+    }
+
+    #[test]
+    fn test_sort_by_dest() {
+        let mut result = sample_result();
+        result.sort_repositories(StatusSortKey::Dest, false);
+        assert_eq!(dests(&result), vec!["a", "b", "c", "d", "e"]);
+
+        result.sort_repositories(StatusSortKey::Dest, true);
+        assert_eq!(dests(&result), vec!["e", "d", "c", "b", "a"]);
+    }
+
+    #[test]
+    fn test_sort_by_state_is_stable() {
+        let mut result = sample_result();
+        result.sort_repositories(StatusSortKey::State, false);
+        // 同じ状態のリポジトリは元の順序を保つ
+        assert_eq!(dests(&result), vec!["d", "a", "e", "c", "b"]);
+
+        let mut result = sample_result();
+        result.sort_repositories(StatusSortKey::State, true);
+        assert_eq!(dests(&result), vec!["c", "b", "a", "e", "d"]);
+    }
+
+    #[test]
+    fn test_sort_by_branch() {
+        let mut result = sample_result();
+        result.sort_repositories(StatusSortKey::Branch, false);
+        // ブランチ不明のリポジトリは最後
+        assert_eq!(dests(&result), vec!["a", "b", "c", "e", "d"]);
+
+        let mut result = sample_result();
+        result.sort_repositories(StatusSortKey::Branch, true);
+        assert_eq!(dests(&result), vec!["d", "c", "e", "a", "b"]);
+    }
+
+    #[test]
+    fn test_sort_keeps_counts() {
+        let mut result = sample_result();
+        result.sort_repositories(StatusSortKey::State, true);
+        assert_eq!(result.clean_count, 2);
+        assert_eq!(result.dirty_count, 2);
+        assert_eq!(result.missing_count, 1);
+        assert_eq!(result.total_count(), 5);
+    }
+
+    /// 1コミットを持つGitリポジトリを作成
+    fn init_repo_with_commit(path: &std::path::Path) {
+        let repo = git2::Repository::init(path).unwrap();
+        std::fs::write(path.join("README.md"), "readme").unwrap();
+        let mut index = repo.index().unwrap();
+        index.add_path(std::path::Path::new("README.md")).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+        repo.commit(Some("HEAD"), &sig, &sig, "Initial commit", &tree, &[])
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_parallel_status_matches_sequential() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut repos = Vec::new();
+        for i in 0..8 {
+            let dest = format!("repo-{:02}", i);
+            init_repo_with_commit(&temp_dir.path().join(&dest));
+            if i % 3 == 0 {
+                std::fs::write(temp_dir.path().join(&dest).join("README.md"), "changed")
+                    .unwrap();
+            }
+            repos.push(ManifestRepo::new(
+                format!("https://example.com/{}.git", dest),
+                dest,
+            ));
+        }
+        // 存在しないリポジトリも含める
+        repos.push(ManifestRepo::new("https://example.com/missing.git", "missing"));
+        // マニフェストの順序がdest順でなくても結果は安定する
+        repos.reverse();
+
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let workspace = Workspace::new(temp_dir.path().to_path_buf(), workspace_config)
+            .with_status(crate::domain::entities::workspace::WorkspaceStatus::Initialized)
+            .with_manifest(Manifest::new(repos));
+
+        let sequential_config = StatusCheckConfig {
+            jobs: Some(1),
+            ..StatusCheckConfig::default()
+        };
+        let parallel_config = StatusCheckConfig {
+            jobs: Some(4),
+            ..StatusCheckConfig::default()
+        };
+
+        let started = std::time::Instant::now();
+        let sequential = StatusCheckUseCase::new(sequential_config)
+            .execute(&workspace)
+            .await
+            .unwrap();
+        let sequential_elapsed = started.elapsed();
+
+        let started = std::time::Instant::now();
+        let parallel = StatusCheckUseCase::new(parallel_config)
+            .execute(&workspace)
+            .await
+            .unwrap();
+        let parallel_elapsed = started.elapsed();
+
+        println!(
+            "status check: sequential {:?}, parallel {:?}",
+            sequential_elapsed, parallel_elapsed
+        );
+
+        let summarize = |result: &StatusResult| {
+            result
+                .repositories
+                .iter()
+                .map(|r| {
+                    (
+                        r.dest.clone(),
+                        r.state.clone(),
+                        r.current_revision.clone(),
+                        r.current_branch.clone(),
+                        r.modified_files,
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(summarize(&sequential), summarize(&parallel));
+
+        // 0は無制限（リポジトリ数まで）で、デッドロックせずに完了する
+        let unbounded_config = StatusCheckConfig {
+            jobs: Some(0),
+            ..StatusCheckConfig::default()
+        };
+        let unbounded = StatusCheckUseCase::new(unbounded_config)
+            .execute(&workspace)
+            .await
+            .unwrap();
+        assert_eq!(summarize(&sequential), summarize(&unbounded));
+        assert_eq!(parallel.total_count(), 9);
+        assert_eq!(parallel.missing_count, 1);
+        assert_eq!(parallel.dirty_count, 3);
+        assert_eq!(parallel.repositories[0].dest, "missing");
+        assert_eq!(parallel.repositories[1].dest, "repo-00");
+    }
+
+    #[tokio::test]
+    async fn test_parallel_status_reports_progress_per_repository() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut repos = Vec::new();
+        for dest in ["app", "lib", "docs"] {
+            init_repo_with_commit(&temp_dir.path().join(dest));
+            repos.push(ManifestRepo::new(
+                format!("https://example.com/{}.git", dest),
+                dest,
+            ));
+        }
+        std::fs::write(temp_dir.path().join("lib/README.md"), "changed").unwrap();
+        repos.push(ManifestRepo::new("https://example.com/missing.git", "missing"));
+
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let workspace = Workspace::new(temp_dir.path().to_path_buf(), workspace_config)
+            .with_status(crate::domain::entities::workspace::WorkspaceStatus::Initialized)
+            .with_manifest(Manifest::new(repos));
+
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        let config = StatusCheckConfig {
+            jobs: Some(4),
+            ..StatusCheckConfig::default()
+        };
+        StatusCheckUseCase::new(config)
+            .with_progress(sender)
+            .execute(&workspace)
+            .await
+            .unwrap();
+
+        let mut started = Vec::new();
+        let mut finished = Vec::new();
+        while let Some(event) = receiver.recv().await {
+            match event {
+                ProgressEvent::RepoStarted { dest, index, total } => {
+                    assert_eq!(total, 4);
+                    started.push((index, dest));
+                }
+                ProgressEvent::RepoFinished { dest, outcome } => {
+                    assert!(started.iter().any(|(_, started)| started == &dest));
+                    finished.push((dest, outcome));
+                }
+                ProgressEvent::RepoProgress { .. } => {}
+            }
+        }
+
+        started.sort();
+        assert_eq!(
+            started,
+            vec![
+                (0, "app".to_string()),
+                (1, "lib".to_string()),
+                (2, "docs".to_string()),
+                (3, "missing".to_string()),
+            ]
+        );
+        finished.sort_by(|a, b| a.0.cmp(&b.0));
+        let checked = |state: &str| RepoOutcome::Checked(state.to_string());
+        assert_eq!(
+            finished,
+            vec![
+                ("app".to_string(), checked("clean")),
+                ("docs".to_string(), checked("clean")),
+                ("lib".to_string(), checked("dirty")),
+                ("missing".to_string(), checked("missing")),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ignored_untracked_files_are_not_reported() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace_root = temp_dir.path();
+
+        // 生成物のみ: .wmgrignoreで全て無視される
+        init_repo_with_commit(&workspace_root.join("app"));
+        std::fs::create_dir_all(workspace_root.join("app/target/debug")).unwrap();
+        std::fs::write(workspace_root.join("app/target/debug/app"), "bin").unwrap();
+        std::fs::write(workspace_root.join("app/build.log"), "log").unwrap();
+
+        // リポジトリ固有のstatus_ignoreと、無視されない未追跡ファイル
+        init_repo_with_commit(&workspace_root.join("web"));
+        std::fs::create_dir_all(workspace_root.join("web/dist")).unwrap();
+        std::fs::write(workspace_root.join("web/dist/bundle.js"), "js").unwrap();
+        std::fs::write(workspace_root.join("web/server.log"), "log").unwrap();
+        std::fs::write(workspace_root.join("web/notes.txt"), "notes").unwrap();
+
+        std::fs::write(
+            workspace_root.join(".wmgrignore"),
+            "# build output\ntarget/\n*.log\n",
+        )
+        .unwrap();
+
+        let mut web = ManifestRepo::new("https://example.com/web.git", "web");
+        web.status_ignore = Some(vec!["dist/".to_string()]);
+        let repos = vec![ManifestRepo::new("https://example.com/app.git", "app"), web];
+
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let workspace = Workspace::new(workspace_root.to_path_buf(), workspace_config)
+            .with_status(crate::domain::entities::workspace::WorkspaceStatus::Initialized)
+            .with_manifest(Manifest::new(repos));
+
+        let result = StatusCheckUseCase::new(StatusCheckConfig::default())
+            .execute(&workspace)
+            .await
+            .unwrap();
+
+        let app = &result.repositories[0];
+        assert_eq!(app.dest, "app");
+        assert_eq!(app.untracked_files, 0);
+        assert_eq!(app.state, RepositoryState::Clean);
+
+        let web = &result.repositories[1];
+        assert_eq!(web.dest, "web");
+        assert_eq!(web.untracked_files, 1);
+        assert_eq!(web.state, RepositoryState::Dirty);
+        assert_eq!(result.dirty_count, 1);
+    }
+
+    #[test]
+    fn test_untracked_breakdown_groups_by_directory_and_extension() {
+        let breakdown = untracked_breakdown(&[
+            "build/out/app.o",
+            "build/app",
+            "debug.log",
+            "trace.log",
+            "src/new.rs",
+            "Makefile.local",
+            "NOTES",
+        ]);
+        let expected: BTreeMap<String, usize> = [
+            ("build/", 2),
+            ("*.log", 2),
+            ("src/", 1),
+            ("*.local", 1),
+            ("NOTES", 1),
+        ]
+        .into_iter()
+        .map(|(key, count)| (key.to_string(), count))
+        .collect();
+        assert_eq!(breakdown, expected);
+    }
+
+    #[tokio::test]
+    async fn test_untracked_summary_counts_untracked_per_type() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = single_repo_workspace(temp_dir.path());
+        let app = temp_dir.path().join("app");
+        std::fs::create_dir_all(app.join("build/obj")).unwrap();
+        std::fs::write(app.join("build/obj/a.o"), "o").unwrap();
+        std::fs::write(app.join("build/obj/b.o"), "o").unwrap();
+        std::fs::write(app.join("build/app"), "bin").unwrap();
+        std::fs::create_dir_all(app.join("src")).unwrap();
+        std::fs::write(app.join("src/feature.rs"), "fn main() {}").unwrap();
+        std::fs::write(app.join("run.log"), "log").unwrap();
+        std::fs::write(app.join("ignored.tmp"), "tmp").unwrap();
+        std::fs::write(temp_dir.path().join(".wmgrignore"), "*.tmp\n").unwrap();
+
+        let config = StatusCheckConfig::default().with_untracked_summary(true);
+        let result = StatusCheckUseCase::new(config)
+            .execute(&workspace)
+            .await
+            .unwrap();
+
+        let status = &result.repositories[0];
+        assert_eq!(status.state, RepositoryState::Dirty);
+        assert_eq!(status.untracked_files, 5);
+        let breakdown = status.untracked_breakdown.as_ref().unwrap();
+        assert_eq!(breakdown.get("build/"), Some(&3));
+        assert_eq!(breakdown.get("src/"), Some(&1));
+        assert_eq!(breakdown.get("*.log"), Some(&1));
+        assert_eq!(breakdown.len(), 3);
+
+        // 指定しない場合は内訳を計算せず、シリアライズもしない
+        let result = StatusCheckUseCase::new(StatusCheckConfig::default())
+            .execute(&workspace)
+            .await
+            .unwrap();
+        assert!(result.repositories[0].untracked_breakdown.is_none());
+        let json = serde_json::to_string(&result).unwrap();
+        assert!(!json.contains("untracked_breakdown"));
+    }
+
+    #[tokio::test]
+    async fn test_extended_status_reports_repository_health() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = single_repo_workspace(temp_dir.path());
+        let app = temp_dir.path().join("app");
+        let repo = git2::Repository::open(&app).unwrap();
+        let head = repo.head().unwrap().peel_to_commit().unwrap();
+        repo.branch("feature", &head, false).unwrap();
+        std::fs::write(app.join(".git/FETCH_HEAD"), "").unwrap();
+
+        let config = StatusCheckConfig::default().with_extended(true);
+        let result = StatusCheckUseCase::new(config)
+            .execute(&workspace)
+            .await
+            .unwrap();
+
+        let health = result.repositories[0].health.as_ref().unwrap();
+        assert_eq!(health.local_branches, 2);
+        assert!(health.disk_size_bytes > 0);
+        let last_fetch = health.last_fetch.unwrap();
+        assert!((Utc::now() - last_fetch).num_seconds() < 60);
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["repositories"][0]["health"]["local_branches"], 2);
+        assert!(json["repositories"][0]["health"]["last_fetch"].is_string());
+
+        // 指定しない場合は調べず、シリアライズもしない
+        let result = StatusCheckUseCase::new(StatusCheckConfig::default())
+            .execute(&workspace)
+            .await
+            .unwrap();
+        assert!(result.repositories[0].health.is_none());
+        let json = serde_json::to_string(&result).unwrap();
+        assert!(!json.contains("health"));
+    }
+
+    #[tokio::test]
+    async fn test_clean_repository_has_no_untracked_breakdown() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = single_repo_workspace(temp_dir.path());
+
+        let config = StatusCheckConfig::default().with_untracked_summary(true);
+        let result = StatusCheckUseCase::new(config)
+            .execute(&workspace)
+            .await
+            .unwrap();
+        assert_eq!(result.repositories[0].state, RepositoryState::Clean);
+        assert!(result.repositories[0].untracked_breakdown.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_local_only_repo_status() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace_root = temp_dir.path();
+
+        // URLのないリポジトリもワークスペース内のディレクトリとして状態を確認する
+        init_repo_with_commit(&workspace_root.join("scratch"));
+        std::fs::write(workspace_root.join("scratch/notes.txt"), "notes").unwrap();
+
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let workspace = Workspace::new(workspace_root.to_path_buf(), workspace_config)
+            .with_status(crate::domain::entities::workspace::WorkspaceStatus::Initialized)
+            .with_manifest(Manifest::new(vec![ManifestRepo::new("", "scratch")]));
+
+        let result = StatusCheckUseCase::new(StatusCheckConfig::default())
+            .execute(&workspace)
+            .await
+            .unwrap();
+
+        let scratch = &result.repositories[0];
+        assert_eq!(scratch.dest, "scratch");
+        assert_eq!(scratch.untracked_files, 1);
+        assert_eq!(scratch.state, RepositoryState::Dirty);
+    }
+
+    /// `app`リポジトリだけを持つワークスペース
+    fn single_repo_workspace(root: &std::path::Path) -> Workspace {
+        init_repo_with_commit(&root.join("app"));
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        Workspace::new(root.to_path_buf(), workspace_config)
+            .with_status(crate::domain::entities::workspace::WorkspaceStatus::Initialized)
+            .with_manifest(Manifest::new(vec![ManifestRepo::new(
+                "https://example.com/app.git",
+                "app",
+            )]))
+    }
+
+    /// 指定した時刻のコミットを追加（最初のコミットならリポジトリも作成）
+    fn commit_at(path: &std::path::Path, message: &str, seconds: i64) -> git2::Oid {
+        let repo = git2::Repository::open(path)
+            .or_else(|_| git2::Repository::init(path))
+            .unwrap();
+        std::fs::write(path.join("CHANGES.md"), message).unwrap();
+        let mut index = repo.index().unwrap();
+        index.add_path(std::path::Path::new("CHANGES.md")).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let sig =
+            git2::Signature::new("Test", "test@example.com", &git2::Time::new(seconds, 0)).unwrap();
+        let parents = match repo.head() {
+            Ok(head) => vec![head.peel_to_commit().unwrap()],
+            Err(_) => vec![],
+        };
+        let parents: Vec<&git2::Commit> = parents.iter().collect();
+        repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &parents)
+            .unwrap()
+    }
+
+    fn tag_head(path: &std::path::Path, name: &str) {
+        let repo = git2::Repository::open(path).unwrap();
+        let head = repo.head().unwrap().peel_to_commit().unwrap();
+        repo.tag_lightweight(name, head.as_object(), false).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_changed_since_revision_and_date() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let release_time = 1_700_000_000;
+
+        // v1.0以降にコミットがない
+        commit_at(&root.join("released"), "initial", release_time - 100);
+        tag_head(&root.join("released"), "v1.0");
+
+        // v1.0以降に2コミット
+        commit_at(&root.join("advanced"), "initial", release_time - 100);
+        tag_head(&root.join("advanced"), "v1.0");
+        commit_at(&root.join("advanced"), "fix", release_time + 100);
+        commit_at(&root.join("advanced"), "feature", release_time + 200);
+
+        // v1.0タグがない
+        commit_at(&root.join("untagged"), "initial", release_time + 300);
+
+        let repos = ["advanced", "released", "untagged"]
+            .iter()
+            .map(|dest| ManifestRepo::new(format!("https://example.com/{}.git", dest), *dest))
+            .collect();
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let workspace = Workspace::new(root.to_path_buf(), workspace_config)
+            .with_status(crate::domain::entities::workspace::WorkspaceStatus::Initialized)
+            .with_manifest(Manifest::new(repos));
+
+        let run = |config: StatusCheckConfig| {
+            let workspace = workspace.clone();
+            async move {
+                StatusCheckUseCase::new(config)
+                    .execute(&workspace)
+                    .await
+                    .unwrap()
+                    .repositories
+                    .into_iter()
+                    .map(|r| (r.dest, r.commits_since))
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        let by_tag = StatusCheckConfig::default()
+            .with_changed_since(ChangedSince::Revision("v1.0".to_string()));
+        assert_eq!(
+            run(by_tag.clone()).await,
+            vec![
+                ("advanced".to_string(), Some(2)),
+                ("released".to_string(), Some(0)),
+                ("untagged".to_string(), None),
+            ]
+        );
+        assert_eq!(
+            run(by_tag.with_only_changed(true)).await,
+            vec![("advanced".to_string(), Some(2))]
+        );
+
+        let by_date = StatusCheckConfig::default()
+            .with_changed_since(ChangedSince::Date(release_time))
+            .with_only_changed(true);
+        assert_eq!(
+            run(by_date).await,
+            vec![
+                ("advanced".to_string(), Some(2)),
+                ("untagged".to_string(), Some(1)),
+            ]
+        );
+
+        // 基準を指定しなければ数えない
+        assert!(run(StatusCheckConfig::default())
+            .await
+            .iter()
+            .all(|(_, count)| count.is_none()));
+    }
+
+    #[tokio::test]
+    async fn test_ahead_behind_against_chosen_remote() {
+        let temp_dir = TempDir::new().unwrap();
+        let app = temp_dir.path().join("app");
+        let first = commit_at(&app, "first", 100);
+        let second = commit_at(&app, "second", 200);
+        let third = commit_at(&app, "third", 300);
+
+        // originは1つ目、upstreamは3つ目のコミットを指し、ローカルは2つ目のコミット
+        let repo = git2::Repository::open(&app).unwrap();
+        let branch = repo.head().unwrap().shorthand().unwrap().to_string();
+        for (remote, oid) in [("origin", first), ("upstream", third)] {
+            repo.remote(remote, &format!("https://example.com/{}.git", remote))
+                .unwrap();
+            let name = format!("refs/remotes/{}/{}", remote, branch);
+            repo.reference(&name, oid, false, "test").unwrap();
+        }
+        repo.reference(&format!("refs/heads/{}", branch), second, true, "test")
+            .unwrap();
+        repo.checkout_head(Some(git2::build::CheckoutBuilder::new().force()))
+            .unwrap();
+
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let workspace = Workspace::new(temp_dir.path().to_path_buf(), workspace_config)
+            .with_status(crate::domain::entities::workspace::WorkspaceStatus::Initialized)
+            .with_manifest(Manifest::new(vec![ManifestRepo::new(
+                "https://example.com/app.git",
+                "app",
+            )]));
+        let run = |remote: &str| {
+            let config = StatusCheckConfig::default().with_ahead_behind_remote(remote);
+            let workspace = workspace.clone();
+            async move {
+                StatusCheckUseCase::new(config)
+                    .execute(&workspace)
+                    .await
+                    .unwrap()
+                    .repositories
+                    .remove(0)
+            }
+        };
+
+        let status = run("origin").await;
+        assert_eq!((status.commits_ahead, status.commits_behind), (1, 0));
+        assert_eq!(status.ahead_behind_remote.as_deref(), Some("origin"));
+
+        let status = run("upstream").await;
+        assert_eq!((status.commits_ahead, status.commits_behind), (0, 1));
+        let json = serde_json::to_value(&status).unwrap();
+        assert_eq!(json["ahead_behind_remote"], "upstream");
+
+        // 存在しないリモートはエラーとして報告する
+        let status = run("missing").await;
+        assert_eq!(status.state, RepositoryState::Error);
+        assert_eq!(
+            status.error_message.as_deref(),
+            Some("Remote not found: missing")
+        );
+    }
+
     #[test]
     fn test_target_repositories_determination() {
         let manifest = Manifest::new(vec![]);
@@ -563,4 +1820,106 @@ mod tests {
         let result = use_case.determine_target_repositories(&workspace);
         assert!(result.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_fetch_refreshes_behind_count() {
+        let temp_dir = TempDir::new().unwrap();
+        let upstream = temp_dir.path().join("upstream");
+        let first = commit_at(&upstream, "first", 100);
+        let workspace_root = temp_dir.path().join("workspace");
+        git2::Repository::clone(upstream.to_str().unwrap(), workspace_root.join("app")).unwrap();
+        commit_at(&upstream, "second", 200);
+
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let workspace = Workspace::new(workspace_root.clone(), workspace_config)
+            .with_status(crate::domain::entities::workspace::WorkspaceStatus::Initialized)
+            .with_manifest(Manifest::new(vec![ManifestRepo::new(
+                "https://example.com/app.git",
+                "app",
+            )]));
+        let run = |fetch: bool| {
+            let config = StatusCheckConfig::default().with_fetch(fetch);
+            let workspace = workspace.clone();
+            async move {
+                StatusCheckUseCase::new(config)
+                    .execute(&workspace)
+                    .await
+                    .unwrap()
+                    .repositories
+                    .remove(0)
+            }
+        };
+
+        // fetchしなければリモートの新しいコミットは見えない
+        assert_eq!(run(false).await.commits_behind, 0);
+
+        let status = run(true).await;
+        assert_eq!(status.state, RepositoryState::Clean);
+        assert_eq!(status.commits_behind, 1);
+
+        // 作業ツリーとブランチは動かない
+        let app = git2::Repository::open(workspace_root.join("app")).unwrap();
+        assert_eq!(app.head().unwrap().target(), Some(first));
+        assert_eq!(
+            std::fs::read_to_string(workspace_root.join("app/CHANGES.md")).unwrap(),
+            "first"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_summary_counts_match_repositories() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut repos = Vec::new();
+        for dest in ["clean-1", "clean-2", "dirty"] {
+            init_repo_with_commit(&temp_dir.path().join(dest));
+            repos.push(ManifestRepo::new(
+                format!("https://example.com/{}.git", dest),
+                dest,
+            ));
+        }
+        std::fs::write(temp_dir.path().join("dirty/README.md"), "changed").unwrap();
+        repos.push(ManifestRepo::new("https://example.com/missing.git", "missing"));
+
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let workspace = Workspace::new(temp_dir.path().to_path_buf(), workspace_config)
+            .with_status(crate::domain::entities::workspace::WorkspaceStatus::Initialized)
+            .with_manifest(Manifest::new(repos));
+
+        let result = StatusCheckUseCase::new(StatusCheckConfig::default())
+            .execute(&workspace)
+            .await
+            .unwrap();
+        assert_eq!(
+            result.summary,
+            StatusSummary {
+                total: 4,
+                clean: 2,
+                dirty: 1,
+                missing: 1,
+                ..StatusSummary::default()
+            }
+        );
+        assert_eq!(
+            result.summary,
+            StatusSummary::from_repositories(&result.repositories)
+        );
+        assert_eq!(result.summary.to_string(), "2 clean, 1 dirty, 1 missing");
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["summary"]["total"], 4);
+        assert_eq!(json["summary"]["wrong_branch"], 0);
+    }
+
+    #[test]
+    fn test_summary_display() {
+        assert_eq!(StatusSummary::default().to_string(), "no repositories");
+
+        let summary = StatusSummary {
+            total: 3,
+            wrong_branch: 1,
+            error: 2,
+            ..StatusSummary::default()
+        };
+        assert_eq!(summary.to_string(), "1 on the wrong branch, 2 errors");
+    }
 }
@@ -1,12 +1,34 @@
+use crate::application::services::manifest_service::{
+    ManifestCacheStats, ManifestProcessingOptions, ManifestService,
+};
+use crate::application::use_cases::foreach_command::ForeachCommandUseCase;
+use crate::common::cancellation::CancellationToken;
+use crate::common::error::WmgrError;
+use crate::common::instrument::instrument_repo;
+use crate::common::progress::{ProgressEvent, ProgressReporter, RepoOutcome};
 use crate::domain::entities::{
-    manifest::ManifestRepo,
-    workspace::{Workspace, WorkspaceStatus},
+    manifest::{ManifestRepo, SyncStrategy},
+    repo_selector::{RepoSelector, RepoSelectorError},
+    workspace::{DestPathError, Workspace, WorkspaceStatus},
 };
 use crate::domain::value_objects::branch_name::BranchName;
+use crate::domain::value_objects::git_url::GitUrl;
 use crate::domain::value_objects::scm_type::ScmType;
-use crate::infrastructure::scm::{ScmFactory, ScmError};
-use std::path::PathBuf;
+use crate::infrastructure::filesystem::backup::{backup_name_prefix, DEFAULT_BACKUP_DIR};
+use crate::infrastructure::filesystem::managed_repos::{ManagedRepos, ManagedReposError};
+use crate::infrastructure::filesystem::manifest_store::ManifestStore;
+use crate::infrastructure::filesystem::sync_journal::{SyncJournal, SyncJournalError};
+use crate::infrastructure::git::lfs;
+use crate::infrastructure::process::{CommandExecutor, ExecutionConfig};
+use crate::infrastructure::scm::{ScmError, ScmFactory, ScmOperations};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::sync::Mutex;
+use tracing::info;
 
 /// SyncRepositories関連のエラー
 #[derive(Debug, Error)]
@@ -26,6 +48,15 @@ pub enum SyncRepositoriesError {
     #[error("Branch sync failed for repo '{repo}': {error}")]
     BranchSyncFailed { repo: String, error: String },
 
+    #[error("Rebase conflict in repo '{repo}': {files}")]
+    RebaseConflict { repo: String, files: String },
+
+    #[error("Merge conflict in repo '{repo}': {files}")]
+    MergeConflict { repo: String, files: String },
+
+    #[error("Submodule update failed for repo '{repo}': {error}")]
+    SubmoduleUpdateFailed { repo: String, error: String },
+
     #[error("Git operation failed: {0}")]
     GitOperationFailed(String),
 
@@ -43,6 +74,80 @@ pub enum SyncRepositoriesError {
 
     #[error("File path error: {0}")]
     FilePathError(#[from] crate::domain::value_objects::file_path::FilePathError),
+
+    #[error("Invalid repository destination: {0}")]
+    InvalidDest(#[from] DestPathError),
+
+    #[error("Sync journal error: {0}")]
+    JournalFailed(#[from] SyncJournalError),
+
+    #[error("Repository selection failed: {0}")]
+    SelectionFailed(#[from] RepoSelectorError),
+
+    #[error("{hook} hook failed for repo '{repo}': {error}")]
+    HookFailed {
+        repo: String,
+        hook: String,
+        error: String,
+    },
+
+    #[error("Reference directory not found: {0}")]
+    ReferenceNotFound(String),
+
+    #[error("Workspace {hook} hook failed: {error}")]
+    WorkspaceHookFailed {
+        hook: String,
+        command: String,
+        error: String,
+    },
+}
+
+impl From<SyncRepositoriesError> for WmgrError {
+    fn from(error: SyncRepositoriesError) -> Self {
+        match &error {
+            SyncRepositoriesError::WorkspaceNotInitialized(path) => {
+                WmgrError::workspace_not_initialized(path)
+            }
+            SyncRepositoriesError::ManifestUpdateFailed(_) => {
+                WmgrError::manifest_error_with_source(error.to_string(), None, error)
+            }
+            SyncRepositoriesError::RemoteUpdateFailed { repo, .. }
+            | SyncRepositoriesError::BranchSyncFailed { repo, .. }
+            | SyncRepositoriesError::RebaseConflict { repo, .. }
+            | SyncRepositoriesError::MergeConflict { repo, .. }
+            | SyncRepositoriesError::SubmoduleUpdateFailed { repo, .. }
+            | SyncRepositoriesError::HookFailed { repo, .. } => {
+                let repo = repo.clone();
+                WmgrError::repository_error_with_source(error.to_string(), Some(repo), error)
+            }
+            SyncRepositoriesError::RepositoryCloneFailed(_)
+            | SyncRepositoriesError::GitOperationFailed(_)
+            | SyncRepositoriesError::ScmOperationFailed(_) => {
+                WmgrError::repository_error_with_source(error.to_string(), None, error)
+            }
+            SyncRepositoriesError::GitUrlError(_) => {
+                WmgrError::validation_error("url", error.to_string(), None)
+            }
+            SyncRepositoriesError::BranchNameError(_) => {
+                WmgrError::validation_error("branch", error.to_string(), None)
+            }
+            SyncRepositoriesError::FilePathError(_) | SyncRepositoriesError::InvalidDest(_) => {
+                WmgrError::validation_error("dest", error.to_string(), None)
+            }
+            SyncRepositoriesError::SelectionFailed(_) => {
+                WmgrError::validation_error("repo", error.to_string(), None)
+            }
+            SyncRepositoriesError::ReferenceNotFound(path) => {
+                WmgrError::validation_error("reference", error.to_string(), Some(path.clone()))
+            }
+            SyncRepositoriesError::WorkspaceHookFailed { command, .. } => {
+                WmgrError::command_error(error.to_string(), command.clone(), None)
+            }
+            SyncRepositoriesError::IoError(_) | SyncRepositoriesError::JournalFailed(_) => {
+                WmgrError::internal_error_with_source(error.to_string(), error)
+            }
+        }
+    }
 }
 
 /// リポジトリ同期の設定
@@ -51,6 +156,15 @@ pub struct SyncRepositoriesConfig {
     /// 特定のグループのみを同期するか（Noneの場合は全て）
     pub groups: Option<Vec<String>>,
 
+    /// いずれかを持つリポジトリのみを対象にするタグ（空の場合は絞り込まない）
+    pub tags: Vec<String>,
+
+    /// ひとつでも持つリポジトリを対象から除外するタグ
+    pub exclude_tags: Vec<String>,
+
+    /// destに一致させるglobパターン（空の場合は絞り込まない）
+    pub repo_patterns: Vec<String>,
+
     /// 強制的に同期するか（ローカル変更を無視）
     pub force: bool,
 
@@ -58,6 +172,9 @@ pub struct SyncRepositoriesConfig {
     pub no_correct_branch: bool,
 
     /// 並列実行の最大数（Noneの場合はCPU数）
+    ///
+    /// 同期ループは現在リポジトリを1つずつ処理するため、この値は子ワークスペースへ
+    /// 引き継がれるのみで並列度には影響しない。
     pub parallel_jobs: Option<usize>,
 
     /// 詳細ログを出力するか
@@ -66,6 +183,47 @@ pub struct SyncRepositoriesConfig {
     /// 子ディレクトリのワークスペースも再帰的に同期するか
     pub recursive: bool,
 
+    /// Gitリポジトリのサブモジュールを再帰的に初期化・更新するか
+    /// （マニフェストのリポジトリごとの`submodules`が優先）
+    pub recurse_submodules: bool,
+
+    /// Git LFSを使うリポジトリで`git lfs pull`を実行するか
+    /// （マニフェストのリポジトリごとの`lfs`が優先）
+    pub lfs: bool,
+
+    /// 新しくクローンするGitリポジトリに使う部分クローンのフィルタ（`blob:none`など）
+    /// （マニフェストのリポジトリごとの`partial_clone`が優先）
+    pub clone_filter: Option<String>,
+
+    /// 新しくクローンするGitリポジトリがオブジェクトを共有するミラーのディレクトリ
+    /// （`<dir>/<リポジトリパス>.git`を参照、マニフェストのリポジトリごとの`reference`が優先）
+    pub reference: Option<PathBuf>,
+
+    /// 分岐時にfast-forwardの代わりにupstreamへリベースするか（マニフェストの戦略より優先）
+    pub rebase: bool,
+
+    /// リモートの先端と一致しているリポジトリのfetch/mergeを省略するか
+    pub only_changed: bool,
+
+    /// 前回中断した同期を再開し、完了済みのリポジトリを省略するか
+    pub resume: bool,
+
+    /// この時間内にfetchされた（`.git/FETCH_HEAD`が更新された）Gitリポジトリを省略する
+    pub since: Option<Duration>,
+
+    /// 再読み込みの前にマニフェストリポジトリをfetchしてfast-forwardするか
+    pub update_manifest: bool,
+
+    /// 記録されたマニフェストブランチの代わりに、この実行中だけ読み込むマニフェストブランチ
+    pub manifest_branch: Option<String>,
+
+    /// 同期中に読み込むマニフェスト（更新後の再読み込み・子ワークスペース）の検証の警告をエラーにするか
+    pub strict_manifest: bool,
+
+    /// フック（リポジトリのpost_clone/post_sync、ワークスペースのpre_sync/post_sync）の
+    /// 失敗で同期全体を失敗させるか
+    pub hooks_fatal: bool,
+
     /// クレデンシャルプロファイル名（CLIから）
     pub credential_profile: Option<String>,
 
@@ -77,11 +235,26 @@ impl Default for SyncRepositoriesConfig {
     fn default() -> Self {
         Self {
             groups: None,
+            tags: Vec::new(),
+            exclude_tags: Vec::new(),
+            repo_patterns: Vec::new(),
             force: false,
             no_correct_branch: false,
             parallel_jobs: None,
             verbose: false,
             recursive: true,
+            recurse_submodules: false,
+            lfs: false,
+            clone_filter: None,
+            reference: None,
+            rebase: false,
+            only_changed: false,
+            resume: false,
+            since: None,
+            update_manifest: false,
+            manifest_branch: None,
+            strict_manifest: false,
+            hooks_fatal: false,
             credential_profile: None,
             credential_file: None,
         }
@@ -94,6 +267,32 @@ impl SyncRepositoriesConfig {
         self
     }
 
+    /// 対象にするタグを設定
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// 除外するタグを設定
+    pub fn with_exclude_tags(mut self, exclude_tags: Vec<String>) -> Self {
+        self.exclude_tags = exclude_tags;
+        self
+    }
+
+    /// destに一致させるglobパターンを設定
+    pub fn with_repo_patterns(mut self, repo_patterns: Vec<String>) -> Self {
+        self.repo_patterns = repo_patterns;
+        self
+    }
+
+    /// グループ・タグ・globの指定から対象リポジトリの選択条件を作成
+    pub fn repo_selector(&self) -> RepoSelector {
+        RepoSelector::new(self.groups.clone())
+            .with_tags(self.tags.clone())
+            .with_exclude_tags(self.exclude_tags.clone())
+            .with_repo_patterns(self.repo_patterns.clone())
+    }
+
     pub fn with_force(mut self, force: bool) -> Self {
         self.force = force;
         self
@@ -118,6 +317,71 @@ impl SyncRepositoriesConfig {
         self.recursive = recursive;
         self
     }
+
+    pub fn with_recurse_submodules(mut self, recurse_submodules: bool) -> Self {
+        self.recurse_submodules = recurse_submodules;
+        self
+    }
+
+    pub fn with_lfs(mut self, lfs: bool) -> Self {
+        self.lfs = lfs;
+        self
+    }
+
+    /// 部分クローンのフィルタを設定
+    pub fn with_clone_filter(mut self, clone_filter: impl Into<String>) -> Self {
+        self.clone_filter = Some(clone_filter.into());
+        self
+    }
+
+    /// オブジェクトを共有するミラーのディレクトリを設定
+    pub fn with_reference(mut self, reference: impl Into<PathBuf>) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    pub fn with_rebase(mut self, rebase: bool) -> Self {
+        self.rebase = rebase;
+        self
+    }
+
+    /// この時間内にfetchされたリポジトリを省略するよう設定
+    pub fn with_since(mut self, since: Duration) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    pub fn with_only_changed(mut self, only_changed: bool) -> Self {
+        self.only_changed = only_changed;
+        self
+    }
+
+    pub fn with_resume(mut self, resume: bool) -> Self {
+        self.resume = resume;
+        self
+    }
+
+    pub fn with_update_manifest(mut self, update_manifest: bool) -> Self {
+        self.update_manifest = update_manifest;
+        self
+    }
+
+    /// この実行中だけ読み込むマニフェストブランチを設定
+    pub fn with_manifest_branch(mut self, manifest_branch: impl Into<String>) -> Self {
+        self.manifest_branch = Some(manifest_branch.into());
+        self
+    }
+
+    /// 同期中に読み込むマニフェストの検証の警告をエラーにするか設定（`--strict-manifest`）
+    pub fn with_strict_manifest(mut self, strict_manifest: bool) -> Self {
+        self.strict_manifest = strict_manifest;
+        self
+    }
+
+    pub fn with_hooks_fatal(mut self, hooks_fatal: bool) -> Self {
+        self.hooks_fatal = hooks_fatal;
+        self
+    }
 }
 
 /// 同期操作の結果
@@ -135,8 +399,26 @@ pub struct SyncResult {
     /// スキップされたリポジトリの数（エラーや設定により）
     pub skipped_count: usize,
 
+    /// 既にリモートの先端と一致していたリポジトリの数（--only-changed）
+    pub up_to_date_count: usize,
+
+    /// 前回の同期で完了済みのため省略したリポジトリの数（--continue）
+    pub resumed_count: usize,
+
+    /// 最近fetchされたため最新とみなして省略したリポジトリの数（--since）
+    pub recent_count: usize,
+
+    /// リポジトリではないディレクトリ（中断されたクローンなど）をクローンし直した数
+    pub recovered_count: usize,
+
     /// 発生したエラーのリスト
     pub errors: Vec<String>,
+
+    /// 同期は継続したが利用者に知らせる警告のリスト
+    pub warnings: Vec<String>,
+
+    /// 中断により同期が完了しなかったリポジトリ（処理中だったものと未着手のもの）
+    pub interrupted: Vec<String>,
 }
 
 impl SyncResult {
@@ -146,7 +428,13 @@ impl SyncResult {
             cloned_count: 0,
             updated_count: 0,
             skipped_count: 0,
+            up_to_date_count: 0,
+            resumed_count: 0,
+            recent_count: 0,
+            recovered_count: 0,
             errors: Vec::new(),
+            warnings: Vec::new(),
+            interrupted: Vec::new(),
         }
     }
 
@@ -154,12 +442,45 @@ impl SyncResult {
         self.errors.push(error);
     }
 
+    pub fn add_warning(&mut self, warning: String) {
+        self.warnings.push(warning);
+    }
+
     pub fn is_success(&self) -> bool {
-        self.errors.is_empty()
+        self.errors.is_empty() && !self.is_interrupted()
+    }
+
+    /// 同期が中断されたか
+    pub fn is_interrupted(&self) -> bool {
+        !self.interrupted.is_empty()
+    }
+
+    /// ワークスペースのpost_syncフックに渡す件数の環境変数
+    pub fn environment(&self) -> HashMap<String, String> {
+        [
+            ("WMGR_SYNC_SYNCED", self.synced_count),
+            ("WMGR_SYNC_CLONED", self.cloned_count),
+            ("WMGR_SYNC_UPDATED", self.updated_count),
+            ("WMGR_SYNC_UP_TO_DATE", self.up_to_date_count),
+            ("WMGR_SYNC_RESUMED", self.resumed_count),
+            ("WMGR_SYNC_RECENT", self.recent_count),
+            ("WMGR_SYNC_RECOVERED", self.recovered_count),
+            ("WMGR_SYNC_SKIPPED", self.skipped_count),
+            ("WMGR_SYNC_ERRORS", self.errors.len()),
+        ]
+        .into_iter()
+        .map(|(key, count)| (key.to_string(), count.to_string()))
+        .collect()
     }
 
     pub fn total_count(&self) -> usize {
-        self.cloned_count + self.updated_count + self.skipped_count
+        self.cloned_count
+            + self.recovered_count
+            + self.updated_count
+            + self.up_to_date_count
+            + self.resumed_count
+            + self.recent_count
+            + self.skipped_count
     }
 }
 
@@ -167,12 +488,54 @@ impl SyncResult {
 pub struct SyncRepositoriesUseCase {
     /// 同期設定
     config: SyncRepositoriesConfig,
+
+    /// 進捗イベントの送信先（子ワークスペースと共有）
+    progress: ProgressReporter,
+
+    /// 同期の中断要求（子ワークスペースと共有）
+    cancellation: CancellationToken,
+
+    /// マニフェストの読み込みに使うストア（子ワークスペースと共有し、解析結果のキャッシュを再利用する）
+    manifest_store: Arc<Mutex<ManifestStore>>,
 }
 
 impl SyncRepositoriesUseCase {
     /// 新しいSyncRepositoriesUseCaseインスタンスを作成
     pub fn new(config: SyncRepositoriesConfig) -> Self {
-        Self { config }
+        let manifest_service = ManifestService::new(
+            ManifestProcessingOptions::from_env().with_strict(config.strict_manifest),
+        );
+        let manifest_store =
+            ManifestStore::with_service_and_options(manifest_service, Default::default());
+        Self {
+            config,
+            progress: ProgressReporter::default(),
+            cancellation: CancellationToken::default(),
+            manifest_store: Arc::new(Mutex::new(manifest_store)),
+        }
+    }
+
+    /// リポジトリごとの進捗イベントを`sender`に送る
+    ///
+    /// 各リポジトリ（子ワークスペースのものを含む）について`RepoStarted`と
+    /// `RepoFinished`を1回ずつ送る。
+    pub fn with_progress(mut self, sender: UnboundedSender<ProgressEvent>) -> Self {
+        self.progress = ProgressReporter::new(sender);
+        self
+    }
+
+    /// `cancellation`が中断されたら、処理中のリポジトリを片付けて同期を止める
+    ///
+    /// 処理中だったリポジトリと未着手のリポジトリは`SyncResult::interrupted`に記録し、
+    /// 途中までクローンしたディレクトリは削除する。子ワークスペースとpost_syncフックは実行しない。
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> Self {
+        self.cancellation = cancellation;
+        self
+    }
+
+    /// 子ワークスペースを含め、この同期で読み込んだマニフェストのキャッシュ統計（デバッグ用）
+    pub async fn manifest_cache_stats(&self) -> ManifestCacheStats {
+        self.manifest_store.lock().await.cache_stats()
     }
 
     /// リポジトリ同期を実行
@@ -182,29 +545,113 @@ impl SyncRepositoriesUseCase {
     ) -> Result<SyncResult, SyncRepositoriesError> {
         // 1. ワークスペースの初期化チェック
         self.check_workspace_initialized(workspace)?;
+        if let Some(base) = &workspace.config.singular_remote {
+            GitUrl::validate_base_url(base)?;
+        }
+        if let Some(reference) = &self.config.reference {
+            if !reference.is_dir() {
+                return Err(SyncRepositoriesError::ReferenceNotFound(
+                    reference.display().to_string(),
+                ));
+            }
+        }
 
-        // 2. マニフェストの更新
+        // 2. マニフェストの更新（更新前のdestは管理対象の記録に残す）
+        let previous_dests: Vec<String> = workspace
+            .manifest
+            .as_ref()
+            .map(|manifest| {
+                manifest
+                    .repos
+                    .iter()
+                    .map(|repo| repo.dest.clone())
+                    .collect()
+            })
+            .unwrap_or_default();
         self.update_manifest(workspace).await?;
 
         // 3. 同期対象リポジトリの決定
         let target_repos = self.determine_target_repositories(workspace)?;
 
-        // 4. リポジトリの同期実行
+        let (pre_sync, post_sync) = workspace
+            .manifest
+            .as_ref()
+            .map(|manifest| (manifest.pre_sync.clone(), manifest.post_sync.clone()))
+            .unwrap_or_default();
         let mut result = SyncResult::new();
+
+        // 4. ワークスペースのpre_syncフック（最初のクローンより前に実行）
+        if let Some(command) = &pre_sync {
+            self.run_workspace_hook("pre_sync", command, workspace, None, &mut result)
+                .await?;
+        }
+
+        // 5. リポジトリの同期実行
         self.sync_repositories(&target_repos, workspace, &mut result)
             .await?;
+        if result.is_interrupted() {
+            return Ok(result);
+        }
 
-        // 5. 再帰的な子ワークスペースの同期（recursive フラグが有効な場合）
+        // 6. 再帰的な子ワークスペースの同期（recursive フラグが有効な場合）
         if self.config.recursive {
             self.sync_child_workspaces(workspace, &mut result).await?;
         }
 
-        // 6. ワークスペース状態の更新
+        // 7. ワークスペースのpost_syncフック（最終的な件数を環境変数で渡す）
+        if let Some(command) = &post_sync {
+            let summary = result.clone();
+            self.run_workspace_hook(
+                "post_sync",
+                command,
+                workspace,
+                Some(&summary),
+                &mut result,
+            )
+            .await?;
+        }
+
+        // 8. ワークスペース状態と管理対象のリポジトリの記録の更新
         workspace.status = WorkspaceStatus::Initialized;
+        if let Err(e) = Self::record_managed_repos(workspace, &previous_dests) {
+            result.add_warning(e.to_string());
+        }
 
         Ok(result)
     }
 
+    /// このワークスペースで管理しているリポジトリのdestを記録（`sync --prune`が参照）
+    ///
+    /// 同期前後のマニフェストのdestのうちディレクトリがあるものを追加し、マニフェストになく
+    /// ディレクトリもなくなったdestは外す。
+    fn record_managed_repos(
+        workspace: &Workspace,
+        previous_dests: &[String],
+    ) -> Result<(), ManagedReposError> {
+        let current_dests: Vec<&String> = workspace
+            .manifest
+            .as_ref()
+            .map(|manifest| manifest.repos.iter().map(|repo| &repo.dest).collect())
+            .unwrap_or_default();
+
+        let mut managed = ManagedRepos::load(&workspace.wmgr_dir())?;
+        let stale: Vec<String> = managed
+            .dests
+            .iter()
+            .filter(|dest| !workspace.resolve_dest(dest).is_ok_and(|path| path.exists()))
+            .cloned()
+            .collect();
+        for dest in stale {
+            managed.remove(&dest);
+        }
+        for dest in previous_dests.iter().chain(current_dests) {
+            if workspace.resolve_dest(dest).is_ok_and(|path| path.exists()) {
+                managed.insert(dest);
+            }
+        }
+        managed.save()
+    }
+
     /// ワークスペースが初期化済みかチェック
     fn check_workspace_initialized(
         &self,
@@ -223,8 +670,35 @@ impl SyncRepositoriesUseCase {
         &self,
         workspace: &mut Workspace,
     ) -> Result<(), SyncRepositoriesError> {
-        // ローカルファーストアプローチ: マニフェストファイルの再読み込みのみ
-        let manifest_file = workspace.manifest_file_path();
+        // 別のマニフェストブランチが指定された場合は、そのブランチのマニフェストを読み込む
+        if let Some(branch) = &self.config.manifest_branch {
+            let branch = BranchName::new(branch)?;
+            let manifest_repo = workspace.manifest_repo_path().ok_or_else(|| {
+                SyncRepositoriesError::ManifestUpdateFailed(format!(
+                    "Cannot use manifest branch '{}': the workspace has no manifest repository",
+                    branch
+                ))
+            })?;
+            let manifest = self
+                .read_manifest_at_branch(workspace, &manifest_repo, &branch)
+                .await?;
+            workspace.manifest = Some(manifest);
+            info!("Manifest loaded from branch '{}'", branch);
+            return Ok(());
+        }
+
+        // マニフェストリポジトリから初期化されたワークスペースでは、要求があれば先に最新化する
+        if self.config.update_manifest {
+            match workspace.manifest_repo_path() {
+                Some(manifest_repo) => self.pull_manifest_repo(workspace, &manifest_repo).await?,
+                None => {
+                    info!("No manifest repository configured, using the local manifest");
+                }
+            }
+        }
+
+        // ローカルファーストアプローチ: マニフェストファイルの再読み込み
+        let manifest_file = workspace.source_manifest_file_path();
 
         if !manifest_file.exists() {
             return Err(SyncRepositoriesError::ManifestUpdateFailed(format!(
@@ -233,53 +707,161 @@ impl SyncRepositoriesUseCase {
             )));
         }
 
-        if self.config.verbose {
-            println!("Reloading manifest from: {}", manifest_file.display());
-        }
+        info!("Reloading manifest from: {}", manifest_file.display());
 
         // ManifestStoreを使ってマニフェストファイルを再読み込み
         let manifest = self.reload_manifest_from_file(&manifest_file).await?;
         workspace.manifest = Some(manifest);
 
-        if self.config.verbose {
-            println!("Manifest reloaded successfully");
-        }
+        info!("Manifest reloaded successfully");
+
+        Ok(())
+    }
+
+    /// マニフェストリポジトリをfetchし、マニフェストブランチをfast-forward
+    async fn pull_manifest_repo(
+        &self,
+        workspace: &Workspace,
+        manifest_repo: &PathBuf,
+    ) -> Result<(), SyncRepositoriesError> {
+        use crate::infrastructure::git::repository::GitRepository;
+
+        info!(
+                "Updating manifest repository at {}",
+                manifest_repo.display()
+            );
+
+        self.perform_git_fetch(manifest_repo).await.map_err(|e| {
+            SyncRepositoriesError::ManifestUpdateFailed(format!(
+                "Failed to fetch manifest repository: {}",
+                e
+            ))
+        })?;
+
+        let git_repo = GitRepository::open(manifest_repo).map_err(|e| {
+            SyncRepositoriesError::ManifestUpdateFailed(format!(
+                "Failed to open manifest repository at {}: {}",
+                manifest_repo.display(),
+                e
+            ))
+        })?;
+        git_repo
+            .fast_forward_merge(&workspace.config.manifest_branch)
+            .map_err(|e| {
+                SyncRepositoriesError::ManifestUpdateFailed(format!(
+                    "Failed to fast-forward manifest branch '{}': {}",
+                    workspace.config.manifest_branch, e
+                ))
+            })?;
 
         Ok(())
     }
 
+    /// マニフェストリポジトリをfetchし、`branch`のマニフェストを読み込む
+    ///
+    /// リモートのブランチ（なければローカルのブランチ）を一時的にチェックアウトし、読み込みの
+    /// 成否にかかわらず元のブランチ・コミットに戻す。記録されたマニフェストブランチは変更しない。
+    async fn read_manifest_at_branch(
+        &self,
+        workspace: &Workspace,
+        manifest_repo: &PathBuf,
+        branch: &BranchName,
+    ) -> Result<crate::domain::entities::manifest::Manifest, SyncRepositoriesError> {
+        use crate::infrastructure::git::repository::GitRepository;
+
+        info!(
+            "Loading manifest from branch '{}' of {}",
+            branch,
+            manifest_repo.display()
+        );
+
+        self.perform_git_fetch(manifest_repo).await.map_err(|e| {
+            SyncRepositoriesError::ManifestUpdateFailed(format!(
+                "Failed to fetch manifest repository: {}",
+                e
+            ))
+        })?;
+
+        let git_repo = GitRepository::open(manifest_repo).map_err(|e| {
+            SyncRepositoriesError::ManifestUpdateFailed(format!(
+                "Failed to open manifest repository at {}: {}",
+                manifest_repo.display(),
+                e
+            ))
+        })?;
+        let remote = git_repo.fetch_remote();
+        let target = [
+            format!("refs/remotes/{}/{}", remote, branch),
+            format!("refs/heads/{}", branch),
+        ]
+        .into_iter()
+        .find(|name| git_repo.git2_repo().find_reference(name).is_ok())
+        .ok_or_else(|| {
+            SyncRepositoriesError::ManifestUpdateFailed(format!(
+                "Manifest branch '{}' not found in {}",
+                branch,
+                manifest_repo.display()
+            ))
+        })?;
+
+        let original = git_repo.head_name().map_err(|e| {
+            SyncRepositoriesError::ManifestUpdateFailed(format!(
+                "Failed to read HEAD of manifest repository: {}",
+                e
+            ))
+        })?;
+        git_repo.checkout(&target).map_err(|e| {
+            SyncRepositoriesError::ManifestUpdateFailed(format!(
+                "Failed to check out manifest branch '{}': {}",
+                branch, e
+            ))
+        })?;
+
+        let manifest = self
+            .reload_manifest_from_file(&workspace.source_manifest_file_path())
+            .await;
+
+        git_repo.checkout(&original).map_err(|e| {
+            SyncRepositoriesError::ManifestUpdateFailed(format!(
+                "Failed to restore manifest repository to '{}': {}",
+                original, e
+            ))
+        })?;
+
+        manifest
+    }
+
     /// マニフェストファイルから再読み込み（ローカルファーストアプローチ）
     async fn reload_manifest_from_file(
         &self,
         manifest_file: &std::path::Path,
     ) -> Result<crate::domain::entities::manifest::Manifest, SyncRepositoriesError> {
-        use crate::infrastructure::filesystem::manifest_store::{
-            ManifestStore, ManifestStoreError,
-        };
+        use crate::infrastructure::filesystem::manifest_store::ManifestStoreError;
 
-        let mut manifest_store = ManifestStore::new();
-        let processed_manifest =
-            manifest_store
-                .read_manifest(manifest_file)
-                .await
-                .map_err(|e| match e {
-                    ManifestStoreError::ManifestFileNotFound(path) => {
-                        SyncRepositoriesError::ManifestUpdateFailed(format!(
-                            "Manifest file not found: {}",
-                            path
-                        ))
-                    }
-                    ManifestStoreError::YamlParsingFailed(err) => {
-                        SyncRepositoriesError::ManifestUpdateFailed(format!(
-                            "YAML parsing failed: {}",
-                            err
-                        ))
-                    }
-                    _ => SyncRepositoriesError::ManifestUpdateFailed(format!(
-                        "Failed to read manifest: {}",
-                        e
-                    )),
-                })?;
+        let processed_manifest = self
+            .manifest_store
+            .lock()
+            .await
+            .read_manifest(manifest_file)
+            .await
+            .map_err(|e| match e {
+                ManifestStoreError::ManifestFileNotFound(path) => {
+                    SyncRepositoriesError::ManifestUpdateFailed(format!(
+                        "Manifest file not found: {}",
+                        path
+                    ))
+                }
+                ManifestStoreError::YamlParsingFailed(err) => {
+                    SyncRepositoriesError::ManifestUpdateFailed(format!(
+                        "YAML parsing failed: {}",
+                        err
+                    ))
+                }
+                _ => SyncRepositoriesError::ManifestUpdateFailed(format!(
+                    "Failed to read manifest: {}",
+                    e
+                )),
+            })?;
 
         Ok(processed_manifest.manifest)
     }
@@ -293,17 +875,17 @@ impl SyncRepositoriesUseCase {
             SyncRepositoriesError::ManifestUpdateFailed("Manifest not loaded".to_string())
         })?;
 
-        let mut target_repos = Vec::new();
-
-        if let Some(groups) = &self.config.groups {
-            // 指定されたグループのリポジトリのみ
-            for group_name in groups {
-                let repos_in_group = manifest.get_repos_in_group(group_name);
-                target_repos.extend(repos_in_group.into_iter().cloned());
-            }
-        } else {
-            // 全てのリポジトリ
-            target_repos = manifest.repos.clone();
+        let target_repos = self
+            .config
+            .repo_selector()
+            .select(manifest, workspace.configured_repos())?
+            .into_iter()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        // ワークスペース外を指すdestがあれば、どのリポジトリにも触れる前に中断する
+        for repo in &target_repos {
+            workspace.resolve_dest(&repo.dest)?;
         }
 
         Ok(target_repos)
@@ -316,59 +898,533 @@ impl SyncRepositoriesUseCase {
         workspace: &Workspace,
         result: &mut SyncResult,
     ) -> Result<(), SyncRepositoriesError> {
-        for repo in target_repos {
-            match self.sync_single_repository(repo, workspace).await {
+        // 完了したリポジトリをジャーナルに記録し、中断後に再開できるようにする
+        let wmgr_dir = workspace.wmgr_dir();
+        let mut journal = if self.config.resume {
+            SyncJournal::load(&wmgr_dir)?
+        } else {
+            let journal = SyncJournal::new(&wmgr_dir);
+            journal.clear()?;
+            journal
+        };
+
+        for (index, repo) in target_repos.iter().enumerate() {
+            if self.cancellation.is_cancelled() {
+                result
+                    .interrupted
+                    .extend(target_repos[index..].iter().map(|repo| repo.dest.clone()));
+                break;
+            }
+            self.progress.started(&repo.dest, index, target_repos.len());
+
+            if self.config.resume && journal.is_completed(&repo.dest) {
+                info!("Skipping {} (completed by the previous sync)", repo.dest);
+                result.resumed_count += 1;
+                self.progress.finished(&repo.dest, RepoOutcome::Resumed);
+                continue;
+            }
+
+            // ローカル専用のリポジトリは取得元がないためクローン・更新しない
+            let repo_path = workspace.resolve_dest(&repo.dest)?;
+            if repo.is_local_only() {
+                if !repo_path.exists() {
+                    result.add_warning(format!(
+                        "Local-only repository '{}' does not exist and has no url to clone from",
+                        repo.dest
+                    ));
+                }
+                result.skipped_count += 1;
+                self.progress.finished(&repo.dest, RepoOutcome::Skipped);
+                continue;
+            }
+
+            if let Some(window) = self.config.since {
+                if repo.scm == ScmType::Git && Self::fetched_within(&repo_path, window) {
+                    info!(
+                        "Skipping {} (fetched within the last {:?})",
+                        repo.dest, window
+                    );
+                    result.recent_count += 1;
+                    self.progress.finished(&repo.dest, RepoOutcome::Recent);
+                    continue;
+                }
+            }
+
+            // 中断されたら処理中の同期を破棄する（子プロセスのgitも終了する）
+            let existed = repo_path.symlink_metadata().is_ok();
+            let synced = tokio::select! {
+                biased;
+                _ = self.cancellation.cancelled() => None,
+                synced = instrument_repo("sync", &repo.dest, async {
+                    let synced = self.sync_single_repository(repo, workspace).await;
+                    match &synced {
+                        Ok(operation) => info!(outcome = ?operation, "Repository synced"),
+                        Err(e) => info!(error = %e, "Repository sync failed"),
+                    }
+                    synced
+                }) => Some(synced),
+            };
+            // Ctrl-Cは子プロセスにも届くため、中断による失敗も中断として扱う
+            let synced = match synced {
+                Some(Err(_)) | None if self.cancellation.is_cancelled() => {
+                    if !existed {
+                        if let Err(e) = Self::remove_partial_clone(&repo_path) {
+                            result.add_warning(format!(
+                                "Failed to remove the partial clone {}: {}",
+                                repo_path.display(),
+                                e
+                            ));
+                        }
+                    }
+                    info!("Sync of {} was interrupted", repo.dest);
+                    self.progress.finished(&repo.dest, RepoOutcome::Interrupted);
+                    result
+                        .interrupted
+                        .extend(target_repos[index..].iter().map(|repo| repo.dest.clone()));
+                    break;
+                }
+                Some(synced) => synced,
+                None => unreachable!("the sync is only abandoned after cancellation"),
+            };
+            match synced {
                 Ok(operation) => {
                     match operation {
                         SyncOperation::Cloned => result.cloned_count += 1,
+                        SyncOperation::Recovered { ref backup } => {
+                            result.recovered_count += 1;
+                            if let Some(backup) = backup {
+                                result.add_warning(format!(
+                                    "{} was not a valid repository and was cloned again; its files were moved to {}",
+                                    repo.dest,
+                                    backup.display()
+                                ));
+                            }
+                        }
                         SyncOperation::Updated => result.updated_count += 1,
+                        SyncOperation::UpToDate => result.up_to_date_count += 1,
                         SyncOperation::Skipped => result.skipped_count += 1,
                     }
                     result.synced_count += 1;
+
+                    if let Some(warning) = self.check_partial_clone(repo, &repo_path, &operation) {
+                        result.add_warning(warning);
+                    }
+
+                    // LFSのファイルを取得できなくてもリポジトリ自体は同期済みのため警告に留める
+                    if let Some(warning) = self.pull_lfs_objects(repo, &repo_path).await {
+                        result.add_warning(warning);
+                    }
+
+                    // フックの失敗はリポジトリごとに報告し、--hooks-fatalの場合のみ中断する
+                    if let Err(e) = self
+                        .run_hooks(
+                            repo,
+                            workspace,
+                            &repo_path,
+                            &operation,
+                            index,
+                            target_repos.len(),
+                        )
+                        .await
+                    {
+                        result.add_error(e.to_string());
+                        self.progress
+                            .finished(&repo.dest, RepoOutcome::Failed(e.to_string()));
+                        if self.config.hooks_fatal {
+                            return Err(e);
+                        }
+                    } else {
+                        self.progress.finished(&repo.dest, operation.outcome());
+                    }
+                    journal.mark_completed(&repo.dest)?;
                 }
                 Err(e) => {
                     result.add_error(format!("Failed to sync {}: {}", repo.dest, e));
                     result.skipped_count += 1;
+                    self.progress
+                        .finished(&repo.dest, RepoOutcome::Failed(e.to_string()));
                 }
             }
         }
 
+        // 全て成功した場合はジャーナルを削除（中断した場合は--continueで再開できるよう残す）
+        if result.is_success() {
+            journal.clear()?;
+        }
+
         Ok(())
     }
 
-    /// 単一リポジトリの同期
-    async fn sync_single_repository(
+    /// 中断されたクローンが途中まで作成したディレクトリを削除し、再実行でクローンし直せるようにする
+    fn remove_partial_clone(repo_path: &Path) -> std::io::Result<()> {
+        match repo_path.symlink_metadata() {
+            Ok(metadata) if metadata.is_dir() => std::fs::remove_dir_all(repo_path),
+            Ok(_) => std::fs::remove_file(repo_path),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// 部分クローンを指定したのに全体がクローンされた場合の警告メッセージ
+    ///
+    /// サーバー（やローカルパスからのクローン）がフィルタに対応していない場合、全体が
+    /// クローンされ、リモートはpromisorとして記録されない（`GitScm`が記録を取り消す）。
+    fn check_partial_clone(
         &self,
         repo: &ManifestRepo,
-        workspace: &Workspace,
-    ) -> Result<SyncOperation, SyncRepositoriesError> {
-        let repo_path = workspace.repo_path(&repo.dest);
-
-        if repo.scm == ScmType::Http {
-            return self.download_http_resource(repo, &repo_path).await;
+        repo_path: &Path,
+        operation: &SyncOperation,
+    ) -> Option<String> {
+        if !matches!(
+            operation,
+            SyncOperation::Cloned | SyncOperation::Recovered { .. }
+        ) {
+            return None;
         }
-
-        if repo.scm == ScmType::Symlink {
-            return self.create_or_update_symlink(repo, &repo_path, workspace).await;
+        let filter = repo.clone_filter(self.config.clone_filter.as_deref())?;
+
+        let git_repo = git2::Repository::open(repo_path).ok()?;
+        let config = git_repo.config().ok()?;
+        let is_partial = git_repo.remotes().ok()?.iter().flatten().any(|name| {
+            config
+                .get_bool(&format!("remote.{}.promisor", name))
+                .unwrap_or(false)
+        });
+        if is_partial {
+            return None;
         }
+        Some(format!(
+            "{} was cloned in full: the server does not support partial clone (--filter={})",
+            repo.dest, filter
+        ))
+    }
 
-        if repo.scm == ScmType::S3 {
-            return self.sync_s3_resource(repo, &repo_path).await;
+    /// Git LFSを使うリポジトリで`git lfs pull`を実行し、ポインタを実際のファイルに置き換える
+    ///
+    /// LFSが無効か使われていない場合は何もしない。git-lfsがインストールされていない場合と
+    /// 取得に失敗した場合は警告メッセージを返す。
+    async fn pull_lfs_objects(&self, repo: &ManifestRepo, repo_path: &Path) -> Option<String> {
+        if !repo.lfs_enabled(self.config.lfs) || !lfs::uses_lfs(repo_path) {
+            return None;
+        }
+        if !lfs::is_installed() {
+            return Some(format!(
+                "{} uses Git LFS but git-lfs is not installed; large files are left as pointers",
+                repo.dest
+            ));
         }
 
-        if repo.scm == ScmType::GDrive {
+        self.progress.progress(&repo.dest, "pulling LFS objects");
+
+        let execution_config = ExecutionConfig::new().with_working_directory(repo_path);
+        let error = match CommandExecutor::execute("git lfs pull", &execution_config).await {
+            Ok(output) if output.success => return None,
+            Ok(output) => output.stderr.trim().to_string(),
+            Err(e) => e.to_string(),
+        };
+        Some(format!(
+            "Failed to pull Git LFS objects for {}: {}",
+            repo.dest, error
+        ))
+    }
+
+    /// 同期操作に対応するフックをリポジトリのディレクトリで実行
+    ///
+    /// クローンした場合は`post_clone`、更新した場合は`post_sync`を実行する。
+    /// 更新不要・スキップの場合は何も実行しない。環境変数はforeachと同じ`WMGR_REPO_*`を設定する。
+    async fn run_hooks(
+        &self,
+        repo: &ManifestRepo,
+        workspace: &Workspace,
+        repo_path: &Path,
+        operation: &SyncOperation,
+        index: usize,
+        count: usize,
+    ) -> Result<(), SyncRepositoriesError> {
+        let (hook, command) = match operation {
+            SyncOperation::Cloned | SyncOperation::Recovered { .. } => {
+                ("post_clone", &repo.post_clone)
+            }
+            SyncOperation::Updated => ("post_sync", &repo.post_sync),
+            SyncOperation::UpToDate | SyncOperation::Skipped => return Ok(()),
+        };
+        let Some(command) = command else {
+            return Ok(());
+        };
+
+        info!("Running {} hook for {}: {}", hook, repo.dest, command);
+        self.progress
+            .progress(&repo.dest, format!("running {} hook", hook));
+
+        let env_vars =
+            ForeachCommandUseCase::repository_environment(repo, workspace, repo_path, index, count);
+        Self::run_hook_command(command, repo_path, env_vars)
+            .await
+            .map_err(|error| SyncRepositoriesError::HookFailed {
+                repo: repo.dest.clone(),
+                hook: hook.to_string(),
+                error,
+            })
+    }
+
+    /// ワークスペースのpre_sync/post_syncフックをルートディレクトリで実行
+    ///
+    /// `WMGR_WORKSPACE_ROOT`を設定し、post_syncには`summary`の件数を`WMGR_SYNC_*`で渡す。
+    /// 失敗は`result`に記録し、`hooks_fatal`の場合のみエラーを返す。
+    async fn run_workspace_hook(
+        &self,
+        hook: &str,
+        command: &str,
+        workspace: &Workspace,
+        summary: Option<&SyncResult>,
+        result: &mut SyncResult,
+    ) -> Result<(), SyncRepositoriesError> {
+        info!("Running workspace {} hook: {}", hook, command);
+
+        let mut env_vars = HashMap::new();
+        env_vars.insert(
+            "WMGR_WORKSPACE_ROOT".to_string(),
+            workspace.root_path.display().to_string(),
+        );
+        if let Some(summary) = summary {
+            env_vars.extend(summary.environment());
+        }
+
+        let root = &workspace.root_path;
+        if let Err(error) = Self::run_hook_command(command, root, env_vars).await {
+            let error = SyncRepositoriesError::WorkspaceHookFailed {
+                hook: hook.to_string(),
+                command: command.to_string(),
+                error,
+            };
+            result.add_error(error.to_string());
+            if self.config.hooks_fatal {
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+
+    /// フックのコマンドをシェル経由で実行し、失敗時はエラーメッセージを返す
+    async fn run_hook_command(
+        command: &str,
+        working_dir: &Path,
+        env_vars: HashMap<String, String>,
+    ) -> Result<(), String> {
+        let execution_config = ExecutionConfig::new()
+            .with_working_directory(working_dir)
+            .with_environment_variables(env_vars)
+            .with_shell(true);
+
+        match CommandExecutor::execute(command, &execution_config).await {
+            Ok(output) if output.success => Ok(()),
+            Ok(output) => Err(match output.stderr.trim() {
+                "" => format!("Command exited with code {}", output.exit_code),
+                stderr => stderr.to_string(),
+            }),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// `.git/FETCH_HEAD`が`window`以内に更新されたか（--since）
+    fn fetched_within(repo_path: &Path, window: Duration) -> bool {
+        std::fs::metadata(repo_path.join(".git").join("FETCH_HEAD"))
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .is_some_and(|elapsed| elapsed <= window)
+    }
+
+    /// 単一リポジトリの同期
+    async fn sync_single_repository(
+        &self,
+        repo: &ManifestRepo,
+        workspace: &Workspace,
+    ) -> Result<SyncOperation, SyncRepositoriesError> {
+        let repo_path = workspace.resolve_dest(&repo.dest)?;
+
+        if repo.scm == ScmType::Http {
+            return self.download_http_resource(repo, &repo_path).await;
+        }
+
+        if repo.scm == ScmType::Symlink {
+            return self.create_or_update_symlink(repo, &repo_path, workspace).await;
+        }
+
+        if repo.scm == ScmType::S3 {
+            return self.sync_s3_resource(repo, &repo_path).await;
+        }
+
+        if repo.scm == ScmType::GDrive {
             return self.sync_gdrive_resource(repo, &repo_path).await;
         }
 
-        if !repo_path.exists() {
+        // 中断されたクローンなどでリポジトリではないディレクトリが残っている場合は作り直す
+        let recovered = if repo_path.exists() && Self::is_partial_clone(repo, &repo_path)? {
+            Some(Self::discard_partial_clone(repo, &repo_path, workspace)?)
+        } else {
+            None
+        };
+
+        // 参照するミラー上のパスは元のURLから決める
+        let reference = repo.reference_path(&workspace.root_path, self.config.reference.as_deref());
+
+        // singular_remoteが設定されている場合はミラーからクローン・フェッチする
+        let mirrored = Self::mirrored_repo(repo, workspace)?;
+        let repo = mirrored.as_ref().unwrap_or(repo);
+        // ブランチ指定のないGitリポジトリは同期するブランチを補う
+        let with_branch = self.repo_with_default_branch(repo, workspace, &repo_path);
+        let repo = with_branch.as_ref().unwrap_or(repo);
+        let shallow = self.resolve_shallow(repo, workspace);
+
+        let operation = if !repo_path.exists() {
             // リポジトリが存在しない場合はクローン
-            self.clone_repository(repo, &repo_path).await?;
-            Ok(SyncOperation::Cloned)
+            self.clone_repository(repo, &repo_path, shallow, reference.as_deref())
+                .await?;
+            match recovered {
+                Some(backup) => SyncOperation::Recovered { backup },
+                None => SyncOperation::Cloned,
+            }
         } else {
-            // 既存リポジトリの更新
-            self.update_repository(repo, &repo_path).await?;
-            Ok(SyncOperation::Updated)
+            if mirrored.is_some() {
+                self.update_remotes(repo, &repo_path).await?;
+            }
+            if repo.scm == ScmType::Git {
+                self.reconcile_shallow(repo, &repo_path, shallow).await?;
+            }
+
+            // リモートの先端と一致している場合はfetch/mergeを省略
+            let at_remote_tip = if self.config.only_changed {
+                let scm = ScmFactory::create_scm_for_url(repo.scm.clone(), &repo.url)?;
+                self.is_at_remote_tip(scm.as_ref(), repo, &repo_path).await
+            } else {
+                false
+            };
+
+            if at_remote_tip {
+                SyncOperation::UpToDate
+            } else {
+                // 既存リポジトリの更新
+                let strategy = self.resolve_sync_strategy(repo, workspace);
+                self.update_repository(repo, &repo_path, strategy).await?;
+                SyncOperation::Updated
+            }
+        };
+
+        if repo.submodules_enabled(self.config.recurse_submodules) {
+            self.update_submodules(repo, &repo_path).await?;
+        }
+
+        Ok(operation)
+    }
+
+    /// リポジトリとして扱えないディレクトリか（中断されたクローン・空のディレクトリなど）
+    fn is_partial_clone(
+        repo: &ManifestRepo,
+        repo_path: &Path,
+    ) -> Result<bool, SyncRepositoriesError> {
+        let scm = ScmFactory::create_scm_for_url(repo.scm.clone(), &repo.url)?;
+        if !scm.is_repository(repo_path) {
+            return Ok(true);
+        }
+        // `.git`があってもlibgit2で開けない場合は壊れている
+        Ok(repo.scm == ScmType::Git && git2::Repository::open(repo_path).is_err())
+    }
+
+    /// クローンし直すためにリポジトリではないディレクトリを取り除く
+    ///
+    /// ファイルを1つも含まない（SCMのメタデータも空のディレクトリだけの）場合は削除し、
+    /// それ以外は開けないリポジトリの`.git`なども含めて`.wmgr/backups`に移動してその場所を返す。
+    fn discard_partial_clone(
+        repo: &ManifestRepo,
+        repo_path: &Path,
+        workspace: &Workspace,
+    ) -> Result<Option<PathBuf>, SyncRepositoriesError> {
+        if !Self::contains_files(repo_path)? {
+            info!("Removing {} to clone it again", repo_path.display());
+            std::fs::remove_dir_all(repo_path)?;
+            return Ok(None);
+        }
+
+        // destの階層をバックアップディレクトリ内に再現する
+        let backup_dir = match Path::new(&repo.dest).parent() {
+            Some(parent) => workspace.root_path.join(DEFAULT_BACKUP_DIR).join(parent),
+            None => workspace.root_path.join(DEFAULT_BACKUP_DIR),
+        };
+        std::fs::create_dir_all(&backup_dir)?;
+        let backup = backup_dir.join(format!(
+            "{}{}",
+            backup_name_prefix(repo_path, ".partial"),
+            chrono::Utc::now().format("%Y%m%d_%H%M%S")
+        ));
+        info!(
+            "Moving {} to {} to clone it again",
+            repo_path.display(),
+            backup.display()
+        );
+        std::fs::rename(repo_path, &backup)?;
+        Ok(Some(backup))
+    }
+
+    /// ディレクトリ以外のエントリが配下に1つでもあるか
+    fn contains_files(dir: &Path) -> std::io::Result<bool> {
+        for entry in std::fs::read_dir(dir)? {
+            let entry = entry?;
+            if !entry.file_type()?.is_dir() || Self::contains_files(&entry.path())? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// サブモジュールを再帰的に初期化し、親リポジトリに記録されたコミットに更新
+    async fn update_submodules(
+        &self,
+        repo: &ManifestRepo,
+        repo_path: &Path,
+    ) -> Result<(), SyncRepositoriesError> {
+        use crate::infrastructure::git::repository::GitRepository;
+
+        self.progress.progress(&repo.dest, "updating submodules");
+
+        let submodule_error = |error: String| SyncRepositoriesError::SubmoduleUpdateFailed {
+            repo: repo.dest.clone(),
+            error,
+        };
+        let git_repo =
+            GitRepository::open(repo_path).map_err(|e| submodule_error(e.to_string()))?;
+        let updated = git_repo
+            .update_submodules()
+            .map_err(|e| submodule_error(e.to_string()))?;
+        if !updated.is_empty() {
+            info!(
+                "Updated submodules of {}: {}",
+                repo.dest,
+                updated.join(", ")
+            );
+        }
+
+        Ok(())
+    }
+
+    /// 取得元URLをsingular_remoteのミラー上のURLに置き換えたリポジトリ定義
+    ///
+    /// singular_remoteが未設定の場合とGit以外のリポジトリではNoneを返す。
+    fn mirrored_repo(
+        repo: &ManifestRepo,
+        workspace: &Workspace,
+    ) -> Result<Option<ManifestRepo>, SyncRepositoriesError> {
+        let Some(base) = &workspace.config.singular_remote else {
+            return Ok(None);
+        };
+        if repo.scm != ScmType::Git {
+            return Ok(None);
         }
+
+        let url = GitUrl::new(&repo.url)?.rebased_onto(base)?;
+        let mut mirrored = repo.clone();
+        mirrored.url = url.to_clone_url();
+        Ok(Some(mirrored))
     }
 
     /// Download HTTP resource (file or archive)
@@ -379,13 +1435,10 @@ impl SyncRepositoriesUseCase {
     ) -> Result<SyncOperation, SyncRepositoriesError> {
         use crate::infrastructure::http::HttpDownloader;
 
-        if self.config.verbose {
-            println!("Downloading HTTP resource: {} to {}", repo.url, target_path.display());
-        }
+        info!("Downloading HTTP resource: {} to {}", repo.url, target_path.display());
 
         let url = repo.url.clone();
         let dest = target_path.clone();
-        let verbose = self.config.verbose;
 
         tokio::task::spawn_blocking(move || {
             let downloader = HttpDownloader::new();
@@ -403,9 +1456,7 @@ impl SyncRepositoriesUseCase {
             ))
         })?;
 
-        if verbose {
-            println!("Successfully downloaded: {} -> {}", repo.url, target_path.display());
-        }
+        info!("Successfully downloaded: {} -> {}", repo.url, target_path.display());
 
         Ok(SyncOperation::Cloned)
     }
@@ -424,13 +1475,11 @@ impl SyncRepositoriesUseCase {
             workspace.root_path.join(target)
         };
 
-        if self.config.verbose {
-            println!(
+        info!(
                 "Symlink: {} -> {}",
                 link_path.display(),
                 resolved_target.display()
             );
-        }
 
         if !resolved_target.exists() {
             return Err(SyncRepositoriesError::RepositoryCloneFailed(format!(
@@ -493,9 +1542,7 @@ impl SyncRepositoriesUseCase {
         use crate::application::services::credential_service::CredentialService;
         use crate::infrastructure::s3::{S3Config, S3Downloader};
 
-        if self.config.verbose {
-            println!("S3 sync: {} -> {}", repo.url, target_path.display());
-        }
+        info!("S3 sync: {} -> {}", repo.url, target_path.display());
 
         let existed = target_path.exists();
 
@@ -537,13 +1584,11 @@ impl SyncRepositoriesUseCase {
             ))
         })?;
 
-        if self.config.verbose {
-            println!(
+        info!(
                 "S3 sync complete: {} files downloaded to {}",
                 result.downloaded,
                 target_path.display()
             );
-        }
 
         Ok(if existed {
             SyncOperation::Updated
@@ -571,9 +1616,7 @@ impl SyncRepositoriesUseCase {
         // Determine remote name and source path
         let (remote_name, source) = Self::resolve_gdrive_source(repo);
 
-        if self.config.verbose {
-            println!("GDrive sync: {} -> {} (remote: {})", source, target_path.display(), remote_name);
-        }
+        info!("GDrive sync: {} -> {} (remote: {})", source, target_path.display(), remote_name);
 
         // Auto-authenticate if needed
         rclone.ensure_remote(&remote_name).await.map_err(|e| {
@@ -632,10 +1675,10 @@ impl SyncRepositoriesUseCase {
         &self,
         repo: &ManifestRepo,
         target_path: &PathBuf,
+        shallow: bool,
+        reference: Option<&Path>,
     ) -> Result<(), SyncRepositoriesError> {
-        if self.config.verbose {
-            println!("Cloning {} ({}) to {}", repo.url, repo.scm, target_path.display());
-        }
+        info!("Cloning {} ({}) to {}", repo.url, repo.scm, target_path.display());
 
         // ディレクトリの親を作成
         if let Some(parent) = target_path.parent() {
@@ -643,7 +1686,9 @@ impl SyncRepositoriesUseCase {
         }
 
         // SCM操作の実行
-        self.perform_scm_clone(repo, target_path).await?;
+        self.progress.progress(&repo.dest, "cloning");
+        self.perform_scm_clone(repo, target_path, shallow, reference)
+            .await?;
 
         Ok(())
     }
@@ -653,19 +1698,22 @@ impl SyncRepositoriesUseCase {
         &self,
         repo: &ManifestRepo,
         target_path: &PathBuf,
+        shallow: bool,
+        reference: Option<&Path>,
     ) -> Result<(), SyncRepositoriesError> {
-        if self.config.verbose {
-            println!("Starting {} clone: {} -> {}", repo.scm, repo.url, target_path.display());
-        }
+        info!("Starting {} clone: {} -> {}", repo.scm, repo.url, target_path.display());
 
         // SCM操作インスタンスを作成
-        let scm = ScmFactory::create_scm(repo.scm.clone())?;
+        let scm = ScmFactory::create_scm_for_url(repo.scm.clone(), &repo.url)?;
         
         // クローンオプションを構築
-        let clone_options = repo.to_clone_options();
+        let mut clone_options = repo.to_clone_options();
+        clone_options.shallow = shallow;
+        clone_options.filter = repo.clone_filter(self.config.clone_filter.as_deref());
+        clone_options.reference = reference.map(Path::to_path_buf);
 
-        // SCMクローンを実行
-        scm.clone_repository(&repo.url, target_path, &clone_options)
+        // SCMクローンを実行（Gitは指定されたプロトコルのまま）
+        scm.clone_repository(&repo.clone_url(), target_path, &clone_options)
             .await
             .map_err(|e| {
                 SyncRepositoriesError::RepositoryCloneFailed(format!(
@@ -674,9 +1722,7 @@ impl SyncRepositoriesUseCase {
                 ))
             })?;
 
-        if self.config.verbose {
-            println!("Successfully cloned: {} -> {}", repo.url, target_path.display());
-        }
+        info!("Successfully cloned: {} -> {}", repo.url, target_path.display());
 
         Ok(())
     }
@@ -688,12 +1734,10 @@ impl SyncRepositoriesUseCase {
         target_path: &PathBuf,
         branch: Option<&str>,
     ) -> Result<(), SyncRepositoriesError> {
-        use crate::domain::value_objects::{file_path::FilePath, git_url::GitUrl};
+        use crate::domain::value_objects::file_path::FilePath;
         use crate::infrastructure::git::repository::{CloneConfig, GitRepository};
 
-        if self.config.verbose {
-            println!("Starting Git clone: {} -> {}", url, target_path.display());
-        }
+        info!("Starting Git clone: {} -> {}", url, target_path.display());
 
         // URLとパスの検証・変換
         let git_url = GitUrl::new(url)?;
@@ -718,8 +1762,154 @@ impl SyncRepositoriesUseCase {
                 ))
             })?;
 
-        if self.config.verbose {
-            println!("Successfully cloned: {} -> {}", url, target_path.display());
+        info!("Successfully cloned: {} -> {}", url, target_path.display());
+
+        Ok(())
+    }
+
+    /// ローカルの先端がリモートブランチの先端と一致しているか（軽量なls-remoteで確認）
+    async fn is_at_remote_tip(
+        &self,
+        scm: &dyn ScmOperations,
+        repo: &ManifestRepo,
+        repo_path: &Path,
+    ) -> bool {
+        // 固定リビジョンの場合は通常の同期に任せる
+        if repo.get_effective_revision().is_some() {
+            return false;
+        }
+
+        let remote_tip = match scm
+            .get_remote_revision(repo_path, repo.branch.as_deref())
+            .await
+        {
+            Ok(Some(tip)) => tip,
+            Ok(None) => return false,
+            Err(e) => {
+                info!("Remote tip lookup failed for {}: {}", repo.dest, e);
+                return false;
+            }
+        };
+
+        match scm.get_current_revision(repo_path).await {
+            Ok(local_tip) if local_tip == remote_tip => {
+                info!("{} is already at {}", repo.dest, remote_tip);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// ブランチ指定のないGitリポジトリに同期するブランチを補ったもの（補わない場合はNone）
+    ///
+    /// リポジトリ設定 > マニフェストのdefault_branch > リモートのHEAD > `main`の順に決める。
+    /// クローン時のリモートのHEADはクローンに任せ、`no_correct_branch`では現在のブランチを保つ。
+    fn repo_with_default_branch(
+        &self,
+        repo: &ManifestRepo,
+        workspace: &Workspace,
+        repo_path: &Path,
+    ) -> Option<ManifestRepo> {
+        if repo.scm != ScmType::Git
+            || repo.branch.is_some()
+            || repo.get_effective_revision().is_some()
+        {
+            return None;
+        }
+
+        let manifest_default = workspace
+            .manifest
+            .as_ref()
+            .and_then(|manifest| manifest.branch_for(repo))
+            .map(str::to_string);
+        let branch = if !repo_path.exists() {
+            manifest_default?
+        } else if self.config.no_correct_branch {
+            return None;
+        } else {
+            manifest_default.unwrap_or_else(|| Self::detect_default_branch(repo_path))
+        };
+        Some(repo.clone().with_branch(branch))
+    }
+
+    /// originのHEADが指すブランチ（判別できない場合は`main`）
+    fn detect_default_branch(repo_path: &Path) -> String {
+        use crate::infrastructure::git::remote::GitRemoteManager;
+
+        let detected = git2::Repository::open(repo_path).ok().and_then(|git_repo| {
+            GitRemoteManager::new(&git_repo)
+                .get_remote_head_branch("origin")
+                .ok()
+                .flatten()
+        });
+        match detected {
+            Some(branch) => branch,
+            None => {
+                info!(
+                    "Could not detect the default branch of {}, using 'main'",
+                    repo_path.display()
+                );
+                "main".to_string()
+            }
+        }
+    }
+
+    /// リポジトリに適用するマージ戦略を決定（--rebase > リポジトリ設定 > マニフェストのデフォルト）
+    fn resolve_sync_strategy(&self, repo: &ManifestRepo, workspace: &Workspace) -> SyncStrategy {
+        if self.config.rebase {
+            return SyncStrategy::Rebase;
+        }
+
+        match &workspace.manifest {
+            Some(manifest) => manifest.sync_strategy_for(repo),
+            None => repo.sync_strategy.unwrap_or_default(),
+        }
+    }
+
+    /// リポジトリをshallow cloneするか（リポジトリ設定 > マニフェストのデフォルト > ワークスペース設定）
+    fn resolve_shallow(&self, repo: &ManifestRepo, workspace: &Workspace) -> bool {
+        match &workspace.manifest {
+            Some(manifest) => manifest.shallow_for(repo, workspace.config.shallow_clones),
+            None => repo.shallow.unwrap_or(workspace.config.shallow_clones),
+        }
+    }
+
+    /// 既存クローンのshallow状態を有効な設定に合わせる（前回の同期から設定が変わった場合）
+    async fn reconcile_shallow(
+        &self,
+        repo: &ManifestRepo,
+        repo_path: &Path,
+        shallow: bool,
+    ) -> Result<(), SyncRepositoriesError> {
+        // 開けない場合は後続の更新処理でエラーとして報告される
+        let Ok(git_repo) = git2::Repository::open(repo_path) else {
+            return Ok(());
+        };
+        if git_repo.is_shallow() == shallow {
+            return Ok(());
+        }
+
+        let (args, kind): (&[&str], &str) = if shallow {
+            (&["fetch", "--depth", "1", "origin"], "shallow")
+        } else {
+            (&["fetch", "--unshallow", "origin"], "full")
+        };
+        info!("Converting {} to a {} clone", repo.dest, kind);
+
+        let output = tokio::process::Command::new("git")
+            .args(args)
+            .current_dir(repo_path)
+            .output()
+            .await?;
+        if !output.status.success() {
+            return Err(SyncRepositoriesError::RemoteUpdateFailed {
+                repo: repo.dest.clone(),
+                error: format!(
+                    "Failed to convert to a {} clone: {}",
+                    kind,
+                    String::from_utf8_lossy(&output.stderr).trim()
+                ),
+            });
         }
 
         Ok(())
@@ -730,13 +1920,23 @@ impl SyncRepositoriesUseCase {
         &self,
         repo: &ManifestRepo,
         repo_path: &PathBuf,
+        strategy: SyncStrategy,
     ) -> Result<(), SyncRepositoriesError> {
-        if self.config.verbose {
-            println!("Updating {} repository at {}", repo.scm, repo_path.display());
+        info!("Updating {} repository at {}", repo.scm, repo_path.display());
+
+        self.progress.progress(&repo.dest, "updating");
+
+        // ff-only以外の戦略ではlibgit2でfetchしてからブランチを同期
+        if strategy != SyncStrategy::FfOnly
+            && repo.scm == ScmType::Git
+            && repo.get_effective_revision().is_none()
+        {
+            self.perform_git_fetch(repo_path).await?;
+            return self.sync_branch(repo, repo_path, strategy).await;
         }
 
         // SCM操作インスタンスを作成
-        let scm = ScmFactory::create_scm(repo.scm.clone())?;
+        let scm = ScmFactory::create_scm_for_url(repo.scm.clone(), &repo.url)?;
 
         // リポジトリの種別を確認
         if !scm.is_repository(repo_path) {
@@ -759,9 +1959,7 @@ impl SyncRepositoriesUseCase {
                 }
             })?;
 
-        if self.config.verbose {
-            println!("Successfully updated {} repository at {}", repo.scm, repo_path.display());
-        }
+        info!("Successfully updated {} repository at {}", repo.scm, repo_path.display());
 
         Ok(())
     }
@@ -772,13 +1970,10 @@ impl SyncRepositoriesUseCase {
         repo: &ManifestRepo,
         repo_path: &PathBuf,
     ) -> Result<(), SyncRepositoriesError> {
-        use crate::domain::value_objects::git_url::GitUrl;
         use crate::infrastructure::git::remote::GitRemoteManager;
         use crate::infrastructure::git::repository::GitRepository;
 
-        if self.config.verbose {
-            println!("Updating remotes for {}", repo_path.display());
-        }
+        info!("Updating remotes for {}", repo_path.display());
 
         // 既存リポジトリを開く
         let git_repo = GitRepository::open(repo_path).map_err(|e| {
@@ -804,9 +1999,7 @@ impl SyncRepositoriesUseCase {
                     error: format!("Failed to update origin remote URL: {}", e),
                 })?;
 
-            if self.config.verbose {
-                println!("Updated origin remote URL to: {}", repo.url);
-            }
+            info!("Updated origin remote URL to: {}", repo.url);
         } else {
             // originリモートが存在しない場合は追加
             remote_manager.add_remote("origin", &git_url).map_err(|e| {
@@ -816,22 +2009,18 @@ impl SyncRepositoriesUseCase {
                 }
             })?;
 
-            if self.config.verbose {
-                println!("Added origin remote with URL: {}", repo.url);
-            }
+            info!("Added origin remote with URL: {}", repo.url);
         }
 
         Ok(())
     }
 
     /// Git fetchの実行
+    ///
+    /// 現在のブランチに上流ブランチが設定されていればそのリモートから、なければoriginからfetchする。
     async fn perform_git_fetch(&self, repo_path: &PathBuf) -> Result<(), SyncRepositoriesError> {
         use crate::infrastructure::git::repository::{FetchConfig, GitRepository};
 
-        if self.config.verbose {
-            println!("Fetching latest changes from origin...");
-        }
-
         // 既存リポジトリを開く
         let git_repo = GitRepository::open(repo_path).map_err(|e| {
             SyncRepositoriesError::GitOperationFailed(format!(
@@ -841,47 +2030,55 @@ impl SyncRepositoriesUseCase {
             ))
         })?;
 
+        let remote_name = git_repo.fetch_remote();
+        info!("Fetching latest changes from {}...", remote_name);
+
         // フェッチ設定
         let fetch_config = FetchConfig {
-            remote_name: "origin".to_string(),
+            remote_name: remote_name.clone(),
             refs: None, // すべてのリファレンスをフェッチ
             progress_callback: None,
         };
 
         // Git fetch実行
         git_repo.fetch(fetch_config).await.map_err(|e| {
-            SyncRepositoriesError::GitOperationFailed(format!("Failed to fetch from origin: {}", e))
+            SyncRepositoriesError::GitOperationFailed(format!(
+                "Failed to fetch from {}: {}",
+                remote_name, e
+            ))
         })?;
 
-        if self.config.verbose {
-            println!("Successfully fetched latest changes");
-        }
+        info!("Successfully fetched latest changes");
 
         Ok(())
     }
 
-    /// ブランチの同期（fast-forward merge）
+    /// ブランチの同期（マージ戦略に応じてfast-forward、リベース、マージ）
     async fn sync_branch(
         &self,
         repo: &ManifestRepo,
         repo_path: &PathBuf,
+        strategy: SyncStrategy,
     ) -> Result<(), SyncRepositoriesError> {
-        let target_branch = repo.branch.as_deref().unwrap_or("main");
+        // 1. 現在のブランチをチェック
+        let current_branch = self.get_current_branch(repo_path).await?;
+
+        // ブランチ指定がない場合やブランチ切り替えが無効な場合は現在のブランチを同期
+        let target_branch = if self.config.no_correct_branch {
+            current_branch.as_str()
+        } else {
+            repo.branch.as_deref().unwrap_or(&current_branch)
+        };
 
-        if self.config.verbose {
-            println!(
+        info!(
                 "Syncing branch '{}' in {}",
                 target_branch,
                 repo_path.display()
             );
-        }
 
         // ブランチ名の検証
         let _branch_name = BranchName::new(target_branch)?;
 
-        // 1. 現在のブランチをチェック
-        let current_branch = self.get_current_branch(repo_path).await?;
-
         // 2. 必要に応じてブランチを切り替え
         if current_branch != target_branch {
             self.perform_git_checkout(repo_path, target_branch).await?;
@@ -899,7 +2096,17 @@ impl SyncRepositoriesUseCase {
             }
         }
 
-        self.perform_git_merge_ff(repo_path, target_branch).await?;
+        match strategy {
+            SyncStrategy::FfOnly => self.perform_git_merge_ff(repo_path, target_branch).await?,
+            SyncStrategy::Rebase => {
+                self.perform_git_rebase(repo, repo_path, target_branch)
+                    .await?
+            }
+            SyncStrategy::Merge => {
+                self.perform_git_merge(repo, repo_path, target_branch)
+                    .await?
+            }
+        }
 
         Ok(())
     }
@@ -937,13 +2144,11 @@ impl SyncRepositoriesUseCase {
     ) -> Result<(), SyncRepositoriesError> {
         use crate::infrastructure::git::repository::GitRepository;
 
-        if self.config.verbose {
-            println!(
+        info!(
                 "Checking out branch '{}' in {}",
                 branch,
                 repo_path.display()
             );
-        }
 
         // 既存リポジトリを開く
         let git_repo = GitRepository::open(repo_path).map_err(|e| {
@@ -962,9 +2167,7 @@ impl SyncRepositoriesUseCase {
                 error: format!("Failed to checkout branch '{}': {}", branch, e),
             })?;
 
-        if self.config.verbose {
-            println!("Successfully checked out branch '{}'", branch);
-        }
+        info!("Successfully checked out branch '{}'", branch);
 
         Ok(())
     }
@@ -1005,13 +2208,11 @@ impl SyncRepositoriesUseCase {
     ) -> Result<(), SyncRepositoriesError> {
         use crate::infrastructure::git::repository::GitRepository;
 
-        if self.config.verbose {
-            println!(
+        info!(
                 "Performing fast-forward merge for branch '{}' in {}",
                 branch,
                 repo_path.display()
             );
-        }
 
         // 既存リポジトリを開く
         let git_repo = GitRepository::open(repo_path).map_err(|e| {
@@ -1030,69 +2231,165 @@ impl SyncRepositoriesUseCase {
             }
         })?;
 
-        if self.config.verbose {
-            println!(
+        info!(
                 "Successfully performed fast-forward merge for branch '{}'",
                 branch
             );
-        }
 
         Ok(())
     }
 
-    /// 子ディレクトリのワークスペースを再帰的に同期
-    async fn sync_child_workspaces(
+    /// upstreamへのリベースの実行
+    async fn perform_git_rebase(
         &self,
-        workspace: &Workspace,
-        result: &mut SyncResult,
+        repo: &ManifestRepo,
+        repo_path: &PathBuf,
+        branch: &str,
     ) -> Result<(), SyncRepositoriesError> {
-        if self.config.verbose {
-            println!("Searching for child workspaces...");
-        }
+        use crate::infrastructure::git::repository::{
+            GitRepository, GitRepositoryError, RebaseOutcome,
+        };
 
-        // 現在のワークスペースのマニフェストを取得
-        let manifest = workspace.manifest.as_ref().ok_or_else(|| {
-            SyncRepositoriesError::ManifestUpdateFailed("Manifest not loaded".to_string())
-        })?;
+        info!(
+                "Rebasing branch '{}' onto origin/{} in {}",
+                branch,
+                branch,
+                repo_path.display()
+            );
 
-        // 各リポジトリディレクトリで子ワークスペースを検索
-        for repo in &manifest.repos {
-            let repo_path = workspace.root_path.join(&repo.dest);
-            
-            if !repo_path.exists() {
-                continue; // リポジトリがまだクローンされていない場合はスキップ
-            }
+        // 既存リポジトリを開く
+        let git_repo = GitRepository::open(repo_path).map_err(|e| {
+            SyncRepositoriesError::GitOperationFailed(format!(
+                "Failed to open repository at {}: {}",
+                repo_path.display(),
+                e
+            ))
+        })?;
 
-            // 子ディレクトリでワークスペースルートを検索
-            if let Some(child_workspace_root) = Workspace::discover_workspace_root(&repo_path) {
-                // 親ワークスペースと同じ場合はスキップ（無限ループ防止）
-                if child_workspace_root == workspace.root_path {
-                    continue;
+        // リベース実行（コンフリクト時は中断して元の状態に戻る）
+        let outcome = git_repo
+            .rebase_onto_upstream(branch)
+            .map_err(|e| match e {
+                GitRepositoryError::RebaseConflict(files) => {
+                    SyncRepositoriesError::RebaseConflict {
+                        repo: repo.dest.clone(),
+                        files,
+                    }
                 }
+                e => SyncRepositoriesError::BranchSyncFailed {
+                    repo: repo.dest.clone(),
+                    error: format!("Failed to rebase branch '{}': {}", branch, e),
+                },
+            })?;
 
-                if self.config.verbose {
-                    println!("Found child workspace at: {}", child_workspace_root.display());
-                }
+        match outcome {
+            RebaseOutcome::UpToDate => info!("Branch '{}' is up to date", branch),
+            RebaseOutcome::FastForwarded => info!("Fast-forwarded branch '{}'", branch),
+            RebaseOutcome::Rebased(count) => info!(
+                "Rebased {} local commit(s) onto origin/{}",
+                count, branch
+            ),
+        }
 
-                // 子ワークスペースの同期を実行
-                match self.sync_child_workspace(&child_workspace_root, result).await {
-                    Ok(_) => {
-                        if self.config.verbose {
-                            println!("Successfully synced child workspace: {}", child_workspace_root.display());
-                        }
-                    }
-                    Err(e) => {
-                        let error_msg = format!(
-                            "Failed to sync child workspace {}: {}",
-                            child_workspace_root.display(),
-                            e
-                        );
-                        if self.config.verbose {
-                            println!("Error: {}", error_msg);
-                        }
-                        result.add_error(error_msg);
-                    }
-                }
+        Ok(())
+    }
+
+    /// upstreamのマージの実行
+    async fn perform_git_merge(
+        &self,
+        repo: &ManifestRepo,
+        repo_path: &PathBuf,
+        branch: &str,
+    ) -> Result<(), SyncRepositoriesError> {
+        use crate::infrastructure::git::repository::{
+            GitRepository, GitRepositoryError, MergeOutcome,
+        };
+
+        info!(
+                "Merging origin/{} into '{}' in {}",
+                branch,
+                branch,
+                repo_path.display()
+            );
+
+        // 既存リポジトリを開く
+        let git_repo = GitRepository::open(repo_path).map_err(|e| {
+            SyncRepositoriesError::GitOperationFailed(format!(
+                "Failed to open repository at {}: {}",
+                repo_path.display(),
+                e
+            ))
+        })?;
+
+        // マージ実行（コンフリクト時は中断して元の状態に戻る）
+        let outcome = git_repo.merge_upstream(branch).map_err(|e| match e {
+            GitRepositoryError::MergeConflict(files) => SyncRepositoriesError::MergeConflict {
+                repo: repo.dest.clone(),
+                files,
+            },
+            e => SyncRepositoriesError::BranchSyncFailed {
+                repo: repo.dest.clone(),
+                error: format!("Failed to merge branch '{}': {}", branch, e),
+            },
+        })?;
+
+        match outcome {
+            MergeOutcome::UpToDate => info!("Branch '{}' is up to date", branch),
+            MergeOutcome::FastForwarded => info!("Fast-forwarded branch '{}'", branch),
+            MergeOutcome::Merged => info!("Merged origin/{} into '{}'", branch, branch),
+        }
+
+        Ok(())
+    }
+
+    /// 子ディレクトリのワークスペースを再帰的に同期
+    async fn sync_child_workspaces(
+        &self,
+        workspace: &Workspace,
+        result: &mut SyncResult,
+    ) -> Result<(), SyncRepositoriesError> {
+        info!("Searching for child workspaces...");
+
+        // 現在のワークスペースのマニフェストを取得
+        let manifest = workspace.manifest.as_ref().ok_or_else(|| {
+            SyncRepositoriesError::ManifestUpdateFailed("Manifest not loaded".to_string())
+        })?;
+
+        // 各リポジトリディレクトリで子ワークスペースを検索
+        for repo in &manifest.repos {
+            if self.cancellation.is_cancelled() {
+                break;
+            }
+            let repo_path = workspace.resolve_dest(&repo.dest)?;
+            
+            if !repo_path.exists() {
+                continue; // リポジトリがまだクローンされていない場合はスキップ
+            }
+
+            // 子ディレクトリでワークスペースルートを検索
+            if let Some(child_workspace_root) = Workspace::discover_workspace_root(&repo_path) {
+                // 親ワークスペースと同じ場合はスキップ（無限ループ防止）
+                if child_workspace_root == workspace.root_path {
+                    continue;
+                }
+
+                info!("Found child workspace at: {}", child_workspace_root.display());
+
+                // 子ワークスペースの同期を実行
+                match self.sync_child_workspace(&child_workspace_root, result).await {
+                    Ok(_) => {
+                        info!("Successfully synced child workspace: {}", child_workspace_root.display());
+                    }
+                    Err(e) => {
+                        let error_msg = format!(
+                            "Failed to sync child workspace {}: {}",
+                            child_workspace_root.display(),
+                            e
+                        );
+                        info!("Error: {}", error_msg);
+                        result.add_error(error_msg);
+                    }
+                }
             }
         }
 
@@ -1107,7 +2404,6 @@ impl SyncRepositoriesUseCase {
     ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), SyncRepositoriesError>> + 'a>> {
         Box::pin(async move {
         use crate::domain::entities::workspace::{WorkspaceConfig, WorkspaceStatus};
-        use crate::infrastructure::filesystem::manifest_store::ManifestStore;
 
         // 子ワークスペースを作成
         let child_workspace_config = WorkspaceConfig::default_local();
@@ -1122,9 +2418,11 @@ impl SyncRepositoriesUseCase {
             )));
         }
 
-        // マニフェストを読み込み
-        let mut manifest_store = ManifestStore::new();
-        let processed_manifest = manifest_store
+        // マニフェストを読み込み（変更のないマニフェストは親と共有のキャッシュから返る）
+        let processed_manifest = self
+            .manifest_store
+            .lock()
+            .await
             .read_manifest(&manifest_file)
             .await
             .map_err(|e| {
@@ -1142,17 +2440,39 @@ impl SyncRepositoriesUseCase {
         // 子ワークスペース用の設定を作成（recursive=falseで無限ループを防止）
         let child_config = SyncRepositoriesConfig {
             groups: self.config.groups.clone(),
+            tags: self.config.tags.clone(),
+            exclude_tags: self.config.exclude_tags.clone(),
+            // destのパターンは親ワークスペースのリポジトリに対するもの
+            repo_patterns: Vec::new(),
             force: self.config.force,
             no_correct_branch: self.config.no_correct_branch,
             parallel_jobs: self.config.parallel_jobs,
             verbose: self.config.verbose,
             recursive: false,
+            recurse_submodules: self.config.recurse_submodules,
+            lfs: self.config.lfs,
+            clone_filter: self.config.clone_filter.clone(),
+            reference: self.config.reference.clone(),
+            rebase: self.config.rebase,
+            only_changed: self.config.only_changed,
+            resume: self.config.resume,
+            since: self.config.since,
+            update_manifest: self.config.update_manifest,
+            // マニフェストブランチの指定は親ワークスペースのマニフェストリポジトリに対するもの
+            manifest_branch: None,
+            strict_manifest: self.config.strict_manifest,
+            hooks_fatal: self.config.hooks_fatal,
             credential_profile: self.config.credential_profile.clone(),
             credential_file: self.config.credential_file.clone(),
         };
 
         // 子ワークスペースの同期実行
-        let child_use_case = SyncRepositoriesUseCase::new(child_config);
+        let child_use_case = SyncRepositoriesUseCase {
+            config: child_config,
+            progress: self.progress.clone(),
+            cancellation: self.cancellation.clone(),
+            manifest_store: self.manifest_store.clone(),
+        };
         let child_result = child_use_case.execute(&mut child_workspace).await?;
 
         // 結果をマージ
@@ -1160,7 +2480,13 @@ impl SyncRepositoriesUseCase {
         result.cloned_count += child_result.cloned_count;
         result.updated_count += child_result.updated_count;
         result.skipped_count += child_result.skipped_count;
+        result.up_to_date_count += child_result.up_to_date_count;
+        result.resumed_count += child_result.resumed_count;
+        result.recent_count += child_result.recent_count;
+        result.recovered_count += child_result.recovered_count;
         result.errors.extend(child_result.errors);
+        result.warnings.extend(child_result.warnings);
+        result.interrupted.extend(child_result.interrupted);
 
         Ok(())
         })
@@ -1172,16 +2498,33 @@ impl SyncRepositoriesUseCase {
 enum SyncOperation {
     /// 新規クローン
     Cloned,
+    /// リポジトリではないディレクトリを取り除いてクローンし直した（退避先があればその場所）
+    Recovered { backup: Option<PathBuf> },
     /// 既存リポジトリの更新
     Updated,
+    /// リモートの先端と一致していたため更新不要
+    UpToDate,
     /// スキップ（エラーまたは設定による）
     Skipped,
 }
 
+impl SyncOperation {
+    /// 進捗イベントで通知する結果
+    fn outcome(&self) -> RepoOutcome {
+        match self {
+            SyncOperation::Cloned => RepoOutcome::Cloned,
+            SyncOperation::Recovered { .. } => RepoOutcome::Recovered,
+            SyncOperation::Updated => RepoOutcome::Updated,
+            SyncOperation::UpToDate => RepoOutcome::UpToDate,
+            SyncOperation::Skipped => RepoOutcome::Skipped,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::entities::manifest::Manifest;
+    use crate::domain::entities::manifest::{Group, Manifest, PartialClone};
     use crate::domain::entities::workspace::WorkspaceConfig;
     use tempfile::TempDir;
 
@@ -1195,6 +2538,36 @@ mod tests {
         assert!(!config.verbose);
     }
 
+    #[test]
+    fn test_error_conversion_to_wmgr_error() {
+        let error: WmgrError =
+            SyncRepositoriesError::WorkspaceNotInitialized("/work".to_string()).into();
+        assert!(matches!(
+            error,
+            WmgrError::WorkspaceNotInitialized { ref workspace_path } if workspace_path == Path::new("/work")
+        ));
+
+        let error: WmgrError = SyncRepositoriesError::RebaseConflict {
+            repo: "backend".to_string(),
+            files: "Cargo.toml".to_string(),
+        }
+        .into();
+        match &error {
+            WmgrError::RepositoryError {
+                repository_name, ..
+            } => assert_eq!(repository_name.as_deref(), Some("backend")),
+            other => panic!("expected RepositoryError, got {:?}", other),
+        }
+        assert_eq!(
+            error.to_string(),
+            "Repository operation failed: Rebase conflict in repo 'backend': Cargo.toml"
+        );
+
+        let error: WmgrError =
+            SyncRepositoriesError::ManifestUpdateFailed("fetch failed".to_string()).into();
+        assert!(matches!(error, WmgrError::ManifestError { .. }));
+    }
+
     #[test]
     fn test_sync_result_creation() {
         let mut result = SyncResult::new();
@@ -1202,6 +2575,7 @@ mod tests {
         assert_eq!(result.cloned_count, 0);
         assert_eq!(result.updated_count, 0);
         assert_eq!(result.skipped_count, 0);
+        assert_eq!(result.resumed_count, 0);
         assert!(result.is_success());
 
         result.add_error("Test error".to_string());
@@ -1243,6 +2617,145 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_target_repositories_follow_configured_groups() {
+        let mut groups = std::collections::HashMap::new();
+        groups.insert(
+            "web".to_string(),
+            Group::new(vec!["frontend".to_string(), "shared".to_string()]),
+        );
+        groups.insert(
+            "backend".to_string(),
+            Group::new(vec!["api".to_string(), "shared".to_string()]),
+        );
+        let manifest = Manifest::new(
+            ["frontend", "api", "shared", "tools"]
+                .iter()
+                .map(|dest| ManifestRepo::new(format!("https://example.com/{}.git", dest), *dest))
+                .collect(),
+        )
+        .with_groups(groups);
+
+        let temp_dir = TempDir::new().unwrap();
+        let targets = |workspace_config: WorkspaceConfig, config: SyncRepositoriesConfig| {
+            let workspace = Workspace::new(temp_dir.path().to_path_buf(), workspace_config)
+                .with_manifest(manifest.clone());
+            SyncRepositoriesUseCase::new(config)
+                .determine_target_repositories(&workspace)
+                .unwrap()
+                .into_iter()
+                .map(|repo| repo.dest)
+                .collect::<Vec<_>>()
+        };
+        let web_only = || {
+            WorkspaceConfig::new("https://example.com/manifest.git", "main")
+                .with_repo_groups(vec!["web".to_string()])
+        };
+
+        // clone_all_repos = false: 設定のグループのリポジトリのみ
+        assert_eq!(
+            targets(web_only(), SyncRepositoriesConfig::default()),
+            vec!["frontend", "shared"]
+        );
+
+        // clone_all_repos = true: グループに関係なく全て
+        assert_eq!(
+            targets(
+                web_only().with_clone_all_repos(true),
+                SyncRepositoriesConfig::default()
+            ),
+            vec!["frontend", "api", "shared", "tools"]
+        );
+
+        // デフォルトグループのみの設定では全て
+        assert_eq!(
+            targets(
+                WorkspaceConfig::new("https://example.com/manifest.git", "main"),
+                SyncRepositoriesConfig::default()
+            ),
+            vec!["frontend", "api", "shared", "tools"]
+        );
+
+        // --group は設定より優先される
+        assert_eq!(
+            targets(
+                web_only(),
+                SyncRepositoriesConfig::default().with_groups(vec!["backend".to_string()])
+            ),
+            vec!["api", "shared"]
+        );
+    }
+
+    #[test]
+    fn test_target_repositories_filter_by_tags() {
+        let mut groups = std::collections::HashMap::new();
+        groups.insert(
+            "backend".to_string(),
+            Group::new(vec!["api".to_string(), "worker".to_string()]),
+        );
+        let repo = |dest: &str, tags: &[&str]| {
+            ManifestRepo::new(format!("https://example.com/{}.git", dest), dest)
+                .with_tags(tags.iter().map(|tag| tag.to_string()).collect())
+        };
+        let manifest = Manifest::new(vec![
+            repo("web", &["service"]),
+            repo("api", &["service", "rust"]),
+            repo("worker", &["rust"]),
+        ])
+        .with_groups(groups);
+
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = Workspace::new(
+            temp_dir.path().to_path_buf(),
+            WorkspaceConfig::new("https://example.com/manifest.git", "main"),
+        )
+        .with_manifest(manifest);
+        let targets = |config: SyncRepositoriesConfig| {
+            SyncRepositoriesUseCase::new(config)
+                .determine_target_repositories(&workspace)
+                .unwrap()
+                .into_iter()
+                .map(|repo| repo.dest)
+                .collect::<Vec<_>>()
+        };
+
+        // --group と --tag の両方に一致するリポジトリのみ
+        assert_eq!(
+            targets(
+                SyncRepositoriesConfig::default()
+                    .with_groups(vec!["backend".to_string()])
+                    .with_tags(vec!["service".to_string()])
+            ),
+            vec!["api"]
+        );
+
+        // グループ未指定では設定のリポジトリからタグで除外する
+        assert_eq!(
+            targets(SyncRepositoriesConfig::default().with_exclude_tags(vec!["rust".to_string()])),
+            vec!["web"]
+        );
+
+        // destのglobもグループ・タグと組み合わせ、一致しない場合はエラー
+        assert_eq!(
+            targets(
+                SyncRepositoriesConfig::default()
+                    .with_tags(vec!["rust".to_string()])
+                    .with_repo_patterns(vec!["w*".to_string()])
+            ),
+            vec!["worker"]
+        );
+        let result = SyncRepositoriesUseCase::new(
+            SyncRepositoriesConfig::default()
+                .with_groups(vec!["backend".to_string()])
+                .with_repo_patterns(vec!["web".to_string()]),
+        )
+        .determine_target_repositories(&workspace);
+        assert!(matches!(
+            result,
+            Err(SyncRepositoriesError::SelectionFailed(RepoSelectorError::NoMatch(_)))
+        ));
+    }
+
     #[test]
     fn test_sync_config_with_options() {
         let config = SyncRepositoriesConfig::default()
@@ -1303,6 +2816,7 @@ mod tests {
         assert_eq!(cloned, SyncOperation::Cloned);
         assert_ne!(cloned, updated);
         assert_ne!(updated, skipped);
+        assert_ne!(updated, SyncOperation::UpToDate);
 
         // Test debug formatting
         assert!(format!("{:?}", cloned).contains("Cloned"));
@@ -1345,4 +2859,1736 @@ mod tests {
             .to_string()
             .contains("Manifest update failed"));
     }
+
+    #[test]
+    fn test_singular_remote_rewrites_clone_urls() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main")
+            .with_singular_remote("https://mirror.internal.example.com/git/");
+        let workspace = Workspace::new(temp_dir.path().to_path_buf(), workspace_config);
+
+        let github = ManifestRepo::new("git@github.com:example/app.git", "app");
+        let gitlab = ManifestRepo::new("https://gitlab.com/group/sub/lib.git", "libs/lib");
+        let svn = ManifestRepo::with_scm("svn://svn.example.com/legacy", "legacy", ScmType::Svn);
+
+        let mirrored = SyncRepositoriesUseCase::mirrored_repo(&github, &workspace)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            mirrored.url,
+            "https://mirror.internal.example.com/git/example/app.git"
+        );
+        assert_eq!(mirrored.dest, "app");
+
+        let mirrored = SyncRepositoriesUseCase::mirrored_repo(&gitlab, &workspace)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            mirrored.url,
+            "https://mirror.internal.example.com/git/group/sub/lib.git"
+        );
+
+        // Git以外のリポジトリとsingular_remote未設定の場合は元のURLのまま
+        assert!(SyncRepositoriesUseCase::mirrored_repo(&svn, &workspace)
+            .unwrap()
+            .is_none());
+        let plain = Workspace::new(
+            temp_dir.path().to_path_buf(),
+            WorkspaceConfig::new("https://example.com/manifest.git", "main"),
+        );
+        assert!(SyncRepositoriesUseCase::mirrored_repo(&github, &plain)
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_singular_remote_repoints_existing_origin() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo_path = temp_dir.path().join("app");
+        let repo = git2::Repository::init(&repo_path).unwrap();
+        repo.remote("origin", "https://github.com/example/app.git")
+            .unwrap();
+
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main")
+            .with_singular_remote("https://mirror.internal.example.com/git");
+        let workspace = Workspace::new(temp_dir.path().to_path_buf(), workspace_config);
+        let mirrored = SyncRepositoriesUseCase::mirrored_repo(
+            &ManifestRepo::new("https://github.com/example/app.git", "app"),
+            &workspace,
+        )
+        .unwrap()
+        .unwrap();
+
+        let use_case = SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default());
+        use_case.update_remotes(&mirrored, &repo_path).await.unwrap();
+
+        assert_eq!(
+            repo.find_remote("origin").unwrap().url(),
+            Some("https://mirror.internal.example.com/git/example/app.git")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_invalid_singular_remote_is_rejected() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main")
+            .with_singular_remote("mirror.internal.example.com/git");
+        let mut workspace = Workspace::new(temp_dir.path().to_path_buf(), workspace_config)
+            .with_status(WorkspaceStatus::Initialized)
+            .with_manifest(Manifest::new(vec![]));
+
+        let use_case = SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default());
+        assert!(matches!(
+            use_case.execute(&mut workspace).await,
+            Err(SyncRepositoriesError::GitUrlError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_missing_reference_directory_is_rejected() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut workspace = Workspace::new(
+            temp_dir.path().to_path_buf(),
+            WorkspaceConfig::new("https://example.com/manifest.git", "main"),
+        )
+        .with_status(WorkspaceStatus::Initialized)
+        .with_manifest(Manifest::new(vec![]));
+
+        let use_case = SyncRepositoriesUseCase::new(
+            SyncRepositoriesConfig::default().with_reference(temp_dir.path().join("mirror")),
+        );
+        assert!(matches!(
+            use_case.execute(&mut workspace).await,
+            Err(SyncRepositoriesError::ReferenceNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_clone_borrows_objects_from_reference() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let source = git2::Repository::init(root.join("sources/app")).unwrap();
+        commit_file(&source, "README", "app");
+        git2::Repository::init(root.join("sources/lib")).unwrap();
+
+        let url = |name: &str| format!("file://{}", root.join("sources").join(name).display());
+        let mut app = ManifestRepo::new(url("app"), "app");
+        app.reference = Some("../sources/app".to_string());
+        // 参照先が存在しない場合は通常のクローンになる
+        let mut lib = ManifestRepo::new(url("lib"), "lib");
+        lib.reference = Some("../sources/missing".to_string());
+        let repos = vec![app, lib];
+        let workspace = Workspace::new(
+            root.join("workspace"),
+            WorkspaceConfig::new("https://example.com/manifest.git", "main"),
+        )
+        .with_manifest(Manifest::new(repos.clone()));
+
+        let use_case = SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default());
+        let mut result = SyncResult::new();
+        use_case
+            .sync_repositories(&repos, &workspace, &mut result)
+            .await
+            .unwrap();
+        assert!(result.is_success(), "{:?}", result.errors);
+        assert_eq!(result.cloned_count, 2);
+
+        let alternates =
+            std::fs::read_to_string(root.join("workspace/app/.git/objects/info/alternates"))
+                .unwrap();
+        assert!(
+            alternates.contains("sources/app/.git/objects"),
+            "{}",
+            alternates
+        );
+        assert!(!root
+            .join("workspace/lib/.git/objects/info/alternates")
+            .exists());
+    }
+
+    #[tokio::test]
+    async fn test_since_skips_recently_fetched_repositories() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let source = git2::Repository::init(root.join("source")).unwrap();
+        commit_file(&source, "README", "source");
+
+        let url = format!("file://{}", root.join("source").display());
+        let repos = vec![
+            ManifestRepo::new(&url, "recent"),
+            ManifestRepo::new(&url, "stale"),
+        ];
+        let workspace = Workspace::new(
+            root.join("workspace"),
+            WorkspaceConfig::new("https://example.com/manifest.git", "main"),
+        )
+        .with_manifest(Manifest::new(repos.clone()));
+
+        let hour_ago = std::time::SystemTime::now() - Duration::from_secs(60 * 60);
+        for (dest, fetched) in [
+            ("recent", std::time::SystemTime::now()),
+            ("stale", hour_ago),
+        ] {
+            let repo_path = root.join("workspace").join(dest);
+            git2::Repository::clone(&url, &repo_path).unwrap();
+            let fetch_head = repo_path.join(".git").join("FETCH_HEAD");
+            std::fs::write(&fetch_head, "").unwrap();
+            std::fs::File::options()
+                .write(true)
+                .open(&fetch_head)
+                .unwrap()
+                .set_modified(fetched)
+                .unwrap();
+        }
+
+        let use_case = SyncRepositoriesUseCase::new(
+            SyncRepositoriesConfig::default().with_since(Duration::from_secs(30 * 60)),
+        );
+        let mut result = SyncResult::new();
+        use_case
+            .sync_repositories(&repos, &workspace, &mut result)
+            .await
+            .unwrap();
+        assert!(result.is_success(), "{:?}", result.errors);
+        assert_eq!(result.recent_count, 1);
+        assert_eq!(result.updated_count, 1);
+        assert_eq!(result.synced_count, 1);
+        assert_eq!(result.total_count(), 2);
+        assert_eq!(result.environment()["WMGR_SYNC_RECENT"], "1");
+    }
+
+    fn commit_file(repo: &git2::Repository, name: &str, content: &str) {
+        std::fs::write(repo.workdir().unwrap().join(name), content).unwrap();
+        let mut index = repo.index().unwrap();
+        index.add_path(std::path::Path::new(name)).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let signature = git2::Signature::now("wmgr", "wmgr@example.com").unwrap();
+        let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+        let parents: Vec<&git2::Commit> = parent.iter().collect();
+        repo.commit(Some("HEAD"), &signature, &signature, name, &tree, &parents)
+            .unwrap();
+    }
+
+    fn push_main(repo: &git2::Repository) {
+        repo.find_remote("origin")
+            .unwrap()
+            .push(&["refs/heads/main:refs/heads/main"], None)
+            .unwrap();
+    }
+
+    /// 固定のリビジョンを返すテスト用SCM
+    struct MockScm {
+        local_tip: String,
+        remote_tip: Option<String>,
+    }
+
+    impl crate::infrastructure::scm::scm_interface::AsAny for MockScm {
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl ScmOperations for MockScm {
+        async fn clone_repository(
+            &self,
+            _url: &str,
+            _dest_path: &std::path::Path,
+            _options: &crate::infrastructure::scm::CloneOptions,
+        ) -> Result<(), ScmError> {
+            unreachable!("clone is not used in these tests")
+        }
+
+        async fn sync_repository(
+            &self,
+            _repo_path: &std::path::Path,
+            _options: &crate::infrastructure::scm::SyncOptions,
+        ) -> Result<(), ScmError> {
+            unreachable!("sync is not used in these tests")
+        }
+
+        async fn fetch(&self, _repo_path: &std::path::Path) -> Result<(), ScmError> {
+            unreachable!("fetch is not used in these tests")
+        }
+
+        async fn get_status(
+            &self,
+            _repo_path: &std::path::Path,
+        ) -> Result<crate::infrastructure::scm::StatusResult, ScmError> {
+            unreachable!("status is not used in these tests")
+        }
+
+        fn is_repository(&self, _path: &std::path::Path) -> bool {
+            true
+        }
+
+        fn scm_type(&self) -> ScmType {
+            ScmType::Git
+        }
+
+        async fn get_current_revision(
+            &self,
+            _repo_path: &std::path::Path,
+        ) -> Result<String, ScmError> {
+            Ok(self.local_tip.clone())
+        }
+
+        async fn has_changes(&self, _repo_path: &std::path::Path) -> Result<bool, ScmError> {
+            Ok(false)
+        }
+
+        async fn get_remote_revision(
+            &self,
+            _repo_path: &std::path::Path,
+            _branch: Option<&str>,
+        ) -> Result<Option<String>, ScmError> {
+            Ok(self.remote_tip.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_only_changed_remote_tip_comparison() {
+        let use_case =
+            SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default().with_only_changed(true));
+        let repo = ManifestRepo::new("https://github.com/example/repo.git", "repo");
+        let repo_path = PathBuf::from("/tmp/repo");
+
+        let matching = MockScm {
+            local_tip: "abc123".to_string(),
+            remote_tip: Some("abc123".to_string()),
+        };
+        assert!(use_case.is_at_remote_tip(&matching, &repo, &repo_path).await);
+
+        let differing = MockScm {
+            local_tip: "abc123".to_string(),
+            remote_tip: Some("def456".to_string()),
+        };
+        assert!(!use_case.is_at_remote_tip(&differing, &repo, &repo_path).await);
+
+        // リモートの先端が不明な場合は通常の同期を行う
+        let unknown = MockScm {
+            local_tip: "abc123".to_string(),
+            remote_tip: None,
+        };
+        assert!(!use_case.is_at_remote_tip(&unknown, &repo, &repo_path).await);
+
+        // 固定リビジョンのリポジトリは対象外
+        let pinned = repo.clone().with_revision("abc123");
+        assert!(!use_case.is_at_remote_tip(&matching, &pinned, &repo_path).await);
+    }
+
+    #[test]
+    fn test_resolve_sync_strategy_mixed_manifest() {
+        use crate::domain::entities::manifest::SyncStrategy;
+
+        let manifest = Manifest::new(vec![
+            ManifestRepo::new("https://github.com/example/a.git", "a")
+                .with_sync_strategy(SyncStrategy::FfOnly),
+            ManifestRepo::new("https://github.com/example/b.git", "b")
+                .with_sync_strategy(SyncStrategy::Rebase),
+            ManifestRepo::new("https://github.com/example/c.git", "c")
+                .with_sync_strategy(SyncStrategy::Merge),
+            ManifestRepo::new("https://github.com/example/d.git", "d"),
+        ])
+        .with_default_sync_strategy(SyncStrategy::Merge);
+        let temp_dir = TempDir::new().unwrap();
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let workspace = Workspace::new(temp_dir.path().to_path_buf(), workspace_config)
+            .with_manifest(manifest.clone());
+
+        let use_case = SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default());
+        let strategies: Vec<_> = manifest
+            .repos
+            .iter()
+            .map(|repo| use_case.resolve_sync_strategy(repo, &workspace))
+            .collect();
+        assert_eq!(
+            strategies,
+            vec![
+                SyncStrategy::FfOnly,
+                SyncStrategy::Rebase,
+                SyncStrategy::Merge,
+                SyncStrategy::Merge,
+            ]
+        );
+
+        // --rebase はマニフェストの設定より優先される
+        let use_case =
+            SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default().with_rebase(true));
+        assert!(manifest
+            .repos
+            .iter()
+            .all(|repo| use_case.resolve_sync_strategy(repo, &workspace) == SyncStrategy::Rebase));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_resume_after_partial_failure() {
+        use crate::infrastructure::filesystem::sync_journal::SYNC_JOURNAL_FILE_NAME;
+
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        std::fs::create_dir_all(root.join("targets/first")).unwrap();
+        std::fs::create_dir_all(root.join("targets/third")).unwrap();
+
+        let symlink_repo = |target: &str, dest: &str| {
+            ManifestRepo::with_scm(target, dest, ScmType::Symlink)
+        };
+        let repos = vec![
+            symlink_repo("targets/first", "first"),
+            // 2つ目はリンク先が存在しないため失敗する
+            symlink_repo("targets/second", "second"),
+            symlink_repo("targets/third", "third"),
+        ];
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let workspace = Workspace::new(root.to_path_buf(), workspace_config);
+        let journal_path = root.join(".wmgr").join(SYNC_JOURNAL_FILE_NAME);
+
+        // 1回目: 途中のリポジトリが失敗
+        let use_case = SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default());
+        let mut result = SyncResult::new();
+        use_case
+            .sync_repositories(&repos, &workspace, &mut result)
+            .await
+            .unwrap();
+        assert_eq!(result.cloned_count, 2);
+        assert_eq!(result.errors.len(), 1);
+        assert!(journal_path.exists());
+
+        let journal = SyncJournal::load(&workspace.wmgr_dir()).unwrap();
+        assert!(journal.is_completed("first"));
+        assert!(!journal.is_completed("second"));
+        assert!(journal.is_completed("third"));
+
+        // 完了済みのリポジトリが再処理されないことを確認するためリンクを削除
+        std::fs::remove_file(root.join("first")).unwrap();
+        std::fs::remove_file(root.join("third")).unwrap();
+        std::fs::create_dir_all(root.join("targets/second")).unwrap();
+
+        // 2回目: --continue で残りのリポジトリのみ処理
+        let use_case =
+            SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default().with_resume(true));
+        let mut result = SyncResult::new();
+        use_case
+            .sync_repositories(&repos, &workspace, &mut result)
+            .await
+            .unwrap();
+        assert!(result.is_success());
+        assert_eq!(result.resumed_count, 2);
+        assert_eq!(result.cloned_count, 1);
+        assert!(root.join("second").is_symlink());
+        assert!(!root.join("first").exists());
+        assert!(!root.join("third").exists());
+
+        // 全て成功したためジャーナルは削除される
+        assert!(!journal_path.exists());
+    }
+
+    #[test]
+    fn test_resolve_shallow_precedence() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut pinned = ManifestRepo::new("https://github.com/example/pinned.git", "pinned");
+        pinned.shallow = Some(false);
+        let plain = ManifestRepo::new("https://github.com/example/plain.git", "plain");
+        let use_case = SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default());
+        let workspace = |shallow_clones: bool, manifest: Manifest| {
+            let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main")
+                .with_shallow_clones(shallow_clones);
+            Workspace::new(temp_dir.path().to_path_buf(), workspace_config).with_manifest(manifest)
+        };
+
+        // 何も設定されていなければfalse
+        let ws = workspace(false, Manifest::new(vec![]));
+        assert!(!use_case.resolve_shallow(&plain, &ws));
+
+        // ワークスペース設定
+        let ws = workspace(true, Manifest::new(vec![]));
+        assert!(use_case.resolve_shallow(&plain, &ws));
+
+        // マニフェストのデフォルトはワークスペース設定より優先
+        let ws = workspace(true, Manifest::new(vec![]).with_default_shallow(false));
+        assert!(!use_case.resolve_shallow(&plain, &ws));
+        let ws = workspace(false, Manifest::new(vec![]).with_default_shallow(true));
+        assert!(use_case.resolve_shallow(&plain, &ws));
+
+        // リポジトリ設定が最優先
+        assert!(!use_case.resolve_shallow(&pinned, &ws));
+    }
+
+    #[tokio::test]
+    async fn test_shallow_setting_change_converts_existing_clone() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+
+        let remote_path = root.join("remote.git");
+        git2::Repository::init_bare(&remote_path).unwrap();
+        let seed = git2::Repository::init(root.join("seed")).unwrap();
+        seed.set_head("refs/heads/main").unwrap();
+        seed.remote("origin", remote_path.to_str().unwrap()).unwrap();
+        for content in ["one", "two", "three"] {
+            commit_file(&seed, "README", content);
+        }
+        push_main(&seed);
+
+        // ローカルパスではgit cloneが--depthを無視するためfile://で指定する
+        let url = format!("file://{}", remote_path.display());
+        let mut repo = ManifestRepo::new(&url, "app").with_branch("main");
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main")
+            .with_shallow_clones(true);
+        let workspace = Workspace::new(root.to_path_buf(), workspace_config)
+            .with_manifest(Manifest::new(vec![repo.clone()]));
+        let use_case = SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default());
+
+        // ワークスペース設定に従ってshallow cloneされる
+        assert_eq!(
+            use_case
+                .sync_single_repository(&repo, &workspace)
+                .await
+                .unwrap(),
+            SyncOperation::Cloned
+        );
+        let app = git2::Repository::open(root.join("app")).unwrap();
+        assert!(app.is_shallow());
+
+        // リポジトリ設定でshallowを無効にすると次の同期で完全なクローンになる
+        repo.shallow = Some(false);
+        use_case
+            .sync_single_repository(&repo, &workspace)
+            .await
+            .unwrap();
+        let app = git2::Repository::open(root.join("app")).unwrap();
+        assert!(!app.is_shallow());
+        let mut revwalk = app.revwalk().unwrap();
+        revwalk.push_head().unwrap();
+        assert_eq!(revwalk.count(), 3);
+
+        // 再び有効にするとshallowに戻る
+        repo.shallow = Some(true);
+        use_case
+            .sync_single_repository(&repo, &workspace)
+            .await
+            .unwrap();
+        assert!(git2::Repository::open(root.join("app")).unwrap().is_shallow());
+    }
+
+    /// `branch`にコミットがあり、HEADがそれを指すベアリポジトリ
+    fn bare_remote_with_head(root: &Path, branch: &str) -> PathBuf {
+        let remote_path = root.join("remote.git");
+        let remote = git2::Repository::init_bare(&remote_path).unwrap();
+        let seed = git2::Repository::init(root.join("seed")).unwrap();
+        seed.set_head(&format!("refs/heads/{}", branch)).unwrap();
+        seed.remote("origin", remote_path.to_str().unwrap()).unwrap();
+        commit_file(&seed, "README", "seed");
+        let refspec = format!("refs/heads/{0}:refs/heads/{0}", branch);
+        seed.find_remote("origin")
+            .unwrap()
+            .push(&[refspec.as_str()], None)
+            .unwrap();
+        remote.set_head(&format!("refs/heads/{}", branch)).unwrap();
+        remote_path
+    }
+
+    fn workspace_with(root: &Path, manifest: Manifest) -> Workspace {
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        Workspace::new(root.to_path_buf(), workspace_config).with_manifest(manifest)
+    }
+
+    #[test]
+    fn test_default_branch_fallback_chain() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let remote_path = bare_remote_with_head(root, "master");
+        git2::Repository::clone(remote_path.to_str().unwrap(), root.join("app")).unwrap();
+        git2::Repository::init(root.join("local")).unwrap();
+
+        let use_case = SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default());
+        let url = remote_path.to_str().unwrap();
+        let branch_of = |repo: &ManifestRepo, manifest: Manifest| {
+            let workspace = workspace_with(root, manifest);
+            use_case
+                .repo_with_default_branch(
+                    repo,
+                    &workspace,
+                    &workspace.resolve_dest(&repo.dest).unwrap(),
+                )
+                .and_then(|repo| repo.branch)
+        };
+
+        // リポジトリ設定があればそのまま使う
+        let pinned = ManifestRepo::new(url, "app").with_branch("release");
+        assert_eq!(branch_of(&pinned, Manifest::new(vec![])), None);
+
+        // マニフェストのdefault_branchはリモートのHEADより優先
+        let app = ManifestRepo::new(url, "app");
+        assert_eq!(
+            branch_of(&app, Manifest::new(vec![]).with_default_branch("develop")),
+            Some("develop".to_string())
+        );
+
+        // どちらもなければリモートのHEAD
+        assert_eq!(
+            branch_of(&app, Manifest::new(vec![])),
+            Some("master".to_string())
+        );
+
+        // リモートのHEADも判別できなければmain
+        let local = ManifestRepo::new(url, "local");
+        assert_eq!(
+            branch_of(&local, Manifest::new(vec![])),
+            Some("main".to_string())
+        );
+
+        // クローン前はマニフェストのdefault_branchだけを使い、それ以外はクローンに任せる
+        let missing = ManifestRepo::new(url, "missing");
+        assert_eq!(branch_of(&missing, Manifest::new(vec![])), None);
+        assert_eq!(
+            branch_of(&missing, Manifest::new(vec![]).with_default_branch("develop")),
+            Some("develop".to_string())
+        );
+
+        // --no-correct-branchでは既存クローンのブランチを変えない
+        let use_case = SyncRepositoriesUseCase::new(
+            SyncRepositoriesConfig::default().with_no_correct_branch(true),
+        );
+        let workspace = workspace_with(root, Manifest::new(vec![]));
+        assert!(use_case
+            .repo_with_default_branch(&app, &workspace, &root.join("app"))
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_sync_checks_out_remote_default_branch() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let remote_path = bare_remote_with_head(root, "master");
+
+        let repo = ManifestRepo::new(remote_path.to_str().unwrap(), "app");
+        let workspace = workspace_with(root, Manifest::new(vec![repo.clone()]));
+        let use_case = SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default());
+        use_case
+            .sync_single_repository(&repo, &workspace)
+            .await
+            .unwrap();
+
+        // 別のブランチに移っていても、次の同期でリモートのデフォルトブランチに戻る
+        let app = git2::Repository::open(root.join("app")).unwrap();
+        let head = app.head().unwrap().peel_to_commit().unwrap();
+        app.branch("topic", &head, false).unwrap();
+        app.set_head("refs/heads/topic").unwrap();
+
+        use_case
+            .sync_single_repository(&repo, &workspace)
+            .await
+            .unwrap();
+        let app = git2::Repository::open(root.join("app")).unwrap();
+        assert_eq!(app.head().unwrap().shorthand(), Some("master"));
+    }
+
+    #[tokio::test]
+    async fn test_update_manifest_pulls_manifest_repository() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+
+        // マニフェストリポジトリのリモートと、そこからクローンしたワークスペース
+        let remote_path = root.join("manifest.git");
+        git2::Repository::init_bare(&remote_path)
+            .unwrap()
+            .set_head("refs/heads/main")
+            .unwrap();
+        let upstream = git2::Repository::init(root.join("upstream")).unwrap();
+        upstream.set_head("refs/heads/main").unwrap();
+        upstream
+            .remote("origin", remote_path.to_str().unwrap())
+            .unwrap();
+        let app = "repos:\n  - dest: app\n    url: https://github.com/example/app.git\n";
+        commit_file(&upstream, "wmgr.yml", app);
+        push_main(&upstream);
+
+        let workspace_root = root.join("workspace");
+        git2::Repository::clone(
+            remote_path.to_str().unwrap(),
+            workspace_root.join(".wmgr").join("manifest"),
+        )
+        .unwrap();
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main")
+            .with_manifest_repo(PathBuf::from(".wmgr").join("manifest"));
+        let mut workspace = Workspace::new(workspace_root, workspace_config)
+            .with_status(WorkspaceStatus::Initialized);
+
+        // 上流でリポジトリが追加される
+        let with_lib = format!(
+            "{}  - dest: lib\n    url: https://github.com/example/lib.git\n",
+            app
+        );
+        commit_file(&upstream, "wmgr.yml", &with_lib);
+        push_main(&upstream);
+
+        let dests = |workspace: &Workspace| -> Vec<String> {
+            let manifest = workspace.manifest.as_ref().unwrap();
+            manifest.repos.iter().map(|r| r.dest.clone()).collect()
+        };
+
+        // フラグなしではローカルのクローンをそのまま読み込む
+        SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default())
+            .update_manifest(&mut workspace)
+            .await
+            .unwrap();
+        assert_eq!(dests(&workspace), ["app"]);
+
+        // --update-manifestでは上流の変更を取り込んでから読み込む
+        SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default().with_update_manifest(true))
+            .update_manifest(&mut workspace)
+            .await
+            .unwrap();
+        assert_eq!(dests(&workspace), ["app", "lib"]);
+    }
+
+    #[tokio::test]
+    async fn test_manifest_branch_override_loads_other_branch() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+
+        // mainにはapp、releaseにはappとlibを持つマニフェストリポジトリ
+        let remote_path = root.join("manifest.git");
+        git2::Repository::init_bare(&remote_path)
+            .unwrap()
+            .set_head("refs/heads/main")
+            .unwrap();
+        let upstream = git2::Repository::init(root.join("upstream")).unwrap();
+        upstream.set_head("refs/heads/main").unwrap();
+        upstream
+            .remote("origin", remote_path.to_str().unwrap())
+            .unwrap();
+        let app = "repos:\n  - dest: app\n    url: https://github.com/example/app.git\n";
+        commit_file(&upstream, "wmgr.yml", app);
+        push_main(&upstream);
+
+        let workspace_root = root.join("workspace");
+        let manifest_repo = workspace_root.join(".wmgr").join("manifest");
+        git2::Repository::clone(remote_path.to_str().unwrap(), &manifest_repo).unwrap();
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main")
+            .with_manifest_repo(PathBuf::from(".wmgr").join("manifest"));
+        let mut workspace = Workspace::new(workspace_root, workspace_config)
+            .with_status(WorkspaceStatus::Initialized);
+
+        // クローン後にreleaseブランチが作られる
+        let head = upstream.head().unwrap().peel_to_commit().unwrap();
+        upstream.branch("release", &head, false).unwrap();
+        upstream.set_head("refs/heads/release").unwrap();
+        let with_lib = format!(
+            "{}  - dest: lib\n    url: https://github.com/example/lib.git\n",
+            app
+        );
+        commit_file(&upstream, "wmgr.yml", &with_lib);
+        upstream
+            .find_remote("origin")
+            .unwrap()
+            .push(&["refs/heads/release:refs/heads/release"], None)
+            .unwrap();
+
+        let dests = |workspace: &Workspace| -> Vec<String> {
+            let manifest = workspace.manifest.as_ref().unwrap();
+            manifest.repos.iter().map(|r| r.dest.clone()).collect()
+        };
+
+        SyncRepositoriesUseCase::new(
+            SyncRepositoriesConfig::default().with_manifest_branch("release"),
+        )
+        .update_manifest(&mut workspace)
+        .await
+        .unwrap();
+        assert_eq!(dests(&workspace), ["app", "lib"]);
+
+        // マニフェストリポジトリと記録されたブランチは元のまま
+        let clone = git2::Repository::open(&manifest_repo).unwrap();
+        assert_eq!(clone.head().unwrap().name(), Some("refs/heads/main"));
+        assert_eq!(
+            std::fs::read_to_string(manifest_repo.join("wmgr.yml")).unwrap(),
+            app
+        );
+        assert_eq!(workspace.config.manifest_branch, "main");
+
+        SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default())
+            .update_manifest(&mut workspace)
+            .await
+            .unwrap();
+        assert_eq!(dests(&workspace), ["app"]);
+
+        // 存在しないブランチ・不正なブランチ名はエラー
+        let result = SyncRepositoriesUseCase::new(
+            SyncRepositoriesConfig::default().with_manifest_branch("missing"),
+        )
+        .update_manifest(&mut workspace)
+        .await;
+        assert!(matches!(
+            result,
+            Err(SyncRepositoriesError::ManifestUpdateFailed(message))
+                if message.contains("Manifest branch 'missing' not found")
+        ));
+        let result = SyncRepositoriesUseCase::new(
+            SyncRepositoriesConfig::default().with_manifest_branch("bad..name"),
+        )
+        .update_manifest(&mut workspace)
+        .await;
+        assert!(matches!(
+            result,
+            Err(SyncRepositoriesError::BranchNameError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_manifest_branch_override_requires_manifest_repository() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("wmgr.yml"),
+            "repos:\n  - dest: app\n    url: https://github.com/example/app.git\n",
+        )
+        .unwrap();
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let mut workspace = Workspace::new(temp_dir.path().to_path_buf(), workspace_config)
+            .with_status(WorkspaceStatus::Initialized);
+
+        let result = SyncRepositoriesUseCase::new(
+            SyncRepositoriesConfig::default().with_manifest_branch("release"),
+        )
+        .update_manifest(&mut workspace)
+        .await;
+        assert!(matches!(
+            result,
+            Err(SyncRepositoriesError::ManifestUpdateFailed(message))
+                if message.contains("no manifest repository")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_update_manifest_without_manifest_repository_uses_local_file() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("wmgr.yml"),
+            "repos:\n  - dest: app\n    url: https://github.com/example/app.git\n",
+        )
+        .unwrap();
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let mut workspace = Workspace::new(temp_dir.path().to_path_buf(), workspace_config)
+            .with_status(WorkspaceStatus::Initialized);
+
+        SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default().with_update_manifest(true))
+            .update_manifest(&mut workspace)
+            .await
+            .unwrap();
+        assert_eq!(workspace.manifest.unwrap().repos[0].dest, "app");
+    }
+
+    #[tokio::test]
+    async fn test_sync_records_repos_dropped_by_manifest_update() {
+        use crate::infrastructure::filesystem::managed_repos::ManagedRepos;
+
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        std::fs::create_dir(root.join("app")).unwrap();
+        std::fs::create_dir(root.join("old")).unwrap();
+        // 読み込み済みのマニフェストにはoldがあるが、更新後のマニフェストにはない
+        std::fs::write(
+            root.join("wmgr.yml"),
+            "repos:\n  - dest: app\n    url: \"\"\n",
+        )
+        .unwrap();
+        let mut workspace = Workspace::new(
+            root.to_path_buf(),
+            WorkspaceConfig::new(root.join("wmgr.yml").display().to_string(), "main"),
+        )
+        .with_status(WorkspaceStatus::Initialized)
+        .with_manifest(Manifest::new(vec![
+            ManifestRepo::new("", "app"),
+            ManifestRepo::new("", "old"),
+            ManifestRepo::new("", "never-cloned"),
+        ]));
+
+        SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default())
+            .execute(&mut workspace)
+            .await
+            .unwrap();
+        let managed = ManagedRepos::load(&workspace.wmgr_dir()).unwrap();
+        assert_eq!(managed.dests.iter().collect::<Vec<_>>(), ["app", "old"]);
+
+        // ディレクトリがなくなったdestは記録から外れる
+        std::fs::remove_dir(root.join("old")).unwrap();
+        SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default())
+            .execute(&mut workspace)
+            .await
+            .unwrap();
+        let managed = ManagedRepos::load(&workspace.wmgr_dir()).unwrap();
+        assert_eq!(managed.dests.iter().collect::<Vec<_>>(), ["app"]);
+    }
+
+    #[tokio::test]
+    async fn test_manifest_cache_is_shared_with_child_workspaces() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        std::fs::write(
+            root.join("wmgr.yml"),
+            "repos:\n  - dest: child\n    url: \"\"\n",
+        )
+        .unwrap();
+        let child = git2::Repository::init(root.join("child")).unwrap();
+        commit_file(&child, "README", "child");
+        std::fs::write(
+            root.join("child/wmgr.yml"),
+            "repos:\n  - dest: inner\n    url: \"\"\n",
+        )
+        .unwrap();
+        let mut workspace = Workspace::new(
+            root.to_path_buf(),
+            WorkspaceConfig::new(root.join("wmgr.yml").display().to_string(), "main"),
+        )
+        .with_status(WorkspaceStatus::Initialized);
+        let use_case = SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default());
+
+        // 1回目: 親と子のマニフェストを1回ずつ解析し、子ワークスペースの同期での
+        // 再読み込みはキャッシュから返る
+        use_case.execute(&mut workspace).await.unwrap();
+        let stats = use_case.manifest_cache_stats().await;
+        assert_eq!((stats.hits, stats.misses, stats.entries), (1, 2, 2));
+
+        // 2回目: どのマニフェストも再解析しない
+        use_case.execute(&mut workspace).await.unwrap();
+        let stats = use_case.manifest_cache_stats().await;
+        assert_eq!((stats.hits, stats.misses, stats.entries), (4, 2, 2));
+    }
+
+    #[tokio::test]
+    async fn test_strict_manifest_applies_to_reloaded_and_child_manifests() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        std::fs::write(
+            root.join("wmgr.yml"),
+            "repos:\n  - dest: child\n    url: \"\"\n",
+        )
+        .unwrap();
+        let child = git2::Repository::init(root.join("child")).unwrap();
+        commit_file(&child, "README", "child");
+        // 大文字小文字だけが異なるdest
+        let colliding = "repos:\n  - dest: Foo\n    url: \"\"\n  - dest: foo\n    url: \"\"\n";
+        std::fs::write(root.join("child/wmgr.yml"), colliding).unwrap();
+        let workspace = || {
+            Workspace::new(
+                root.to_path_buf(),
+                WorkspaceConfig::new(root.join("wmgr.yml").display().to_string(), "main"),
+            )
+            .with_status(WorkspaceStatus::Initialized)
+        };
+        let config = SyncRepositoriesConfig::default();
+
+        // 子ワークスペースのマニフェストの警告は既定ではエラーにならない
+        let result = SyncRepositoriesUseCase::new(config.clone())
+            .execute(&mut workspace())
+            .await
+            .unwrap();
+        assert!(
+            !result.errors.iter().any(|e| e.contains("collide")),
+            "{:?}",
+            result.errors
+        );
+
+        // strictでは子ワークスペースのマニフェストを読み込めない
+        let strict = config.with_strict_manifest(true);
+        let result = SyncRepositoriesUseCase::new(strict.clone())
+            .execute(&mut workspace())
+            .await
+            .unwrap();
+        assert!(
+            result.errors.iter().any(|e| e.contains("collide")),
+            "{:?}",
+            result.errors
+        );
+
+        // 同期の最初に再読み込みするワークスペースのマニフェストも同様
+        std::fs::write(root.join("wmgr.yml"), colliding).unwrap();
+        let error = SyncRepositoriesUseCase::new(strict)
+            .execute(&mut workspace())
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("collide"), "{}", error);
+    }
+
+    #[tokio::test]
+    async fn test_sync_rebase_reports_conflicts_per_repo() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+
+        // 共有リモートと初期コミット
+        let remote_path = root.join("remote.git");
+        git2::Repository::init_bare(&remote_path).unwrap();
+        let seed = git2::Repository::init(root.join("seed")).unwrap();
+        seed.set_head("refs/heads/main").unwrap();
+        commit_file(&seed, "README", "seed");
+        seed.remote("origin", remote_path.to_str().unwrap()).unwrap();
+        push_main(&seed);
+
+        let clone = |name: &str| {
+            git2::build::RepoBuilder::new()
+                .branch("main")
+                .clone(remote_path.to_str().unwrap(), &root.join(name))
+                .unwrap()
+        };
+        let clean = clone("clean");
+        let conflicting = clone("conflicting");
+
+        // リモート側でREADMEを変更
+        commit_file(&seed, "README", "remote change");
+        push_main(&seed);
+
+        // 各リポジトリでローカルコミットを作成して分岐させる
+        commit_file(&clean, "local.txt", "local");
+        commit_file(&conflicting, "README", "local change");
+
+        let repos = vec![
+            ManifestRepo::new("https://github.com/example/clean.git", "clean"),
+            ManifestRepo::new("https://github.com/example/conflicting.git", "conflicting"),
+        ];
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let workspace = Workspace::new(root.to_path_buf(), workspace_config);
+
+        let config = SyncRepositoriesConfig::default().with_rebase(true);
+        let use_case = SyncRepositoriesUseCase::new(config);
+        let mut result = SyncResult::new();
+        use_case
+            .sync_repositories(&repos, &workspace, &mut result)
+            .await
+            .unwrap();
+
+        // クリーンに分岐したリポジトリはリベースされる
+        assert_eq!(result.updated_count, 1);
+        let head = clean.head().unwrap().peel_to_commit().unwrap();
+        assert_eq!(head.message(), Some("local.txt"));
+        assert_eq!(
+            std::fs::read_to_string(root.join("clean/README")).unwrap(),
+            "remote change"
+        );
+
+        // コンフリクトはリポジトリ単位で報告され、全体は中断されない
+        assert_eq!(result.errors.len(), 1);
+        assert!(result.errors[0].contains("conflicting"));
+        assert!(result.errors[0].contains("Rebase conflict"));
+        assert_eq!(conflicting.state(), git2::RepositoryState::Clean);
+        assert_eq!(
+            std::fs::read_to_string(root.join("conflicting/README")).unwrap(),
+            "local change"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_post_clone_hook_runs_once_on_clone() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+
+        let remote_path = root.join("remote.git");
+        git2::Repository::init_bare(&remote_path).unwrap();
+        let seed = git2::Repository::init(root.join("seed")).unwrap();
+        seed.set_head("refs/heads/main").unwrap();
+        commit_file(&seed, "README", "seed");
+        seed.remote("origin", remote_path.to_str().unwrap()).unwrap();
+        push_main(&seed);
+
+        // フックはリポジトリのディレクトリで実行され、WMGR_REPO_*が設定される
+        let url = format!("file://{}", remote_path.display());
+        let repos = vec![ManifestRepo::new(&url, "app")
+            .with_branch("main")
+            .with_post_clone("echo \"clone $WMGR_REPO_DEST $(basename $PWD)\" >> ../hooks.log")
+            .with_post_sync("echo \"sync $WMGR_REPO_DEST\" >> ../hooks.log")];
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let workspace = Workspace::new(root.to_path_buf(), workspace_config)
+            .with_manifest(Manifest::new(repos.clone()));
+        let hooks_log = || std::fs::read_to_string(root.join("hooks.log")).unwrap();
+
+        let use_case =
+            SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default().with_only_changed(true));
+        let mut result = SyncResult::new();
+        use_case
+            .sync_repositories(&repos, &workspace, &mut result)
+            .await
+            .unwrap();
+        assert!(result.is_success());
+        assert_eq!(result.cloned_count, 1);
+        assert_eq!(hooks_log(), "clone app app\n");
+
+        // 更新不要の同期ではフックを実行しない
+        let mut result = SyncResult::new();
+        use_case
+            .sync_repositories(&repos, &workspace, &mut result)
+            .await
+            .unwrap();
+        assert_eq!(result.up_to_date_count, 1);
+        assert_eq!(hooks_log(), "clone app app\n");
+
+        // 更新した場合はpost_syncのみ実行する
+        commit_file(&seed, "README", "update");
+        push_main(&seed);
+        let mut result = SyncResult::new();
+        use_case
+            .sync_repositories(&repos, &workspace, &mut result)
+            .await
+            .unwrap();
+        assert_eq!(result.updated_count, 1);
+        assert_eq!(hooks_log(), "clone app app\nsync app\n");
+    }
+
+    #[tokio::test]
+    async fn test_failing_hook_is_reported_per_repo() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        std::fs::create_dir_all(root.join("targets/first")).unwrap();
+        std::fs::create_dir_all(root.join("targets/second")).unwrap();
+
+        let repos = vec![
+            ManifestRepo::with_scm("targets/first", "first", ScmType::Symlink)
+                .with_post_clone("echo broken >&2; exit 3"),
+            ManifestRepo::with_scm("targets/second", "second", ScmType::Symlink),
+        ];
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let workspace = Workspace::new(root.to_path_buf(), workspace_config);
+
+        // 既定ではエラーとして報告し、残りのリポジトリの同期を続ける
+        let use_case = SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default());
+        let mut result = SyncResult::new();
+        use_case
+            .sync_repositories(&repos, &workspace, &mut result)
+            .await
+            .unwrap();
+        assert_eq!(result.cloned_count, 2);
+        assert_eq!(result.errors.len(), 1);
+        assert!(result.errors[0].contains("post_clone hook failed for repo 'first'"));
+        assert!(result.errors[0].contains("broken"));
+
+        // --hooks-fatalでは同期全体が失敗する
+        std::fs::remove_file(root.join("first")).unwrap();
+        std::fs::remove_file(root.join("second")).unwrap();
+        let use_case =
+            SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default().with_hooks_fatal(true));
+        let mut result = SyncResult::new();
+        let error = use_case
+            .sync_repositories(&repos, &workspace, &mut result)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            SyncRepositoriesError::HookFailed { ref repo, .. } if repo == "first"
+        ));
+        assert!(!root.join("second").exists());
+    }
+
+    #[tokio::test]
+    async fn test_workspace_hooks_run_around_sync() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        std::fs::create_dir_all(root.join("targets/first")).unwrap();
+        std::fs::create_dir_all(root.join("targets/second")).unwrap();
+        let manifest = r#"pre_sync: 'ls > pre.log'
+post_sync: 'echo "$WMGR_SYNC_CLONED $WMGR_SYNC_SYNCED $WMGR_SYNC_ERRORS $WMGR_WORKSPACE_ROOT" > post.log'
+repos:
+  - dest: first
+    url: targets/first
+    scm: symlink
+  - dest: second
+    url: targets/second
+    scm: symlink
+"#;
+        std::fs::write(root.join("wmgr.yml"), manifest).unwrap();
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let mut workspace = Workspace::new(root.to_path_buf(), workspace_config)
+            .with_status(WorkspaceStatus::Initialized);
+
+        let result = SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default())
+            .execute(&mut workspace)
+            .await
+            .unwrap();
+        assert!(result.is_success());
+        assert_eq!(result.cloned_count, 2);
+
+        // pre_syncはどのリポジトリよりも先にワークスペースのルートで実行される
+        let pre = std::fs::read_to_string(root.join("pre.log")).unwrap();
+        let listed: Vec<&str> = pre.lines().collect();
+        assert!(listed.contains(&"wmgr.yml"));
+        assert!(!listed.contains(&"first"));
+        assert!(!listed.contains(&"second"));
+
+        // post_syncは最終的な件数を受け取る
+        assert_eq!(
+            std::fs::read_to_string(root.join("post.log")).unwrap(),
+            format!("2 2 0 {}\n", root.display())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failing_workspace_hook_behavior() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        std::fs::create_dir_all(root.join("targets/first")).unwrap();
+        let manifest = r#"pre_sync: 'exit 1'
+repos:
+  - dest: first
+    url: targets/first
+    scm: symlink
+"#;
+        std::fs::write(root.join("wmgr.yml"), manifest).unwrap();
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let mut workspace = Workspace::new(root.to_path_buf(), workspace_config)
+            .with_status(WorkspaceStatus::Initialized);
+
+        // 既定では失敗を報告して同期を続ける
+        let result = SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default())
+            .execute(&mut workspace)
+            .await
+            .unwrap();
+        assert_eq!(result.cloned_count, 1);
+        assert_eq!(
+            result.errors,
+            vec!["Workspace pre_sync hook failed: Command exited with code 1"]
+        );
+
+        // --hooks-fatalではリポジトリを同期する前に中断する
+        std::fs::remove_file(root.join("first")).unwrap();
+        let error = SyncRepositoriesUseCase::new(
+            SyncRepositoriesConfig::default().with_hooks_fatal(true),
+        )
+        .execute(&mut workspace)
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            error,
+            SyncRepositoriesError::WorkspaceHookFailed { ref hook, .. } if hook == "pre_sync"
+        ));
+        assert!(!root.join("first").exists());
+    }
+
+    #[tokio::test]
+    async fn test_local_only_repo_is_not_cloned() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let repos = vec![ManifestRepo::new("", "scratch")];
+        let workspace = Workspace::new(
+            root.to_path_buf(),
+            WorkspaceConfig::new("https://example.com/manifest.git", "main"),
+        )
+        .with_manifest(Manifest::new(repos.clone()));
+        let use_case = SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default());
+
+        // 存在しない場合はエラーではなく警告
+        let mut result = SyncResult::new();
+        use_case
+            .sync_repositories(&repos, &workspace, &mut result)
+            .await
+            .unwrap();
+        assert!(result.is_success());
+        assert_eq!(result.skipped_count, 1);
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].contains("scratch"));
+        assert!(!root.join("scratch").exists());
+
+        // 存在する場合は何もせずスキップ
+        let scratch = git2::Repository::init(root.join("scratch")).unwrap();
+        commit_file(&scratch, "README", "local");
+        let mut result = SyncResult::new();
+        use_case
+            .sync_repositories(&repos, &workspace, &mut result)
+            .await
+            .unwrap();
+        assert!(result.is_success());
+        assert!(result.warnings.is_empty());
+        assert_eq!(result.skipped_count, 1);
+        assert_eq!(result.cloned_count + result.updated_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_cancellation_removes_partial_clone() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+
+        // 接続を受け付けるだけで応答しないサーバーで、クローンを途中で止めておく
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        std::thread::spawn(move || {
+            let connections: Vec<_> = listener.incoming().collect();
+            drop(connections);
+        });
+
+        let repos = vec![
+            ManifestRepo::new(format!("git://127.0.0.1:{}/app.git", port), "app")
+                .with_branch("main"),
+            ManifestRepo::new("", "later"),
+        ];
+        let workspace = Workspace::new(
+            root.to_path_buf(),
+            WorkspaceConfig::new("https://example.com/manifest.git", "main"),
+        )
+        .with_manifest(Manifest::new(repos.clone()));
+
+        let cancellation = CancellationToken::new();
+        let use_case = SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default())
+            .with_cancellation(cancellation.clone());
+        let sync = async {
+            let mut result = SyncResult::new();
+            use_case
+                .sync_repositories(&repos, &workspace, &mut result)
+                .await
+                .map(|_| result)
+        };
+        let interrupt = async {
+            // gitがクローン先を作成するまで待ってから中断する
+            for _ in 0..500 {
+                if root.join("app").exists() {
+                    break;
+                }
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            }
+            assert!(root.join("app").exists(), "the clone never started");
+            cancellation.cancel();
+        };
+        let (result, _) = tokio::join!(sync, interrupt);
+        let result = result.unwrap();
+
+        assert!(!root.join("app").exists());
+        assert_eq!(result.interrupted, vec!["app", "later"]);
+        assert!(result.is_interrupted());
+        assert!(!result.is_success());
+        assert_eq!(result.synced_count, 0);
+        assert!(result.errors.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_partial_clone_directories_are_cloned_again() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let remote_path = bare_remote_with_head(root, "main");
+        let url = format!("file://{}", remote_path.display());
+
+        // 中断されたクローンの残骸（空の.gitだけ）、libgit2で開けない.gitだけのディレクトリと、
+        // リポジトリではないファイルを含むディレクトリ
+        std::fs::create_dir_all(root.join("app/.git/objects")).unwrap();
+        std::fs::create_dir_all(root.join("tool/.git")).unwrap();
+        std::fs::write(root.join("tool/.git/HEAD"), "not a ref").unwrap();
+        std::fs::create_dir_all(root.join("libs/core")).unwrap();
+        std::fs::write(root.join("libs/core/notes.txt"), "keep me").unwrap();
+
+        let repos = vec![
+            ManifestRepo::new(&url, "app").with_branch("main"),
+            ManifestRepo::new(&url, "tool").with_branch("main"),
+            ManifestRepo::new(&url, "libs/core").with_branch("main"),
+        ];
+        let workspace = workspace_with(root, Manifest::new(repos.clone()));
+        let use_case = SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default());
+        let mut result = SyncResult::new();
+        use_case
+            .sync_repositories(&repos, &workspace, &mut result)
+            .await
+            .unwrap();
+
+        assert!(result.is_success(), "{:?}", result.errors);
+        assert_eq!(result.recovered_count, 3);
+        assert_eq!(result.cloned_count, 0);
+        assert_eq!(result.synced_count, 3);
+        for dest in ["app", "tool", "libs/core"] {
+            let repo = git2::Repository::open(root.join(dest)).unwrap();
+            assert!(repo.head().unwrap().peel_to_commit().is_ok());
+        }
+
+        // ファイルがあったディレクトリは.gitだけでもバックアップに移動される
+        assert!(!root.join("libs/core/notes.txt").exists());
+        assert_eq!(result.warnings.len(), 2);
+        // 空の.gitだけだったappは削除され、バックアップは作られない
+        let mut top_level: Vec<PathBuf> = std::fs::read_dir(root.join(".wmgr/backups"))
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        top_level.sort();
+        assert_eq!(top_level.len(), 2, "{:?}", top_level);
+        assert_eq!(top_level[0], root.join(".wmgr/backups/libs"));
+        let tool_backup = &top_level[1];
+        assert!(tool_backup
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .starts_with("tool.partial_"));
+        assert_eq!(
+            std::fs::read_to_string(tool_backup.join(".git/HEAD")).unwrap(),
+            "not a ref"
+        );
+        assert!(result.warnings[0].contains(&tool_backup.display().to_string()));
+        let backups: Vec<PathBuf> = std::fs::read_dir(root.join(".wmgr/backups/libs"))
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        assert_eq!(backups.len(), 1);
+        assert!(backups[0]
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .starts_with("core.partial_"));
+        assert_eq!(
+            std::fs::read_to_string(backups[0].join("notes.txt")).unwrap(),
+            "keep me"
+        );
+        assert!(result.warnings[1].contains(&backups[0].display().to_string()));
+
+        // 正常なリポジトリはそのまま更新される
+        let mut result = SyncResult::new();
+        use_case
+            .sync_repositories(&repos, &workspace, &mut result)
+            .await
+            .unwrap();
+        assert_eq!(result.recovered_count, 0);
+        assert_eq!(result.updated_count, 3);
+    }
+
+    #[tokio::test]
+    async fn test_progress_events_pair_per_repository() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+
+        let remote_path = root.join("remote.git");
+        git2::Repository::init_bare(&remote_path).unwrap();
+        let seed = git2::Repository::init(root.join("seed")).unwrap();
+        seed.set_head("refs/heads/main").unwrap();
+        commit_file(&seed, "README", "seed");
+        seed.remote("origin", remote_path.to_str().unwrap()).unwrap();
+        push_main(&seed);
+        std::fs::create_dir(root.join("scratch")).unwrap();
+
+        let repos = vec![
+            ManifestRepo::new(format!("file://{}", remote_path.display()), "app")
+                .with_branch("main"),
+            ManifestRepo::new("", "scratch"),
+            ManifestRepo::new(format!("file://{}", root.join("missing.git").display()), "broken"),
+        ];
+        let workspace_config = WorkspaceConfig::new("https://example.com/manifest.git", "main");
+        let workspace = Workspace::new(root.to_path_buf(), workspace_config)
+            .with_manifest(Manifest::new(repos.clone()));
+
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        let use_case =
+            SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default()).with_progress(sender);
+        let mut result = SyncResult::new();
+        use_case
+            .sync_repositories(&repos, &workspace, &mut result)
+            .await
+            .unwrap();
+        drop(use_case);
+
+        let mut events = Vec::new();
+        while let Some(event) = receiver.recv().await {
+            events.push(event);
+        }
+
+        for (index, repo) in repos.iter().enumerate() {
+            let repo_events: Vec<&ProgressEvent> = events
+                .iter()
+                .filter(|event| match event {
+                    ProgressEvent::RepoStarted { dest, .. }
+                    | ProgressEvent::RepoProgress { dest, .. }
+                    | ProgressEvent::RepoFinished { dest, .. } => dest == &repo.dest,
+                })
+                .collect();
+            assert_eq!(
+                repo_events.first(),
+                Some(&&ProgressEvent::RepoStarted {
+                    dest: repo.dest.clone(),
+                    index,
+                    total: 3,
+                })
+            );
+            let finished: Vec<&RepoOutcome> = repo_events
+                .iter()
+                .filter_map(|event| match event {
+                    ProgressEvent::RepoFinished { outcome, .. } => Some(outcome),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(finished.len(), 1, "{}: {:?}", repo.dest, repo_events);
+            assert!(matches!(
+                repo_events.last(),
+                Some(ProgressEvent::RepoFinished { .. })
+            ));
+        }
+
+        let outcome = |dest: &str| {
+            events.iter().find_map(|event| match event {
+                ProgressEvent::RepoFinished { dest: d, outcome } if d == dest => {
+                    Some(outcome.clone())
+                }
+                _ => None,
+            })
+        };
+        assert_eq!(outcome("app"), Some(RepoOutcome::Cloned));
+        assert_eq!(outcome("scratch"), Some(RepoOutcome::Skipped));
+        assert!(outcome("broken").unwrap().is_failure());
+        assert!(events.contains(&ProgressEvent::RepoProgress {
+            dest: "app".to_string(),
+            message: "cloning".to_string(),
+        }));
+    }
+
+    /// `url`を`path`にサブモジュールとして追加してコミット
+    fn add_submodule(repo: &git2::Repository, url: &str, path: &str) {
+        let mut submodule = repo.submodule(url, Path::new(path), true).unwrap();
+        submodule.clone(None).unwrap();
+        submodule.add_finalize().unwrap();
+        let tree = repo
+            .find_tree(repo.index().unwrap().write_tree().unwrap())
+            .unwrap();
+        let signature = git2::Signature::now("wmgr", "wmgr@example.com").unwrap();
+        let parent = repo.head().unwrap().peel_to_commit().unwrap();
+        repo.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            path,
+            &tree,
+            &[&parent],
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_submodules_are_populated_on_clone_and_sync() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let url = |name: &str| format!("file://{}", root.join("sources").join(name).display());
+
+        for (name, submodule) in [("app", "lib"), ("tool", "lib"), ("broken", "gone")] {
+            if !root.join("sources").join(submodule).exists() {
+                let lib = git2::Repository::init(root.join("sources").join(submodule)).unwrap();
+                commit_file(&lib, "LIB", submodule);
+            }
+            let repo = git2::Repository::init(root.join("sources").join(name)).unwrap();
+            commit_file(&repo, "README", name);
+            add_submodule(&repo, &url(submodule), "lib");
+        }
+        // brokenのサブモジュールの取得元を消して更新を失敗させる
+        std::fs::remove_dir_all(root.join("sources/gone")).unwrap();
+
+        let mut tool = ManifestRepo::new(url("tool"), "tool");
+        tool.submodules = Some(false);
+        let repos = vec![
+            ManifestRepo::new(url("app"), "app"),
+            tool,
+            ManifestRepo::new(url("broken"), "broken"),
+        ];
+        let workspace = Workspace::new(
+            root.join("workspace"),
+            WorkspaceConfig::new("https://example.com/manifest.git", "main"),
+        )
+        .with_manifest(Manifest::new(repos.clone()));
+        let workspace_root = root.join("workspace");
+
+        // クローン時: --recurse-submodules相当の設定で再帰的に取得し、失敗はリポジトリごとに報告
+        let use_case = SyncRepositoriesUseCase::new(
+            SyncRepositoriesConfig::default().with_recurse_submodules(true),
+        );
+        let mut result = SyncResult::new();
+        use_case
+            .sync_repositories(&repos, &workspace, &mut result)
+            .await
+            .unwrap();
+        assert!(workspace_root.join("app/lib/LIB").exists());
+        assert!(!workspace_root.join("tool/lib/LIB").exists());
+        assert_eq!(result.cloned_count, 2);
+        assert_eq!(result.errors.len(), 1, "{:?}", result.errors);
+        assert!(result.errors[0].contains("Submodule update failed for repo 'broken'"));
+
+        // 更新時: リポジトリごとの設定で有効にすると既存のクローンでも取得する
+        let mut tool = repos[1].clone();
+        tool.submodules = Some(true);
+        let use_case = SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default());
+        let mut result = SyncResult::new();
+        use_case
+            .sync_repositories(&[tool], &workspace, &mut result)
+            .await
+            .unwrap();
+        assert!(result.is_success(), "{:?}", result.errors);
+        assert!(workspace_root.join("tool/lib/LIB").exists());
+    }
+
+    #[tokio::test]
+    async fn test_lfs_problems_are_warnings() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let source = git2::Repository::init(root.join("sources/assets")).unwrap();
+        commit_file(
+            &source,
+            ".gitattributes",
+            "*.psd filter=lfs diff=lfs merge=lfs -text\n",
+        );
+
+        let url = format!("file://{}", root.join("sources/assets").display());
+        let repos = vec![ManifestRepo::new(url, "assets")];
+        let workspace = Workspace::new(
+            root.join("workspace"),
+            WorkspaceConfig::new("https://example.com/manifest.git", "main"),
+        )
+        .with_manifest(Manifest::new(repos.clone()));
+
+        // LFSが無効の場合は何もしない
+        let use_case = SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default());
+        let mut result = SyncResult::new();
+        use_case
+            .sync_repositories(&repos, &workspace, &mut result)
+            .await
+            .unwrap();
+        assert_eq!(result.cloned_count, 1);
+        assert!(result.warnings.is_empty(), "{:?}", result.warnings);
+
+        // git-lfsがない環境では警告のみで同期は成功する
+        let use_case =
+            SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default().with_lfs(true));
+        let mut result = SyncResult::new();
+        use_case
+            .sync_repositories(&repos, &workspace, &mut result)
+            .await
+            .unwrap();
+        assert!(result.is_success(), "{:?}", result.errors);
+        if !lfs::is_installed() {
+            assert_eq!(result.warnings.len(), 1);
+            assert!(result.warnings[0].contains("git-lfs is not installed"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_partial_clone_warns_when_server_ignores_filter() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let supported = git2::Repository::init(root.join("sources/supported")).unwrap();
+        commit_file(&supported, "README", "supported");
+        supported
+            .config()
+            .unwrap()
+            .set_bool("uploadpack.allowFilter", true)
+            .unwrap();
+        let unsupported = git2::Repository::init(root.join("sources/unsupported")).unwrap();
+        commit_file(&unsupported, "README", "unsupported");
+
+        // ローカルパスではgit cloneが--filterを無視するためfile://で指定する
+        let url = |name: &str| format!("file://{}", root.join("sources").join(name).display());
+        let mut blobless = ManifestRepo::new(url("supported"), "supported");
+        blobless.partial_clone = Some(PartialClone::Blobless);
+        let repos = vec![
+            blobless,
+            ManifestRepo::new(url("unsupported"), "unsupported"),
+        ];
+        let workspace = Workspace::new(
+            root.join("workspace"),
+            WorkspaceConfig::new("https://example.com/manifest.git", "main"),
+        )
+        .with_manifest(Manifest::new(repos.clone()));
+
+        // --filterはpartial_cloneのないリポジトリに適用される
+        let use_case = SyncRepositoriesUseCase::new(
+            SyncRepositoriesConfig::default().with_clone_filter("tree:0"),
+        );
+        let mut result = SyncResult::new();
+        use_case
+            .sync_repositories(&repos, &workspace, &mut result)
+            .await
+            .unwrap();
+        assert!(result.is_success(), "{:?}", result.errors);
+        assert_eq!(result.cloned_count, 2);
+
+        let config = git2::Repository::open(root.join("workspace/supported"))
+            .unwrap()
+            .config()
+            .unwrap();
+        assert!(config.get_bool("remote.origin.promisor").unwrap());
+        assert_eq!(
+            config
+                .get_string("remote.origin.partialclonefilter")
+                .unwrap(),
+            "blob:none"
+        );
+
+        assert_eq!(result.warnings.len(), 1, "{:?}", result.warnings);
+        assert!(result.warnings[0].starts_with("unsupported was cloned in full"));
+        assert!(result.warnings[0].contains("--filter=tree:0"));
+    }
+
+    fn mock_workspace(root: &Path, repos: &[ManifestRepo]) -> Workspace {
+        crate::infrastructure::scm::mock_scm::MockScm::register();
+        Workspace::new(
+            root.to_path_buf(),
+            WorkspaceConfig::new("https://example.com/manifest.git", "main"),
+        )
+        .with_manifest(Manifest::new(repos.to_vec()))
+    }
+
+    fn mock_repo(url: &str, dest: &str) -> ManifestRepo {
+        ManifestRepo::with_scm(url, dest, ScmType::Hg)
+    }
+
+    #[tokio::test]
+    async fn test_mock_scm_clone_then_update() {
+        use crate::infrastructure::scm::mock_scm::MockScm;
+
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let repos = vec![
+            mock_repo("mock://ok/app", "app"),
+            mock_repo("mock://delay-20/lib", "libs/lib"),
+        ];
+        let workspace = mock_workspace(root, &repos);
+        let use_case = SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default());
+
+        // 1回目: クローン
+        let mut result = SyncResult::new();
+        use_case
+            .sync_repositories(&repos, &workspace, &mut result)
+            .await
+            .unwrap();
+        assert!(result.is_success(), "{:?}", result.errors);
+        assert_eq!(result.cloned_count, 2);
+        assert_eq!(MockScm::update_count(&root.join("app")), Some(0));
+        assert_eq!(MockScm::update_count(&root.join("libs/lib")), Some(0));
+
+        // 2回目: 既存のクローンを更新
+        let mut result = SyncResult::new();
+        use_case
+            .sync_repositories(&repos, &workspace, &mut result)
+            .await
+            .unwrap();
+        assert!(result.is_success(), "{:?}", result.errors);
+        assert_eq!(result.cloned_count, 0);
+        assert_eq!(result.updated_count, 2);
+        assert_eq!(MockScm::update_count(&root.join("app")), Some(1));
+        assert_eq!(MockScm::update_count(&root.join("libs/lib")), Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_mock_scm_failures_are_reported_per_repo() {
+        use crate::infrastructure::scm::mock_scm::MockScm;
+
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let repos = vec![
+            mock_repo("mock://fail/broken-clone", "broken-clone"),
+            mock_repo("mock://fail/broken-update", "broken-update"),
+            mock_repo("mock://ok/app", "app"),
+        ];
+        MockScm::create_clone(&root.join("broken-update"), "mock://fail/broken-update").unwrap();
+        let workspace = mock_workspace(root, &repos);
+        let use_case = SyncRepositoriesUseCase::new(SyncRepositoriesConfig::default());
+
+        let mut result = SyncResult::new();
+        use_case
+            .sync_repositories(&repos, &workspace, &mut result)
+            .await
+            .unwrap();
+
+        // 失敗したリポジトリがあっても残りは同期される
+        assert!(!result.is_success());
+        assert_eq!(result.cloned_count, 1);
+        assert_eq!(result.errors.len(), 2);
+        assert!(result.errors.iter().any(|e| e.contains("broken-clone")));
+        assert!(result.errors.iter().any(|e| e.contains("broken-update")));
+        assert!(!root.join("broken-clone").exists());
+        assert_eq!(MockScm::update_count(&root.join("broken-update")), Some(0));
+        assert_eq!(MockScm::update_count(&root.join("app")), Some(0));
+    }
 }
@@ -0,0 +1,78 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tokio::sync::Notify;
+
+/// 実行中の処理の中断要求（Ctrl-Cなど）
+///
+/// 複製したトークンは状態を共有する。一度`cancel`すると元に戻らない。
+/// 中断を確認したユースケースは、途中の作業を片付けてから結果を返す。
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    notify: Notify,
+}
+
+impl CancellationToken {
+    /// 中断されていないトークンを作成
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 中断を要求し、`cancelled`で待っているタスクを起こす
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::SeqCst);
+        self.inner.notify.notify_waiters();
+    }
+
+    /// 中断が要求されたか
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// 中断が要求されるまで待つ（要求済みの場合はすぐに完了する）
+    pub async fn cancelled(&self) {
+        loop {
+            // 状態を確認する前に待機を登録し、その間のcancelを取りこぼさない
+            let notified = self.inner.notify.notified();
+            if self.is_cancelled() {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_cancel_wakes_waiters() {
+        let token = CancellationToken::new();
+        assert!(!token.is_cancelled());
+
+        let waiter = tokio::spawn({
+            let token = token.clone();
+            async move { token.cancelled().await }
+        });
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        token.cancel();
+
+        tokio::time::timeout(Duration::from_secs(5), waiter)
+            .await
+            .expect("waiter was not woken")
+            .unwrap();
+        assert!(token.is_cancelled());
+
+        // 中断済みのトークンはすぐに完了する
+        tokio::time::timeout(Duration::from_secs(5), token.cancelled())
+            .await
+            .unwrap();
+    }
+}
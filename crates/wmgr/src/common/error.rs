@@ -39,6 +39,18 @@ pub enum WmgrError {
         workspace_path: Option<PathBuf>,
     },
 
+    #[error("Workspace not initialized at: {}", workspace_path.display())]
+    WorkspaceNotInitialized { workspace_path: PathBuf },
+
+    #[error("{message}")]
+    WorkspaceLocked { message: String, lock_path: PathBuf },
+
+    #[error("{failed} repositories failed to synchronize")]
+    SyncIncomplete { failed: usize },
+
+    #[error("The command failed in {failed} repositories")]
+    ForeachIncomplete { failed: usize },
+
     #[error("Repository operation failed: {message}")]
     RepositoryError {
         message: String,
@@ -177,6 +189,12 @@ impl WmgrError {
         }
     }
 
+    pub fn workspace_not_initialized(workspace_path: impl Into<PathBuf>) -> Self {
+        Self::WorkspaceNotInitialized {
+            workspace_path: workspace_path.into(),
+        }
+    }
+
     pub fn repository_error(message: impl Into<String>, repository_name: Option<String>) -> Self {
         Self::RepositoryError {
             message: message.into(),
@@ -293,6 +311,32 @@ impl WmgrError {
             source: Some(Box::new(source)),
         }
     }
+
+    /// Suggested next step for the user, shown below the error message
+    pub fn hint(&self) -> Option<&'static str> {
+        match self {
+            Self::WorkspaceNotInitialized { .. } => Some(
+                "Run 'wmgr init' to create a workspace here, or pass -C <dir> to use an existing one",
+            ),
+            Self::WorkspaceLocked { .. } => Some(
+                "Wait for the other wmgr process to finish; if no such process exists, delete the lock file",
+            ),
+            Self::SyncIncomplete { .. } => {
+                Some("Run 'wmgr sync --continue' to retry only the remaining repositories")
+            }
+            Self::ManifestError { .. } => {
+                Some("Check the manifest for YAML syntax errors and missing required fields")
+            }
+            Self::RepositoryError { .. } | Self::GitError { .. } => {
+                Some("Run 'wmgr status' to inspect the state of the repositories")
+            }
+            Self::NetworkError { .. } => {
+                Some("Check your network connection, proxy settings and credentials")
+            }
+            Self::Timeout { .. } => Some("Retry with a longer timeout"),
+            _ => None,
+        }
+    }
 }
 
 impl From<git2::Error> for WmgrError {
@@ -366,6 +410,34 @@ mod tests {
         assert_eq!(error.to_string(), "Operation timed out after 30 seconds");
     }
 
+    #[test]
+    fn test_workspace_not_initialized_message_and_hint() {
+        let error = WmgrError::workspace_not_initialized("/work/space");
+        assert_eq!(
+            error.to_string(),
+            "Workspace not initialized at: /work/space"
+        );
+        assert!(error.hint().unwrap().contains("wmgr init"));
+    }
+
+    #[test]
+    fn test_hint_per_variant() {
+        assert!(WmgrError::manifest_error("bad", None)
+            .hint()
+            .unwrap()
+            .contains("manifest"));
+        assert!(
+            WmgrError::repository_error("failed", Some("repo".to_string()))
+                .hint()
+                .unwrap()
+                .contains("wmgr status")
+        );
+        assert!(WmgrError::internal_error("boom").hint().is_none());
+        assert!(WmgrError::validation_error("field", "message", None)
+            .hint()
+            .is_none());
+    }
+
     #[test]
     fn test_error_conversion_from_io_error() {
         let io_error = std::io::Error::new(std::io::ErrorKind::NotFound, "file not found");
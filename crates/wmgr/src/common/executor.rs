@@ -96,6 +96,20 @@ pub trait ExecutableTask: Send + Sync {
     }
 }
 
+/// ジョブ数の指定から実際の並列度を決定
+///
+/// `None` はCPU数、`Some(0)` は無制限（タスク数まで）を意味する。
+/// 結果は常に1以上かつタスク数以下になるため、セマフォの許可数が0になって
+/// デッドロックすることも、上限を超えてパニックすることもない。
+pub fn resolve_parallelism(jobs: Option<usize>, task_count: usize) -> usize {
+    let limit = match jobs {
+        None => num_cpus::get(),
+        Some(0) => task_count,
+        Some(n) => n,
+    };
+    limit.min(task_count).max(1)
+}
+
 /// タスク実行設定
 #[derive(Debug, Clone)]
 pub struct ExecutorConfig {
@@ -534,6 +548,23 @@ impl TaskExecutor {
 #[cfg(test)]
 mod tests {
     use super::*;
+
+    #[test]
+    fn test_resolve_parallelism() {
+        // 未指定はCPU数（タスク数が上限）
+        assert_eq!(resolve_parallelism(None, 1000), num_cpus::get().min(1000));
+        assert_eq!(resolve_parallelism(None, 1), 1);
+        // 0は無制限
+        assert_eq!(resolve_parallelism(Some(0), 50), 50);
+        // 明示的な指定
+        assert_eq!(resolve_parallelism(Some(4), 50), 4);
+        assert_eq!(resolve_parallelism(Some(8), 3), 3);
+        // 非常に大きな値もタスク数に収まる
+        assert_eq!(resolve_parallelism(Some(usize::MAX), 7), 7);
+        // タスクがなくても許可数は0にならない
+        assert_eq!(resolve_parallelism(Some(0), 0), 1);
+        assert_eq!(resolve_parallelism(None, 0), 1);
+    }
     use std::sync::atomic::{AtomicU32, Ordering};
 
     struct TestTask {
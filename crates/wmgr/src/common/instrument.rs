@@ -0,0 +1,91 @@
+use std::future::Future;
+use std::time::Instant;
+use tracing::{field, info_span, Instrument};
+
+/// リポジトリ単位の操作をtracingのspan内で実行する
+///
+/// spanの名前は`repo`で、`operation`（sync/status/foreach）・`dest`・`elapsed_ms`を持つ。
+/// span内で出力したログにもこれらのフィールドが付くため、JSONのログから
+/// リポジトリごとに集計できる。
+pub async fn instrument_repo<F: Future>(
+    operation: &'static str,
+    dest: &str,
+    future: F,
+) -> F::Output {
+    let span = info_span!("repo", operation, dest, elapsed_ms = field::Empty);
+    let started = Instant::now();
+    let output = future.instrument(span.clone()).await;
+    span.record("elapsed_ms", started.elapsed().as_millis() as u64);
+    output
+}
+
+/// テスト用: 作成された`repo` spanのフィールドを記録する
+#[cfg(test)]
+pub(crate) mod capture {
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id};
+    use tracing::Subscriber;
+    use tracing_subscriber::layer::{Context, SubscriberExt};
+    use tracing_subscriber::Layer;
+
+    /// 記録したspanのフィールド（名前 -> 値）
+    pub(crate) type CapturedSpans = Arc<Mutex<Vec<HashMap<String, String>>>>;
+
+    struct RepoSpanLayer {
+        spans: CapturedSpans,
+    }
+
+    struct FieldVisitor<'a>(&'a mut HashMap<String, String>);
+
+    impl Visit for FieldVisitor<'_> {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0.insert(field.name().to_string(), value.to_string());
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.0
+                .insert(field.name().to_string(), format!("{:?}", value));
+        }
+    }
+
+    impl<S: Subscriber> Layer<S> for RepoSpanLayer {
+        fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: Context<'_, S>) {
+            if attrs.metadata().name() != "repo" {
+                return;
+            }
+            let mut fields = HashMap::new();
+            attrs.record(&mut FieldVisitor(&mut fields));
+            self.spans.lock().unwrap().push(fields);
+        }
+    }
+
+    /// このスレッドで作成される`repo` spanを記録する（guardを破棄するまで）
+    pub(crate) fn capture_repo_spans() -> (tracing::subscriber::DefaultGuard, CapturedSpans) {
+        let spans = CapturedSpans::default();
+        let subscriber = tracing_subscriber::registry().with(RepoSpanLayer {
+            spans: spans.clone(),
+        });
+        (tracing::subscriber::set_default(subscriber), spans)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::capture::capture_repo_spans;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_instrument_repo_emits_span_with_fields() {
+        let (_guard, spans) = capture_repo_spans();
+
+        let value = instrument_repo("status", "libs/core", async { 42 }).await;
+        assert_eq!(value, 42);
+
+        let spans = spans.lock().unwrap();
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0]["operation"], "status");
+        assert_eq!(spans[0]["dest"], "libs/core");
+    }
+}
@@ -1,4 +1,7 @@
+pub mod cancellation;
 pub mod error;
 pub mod executor;
+pub mod instrument;
+pub mod progress;
 pub mod result;
 pub mod templates;
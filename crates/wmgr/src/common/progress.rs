@@ -0,0 +1,142 @@
+use tokio::sync::mpsc::UnboundedSender;
+
+/// リポジトリ単位の処理の進捗イベント
+///
+/// GUI/TUIなどCLI以外の利用者が、ユースケースの実行中に状況を表示するためのもの。
+/// 各リポジトリについて`RepoStarted`と`RepoFinished`が必ず1回ずつ送られ、
+/// その間に0個以上の`RepoProgress`が送られる。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProgressEvent {
+    /// リポジトリの処理を開始した（`index`は0始まり、`total`は対象リポジトリ数）
+    RepoStarted {
+        dest: String,
+        index: usize,
+        total: usize,
+    },
+
+    /// 処理中の段階（クローン・fetch・フック実行など）
+    RepoProgress { dest: String, message: String },
+
+    /// リポジトリの処理が終了した
+    RepoFinished { dest: String, outcome: RepoOutcome },
+}
+
+/// リポジトリの処理結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RepoOutcome {
+    /// 新規にクローンした
+    Cloned,
+    /// リポジトリではないディレクトリ（中断されたクローンなど）をクローンし直した
+    Recovered,
+    /// 既存のリポジトリを更新した
+    Updated,
+    /// リモートの先端と一致していたため更新不要
+    UpToDate,
+    /// 前回の同期で完了済みのため省略した（--continue）
+    Resumed,
+    /// 最近fetchされたため最新とみなして省略した（--since）
+    Recent,
+    /// 取得元がない・設定などにより処理しなかった
+    Skipped,
+    /// コマンドが成功した（foreach）
+    Succeeded,
+    /// 状態を調べた（status。状態の説明）
+    Checked(String),
+    /// 失敗した（エラー内容）
+    Failed(String),
+    /// 処理中に中断された（Ctrl-Cなど）
+    Interrupted,
+}
+
+impl RepoOutcome {
+    /// 表示用の短い説明
+    pub fn as_str(&self) -> &str {
+        match self {
+            RepoOutcome::Cloned => "cloned",
+            RepoOutcome::Recovered => "re-cloned",
+            RepoOutcome::Updated => "updated",
+            RepoOutcome::UpToDate => "up to date",
+            RepoOutcome::Resumed => "already synced",
+            RepoOutcome::Recent => "fetched recently",
+            RepoOutcome::Skipped => "skipped",
+            RepoOutcome::Succeeded => "succeeded",
+            RepoOutcome::Checked(state) => state,
+            RepoOutcome::Failed(error) => error,
+            RepoOutcome::Interrupted => "interrupted",
+        }
+    }
+
+    /// 失敗したか
+    pub fn is_failure(&self) -> bool {
+        matches!(self, RepoOutcome::Failed(_))
+    }
+}
+
+/// 進捗イベントの送信側（未設定の場合は何もしない）
+///
+/// 受信側が破棄されていても処理は続けるため、送信エラーは無視する。
+#[derive(Debug, Clone, Default)]
+pub struct ProgressReporter {
+    sender: Option<UnboundedSender<ProgressEvent>>,
+}
+
+impl ProgressReporter {
+    /// イベントを`sender`に送る
+    pub fn new(sender: UnboundedSender<ProgressEvent>) -> Self {
+        Self {
+            sender: Some(sender),
+        }
+    }
+
+    /// イベントを送信
+    pub fn emit(&self, event: ProgressEvent) {
+        if let Some(sender) = &self.sender {
+            let _ = sender.send(event);
+        }
+    }
+
+    /// `RepoStarted`を送信
+    pub fn started(&self, dest: &str, index: usize, total: usize) {
+        self.emit(ProgressEvent::RepoStarted {
+            dest: dest.to_string(),
+            index,
+            total,
+        });
+    }
+
+    /// `RepoProgress`を送信
+    pub fn progress(&self, dest: &str, message: impl Into<String>) {
+        self.emit(ProgressEvent::RepoProgress {
+            dest: dest.to_string(),
+            message: message.into(),
+        });
+    }
+
+    /// `RepoFinished`を送信
+    pub fn finished(&self, dest: &str, outcome: RepoOutcome) {
+        self.emit(ProgressEvent::RepoFinished {
+            dest: dest.to_string(),
+            outcome,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::sync::mpsc::unbounded_channel;
+
+    #[test]
+    fn test_reporter_without_sender_is_a_no_op() {
+        ProgressReporter::default().finished("app", RepoOutcome::Cloned);
+    }
+
+    #[test]
+    fn test_reporter_ignores_dropped_receiver() {
+        let (sender, receiver) = unbounded_channel();
+        let reporter = ProgressReporter::new(sender);
+        reporter.started("app", 0, 1);
+        drop(receiver);
+        reporter.finished("app", RepoOutcome::Failed("boom".to_string()));
+    }
+}
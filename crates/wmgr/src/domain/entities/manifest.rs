@@ -1,8 +1,10 @@
 use super::repository::{Remote, Repository};
+use crate::domain::value_objects::git_url::GitUrl;
 use crate::domain::value_objects::scm_type::ScmType;
 use crate::infrastructure::scm::{CloneOptions, SyncOptions};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
 
 /// グループの定義
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -31,10 +33,54 @@ impl Group {
     }
 }
 
+/// 既存リポジトリの更新時にupstreamを取り込む方法
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SyncStrategy {
+    /// fast-forwardのみ（分岐している場合は失敗）
+    #[default]
+    FfOnly,
+    /// ローカルコミットをupstreamの上にリベース
+    Rebase,
+    /// upstreamをマージ（分岐している場合はマージコミットを作成）
+    Merge,
+}
+
+impl std::fmt::Display for SyncStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SyncStrategy::FfOnly => write!(f, "ff-only"),
+            SyncStrategy::Rebase => write!(f, "rebase"),
+            SyncStrategy::Merge => write!(f, "merge"),
+        }
+    }
+}
+
+/// 部分クローンの種類（`git clone --filter`で必要になるまで取得を遅らせるオブジェクト）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PartialClone {
+    /// ファイルの内容を取得しない（`blob:none`）
+    Blobless,
+    /// ファイルの内容とツリーを取得しない（`tree:0`）
+    Treeless,
+}
+
+impl PartialClone {
+    /// `git clone --filter`に渡すフィルタ
+    pub fn filter(&self) -> &'static str {
+        match self {
+            PartialClone::Blobless => "blob:none",
+            PartialClone::Treeless => "tree:0",
+        }
+    }
+}
+
 /// マニフェストのリポジトリ定義
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ManifestRepo {
-    /// リポジトリのURL
+    /// リポジトリのURL（省略した場合はクローンしないローカル専用リポジトリ）
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub url: String,
 
     /// ワークスペース内での相対パス
@@ -56,9 +102,26 @@ pub struct ManifestRepo {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub remotes: Option<Vec<Remote>>,
 
-    /// shallow cloneを使用するか
-    #[serde(default)]
-    pub shallow: bool,
+    /// shallow cloneを使用するか（未指定の場合はマニフェスト・ワークスペースのデフォルト）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shallow: Option<bool>,
+
+    /// サブモジュールを再帰的に初期化・更新するか（未指定の場合は`--recurse-submodules`に従う）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submodules: Option<bool>,
+
+    /// 同期後にGit LFSのファイルを取得するか（未指定の場合は`--lfs`に従う）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lfs: Option<bool>,
+
+    /// 部分クローンにするか（未指定の場合は`sync --filter`に従う）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partial_clone: Option<PartialClone>,
+
+    /// クローン時にオブジェクトを共有するローカルリポジトリ（ワークスペースからの相対パス可、
+    /// 未指定の場合は`sync --reference`のミラー）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
 
     /// ファイルコピー操作の定義
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -92,9 +155,41 @@ pub struct ManifestRepo {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub extra_options: Option<Vec<String>>,
 
+    /// クローン時にgit cloneへそのまま渡す追加の引数（`--filter=blob:none`など、Gitのみ）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clone_args: Option<Vec<String>>,
+
     /// クレデンシャルプロファイル名（~/.config/wmgr/credential.yml のプロファイル参照）
     #[serde(skip_serializing_if = "Option::is_none")]
     pub profile: Option<String>,
+
+    /// 同期時のマージ戦略（未指定の場合はマニフェストのデフォルト）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_strategy: Option<SyncStrategy>,
+
+    /// statusで未追跡ファイルとして数えないglobパターン（.wmgrignoreに追加して適用）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_ignore: Option<Vec<String>>,
+
+    /// foreachでこのリポジトリに適用するタイムアウト（秒、全体のタイムアウトより優先）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foreach_timeout: Option<u64>,
+
+    /// 選択用のタグ（`--tag` / `--exclude-tag`で絞り込む）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+
+    /// クローン直後にリポジトリのディレクトリで実行するシェルコマンド
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_clone: Option<String>,
+
+    /// 既存リポジトリの更新後にリポジトリのディレクトリで実行するシェルコマンド
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_sync: Option<String>,
+
+    /// このバージョンが扱わないフィールド（書き戻しても失われないよう保持する）
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_yaml::Value>,
 }
 
 /// ファイルコピー操作
@@ -113,7 +208,10 @@ pub struct FileSymlink {
     /// リンク元（ワークスペースルートからの相対パス）
     pub source: String,
 
-    /// リンク先（sourceからの相対パス）
+    /// リンク先
+    ///
+    /// 相対パスは通常のシンボリックリンクと同様に、リンク自身のディレクトリ
+    /// （sourceの親ディレクトリ）を基準に解決される。
     pub target: String,
 }
 
@@ -126,49 +224,49 @@ pub enum ScmOptions {
         /// リモート名（デフォルト: origin）
         #[serde(skip_serializing_if = "Option::is_none")]
         remote: Option<String>,
-        
+
         /// 浅いクローンの深度（デフォルト: 1）
         #[serde(skip_serializing_if = "Option::is_none")]
         depth: Option<u32>,
-        
+
         /// サブモジュールも再帰的にクローンするか
         #[serde(default)]
         recurse_submodules: bool,
     },
-    
+
     /// SVN固有のオプション
     Svn {
         /// 特定のリビジョン
         #[serde(skip_serializing_if = "Option::is_none")]
         revision: Option<String>,
-        
+
         /// ユーザー名
         #[serde(skip_serializing_if = "Option::is_none")]
         username: Option<String>,
-        
+
         /// パスワード（通常は環境変数で指定）
         #[serde(skip_serializing_if = "Option::is_none")]
         password: Option<String>,
     },
-    
+
     /// Perforce固有のオプション
     P4 {
         /// クライアントワークスペース名
         #[serde(skip_serializing_if = "Option::is_none")]
         client: Option<String>,
-        
+
         /// 特定のチェンジリスト
         #[serde(skip_serializing_if = "Option::is_none")]
         changelist: Option<String>,
-        
+
         /// ストリーム（P4 Streams使用時）
         #[serde(skip_serializing_if = "Option::is_none")]
         stream: Option<String>,
-        
+
         /// ユーザー名
         #[serde(skip_serializing_if = "Option::is_none")]
         username: Option<String>,
-        
+
         /// パスワード（通常は環境変数で指定）
         #[serde(skip_serializing_if = "Option::is_none")]
         password: Option<String>,
@@ -213,7 +311,11 @@ impl ManifestRepo {
             sha1: None,
             tag: None,
             remotes: None,
-            shallow: false,
+            shallow: None,
+            submodules: None,
+            lfs: None,
+            partial_clone: None,
+            reference: None,
             copy: None,
             symlink: None,
             scm: ScmType::default(),
@@ -222,7 +324,15 @@ impl ManifestRepo {
             username: None,
             password: None,
             extra_options: None,
+            clone_args: None,
             profile: None,
+            sync_strategy: None,
+            status_ignore: None,
+            foreach_timeout: None,
+            tags: None,
+            post_clone: None,
+            post_sync: None,
+            extra: HashMap::new(),
         }
     }
 
@@ -235,7 +345,11 @@ impl ManifestRepo {
             sha1: None,
             tag: None,
             remotes: None,
-            shallow: false,
+            shallow: None,
+            submodules: None,
+            lfs: None,
+            partial_clone: None,
+            reference: None,
             copy: None,
             symlink: None,
             scm,
@@ -244,7 +358,15 @@ impl ManifestRepo {
             username: None,
             password: None,
             extra_options: None,
+            clone_args: None,
             profile: None,
+            sync_strategy: None,
+            status_ignore: None,
+            foreach_timeout: None,
+            tags: None,
+            post_clone: None,
+            post_sync: None,
+            extra: HashMap::new(),
         }
     }
 
@@ -267,6 +389,35 @@ impl ManifestRepo {
         self
     }
 
+    /// 同期時のマージ戦略を設定
+    pub fn with_sync_strategy(mut self, strategy: SyncStrategy) -> Self {
+        self.sync_strategy = Some(strategy);
+        self
+    }
+
+    /// タグを設定
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = Some(tags);
+        self
+    }
+
+    /// 指定したタグを持つか
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().flatten().any(|t| t == tag)
+    }
+
+    /// クローン後のフックを設定
+    pub fn with_post_clone(mut self, command: impl Into<String>) -> Self {
+        self.post_clone = Some(command.into());
+        self
+    }
+
+    /// 更新後のフックを設定
+    pub fn with_post_sync(mut self, command: impl Into<String>) -> Self {
+        self.post_sync = Some(command.into());
+        self
+    }
+
     /// SCM固有オプションを設定
     pub fn with_scm_options(mut self, options: ScmOptions) -> Self {
         self.scm_options = Some(options);
@@ -279,6 +430,12 @@ impl ManifestRepo {
         self
     }
 
+    /// クローン時の追加引数を設定
+    pub fn with_clone_args(mut self, args: Vec<String>) -> Self {
+        self.clone_args = Some(args);
+        self
+    }
+
     /// SCM固有の設定を取得（Git用）
     pub fn get_git_options(&self) -> Option<&ScmOptions> {
         match (&self.scm, &self.scm_options) {
@@ -303,6 +460,13 @@ impl ManifestRepo {
         }
     }
 
+    /// URLがなく、ワークスペース内で作成されたローカル専用のリポジトリか
+    ///
+    /// ローカル専用のリポジトリはsyncでクローン・更新されないが、statusやforeachの対象になる。
+    pub fn is_local_only(&self) -> bool {
+        self.url.trim().is_empty()
+    }
+
     /// 有効なリビジョンを取得（SCM固有オプションまたは共通フィールドから）
     pub fn get_effective_revision(&self) -> Option<&String> {
         // まずSCM固有オプションをチェック
@@ -317,32 +481,105 @@ impl ManifestRepo {
     pub fn get_effective_auth(&self) -> (Option<&String>, Option<&String>) {
         // まずSCM固有オプションをチェック
         match &self.scm_options {
-            Some(ScmOptions::Svn { username, password, .. }) => {
-                (username.as_ref(), password.as_ref())
-            }
-            Some(ScmOptions::P4 { username, password, .. }) => {
-                (username.as_ref(), password.as_ref())
-            }
+            Some(ScmOptions::Svn {
+                username, password, ..
+            }) => (username.as_ref(), password.as_ref()),
+            Some(ScmOptions::P4 {
+                username, password, ..
+            }) => (username.as_ref(), password.as_ref()),
             _ => (self.username.as_ref(), self.password.as_ref()),
         }
     }
 
+    /// サブモジュールを更新するか（`submodules` > Gitオプションの`recurse_submodules` > `default`）
+    ///
+    /// Git以外のリポジトリは常にfalse。
+    pub fn submodules_enabled(&self, default: bool) -> bool {
+        if self.scm != ScmType::Git {
+            return false;
+        }
+        let recurse_submodules = matches!(
+            self.scm_options,
+            Some(ScmOptions::Git {
+                recurse_submodules: true,
+                ..
+            })
+        );
+        self.submodules.unwrap_or(recurse_submodules || default)
+    }
+
+    /// Git LFSのファイルを取得するか（`lfs` > `default`）
+    ///
+    /// Git以外のリポジトリは常にfalse。
+    pub fn lfs_enabled(&self, default: bool) -> bool {
+        self.scm == ScmType::Git && self.lfs.unwrap_or(default)
+    }
+
+    /// クローン時に使う部分クローンのフィルタ（`partial_clone` > `default`）
+    ///
+    /// Git以外のリポジトリは常にNone。
+    pub fn clone_filter(&self, default: Option<&str>) -> Option<String> {
+        if self.scm != ScmType::Git {
+            return None;
+        }
+        self.partial_clone
+            .map(|partial_clone| partial_clone.filter().to_string())
+            .or_else(|| default.map(str::to_string))
+    }
+
+    /// クローン時に`--reference-if-able`で参照するリポジトリのパス（`reference` > ミラー）
+    ///
+    /// `reference`はワークスペースのルートからの相対パスとして解決する。ミラーでは
+    /// `<mirror_dir>/<リポジトリパス>.git`を参照する。Git以外のリポジトリは常にNone。
+    pub fn reference_path(
+        &self,
+        workspace_root: &Path,
+        mirror_dir: Option<&Path>,
+    ) -> Option<PathBuf> {
+        if self.scm != ScmType::Git {
+            return None;
+        }
+        if let Some(reference) = &self.reference {
+            return Some(workspace_root.join(reference));
+        }
+        let url = GitUrl::new(&self.url).ok()?;
+        mirror_dir.map(|mirror| mirror.join(format!("{}.git", url.repo_path())))
+    }
+
+    /// SCMに渡すclone URL
+    ///
+    /// Gitリポジトリは指定されたプロトコルを保ったまま正規化する（`GitUrl::to_clone_url`）。
+    /// Git以外のSCMや解析できないURLはそのまま返す。
+    pub fn clone_url(&self) -> String {
+        if self.scm != ScmType::Git {
+            return self.url.clone();
+        }
+        GitUrl::new(&self.url)
+            .map(|url| url.to_clone_url())
+            .unwrap_or_else(|_| self.url.clone())
+    }
+
     /// CloneOptionsに変換
     pub fn to_clone_options(&self) -> CloneOptions {
         let (username, password) = self.get_effective_auth();
         let mut options = CloneOptions {
             branch: self.branch.clone(),
-            shallow: self.shallow,
+            shallow: self.shallow.unwrap_or(false),
             revision: self.get_effective_revision().cloned(),
             username: username.cloned(),
             password: password.cloned(),
             extra_options: self.extra_options.clone().unwrap_or_default(),
+            clone_args: self.clone_args.clone().unwrap_or_default(),
             ..Default::default()
         };
 
         // SCM固有オプションから設定を取得
         match &self.scm_options {
-            Some(ScmOptions::Git { remote, depth, recurse_submodules }) => {
+            Some(ScmOptions::Git {
+                remote,
+                depth,
+                recurse_submodules,
+            }) => {
                 options.remote = remote.clone();
                 options.depth = *depth;
                 options.recurse_submodules = *recurse_submodules;
@@ -384,7 +621,12 @@ impl ManifestRepo {
 
     /// Repositoryエンティティに変換
     pub fn to_repository(&self) -> Repository {
-        let mut remotes = vec![Remote::new("origin", &self.url)];
+        // ローカル専用のリポジトリにはoriginがない
+        let mut remotes = if self.is_local_only() {
+            Vec::new()
+        } else {
+            vec![Remote::new("origin", &self.url)]
+        };
 
         // 追加のリモートがあれば追加
         if let Some(additional_remotes) = &self.remotes {
@@ -402,7 +644,7 @@ impl ManifestRepo {
         if let Some(tag) = &self.tag {
             repo = repo.with_tag(tag);
         }
-        repo = repo.with_shallow(self.shallow);
+        repo = repo.with_shallow(self.shallow.unwrap_or(false));
 
         repo
     }
@@ -429,6 +671,30 @@ pub struct Manifest {
     /// クレデンシャルヘルパーコマンド（git credential-helper パターン）
     #[serde(skip_serializing_if = "Option::is_none")]
     pub credential_helper: Option<String>,
+
+    /// デフォルトの同期時マージ戦略（オプション）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_sync_strategy: Option<SyncStrategy>,
+
+    /// shallow cloneのデフォルト（オプション、ワークスペース設定より優先）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_shallow: Option<bool>,
+
+    /// sync開始前にワークスペースのルートで一度だけ実行するシェルコマンド
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pre_sync: Option<String>,
+
+    /// sync完了後にワークスペースのルートで一度だけ実行するシェルコマンド
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_sync: Option<String>,
+
+    /// エコシステム（`cargo`・`npm`・`yarn`・`pip`・`go`）ごとの`audit`で使う監査コマンド
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audit_commands: Option<BTreeMap<String, String>>,
+
+    /// このバージョンが扱わないフィールド（書き戻しても失われないよう保持する）
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_yaml::Value>,
 }
 
 impl Manifest {
@@ -440,6 +706,12 @@ impl Manifest {
             default_branch: None,
             default_scm: None,
             credential_helper: None,
+            default_sync_strategy: None,
+            default_shallow: None,
+            pre_sync: None,
+            post_sync: None,
+            audit_commands: None,
+            extra: HashMap::new(),
         }
     }
 
@@ -461,6 +733,61 @@ impl Manifest {
         self
     }
 
+    /// デフォルトの同期時マージ戦略を設定
+    pub fn with_default_sync_strategy(mut self, strategy: SyncStrategy) -> Self {
+        self.default_sync_strategy = Some(strategy);
+        self
+    }
+
+    /// shallow cloneのデフォルトを設定
+    pub fn with_default_shallow(mut self, shallow: bool) -> Self {
+        self.default_shallow = Some(shallow);
+        self
+    }
+
+    /// sync開始前のフックを設定
+    pub fn with_pre_sync(mut self, command: impl Into<String>) -> Self {
+        self.pre_sync = Some(command.into());
+        self
+    }
+
+    /// sync完了後のフックを設定
+    pub fn with_post_sync(mut self, command: impl Into<String>) -> Self {
+        self.post_sync = Some(command.into());
+        self
+    }
+
+    /// エコシステムの監査コマンドを設定
+    pub fn with_audit_command(
+        mut self,
+        ecosystem: impl Into<String>,
+        command: impl Into<String>,
+    ) -> Self {
+        self.audit_commands
+            .get_or_insert_with(BTreeMap::new)
+            .insert(ecosystem.into(), command.into());
+        self
+    }
+
+    /// リポジトリをshallow cloneするか（リポジトリ設定 > マニフェストのデフォルト > ワークスペース設定）
+    pub fn shallow_for(&self, repo: &ManifestRepo, workspace_default: bool) -> bool {
+        repo.shallow
+            .or(self.default_shallow)
+            .unwrap_or(workspace_default)
+    }
+
+    /// リポジトリで使うブランチを取得（リポジトリ設定 > マニフェストのデフォルト）
+    pub fn branch_for<'a>(&'a self, repo: &'a ManifestRepo) -> Option<&'a str> {
+        repo.branch.as_deref().or(self.default_branch.as_deref())
+    }
+
+    /// リポジトリに適用する同期時マージ戦略を取得（リポジトリ設定 > マニフェストのデフォルト）
+    pub fn sync_strategy_for(&self, repo: &ManifestRepo) -> SyncStrategy {
+        repo.sync_strategy
+            .or(self.default_sync_strategy)
+            .unwrap_or_default()
+    }
+
     /// 特定のグループに属するリポジトリを取得
     pub fn get_repos_in_group(&self, group_name: &str) -> Vec<&ManifestRepo> {
         if let Some(groups) = &self.groups {
@@ -477,6 +804,36 @@ impl Manifest {
         }
     }
 
+    /// いずれかのグループに属するリポジトリ（マニフェスト順、グループ未指定なら全て）
+    pub fn repos_in_groups(&self, groups: &[String]) -> Vec<&ManifestRepo> {
+        if groups.is_empty() {
+            return self.repos.iter().collect();
+        }
+        self.repos
+            .iter()
+            .filter(|repo| {
+                groups.iter().any(|group| {
+                    self.get_repos_in_group(group)
+                        .iter()
+                        .any(|r| r.dest == repo.dest)
+                })
+            })
+            .collect()
+    }
+
+    /// 指定したdestのリポジトリが属するグループ名（名前順）
+    pub fn groups_of(&self, dest: &str) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .groups
+            .iter()
+            .flatten()
+            .filter(|(_, group)| group.repos.iter().any(|r| r == dest))
+            .map(|(name, _)| name.clone())
+            .collect();
+        names.sort();
+        names
+    }
+
     /// 全てのリポジトリをRepositoryエンティティのリストに変換
     pub fn to_repositories(&self) -> Vec<Repository> {
         self.repos.iter().map(|r| r.to_repository()).collect()
@@ -507,7 +864,7 @@ mod tests {
 
         assert_eq!(repo.url, "git@github.com:example/repo.git");
         assert_eq!(repo.dest, "path/to/repo");
-        assert!(!repo.shallow);
+        assert_eq!(repo.shallow, None);
     }
 
     #[test]
@@ -525,6 +882,34 @@ mod tests {
         assert_eq!(repo.clone_url(), Some("git@github.com:example/repo.git"));
     }
 
+    #[test]
+    fn test_manifest_repo_clone_url() {
+        let ssh = ManifestRepo::new("git@github.com:example/app", "app");
+        assert_eq!(ssh.clone_url(), "git@github.com:example/app.git");
+
+        let https = ManifestRepo::new("https://github.com/example/app", "app");
+        assert_eq!(https.clone_url(), "https://github.com/example/app.git");
+
+        let svn = ManifestRepo::with_scm("svn://svn.example.com/legacy", "legacy", ScmType::Svn);
+        assert_eq!(svn.clone_url(), "svn://svn.example.com/legacy");
+    }
+
+    #[test]
+    fn test_local_only_repo() {
+        let manifest: Manifest =
+            serde_yaml::from_str("repos:\n  - dest: scratch\n  - dest: app\n    url: https://github.com/example/app.git\n")
+                .unwrap();
+
+        let scratch = &manifest.repos[0];
+        assert!(scratch.is_local_only());
+        assert!(scratch.to_repository().remotes.is_empty());
+        assert!(!manifest.repos[1].is_local_only());
+
+        // 書き出し時もurlを省略する
+        let yaml = serde_yaml::to_string(&manifest).unwrap();
+        assert!(!yaml.contains("url: ''"));
+    }
+
     #[test]
     fn test_manifest_with_groups() {
         let repos = vec![
@@ -546,4 +931,311 @@ mod tests {
         assert_eq!(group_repos[0].dest, "repo1");
         assert_eq!(group_repos[1].dest, "repo2");
     }
+
+    #[test]
+    fn test_groups_of_repo() {
+        let repos = vec![
+            ManifestRepo::new("git@github.com:example/repo1.git", "repo1"),
+            ManifestRepo::new("git@github.com:example/repo2.git", "repo2"),
+        ];
+        let mut groups = HashMap::new();
+        groups.insert("web".to_string(), Group::new(vec!["repo1".to_string()]));
+        groups.insert(
+            "core".to_string(),
+            Group::new(vec!["repo1".to_string(), "repo2".to_string()]),
+        );
+        let manifest = Manifest::new(repos).with_groups(groups);
+
+        assert_eq!(manifest.groups_of("repo1"), vec!["core", "web"]);
+
+        let dests = |groups: &[&str]| -> Vec<String> {
+            let groups: Vec<String> = groups.iter().map(|g| g.to_string()).collect();
+            manifest
+                .repos_in_groups(&groups)
+                .iter()
+                .map(|r| r.dest.clone())
+                .collect()
+        };
+        assert_eq!(dests(&[]), vec!["repo1", "repo2"]);
+        assert_eq!(dests(&["web"]), vec!["repo1"]);
+        assert_eq!(dests(&["web", "core"]), vec!["repo1", "repo2"]);
+        assert!(dests(&["missing"]).is_empty());
+        assert_eq!(manifest.groups_of("repo2"), vec!["core"]);
+        assert!(manifest.groups_of("unknown").is_empty());
+        assert!(Manifest::new(vec![]).groups_of("repo1").is_empty());
+    }
+
+    #[test]
+    fn test_sync_strategy_mixed_manifest() {
+        let yaml = r#"
+default_sync_strategy: merge
+repos:
+  - url: git@github.com:example/ff.git
+    dest: ff
+    sync_strategy: ff-only
+  - url: git@github.com:example/rebase.git
+    dest: rebase
+    sync_strategy: rebase
+  - url: git@github.com:example/default.git
+    dest: default
+"#;
+        let manifest: Manifest = serde_yaml::from_str(yaml).unwrap();
+
+        let strategy =
+            |dest: &str| manifest.sync_strategy_for(manifest.find_repo_by_dest(dest).unwrap());
+        assert_eq!(strategy("ff"), SyncStrategy::FfOnly);
+        assert_eq!(strategy("rebase"), SyncStrategy::Rebase);
+        assert_eq!(strategy("default"), SyncStrategy::Merge);
+
+        // マニフェストのデフォルトがない場合はff-only
+        let manifest = Manifest::new(vec![ManifestRepo::new("git@github.com:example/a.git", "a")]);
+        assert_eq!(
+            manifest.sync_strategy_for(&manifest.repos[0]),
+            SyncStrategy::FfOnly
+        );
+    }
+
+    #[test]
+    fn test_shallow_precedence() {
+        let yaml = r#"
+default_shallow: true
+repos:
+  - url: git@github.com:example/full.git
+    dest: full
+    shallow: false
+  - url: git@github.com:example/default.git
+    dest: default
+"#;
+        let manifest: Manifest = serde_yaml::from_str(yaml).unwrap();
+        let shallow = |dest: &str, workspace_default: bool| {
+            manifest.shallow_for(manifest.find_repo_by_dest(dest).unwrap(), workspace_default)
+        };
+
+        // リポジトリ設定はマニフェストのデフォルトより優先
+        assert!(!shallow("full", true));
+        // マニフェストのデフォルトはワークスペース設定より優先
+        assert!(shallow("default", false));
+
+        // マニフェストのデフォルトがない場合はワークスペース設定、それもなければfalse
+        let manifest = Manifest::new(vec![ManifestRepo::new("git@github.com:example/a.git", "a")]);
+        assert!(manifest.shallow_for(&manifest.repos[0], true));
+        assert!(!manifest.shallow_for(&manifest.repos[0], false));
+    }
+
+    #[test]
+    fn test_branch_precedence() {
+        let yaml = r#"
+default_branch: develop
+repos:
+  - url: git@github.com:example/pinned.git
+    dest: pinned
+    branch: release
+  - url: git@github.com:example/default.git
+    dest: default
+"#;
+        let manifest: Manifest = serde_yaml::from_str(yaml).unwrap();
+        let branch = |dest: &str| manifest.branch_for(manifest.find_repo_by_dest(dest).unwrap());
+
+        // リポジトリ設定はマニフェストのデフォルトより優先
+        assert_eq!(branch("pinned"), Some("release"));
+        assert_eq!(branch("default"), Some("develop"));
+
+        // どちらもない場合はNone（リモートのHEADから決める）
+        let manifest = Manifest::new(vec![ManifestRepo::new("git@github.com:example/a.git", "a")]);
+        assert_eq!(manifest.branch_for(&manifest.repos[0]), None);
+    }
+
+    #[test]
+    fn test_submodules_precedence() {
+        let yaml = r#"
+repos:
+  - url: git@github.com:example/on.git
+    dest: on
+    submodules: true
+  - url: git@github.com:example/off.git
+    dest: off
+    submodules: false
+  - url: git@github.com:example/legacy.git
+    dest: legacy
+    scm_options:
+      type: Git
+      recurse_submodules: true
+  - url: git@github.com:example/default.git
+    dest: default
+  - url: https://svn.example.com/repo
+    dest: svn
+    scm: svn
+    submodules: true
+"#;
+        let manifest: Manifest = serde_yaml::from_str(yaml).unwrap();
+        let submodules = |dest: &str, default: bool| {
+            manifest
+                .find_repo_by_dest(dest)
+                .unwrap()
+                .submodules_enabled(default)
+        };
+
+        // リポジトリ設定は--recurse-submodulesより優先
+        assert!(submodules("on", false));
+        assert!(!submodules("off", true));
+        assert!(submodules("legacy", false));
+        assert!(submodules("default", true));
+        assert!(!submodules("default", false));
+        // Git以外は対象外
+        assert!(!submodules("svn", true));
+    }
+
+    #[test]
+    fn test_lfs_precedence() {
+        let yaml = r#"
+repos:
+  - url: git@github.com:example/assets.git
+    dest: assets
+    lfs: true
+  - url: git@github.com:example/code.git
+    dest: code
+    lfs: false
+  - url: git@github.com:example/default.git
+    dest: default
+  - url: https://svn.example.com/repo
+    dest: svn
+    scm: svn
+    lfs: true
+"#;
+        let manifest: Manifest = serde_yaml::from_str(yaml).unwrap();
+        let lfs = |dest: &str, default: bool| {
+            manifest
+                .find_repo_by_dest(dest)
+                .unwrap()
+                .lfs_enabled(default)
+        };
+
+        assert!(lfs("assets", false));
+        assert!(!lfs("code", true));
+        assert!(lfs("default", true));
+        assert!(!lfs("default", false));
+        assert!(!lfs("svn", true));
+    }
+
+    #[test]
+    fn test_clone_filter_precedence() {
+        let yaml = r#"
+repos:
+  - url: git@github.com:example/monorepo.git
+    dest: monorepo
+    partial_clone: blobless
+  - url: git@github.com:example/history.git
+    dest: history
+    partial_clone: treeless
+  - url: git@github.com:example/default.git
+    dest: default
+  - url: https://svn.example.com/repo
+    dest: svn
+    scm: svn
+    partial_clone: blobless
+"#;
+        let manifest: Manifest = serde_yaml::from_str(yaml).unwrap();
+        let filter = |dest: &str, default: Option<&str>| {
+            manifest
+                .find_repo_by_dest(dest)
+                .unwrap()
+                .clone_filter(default)
+        };
+
+        assert_eq!(
+            filter("monorepo", Some("tree:0")).as_deref(),
+            Some("blob:none")
+        );
+        assert_eq!(filter("history", None).as_deref(), Some("tree:0"));
+        assert_eq!(
+            filter("default", Some("blob:limit=1m")).as_deref(),
+            Some("blob:limit=1m")
+        );
+        assert_eq!(filter("default", None), None);
+        assert_eq!(filter("svn", Some("blob:none")), None);
+
+        let invalid = yaml.replace("partial_clone: treeless", "partial_clone: sparse");
+        assert!(serde_yaml::from_str::<Manifest>(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_reference_path_precedence() {
+        let yaml = r#"
+repos:
+  - url: git@github.com:example/app.git
+    dest: app
+  - url: git@github.com:example/lib.git
+    dest: lib
+    reference: ../objects/lib.git
+  - url: https://svn.example.com/repo
+    dest: svn
+    scm: svn
+    reference: /srv/svn
+"#;
+        let manifest: Manifest = serde_yaml::from_str(yaml).unwrap();
+        let root = Path::new("/work");
+        let mirror = Path::new("/cache/mirror");
+        let reference = |dest: &str, mirror_dir: Option<&Path>| {
+            manifest
+                .find_repo_by_dest(dest)
+                .unwrap()
+                .reference_path(root, mirror_dir)
+        };
+
+        assert_eq!(
+            reference("app", Some(mirror)),
+            Some(PathBuf::from("/cache/mirror/example/app.git"))
+        );
+        assert_eq!(reference("app", None), None);
+        assert_eq!(
+            reference("lib", Some(mirror)),
+            Some(PathBuf::from("/work/../objects/lib.git"))
+        );
+        assert_eq!(reference("svn", Some(mirror)), None);
+    }
+
+    #[test]
+    fn test_audit_commands_parsing() {
+        let yaml = r#"
+audit_commands:
+  pip: pip-audit -r requirements.txt --format json
+  go: govulncheck -json ./cmd/...
+repos:
+  - url: git@github.com:example/repo.git
+    dest: repo
+"#;
+        let manifest: Manifest = serde_yaml::from_str(yaml).unwrap();
+        let commands = manifest.audit_commands.as_ref().unwrap();
+        assert_eq!(
+            commands.get("pip").map(String::as_str),
+            Some("pip-audit -r requirements.txt --format json")
+        );
+        assert_eq!(commands.len(), 2);
+
+        // 未指定の場合は出力しない
+        let yaml = serde_yaml::to_string(&Manifest::new(vec![])).unwrap();
+        assert!(!yaml.contains("audit_commands"));
+    }
+
+    #[test]
+    fn test_sync_strategy_rejects_unknown_value() {
+        let yaml = r#"
+repos:
+  - url: git@github.com:example/repo.git
+    dest: repo
+    sync_strategy: squash
+"#;
+        let result: Result<Manifest, _> = serde_yaml::from_str(yaml);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("squash"));
+    }
+
+    #[test]
+    fn test_sync_strategy_serialization() {
+        let repo = ManifestRepo::new("git@github.com:example/repo.git", "repo")
+            .with_sync_strategy(SyncStrategy::FfOnly);
+        let yaml = serde_yaml::to_string(&repo).unwrap();
+        assert!(yaml.contains("sync_strategy: ff-only"));
+        assert_eq!(SyncStrategy::Rebase.to_string(), "rebase");
+    }
 }
@@ -1,5 +1,6 @@
 pub mod credential;
 pub mod manifest;
+pub mod repo_selector;
 pub mod repository;
 pub mod workspace;
 pub mod workspace_config;
@@ -0,0 +1,271 @@
+use super::manifest::{Manifest, ManifestRepo};
+use globset::{GlobBuilder, GlobMatcher};
+use thiserror::Error;
+
+/// リポジトリ選択のエラー
+#[derive(Debug, Error)]
+pub enum RepoSelectorError {
+    #[error("Invalid repository pattern '{pattern}': {error}")]
+    InvalidPattern { pattern: String, error: String },
+
+    #[error("Repository pattern '{0}' did not match any repository")]
+    NoMatch(String),
+}
+
+/// グループ・タグ・destのglobによる対象リポジトリの選択条件
+///
+/// グループを指定した場合はいずれかのグループに属するリポジトリ、指定しない場合は
+/// 呼び出し側の既定のリポジトリが候補になる。候補のうち`tags`のいずれかを持ち、
+/// `exclude_tags`をひとつも持たず、destが`repo_patterns`のいずれかに一致するものが
+/// 選択される。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RepoSelector {
+    /// 対象グループ（Noneの場合は既定のリポジトリ）
+    pub groups: Option<Vec<String>>,
+
+    /// いずれかを持つリポジトリのみを対象にするタグ（空の場合は絞り込まない）
+    pub tags: Vec<String>,
+
+    /// ひとつでも持つリポジトリを除外するタグ
+    pub exclude_tags: Vec<String>,
+
+    /// destに一致させるglobパターン（空の場合は絞り込まない）
+    pub repo_patterns: Vec<String>,
+}
+
+impl RepoSelector {
+    /// グループを指定して新しい選択条件を作成
+    pub fn new(groups: Option<Vec<String>>) -> Self {
+        Self {
+            groups,
+            ..Default::default()
+        }
+    }
+
+    /// 対象にするタグを設定
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// 除外するタグを設定
+    pub fn with_exclude_tags(mut self, exclude_tags: Vec<String>) -> Self {
+        self.exclude_tags = exclude_tags;
+        self
+    }
+
+    /// destに一致させるglobパターンを設定
+    pub fn with_repo_patterns(mut self, repo_patterns: Vec<String>) -> Self {
+        self.repo_patterns = repo_patterns;
+        self
+    }
+
+    /// リポジトリがタグの条件を満たすか
+    pub fn matches_tags(&self, repo: &ManifestRepo) -> bool {
+        let included = self.tags.is_empty() || self.tags.iter().any(|tag| repo.has_tag(tag));
+        included && !self.exclude_tags.iter().any(|tag| repo.has_tag(tag))
+    }
+
+    /// 対象リポジトリを選択（マニフェスト順）
+    ///
+    /// グループ未指定の場合は`default_repos`を候補にする。グループとタグで絞り込んだ
+    /// 候補にひとつも一致しないパターンがあればエラーにする。
+    pub fn select<'a>(
+        &self,
+        manifest: &'a Manifest,
+        default_repos: Vec<&'a ManifestRepo>,
+    ) -> Result<Vec<&'a ManifestRepo>, RepoSelectorError> {
+        let matchers = self.compile_patterns()?;
+        let candidates: Vec<&ManifestRepo> = match &self.groups {
+            Some(groups) => manifest.repos_in_groups(groups),
+            None => default_repos,
+        }
+        .into_iter()
+        .filter(|repo| self.matches_tags(repo))
+        .collect();
+
+        if matchers.is_empty() {
+            return Ok(candidates);
+        }
+
+        for (pattern, matcher) in &matchers {
+            if !candidates.iter().any(|repo| matcher.is_match(&repo.dest)) {
+                return Err(RepoSelectorError::NoMatch(pattern.clone()));
+            }
+        }
+
+        Ok(candidates
+            .into_iter()
+            .filter(|repo| matchers.iter().any(|(_, m)| m.is_match(&repo.dest)))
+            .collect())
+    }
+
+    /// destのglobパターンをコンパイル（`*`はパス区切りをまたがない）
+    fn compile_patterns(&self) -> Result<Vec<(String, GlobMatcher)>, RepoSelectorError> {
+        self.repo_patterns
+            .iter()
+            .map(|pattern| {
+                let glob = GlobBuilder::new(pattern.trim_end_matches('/'))
+                    .literal_separator(true)
+                    .build()
+                    .map_err(|e| RepoSelectorError::InvalidPattern {
+                        pattern: pattern.clone(),
+                        error: e.to_string(),
+                    })?;
+                Ok((pattern.clone(), glob.compile_matcher()))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::manifest::Group;
+    use std::collections::HashMap;
+
+    fn tagged(dest: &str, tags: &[&str]) -> ManifestRepo {
+        ManifestRepo::new(format!("https://example.com/{}.git", dest), dest)
+            .with_tags(tags.iter().map(|t| t.to_string()).collect())
+    }
+
+    fn sample_manifest() -> Manifest {
+        let mut groups = HashMap::new();
+        groups.insert(
+            "backend".to_string(),
+            Group::new(vec![
+                "api".to_string(),
+                "worker".to_string(),
+                "db".to_string(),
+            ]),
+        );
+        groups.insert("frontend".to_string(), Group::new(vec!["web".to_string()]));
+
+        Manifest::new(vec![
+            tagged("api", &["rust", "service"]),
+            tagged("worker", &["rust", "legacy"]),
+            tagged("db", &["sql"]),
+            tagged("web", &["typescript", "service"]),
+            ManifestRepo::new("https://example.com/docs.git", "docs"),
+        ])
+        .with_groups(groups)
+    }
+
+    fn dests(repos: Vec<&ManifestRepo>) -> Vec<&str> {
+        repos.into_iter().map(|r| r.dest.as_str()).collect()
+    }
+
+    fn selected<'a>(selector: &RepoSelector, manifest: &'a Manifest) -> Vec<&'a str> {
+        dests(
+            selector
+                .select(manifest, manifest.repos.iter().collect())
+                .unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_select_without_filters_returns_default_repos() {
+        let manifest = sample_manifest();
+        let selector = RepoSelector::default();
+
+        assert_eq!(
+            selected(&selector, &manifest),
+            vec!["api", "worker", "db", "web", "docs"]
+        );
+    }
+
+    #[test]
+    fn test_select_by_tag() {
+        let manifest = sample_manifest();
+        let selector = RepoSelector::default().with_tags(vec!["service".to_string()]);
+
+        assert_eq!(selected(&selector, &manifest), vec!["api", "web"]);
+    }
+
+    #[test]
+    fn test_select_tag_include_within_group_include() {
+        let manifest = sample_manifest();
+        let selector = RepoSelector::new(Some(vec!["backend".to_string()]))
+            .with_tags(vec!["rust".to_string()]);
+
+        assert_eq!(selected(&selector, &manifest), vec!["api", "worker"]);
+    }
+
+    #[test]
+    fn test_select_group_with_tag_include_and_exclude() {
+        let manifest = sample_manifest();
+        let selector = RepoSelector::new(Some(vec!["backend".to_string(), "frontend".to_string()]))
+            .with_tags(vec!["rust".to_string(), "service".to_string()])
+            .with_exclude_tags(vec!["legacy".to_string()]);
+
+        assert_eq!(selected(&selector, &manifest), vec!["api", "web"]);
+    }
+
+    #[test]
+    fn test_exclude_tag_keeps_untagged_repos() {
+        let manifest = sample_manifest();
+        let selector = RepoSelector::default().with_exclude_tags(vec!["rust".to_string()]);
+
+        assert_eq!(selected(&selector, &manifest), vec!["db", "web", "docs"]);
+    }
+
+    #[test]
+    fn test_select_by_dest_glob() {
+        let mut manifest = sample_manifest();
+        manifest.repos.extend([
+            ManifestRepo::new("https://example.com/a.git", "libs/a"),
+            ManifestRepo::new("https://example.com/b.git", "libs/b"),
+            ManifestRepo::new("https://example.com/c.git", "libs/nested/c"),
+            ManifestRepo::new("https://example.com/s1.git", "apps/service"),
+            ManifestRepo::new("https://example.com/s2.git", "apps/web/service"),
+            ManifestRepo::new("https://example.com/s3.git", "service"),
+        ]);
+        let select = |patterns: &[&str]| {
+            RepoSelector::default()
+                .with_repo_patterns(patterns.iter().map(|p| p.to_string()).collect())
+                .select(&manifest, manifest.repos.iter().collect())
+                .map(dests)
+        };
+
+        assert_eq!(select(&["libs/*"]).unwrap(), vec!["libs/a", "libs/b"]);
+        assert_eq!(
+            select(&["**/service"]).unwrap(),
+            vec!["apps/service", "apps/web/service", "service"]
+        );
+        assert_eq!(select(&["libs/nested/c"]).unwrap(), vec!["libs/nested/c"]);
+        assert_eq!(select(&["api", "libs/b"]).unwrap(), vec!["api", "libs/b"]);
+    }
+
+    #[test]
+    fn test_select_dest_glob_with_group_and_tag() {
+        let manifest = sample_manifest();
+        let selector = RepoSelector::new(Some(vec!["backend".to_string()]))
+            .with_tags(vec!["rust".to_string()])
+            .with_repo_patterns(vec!["w*".to_string()]);
+
+        assert_eq!(selected(&selector, &manifest), vec!["worker"]);
+    }
+
+    #[test]
+    fn test_select_dest_glob_without_match_is_error() {
+        let manifest = sample_manifest();
+        let result = RepoSelector::default()
+            .with_repo_patterns(vec!["api".to_string(), "libs/*".to_string()])
+            .select(&manifest, manifest.repos.iter().collect());
+        assert!(matches!(result, Err(RepoSelectorError::NoMatch(p)) if p == "libs/*"));
+
+        // グループで絞り込んだ後の候補に一致しない場合もエラー
+        let result = RepoSelector::new(Some(vec!["frontend".to_string()]))
+            .with_repo_patterns(vec!["api".to_string()])
+            .select(&manifest, manifest.repos.iter().collect());
+        assert!(matches!(result, Err(RepoSelectorError::NoMatch(_))));
+
+        let result = RepoSelector::default()
+            .with_repo_patterns(vec!["libs/[".to_string()])
+            .select(&manifest, manifest.repos.iter().collect());
+        assert!(matches!(
+            result,
+            Err(RepoSelectorError::InvalidPattern { .. })
+        ));
+    }
+}
@@ -1,6 +1,10 @@
-use super::{manifest::Manifest, repository::Repository};
+use super::{
+    manifest::{Manifest, ManifestRepo},
+    repository::Repository,
+};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Component, Path, PathBuf};
+use thiserror::Error;
 
 /// ワークスペースの設定
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,9 +27,17 @@ pub struct WorkspaceConfig {
     #[serde(default)]
     pub clone_all_repos: bool,
 
-    /// 単一リモート名（設定されている場合）
+    /// 全てのGitリポジトリの取得元とするミラーのベースURL（設定されている場合）
+    ///
+    /// 各リポジトリは`<singular_remote>/<リポジトリパス>`からクローン・フェッチされる。
     #[serde(skip_serializing_if = "Option::is_none")]
     pub singular_remote: Option<String>,
+
+    /// マニフェストリポジトリのローカルクローンの場所（ワークスペースルートからの相対パス）
+    ///
+    /// マニフェストリポジトリから初期化されたワークスペースでのみ設定される。
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manifest_repo: Option<PathBuf>,
 }
 
 impl WorkspaceConfig {
@@ -38,6 +50,7 @@ impl WorkspaceConfig {
             repo_groups: vec!["default".to_string()],
             clone_all_repos: false,
             singular_remote: None,
+            manifest_repo: None,
         }
     }
 
@@ -50,6 +63,7 @@ impl WorkspaceConfig {
             repo_groups: vec!["default".to_string()],
             clone_all_repos: false,
             singular_remote: None,
+            manifest_repo: None,
         }
     }
 
@@ -77,12 +91,77 @@ impl WorkspaceConfig {
         self
     }
 
+    /// マニフェストリポジトリの場所を設定
+    pub fn with_manifest_repo(mut self, path: impl Into<PathBuf>) -> Self {
+        self.manifest_repo = Some(path.into());
+        self
+    }
+
     /// デフォルトグループのみを使用しているか
     pub fn is_using_default_group(&self) -> bool {
         self.repo_groups.len() == 1 && self.repo_groups[0] == "default"
     }
 }
 
+/// リポジトリのdestをワークスペース内のパスに解決できない場合のエラー
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum DestPathError {
+    #[error("Repository destination is empty")]
+    Empty,
+
+    #[error("Repository destination '{0}' must be relative to the workspace")]
+    Absolute(String),
+
+    #[error("Repository destination '{0}' points outside the workspace")]
+    Traversal(String),
+
+    #[error("Repository destination '{0}' leads outside the workspace through a symbolic link")]
+    SymlinkEscape(String),
+}
+
+/// destをワークスペースからの相対パスに正規化
+///
+/// 絶対パスと`..`を含むdestはワークスペースの外を指しうるため拒否する。`./`や重複した
+/// 区切りは取り除く（まだクローンしていないパスも扱うため、ファイルシステムは参照しない）。
+pub fn normalize_dest(dest: &str) -> Result<PathBuf, DestPathError> {
+    let mut relative = PathBuf::new();
+    for component in Path::new(dest).components() {
+        match component {
+            Component::Normal(part) => relative.push(part),
+            Component::CurDir => {}
+            Component::ParentDir => return Err(DestPathError::Traversal(dest.to_string())),
+            Component::RootDir | Component::Prefix(_) => {
+                return Err(DestPathError::Absolute(dest.to_string()))
+            }
+        }
+    }
+    if relative.as_os_str().is_empty() {
+        return Err(DestPathError::Empty);
+    }
+    Ok(relative)
+}
+
+/// destを`root`以下のパスに解決
+///
+/// [`normalize_dest`]で正規化した上で、既に存在する部分のパスを実体パスに解決し、
+/// シンボリックリンクをたどって`root`の外に出ないことを確かめる。
+pub fn resolve_dest_in(root: &Path, dest: &str) -> Result<PathBuf, DestPathError> {
+    let path = root.join(normalize_dest(dest)?);
+
+    // ワークスペースがまだない場合はリンクもないので確かめるものがない
+    let Ok(canonical_root) = root.canonicalize() else {
+        return Ok(path);
+    };
+    let inside = path
+        .ancestors()
+        .find_map(|existing| existing.canonicalize().ok())
+        .map_or(true, |existing| existing.starts_with(&canonical_root));
+    if !inside {
+        return Err(DestPathError::SymlinkEscape(dest.to_string()));
+    }
+    Ok(path)
+}
+
 /// ワークスペースの状態
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum WorkspaceStatus {
@@ -239,15 +318,38 @@ impl Workspace {
         }
     }
 
+    /// マニフェストリポジトリのローカルクローンのパス（設定されている場合）
+    pub fn manifest_repo_path(&self) -> Option<PathBuf> {
+        self.config
+            .manifest_repo
+            .as_ref()
+            .map(|path| self.root_path.join(path))
+    }
+
+    /// 同期時に読み込むマニフェストファイルのパス
+    ///
+    /// マニフェストリポジトリにマニフェストファイルがあればそれを、なければ
+    /// `manifest_file_path`と同じファイルを返す。
+    pub fn source_manifest_file_path(&self) -> PathBuf {
+        self.manifest_repo_path()
+            .and_then(|dir| {
+                ["wmgr.yml", "wmgr.yaml", "manifest.yml", "manifest.yaml"]
+                    .iter()
+                    .map(|name| dir.join(name))
+                    .find(|path| path.is_file())
+            })
+            .unwrap_or_else(|| self.manifest_file_path())
+    }
+
     /// 旧マニフェストファイル（.tsrc/manifest.yml）からの移行用
     /// レガシーサポート用
     pub fn legacy_manifest_file_path(&self) -> PathBuf {
         self.wmgr_dir().join("manifest.yml")
     }
 
-    /// 特定のリポジトリのパスを取得
-    pub fn repo_path(&self, dest: &str) -> PathBuf {
-        self.root_path.join(dest)
+    /// リポジトリのdestをワークスペース内のパスに解決（[`resolve_dest_in`]を参照）
+    pub fn resolve_dest(&self, dest: &str) -> Result<PathBuf, DestPathError> {
+        resolve_dest_in(&self.root_path, dest)
     }
 
     /// ワークスペースが初期化されているか
@@ -260,6 +362,20 @@ impl Workspace {
         matches!(self.status, WorkspaceStatus::Corrupted)
     }
 
+    /// 設定に基づいて管理対象とするマニフェストのリポジトリ（マニフェスト順）
+    ///
+    /// `clone_all_repos`が有効な場合はグループに関係なく全リポジトリを、無効な場合は
+    /// `repo_groups`に属するリポジトリのみを返す。デフォルトグループのみの場合は全リポジトリ。
+    pub fn configured_repos(&self) -> Vec<&ManifestRepo> {
+        let Some(manifest) = &self.manifest else {
+            return Vec::new();
+        };
+        if self.config.clone_all_repos || self.config.is_using_default_group() {
+            return manifest.repos.iter().collect();
+        }
+        manifest.repos_in_groups(&self.config.repo_groups)
+    }
+
     /// 設定されたグループに基づいてリポジトリをフィルタリング
     pub fn filter_repos_by_groups(&self) -> Vec<&Repository> {
         if let Some(manifest) = &self.manifest {
@@ -372,11 +488,66 @@ mod tests {
             PathBuf::from("/path/to/workspace/.wmgr")
         );
         assert_eq!(
-            workspace.repo_path("repo1"),
+            workspace.resolve_dest("repo1").unwrap(),
             PathBuf::from("/path/to/workspace/repo1")
         );
     }
 
+    #[test]
+    fn test_resolve_dest() {
+        let config = WorkspaceConfig::new("git@github.com:example/manifest.git", "main");
+        let workspace = Workspace::new(PathBuf::from("/path/to/workspace"), config);
+
+        assert_eq!(
+            workspace.resolve_dest("./libs//core/").unwrap(),
+            PathBuf::from("/path/to/workspace/libs/core")
+        );
+        assert_eq!(
+            workspace.resolve_dest("../escape"),
+            Err(DestPathError::Traversal("../escape".to_string()))
+        );
+        assert_eq!(
+            workspace.resolve_dest("libs/../../escape"),
+            Err(DestPathError::Traversal("libs/../../escape".to_string()))
+        );
+        assert_eq!(
+            workspace.resolve_dest("/etc/passwd"),
+            Err(DestPathError::Absolute("/etc/passwd".to_string()))
+        );
+        assert_eq!(workspace.resolve_dest(""), Err(DestPathError::Empty));
+        assert_eq!(workspace.resolve_dest("."), Err(DestPathError::Empty));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_resolve_dest_rejects_symlinks_leading_outside() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let root = temp_dir.path().join("workspace");
+        let outside = temp_dir.path().join("outside");
+        std::fs::create_dir_all(root.join("libs")).unwrap();
+        std::fs::create_dir_all(&outside).unwrap();
+        std::os::unix::fs::symlink(&outside, root.join("escape")).unwrap();
+        std::os::unix::fs::symlink(root.join("libs"), root.join("shared")).unwrap();
+
+        assert_eq!(
+            resolve_dest_in(&root, "escape/app"),
+            Err(DestPathError::SymlinkEscape("escape/app".to_string()))
+        );
+        assert_eq!(
+            resolve_dest_in(&root, "escape"),
+            Err(DestPathError::SymlinkEscape("escape".to_string()))
+        );
+        // ワークスペース内を指すリンクとまだ存在しないパスは解決できる
+        assert_eq!(
+            resolve_dest_in(&root, "shared/core").unwrap(),
+            root.join("shared/core")
+        );
+        assert_eq!(
+            resolve_dest_in(&root, "new/app").unwrap(),
+            root.join("new/app")
+        );
+    }
+
     #[test]
     fn test_workspace_status() {
         let config = WorkspaceConfig::new("git@github.com:example/manifest.git", "main");
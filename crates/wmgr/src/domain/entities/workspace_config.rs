@@ -174,8 +174,8 @@ impl WorkspaceConfig {
             }
 
             // 浅いクローンの設定を適用（Git用）
-            if normalized.scm == ScmType::Git && !normalized.shallow && defaults.shallow {
-                normalized.shallow = defaults.shallow;
+            if normalized.scm == ScmType::Git && normalized.shallow.is_none() && defaults.shallow {
+                normalized.shallow = Some(true);
             }
         }
 
@@ -32,40 +32,176 @@ pub enum GitUrlError {
     InvalidCharacters(String),
 }
 
+/// 私設IPアドレスとlocalhostを許可する環境変数（`1`/`true`/`yes`で有効）
+pub const ALLOW_PRIVATE_HOSTS_ENV: &str = "WMGR_ALLOW_PRIVATE_HOSTS";
+
+/// 私設IPアドレスやlocalhostでも許可するホストのカンマ区切りリストを指定する環境変数
+pub const ALLOWED_HOSTS_ENV: &str = "WMGR_ALLOWED_HOSTS";
+
+/// クラウドのメタデータサービスなど、ポリシーに関わらず拒否するホスト
+const ALWAYS_BLOCKED_HOSTS: [&str; 4] = [
+    "0.0.0.0",
+    "169.254.169.254",          // AWS metadata service
+    "metadata.google.internal", // Google Cloud metadata
+    "metadata",
+];
+
+/// URLのホストに対する検証ポリシー
+///
+/// 既定では私設IPアドレスとlocalhostを拒否する。社内のGitサーバーやテスト用の
+/// ローカルサーバーを使う場合は、全体を許可するか許可するホストを列挙する。
+/// メタデータサービスとリンクローカルアドレスはどちらの場合も拒否する。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HostPolicy {
+    /// 私設IPアドレスとlocalhostを許可するか
+    pub allow_private_hosts: bool,
+
+    /// 私設IPアドレスやlocalhostでも許可するホスト（大文字小文字は区別しない）
+    pub allowed_hosts: Vec<String>,
+}
+
+impl HostPolicy {
+    /// 私設IPアドレスとlocalhostを拒否する既定のポリシーを作成
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 私設IPアドレスとlocalhostを許可するかを設定
+    pub fn with_allow_private_hosts(mut self, allow: bool) -> Self {
+        self.allow_private_hosts = allow;
+        self
+    }
+
+    /// 許可するホストを設定
+    pub fn with_allowed_hosts(mut self, hosts: Vec<String>) -> Self {
+        self.allowed_hosts = hosts;
+        self
+    }
+
+    /// `WMGR_ALLOW_PRIVATE_HOSTS`と`WMGR_ALLOWED_HOSTS`からポリシーを作成
+    pub fn from_env() -> Self {
+        Self::from_env_values(
+            std::env::var(ALLOW_PRIVATE_HOSTS_ENV).ok().as_deref(),
+            std::env::var(ALLOWED_HOSTS_ENV).ok().as_deref(),
+        )
+    }
+
+    /// 環境変数の値からポリシーを作成
+    fn from_env_values(allow_private_hosts: Option<&str>, allowed_hosts: Option<&str>) -> Self {
+        let allow_private_hosts = allow_private_hosts
+            .map(|value| {
+                matches!(
+                    value.trim().to_ascii_lowercase().as_str(),
+                    "1" | "true" | "yes"
+                )
+            })
+            .unwrap_or(false);
+        let allowed_hosts = allowed_hosts
+            .map(|value| {
+                value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|host| !host.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            allow_private_hosts,
+            allowed_hosts,
+        }
+    }
+
+    /// 私設IPアドレス・localhostの制限を外してよいホストか
+    fn permits(&self, host: &str) -> bool {
+        self.allow_private_hosts
+            || self
+                .allowed_hosts
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(host))
+    }
+}
+
+/// 利用者が指定したURLのプロトコル
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GitProtocol {
+    /// https://
+    #[default]
+    Https,
+    /// http://
+    Http,
+    /// git@host:path または ssh://
+    Ssh,
+    /// git://
+    Git,
+}
+
 /// Git URLの値オブジェクト
+///
+/// 比較用にはhttps形式へ正規化したURLを持ち、clone・リモート設定には
+/// 利用者が指定した元の形式（`to_clone_url`）を使う。
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct GitUrl {
-    /// 正規化されたURL文字列
+    /// 正規化されたURL文字列（比較用）
     url: String,
 
+    /// 利用者が指定した形式のURL文字列（前後の空白のみ除去）
+    #[serde(default)]
+    original: String,
+
+    /// 利用者が指定したプロトコル
+    #[serde(default)]
+    protocol: GitProtocol,
+
     /// URLのスキーム（https、git、ssh等）
     scheme: String,
 
     /// ホスト名
     host: String,
 
+    /// `ssh://`形式で明示されたポート番号
+    #[serde(default)]
+    port: Option<u16>,
+
     /// リポジトリパス（組織/リポジトリ名）
     repo_path: String,
 }
 
 impl GitUrl {
-    /// 新しいGitUrlインスタンスを作成
+    /// 新しいGitUrlインスタンスを作成（ホストは環境変数のポリシーで検証）
     pub fn new(url: &str) -> Result<Self, GitUrlError> {
+        Self::with_policy(url, &HostPolicy::from_env())
+    }
+
+    /// ホストの検証ポリシーを指定して新しいGitUrlインスタンスを作成
+    pub fn with_policy(url: &str, policy: &HostPolicy) -> Result<Self, GitUrlError> {
         // セキュリティ検証を最初に実行
         Self::validate_security(url)?;
 
         let normalized = Self::normalize_url(url)?;
-        let (scheme, host, repo_path) = Self::parse_url(&normalized)?;
+        let (mut scheme, host, repo_path) = Self::parse_url(&normalized)?;
+
+        // ssh://形式はスキームとポートを保持する（正規化URLには含めない）
+        let mut port = None;
+        if let Some(ssh) = Self::parse_ssh_scheme(url)? {
+            scheme = ssh.scheme().to_string();
+            port = ssh.port();
+        }
 
         // 正規化後もセキュリティ検証を実行
         Self::validate_security(&normalized)?;
-        Self::validate_host_security(&host)?;
+        Self::validate_host_security(&host, policy)?;
         Self::validate_path_security(&repo_path)?;
 
         Ok(Self {
             url: normalized,
+            original: url.trim().to_string(),
+            protocol: Self::detect_protocol(url),
             scheme,
             host,
+            port,
             repo_path,
         })
     }
@@ -159,38 +295,32 @@ impl GitUrl {
     }
 
     /// ホスト名のセキュリティ検証
-    fn validate_host_security(host: &str) -> Result<(), GitUrlError> {
-        // IPアドレスの私設レンジをチェック
-        if Self::is_private_ip(host) {
-            return Err(GitUrlError::BlockedDomain(format!(
-                "Private IP address not allowed: {}",
-                host
-            )));
-        }
-
-        // ローカルホストをチェック
-        if host == "localhost" || host == "127.0.0.1" || host == "::1" {
+    fn validate_host_security(host: &str, policy: &HostPolicy) -> Result<(), GitUrlError> {
+        // メタデータサービスとリンクローカルアドレスはポリシーに関わらず拒否
+        if Self::is_link_local_ip(host)
+            || ALWAYS_BLOCKED_HOSTS
+                .iter()
+                .any(|blocked| host.eq_ignore_ascii_case(blocked))
+        {
             return Err(GitUrlError::BlockedDomain(format!(
-                "Localhost not allowed: {}",
+                "Blocked domain: {}",
                 host
             )));
         }
 
-        // 既知の悪意のあるドメインをチェック
-        let blocked_domains = [
-            "0.0.0.0",
-            "127.0.0.1",
-            "localhost",
-            "169.254.169.254",          // AWS metadata service
-            "metadata.google.internal", // Google Cloud metadata
-            "metadata",
-            "169.254.169.254",
-        ];
+        if !policy.permits(host) {
+            // IPアドレスの私設レンジをチェック
+            if Self::is_private_ip(host) {
+                return Err(GitUrlError::BlockedDomain(format!(
+                    "Private IP address not allowed: {}",
+                    host
+                )));
+            }
 
-        for blocked in &blocked_domains {
-            if host.eq_ignore_ascii_case(blocked) {
+            // ローカルホストをチェック
+            if host.eq_ignore_ascii_case("localhost") {
                 return Err(GitUrlError::BlockedDomain(format!(
-                    "Blocked domain: {}",
+                    "Localhost not allowed: {}",
                     host
                 )));
             }
@@ -258,7 +388,6 @@ impl GitUrl {
                         || (octets[0] == 172 && (octets[1] & 0xF0) == 16)
                         || (octets[0] == 192 && octets[1] == 168)
                         || octets[0] == 127 // ループバック
-                        || octets[0] == 169 && octets[1] == 254 // リンクローカル
                 }
                 IpAddr::V6(ipv6) => {
                     ipv6.is_loopback() || ipv6.is_unspecified() ||
@@ -271,10 +400,43 @@ impl GitUrl {
         }
     }
 
+    /// リンクローカルアドレス（169.254.0.0/16、fe80::/10）かどうかをチェック
+    fn is_link_local_ip(host: &str) -> bool {
+        use std::net::IpAddr;
+
+        match host.parse::<IpAddr>() {
+            Ok(IpAddr::V4(ipv4)) => ipv4.is_link_local(),
+            Ok(IpAddr::V6(ipv6)) => (ipv6.segments()[0] & 0xffc0) == 0xfe80,
+            Err(_) => false,
+        }
+    }
+
+    /// 入力されたURLのプロトコルを判定
+    fn detect_protocol(url: &str) -> GitProtocol {
+        let lower = url.trim().to_ascii_lowercase();
+        if Self::detect_ssh_scheme(&lower) || lower.starts_with("git@") {
+            GitProtocol::Ssh
+        } else if lower.starts_with("http://") {
+            GitProtocol::Http
+        } else if lower.starts_with("git://") {
+            GitProtocol::Git
+        } else {
+            GitProtocol::Https
+        }
+    }
+
     /// URLを正規化
     fn normalize_url(url: &str) -> Result<String, GitUrlError> {
         let trimmed = url.trim();
 
+        // ssh://[user@]host[:port]/path をhttps形式に変換（ユーザーとポートは比較に使わない）
+        if let Some(parsed) = Self::parse_ssh_scheme(trimmed)? {
+            let host = parsed.host_str().ok_or(GitUrlError::MissingHost)?;
+            let path = parsed.path().trim_start_matches('/');
+            let path_without_git = path.strip_suffix(".git").unwrap_or(path);
+            return Ok(format!("https://{}/{}", host, path_without_git));
+        }
+
         // SSH形式（git@host:path）をhttps形式に変換
         if let Some(captures) = Regex::new(r"^git@([^:]+):(.+)$").unwrap().captures(trimmed) {
             let host = captures.get(1).unwrap().as_str();
@@ -299,6 +461,17 @@ impl GitUrl {
         Err(GitUrlError::InvalidFormat(url.to_string()))
     }
 
+    /// `ssh://`形式のURLを解析（それ以外の形式はNone）
+    fn parse_ssh_scheme(url: &str) -> Result<Option<Url>, GitUrlError> {
+        let trimmed = url.trim();
+        if !Self::detect_ssh_scheme(trimmed) {
+            return Ok(None);
+        }
+        Url::parse(trimmed)
+            .map(Some)
+            .map_err(|_| GitUrlError::InvalidFormat(url.to_string()))
+    }
+
     /// URLを解析してコンポーネントに分割
     fn parse_url(url: &str) -> Result<(String, String, String), GitUrlError> {
         let parsed = Url::parse(url).map_err(|_| GitUrlError::InvalidFormat(url.to_string()))?;
@@ -326,11 +499,35 @@ impl GitUrl {
         Ok((scheme, host, repo_path))
     }
 
-    /// 元のURL文字列を取得
+    /// 正規化されたURL文字列を取得
     pub fn as_str(&self) -> &str {
         &self.url
     }
 
+    /// 利用者が指定したプロトコルを取得
+    pub fn preferred_protocol(&self) -> GitProtocol {
+        self.protocol
+    }
+
+    /// clone・リモート設定に使うURL（利用者が指定したプロトコルのまま`.git`付きで返す）
+    ///
+    /// SSHで指定されたURLをhttpsに書き換えると鍵認証のみのサーバーに接続できないため、
+    /// 正規化したURL（`as_str`）は比較にだけ使う。`ssh://`形式はユーザー名とポートを
+    /// 含めて指定どおりに返す。
+    pub fn to_clone_url(&self) -> String {
+        match self.protocol {
+            GitProtocol::Ssh if Self::detect_ssh_scheme(&self.original) => self.original.clone(),
+            GitProtocol::Ssh => self.to_ssh_url(),
+            GitProtocol::Git => format!("{}.git", self.url.replacen("https://", "git://", 1)),
+            GitProtocol::Https | GitProtocol::Http => format!("{}.git", self.url),
+        }
+    }
+
+    /// `ssh://`形式のURLかどうか
+    fn detect_ssh_scheme(url: &str) -> bool {
+        url.trim().to_ascii_lowercase().starts_with("ssh://")
+    }
+
     /// スキームを取得
     pub fn scheme(&self) -> &str {
         &self.scheme
@@ -341,14 +538,27 @@ impl GitUrl {
         &self.host
     }
 
+    /// ポート番号を取得（`ssh://`形式で明示された場合のみ）
+    pub fn port(&self) -> Option<u16> {
+        self.port
+    }
+
     /// リポジトリパスを取得
     pub fn repo_path(&self) -> &str {
         &self.repo_path
     }
 
     /// SSH形式のURLを生成
+    ///
+    /// `git@host:path`形式ではポートを指定できないため、22以外のポートがある場合は
+    /// `ssh://git@host:port/path.git`形式で返す。
     pub fn to_ssh_url(&self) -> String {
-        format!("git@{}:{}.git", self.host, self.repo_path)
+        match self.port {
+            Some(port) if port != 22 => {
+                format!("ssh://git@{}:{}/{}.git", self.host, port, self.repo_path)
+            }
+            _ => format!("git@{}:{}.git", self.host, self.repo_path),
+        }
     }
 
     /// HTTPS形式のURLを生成
@@ -371,6 +581,34 @@ impl GitUrl {
         }
     }
 
+    /// ミラーのベースURLとして使えるか検証
+    ///
+    /// リポジトリパスは不要だが、スキームとホストはリポジトリURLと同じ規則で検証する。
+    pub fn validate_base_url(base: &str) -> Result<(), GitUrlError> {
+        let base = base.trim().trim_end_matches('/');
+        Self::validate_security(base)?;
+
+        let normalized = Self::normalize_url(base)?;
+        let parsed =
+            Url::parse(&normalized).map_err(|_| GitUrlError::InvalidFormat(base.to_string()))?;
+        if parsed.query().is_some() || parsed.fragment().is_some() {
+            return Err(GitUrlError::InvalidFormat(base.to_string()));
+        }
+
+        let host = parsed.host_str().ok_or(GitUrlError::MissingHost)?;
+        Self::validate_host_security(host, &HostPolicy::from_env())
+    }
+
+    /// 同じリポジトリパスをベースURLの下に置いたURLを作成（ミラーからの取得用）
+    pub fn rebased_onto(&self, base: &str) -> Result<GitUrl, GitUrlError> {
+        Self::validate_base_url(base)?;
+        GitUrl::new(&format!(
+            "{}/{}.git",
+            base.trim().trim_end_matches('/'),
+            self.repo_path
+        ))
+    }
+
     /// 同じリポジトリを指しているかチェック
     pub fn is_same_repo(&self, other: &GitUrl) -> bool {
         self.host == other.host && self.repo_path == other.repo_path
@@ -434,6 +672,77 @@ mod tests {
         assert_eq!(git_url.to_https_url(), "https://github.com/owner/repo.git");
     }
 
+    #[test]
+    fn test_clone_url_keeps_original_protocol() {
+        let ssh = GitUrl::new("git@github.com:owner/repo.git").unwrap();
+        assert_eq!(ssh.preferred_protocol(), GitProtocol::Ssh);
+        assert_eq!(ssh.to_clone_url(), "git@github.com:owner/repo.git");
+        assert_eq!(ssh.as_str(), "https://github.com/owner/repo");
+
+        let ssh_scheme = GitUrl::new("ssh://git@github.com:22/owner/repo.git").unwrap();
+        assert_eq!(ssh_scheme.preferred_protocol(), GitProtocol::Ssh);
+        assert_eq!(
+            ssh_scheme.to_clone_url(),
+            "ssh://git@github.com:22/owner/repo.git"
+        );
+        assert!(ssh_scheme.is_same_repo(&ssh));
+
+        let https = GitUrl::new(" https://github.com/owner/repo ").unwrap();
+        assert_eq!(https.preferred_protocol(), GitProtocol::Https);
+        assert_eq!(https.to_clone_url(), "https://github.com/owner/repo.git");
+        assert!(https.is_same_repo(&ssh));
+    }
+
+    #[test]
+    fn test_clone_url_per_scheme() {
+        let cases = [
+            ("git@github.com:owner/repo", "git@github.com:owner/repo.git"),
+            (
+                "ssh://deploy@git.example.com:2222/org/repo.git",
+                "ssh://deploy@git.example.com:2222/org/repo.git",
+            ),
+            ("https://github.com/owner/repo.git", "https://github.com/owner/repo.git"),
+            (
+                "https://git.example.com:8443/org/repo",
+                "https://git.example.com:8443/org/repo.git",
+            ),
+            ("http://git.example.com/org/repo", "http://git.example.com/org/repo.git"),
+            ("git://git.example.com/org/repo.git", "git://git.example.com/org/repo.git"),
+        ];
+
+        for (input, expected) in cases {
+            let git_url = GitUrl::new(input).unwrap();
+            assert_eq!(git_url.to_clone_url(), expected, "input: {}", input);
+        }
+    }
+
+    #[test]
+    fn test_ssh_scheme_with_port() {
+        let git_url = GitUrl::new("ssh://git@gitea.example.com:2222/org/repo.git").unwrap();
+        assert_eq!(git_url.scheme(), "ssh");
+        assert_eq!(git_url.host(), "gitea.example.com");
+        assert_eq!(git_url.port(), Some(2222));
+        assert_eq!(git_url.repo_path(), "org/repo");
+        assert_eq!(
+            git_url.to_ssh_url(),
+            "ssh://git@gitea.example.com:2222/org/repo.git"
+        );
+        assert_eq!(git_url.as_str(), "https://gitea.example.com/org/repo");
+
+        // 生成したSSH URLから同じURLを作り直せる
+        let round_trip = GitUrl::new(&git_url.to_ssh_url()).unwrap();
+        assert_eq!(round_trip.port(), Some(2222));
+        assert_eq!(round_trip.to_ssh_url(), git_url.to_ssh_url());
+        assert!(round_trip.is_same_repo(&git_url));
+
+        // 既定のポートとポート省略時はgit@host:path形式
+        let default_port = GitUrl::new("ssh://git@github.com:22/owner/repo.git").unwrap();
+        assert_eq!(default_port.to_ssh_url(), "git@github.com:owner/repo.git");
+        let no_port = GitUrl::new("ssh://git@github.com/owner/repo.git").unwrap();
+        assert_eq!(no_port.port(), None);
+        assert_eq!(no_port.to_ssh_url(), "git@github.com:owner/repo.git");
+    }
+
     #[test]
     fn test_repo_info() {
         let git_url = GitUrl::new("https://github.com/owner/repo").unwrap();
@@ -639,6 +948,70 @@ mod tests {
         assert!(matches!(result, Err(GitUrlError::BlockedDomain(_))));
     }
 
+    #[test]
+    fn test_policy_permits_private_hosts() {
+        let policy = HostPolicy::new().with_allow_private_hosts(true);
+
+        let url = GitUrl::with_policy("https://10.0.0.5/team/repo.git", &policy).unwrap();
+        assert_eq!(url.host(), "10.0.0.5");
+        assert!(GitUrl::with_policy("https://192.168.1.1/team/repo", &policy).is_ok());
+        assert!(GitUrl::with_policy("https://localhost/team/repo", &policy).is_ok());
+        assert!(GitUrl::with_policy("git@127.0.0.1:team/repo.git", &policy).is_ok());
+
+        // メタデータサービスとリンクローカルアドレスは許可しない
+        for url in [
+            "https://169.254.169.254/latest/meta-data",
+            "https://metadata.google.internal/computeMetadata/v1",
+            "https://169.254.170.2/team/repo",
+        ] {
+            assert!(
+                matches!(
+                    GitUrl::with_policy(url, &policy),
+                    Err(GitUrlError::BlockedDomain(_))
+                ),
+                "{} should stay blocked",
+                url
+            );
+        }
+    }
+
+    #[test]
+    fn test_policy_allowed_hosts() {
+        let policy = HostPolicy::new().with_allowed_hosts(vec![
+            "git.corp.internal".to_string(),
+            "192.168.10.20".to_string(),
+            "169.254.169.254".to_string(),
+        ]);
+
+        assert!(GitUrl::with_policy("https://192.168.10.20/team/repo", &policy).is_ok());
+        assert!(GitUrl::with_policy("https://git.corp.internal/team/repo", &policy).is_ok());
+        assert!(matches!(
+            GitUrl::with_policy("https://192.168.10.21/team/repo", &policy),
+            Err(GitUrlError::BlockedDomain(_))
+        ));
+        assert!(matches!(
+            GitUrl::with_policy("https://localhost/team/repo", &policy),
+            Err(GitUrlError::BlockedDomain(_))
+        ));
+        // 許可リストに含めてもメタデータサービスは拒否する
+        assert!(matches!(
+            GitUrl::with_policy("https://169.254.169.254/team/repo", &policy),
+            Err(GitUrlError::BlockedDomain(_))
+        ));
+    }
+
+    #[test]
+    fn test_policy_from_env_values() {
+        assert_eq!(HostPolicy::from_env_values(None, None), HostPolicy::new());
+        assert!(HostPolicy::from_env_values(Some("1"), None).allow_private_hosts);
+        assert!(HostPolicy::from_env_values(Some("TRUE"), None).allow_private_hosts);
+        assert!(!HostPolicy::from_env_values(Some("0"), None).allow_private_hosts);
+        assert_eq!(
+            HostPolicy::from_env_values(None, Some(" git.local, ,10.0.0.5 ")).allowed_hosts,
+            vec!["git.local".to_string(), "10.0.0.5".to_string()]
+        );
+    }
+
     #[test]
     fn test_url_length_validation() {
         // 異常に長いURL
@@ -712,6 +1085,48 @@ mod tests {
         assert!(result.is_err(), "Should reject IPv6 private");
     }
 
+    #[test]
+    fn test_rebased_onto_mirror() {
+        let url = GitUrl::new("git@github.com:example/repo.git").unwrap();
+
+        for base in [
+            "https://mirror.example.com/git",
+            "https://mirror.example.com/git/",
+            "git@mirror.example.com:git",
+        ] {
+            let mirrored = url.rebased_onto(base).unwrap();
+            assert_eq!(mirrored.host(), "mirror.example.com");
+            assert_eq!(mirrored.repo_path(), "git/example/repo");
+            assert_eq!(
+                mirrored.to_https_url(),
+                "https://mirror.example.com/git/example/repo.git"
+            );
+        }
+
+        let mirrored = url.rebased_onto("https://mirror.example.com").unwrap();
+        assert_eq!(mirrored.repo_path(), "example/repo");
+    }
+
+    #[test]
+    fn test_base_url_validation() {
+        assert!(GitUrl::validate_base_url("https://mirror.example.com").is_ok());
+        assert!(GitUrl::validate_base_url("git://mirror.example.com/git").is_ok());
+
+        assert!(matches!(
+            GitUrl::validate_base_url("mirror.example.com/git"),
+            Err(GitUrlError::InvalidFormat(_))
+        ));
+        assert!(matches!(
+            GitUrl::validate_base_url("https://mirror.example.com/git?token=1"),
+            Err(GitUrlError::InvalidFormat(_))
+        ));
+        assert!(matches!(
+            GitUrl::validate_base_url("https://localhost/git"),
+            Err(GitUrlError::BlockedDomain(_))
+        ));
+        assert!(GitUrl::validate_base_url("").is_err());
+    }
+
     #[test]
     fn test_error_messages() {
         let result = GitUrl::new("javascript:alert('xss')");
@@ -0,0 +1,104 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// Write `contents` to `path` so that readers never see a partially written file
+///
+/// The contents are written to a temporary file in the same directory, flushed
+/// to disk and renamed over `path`. Since a rename within a directory is atomic,
+/// `path` holds either its previous contents or the new ones, even if the
+/// process crashes midway. The temporary file is removed when a step fails.
+pub fn write_atomic(path: &Path, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    write_via_temp_file(path, contents.as_ref(), |_| {})
+}
+
+/// Temporary file `write_atomic` uses for `path`: `.<file name>.tmp.<pid>` next to it
+fn temp_path(path: &Path) -> PathBuf {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    path.with_file_name(format!(".{}.tmp.{}", file_name, std::process::id()))
+}
+
+/// Body of `write_atomic`, calling `before_rename` with the written temporary file
+fn write_via_temp_file(
+    path: &Path,
+    contents: &[u8],
+    before_rename: impl FnOnce(&Path),
+) -> io::Result<()> {
+    let tmp_path = temp_path(path);
+
+    let result = (|| {
+        let mut file = fs::File::create(&tmp_path)?;
+        file.write_all(contents)?;
+        file.sync_all()?;
+        drop(file);
+
+        before_rename(&tmp_path);
+        fs::rename(&tmp_path, path)
+    })();
+
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp_path);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_target_is_replaced_only_by_the_rename() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("wmgr.yml");
+        fs::write(&path, "old").unwrap();
+
+        let mut observed = None;
+        write_via_temp_file(&path, b"new", |tmp_path| {
+            observed = Some((
+                tmp_path.to_path_buf(),
+                fs::read_to_string(tmp_path).unwrap(),
+                fs::read_to_string(&path).unwrap(),
+            ));
+        })
+        .unwrap();
+
+        // Until the rename the target still holds the complete old contents
+        let (tmp_path, tmp_contents, target_contents) = observed.unwrap();
+        assert_eq!(tmp_path.parent(), path.parent());
+        assert_eq!(tmp_contents, "new");
+        assert_eq!(target_contents, "old");
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+        assert!(!tmp_path.exists());
+    }
+
+    #[test]
+    fn test_write_atomic_creates_missing_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("config.yml");
+
+        write_atomic(&path, "manifest_branch: main\n").unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "manifest_branch: main\n"
+        );
+        assert_eq!(fs::read_dir(temp_dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_failed_rename_removes_temp_file() {
+        let temp_dir = TempDir::new().unwrap();
+        // A non-empty directory cannot be replaced by a file
+        let path = temp_dir.path().join("occupied");
+        fs::create_dir(&path).unwrap();
+        fs::write(path.join("keep"), "").unwrap();
+
+        assert!(write_atomic(&path, "new").is_err());
+        assert!(path.join("keep").exists());
+        assert!(!temp_path(&path).exists());
+    }
+}
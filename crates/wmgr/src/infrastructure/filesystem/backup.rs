@@ -0,0 +1,92 @@
+use std::path::{Path, PathBuf};
+
+/// Default directory for file backups, relative to the workspace root
+pub const DEFAULT_BACKUP_DIR: &str = ".wmgr/backups";
+
+/// Directory in which the backups of `file_path` are stored
+///
+/// Without a backup directory, backups sit next to the file. A relative backup
+/// directory is resolved against the workspace root containing the file: the
+/// nearest ancestor with a `.wmgr` directory, or the file's own directory when
+/// there is none. The file's location below that root is mirrored inside the
+/// backup directory so equally named files in different directories never
+/// share backups.
+pub fn backup_dir_for(file_path: &Path, backup_dir: Option<&Path>) -> PathBuf {
+    let parent = match file_path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+
+    let Some(backup_dir) = backup_dir else {
+        return parent.to_path_buf();
+    };
+
+    let root = parent
+        .ancestors()
+        .find(|dir| dir.join(".wmgr").is_dir())
+        .unwrap_or(parent);
+    let relative = parent.strip_prefix(root).unwrap_or(Path::new(""));
+
+    root.join(backup_dir).join(relative)
+}
+
+/// Name prefix shared by all backups of `file_path`
+pub fn backup_name_prefix(file_path: &Path, suffix: &str) -> String {
+    let file_name = file_path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    format!("{}{}_", file_name, suffix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_backup_dir_next_to_file_when_unset() {
+        let file = Path::new("/ws/shared/config.yml");
+        assert_eq!(backup_dir_for(file, None), Path::new("/ws/shared"));
+    }
+
+    #[test]
+    fn test_backup_dir_mirrors_path_below_workspace_root() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        std::fs::create_dir_all(root.join(".wmgr")).unwrap();
+        std::fs::create_dir_all(root.join("shared")).unwrap();
+        let backup_dir = Path::new(DEFAULT_BACKUP_DIR);
+
+        assert_eq!(
+            backup_dir_for(&root.join("wmgr.yml"), Some(backup_dir)),
+            root.join(".wmgr/backups")
+        );
+        assert_eq!(
+            backup_dir_for(&root.join("shared/config.yml"), Some(backup_dir)),
+            root.join(".wmgr/backups/shared")
+        );
+        assert_eq!(
+            backup_dir_for(&root.join(".wmgr/config.yml"), Some(backup_dir)),
+            root.join(".wmgr/backups/.wmgr")
+        );
+    }
+
+    #[test]
+    fn test_absolute_backup_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let file = temp_dir.path().join("wmgr.yml");
+        assert_eq!(
+            backup_dir_for(&file, Some(Path::new("/var/backups/wmgr"))),
+            Path::new("/var/backups/wmgr")
+        );
+    }
+
+    #[test]
+    fn test_backup_name_prefix() {
+        assert_eq!(
+            backup_name_prefix(Path::new("/ws/manifest.yml"), ".bak"),
+            "manifest.yml.bak_"
+        );
+    }
+}
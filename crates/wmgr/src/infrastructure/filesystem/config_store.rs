@@ -1,4 +1,7 @@
+use super::atomic_write::write_atomic;
+use super::backup::{backup_dir_for, backup_name_prefix, DEFAULT_BACKUP_DIR};
 use crate::domain::entities::workspace::WorkspaceConfig;
+use crate::domain::value_objects::git_url::GitUrl;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -69,6 +72,10 @@ pub struct BackupConfig {
 
     /// Backup file suffix pattern
     pub backup_suffix: String,
+
+    /// Directory for backups (relative paths are resolved against the workspace root,
+    /// `None` keeps backups next to the original file)
+    pub backup_dir: Option<PathBuf>,
 }
 
 impl Default for BackupConfig {
@@ -77,6 +84,7 @@ impl Default for BackupConfig {
             create_backup: true,
             max_backups: 5,
             backup_suffix: ".bak".to_string(),
+            backup_dir: Some(PathBuf::from(DEFAULT_BACKUP_DIR)),
         }
     }
 }
@@ -126,6 +134,9 @@ pub struct ValidatedWorkspaceConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[validate(length(min = 1, max = 255))]
     pub singular_remote: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manifest_repo: Option<PathBuf>,
 }
 
 impl From<WorkspaceConfig> for ValidatedWorkspaceConfig {
@@ -137,6 +148,7 @@ impl From<WorkspaceConfig> for ValidatedWorkspaceConfig {
             repo_groups: config.repo_groups,
             clone_all_repos: config.clone_all_repos,
             singular_remote: config.singular_remote,
+            manifest_repo: config.manifest_repo,
         }
     }
 }
@@ -150,6 +162,7 @@ impl From<ValidatedWorkspaceConfig> for WorkspaceConfig {
             repo_groups: config.repo_groups,
             clone_all_repos: config.clone_all_repos,
             singular_remote: config.singular_remote,
+            manifest_repo: config.manifest_repo,
         }
     }
 }
@@ -180,6 +193,12 @@ impl ConfigStore {
         }
     }
 
+    /// Enable or disable backups of files before they are overwritten or deleted
+    pub fn with_backup(mut self, create_backup: bool) -> Self {
+        self.backup_config.create_backup = create_backup;
+        self
+    }
+
     /// Read workspace configuration from YAML file
     pub fn read_workspace_config<P: AsRef<Path>>(
         &self,
@@ -241,8 +260,8 @@ impl ConfigStore {
         let yaml_content = serde_yaml::to_string(&validated_config)
             .map_err(|e| ConfigStoreError::YamlSerializationFailed(e.to_string()))?;
 
-        // Write to file
-        fs::write(config_path, yaml_content)
+        // Replace the file atomically so a crash never leaves a truncated config
+        write_atomic(config_path, yaml_content)
             .map_err(|e| ConfigStoreError::WriteFailed(e.to_string()))?;
 
         Ok(())
@@ -292,8 +311,8 @@ impl ConfigStore {
         let yaml_content = serde_yaml::to_string(config)
             .map_err(|e| ConfigStoreError::YamlSerializationFailed(e.to_string()))?;
 
-        // Write to file
-        fs::write(config_path, yaml_content)
+        // Replace the file atomically so a crash never leaves a truncated config
+        write_atomic(config_path, yaml_content)
             .map_err(|e| ConfigStoreError::WriteFailed(e.to_string()))?;
 
         Ok(())
@@ -377,21 +396,20 @@ impl ConfigStore {
         config_path: P,
     ) -> Result<Vec<PathBuf>, ConfigStoreError> {
         let config_path = config_path.as_ref();
-        let parent = config_path.parent().unwrap_or(Path::new("."));
-        let base_name = config_path.file_name().ok_or_else(|| {
-            ConfigStoreError::InvalidConfigPath(config_path.display().to_string())
-        })?;
+        if config_path.file_name().is_none() {
+            return Err(ConfigStoreError::InvalidConfigPath(
+                config_path.display().to_string(),
+            ));
+        }
+        let prefix = backup_name_prefix(config_path, &self.backup_config.backup_suffix);
 
         let mut backups = Vec::new();
 
-        if let Ok(entries) = fs::read_dir(parent) {
+        if let Ok(entries) = fs::read_dir(self.backup_dir(config_path)) {
             for entry in entries.flatten() {
                 let path = entry.path();
                 if let Some(name) = path.file_name() {
-                    let name_str = name.to_string_lossy();
-                    if name_str.starts_with(&base_name.to_string_lossy().to_string())
-                        && name_str.contains(&self.backup_config.backup_suffix)
-                    {
+                    if name.to_string_lossy().starts_with(&prefix) {
                         backups.push(path);
                     }
                 }
@@ -425,6 +443,15 @@ impl ConfigStore {
             .validate()
             .map_err(|e| ConfigStoreError::ValidationFailed(format!("{:?}", e)))?;
 
+        if let Some(remote) = &config.singular_remote {
+            GitUrl::validate_base_url(remote).map_err(|e| {
+                ConfigStoreError::ValidationFailed(format!(
+                    "Invalid singular_remote '{}': {}",
+                    remote, e
+                ))
+            })?;
+        }
+
         // Additional custom validation
         if self.validation_config.strict_validation {
             self.strict_validate_workspace_config(config)?;
@@ -464,16 +491,23 @@ impl ConfigStore {
         Ok(())
     }
 
+    /// Directory holding the backups of a configuration file
+    fn backup_dir(&self, config_path: &Path) -> PathBuf {
+        backup_dir_for(config_path, self.backup_config.backup_dir.as_deref())
+    }
+
     /// Create backup of configuration file
     fn create_backup<P: AsRef<Path>>(&self, config_path: P) -> Result<(), ConfigStoreError> {
         let config_path = config_path.as_ref();
 
-        // Generate backup filename with timestamp
+        // Generate backup filename with timestamp inside the backup directory
+        let backup_dir = self.backup_dir(config_path);
+        fs::create_dir_all(&backup_dir)
+            .map_err(|e| ConfigStoreError::BackupFailed(e.to_string()))?;
         let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S");
-        let backup_path = config_path.with_file_name(format!(
-            "{}{}_{}",
-            config_path.file_name().unwrap().to_string_lossy(),
-            self.backup_config.backup_suffix,
+        let backup_path = backup_dir.join(format!(
+            "{}{}",
+            backup_name_prefix(config_path, &self.backup_config.backup_suffix),
             timestamp
         ));
 
@@ -489,16 +523,11 @@ impl ConfigStore {
 
     /// Clean up old backup files
     fn cleanup_old_backups<P: AsRef<Path>>(&self, config_path: P) -> Result<(), ConfigStoreError> {
-        let mut backups = self.list_backups(config_path)?;
+        let backups = self.list_backups(config_path)?;
 
-        // Keep only the maximum number of backups
-        if backups.len() > self.backup_config.max_backups {
-            backups.truncate(self.backup_config.max_backups);
-
-            // Remove older backups
-            for backup in backups.iter().skip(self.backup_config.max_backups) {
-                let _ = fs::remove_file(backup); // Ignore errors for cleanup
-            }
+        // Keep only the maximum number of backups (list is newest first)
+        for backup in backups.iter().skip(self.backup_config.max_backups) {
+            let _ = fs::remove_file(backup); // Ignore errors for cleanup
         }
 
         Ok(())
@@ -530,6 +559,25 @@ mod tests {
         assert!(store.validation_config.validate_on_read);
     }
 
+    #[test]
+    fn test_no_backup_when_disabled() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.yml");
+        let store = ConfigStore::new().with_backup(false);
+        let config = create_test_config();
+
+        store.write_workspace_config(&config_path, &config).unwrap();
+        store.write_workspace_config(&config_path, &config).unwrap();
+        store.delete_config(&config_path).unwrap();
+
+        let backups: Vec<_> = fs::read_dir(temp_dir.path())
+            .unwrap()
+            .flatten()
+            .filter(|entry| entry.file_name().to_string_lossy().contains(".bak_"))
+            .collect();
+        assert!(backups.is_empty());
+    }
+
     #[test]
     fn test_write_and_read_workspace_config() {
         let temp_dir = TempDir::new().unwrap();
@@ -596,6 +644,28 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_singular_remote_must_be_a_base_url() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.yml");
+        let store = ConfigStore::new();
+
+        let config = create_test_config().with_singular_remote("https://mirror.example.com/git");
+        store.write_workspace_config(&config_path, &config).unwrap();
+        let read_config = store.read_workspace_config(&config_path).unwrap();
+        assert_eq!(
+            read_config.singular_remote.as_deref(),
+            Some("https://mirror.example.com/git")
+        );
+
+        let config = create_test_config().with_singular_remote("mirror");
+        let result = store.write_workspace_config(&config_path, &config);
+        assert!(matches!(
+            result,
+            Err(ConfigStoreError::ValidationFailed(message)) if message.contains("singular_remote")
+        ));
+    }
+
     #[test]
     fn test_backup_functionality() {
         let temp_dir = TempDir::new().unwrap();
@@ -618,6 +688,59 @@ mod tests {
         assert!(!backups.is_empty());
     }
 
+    #[test]
+    fn test_backups_land_in_workspace_backup_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let wmgr_dir = temp_dir.path().join(".wmgr");
+        fs::create_dir_all(&wmgr_dir).unwrap();
+        let config_path = wmgr_dir.join("config.yml");
+        let store = ConfigStore::new();
+        let config = create_test_config();
+
+        store.write_workspace_config(&config_path, &config).unwrap();
+        store.write_workspace_config(&config_path, &config).unwrap();
+
+        let backups = store.list_backups(&config_path).unwrap();
+        assert_eq!(backups.len(), 1);
+        assert_eq!(
+            backups[0].parent().unwrap(),
+            temp_dir.path().join(".wmgr/backups/.wmgr")
+        );
+        assert!(!fs::read_dir(&wmgr_dir)
+            .unwrap()
+            .flatten()
+            .any(|entry| entry.file_name().to_string_lossy().contains(".bak_")));
+    }
+
+    #[test]
+    fn test_backup_rotation_in_configured_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.yml");
+        let backup_dir = temp_dir.path().join("backups");
+        let backup_config = BackupConfig {
+            max_backups: 1,
+            backup_dir: Some(backup_dir.clone()),
+            ..BackupConfig::default()
+        };
+        let store = ConfigStore::with_config(backup_config, ValidationConfig::default());
+        let config = create_test_config();
+
+        fs::create_dir_all(&backup_dir).unwrap();
+        let old_backup = backup_dir.join("config.yml.bak_20200101_000000");
+        fs::File::create(&old_backup)
+            .unwrap()
+            .set_modified(std::time::SystemTime::UNIX_EPOCH)
+            .unwrap();
+
+        store.write_workspace_config(&config_path, &config).unwrap();
+        store.write_workspace_config(&config_path, &config).unwrap();
+
+        let backups = store.list_backups(&config_path).unwrap();
+        assert_eq!(backups.len(), 1);
+        assert_ne!(backups[0], old_backup);
+        assert!(!old_backup.exists());
+    }
+
     #[test]
     fn test_delete_config() {
         let temp_dir = TempDir::new().unwrap();
@@ -0,0 +1,176 @@
+use crate::domain::entities::workspace::normalize_dest;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Name of the record file inside the `.wmgr` directory
+pub const MANAGED_REPOS_FILE_NAME: &str = "managed-repos.json";
+
+/// Managed repository record related errors
+#[derive(Debug, Error)]
+pub enum ManagedReposError {
+    #[error("Failed to read managed repository record {path}: {error}")]
+    ReadFailed { path: String, error: String },
+
+    #[error("Failed to write managed repository record {path}: {error}")]
+    WriteFailed { path: String, error: String },
+
+    #[error("Invalid managed repository record {path}: {error}")]
+    ParseFailed { path: String, error: String },
+}
+
+/// Record of the repository directories that wmgr has synced into the workspace
+///
+/// The record lives at `.wmgr/managed-repos.json` and is updated after every
+/// sync. It is what `sync --prune` compares the manifest against, so that
+/// directories wmgr never managed (such as the user's own clones) are never
+/// pruned.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManagedRepos {
+    /// Destinations of the managed repositories, as returned by [`dest_key`]
+    pub dests: BTreeSet<String>,
+
+    /// When the record was last written
+    pub updated_at: DateTime<Utc>,
+
+    #[serde(skip)]
+    path: PathBuf,
+}
+
+impl ManagedRepos {
+    /// Create an empty record for the workspace whose `.wmgr` directory is `wmgr_dir`
+    pub fn new(wmgr_dir: &Path) -> Self {
+        Self {
+            dests: BTreeSet::new(),
+            updated_at: Utc::now(),
+            path: wmgr_dir.join(MANAGED_REPOS_FILE_NAME),
+        }
+    }
+
+    /// Load the record, returning an empty one when no record exists
+    pub fn load(wmgr_dir: &Path) -> Result<Self, ManagedReposError> {
+        let path = wmgr_dir.join(MANAGED_REPOS_FILE_NAME);
+        if !path.exists() {
+            return Ok(Self::new(wmgr_dir));
+        }
+
+        let content = fs::read_to_string(&path).map_err(|e| ManagedReposError::ReadFailed {
+            path: path.display().to_string(),
+            error: e.to_string(),
+        })?;
+        let mut record: Self =
+            serde_json::from_str(&content).map_err(|e| ManagedReposError::ParseFailed {
+                path: path.display().to_string(),
+                error: e.to_string(),
+            })?;
+        record.path = path;
+        Ok(record)
+    }
+
+    /// Path of the record file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Record a repository destination (invalid destinations are ignored)
+    pub fn insert(&mut self, dest: &str) {
+        if let Some(key) = dest_key(dest) {
+            self.dests.insert(key);
+        }
+    }
+
+    /// Forget a repository destination, e.g. after its directory was pruned
+    pub fn remove(&mut self, dest: &str) {
+        if let Some(key) = dest_key(dest) {
+            self.dests.remove(&key);
+        }
+    }
+
+    /// Whether the destination is recorded
+    pub fn contains(&self, dest: &str) -> bool {
+        dest_key(dest).is_some_and(|key| self.dests.contains(&key))
+    }
+
+    /// Write the record to disk
+    pub fn save(&mut self) -> Result<(), ManagedReposError> {
+        let path = self.path.clone();
+        let write_error = |e: &dyn std::fmt::Display| ManagedReposError::WriteFailed {
+            path: path.display().to_string(),
+            error: e.to_string(),
+        };
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| write_error(&e))?;
+        }
+
+        self.updated_at = Utc::now();
+        let content = serde_json::to_string_pretty(self).map_err(|e| write_error(&e))?;
+
+        // Write to a temporary file first so an interrupted write never leaves a truncated record
+        let tmp_path = path.with_extension("json.tmp");
+        fs::write(&tmp_path, content).map_err(|e| write_error(&e))?;
+        fs::rename(&tmp_path, &path).map_err(|e| write_error(&e))?;
+        Ok(())
+    }
+}
+
+/// The key a manifest `dest` is recorded under, e.g. `libs/core` for `./libs/core/`
+///
+/// Returns `None` for destinations that are empty or leave the workspace.
+pub fn dest_key(dest: &str) -> Option<String> {
+    normalize_dest(dest)
+        .ok()
+        .map(|path| path.to_string_lossy().replace('\\', "/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_load_missing_record_is_empty() {
+        let temp_dir = TempDir::new().unwrap();
+        let record = ManagedRepos::load(&temp_dir.path().join(".wmgr")).unwrap();
+        assert!(record.dests.is_empty());
+    }
+
+    #[test]
+    fn test_record_round_trip_with_normalized_dests() {
+        let temp_dir = TempDir::new().unwrap();
+        let wmgr_dir = temp_dir.path().join(".wmgr");
+
+        let mut record = ManagedRepos::new(&wmgr_dir);
+        record.insert("./libs/core/");
+        record.insert("app");
+        record.insert("old");
+        record.insert("../outside");
+        record.remove("old");
+        record.save().unwrap();
+
+        let loaded = ManagedRepos::load(&wmgr_dir).unwrap();
+        assert_eq!(
+            loaded.dests.iter().collect::<Vec<_>>(),
+            ["app", "libs/core"]
+        );
+        assert!(loaded.contains("libs/core"));
+        assert!(!loaded.contains("old"));
+        assert_eq!(loaded.path(), wmgr_dir.join(MANAGED_REPOS_FILE_NAME));
+    }
+
+    #[test]
+    fn test_invalid_record_is_reported() {
+        let temp_dir = TempDir::new().unwrap();
+        let wmgr_dir = temp_dir.path().join(".wmgr");
+        fs::create_dir_all(&wmgr_dir).unwrap();
+        fs::write(wmgr_dir.join(MANAGED_REPOS_FILE_NAME), "not json").unwrap();
+
+        assert!(matches!(
+            ManagedRepos::load(&wmgr_dir),
+            Err(ManagedReposError::ParseFailed { .. })
+        ));
+    }
+}
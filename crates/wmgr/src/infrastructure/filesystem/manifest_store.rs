@@ -1,13 +1,20 @@
+use futures::stream::{self, StreamExt};
+use std::collections::HashMap;
+use std::future::Future;
 #[cfg(unix)]
 use std::os::unix::fs as unix_fs;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
 use tokio::fs as async_fs;
 
+use super::atomic_write::write_atomic;
+use super::backup::{backup_dir_for, backup_name_prefix, DEFAULT_BACKUP_DIR};
 use crate::application::services::manifest_service::{
-    ManifestService, ManifestServiceError, ProcessedManifest,
+    ManifestCacheStats, ManifestFormat, ManifestService, ManifestServiceError, ProcessedManifest,
 };
+use crate::common::error::WmgrError;
 use crate::domain::entities::manifest::{FileCopy, FileSymlink, Manifest, ManifestRepo};
+use crate::domain::entities::workspace::resolve_dest_in;
 
 /// Manifest store related errors
 #[derive(Debug, Error)]
@@ -69,6 +76,19 @@ pub enum ManifestStoreError {
     YamlError(#[from] serde_yaml::Error),
 }
 
+impl From<ManifestStoreError> for WmgrError {
+    fn from(error: ManifestStoreError) -> Self {
+        match &error {
+            ManifestStoreError::ManifestFileNotFound(path)
+            | ManifestStoreError::InvalidManifestPath(path) => {
+                let path = PathBuf::from(path);
+                WmgrError::manifest_error_with_source(error.to_string(), Some(path), error)
+            }
+            _ => WmgrError::manifest_error_with_source(error.to_string(), None, error),
+        }
+    }
+}
+
 /// Manifest file metadata
 #[derive(Debug, Clone)]
 pub struct ManifestMetadata {
@@ -108,6 +128,13 @@ pub struct FileOperationConfig {
 
     /// Maximum number of backup files to keep
     pub max_backups: usize,
+
+    /// Directory for backups (relative paths are resolved against the workspace root,
+    /// `None` keeps backups next to the original file)
+    pub backup_dir: Option<PathBuf>,
+
+    /// Maximum number of file operations to run concurrently
+    pub max_concurrent_operations: usize,
 }
 
 impl Default for FileOperationConfig {
@@ -118,6 +145,8 @@ impl Default for FileOperationConfig {
             create_parent_dirs: true,
             validate_paths: true,
             max_backups: 5,
+            backup_dir: Some(PathBuf::from(DEFAULT_BACKUP_DIR)),
+            max_concurrent_operations: 16,
         }
     }
 }
@@ -212,6 +241,23 @@ impl ManifestStore {
         }
     }
 
+    /// Enable or disable backups of files before they are overwritten or deleted
+    pub fn with_backup(mut self, create_backup: bool) -> Self {
+        self.options.file_operation_config.create_backup = create_backup;
+        self
+    }
+
+    /// Back up a file the way writes do, unless backups are disabled
+    pub async fn backup_file<P: AsRef<Path>>(
+        &self,
+        file_path: P,
+    ) -> Result<(), ManifestStoreError> {
+        if self.options.file_operation_config.create_backup {
+            self.create_backup(file_path).await?;
+        }
+        Ok(())
+    }
+
     /// Read and parse manifest from YAML file
     pub async fn read_manifest<P: AsRef<Path>>(
         &mut self,
@@ -241,6 +287,11 @@ impl ManifestStore {
         Ok(processed_manifest)
     }
 
+    /// Statistics of the parsed manifest cache, for debugging
+    pub fn cache_stats(&self) -> ManifestCacheStats {
+        self.manifest_service.cache_stats()
+    }
+
     /// Read manifest from URL
     pub async fn read_manifest_from_url(
         &mut self,
@@ -260,7 +311,10 @@ impl ManifestStore {
         Ok(processed_manifest)
     }
 
-    /// Write manifest to YAML file
+    /// Write manifest to a YAML file, or TOML when the path ends in `.toml`
+    ///
+    /// Serialization drops comments, so the comment block at the top of the
+    /// file being replaced is copied in front of the new content.
     pub async fn write_manifest<P: AsRef<Path>>(
         &self,
         manifest_path: P,
@@ -280,6 +334,16 @@ impl ManifestStore {
             self.create_backup(manifest_path).await?;
         }
 
+        // Keep the header comments of the file being replaced (JSON has no comments)
+        let format = ManifestFormat::from_path(manifest_path);
+        let header = match format {
+            ManifestFormat::Json => String::new(),
+            _ => async_fs::read_to_string(manifest_path)
+                .await
+                .map(|existing| header_comments(&existing))
+                .unwrap_or_default(),
+        };
+
         // Ensure parent directory exists
         if let Some(parent) = manifest_path.parent() {
             if self.options.file_operation_config.create_parent_dirs && !parent.exists() {
@@ -289,15 +353,15 @@ impl ManifestStore {
             }
         }
 
-        // Serialize manifest to YAML
-        let yaml_content = self
+        // Serialize manifest in the format given by the file extension
+        let content = self
             .manifest_service
-            .serialize_to_yaml(manifest)
+            .serialize(manifest, format)
             .map_err(ManifestStoreError::ManifestServiceError)?;
+        let content = header + &content;
 
-        // Write to file
-        async_fs::write(manifest_path, yaml_content)
-            .await
+        // Replace the file atomically so a crash never leaves a truncated manifest
+        write_atomic(manifest_path, content)
             .map_err(|e| ManifestStoreError::WriteFailed(e.to_string()))?;
 
         Ok(())
@@ -314,20 +378,22 @@ impl ManifestStore {
         }
 
         let workspace_root = workspace_root.as_ref();
-        let mut results = Vec::new();
-
-        for repo in &manifest.repos {
-            if let Some(copy_operations) = &repo.copy {
-                for copy_op in copy_operations {
-                    let result = self
-                        .execute_copy_operation(copy_op, repo, workspace_root)
-                        .await;
-                    results.push(result);
-                }
-            }
-        }
-
-        Ok(results)
+        let operations = manifest
+            .repos
+            .iter()
+            .flat_map(|repo| {
+                repo.copy
+                    .iter()
+                    .flatten()
+                    .map(move |copy_op| (workspace_root.join(&copy_op.dest), (copy_op, repo)))
+            })
+            .collect();
+
+        Ok(self
+            .run_file_operations(operations, |(copy_op, repo)| {
+                self.execute_copy_operation(copy_op, repo, workspace_root)
+            })
+            .await)
     }
 
     /// Process symlink operations from manifest
@@ -341,20 +407,21 @@ impl ManifestStore {
         }
 
         let workspace_root = workspace_root.as_ref();
-        let mut results = Vec::new();
-
-        for repo in &manifest.repos {
-            if let Some(symlink_operations) = &repo.symlink {
-                for symlink_op in symlink_operations {
-                    let result = self
-                        .execute_symlink_operation(symlink_op, repo, workspace_root)
-                        .await;
-                    results.push(result);
-                }
-            }
-        }
-
-        Ok(results)
+        let operations = manifest
+            .repos
+            .iter()
+            .flat_map(|repo| {
+                repo.symlink.iter().flatten().map(move |symlink_op| {
+                    (workspace_root.join(&symlink_op.source), (symlink_op, repo))
+                })
+            })
+            .collect();
+
+        Ok(self
+            .run_file_operations(operations, |(symlink_op, repo)| {
+                self.execute_symlink_operation(symlink_op, repo, workspace_root)
+            })
+            .await)
     }
 
     /// Process all file operations from manifest
@@ -469,6 +536,57 @@ impl ManifestStore {
 
     // Private helper methods
 
+    /// Run independent file operations concurrently
+    ///
+    /// Operations are keyed by the path they write to. Operations sharing a key run
+    /// one after another in manifest order, so backups of the same file never race.
+    /// Results are sorted by destination path to keep the output deterministic.
+    async fn run_file_operations<T, F, Fut>(
+        &self,
+        operations: Vec<(PathBuf, T)>,
+        execute: F,
+    ) -> Vec<FileOperationResult>
+    where
+        F: Fn(T) -> Fut,
+        Fut: Future<Output = FileOperationResult>,
+    {
+        let mut batches: Vec<Vec<T>> = Vec::new();
+        let mut batch_index: HashMap<PathBuf, usize> = HashMap::new();
+        for (written_path, operation) in operations {
+            match batch_index.get(&written_path) {
+                Some(&index) => batches[index].push(operation),
+                None => {
+                    batch_index.insert(written_path, batches.len());
+                    batches.push(vec![operation]);
+                }
+            }
+        }
+
+        let execute = &execute;
+        let concurrency = self
+            .options
+            .file_operation_config
+            .max_concurrent_operations
+            .max(1);
+        let mut results: Vec<FileOperationResult> = stream::iter(batches)
+            .map(|batch| async move {
+                let mut results = Vec::with_capacity(batch.len());
+                for operation in batch {
+                    results.push(execute(operation).await);
+                }
+                results
+            })
+            .buffer_unordered(concurrency)
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .flatten()
+            .collect();
+
+        results.sort_by(|a, b| a.destination.cmp(&b.destination));
+        results
+    }
+
     /// Execute a copy operation
     async fn execute_copy_operation(
         &self,
@@ -476,7 +594,11 @@ impl ManifestStore {
         repo: &ManifestRepo,
         workspace_root: &Path,
     ) -> FileOperationResult {
-        let source_path = workspace_root.join(&repo.dest).join(&copy_op.file);
+        let repo_path = resolve_dest_in(workspace_root, &repo.dest);
+        let source_path = match &repo_path {
+            Ok(repo_path) => repo_path.join(&copy_op.file),
+            Err(_) => Path::new(&repo.dest).join(&copy_op.file),
+        };
         let dest_path = workspace_root.join(&copy_op.dest);
 
         let mut result = FileOperationResult {
@@ -488,6 +610,11 @@ impl ManifestStore {
             backup_created: false,
         };
 
+        if let Err(e) = repo_path {
+            result.error = Some(e.to_string());
+            return result;
+        }
+
         // Validate paths if enabled
         if self.options.file_operation_config.validate_paths {
             if let Err(e) = self.validate_copy_paths(&source_path, &dest_path) {
@@ -496,8 +623,8 @@ impl ManifestStore {
             }
         }
 
-        // Create backup if enabled and destination exists
-        if self.options.file_operation_config.create_backup && dest_path.exists() {
+        // Create backup if enabled and destination exists (directories are not backed up)
+        if self.options.file_operation_config.create_backup && dest_path.is_file() {
             if let Err(e) = self.create_backup(&dest_path).await {
                 result.error = Some(format!("Backup failed: {}", e));
                 return result;
@@ -521,8 +648,17 @@ impl ManifestStore {
             return result;
         }
 
-        // Perform the copy operation
-        match async_fs::copy(&source_path, &dest_path).await {
+        // Perform the copy operation, recursing into directories
+        let copy_result = if source_path.is_dir() {
+            let (source, dest) = (source_path.clone(), dest_path.clone());
+            tokio::task::spawn_blocking(move || copy_dir_recursive(&source, &dest))
+                .await
+                .unwrap_or_else(|e| Err(std::io::Error::other(e)))
+        } else {
+            async_fs::copy(&source_path, &dest_path).await.map(|_| ())
+        };
+
+        match copy_result {
             Ok(_) => {
                 result.success = true;
             }
@@ -546,7 +682,7 @@ impl ManifestStore {
 
         let mut result = FileOperationResult {
             source: source_path.clone(),
-            destination: target_path.to_path_buf(),
+            destination: resolve_symlink_target(&source_path, target_path),
             operation_type: "symlink".to_string(),
             success: false,
             error: None,
@@ -586,9 +722,14 @@ impl ManifestStore {
             return result;
         }
 
-        // Remove existing symlink if it exists
+        // Remove existing symlink if it exists (directory links on Windows need remove_dir)
         if source_path.is_symlink() {
-            if let Err(e) = async_fs::remove_file(&source_path).await {
+            let removed = if cfg!(windows) && source_path.is_dir() {
+                async_fs::remove_dir(&source_path).await
+            } else {
+                async_fs::remove_file(&source_path).await
+            };
+            if let Err(e) = removed {
                 result.error = Some(format!("Failed to remove existing symlink: {}", e));
                 return result;
             }
@@ -599,10 +740,7 @@ impl ManifestStore {
         let symlink_result = unix_fs::symlink(target_path, &source_path);
 
         #[cfg(windows)]
-        let symlink_result = {
-            use std::os::windows::fs::symlink_file;
-            symlink_file(target_path, &source_path)
-        };
+        let symlink_result = create_windows_symlink(target_path, &result.destination, &source_path);
 
         match symlink_result {
             Ok(_) => {
@@ -628,7 +766,9 @@ impl ManifestStore {
             // Validate copy operations
             if let Some(copy_operations) = &repo.copy {
                 for copy_op in copy_operations {
-                    let source_path = base_dir.join(&repo.dest).join(&copy_op.file);
+                    let source_path = resolve_dest_in(base_dir, &repo.dest)
+                        .map_err(|e| ManifestStoreError::PathValidationFailed(e.to_string()))?
+                        .join(&copy_op.file);
                     let dest_path = base_dir.join(&copy_op.dest);
                     self.validate_copy_paths(&source_path, &dest_path)?;
                 }
@@ -703,6 +843,14 @@ impl ManifestStore {
         Ok(())
     }
 
+    /// Directory holding the backups of a file
+    fn backup_dir(&self, file_path: &Path) -> PathBuf {
+        backup_dir_for(
+            file_path,
+            self.options.file_operation_config.backup_dir.as_deref(),
+        )
+    }
+
     /// Create backup of a file
     async fn create_backup<P: AsRef<Path>>(&self, file_path: P) -> Result<(), ManifestStoreError> {
         let file_path = file_path.as_ref();
@@ -711,11 +859,15 @@ impl ManifestStore {
             return Ok(()); // Nothing to backup
         }
 
-        // Generate backup filename with timestamp
+        // Generate backup filename with timestamp inside the backup directory
+        let backup_dir = self.backup_dir(file_path);
+        async_fs::create_dir_all(&backup_dir)
+            .await
+            .map_err(|e| ManifestStoreError::BackupFailed(e.to_string()))?;
         let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S");
-        let backup_path = file_path.with_file_name(format!(
-            "{}.bak_{}",
-            file_path.file_name().unwrap().to_string_lossy(),
+        let backup_path = backup_dir.join(format!(
+            "{}{}",
+            backup_name_prefix(file_path, ".bak"),
             timestamp
         ));
 
@@ -736,19 +888,21 @@ impl ManifestStore {
         file_path: P,
     ) -> Result<(), ManifestStoreError> {
         let file_path = file_path.as_ref();
-        let parent = file_path.parent().unwrap_or(Path::new("."));
-        let base_name = file_path.file_name().ok_or_else(|| {
-            ManifestStoreError::InvalidManifestPath(file_path.display().to_string())
-        })?;
+        if file_path.file_name().is_none() {
+            return Err(ManifestStoreError::InvalidManifestPath(
+                file_path.display().to_string(),
+            ));
+        }
+        let backup_dir = self.backup_dir(file_path);
+        let prefix = backup_name_prefix(file_path, ".bak");
 
         let mut backups = Vec::new();
 
-        if let Ok(mut entries) = async_fs::read_dir(parent).await {
+        if let Ok(mut entries) = async_fs::read_dir(&backup_dir).await {
             while let Ok(Some(entry)) = entries.next_entry().await {
                 let path = entry.path();
                 if let Some(name) = path.file_name() {
-                    let name_str = name.to_string_lossy();
-                    if name_str.starts_with(&format!("{}.bak_", base_name.to_string_lossy())) {
+                    if name.to_string_lossy().starts_with(&prefix) {
                         backups.push(path);
                     }
                 }
@@ -788,6 +942,134 @@ impl Default for ManifestStore {
     }
 }
 
+/// Resolve a symlink target the way the OS does when following the link
+///
+/// Relative targets are relative to the directory containing the link, not to the
+/// workspace root or the process working directory.
+fn resolve_symlink_target(link_path: &Path, target: &Path) -> PathBuf {
+    if target.is_absolute() {
+        return target.to_path_buf();
+    }
+
+    let mut resolved = link_path.parent().unwrap_or(Path::new("")).to_path_buf();
+    for component in target.components() {
+        match component {
+            std::path::Component::ParentDir => {
+                resolved.pop();
+            }
+            std::path::Component::CurDir => {}
+            other => resolved.push(other),
+        }
+    }
+    resolved
+}
+
+/// Windows error code returned when the process may not create symbolic links
+#[cfg(windows)]
+const ERROR_PRIVILEGE_NOT_HELD: i32 = 1314;
+
+/// Create a symlink on Windows, picking the file or directory variant from the target
+///
+/// Directory links fall back to a junction when symlinks need a privilege the process
+/// lacks. File links have no such fallback, so the error explains how to enable them.
+#[cfg(windows)]
+fn create_windows_symlink(
+    target: &Path,
+    resolved_target: &Path,
+    link: &Path,
+) -> std::io::Result<()> {
+    use std::os::windows::fs::{symlink_dir, symlink_file};
+
+    if resolved_target.is_dir() {
+        match symlink_dir(target, link) {
+            Err(e) if e.raw_os_error() == Some(ERROR_PRIVILEGE_NOT_HELD) => {
+                create_junction(resolved_target, link)
+            }
+            other => other,
+        }
+    } else {
+        symlink_file(target, link).map_err(|e| {
+            if e.raw_os_error() == Some(ERROR_PRIVILEGE_NOT_HELD) {
+                std::io::Error::new(
+                    e.kind(),
+                    format!(
+                        "{} (enable Developer Mode or run from an elevated prompt to create file symlinks)",
+                        e
+                    ),
+                )
+            } else {
+                e
+            }
+        })
+    }
+}
+
+/// Create a directory junction, which needs no special privilege on Windows
+#[cfg(windows)]
+fn create_junction(target: &Path, link: &Path) -> std::io::Result<()> {
+    let output = std::process::Command::new("cmd")
+        .args(["/C", "mklink", "/J"])
+        .arg(link)
+        .arg(target)
+        .output()?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(std::io::Error::other(format!(
+            "Failed to create junction {} -> {}: {}",
+            link.display(),
+            target.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )))
+    }
+}
+
+/// The comment lines at the top of a YAML or TOML manifest, up to the first entry
+///
+/// Blank lines between and after the comments are kept, and the header always
+/// ends with a newline. Returns an empty string when the file does not start
+/// with a comment.
+fn header_comments(content: &str) -> String {
+    let mut end = 0;
+    let mut offset = 0;
+    for line in content.split_inclusive('\n') {
+        let trimmed = line.trim();
+        if trimmed.starts_with('#') || (trimmed.is_empty() && end > 0) {
+            end = offset + line.len();
+        } else if !trimmed.is_empty() {
+            break;
+        }
+        offset += line.len();
+    }
+
+    let mut header = content[..end].to_string();
+    if !header.is_empty() && !header.ends_with('\n') {
+        header.push('\n');
+    }
+    header
+}
+
+/// Copy a directory tree, creating directories and copying files below `source`
+fn copy_dir_recursive(source: &Path, dest: &Path) -> std::io::Result<()> {
+    for entry in walkdir::WalkDir::new(source) {
+        let entry = entry.map_err(std::io::Error::from)?;
+        let relative = entry
+            .path()
+            .strip_prefix(source)
+            .map_err(std::io::Error::other)?;
+        let target = dest.join(relative);
+
+        if entry.file_type().is_dir() {
+            std::fs::create_dir_all(&target)?;
+        } else {
+            std::fs::copy(entry.path(), &target)?;
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -855,6 +1137,150 @@ mod tests {
         assert_eq!(processed_manifest.manifest.repos[1].dest, "repo2");
     }
 
+    #[tokio::test]
+    async fn test_unknown_fields_survive_read_modify_write() {
+        let temp_dir = TempDir::new().unwrap();
+        let manifest_path = temp_dir.path().join("manifest.yml");
+        std::fs::write(
+            &manifest_path,
+            r#"
+repos:
+  - dest: app
+    url: https://github.com/example/app.git
+    review:
+      required_approvals: 2
+x-owner: platform-team
+"#,
+        )
+        .unwrap();
+
+        // Read, add a repository and write back, as `add` does
+        let mut store = ManifestStore::new();
+        let mut manifest = store.read_manifest(&manifest_path).await.unwrap().manifest;
+        manifest.repos.push(ManifestRepo::new(
+            "https://github.com/example/lib.git",
+            "lib",
+        ));
+        store
+            .write_manifest(&manifest_path, &manifest)
+            .await
+            .unwrap();
+
+        let written: serde_yaml::Value =
+            serde_yaml::from_str(&std::fs::read_to_string(&manifest_path).unwrap()).unwrap();
+        assert_eq!(written["x-owner"].as_str(), Some("platform-team"));
+        assert_eq!(
+            written["repos"][0]["review"]["required_approvals"].as_u64(),
+            Some(2)
+        );
+        assert_eq!(written["repos"][1]["dest"].as_str(), Some("lib"));
+        assert!(written["repos"][1].get("review").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_rewrite_keeps_header_comments() {
+        let temp_dir = TempDir::new().unwrap();
+        let manifest_path = temp_dir.path().join("manifest.yml");
+        std::fs::write(
+            &manifest_path,
+            "# Platform team workspace\n# Ask #platform before adding repositories\n\nrepos:\n  - dest: app\n    url: https://github.com/example/app.git\n",
+        )
+        .unwrap();
+
+        // Read, add a repository and write back, as `add` does
+        let mut store = ManifestStore::new();
+        let mut manifest = store.read_manifest(&manifest_path).await.unwrap().manifest;
+        manifest.repos.push(ManifestRepo::new(
+            "https://github.com/example/lib.git",
+            "lib",
+        ));
+        store
+            .write_manifest(&manifest_path, &manifest)
+            .await
+            .unwrap();
+
+        let written = std::fs::read_to_string(&manifest_path).unwrap();
+        assert!(
+            written.starts_with(
+                "# Platform team workspace\n# Ask #platform before adding repositories\n\nrepos:\n"
+            ),
+            "{}",
+            written
+        );
+        let reread = store.read_manifest(&manifest_path).await.unwrap().manifest;
+        assert_eq!(reread.repos.len(), 2);
+
+        // Writing again does not duplicate the header
+        store.write_manifest(&manifest_path, &reread).await.unwrap();
+        let rewritten = std::fs::read_to_string(&manifest_path).unwrap();
+        assert_eq!(rewritten.matches("# Platform team workspace").count(), 1);
+    }
+
+    #[test]
+    fn test_header_comments() {
+        assert_eq!(
+            header_comments("# a\n\n# b\n\nrepos: []\n"),
+            "# a\n\n# b\n\n"
+        );
+        assert_eq!(header_comments("\n# a\nrepos: []\n"), "\n# a\n");
+        assert_eq!(header_comments("# only"), "# only\n");
+        assert_eq!(header_comments("repos: [] # trailing\n# later\n"), "");
+        assert_eq!(header_comments(""), "");
+    }
+
+    #[tokio::test]
+    async fn test_overwriting_manifest_leaves_no_temp_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let manifest_path = temp_dir.path().join("manifest.yml");
+        let store = ManifestStore::new();
+        let manifest = create_test_manifest().await;
+
+        for _ in 0..2 {
+            store
+                .write_manifest(&manifest_path, &manifest)
+                .await
+                .unwrap();
+        }
+
+        let leftovers: Vec<_> = std::fs::read_dir(temp_dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .filter(|name| name.contains(".tmp"))
+            .collect();
+        assert!(leftovers.is_empty(), "{:?}", leftovers);
+        assert!(async_fs::read_to_string(&manifest_path)
+            .await
+            .unwrap()
+            .contains("repo1"));
+    }
+
+    #[tokio::test]
+    async fn test_write_and_read_toml_manifest() {
+        let temp_dir = TempDir::new().unwrap();
+        let manifest_path = temp_dir.path().join("wmgr.toml");
+        let mut store = ManifestStore::new();
+        let original_manifest = create_test_manifest().await;
+
+        store
+            .write_manifest(&manifest_path, &original_manifest)
+            .await
+            .unwrap();
+        let written = async_fs::read_to_string(&manifest_path).await.unwrap();
+        assert!(written.contains("[[repos]]"));
+
+        let processed_manifest = store.read_manifest(&manifest_path).await.unwrap();
+        assert_eq!(processed_manifest.manifest.repos.len(), 2);
+        assert_eq!(
+            processed_manifest.manifest.default_branch.as_deref(),
+            Some("main")
+        );
+        assert!(processed_manifest
+            .manifest
+            .groups
+            .unwrap()
+            .contains_key("core"));
+    }
+
     #[tokio::test]
     async fn test_read_nonexistent_manifest() {
         let temp_dir = TempDir::new().unwrap();
@@ -938,6 +1364,310 @@ mod tests {
         assert!(symlink_path.is_symlink());
     }
 
+    #[tokio::test]
+    async fn test_many_copy_operations_run_concurrently_in_sorted_order() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace_root = temp_dir.path();
+        let store = ManifestStore::new();
+
+        let mut repo = ManifestRepo::new("https://github.com/example/repo1.git", "repo1");
+        let repo_dir = workspace_root.join("repo1");
+        async_fs::create_dir_all(&repo_dir).await.unwrap();
+
+        // Dozens of copies into sibling paths that share not-yet-existing parents
+        let mut copy_ops = Vec::new();
+        for i in (0..40).rev() {
+            let file = format!("file{:02}.txt", i);
+            async_fs::write(repo_dir.join(&file), format!("content {}", i))
+                .await
+                .unwrap();
+            copy_ops.push(FileCopy {
+                file,
+                dest: format!("shared/dir{}/file{:02}.txt", i % 4, i),
+            });
+        }
+        repo.copy = Some(copy_ops);
+        let manifest = Manifest::new(vec![repo]);
+
+        let results = store
+            .process_copy_operations(&manifest, workspace_root)
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 40);
+        assert!(results.iter().all(|r| r.success), "{:?}", results);
+        assert!(results
+            .windows(2)
+            .all(|pair| pair[0].destination <= pair[1].destination));
+        for i in 0..40 {
+            let copied = workspace_root.join(format!("shared/dir{}/file{:02}.txt", i % 4, i));
+            assert_eq!(
+                async_fs::read_to_string(copied).await.unwrap(),
+                format!("content {}", i)
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_copy_operation_copies_nested_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace_root = temp_dir.path();
+        let store = ManifestStore::new();
+
+        let templates = workspace_root.join("repo1/templates");
+        async_fs::create_dir_all(templates.join("ci/jobs"))
+            .await
+            .unwrap();
+        async_fs::create_dir_all(templates.join("empty"))
+            .await
+            .unwrap();
+        async_fs::write(templates.join("README.md"), "readme")
+            .await
+            .unwrap();
+        async_fs::write(templates.join("ci/pipeline.yml"), "pipeline")
+            .await
+            .unwrap();
+        async_fs::write(templates.join("ci/jobs/build.yml"), "build")
+            .await
+            .unwrap();
+
+        let mut repo = ManifestRepo::new("https://github.com/example/repo1.git", "repo1");
+        repo.copy = Some(vec![FileCopy {
+            file: "templates".to_string(),
+            dest: "shared/templates".to_string(),
+        }]);
+        let manifest = Manifest::new(vec![repo]);
+
+        let results = store
+            .process_copy_operations(&manifest, workspace_root)
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert!(results[0].success, "{:?}", results[0].error);
+
+        let copied = workspace_root.join("shared/templates");
+        assert_eq!(
+            async_fs::read_to_string(copied.join("README.md"))
+                .await
+                .unwrap(),
+            "readme"
+        );
+        assert_eq!(
+            async_fs::read_to_string(copied.join("ci/pipeline.yml"))
+                .await
+                .unwrap(),
+            "pipeline"
+        );
+        assert_eq!(
+            async_fs::read_to_string(copied.join("ci/jobs/build.yml"))
+                .await
+                .unwrap(),
+            "build"
+        );
+        assert!(copied.join("empty").is_dir());
+    }
+
+    #[tokio::test]
+    async fn test_directory_copy_keeps_path_traversal_validation() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace_root = temp_dir.path();
+        let store = ManifestStore::new();
+        async_fs::create_dir_all(workspace_root.join("repo1/templates"))
+            .await
+            .unwrap();
+
+        let mut repo = ManifestRepo::new("https://github.com/example/repo1.git", "repo1");
+        repo.copy = Some(vec![FileCopy {
+            file: "templates".to_string(),
+            dest: "../outside".to_string(),
+        }]);
+        let manifest = Manifest::new(vec![repo]);
+
+        let results = store
+            .process_copy_operations(&manifest, workspace_root)
+            .await
+            .unwrap();
+        assert!(!results[0].success);
+        assert!(!temp_dir.path().parent().unwrap().join("outside").exists());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_copy_source_in_symlinked_repo_outside_workspace_is_refused() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace_root = temp_dir.path().join("workspace");
+        let outside = temp_dir.path().join("outside");
+        async_fs::create_dir_all(&workspace_root).await.unwrap();
+        async_fs::create_dir_all(&outside).await.unwrap();
+        async_fs::write(outside.join("secret"), "secret")
+            .await
+            .unwrap();
+        unix_fs::symlink(&outside, workspace_root.join("repo1")).unwrap();
+        let store = ManifestStore::new();
+
+        let mut repo = ManifestRepo::new("https://github.com/example/repo1.git", "repo1");
+        repo.copy = Some(vec![FileCopy {
+            file: "secret".to_string(),
+            dest: "copied".to_string(),
+        }]);
+        let manifest = Manifest::new(vec![repo]);
+
+        let results = store
+            .process_copy_operations(&manifest, &workspace_root)
+            .await
+            .unwrap();
+        assert!(!results[0].success);
+        assert!(results[0].error.as_ref().unwrap().contains("symbolic link"));
+        assert!(!workspace_root.join("copied").exists());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_relative_symlink_target_resolves_from_link_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace_root = temp_dir.path();
+        let store = ManifestStore::new();
+
+        let tool = workspace_root.join("repo1/bin/tool");
+        async_fs::create_dir_all(tool.parent().unwrap())
+            .await
+            .unwrap();
+        async_fs::write(&tool, "tool").await.unwrap();
+
+        let mut repo = ManifestRepo::new("https://github.com/example/repo1.git", "repo1");
+        repo.symlink = Some(vec![FileSymlink {
+            source: "tools/bin/tool".to_string(),
+            target: "../../repo1/bin/tool".to_string(),
+        }]);
+        let manifest = Manifest::new(vec![repo]);
+
+        let results = store
+            .process_symlink_operations(&manifest, workspace_root)
+            .await
+            .unwrap();
+        assert!(results[0].success, "{:?}", results[0].error);
+        assert_eq!(results[0].destination, tool);
+
+        // The link stays relative and follows to the intended file, independent of the CWD
+        let link = workspace_root.join("tools/bin/tool");
+        assert_eq!(
+            std::fs::read_link(&link).unwrap(),
+            Path::new("../../repo1/bin/tool")
+        );
+        assert_ne!(std::env::current_dir().unwrap(), workspace_root);
+        assert_eq!(async_fs::read_to_string(&link).await.unwrap(), "tool");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_symlink_to_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace_root = temp_dir.path();
+        let store = ManifestStore::new();
+        async_fs::create_dir_all(workspace_root.join("repo1/config"))
+            .await
+            .unwrap();
+        async_fs::write(workspace_root.join("repo1/config/app.yml"), "app")
+            .await
+            .unwrap();
+
+        let mut repo = ManifestRepo::new("https://github.com/example/repo1.git", "repo1");
+        repo.symlink = Some(vec![FileSymlink {
+            source: "config".to_string(),
+            target: "repo1/config".to_string(),
+        }]);
+        let manifest = Manifest::new(vec![repo]);
+
+        let results = store
+            .process_symlink_operations(&manifest, workspace_root)
+            .await
+            .unwrap();
+        assert!(results[0].success, "{:?}", results[0].error);
+        assert_eq!(
+            async_fs::read_to_string(workspace_root.join("config/app.yml"))
+                .await
+                .unwrap(),
+            "app"
+        );
+    }
+
+    #[cfg(windows)]
+    #[tokio::test]
+    async fn test_windows_symlink_file_and_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace_root = temp_dir.path();
+        let store = ManifestStore::new();
+        async_fs::create_dir_all(workspace_root.join("repo1/config"))
+            .await
+            .unwrap();
+        async_fs::write(workspace_root.join("repo1/config/app.yml"), "app")
+            .await
+            .unwrap();
+
+        let mut repo = ManifestRepo::new("https://github.com/example/repo1.git", "repo1");
+        repo.symlink = Some(vec![
+            FileSymlink {
+                source: "config".to_string(),
+                target: "repo1\\config".to_string(),
+            },
+            FileSymlink {
+                source: "app.yml".to_string(),
+                target: "repo1\\config\\app.yml".to_string(),
+            },
+        ]);
+        let manifest = Manifest::new(vec![repo]);
+
+        let results = store
+            .process_symlink_operations(&manifest, workspace_root)
+            .await
+            .unwrap();
+
+        // Directory links always succeed thanks to the junction fallback
+        let dir_result = results
+            .iter()
+            .find(|r| r.source == workspace_root.join("config"))
+            .unwrap();
+        assert!(dir_result.success, "{:?}", dir_result.error);
+        assert_eq!(
+            async_fs::read_to_string(workspace_root.join("config\\app.yml"))
+                .await
+                .unwrap(),
+            "app"
+        );
+
+        // File links either succeed or explain how to enable symlink creation
+        let file_result = results
+            .iter()
+            .find(|r| r.source == workspace_root.join("app.yml"))
+            .unwrap();
+        if file_result.success {
+            assert!(workspace_root.join("app.yml").is_symlink());
+        } else {
+            assert!(file_result
+                .error
+                .as_deref()
+                .unwrap()
+                .contains("Developer Mode"));
+        }
+    }
+
+    #[test]
+    fn test_resolve_symlink_target() {
+        let link = Path::new("/ws/tools/bin/tool");
+        assert_eq!(
+            resolve_symlink_target(link, Path::new("../../repo1/bin/tool")),
+            Path::new("/ws/repo1/bin/tool")
+        );
+        assert_eq!(
+            resolve_symlink_target(link, Path::new("./other")),
+            Path::new("/ws/tools/bin/other")
+        );
+        assert_eq!(
+            resolve_symlink_target(link, Path::new("/opt/tool")),
+            Path::new("/opt/tool")
+        );
+    }
+
     #[tokio::test]
     async fn test_process_all_file_operations() {
         let temp_dir = TempDir::new().unwrap();
@@ -1039,8 +1769,8 @@ mod tests {
             .await
             .unwrap();
 
-        // Check that backup was created
-        let parent_dir = temp_dir.path();
+        // Check that backup was created in the backup directory, not next to the file
+        let parent_dir = temp_dir.path().join(".wmgr/backups");
         let mut backup_found = false;
         if let Ok(mut entries) = async_fs::read_dir(parent_dir).await {
             while let Ok(Some(entry)) = entries.next_entry().await {
@@ -1052,6 +1782,91 @@ mod tests {
             }
         }
         assert!(backup_found);
+        assert!(!temp_dir
+            .path()
+            .read_dir()
+            .unwrap()
+            .flatten()
+            .any(|entry| entry.file_name().to_string_lossy().contains(".bak_")));
+    }
+
+    #[tokio::test]
+    async fn test_backup_rotation_in_configured_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let manifest_path = temp_dir.path().join("manifest.yml");
+        let backup_dir = temp_dir.path().join("backups");
+        let mut options = ManifestProcessingOptions::default();
+        options.file_operation_config.backup_dir = Some(backup_dir.clone());
+        options.file_operation_config.max_backups = 2;
+        let store = ManifestStore::with_options(options);
+        let manifest = create_test_manifest().await;
+
+        // Older backups from previous runs
+        std::fs::create_dir_all(&backup_dir).unwrap();
+        for (i, name) in [
+            "manifest.yml.bak_20200101_000000",
+            "manifest.yml.bak_20200102_000000",
+        ]
+        .iter()
+        .enumerate()
+        {
+            let file = std::fs::File::create(backup_dir.join(name)).unwrap();
+            file.set_modified(
+                std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(i as u64 + 1),
+            )
+            .unwrap();
+        }
+        std::fs::write(backup_dir.join("other.yml.bak_20200101_000000"), "").unwrap();
+
+        store
+            .write_manifest(&manifest_path, &manifest)
+            .await
+            .unwrap();
+        store
+            .write_manifest(&manifest_path, &manifest)
+            .await
+            .unwrap();
+
+        let mut names: Vec<String> = std::fs::read_dir(&backup_dir)
+            .unwrap()
+            .flatten()
+            .map(|entry| entry.file_name().to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+
+        // The oldest backup is rotated out; unrelated backups are untouched
+        assert_eq!(names.len(), 3, "{:?}", names);
+        assert!(!names.contains(&"manifest.yml.bak_20200101_000000".to_string()));
+        assert!(names.contains(&"manifest.yml.bak_20200102_000000".to_string()));
+        assert!(names.contains(&"other.yml.bak_20200101_000000".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_no_backup_when_disabled() {
+        let temp_dir = TempDir::new().unwrap();
+        let manifest_path = temp_dir.path().join("manifest.yml");
+        let store = ManifestStore::new().with_backup(false);
+        let manifest = create_test_manifest().await;
+
+        store
+            .write_manifest(&manifest_path, &manifest)
+            .await
+            .unwrap();
+        store
+            .write_manifest(&manifest_path, &manifest)
+            .await
+            .unwrap();
+        store.backup_file(&manifest_path).await.unwrap();
+        store.delete_manifest(&manifest_path).await.unwrap();
+
+        let mut entries = async_fs::read_dir(temp_dir.path()).await.unwrap();
+        while let Some(entry) = entries.next_entry().await.unwrap() {
+            assert!(
+                !entry.file_name().to_string_lossy().contains(".bak_"),
+                "unexpected backup {:?}",
+                entry.path()
+            );
+        }
     }
 
     #[tokio::test]
@@ -1,2 +1,8 @@
+pub mod atomic_write;
+pub mod backup;
 pub mod config_store;
+pub mod managed_repos;
 pub mod manifest_store;
+pub mod status_ignore;
+pub mod sync_journal;
+pub mod workspace_lock;
@@ -0,0 +1,160 @@
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use std::fs;
+use std::path::Path;
+use thiserror::Error;
+
+/// Name of the workspace-level ignore file, next to the manifest
+pub const IGNORE_FILE_NAME: &str = ".wmgrignore";
+
+/// Status ignore related errors
+#[derive(Debug, Error)]
+pub enum StatusIgnoreError {
+    #[error("Failed to read {path}: {error}")]
+    ReadFailed { path: String, error: String },
+
+    #[error("Invalid ignore pattern '{pattern}': {error}")]
+    InvalidPattern { pattern: String, error: String },
+}
+
+/// Glob patterns for untracked files that wmgr should not report
+///
+/// Patterns follow a subset of `.gitignore` syntax: a pattern without a `/`
+/// matches at any depth (`*.log`), a trailing `/` matches everything below a
+/// directory (`target/`), and a leading `/` anchors the pattern to the
+/// repository root. This only affects what `wmgr status` counts; git's own
+/// ignore rules are applied before these.
+#[derive(Debug, Clone)]
+pub struct StatusIgnore {
+    set: GlobSet,
+}
+
+impl StatusIgnore {
+    /// Compile the given patterns
+    pub fn new<S: AsRef<str>>(patterns: &[S]) -> Result<Self, StatusIgnoreError> {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in patterns {
+            let pattern = pattern.as_ref();
+            let glob = GlobBuilder::new(&Self::to_glob(pattern))
+                .literal_separator(true)
+                .build()
+                .map_err(|e| StatusIgnoreError::InvalidPattern {
+                    pattern: pattern.to_string(),
+                    error: e.to_string(),
+                })?;
+            builder.add(glob);
+        }
+
+        let set = builder
+            .build()
+            .map_err(|e| StatusIgnoreError::InvalidPattern {
+                pattern: patterns
+                    .iter()
+                    .map(|p| p.as_ref())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                error: e.to_string(),
+            })?;
+        Ok(Self { set })
+    }
+
+    /// Read the patterns from `.wmgrignore` in the workspace root
+    ///
+    /// Blank lines and lines starting with `#` are skipped. A missing file
+    /// yields no patterns.
+    pub fn load_patterns(workspace_root: &Path) -> Result<Vec<String>, StatusIgnoreError> {
+        let path = workspace_root.join(IGNORE_FILE_NAME);
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let content = fs::read_to_string(&path).map_err(|e| StatusIgnoreError::ReadFailed {
+            path: path.display().to_string(),
+            error: e.to_string(),
+        })?;
+        Ok(content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(str::to_string)
+            .collect())
+    }
+
+    /// Whether an untracked path (relative to the repository root) is ignored
+    pub fn is_ignored(&self, path: &str) -> bool {
+        self.set.is_match(path)
+    }
+
+    /// Translate an ignore pattern into an equivalent glob
+    fn to_glob(pattern: &str) -> String {
+        let (pattern, directory) = match pattern.strip_suffix('/') {
+            Some(stripped) => (stripped, true),
+            None => (pattern, false),
+        };
+
+        let mut glob = match pattern.strip_prefix('/') {
+            Some(anchored) => anchored.to_string(),
+            None if pattern.contains('/') => pattern.to_string(),
+            None => format!("**/{}", pattern),
+        };
+        if directory {
+            glob.push_str("/**");
+        }
+        glob
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_directory_and_extension_patterns() {
+        let ignore = StatusIgnore::new(&["target/", "*.log"]).unwrap();
+
+        assert!(ignore.is_ignored("target/debug/app"));
+        assert!(ignore.is_ignored("crates/core/target/release/lib.rlib"));
+        assert!(ignore.is_ignored("build.log"));
+        assert!(ignore.is_ignored("logs/nested/run.log"));
+
+        assert!(!ignore.is_ignored("src/main.rs"));
+        assert!(!ignore.is_ignored("targets.txt"));
+        assert!(!ignore.is_ignored("build.log.txt"));
+    }
+
+    #[test]
+    fn test_anchored_and_nested_patterns() {
+        let ignore = StatusIgnore::new(&["/out", "docs/*.html"]).unwrap();
+
+        assert!(ignore.is_ignored("out"));
+        assert!(!ignore.is_ignored("sub/out"));
+        assert!(ignore.is_ignored("docs/index.html"));
+        assert!(!ignore.is_ignored("docs/api/index.html"));
+    }
+
+    #[test]
+    fn test_invalid_pattern_is_reported() {
+        assert!(matches!(
+            StatusIgnore::new(&["[unclosed"]),
+            Err(StatusIgnoreError::InvalidPattern { .. })
+        ));
+    }
+
+    #[test]
+    fn test_load_patterns_skips_comments_and_blank_lines() {
+        let temp_dir = TempDir::new().unwrap();
+        assert!(StatusIgnore::load_patterns(temp_dir.path())
+            .unwrap()
+            .is_empty());
+
+        fs::write(
+            temp_dir.path().join(IGNORE_FILE_NAME),
+            "# generated output\ntarget/\n\n  *.log  \n",
+        )
+        .unwrap();
+        assert_eq!(
+            StatusIgnore::load_patterns(temp_dir.path()).unwrap(),
+            vec!["target/".to_string(), "*.log".to_string()]
+        );
+    }
+}
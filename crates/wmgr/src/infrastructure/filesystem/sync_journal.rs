@@ -0,0 +1,177 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Name of the journal file inside the `.wmgr` directory
+pub const SYNC_JOURNAL_FILE_NAME: &str = "sync-state.json";
+
+/// Sync journal related errors
+#[derive(Debug, Error)]
+pub enum SyncJournalError {
+    #[error("Failed to read sync journal {path}: {error}")]
+    ReadFailed { path: String, error: String },
+
+    #[error("Failed to write sync journal {path}: {error}")]
+    WriteFailed { path: String, error: String },
+
+    #[error("Invalid sync journal {path}: {error}")]
+    ParseFailed { path: String, error: String },
+}
+
+/// Record of the repositories completed by an interrupted sync
+///
+/// The journal lives at `.wmgr/sync-state.json` and is rewritten after each
+/// successful repository so that `wmgr sync --continue` can skip them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncJournal {
+    /// Destinations of repositories that synced successfully
+    pub completed: BTreeSet<String>,
+
+    /// When the journal was last written
+    pub updated_at: DateTime<Utc>,
+
+    #[serde(skip)]
+    path: PathBuf,
+}
+
+impl SyncJournal {
+    /// Create an empty journal for the workspace whose `.wmgr` directory is `wmgr_dir`
+    pub fn new(wmgr_dir: &Path) -> Self {
+        Self {
+            completed: BTreeSet::new(),
+            updated_at: Utc::now(),
+            path: wmgr_dir.join(SYNC_JOURNAL_FILE_NAME),
+        }
+    }
+
+    /// Load the journal, returning an empty one when no journal exists
+    pub fn load(wmgr_dir: &Path) -> Result<Self, SyncJournalError> {
+        let path = wmgr_dir.join(SYNC_JOURNAL_FILE_NAME);
+        if !path.exists() {
+            return Ok(Self::new(wmgr_dir));
+        }
+
+        let content = fs::read_to_string(&path).map_err(|e| SyncJournalError::ReadFailed {
+            path: path.display().to_string(),
+            error: e.to_string(),
+        })?;
+        let mut journal: Self =
+            serde_json::from_str(&content).map_err(|e| SyncJournalError::ParseFailed {
+                path: path.display().to_string(),
+                error: e.to_string(),
+            })?;
+        journal.path = path;
+        Ok(journal)
+    }
+
+    /// Path of the journal file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Whether the repository was completed by a previous run
+    pub fn is_completed(&self, dest: &str) -> bool {
+        self.completed.contains(dest)
+    }
+
+    /// Record a successfully synced repository and persist the journal
+    pub fn mark_completed(&mut self, dest: &str) -> Result<(), SyncJournalError> {
+        self.completed.insert(dest.to_string());
+        self.save()
+    }
+
+    /// Write the journal to disk
+    pub fn save(&mut self) -> Result<(), SyncJournalError> {
+        let path = self.path.clone();
+        let write_error = |e: &dyn std::fmt::Display| SyncJournalError::WriteFailed {
+            path: path.display().to_string(),
+            error: e.to_string(),
+        };
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| write_error(&e))?;
+        }
+
+        self.updated_at = Utc::now();
+        let content = serde_json::to_string_pretty(self).map_err(|e| write_error(&e))?;
+
+        // Write to a temporary file first so an interrupted write never leaves a truncated journal
+        let tmp_path = path.with_extension("json.tmp");
+        fs::write(&tmp_path, content).map_err(|e| write_error(&e))?;
+        fs::rename(&tmp_path, &path).map_err(|e| write_error(&e))?;
+        Ok(())
+    }
+
+    /// Remove the journal after a fully successful sync
+    pub fn clear(&self) -> Result<(), SyncJournalError> {
+        match fs::remove_file(&self.path) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(SyncJournalError::WriteFailed {
+                path: self.path.display().to_string(),
+                error: e.to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_load_missing_journal_is_empty() {
+        let temp_dir = TempDir::new().unwrap();
+        let journal = SyncJournal::load(&temp_dir.path().join(".wmgr")).unwrap();
+        assert!(journal.completed.is_empty());
+        assert!(!journal.is_completed("repo"));
+    }
+
+    #[test]
+    fn test_mark_completed_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let wmgr_dir = temp_dir.path().join(".wmgr");
+
+        let mut journal = SyncJournal::new(&wmgr_dir);
+        journal.mark_completed("frontend").unwrap();
+        journal.mark_completed("backend").unwrap();
+        assert!(wmgr_dir.join(SYNC_JOURNAL_FILE_NAME).exists());
+
+        let loaded = SyncJournal::load(&wmgr_dir).unwrap();
+        assert!(loaded.is_completed("frontend"));
+        assert!(loaded.is_completed("backend"));
+        assert!(!loaded.is_completed("shared"));
+        assert_eq!(loaded.path(), wmgr_dir.join(SYNC_JOURNAL_FILE_NAME));
+    }
+
+    #[test]
+    fn test_clear_removes_journal() {
+        let temp_dir = TempDir::new().unwrap();
+        let wmgr_dir = temp_dir.path().join(".wmgr");
+
+        let mut journal = SyncJournal::new(&wmgr_dir);
+        journal.mark_completed("frontend").unwrap();
+        journal.clear().unwrap();
+        assert!(!wmgr_dir.join(SYNC_JOURNAL_FILE_NAME).exists());
+
+        // Clearing a missing journal is not an error
+        journal.clear().unwrap();
+    }
+
+    #[test]
+    fn test_invalid_journal_is_reported() {
+        let temp_dir = TempDir::new().unwrap();
+        let wmgr_dir = temp_dir.path().join(".wmgr");
+        fs::create_dir_all(&wmgr_dir).unwrap();
+        fs::write(wmgr_dir.join(SYNC_JOURNAL_FILE_NAME), "not json").unwrap();
+
+        assert!(matches!(
+            SyncJournal::load(&wmgr_dir),
+            Err(SyncJournalError::ParseFailed { .. })
+        ));
+    }
+}
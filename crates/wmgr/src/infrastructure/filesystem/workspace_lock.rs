@@ -0,0 +1,293 @@
+use crate::common::error::WmgrError;
+use std::fs::{self, File, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Name of the lock file inside the `.wmgr` directory
+pub const LOCK_FILE_NAME: &str = "lock";
+
+/// Workspace lock related errors
+#[derive(Debug, Error)]
+pub enum WorkspaceLockError {
+    #[error(
+        "Another wmgr process is running in this workspace (pid {}). If no such process exists, remove {}",
+        pid.map(|p| p.to_string()).unwrap_or_else(|| "unknown".to_string()),
+        path.display()
+    )]
+    AlreadyLocked { pid: Option<u32>, path: PathBuf },
+
+    #[error("Failed to create lock file {path}: {error}")]
+    CreateFailed { path: String, error: String },
+
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+}
+
+impl From<WorkspaceLockError> for WmgrError {
+    fn from(error: WorkspaceLockError) -> Self {
+        match error {
+            WorkspaceLockError::AlreadyLocked { ref path, .. } => WmgrError::WorkspaceLocked {
+                lock_path: path.clone(),
+                message: error.to_string(),
+            },
+            WorkspaceLockError::CreateFailed { ref path, .. } => {
+                WmgrError::filesystem_error(error.to_string(), Some(PathBuf::from(path)))
+            }
+            WorkspaceLockError::IoError(source) => WmgrError::filesystem_error_with_source(
+                "Failed to acquire workspace lock",
+                None,
+                source,
+            ),
+        }
+    }
+}
+
+/// Advisory lock guarding a workspace against concurrent mutating commands
+///
+/// The lock is a `.wmgr/lock` file created atomically (`O_EXCL`) and holding
+/// the PID of the owning process. It is removed when the guard is dropped.
+/// A process that is killed, or that aborts on a panic (release builds use
+/// `panic = "abort"`), never drops the guard; on Unix the next acquisition
+/// sees that the recorded process no longer exists and takes the lock over.
+/// Elsewhere such a lock has to be removed by hand.
+#[derive(Debug)]
+pub struct WorkspaceLock {
+    path: PathBuf,
+}
+
+impl WorkspaceLock {
+    /// Acquire the lock for the workspace whose `.wmgr` directory is `wmgr_dir`
+    pub fn acquire(wmgr_dir: &Path) -> Result<Self, WorkspaceLockError> {
+        fs::create_dir_all(wmgr_dir)?;
+        let path = wmgr_dir.join(LOCK_FILE_NAME);
+
+        let mut file = match Self::create(&path)? {
+            Some(file) => file,
+            None => {
+                // A lock whose PID is not written yet belongs to a process still starting up
+                let pid = Self::holder_pid(&path);
+                let stale = pid.is_some_and(|pid| !process_exists(pid));
+                let taken_over = if stale && Self::remove_stale(&path, pid) {
+                    Self::create(&path)?
+                } else {
+                    None
+                };
+                match taken_over {
+                    Some(file) => file,
+                    None => {
+                        return Err(WorkspaceLockError::AlreadyLocked {
+                            pid: Self::holder_pid(&path),
+                            path,
+                        });
+                    }
+                }
+            }
+        };
+
+        // From here on the guard owns the file, so a failed write still cleans up
+        let lock = Self { path };
+        writeln!(file, "{}", std::process::id())?;
+        Ok(lock)
+    }
+
+    /// Path of the lock file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Create the lock file, returning `None` if it already exists
+    fn create(path: &Path) -> Result<Option<File>, WorkspaceLockError> {
+        match OpenOptions::new().write(true).create_new(true).open(path) {
+            Ok(file) => Ok(Some(file)),
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => Ok(None),
+            Err(e) => Err(WorkspaceLockError::CreateFailed {
+                path: path.display().to_string(),
+                error: e.to_string(),
+            }),
+        }
+    }
+
+    /// Remove the lock file left by the dead process `pid`, returning whether it is gone
+    ///
+    /// The file is first renamed to a name of our own, so of several processes
+    /// taking over the same stale lock only one removes it. If another process
+    /// took the lock over in the meantime, its lock file is put back.
+    fn remove_stale(path: &Path, pid: Option<u32>) -> bool {
+        let claimed = path.with_extension(format!("stale.{}", std::process::id()));
+        match fs::rename(path, &claimed) {
+            Ok(()) => {}
+            Err(e) => return e.kind() == ErrorKind::NotFound,
+        }
+        if Self::holder_pid(&claimed) == pid {
+            let _ = fs::remove_file(&claimed);
+            return true;
+        }
+        let _ = fs::hard_link(&claimed, path);
+        let _ = fs::remove_file(&claimed);
+        false
+    }
+
+    /// Read the PID recorded in an existing lock file
+    fn holder_pid(path: &Path) -> Option<u32> {
+        fs::read_to_string(path).ok()?.trim().parse().ok()
+    }
+}
+
+/// Whether a process with this PID exists
+#[cfg(unix)]
+fn process_exists(pid: u32) -> bool {
+    let Ok(pid) = libc::pid_t::try_from(pid) else {
+        return true;
+    };
+    // Signal 0 only checks for existence; EPERM means the process belongs to another user
+    // SAFETY: kill with signal 0 sends no signal and has no side effects
+    let status = unsafe { libc::kill(pid, 0) };
+    status == 0 || std::io::Error::last_os_error().raw_os_error() != Some(libc::ESRCH)
+}
+
+/// Whether a process with this PID exists (always assumed on platforms without a check)
+#[cfg(not(unix))]
+fn process_exists(_pid: u32) -> bool {
+    true
+}
+
+impl Drop for WorkspaceLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_acquire_writes_pid() {
+        let temp_dir = TempDir::new().unwrap();
+        let wmgr_dir = temp_dir.path().join(".wmgr");
+
+        let lock = WorkspaceLock::acquire(&wmgr_dir).unwrap();
+        assert_eq!(lock.path(), wmgr_dir.join(LOCK_FILE_NAME));
+
+        let content = fs::read_to_string(lock.path()).unwrap();
+        assert_eq!(content.trim(), std::process::id().to_string());
+    }
+
+    #[test]
+    fn test_second_acquisition_fails() {
+        let temp_dir = TempDir::new().unwrap();
+        let wmgr_dir = temp_dir.path().join(".wmgr");
+
+        let _lock = WorkspaceLock::acquire(&wmgr_dir).unwrap();
+
+        match WorkspaceLock::acquire(&wmgr_dir) {
+            Err(WorkspaceLockError::AlreadyLocked { pid, path }) => {
+                assert_eq!(pid, Some(std::process::id()));
+                assert_eq!(path, wmgr_dir.join(LOCK_FILE_NAME));
+            }
+            other => panic!("expected AlreadyLocked, got {:?}", other),
+        }
+
+        let message = WorkspaceLock::acquire(&wmgr_dir).unwrap_err().to_string();
+        assert!(message.contains("Another wmgr process is running"));
+        assert!(message.contains(&std::process::id().to_string()));
+    }
+
+    #[test]
+    fn test_already_locked_converts_to_workspace_locked() {
+        let temp_dir = TempDir::new().unwrap();
+        let wmgr_dir = temp_dir.path().join(".wmgr");
+
+        let _lock = WorkspaceLock::acquire(&wmgr_dir).unwrap();
+        let error: WmgrError = WorkspaceLock::acquire(&wmgr_dir).unwrap_err().into();
+
+        match &error {
+            WmgrError::WorkspaceLocked { lock_path, .. } => {
+                assert_eq!(lock_path, &wmgr_dir.join(LOCK_FILE_NAME))
+            }
+            other => panic!("expected WorkspaceLocked, got {:?}", other),
+        }
+        assert!(error
+            .to_string()
+            .contains("Another wmgr process is running"));
+        assert!(error.hint().is_some());
+    }
+
+    #[test]
+    fn test_concurrent_acquisition_only_one_wins() {
+        let temp_dir = TempDir::new().unwrap();
+        let wmgr_dir = temp_dir.path().join(".wmgr");
+        let barrier = std::sync::Arc::new(std::sync::Barrier::new(8));
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let wmgr_dir = wmgr_dir.clone();
+                let barrier = barrier.clone();
+                std::thread::spawn(move || {
+                    barrier.wait();
+                    let lock = WorkspaceLock::acquire(&wmgr_dir);
+                    let acquired = lock.is_ok();
+                    // Hold the lock until every thread has tried to acquire it
+                    barrier.wait();
+                    acquired
+                })
+            })
+            .collect();
+
+        let acquired = handles
+            .into_iter()
+            .map(|h| h.join().unwrap())
+            .filter(|acquired| *acquired)
+            .count();
+        assert_eq!(acquired, 1);
+    }
+
+    #[test]
+    fn test_lock_released_on_drop() {
+        let temp_dir = TempDir::new().unwrap();
+        let wmgr_dir = temp_dir.path().join(".wmgr");
+
+        {
+            let _lock = WorkspaceLock::acquire(&wmgr_dir).unwrap();
+        }
+        assert!(!wmgr_dir.join(LOCK_FILE_NAME).exists());
+        assert!(WorkspaceLock::acquire(&wmgr_dir).is_ok());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_lock_of_dead_process_is_taken_over() {
+        let temp_dir = TempDir::new().unwrap();
+        let wmgr_dir = temp_dir.path().join(".wmgr");
+        fs::create_dir_all(&wmgr_dir).unwrap();
+
+        // A process that was killed (or aborted on a panic) while holding the lock
+        let mut child = std::process::Command::new("true").spawn().unwrap();
+        let dead_pid = child.id();
+        child.wait().unwrap();
+        fs::write(wmgr_dir.join(LOCK_FILE_NAME), format!("{}\n", dead_pid)).unwrap();
+
+        let lock = WorkspaceLock::acquire(&wmgr_dir).unwrap();
+        let content = fs::read_to_string(lock.path()).unwrap();
+        assert_eq!(content.trim(), std::process::id().to_string());
+        assert_eq!(fs::read_dir(&wmgr_dir).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_lock_of_running_process_is_kept() {
+        let temp_dir = TempDir::new().unwrap();
+        let wmgr_dir = temp_dir.path().join(".wmgr");
+        fs::create_dir_all(&wmgr_dir).unwrap();
+
+        // This process is alive, as is a lock whose PID could not be read
+        for content in [format!("{}\n", std::process::id()), String::new()] {
+            fs::write(wmgr_dir.join(LOCK_FILE_NAME), content).unwrap();
+            assert!(matches!(
+                WorkspaceLock::acquire(&wmgr_dir),
+                Err(WorkspaceLockError::AlreadyLocked { .. })
+            ));
+        }
+    }
+}
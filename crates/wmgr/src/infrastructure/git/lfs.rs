@@ -0,0 +1,73 @@
+use crate::infrastructure::process::CommandExecutor;
+use std::path::Path;
+
+/// Name of the Git LFS executable that `git lfs` dispatches to
+pub const GIT_LFS_EXECUTABLE: &str = "git-lfs";
+
+/// Whether the repository at `repo_path` stores files in Git LFS
+///
+/// Looks for an LFS filter in the top-level `.gitattributes`, which is where
+/// `git lfs track` records tracked patterns.
+pub fn uses_lfs(repo_path: &Path) -> bool {
+    std::fs::read_to_string(repo_path.join(".gitattributes"))
+        .map(|content| has_lfs_filter(&content))
+        .unwrap_or(false)
+}
+
+/// Whether `.gitattributes` content assigns the `lfs` filter to any pattern
+pub fn has_lfs_filter(gitattributes: &str) -> bool {
+    gitattributes
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .any(|line| {
+            line.split_whitespace()
+                .skip(1)
+                .any(|attribute| attribute == "filter=lfs")
+        })
+}
+
+/// Whether the `git-lfs` executable is available on PATH
+pub fn is_installed() -> bool {
+    CommandExecutor::command_exists(GIT_LFS_EXECUTABLE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_has_lfs_filter() {
+        let gitattributes = "\
+# Binary assets
+*.psd filter=lfs diff=lfs merge=lfs -text
+*.sh text eol=lf
+";
+        assert!(has_lfs_filter(gitattributes));
+
+        assert!(!has_lfs_filter("*.sh text eol=lf\n"));
+        assert!(!has_lfs_filter(
+            "# *.psd filter=lfs diff=lfs merge=lfs -text\n"
+        ));
+        assert!(!has_lfs_filter("*.psd -filter\n"));
+        assert!(!has_lfs_filter("filter=lfs\n"));
+        assert!(!has_lfs_filter(""));
+    }
+
+    #[test]
+    fn test_uses_lfs_reads_top_level_gitattributes() {
+        let temp_dir = TempDir::new().unwrap();
+        assert!(!uses_lfs(temp_dir.path()));
+
+        std::fs::write(temp_dir.path().join(".gitattributes"), "*.bin text\n").unwrap();
+        assert!(!uses_lfs(temp_dir.path()));
+
+        std::fs::write(
+            temp_dir.path().join(".gitattributes"),
+            "*.bin filter=lfs diff=lfs merge=lfs -text\n",
+        )
+        .unwrap();
+        assert!(uses_lfs(temp_dir.path()));
+    }
+}
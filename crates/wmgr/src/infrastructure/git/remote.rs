@@ -97,7 +97,7 @@ impl RemoteInfo {
 
     /// Convert to domain Remote entity
     pub fn to_domain_remote(&self) -> DomainRemote {
-        DomainRemote::new(&self.name, self.url.as_str())
+        DomainRemote::new(&self.name, self.url.to_clone_url())
     }
 }
 
@@ -338,6 +338,95 @@ impl<'repo> GitRemoteManager<'repo> {
         }
     }
 
+    /// Look up the commit at the tip of a remote branch without fetching
+    ///
+    /// Equivalent to `git ls-remote <name> refs/heads/<branch>`. Returns `None`
+    /// when the branch does not exist on the remote.
+    pub fn get_remote_tip(
+        &self,
+        name: &str,
+        branch: &str,
+    ) -> Result<Option<String>, GitRemoteError> {
+        let mut remote = self
+            .repo
+            .find_remote(name)
+            .map_err(|_| GitRemoteError::RemoteNotFound(name.to_string()))?;
+
+        let mut callbacks = git2::RemoteCallbacks::new();
+        callbacks.credentials(|_url, username_from_url, allowed_types| {
+            if allowed_types.contains(git2::CredentialType::SSH_KEY) {
+                git2::Cred::ssh_key_from_agent(username_from_url.unwrap_or("git"))
+            } else {
+                Err(git2::Error::from_str("Authentication not supported"))
+            }
+        });
+
+        remote
+            .connect_auth(Direction::Fetch, Some(callbacks), None)
+            .map_err(|e| GitRemoteError::RemoteOperationFailed(e.message().to_string()))?;
+
+        let branch_ref = format!("refs/heads/{}", branch);
+        let tip = remote
+            .list()?
+            .iter()
+            .find(|head| head.name() == branch_ref)
+            .map(|head| head.oid().to_string());
+
+        remote.disconnect()?;
+
+        Ok(tip)
+    }
+
+    /// Branch the remote's HEAD points to, e.g. `main` or `master`
+    ///
+    /// Uses the `refs/remotes/<name>/HEAD` symbolic ref recorded when the
+    /// repository was cloned, and asks the remote (like `git remote show <name>`)
+    /// when it is missing. Returns `None` when the remote does not advertise a
+    /// default branch.
+    pub fn get_remote_head_branch(&self, name: &str) -> Result<Option<String>, GitRemoteError> {
+        let mut remote = self
+            .repo
+            .find_remote(name)
+            .map_err(|_| GitRemoteError::RemoteNotFound(name.to_string()))?;
+
+        let tracking_prefix = format!("refs/remotes/{}/", name);
+        if let Ok(head) = self
+            .repo
+            .find_reference(&format!("{}HEAD", tracking_prefix))
+        {
+            if let Some(branch) = head
+                .symbolic_target()
+                .and_then(|target| target.strip_prefix(&tracking_prefix))
+            {
+                return Ok(Some(branch.to_string()));
+            }
+        }
+
+        let mut callbacks = git2::RemoteCallbacks::new();
+        callbacks.credentials(|_url, username_from_url, allowed_types| {
+            if allowed_types.contains(git2::CredentialType::SSH_KEY) {
+                git2::Cred::ssh_key_from_agent(username_from_url.unwrap_or("git"))
+            } else {
+                Err(git2::Error::from_str("Authentication not supported"))
+            }
+        });
+
+        remote
+            .connect_auth(Direction::Fetch, Some(callbacks), None)
+            .map_err(|e| GitRemoteError::RemoteOperationFailed(e.message().to_string()))?;
+
+        // An empty remote or one without a HEAD has no default branch
+        let branch = remote.default_branch().ok().and_then(|head| {
+            head.as_str()
+                .and_then(|head| head.strip_prefix("refs/heads/"))
+                .map(|branch| branch.to_string())
+        });
+
+        remote.disconnect()?;
+
+        Ok(branch)
+    }
+
     /// Prune remote tracking branches
     pub fn prune_remote(&self, name: &str) -> Result<Vec<String>, GitRemoteError> {
         let mut remote = self
@@ -437,9 +526,8 @@ impl<'repo> GitRemoteManager<'repo> {
 
     /// Get optimal URL format based on GitUrl capabilities
     fn get_optimal_url_format(&self, url: &GitUrl) -> String {
-        // For most cases, use the normalized HTTPS URL
-        // In production, you might want to prefer SSH for authenticated operations
-        url.to_https_url()
+        // Keep the protocol the user asked for; SSH-only servers reject HTTPS
+        url.to_clone_url()
     }
 }
 
@@ -574,6 +662,18 @@ mod tests {
         assert!(remote_info.is_default);
     }
 
+    #[test]
+    fn test_add_remote_keeps_ssh_url() {
+        let (_temp_dir, repo) = create_test_repo();
+        let manager = GitRemoteManager::new(&repo);
+        let url = GitUrl::new("git@github.com:example/repo.git").unwrap();
+
+        manager.add_remote("origin", &url).unwrap();
+
+        let remote = repo.find_remote("origin").unwrap();
+        assert_eq!(remote.url(), Some("git@github.com:example/repo.git"));
+    }
+
     #[test]
     fn test_add_duplicate_remote() {
         let (_temp_dir, repo) = create_test_repo();
@@ -645,6 +745,117 @@ mod tests {
         assert_eq!(suggested, "example1");
     }
 
+    #[test]
+    fn test_get_remote_tip() {
+        let (temp_dir, repo) = create_test_repo();
+        let remote_path = temp_dir.path().join("remote.git");
+        let remote_repo = Git2Repository::init_bare(&remote_path).unwrap();
+
+        let signature = git2::Signature::now("wmgr", "wmgr@example.com").unwrap();
+        let tree_id = remote_repo.treebuilder(None).unwrap().write().unwrap();
+        let tree = remote_repo.find_tree(tree_id).unwrap();
+        let commit_id = remote_repo
+            .commit(
+                Some("refs/heads/main"),
+                &signature,
+                &signature,
+                "initial",
+                &tree,
+                &[],
+            )
+            .unwrap();
+
+        repo.remote("origin", remote_path.to_str().unwrap())
+            .unwrap();
+        let manager = GitRemoteManager::new(&repo);
+
+        assert_eq!(
+            manager.get_remote_tip("origin", "main").unwrap(),
+            Some(commit_id.to_string())
+        );
+        assert_eq!(manager.get_remote_tip("origin", "missing").unwrap(), None);
+        assert!(matches!(
+            manager.get_remote_tip("upstream", "main"),
+            Err(GitRemoteError::RemoteNotFound(_))
+        ));
+    }
+
+    /// A bare repository with one commit on `branch`, which its HEAD points to
+    fn create_bare_remote(path: &std::path::Path, branch: &str) -> Git2Repository {
+        let remote_repo = Git2Repository::init_bare(path).unwrap();
+        {
+            let signature = git2::Signature::now("wmgr", "wmgr@example.com").unwrap();
+            let tree_id = remote_repo.treebuilder(None).unwrap().write().unwrap();
+            let tree = remote_repo.find_tree(tree_id).unwrap();
+            let branch_ref = format!("refs/heads/{}", branch);
+            remote_repo
+                .commit(
+                    Some(&branch_ref),
+                    &signature,
+                    &signature,
+                    "initial",
+                    &tree,
+                    &[],
+                )
+                .unwrap();
+            remote_repo.set_head(&branch_ref).unwrap();
+        }
+        remote_repo
+    }
+
+    #[test]
+    fn test_get_remote_head_branch_from_remote() {
+        let (temp_dir, repo) = create_test_repo();
+        let remote_path = temp_dir.path().join("remote.git");
+        create_bare_remote(&remote_path, "master");
+        repo.remote("origin", remote_path.to_str().unwrap())
+            .unwrap();
+        let manager = GitRemoteManager::new(&repo);
+
+        assert_eq!(
+            manager.get_remote_head_branch("origin").unwrap().as_deref(),
+            Some("master")
+        );
+        assert!(matches!(
+            manager.get_remote_head_branch("upstream"),
+            Err(GitRemoteError::RemoteNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_get_remote_head_branch_prefers_recorded_head() {
+        let (temp_dir, repo) = create_test_repo();
+        let remote_path = temp_dir.path().join("remote.git");
+        create_bare_remote(&remote_path, "master");
+        repo.remote("origin", remote_path.to_str().unwrap())
+            .unwrap();
+        repo.reference_symbolic(
+            "refs/remotes/origin/HEAD",
+            "refs/remotes/origin/develop",
+            true,
+            "recorded at clone time",
+        )
+        .unwrap();
+        let manager = GitRemoteManager::new(&repo);
+
+        assert_eq!(
+            manager.get_remote_head_branch("origin").unwrap().as_deref(),
+            Some("develop")
+        );
+    }
+
+    #[test]
+    fn test_get_remote_head_branch_of_empty_remote() {
+        let (temp_dir, repo) = create_test_repo();
+        let remote_path = temp_dir.path().join("remote.git");
+        Git2Repository::init_bare(&remote_path).unwrap();
+        repo.remote("origin", remote_path.to_str().unwrap())
+            .unwrap();
+        let manager = GitRemoteManager::new(&repo);
+
+        assert_eq!(manager.get_remote_head_branch("origin").unwrap(), None);
+    }
+
     #[test]
     fn test_domain_remote_conversion() {
         let url = GitUrl::new("https://github.com/example/repo.git").unwrap();
@@ -652,6 +863,6 @@ mod tests {
         let domain_remote = remote_info.to_domain_remote();
 
         assert_eq!(domain_remote.name, "origin");
-        assert_eq!(domain_remote.url, url.as_str());
+        assert_eq!(domain_remote.url, url.to_clone_url());
     }
 }
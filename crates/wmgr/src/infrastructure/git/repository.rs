@@ -5,9 +5,10 @@ use crate::domain::value_objects::{
 };
 use git2::{
     build::CheckoutBuilder, BranchType, Cred, CredentialType, FetchOptions, Oid, RemoteCallbacks,
-    Repository as Git2Repository, ResetType,
+    Repository as Git2Repository, ResetType, SubmoduleUpdateOptions,
 };
 use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 use thiserror::Error;
 
 /// Git repository operations related errors
@@ -46,6 +47,21 @@ pub enum GitRepositoryError {
     #[error("Authentication failed: {0}")]
     AuthenticationFailed(String),
 
+    #[error("Git push failed: {0}")]
+    PushFailed(String),
+
+    #[error("Push rejected (non-fast-forward): {0}")]
+    PushRejected(String),
+
+    #[error("Rebase conflict in: {0}")]
+    RebaseConflict(String),
+
+    #[error("Merge conflict in: {0}")]
+    MergeConflict(String),
+
+    #[error("Submodule update failed for {path}: {error}")]
+    SubmoduleUpdateFailed { path: String, error: String },
+
     #[error("Working directory has uncommitted changes")]
     WorkingDirectoryDirty,
 
@@ -147,6 +163,52 @@ impl Default for FetchConfig {
     }
 }
 
+/// Push options for repository pushing
+#[derive(Debug, Clone, Default)]
+pub struct PushConfig {
+    /// Whether to force the push (overwrite remote history)
+    pub force: bool,
+
+    /// Username for HTTP(S) authentication
+    pub username: Option<String>,
+
+    /// Password or token for HTTP(S) authentication
+    pub password: Option<String>,
+}
+
+/// Resolved destination of a push for the current branch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PushTarget {
+    /// Remote name to push to
+    pub remote_name: String,
+
+    /// Local branch being pushed
+    pub local_branch: String,
+
+    /// Branch name on the remote side
+    pub remote_branch: String,
+
+    /// Whether the target was taken from the configured upstream
+    pub has_upstream: bool,
+}
+
+impl PushTarget {
+    /// Refspec used to push the local branch to the remote branch
+    pub fn refspec(&self, force: bool) -> String {
+        format!(
+            "{}refs/heads/{}:refs/heads/{}",
+            if force { "+" } else { "" },
+            self.local_branch,
+            self.remote_branch
+        )
+    }
+
+    /// Remote tracking reference for the remote branch
+    pub fn tracking_ref(&self) -> String {
+        format!("refs/remotes/{}/{}", self.remote_name, self.remote_branch)
+    }
+}
+
 /// Wrapper around git2::Repository with high-level operations
 pub struct GitRepository {
     /// The underlying git2 repository
@@ -254,7 +316,7 @@ impl GitRepository {
         }
 
         // Convert GitUrl to appropriate format for git2
-        let clone_url = url.to_https_url(); // Start with HTTPS, fallback to SSH if needed
+        let clone_url = url.to_clone_url();
 
         // Perform the clone
         let repo = builder.clone(&clone_url, &target_path_buf)?;
@@ -302,18 +364,278 @@ impl GitRepository {
         Ok(())
     }
 
+    /// Initialize and update all submodules recursively
+    ///
+    /// Missing submodules are cloned and every submodule is checked out at the
+    /// commit recorded in its parent repository. Returns the paths of the
+    /// updated submodules relative to this repository.
+    pub fn update_submodules(&self) -> Result<Vec<String>, GitRepositoryError> {
+        let mut updated = Vec::new();
+        Self::update_submodules_in(&self.repo, Path::new(""), &mut updated)?;
+        Ok(updated)
+    }
+
+    fn update_submodules_in(
+        repo: &Git2Repository,
+        prefix: &Path,
+        updated: &mut Vec<String>,
+    ) -> Result<(), GitRepositoryError> {
+        for mut submodule in repo.submodules()? {
+            let path = prefix.join(submodule.path());
+            let failed = |e: git2::Error| GitRepositoryError::SubmoduleUpdateFailed {
+                path: path.display().to_string(),
+                error: e.message().to_string(),
+            };
+
+            let mut callbacks = RemoteCallbacks::new();
+            callbacks.credentials(|_url, username_from_url, allowed_types| {
+                if allowed_types.contains(CredentialType::SSH_KEY) {
+                    Cred::ssh_key_from_agent(username_from_url.unwrap_or("git"))
+                } else {
+                    Err(git2::Error::from_str("Authentication not supported"))
+                }
+            });
+            let mut fetch_options = FetchOptions::new();
+            fetch_options.remote_callbacks(callbacks);
+            let mut options = SubmoduleUpdateOptions::new();
+            options.fetch(fetch_options);
+
+            submodule.update(true, Some(&mut options)).map_err(failed)?;
+            let submodule_repo = submodule.open().map_err(failed)?;
+            updated.push(path.display().to_string());
+
+            Self::update_submodules_in(&submodule_repo, &path, updated)?;
+        }
+        Ok(())
+    }
+
+    /// Resolve where the current branch should be pushed
+    ///
+    /// Uses the configured upstream of the current branch, falling back to
+    /// `origin` and the current branch name when no upstream is set.
+    pub fn push_target(&self) -> Result<PushTarget, GitRepositoryError> {
+        let head = self.repo.head()?;
+        if !head.is_branch() {
+            return Err(GitRepositoryError::BranchNotFound(
+                "HEAD is detached".to_string(),
+            ));
+        }
+
+        let local_branch = head
+            .shorthand()
+            .ok_or_else(|| GitRepositoryError::BranchNotFound("HEAD".to_string()))?
+            .to_string();
+        let local_ref = format!("refs/heads/{}", local_branch);
+
+        let upstream_remote = self
+            .repo
+            .branch_upstream_remote(&local_ref)
+            .ok()
+            .and_then(|buf| buf.as_str().map(|s| s.to_string()));
+        let upstream_merge = self
+            .repo
+            .config()?
+            .get_string(&format!("branch.{}.merge", local_branch))
+            .ok();
+
+        match (upstream_remote, upstream_merge) {
+            (Some(remote_name), Some(merge_ref)) => Ok(PushTarget {
+                remote_name,
+                remote_branch: merge_ref
+                    .strip_prefix("refs/heads/")
+                    .unwrap_or(&merge_ref)
+                    .to_string(),
+                local_branch,
+                has_upstream: true,
+            }),
+            _ => Ok(PushTarget {
+                remote_name: "origin".to_string(),
+                remote_branch: local_branch.clone(),
+                local_branch,
+                has_upstream: false,
+            }),
+        }
+    }
+
+    /// Upstream tracking branch of the current branch, e.g. `origin/main`
+    ///
+    /// Returns `None` when HEAD is detached or unborn, or when no upstream is
+    /// configured for the current branch.
+    pub fn upstream_branch(&self) -> Option<String> {
+        let upstream = self.current_local_branch()?.upstream().ok()?;
+        upstream.name().ok().flatten().map(|name| name.to_string())
+    }
+
+    /// Remote of the current branch's upstream, e.g. `origin`
+    ///
+    /// Returns `None` in the same cases as [`Self::upstream_branch`].
+    pub fn upstream_remote(&self) -> Option<String> {
+        let branch = self.current_local_branch()?;
+        let local_ref = branch.get().name()?;
+        let remote = self.repo.branch_upstream_remote(local_ref).ok()?;
+        remote.as_str().map(|name| name.to_string())
+    }
+
+    /// Remote that sync fetches from: the current branch's upstream remote,
+    /// or `origin` when there is none
+    ///
+    /// With `singular_remote` configured, sync points `origin` at the mirror.
+    pub fn fetch_remote(&self) -> String {
+        self.upstream_remote()
+            .unwrap_or_else(|| "origin".to_string())
+    }
+
+    /// Commits ahead/behind `<remote>/<current-branch>`, regardless of the
+    /// configured upstream
+    ///
+    /// Fails when HEAD is not on a branch, the remote does not exist or the
+    /// remote branch has not been fetched.
+    pub fn ahead_behind_remote(&self, remote: &str) -> Result<(usize, usize), GitRepositoryError> {
+        let branch = self.current_local_branch().ok_or_else(|| {
+            GitRepositoryError::GitOperationFailed("HEAD is not on a branch".to_string())
+        })?;
+        self.repo
+            .find_remote(remote)
+            .map_err(|_| GitRepositoryError::RemoteNotFound(remote.to_string()))?;
+
+        let branch_name = branch.name()?.unwrap_or_default().to_string();
+        let remote_branch = format!("{}/{}", remote, branch_name);
+        let remote_oid = self
+            .repo
+            .find_reference(&format!("refs/remotes/{}", remote_branch))
+            .and_then(|r| r.peel_to_commit())
+            .map_err(|_| GitRepositoryError::BranchNotFound(remote_branch))?
+            .id();
+        let local_oid = branch.get().peel_to_commit()?.id();
+
+        Ok(self.repo.graph_ahead_behind(local_oid, remote_oid)?)
+    }
+
+    /// Count commits on the local branch that are not on the remote branch
+    ///
+    /// Returns `None` when the remote branch is not known locally yet, i.e. the
+    /// push would create it.
+    pub fn count_unpushed_commits(
+        &self,
+        target: &PushTarget,
+    ) -> Result<Option<usize>, GitRepositoryError> {
+        let local_oid = self
+            .repo
+            .find_reference(&format!("refs/heads/{}", target.local_branch))?
+            .peel_to_commit()?
+            .id();
+
+        let remote_oid = match self.repo.find_reference(&target.tracking_ref()) {
+            Ok(reference) => reference.peel_to_commit()?.id(),
+            Err(_) => return Ok(None),
+        };
+
+        let (ahead, _behind) = self.repo.graph_ahead_behind(local_oid, remote_oid)?;
+        Ok(Some(ahead))
+    }
+
+    /// Push the local branch to its remote
+    pub fn push(&self, target: &PushTarget, config: &PushConfig) -> Result<(), GitRepositoryError> {
+        let mut remote = self
+            .repo
+            .find_remote(&target.remote_name)
+            .map_err(|_| GitRepositoryError::RemoteNotFound(target.remote_name.clone()))?;
+
+        let git_config = self.repo.config()?;
+        let rejections = std::cell::RefCell::new(Vec::new());
+        let mut attempts = 0;
+
+        let mut callbacks = RemoteCallbacks::new();
+
+        // Set up authentication
+        callbacks.credentials(|url, username_from_url, allowed_types| {
+            // libgit2 keeps asking while credentials are refused
+            attempts += 1;
+            if attempts > 3 {
+                return Err(git2::Error::from_str("Authentication failed"));
+            }
+
+            if allowed_types.contains(CredentialType::SSH_KEY) {
+                Cred::ssh_key_from_agent(username_from_url.unwrap_or("git"))
+            } else if allowed_types.contains(CredentialType::USER_PASS_PLAINTEXT) {
+                match (&config.username, &config.password) {
+                    (Some(username), Some(password)) => {
+                        Cred::userpass_plaintext(username, password)
+                    }
+                    (None, Some(token)) => Cred::userpass_plaintext(
+                        username_from_url.unwrap_or("x-access-token"),
+                        token,
+                    ),
+                    _ => Cred::credential_helper(&git_config, url, username_from_url),
+                }
+            } else if allowed_types.contains(CredentialType::DEFAULT) {
+                Cred::default()
+            } else {
+                Err(git2::Error::from_str("No supported authentication method"))
+            }
+        });
+
+        // Collect references rejected by the remote
+        callbacks.push_update_reference(|refname, status| {
+            if let Some(message) = status {
+                rejections
+                    .borrow_mut()
+                    .push(format!("{}: {}", refname, message));
+            }
+            Ok(())
+        });
+
+        {
+            let mut push_options = git2::PushOptions::new();
+            push_options.remote_callbacks(callbacks);
+
+            let refspec = target.refspec(config.force);
+            remote
+                .push(&[refspec.as_str()], Some(&mut push_options))
+                .map_err(|e| match e.code() {
+                    git2::ErrorCode::NotFastForward => {
+                        GitRepositoryError::PushRejected(e.message().to_string())
+                    }
+                    git2::ErrorCode::Auth => {
+                        GitRepositoryError::AuthenticationFailed(e.message().to_string())
+                    }
+                    _ => GitRepositoryError::PushFailed(e.message().to_string()),
+                })?;
+        }
+
+        let rejections = rejections.into_inner();
+        if !rejections.is_empty() {
+            return Err(GitRepositoryError::PushRejected(rejections.join(", ")));
+        }
+
+        Ok(())
+    }
+
     /// Checkout a specific branch or commit
     pub fn checkout(&self, target: &str) -> Result<(), GitRepositoryError> {
         // Try to find the reference
-        let reference = self
+        let reference = match self
             .repo
             .find_reference(target)
             .or_else(|_| self.repo.find_reference(&format!("refs/heads/{}", target)))
             .or_else(|_| {
                 self.repo
                     .find_reference(&format!("refs/remotes/origin/{}", target))
-            })
-            .map_err(|_| GitRepositoryError::BranchNotFound(target.to_string()))?;
+            }) {
+            Ok(reference) => reference,
+            Err(_) => {
+                // Fall back to a full commit id, checked out as a detached HEAD
+                let commit = Oid::from_str(target)
+                    .and_then(|oid| self.repo.find_commit(oid))
+                    .map_err(|_| GitRepositoryError::BranchNotFound(target.to_string()))?;
+                let mut checkout_builder = CheckoutBuilder::new();
+                checkout_builder.safe();
+                self.repo
+                    .checkout_tree(commit.as_object(), Some(&mut checkout_builder))?;
+                self.repo.set_head_detached(commit.id())?;
+                return Ok(());
+            }
+        };
 
         let commit = reference.peel_to_commit()?;
 
@@ -409,7 +731,7 @@ impl GitRepository {
         let head_commit = head.peel_to_commit()?;
 
         // Find the upstream branch
-        let upstream_ref = format!("refs/remotes/origin/{}", branch_name);
+        let upstream_ref = self.tracking_ref_for(branch_name);
         let upstream = self
             .repo
             .find_reference(&upstream_ref)
@@ -427,18 +749,166 @@ impl GitRepository {
             ));
         }
 
+        // Update working directory before moving HEAD so the safe checkout
+        // compares against the current tree
+        let mut checkout_builder = CheckoutBuilder::new();
+        checkout_builder.safe();
+        self.repo
+            .checkout_tree(upstream_commit.as_object(), Some(&mut checkout_builder))?;
+
         // Perform fast-forward
         let mut head_ref = self.repo.head()?;
         head_ref.set_target(upstream_commit.id(), "Fast-forward merge")?;
 
-        // Update working directory
-        let mut checkout_builder = CheckoutBuilder::new();
-        checkout_builder.safe();
-        self.repo.checkout_head(Some(&mut checkout_builder))?;
-
         Ok(())
     }
 
+    /// Rebase local commits onto the upstream branch
+    ///
+    /// Fast-forwards when the local branch has no commits of its own. The
+    /// working directory must be clean; on conflict the rebase is aborted and
+    /// the repository is left as it was.
+    pub fn rebase_onto_upstream(
+        &self,
+        branch_name: &str,
+    ) -> Result<RebaseOutcome, GitRepositoryError> {
+        if !self.is_working_directory_clean()? {
+            return Err(GitRepositoryError::WorkingDirectoryDirty);
+        }
+
+        let upstream_ref = self.tracking_ref_for(branch_name);
+        let upstream = self
+            .repo
+            .find_reference(&upstream_ref)
+            .map_err(|_| GitRepositoryError::BranchNotFound(upstream_ref))?;
+        let upstream_oid = upstream.peel_to_commit()?.id();
+        let head = self.repo.head()?;
+        let head_oid = head.peel_to_commit()?.id();
+
+        if head_oid == upstream_oid || self.repo.graph_descendant_of(head_oid, upstream_oid)? {
+            return Ok(RebaseOutcome::UpToDate);
+        }
+
+        if self.repo.graph_descendant_of(upstream_oid, head_oid)? {
+            self.fast_forward_merge(branch_name)?;
+            return Ok(RebaseOutcome::FastForwarded);
+        }
+
+        let branch = self.repo.reference_to_annotated_commit(&head)?;
+        let onto = self.repo.reference_to_annotated_commit(&upstream)?;
+        let mut rebase = self.repo.rebase(Some(&branch), Some(&onto), None, None)?;
+        let mut rebased = 0;
+
+        while let Some(operation) = rebase.next() {
+            let operation = match operation {
+                Ok(operation) => operation,
+                Err(e) => {
+                    rebase.abort()?;
+                    return Err(GitRepositoryError::MergeFailed(e.message().to_string()));
+                }
+            };
+
+            let conflicts = self.conflicted_paths()?;
+            if !conflicts.is_empty() {
+                rebase.abort()?;
+                return Err(GitRepositoryError::RebaseConflict(conflicts.join(", ")));
+            }
+
+            // Keep the original committer when no identity is configured
+            let original = self.repo.find_commit(operation.id())?;
+            let committer = self
+                .repo
+                .signature()
+                .unwrap_or_else(|_| original.committer().to_owned());
+
+            match rebase.commit(None, &committer, None) {
+                Ok(_) => rebased += 1,
+                // The change is already upstream; nothing left to commit
+                Err(e) if e.code() == git2::ErrorCode::Applied => {}
+                Err(e) => {
+                    rebase.abort()?;
+                    return Err(GitRepositoryError::MergeFailed(e.message().to_string()));
+                }
+            }
+        }
+
+        rebase.finish(None)?;
+
+        Ok(RebaseOutcome::Rebased(rebased))
+    }
+
+    /// Merge the upstream branch into the local branch
+    ///
+    /// Fast-forwards when possible and otherwise creates a merge commit. The
+    /// working directory must be clean; on conflict the merge is aborted and
+    /// the repository is left as it was.
+    pub fn merge_upstream(&self, branch_name: &str) -> Result<MergeOutcome, GitRepositoryError> {
+        if !self.is_working_directory_clean()? {
+            return Err(GitRepositoryError::WorkingDirectoryDirty);
+        }
+
+        let upstream_ref = self.tracking_ref_for(branch_name);
+        let upstream = self
+            .repo
+            .find_reference(&upstream_ref)
+            .map_err(|_| GitRepositoryError::BranchNotFound(upstream_ref))?;
+        let upstream_commit = upstream.peel_to_commit()?;
+        let head_commit = self.repo.head()?.peel_to_commit()?;
+
+        if head_commit.id() == upstream_commit.id()
+            || self
+                .repo
+                .graph_descendant_of(head_commit.id(), upstream_commit.id())?
+        {
+            return Ok(MergeOutcome::UpToDate);
+        }
+
+        if self
+            .repo
+            .graph_descendant_of(upstream_commit.id(), head_commit.id())?
+        {
+            self.fast_forward_merge(branch_name)?;
+            return Ok(MergeOutcome::FastForwarded);
+        }
+
+        let annotated = self.repo.reference_to_annotated_commit(&upstream)?;
+        self.repo.merge(&[&annotated], None, None)?;
+
+        let conflicts = self.conflicted_paths()?;
+        if !conflicts.is_empty() {
+            // Abort the merge and restore the previous state
+            self.repo.cleanup_state()?;
+            self.repo
+                .reset(head_commit.as_object(), ResetType::Hard, None)?;
+            return Err(GitRepositoryError::MergeConflict(conflicts.join(", ")));
+        }
+
+        let mut index = self.repo.index()?;
+        let tree = self.repo.find_tree(index.write_tree()?)?;
+
+        // Fall back to the local committer when no identity is configured
+        let signature = self
+            .repo
+            .signature()
+            .unwrap_or_else(|_| head_commit.committer().to_owned());
+        let message = format!(
+            "Merge remote-tracking branch 'origin/{}' into {}",
+            branch_name, branch_name
+        );
+
+        self.repo.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            &message,
+            &tree,
+            &[&head_commit, &upstream_commit],
+        )?;
+        self.repo.cleanup_state()?;
+
+        Ok(MergeOutcome::Merged)
+    }
+
     /// Get repository status
     pub fn status(&self) -> Result<RepositoryStatus, GitRepositoryError> {
         // Get current branch
@@ -500,6 +970,44 @@ impl GitRepository {
         }
     }
 
+    /// Full SHA of the commit currently checked out
+    pub fn head_commit_id(&self) -> Result<String, GitRepositoryError> {
+        Ok(self.repo.head()?.peel_to_commit()?.id().to_string())
+    }
+
+    /// Count commits reachable from HEAD but not from `revision`
+    ///
+    /// Returns `None` when `revision` does not resolve in this repository.
+    pub fn count_commits_since_revision(
+        &self,
+        revision: &str,
+    ) -> Result<Option<usize>, GitRepositoryError> {
+        let base = match self.repo.revparse_single(revision) {
+            Ok(object) => object.peel_to_commit()?.id(),
+            Err(_) => return Ok(None),
+        };
+
+        let mut revwalk = self.repo.revwalk()?;
+        revwalk.push_head()?;
+        revwalk.hide(base)?;
+        let commits = revwalk.collect::<Result<Vec<_>, _>>()?;
+        Ok(Some(commits.len()))
+    }
+
+    /// Count commits reachable from HEAD that were committed after `timestamp` (Unix seconds)
+    pub fn count_commits_since_time(&self, timestamp: i64) -> Result<usize, GitRepositoryError> {
+        let mut revwalk = self.repo.revwalk()?;
+        revwalk.push_head()?;
+
+        let mut count = 0;
+        for oid in revwalk {
+            if self.repo.find_commit(oid?)?.time().seconds() > timestamp {
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
     /// List all branches
     pub fn list_branches(
         &self,
@@ -524,12 +1032,131 @@ impl GitRepository {
         Ok(branch_names)
     }
 
+    /// Full name of the branch HEAD points at (`refs/heads/main`), or the commit id when detached
+    ///
+    /// The result can be passed to [`GitRepository::checkout`] to return to the current HEAD.
+    pub fn head_name(&self) -> Result<String, GitRepositoryError> {
+        let head = self.repo.head()?;
+        if head.is_branch() {
+            if let Some(name) = head.name() {
+                return Ok(name.to_string());
+            }
+        }
+        Ok(head.peel_to_commit()?.id().to_string())
+    }
+
+    /// Number of local branches
+    pub fn local_branch_count(&self) -> Result<usize, GitRepositoryError> {
+        Ok(self.repo.branches(Some(BranchType::Local))?.count())
+    }
+
+    /// When the repository was last fetched, from the modification time of `FETCH_HEAD`
+    ///
+    /// Returns `None` when the repository has never been fetched.
+    pub fn last_fetch_time(&self) -> Option<SystemTime> {
+        std::fs::metadata(self.repo.path().join("FETCH_HEAD"))
+            .and_then(|metadata| metadata.modified())
+            .ok()
+    }
+
+    /// Estimated on-disk size of the repository in bytes
+    ///
+    /// The git directory is measured and tracked files are counted with the sizes
+    /// recorded in the index, so the working tree is never walked. Untracked and
+    /// ignored files (such as build output) are not included.
+    pub fn estimated_disk_size(&self) -> Result<u64, GitRepositoryError> {
+        let git_dir_size: u64 = walkdir::WalkDir::new(self.repo.path())
+            .into_iter()
+            .filter_map(|entry| entry.ok()?.metadata().ok())
+            .filter(|metadata| metadata.is_file())
+            .map(|metadata| metadata.len())
+            .sum();
+        let tracked_size: u64 = self
+            .repo
+            .index()?
+            .iter()
+            .map(|entry| u64::from(entry.file_size))
+            .sum();
+        Ok(git_dir_size + tracked_size)
+    }
+
+    /// Whether a branch named `name` exists
+    ///
+    /// Remote tracking branches are named with their remote, e.g. `origin/main`.
+    pub fn has_branch(&self, name: &str, branch_type: GitBranchType) -> bool {
+        let branch_type = match branch_type {
+            GitBranchType::Local => BranchType::Local,
+            GitBranchType::Remote => BranchType::Remote,
+        };
+        self.repo.find_branch(name, branch_type).is_ok()
+    }
+
+    /// Set the upstream of a local branch, e.g. `origin/main`
+    pub fn set_upstream(
+        &self,
+        branch_name: &str,
+        upstream: &str,
+    ) -> Result<(), GitRepositoryError> {
+        self.repo
+            .find_branch(branch_name, BranchType::Local)
+            .map_err(|_| GitRepositoryError::BranchNotFound(branch_name.to_string()))?
+            .set_upstream(Some(upstream))?;
+        Ok(())
+    }
+
     /// Check if working directory has uncommitted changes
     pub fn is_working_directory_clean(&self) -> Result<bool, GitRepositoryError> {
         let statuses = self.repo.statuses(None)?;
         Ok(statuses.is_empty())
     }
 
+    /// Check if the repository holds work that exists nowhere else
+    ///
+    /// True when there is a stash, or when a local branch, tag or detached HEAD
+    /// points at a commit that no remote-tracking ref contains. Such work is lost
+    /// if the repository is deleted even though the working directory is clean.
+    pub fn has_unpushed_work(&self) -> Result<bool, GitRepositoryError> {
+        if self.repo.find_reference("refs/stash").is_ok() {
+            return Ok(true);
+        }
+
+        let mut remote_oids = Vec::new();
+        let mut local_oids = Vec::new();
+        for reference in self.repo.references()? {
+            let reference = reference?;
+            let Some(name) = reference.name() else {
+                continue;
+            };
+            let Ok(commit) = reference.peel_to_commit() else {
+                continue;
+            };
+            if name.starts_with("refs/remotes/") {
+                remote_oids.push(commit.id());
+            } else if name.starts_with("refs/heads/") || name.starts_with("refs/tags/") {
+                local_oids.push(commit.id());
+            }
+        }
+        if self.repo.head_detached().unwrap_or(false) {
+            if let Ok(head) = self.repo.head().and_then(|head| head.peel_to_commit()) {
+                local_oids.push(head.id());
+            }
+        }
+
+        for local in local_oids {
+            let mut contained = false;
+            for remote in &remote_oids {
+                if *remote == local || self.repo.graph_descendant_of(*remote, local)? {
+                    contained = true;
+                    break;
+                }
+            }
+            if !contained {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
     /// Get repository path
     pub fn path(&self) -> &Path {
         &self.path
@@ -542,14 +1169,53 @@ impl GitRepository {
 
     // Private helper methods
 
-    /// Calculate ahead/behind commits for current branch
+    /// Local branch checked out at HEAD, if any
+    fn current_local_branch(&self) -> Option<git2::Branch<'_>> {
+        let head = self.repo.head().ok()?;
+        if !head.is_branch() {
+            return None;
+        }
+        let name = head.shorthand()?;
+        self.repo.find_branch(name, BranchType::Local).ok()
+    }
+
+    /// Remote tracking reference to sync `branch_name` with
+    ///
+    /// Uses the branch's configured upstream, falling back to the branch of the
+    /// same name on `origin`.
+    fn tracking_ref_for(&self, branch_name: &str) -> String {
+        self.repo
+            .find_branch(branch_name, BranchType::Local)
+            .and_then(|branch| branch.upstream())
+            .ok()
+            .and_then(|upstream| upstream.get().name().map(|name| name.to_string()))
+            .unwrap_or_else(|| format!("refs/remotes/origin/{}", branch_name))
+    }
+
+    /// Paths with unresolved conflicts in the index
+    fn conflicted_paths(&self) -> Result<Vec<String>, GitRepositoryError> {
+        let index = self.repo.index()?;
+        let mut paths = Vec::new();
+
+        if index.has_conflicts() {
+            for conflict in index.conflicts()? {
+                let conflict = conflict?;
+                if let Some(entry) = conflict.our.or(conflict.their).or(conflict.ancestor) {
+                    paths.push(String::from_utf8_lossy(&entry.path).to_string());
+                }
+            }
+        }
+
+        Ok(paths)
+    }
+
+    /// Calculate ahead/behind commits for current branch against its upstream
     fn calculate_ahead_behind(
         &self,
         branch_name: &str,
     ) -> Result<(usize, usize), GitRepositoryError> {
-        // Simplified implementation - in a real scenario, you'd want more sophisticated logic
         let local_ref = format!("refs/heads/{}", branch_name);
-        let remote_ref = format!("refs/remotes/origin/{}", branch_name);
+        let remote_ref = self.tracking_ref_for(branch_name);
 
         let local_commit = self
             .repo
@@ -565,13 +1231,7 @@ impl GitRepository {
 
         match (local_commit, remote_commit) {
             (Ok(local_oid), Ok(remote_oid)) => {
-                if local_oid == remote_oid {
-                    Ok((0, 0))
-                } else {
-                    // Simplified: just check if they're different
-                    // TODO: Implement proper ahead/behind calculation using commit walking
-                    Ok((1, 0)) // Placeholder
-                }
+                Ok(self.repo.graph_ahead_behind(local_oid, remote_oid)?)
             }
             _ => Ok((0, 0)),
         }
@@ -589,6 +1249,28 @@ pub enum ResetMode {
     Hard,
 }
 
+/// Outcome of rebasing onto the upstream branch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RebaseOutcome {
+    /// Local branch already contains the upstream
+    UpToDate,
+    /// Local branch had no commits of its own and was fast-forwarded
+    FastForwarded,
+    /// Local commits were replayed on top of the upstream
+    Rebased(usize),
+}
+
+/// Outcome of merging the upstream branch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeOutcome {
+    /// Local branch already contains the upstream
+    UpToDate,
+    /// Local branch had no commits of its own and was fast-forwarded
+    FastForwarded,
+    /// A merge commit was created
+    Merged,
+}
+
 /// Git branch types
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum GitBranchType {
@@ -668,4 +1350,517 @@ mod tests {
     fn test_branch_types() {
         assert_ne!(GitBranchType::Local, GitBranchType::Remote);
     }
+
+    fn commit_file(repo: &Git2Repository, name: &str, content: &str) -> Oid {
+        let workdir = repo.workdir().unwrap();
+        std::fs::write(workdir.join(name), content).unwrap();
+
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new(name)).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let signature = git2::Signature::now("wmgr", "wmgr@example.com").unwrap();
+
+        let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+        let parents: Vec<&git2::Commit> = parent.iter().collect();
+        repo.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            &format!("update {}", name),
+            &tree,
+            &parents,
+        )
+        .unwrap()
+    }
+
+    /// Create a bare remote and a clone of it with one pushed commit on `main`
+    fn setup_remote_and_clone(temp_dir: &TempDir, name: &str) -> GitRepository {
+        let remote_path = temp_dir.path().join("remote.git");
+        if !remote_path.exists() {
+            let seed = GitRepository::init(temp_dir.path().join("seed"), false).unwrap();
+            seed.repo.set_head("refs/heads/main").unwrap();
+            commit_file(&seed.repo, "README", "seed");
+            Git2Repository::init_bare(&remote_path).unwrap();
+            seed.repo
+                .remote("origin", remote_path.to_str().unwrap())
+                .unwrap();
+            let target = seed.push_target().unwrap();
+            seed.push(&target, &PushConfig::default()).unwrap();
+        }
+
+        let clone_path = temp_dir.path().join(name);
+        let repo = git2::build::RepoBuilder::new()
+            .branch("main")
+            .clone(remote_path.to_str().unwrap(), &clone_path)
+            .unwrap();
+        drop(repo);
+        GitRepository::open(&clone_path).unwrap()
+    }
+
+    #[test]
+    fn test_push_target_uses_upstream() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = setup_remote_and_clone(&temp_dir, "work");
+
+        let target = repo.push_target().unwrap();
+        assert_eq!(target.remote_name, "origin");
+        assert_eq!(target.local_branch, "main");
+        assert_eq!(target.remote_branch, "main");
+        assert!(target.has_upstream);
+        assert_eq!(target.refspec(false), "refs/heads/main:refs/heads/main");
+        assert_eq!(target.refspec(true), "+refs/heads/main:refs/heads/main");
+    }
+
+    #[test]
+    fn test_push_target_falls_back_to_origin() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = GitRepository::init(temp_dir.path().join("repo"), false).unwrap();
+        repo.repo.set_head("refs/heads/feature").unwrap();
+        commit_file(&repo.repo, "a.txt", "a");
+
+        let target = repo.push_target().unwrap();
+        assert_eq!(target.remote_name, "origin");
+        assert_eq!(target.remote_branch, "feature");
+        assert!(!target.has_upstream);
+        assert_eq!(repo.count_unpushed_commits(&target).unwrap(), None);
+    }
+
+    #[test]
+    fn test_upstream_branch_of_clone() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = setup_remote_and_clone(&temp_dir, "work");
+
+        assert_eq!(repo.upstream_branch().as_deref(), Some("origin/main"));
+        assert_eq!(repo.upstream_remote().as_deref(), Some("origin"));
+
+        commit_file(&repo.repo, "a.txt", "a");
+        let status = repo.status().unwrap();
+        assert_eq!((status.ahead, status.behind), (1, 0));
+    }
+
+    #[test]
+    fn test_upstream_branch_without_upstream() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = GitRepository::init(temp_dir.path().join("repo"), false).unwrap();
+        repo.repo.set_head("refs/heads/feature").unwrap();
+
+        // Unborn branch
+        assert_eq!(repo.upstream_branch(), None);
+
+        let first = commit_file(&repo.repo, "a.txt", "a");
+        assert_eq!(repo.upstream_branch(), None);
+        assert_eq!(repo.upstream_remote(), None);
+        let status = repo.status().unwrap();
+        assert_eq!((status.ahead, status.behind), (0, 0));
+
+        // Detached HEAD
+        repo.repo.set_head_detached(first).unwrap();
+        assert_eq!(repo.upstream_branch(), None);
+    }
+
+    #[test]
+    fn test_upstream_branch_with_differently_named_remote_branch() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = setup_remote_and_clone(&temp_dir, "work");
+        let head = repo.repo.head().unwrap().peel_to_commit().unwrap();
+        repo.repo.branch("topic", &head, false).unwrap();
+        repo.repo.set_head("refs/heads/topic").unwrap();
+        repo.repo
+            .find_branch("topic", BranchType::Local)
+            .unwrap()
+            .set_upstream(Some("origin/main"))
+            .unwrap();
+
+        assert_eq!(repo.upstream_branch().as_deref(), Some("origin/main"));
+        assert_eq!(repo.tracking_ref_for("topic"), "refs/remotes/origin/main");
+        assert_eq!(repo.tracking_ref_for("other"), "refs/remotes/origin/other");
+
+        commit_file(&repo.repo, "a.txt", "a");
+        commit_file(&repo.repo, "b.txt", "b");
+        let status = repo.status().unwrap();
+        assert_eq!(status.current_branch.as_deref(), Some("topic"));
+        assert_eq!((status.ahead, status.behind), (2, 0));
+    }
+
+    #[test]
+    fn test_ahead_behind_remote() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = setup_remote_and_clone(&temp_dir, "work");
+        let first = commit_file(&repo.repo, "a.txt", "a");
+        repo.repo
+            .remote("upstream", "https://example.com/upstream.git")
+            .unwrap();
+        repo.repo
+            .reference("refs/remotes/upstream/main", first, false, "test")
+            .unwrap();
+        commit_file(&repo.repo, "b.txt", "b");
+
+        assert_eq!(repo.ahead_behind_remote("origin").unwrap(), (2, 0));
+        assert_eq!(repo.ahead_behind_remote("upstream").unwrap(), (1, 0));
+
+        assert!(matches!(
+            repo.ahead_behind_remote("missing"),
+            Err(GitRepositoryError::RemoteNotFound(name)) if name == "missing"
+        ));
+        repo.repo
+            .remote("fork", "https://example.com/fork.git")
+            .unwrap();
+        assert!(matches!(
+            repo.ahead_behind_remote("fork"),
+            Err(GitRepositoryError::BranchNotFound(name)) if name == "fork/main"
+        ));
+    }
+
+    #[test]
+    fn test_checkout_detached_and_back() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = setup_remote_and_clone(&temp_dir, "work");
+        let first = repo.head_commit_id().unwrap();
+        commit_file(&repo.repo, "a.txt", "a");
+        assert_eq!(repo.head_name().unwrap(), "refs/heads/main");
+
+        // Remote tracking branches are checked out detached
+        repo.checkout("refs/remotes/origin/main").unwrap();
+        assert!(repo.repo.head_detached().unwrap());
+        assert_eq!(repo.head_name().unwrap(), first);
+        assert!(!temp_dir.path().join("work/a.txt").exists());
+
+        repo.checkout("refs/heads/main").unwrap();
+        assert_eq!(repo.head_name().unwrap(), "refs/heads/main");
+        assert!(temp_dir.path().join("work/a.txt").exists());
+
+        repo.checkout(&first).unwrap();
+        assert!(repo.repo.head_detached().unwrap());
+        assert!(matches!(
+            repo.checkout("0123456789abcdef0123456789abcdef01234567"),
+            Err(GitRepositoryError::BranchNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_health_metadata() {
+        let temp_dir = TempDir::new().unwrap();
+        let fresh = GitRepository::init(temp_dir.path().join("fresh"), false).unwrap();
+        assert!(fresh.last_fetch_time().is_none());
+        assert_eq!(fresh.local_branch_count().unwrap(), 0);
+
+        let repo = setup_remote_and_clone(&temp_dir, "work");
+        assert_eq!(repo.local_branch_count().unwrap(), 1);
+
+        repo.create_branch(&BranchName::new("feature").unwrap(), None)
+            .unwrap();
+        assert_eq!(repo.local_branch_count().unwrap(), 2);
+
+        fetch_origin(&repo);
+        let fetched = repo.last_fetch_time().unwrap();
+        assert!(fetched.elapsed().unwrap().as_secs() < 60);
+
+        // Tracked files are counted from the index on top of the git directory
+        let before = repo.estimated_disk_size().unwrap();
+        assert!(before > 0);
+        commit_file(&repo.repo, "big.txt", &"x".repeat(10_000));
+        assert!(repo.estimated_disk_size().unwrap() >= before + 10_000);
+
+        // Untracked files are not included
+        let after_commit = repo.estimated_disk_size().unwrap();
+        std::fs::write(
+            temp_dir.path().join("work/untracked.bin"),
+            vec![0u8; 50_000],
+        )
+        .unwrap();
+        assert_eq!(repo.estimated_disk_size().unwrap(), after_commit);
+    }
+
+    #[test]
+    fn test_push_unpushed_commits() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = setup_remote_and_clone(&temp_dir, "work");
+        let target = repo.push_target().unwrap();
+        assert_eq!(repo.count_unpushed_commits(&target).unwrap(), Some(0));
+
+        commit_file(&repo.repo, "a.txt", "a");
+        commit_file(&repo.repo, "b.txt", "b");
+        assert_eq!(repo.count_unpushed_commits(&target).unwrap(), Some(2));
+
+        repo.push(&target, &PushConfig::default()).unwrap();
+        assert_eq!(repo.count_unpushed_commits(&target).unwrap(), Some(0));
+    }
+
+    #[test]
+    fn test_has_unpushed_work() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = setup_remote_and_clone(&temp_dir, "work");
+        assert!(!repo.has_unpushed_work().unwrap());
+
+        // A local-only branch at a pushed commit loses nothing
+        let head = repo.repo.head().unwrap().peel_to_commit().unwrap();
+        repo.repo.branch("topic", &head, false).unwrap();
+        assert!(!repo.has_unpushed_work().unwrap());
+
+        // An unpushed commit, even on a branch that is not checked out
+        let commit = commit_file(&repo.repo, "a.txt", "a");
+        repo.repo
+            .branch("wip", &repo.repo.find_commit(commit).unwrap(), false)
+            .unwrap();
+        repo.repo
+            .reset(head.as_object(), ResetType::Hard, None)
+            .unwrap();
+        assert!(repo.has_unpushed_work().unwrap());
+        repo.repo
+            .find_branch("wip", BranchType::Local)
+            .unwrap()
+            .delete()
+            .unwrap();
+        assert!(!repo.has_unpushed_work().unwrap());
+
+        // A stash
+        let mut git2_repo = Git2Repository::open(repo.path()).unwrap();
+        std::fs::write(repo.path().join("README"), "changed").unwrap();
+        let signature = git2::Signature::now("wmgr", "wmgr@example.com").unwrap();
+        git2_repo.stash_save(&signature, "wip", None).unwrap();
+        assert!(repo.is_working_directory_clean().unwrap());
+        assert!(repo.has_unpushed_work().unwrap());
+    }
+
+    #[test]
+    fn test_push_rejected_non_fast_forward() {
+        let temp_dir = TempDir::new().unwrap();
+        let first = setup_remote_and_clone(&temp_dir, "first");
+        let second = setup_remote_and_clone(&temp_dir, "second");
+
+        commit_file(&first.repo, "a.txt", "first");
+        let target = first.push_target().unwrap();
+        first.push(&target, &PushConfig::default()).unwrap();
+
+        commit_file(&second.repo, "a.txt", "second");
+        let target = second.push_target().unwrap();
+        let result = second.push(&target, &PushConfig::default());
+        assert!(matches!(result, Err(GitRepositoryError::PushRejected(_))));
+
+        let force = PushConfig {
+            force: true,
+            ..Default::default()
+        };
+        assert!(second.push(&target, &force).is_ok());
+    }
+
+    fn fetch_origin(repo: &GitRepository) {
+        repo.repo
+            .find_remote("origin")
+            .unwrap()
+            .fetch::<&str>(&[], None, None)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_rebase_onto_upstream_diverged() {
+        let temp_dir = TempDir::new().unwrap();
+        let upstream = setup_remote_and_clone(&temp_dir, "upstream");
+        let local = setup_remote_and_clone(&temp_dir, "local");
+
+        commit_file(&upstream.repo, "remote.txt", "remote");
+        let target = upstream.push_target().unwrap();
+        upstream.push(&target, &PushConfig::default()).unwrap();
+
+        commit_file(&local.repo, "local.txt", "local");
+        fetch_origin(&local);
+
+        let outcome = local.rebase_onto_upstream("main").unwrap();
+        assert_eq!(outcome, RebaseOutcome::Rebased(1));
+
+        let head = local.repo.head().unwrap().peel_to_commit().unwrap();
+        assert_eq!(head.message(), Some("update local.txt"));
+        let parent = head.parent(0).unwrap();
+        assert_eq!(parent.message(), Some("update remote.txt"));
+        assert!(local.path().join("remote.txt").exists());
+        assert!(local.is_working_directory_clean().unwrap());
+
+        assert_eq!(
+            local.rebase_onto_upstream("main").unwrap(),
+            RebaseOutcome::UpToDate
+        );
+    }
+
+    #[test]
+    fn test_rebase_onto_upstream_fast_forward() {
+        let temp_dir = TempDir::new().unwrap();
+        let upstream = setup_remote_and_clone(&temp_dir, "upstream");
+        let local = setup_remote_and_clone(&temp_dir, "local");
+
+        commit_file(&upstream.repo, "remote.txt", "remote");
+        let target = upstream.push_target().unwrap();
+        upstream.push(&target, &PushConfig::default()).unwrap();
+        fetch_origin(&local);
+
+        assert_eq!(
+            local.rebase_onto_upstream("main").unwrap(),
+            RebaseOutcome::FastForwarded
+        );
+        assert!(local.path().join("remote.txt").exists());
+    }
+
+    #[test]
+    fn test_rebase_onto_upstream_conflict() {
+        let temp_dir = TempDir::new().unwrap();
+        let upstream = setup_remote_and_clone(&temp_dir, "upstream");
+        let local = setup_remote_and_clone(&temp_dir, "local");
+
+        commit_file(&upstream.repo, "README", "remote change");
+        let target = upstream.push_target().unwrap();
+        upstream.push(&target, &PushConfig::default()).unwrap();
+
+        let local_head = commit_file(&local.repo, "README", "local change");
+        fetch_origin(&local);
+
+        let result = local.rebase_onto_upstream("main");
+        match result {
+            Err(GitRepositoryError::RebaseConflict(files)) => assert_eq!(files, "README"),
+            other => panic!("expected rebase conflict, got {:?}", other),
+        }
+
+        // The rebase is aborted and the local branch is untouched
+        assert_eq!(local.repo.state(), git2::RepositoryState::Clean);
+        let head = local.repo.head().unwrap();
+        assert_eq!(head.shorthand(), Some("main"));
+        assert_eq!(head.target(), Some(local_head));
+    }
+
+    #[test]
+    fn test_merge_upstream_diverged() {
+        let temp_dir = TempDir::new().unwrap();
+        let upstream = setup_remote_and_clone(&temp_dir, "upstream");
+        let local = setup_remote_and_clone(&temp_dir, "local");
+
+        commit_file(&upstream.repo, "remote.txt", "remote");
+        let target = upstream.push_target().unwrap();
+        upstream.push(&target, &PushConfig::default()).unwrap();
+
+        let local_head = commit_file(&local.repo, "local.txt", "local");
+        fetch_origin(&local);
+
+        assert_eq!(local.merge_upstream("main").unwrap(), MergeOutcome::Merged);
+
+        let head = local.repo.head().unwrap().peel_to_commit().unwrap();
+        assert_eq!(head.parent_count(), 2);
+        assert_eq!(head.parent_id(0).unwrap(), local_head);
+        assert!(local.path().join("remote.txt").exists());
+        assert!(local.path().join("local.txt").exists());
+        assert_eq!(local.repo.state(), git2::RepositoryState::Clean);
+        assert!(local.is_working_directory_clean().unwrap());
+    }
+
+    #[test]
+    fn test_merge_upstream_conflict() {
+        let temp_dir = TempDir::new().unwrap();
+        let upstream = setup_remote_and_clone(&temp_dir, "upstream");
+        let local = setup_remote_and_clone(&temp_dir, "local");
+
+        commit_file(&upstream.repo, "README", "remote change");
+        commit_file(&upstream.repo, "remote.txt", "remote");
+        let target = upstream.push_target().unwrap();
+        upstream.push(&target, &PushConfig::default()).unwrap();
+
+        let local_head = commit_file(&local.repo, "README", "local change");
+        fetch_origin(&local);
+
+        match local.merge_upstream("main") {
+            Err(GitRepositoryError::MergeConflict(files)) => assert_eq!(files, "README"),
+            other => panic!("expected merge conflict, got {:?}", other),
+        }
+
+        // The merge is aborted and the working tree restored
+        assert_eq!(local.repo.state(), git2::RepositoryState::Clean);
+        assert_eq!(local.repo.head().unwrap().target(), Some(local_head));
+        assert!(!local.path().join("remote.txt").exists());
+        assert!(local.is_working_directory_clean().unwrap());
+    }
+
+    #[test]
+    fn test_rebase_onto_upstream_requires_clean_tree() {
+        let temp_dir = TempDir::new().unwrap();
+        let local = setup_remote_and_clone(&temp_dir, "local");
+        std::fs::write(local.path().join("README"), "dirty").unwrap();
+
+        assert!(matches!(
+            local.rebase_onto_upstream("main"),
+            Err(GitRepositoryError::WorkingDirectoryDirty)
+        ));
+    }
+
+    #[test]
+    fn test_update_submodules_populates_nested_submodules() {
+        let temp_dir = TempDir::new().unwrap();
+        let url = |name: &str| format!("file://{}", temp_dir.path().join(name).display());
+
+        // leaf <- lib <- app
+        let leaf = Git2Repository::init(temp_dir.path().join("leaf")).unwrap();
+        commit_file(&leaf, "LEAF", "leaf");
+        let lib = Git2Repository::init(temp_dir.path().join("lib")).unwrap();
+        commit_file(&lib, "LIB", "lib");
+        add_submodule(&lib, &url("leaf"), "leaf");
+        let app = Git2Repository::init(temp_dir.path().join("app")).unwrap();
+        commit_file(&app, "README", "app");
+        add_submodule(&app, &url("lib"), "vendor/lib");
+
+        let clone_path = temp_dir.path().join("clone");
+        git2::build::RepoBuilder::new()
+            .clone(&url("app"), &clone_path)
+            .unwrap();
+        assert!(!clone_path.join("vendor/lib/LIB").exists());
+
+        let clone = GitRepository::open(&clone_path).unwrap();
+        let updated = clone.update_submodules().unwrap();
+        assert_eq!(updated, vec!["vendor/lib", "vendor/lib/leaf"]);
+        assert!(clone_path.join("vendor/lib/LIB").exists());
+        assert!(clone_path.join("vendor/lib/leaf/LEAF").exists());
+
+        // Updating again is a no-op
+        assert_eq!(clone.update_submodules().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_update_submodules_reports_unreachable_url() {
+        let temp_dir = TempDir::new().unwrap();
+        let app = Git2Repository::init(temp_dir.path().join("app")).unwrap();
+        commit_file(&app, "README", "app");
+        let lib = Git2Repository::init(temp_dir.path().join("lib")).unwrap();
+        commit_file(&lib, "LIB", "lib");
+        let lib_url = format!("file://{}", temp_dir.path().join("lib").display());
+        add_submodule(&app, &lib_url, "lib");
+
+        let clone_path = temp_dir.path().join("clone");
+        git2::build::RepoBuilder::new()
+            .clone(app.path().to_str().unwrap(), &clone_path)
+            .unwrap();
+        std::fs::remove_dir_all(temp_dir.path().join("lib")).unwrap();
+
+        let clone = GitRepository::open(&clone_path).unwrap();
+        match clone.update_submodules() {
+            Err(GitRepositoryError::SubmoduleUpdateFailed { path, .. }) => assert_eq!(path, "lib"),
+            other => panic!("expected submodule update failure, got {:?}", other),
+        }
+    }
+
+    /// Add `url` as a submodule at `path` of `repo` and commit it
+    fn add_submodule(repo: &Git2Repository, url: &str, path: &str) {
+        let mut submodule = repo.submodule(url, Path::new(path), true).unwrap();
+        submodule.clone(None).unwrap();
+        submodule.add_finalize().unwrap();
+
+        let tree = repo
+            .find_tree(repo.index().unwrap().write_tree().unwrap())
+            .unwrap();
+        let signature = git2::Signature::now("wmgr", "wmgr@example.com").unwrap();
+        let parent = repo.head().unwrap().peel_to_commit().unwrap();
+        repo.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            &format!("add {}", path),
+            &tree,
+            &[&parent],
+        )
+        .unwrap();
+    }
 }
@@ -20,6 +20,6 @@ pub use filesystem::{config_store::ConfigStore, manifest_store::ManifestStore};
 pub use git::{GitRemoteManager, GitRepository};
 pub use process::CommandExecutor;
 pub use scm::{
-    scm_factory::ScmFactory,
+    scm_factory::{ScmConstructor, ScmFactory},
     scm_interface::{AsAny, CloneOptions, ScmError, ScmOperations, StatusResult, SyncOptions},
 };
@@ -339,6 +339,9 @@ impl CommandExecutor {
         });
         cmd.stdin(Stdio::null());
 
+        // Make sure a process abandoned on timeout does not keep running
+        cmd.kill_on_drop(true);
+
         // Spawn process
         let child = cmd.spawn().map_err(|e| {
             CommandExecutorError::SpawnFailed(format!("Failed to spawn '{}': {}", command, e))
@@ -0,0 +1,201 @@
+use std::fs;
+use std::path::Path;
+use thiserror::Error;
+
+use crate::common::error::WmgrError;
+
+/// Dotenv parsing errors
+#[derive(Debug, Error)]
+pub enum DotenvError {
+    #[error("Failed to read {path}: {error}")]
+    ReadFailed { path: String, error: String },
+
+    #[error("Invalid line {line}: {message}")]
+    InvalidLine { line: usize, message: String },
+}
+
+impl From<DotenvError> for WmgrError {
+    fn from(error: DotenvError) -> Self {
+        WmgrError::config_error_with_source(error.to_string(), error)
+    }
+}
+
+/// Read and parse a dotenv file
+pub fn load_dotenv(path: &Path) -> Result<Vec<(String, String)>, DotenvError> {
+    let content = fs::read_to_string(path).map_err(|e| DotenvError::ReadFailed {
+        path: path.display().to_string(),
+        error: e.to_string(),
+    })?;
+    parse_dotenv(&content)
+}
+
+/// Parse dotenv-style `KEY=VALUE` lines
+///
+/// Blank lines and lines starting with `#` are skipped, and an optional
+/// leading `export ` is accepted. Unquoted values are trimmed and end at an
+/// inline ` #` comment. Single-quoted values are taken literally; double-quoted
+/// values support the `\n`, `\t`, `\"` and `\\` escapes. Entries are returned
+/// in file order, so a later duplicate key overrides an earlier one when
+/// collected into a map.
+pub fn parse_dotenv(content: &str) -> Result<Vec<(String, String)>, DotenvError> {
+    let mut entries = Vec::new();
+
+    for (index, raw_line) in content.lines().enumerate() {
+        let line = raw_line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let invalid = |message: &str| DotenvError::InvalidLine {
+            line: index + 1,
+            message: message.to_string(),
+        };
+
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| invalid("expected KEY=VALUE"))?;
+
+        let key = key.trim();
+        if !is_valid_key(key) {
+            return Err(invalid(&format!("invalid variable name '{}'", key)));
+        }
+
+        let value = parse_value(value.trim()).map_err(|message| invalid(&message))?;
+        entries.push((key.to_string(), value));
+    }
+
+    Ok(entries)
+}
+
+fn is_valid_key(key: &str) -> bool {
+    let mut chars = key.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn parse_value(value: &str) -> Result<String, String> {
+    let (parsed, rest) = match value.chars().next() {
+        Some('\'') => {
+            let end = value[1..]
+                .find('\'')
+                .ok_or_else(|| "unterminated single-quoted value".to_string())?;
+            (value[1..1 + end].to_string(), &value[end + 2..])
+        }
+        Some('"') => {
+            let mut parsed = String::new();
+            let mut chars = value[1..].char_indices();
+            let mut end = None;
+            while let Some((i, c)) = chars.next() {
+                match c {
+                    '"' => {
+                        end = Some(i + 2);
+                        break;
+                    }
+                    '\\' => match chars.next() {
+                        Some((_, 'n')) => parsed.push('\n'),
+                        Some((_, 't')) => parsed.push('\t'),
+                        Some((_, other)) => parsed.push(other),
+                        None => break,
+                    },
+                    other => parsed.push(other),
+                }
+            }
+            let end = end.ok_or_else(|| "unterminated double-quoted value".to_string())?;
+            (parsed, &value[end..])
+        }
+        _ => {
+            let unquoted = match value.find(" #").or_else(|| value.find("\t#")) {
+                Some(comment) => &value[..comment],
+                None => value,
+            };
+            return Ok(unquoted.trim_end().to_string());
+        }
+    };
+
+    let rest = rest.trim_start();
+    if !rest.is_empty() && !rest.starts_with('#') {
+        return Err(format!(
+            "unexpected characters after quoted value: '{}'",
+            rest
+        ));
+    }
+    Ok(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn pairs(entries: &[(&str, &str)]) -> Vec<(String, String)> {
+        entries
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_comments_and_unquoted_values() {
+        let content = "# shared settings\n\nRUST_LOG=debug\nexport CI = true  # set by CI\nEMPTY=\nURL=http://host/#anchor\n";
+        assert_eq!(
+            parse_dotenv(content).unwrap(),
+            pairs(&[
+                ("RUST_LOG", "debug"),
+                ("CI", "true"),
+                ("EMPTY", ""),
+                ("URL", "http://host/#anchor"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_quoted_values() {
+        let content = concat!(
+            "GREETING=\"hello world\" # comment\n",
+            "ESCAPED=\"line1\\nline2 \\\"quoted\\\"\"\n",
+            "LITERAL='no $expansion \\n # here'\n",
+            "EQUALS=\"a=b\"\n",
+        );
+        assert_eq!(
+            parse_dotenv(content).unwrap(),
+            pairs(&[
+                ("GREETING", "hello world"),
+                ("ESCAPED", "line1\nline2 \"quoted\""),
+                ("LITERAL", "no $expansion \\n # here"),
+                ("EQUALS", "a=b"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_invalid_lines_report_line_number() {
+        for (content, line) in [
+            ("A=1\nnot a pair\n", 2),
+            ("1KEY=value\n", 1),
+            ("A=1\n\nB=\"unterminated\n", 3),
+            ("A='x' trailing\n", 1),
+        ] {
+            match parse_dotenv(content) {
+                Err(DotenvError::InvalidLine { line: actual, .. }) => assert_eq!(actual, line),
+                other => panic!("expected invalid line for {:?}, got {:?}", content, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_load_dotenv() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join(".env");
+        assert!(matches!(
+            load_dotenv(&path),
+            Err(DotenvError::ReadFailed { .. })
+        ));
+
+        fs::write(&path, "A=1\nA=2\n").unwrap();
+        assert_eq!(
+            load_dotenv(&path).unwrap(),
+            pairs(&[("A", "1"), ("A", "2")])
+        );
+    }
+}
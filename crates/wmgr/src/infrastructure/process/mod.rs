@@ -1,6 +1,8 @@
 pub mod command_executor;
+pub mod dotenv;
 
 pub use command_executor::{
     CommandExecutor, CommandExecutorError, ExecutionConfig, ExecutionResult, ExecutionTask,
     ParallelConfig, ParallelResult,
 };
+pub use dotenv::{load_dotenv, parse_dotenv, DotenvError};
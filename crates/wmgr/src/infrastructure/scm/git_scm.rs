@@ -1,5 +1,7 @@
 use super::scm_interface::{AsAny, CloneOptions, ScmError, ScmOperations, StatusResult, SyncOptions};
 use crate::domain::value_objects::scm_type::ScmType;
+use crate::infrastructure::git::remote::GitRemoteManager;
+use crate::infrastructure::git::repository::{FetchConfig, GitRepository};
 use async_trait::async_trait;
 use std::any::Any;
 use std::collections::HashMap;
@@ -57,6 +59,8 @@ impl GitScm {
     ) -> Result<std::process::Output, ScmError> {
         let mut cmd = Command::new(&self.git_executable);
         cmd.args(args).stdout(Stdio::piped()).stderr(Stdio::piped());
+        // An abandoned operation (e.g. an interrupted sync) must not leave git running
+        cmd.kill_on_drop(true);
 
         if let Some(dir) = working_dir {
             cmd.current_dir(dir);
@@ -110,6 +114,28 @@ impl ScmOperations for GitScm {
             args.push(branch);
         }
 
+        // Add partial clone filter
+        let filter = options
+            .filter
+            .as_ref()
+            .map(|spec| format!("--filter={}", spec));
+        if let Some(filter) = &filter {
+            args.push(filter);
+        }
+
+        // Borrow objects from a local repository when it exists (git alternates)
+        if let Some(reference) = &options.reference {
+            args.push("--reference-if-able");
+            args.push(reference.to_str().ok_or_else(|| ScmError::Internal {
+                message: "Invalid reference path".to_string(),
+            })?);
+        }
+
+        // Add the manifest's clone_args (e.g. --filter=blob:none)
+        for arg in &options.clone_args {
+            args.push(arg);
+        }
+
         // Add URL and destination
         args.push(url);
         args.push(
@@ -135,6 +161,18 @@ impl ScmOperations for GitScm {
             )));
         }
 
+        // A server without partial clone support sends every object but git still records
+        // origin as a promisor remote, so record the clone as the full clone it is
+        if options.filter.is_some()
+            && String::from_utf8_lossy(&output.stderr)
+                .contains("filtering not recognized by server")
+        {
+            for key in ["remote.origin.promisor", "remote.origin.partialclonefilter"] {
+                self.execute_git_command(&["config", "--unset", key], Some(dest_path))
+                    .await?;
+            }
+        }
+
         // If a specific revision is requested, checkout that revision
         if let Some(revision) = &options.revision {
             self.execute_git_command_checked(&["checkout", revision], Some(dest_path))
@@ -193,6 +231,27 @@ impl ScmOperations for GitScm {
         Ok(())
     }
 
+    async fn fetch(&self, repo_path: &Path) -> Result<(), ScmError> {
+        // libgit2 handles are not Sync, so the fetch runs on a blocking thread
+        let repo_path = repo_path.to_path_buf();
+        tokio::task::spawn_blocking(move || {
+            let git_repo = GitRepository::open(&repo_path)
+                .map_err(|e| ScmError::sync_failed(format!("Failed to open repository: {}", e)))?;
+            let remote_name = git_repo
+                .upstream_remote()
+                .unwrap_or_else(|| "origin".to_string());
+            futures::executor::block_on(git_repo.fetch(FetchConfig {
+                remote_name,
+                ..FetchConfig::default()
+            }))
+            .map_err(|e| ScmError::sync_failed(format!("Fetch failed: {}", e)))
+        })
+        .await
+        .map_err(|e| ScmError::Internal {
+            message: format!("Fetch task failed: {}", e),
+        })?
+    }
+
     async fn get_status(&self, repo_path: &Path) -> Result<StatusResult, ScmError> {
         // Get current revision
         let current_revision = self
@@ -213,7 +272,8 @@ impl ScmOperations for GitScm {
             .await
             .map_err(|e| ScmError::status_failed(format!("Failed to get status: {}", e)))?;
 
-        let has_changes = !status_output.is_empty();
+        // Untracked files are reported separately below
+        let has_changes = status_output.lines().any(|line| !line.starts_with("??"));
 
         // Check for untracked files
         let untracked_output = self
@@ -221,11 +281,20 @@ impl ScmOperations for GitScm {
             .await
             .map_err(|e| ScmError::status_failed(format!("Failed to check untracked files: {}", e)))?;
 
-        let has_untracked = !untracked_output.is_empty();
+        let untracked_files: Vec<String> = untracked_output
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| line.to_string())
+            .collect();
+        let has_untracked = !untracked_files.is_empty();
 
         // Get ahead/behind count
         let (ahead_count, behind_count) = if let Some(branch) = &current_branch {
-            let upstream_ref = format!("origin/{}", branch);
+            // Compare with the configured upstream, or the same branch on origin without one
+            let upstream_ref = GitRepository::open(repo_path)
+                .ok()
+                .and_then(|repo| repo.upstream_branch())
+                .unwrap_or_else(|| format!("origin/{}", branch));
             
             // Check if upstream exists
             let upstream_exists = self
@@ -273,6 +342,7 @@ impl ScmOperations for GitScm {
             current_branch,
             has_changes,
             has_untracked,
+            untracked_files,
             ahead_count,
             behind_count,
             extra_info,
@@ -298,10 +368,125 @@ impl ScmOperations for GitScm {
             .await?;
         Ok(!output.is_empty())
     }
+
+    async fn get_remote_revision(
+        &self,
+        repo_path: &Path,
+        branch: Option<&str>,
+    ) -> Result<Option<String>, ScmError> {
+        let branch = match branch {
+            Some(branch) => branch.to_string(),
+            None => {
+                let current = self
+                    .execute_git_command_checked(&["branch", "--show-current"], Some(repo_path))
+                    .await?;
+                if current.is_empty() {
+                    // Detached HEAD has no remote branch to compare against
+                    return Ok(None);
+                }
+                current
+            }
+        };
+
+        let repo_path = repo_path.to_path_buf();
+        tokio::task::spawn_blocking(move || {
+            let repo = git2::Repository::open(&repo_path)
+                .map_err(|e| ScmError::status_failed(e.message().to_string()))?;
+            GitRemoteManager::new(&repo)
+                .get_remote_tip("origin", &branch)
+                .map_err(|e| ScmError::network_error(e.to_string()))
+        })
+        .await
+        .map_err(|e| ScmError::Internal {
+            message: format!("Remote lookup task failed: {}", e),
+        })?
+    }
 }
 
 impl AsAny for GitScm {
     fn as_any(&self) -> &dyn Any {
         self
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn commit(path: &Path, content: &str) -> git2::Oid {
+        let repo = git2::Repository::open(path)
+            .or_else(|_| git2::Repository::init(path))
+            .unwrap();
+        std::fs::write(path.join("README"), content).unwrap();
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new("README")).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+        let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+        let parents: Vec<&git2::Commit> = parent.iter().collect();
+        repo.commit(Some("HEAD"), &sig, &sig, content, &tree, &parents)
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_fetch_updates_remote_tracking_refs_only() {
+        let temp_dir = TempDir::new().unwrap();
+        let upstream = temp_dir.path().join("upstream");
+        let first = commit(&upstream, "first");
+        let clone_path = temp_dir.path().join("clone");
+        let clone = git2::Repository::clone(upstream.to_str().unwrap(), &clone_path).unwrap();
+        let branch = clone.head().unwrap().shorthand().unwrap().to_string();
+        let second = commit(&upstream, "second");
+
+        GitScm::new().fetch(&clone_path).await.unwrap();
+
+        let tracking = clone
+            .find_reference(&format!("refs/remotes/origin/{}", branch))
+            .unwrap();
+        assert_eq!(tracking.target(), Some(second));
+        assert_eq!(clone.head().unwrap().target(), Some(first));
+        assert_eq!(
+            std::fs::read_to_string(clone_path.join("README")).unwrap(),
+            "first"
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_clone_passes_clone_options_before_url() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir::new().unwrap();
+        let args_file = temp_dir.path().join("args");
+        let git = temp_dir.path().join("fake-git");
+        std::fs::write(
+            &git,
+            format!("#!/bin/sh\nprintf '%s\\n' \"$@\" > '{}'\n", args_file.display()),
+        )
+        .unwrap();
+        std::fs::set_permissions(&git, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let options = CloneOptions {
+            branch: Some("main".to_string()),
+            clone_args: vec!["--single-branch".to_string(), "--no-tags".to_string()],
+            filter: Some("blob:none".to_string()),
+            reference: Some(temp_dir.path().join("mirror/app.git")),
+            ..Default::default()
+        };
+        let dest = temp_dir.path().join("app");
+        GitScm::with_executable(git.display().to_string())
+            .clone_repository("https://example.com/app.git", &dest, &options)
+            .await
+            .unwrap();
+
+        let args = std::fs::read_to_string(&args_file).unwrap();
+        let expected = format!(
+            "clone\n--branch\nmain\n--filter=blob:none\n--reference-if-able\n{}\n--single-branch\n--no-tags\nhttps://example.com/app.git\n{}\n",
+            temp_dir.path().join("mirror/app.git").display(),
+            dest.display()
+        );
+        assert_eq!(args, expected);
+    }
+}
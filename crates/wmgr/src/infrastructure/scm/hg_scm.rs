@@ -230,6 +230,14 @@ impl ScmOperations for HgScm {
         Ok(())
     }
 
+    async fn fetch(&self, repo_path: &Path) -> Result<(), ScmError> {
+        // Pull without --update leaves the working directory alone
+        self.execute_hg_command_checked(&["pull"], Some(repo_path))
+            .await
+            .map_err(|e| ScmError::sync_failed(format!("Pull failed: {}", e)))?;
+        Ok(())
+    }
+
     async fn get_status(&self, repo_path: &Path) -> Result<StatusResult, ScmError> {
         // Get current revision (changeset hash)
         let current_revision = self
@@ -315,6 +323,7 @@ impl ScmOperations for HgScm {
             current_branch,
             has_changes,
             has_untracked,
+            untracked_files: Vec::new(),
             ahead_count,
             behind_count,
             extra_info,
@@ -0,0 +1,224 @@
+use super::scm_factory::ScmFactory;
+use super::scm_interface::{
+    AsAny, CloneOptions, ScmError, ScmOperations, StatusResult, SyncOptions,
+};
+use crate::domain::value_objects::scm_type::ScmType;
+use async_trait::async_trait;
+use std::any::Any;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Identifier the mock is registered under, and the scheme of its URLs
+pub const MOCK_SCHEME: &str = "mock";
+
+/// File written into mock clones: the clone URL, then the number of updates
+const MARKER_FILE: &str = ".mock-scm";
+
+/// Outcome scripted by the first component of a `mock://` URL
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MockOutcome {
+    /// `mock://ok/<name>`
+    Success,
+    /// `mock://fail/<name>`
+    Failure,
+    /// `mock://delay-<ms>/<name>`: succeeds after the given delay
+    Delay(Duration),
+}
+
+impl MockOutcome {
+    /// Parse the outcome of a `mock://` URL
+    pub fn from_url(url: &str) -> Option<Self> {
+        let rest = url.strip_prefix("mock://")?;
+        match rest.split('/').next()? {
+            "ok" => Some(Self::Success),
+            "fail" => Some(Self::Failure),
+            outcome => outcome
+                .strip_prefix("delay-")?
+                .parse()
+                .ok()
+                .map(|ms| Self::Delay(Duration::from_millis(ms))),
+        }
+    }
+
+    async fn run(self, url: &str) -> Result<(), String> {
+        match self {
+            Self::Success => Ok(()),
+            Self::Failure => Err(format!("scripted failure for {}", url)),
+            Self::Delay(delay) => {
+                tokio::time::sleep(delay).await;
+                Ok(())
+            }
+        }
+    }
+}
+
+/// SCM that simulates repositories in the local filesystem, for tests that
+/// must not touch the network
+///
+/// A clone creates the destination with a marker file and a sync increments
+/// the update count stored in it. Outcomes are scripted per repository by its
+/// URL (see [`MockOutcome`]), so the registered instance holds no state and
+/// tests can run in parallel.
+///
+/// Repositories opt in through a `mock://` URL once [`MockScm::register`] has
+/// run. Pair them with a non-Git SCM type: Git repositories also get libgit2
+/// handling that a mock clone cannot satisfy.
+#[derive(Debug, Default)]
+pub struct MockScm;
+
+impl MockScm {
+    /// Register the mock with [`ScmFactory`] under [`MOCK_SCHEME`]
+    pub fn register() {
+        ScmFactory::register(MOCK_SCHEME, || Arc::new(MockScm));
+    }
+
+    /// Create a mock clone of `url` at `path` without running its outcome,
+    /// e.g. to seed a workspace whose updates should fail
+    pub fn create_clone(path: &Path, url: &str) -> Result<(), ScmError> {
+        std::fs::create_dir_all(path)?;
+        Self::write_marker(path, url, 0)
+    }
+
+    /// Number of times the mock clone at `path` has been synced
+    pub fn update_count(path: &Path) -> Option<u32> {
+        Self::read_marker(path).map(|(_, count)| count)
+    }
+
+    fn read_marker(path: &Path) -> Option<(String, u32)> {
+        let content = std::fs::read_to_string(path.join(MARKER_FILE)).ok()?;
+        let mut lines = content.lines();
+        let url = lines.next()?.to_string();
+        let count = lines.next()?.parse().ok()?;
+        Some((url, count))
+    }
+
+    fn write_marker(path: &Path, url: &str, count: u32) -> Result<(), ScmError> {
+        std::fs::write(path.join(MARKER_FILE), format!("{}\n{}\n", url, count))?;
+        Ok(())
+    }
+
+    fn outcome(url: &str) -> Result<MockOutcome, ScmError> {
+        MockOutcome::from_url(url).ok_or_else(|| ScmError::InvalidUrl {
+            url: url.to_string(),
+        })
+    }
+}
+
+impl AsAny for MockScm {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[async_trait]
+impl ScmOperations for MockScm {
+    async fn clone_repository(
+        &self,
+        url: &str,
+        dest_path: &Path,
+        _options: &CloneOptions,
+    ) -> Result<(), ScmError> {
+        Self::outcome(url)?
+            .run(url)
+            .await
+            .map_err(ScmError::clone_failed)?;
+        Self::create_clone(dest_path, url)
+    }
+
+    async fn sync_repository(
+        &self,
+        repo_path: &Path,
+        _options: &SyncOptions,
+    ) -> Result<(), ScmError> {
+        let (url, count) =
+            Self::read_marker(repo_path).ok_or_else(|| ScmError::RepositoryNotFound {
+                path: repo_path.display().to_string(),
+            })?;
+        Self::outcome(&url)?
+            .run(&url)
+            .await
+            .map_err(ScmError::sync_failed)?;
+        Self::write_marker(repo_path, &url, count + 1)
+    }
+
+    async fn fetch(&self, repo_path: &Path) -> Result<(), ScmError> {
+        let (url, _) =
+            Self::read_marker(repo_path).ok_or_else(|| ScmError::RepositoryNotFound {
+                path: repo_path.display().to_string(),
+            })?;
+        Self::outcome(&url)?
+            .run(&url)
+            .await
+            .map_err(ScmError::sync_failed)
+    }
+
+    async fn get_status(&self, repo_path: &Path) -> Result<StatusResult, ScmError> {
+        Ok(StatusResult {
+            current_revision: self.get_current_revision(repo_path).await?,
+            current_branch: None,
+            has_changes: false,
+            has_untracked: false,
+            untracked_files: Vec::new(),
+            ahead_count: None,
+            behind_count: None,
+            extra_info: HashMap::new(),
+        })
+    }
+
+    fn is_repository(&self, path: &Path) -> bool {
+        path.join(MARKER_FILE).is_file()
+    }
+
+    fn scm_type(&self) -> ScmType {
+        ScmType::Hg
+    }
+
+    async fn get_current_revision(&self, repo_path: &Path) -> Result<String, ScmError> {
+        Self::update_count(repo_path)
+            .map(|count| format!("mock-{}", count))
+            .ok_or_else(|| ScmError::RepositoryNotFound {
+                path: repo_path.display().to_string(),
+            })
+    }
+
+    async fn has_changes(&self, _repo_path: &Path) -> Result<bool, ScmError> {
+        Ok(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outcome_from_url() {
+        assert_eq!(
+            MockOutcome::from_url("mock://ok/app"),
+            Some(MockOutcome::Success)
+        );
+        assert_eq!(
+            MockOutcome::from_url("mock://fail/app"),
+            Some(MockOutcome::Failure)
+        );
+        assert_eq!(
+            MockOutcome::from_url("mock://delay-25/app"),
+            Some(MockOutcome::Delay(Duration::from_millis(25)))
+        );
+        assert_eq!(MockOutcome::from_url("mock://later/app"), None);
+        assert_eq!(MockOutcome::from_url("https://example.com/app"), None);
+    }
+
+    #[tokio::test]
+    async fn test_factory_resolves_mock_urls() {
+        MockScm::register();
+
+        let scm = ScmFactory::create_scm_for_url(ScmType::Hg, "mock://ok/app").unwrap();
+        assert!(scm.as_any().downcast_ref::<MockScm>().is_some());
+
+        // Other schemes still resolve by SCM type
+        let scm = ScmFactory::create_scm_for_url(ScmType::Hg, "https://example.com/app").unwrap();
+        assert!(scm.as_any().downcast_ref::<MockScm>().is_none());
+    }
+}
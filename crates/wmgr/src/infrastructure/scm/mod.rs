@@ -9,6 +9,8 @@ pub mod svn_scm;
 pub mod p4_scm;
 pub mod hg_scm;
 pub mod scm_factory;
+#[cfg(test)]
+pub mod mock_scm;
 
 pub use scm_interface::{ScmOperations, ScmError, CloneOptions, SyncOptions, StatusResult};
-pub use scm_factory::ScmFactory;
\ No newline at end of file
+pub use scm_factory::{ScmConstructor, ScmFactory};
\ No newline at end of file
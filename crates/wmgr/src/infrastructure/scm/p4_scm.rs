@@ -312,6 +312,11 @@ impl ScmOperations for P4Scm {
         Ok(())
     }
 
+    async fn fetch(&self, _repo_path: &Path) -> Result<(), ScmError> {
+        // Perforce keeps history on the server; there are no local refs to refresh
+        Ok(())
+    }
+
     async fn get_status(&self, repo_path: &Path) -> Result<StatusResult, ScmError> {
         // Read P4 configuration
         let config_path = repo_path.join(".p4").join("config");
@@ -378,6 +383,7 @@ impl ScmOperations for P4Scm {
             current_branch: Some(depot_path.clone()), // Use depot path as "branch"
             has_changes,
             has_untracked,
+            untracked_files: Vec::new(),
             ahead_count: None, // P4 doesn't have ahead/behind concept
             behind_count: None,
             extra_info,
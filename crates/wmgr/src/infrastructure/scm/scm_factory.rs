@@ -4,19 +4,105 @@ use super::p4_scm::P4Scm;
 use super::scm_interface::{ScmError, ScmOperations};
 use super::svn_scm::SvnScm;
 use crate::domain::value_objects::scm_type::ScmType;
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
+
+/// Constructor for an SCM implementation registered with [`ScmFactory::register`]
+pub type ScmConstructor = Arc<dyn Fn() -> Arc<dyn ScmOperations> + Send + Sync>;
+
+/// SCM implementations keyed by their identifier, with the built-ins registered
+/// on first use
+fn registry() -> &'static RwLock<HashMap<String, ScmConstructor>> {
+    static REGISTRY: OnceLock<RwLock<HashMap<String, ScmConstructor>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        let builtins: [(ScmType, ScmConstructor); 4] = [
+            (ScmType::Git, Arc::new(|| Arc::new(GitScm::new()))),
+            (ScmType::Svn, Arc::new(|| Arc::new(SvnScm::new()))),
+            (ScmType::P4, Arc::new(|| Arc::new(P4Scm::new()))),
+            (ScmType::Hg, Arc::new(|| Arc::new(HgScm::new()))),
+        ];
+        let constructors = builtins
+            .into_iter()
+            .map(|(scm_type, constructor)| (scm_type.to_string(), constructor))
+            .collect();
+        RwLock::new(constructors)
+    })
+}
 
 /// Factory for creating SCM implementation instances
+///
+/// Implementations are looked up by identifier ("git", "svn", "p4", "hg", ...).
+/// Library consumers can add their own or replace a built-in with
+/// [`ScmFactory::register`]; every repository of that SCM type then goes
+/// through the registered implementation.
 pub struct ScmFactory;
 
 impl ScmFactory {
+    /// Register an SCM implementation under the given identifier
+    ///
+    /// Identifiers are case-insensitive. Registering an existing identifier
+    /// replaces its implementation and returns the previous constructor.
+    pub fn register<F>(name: impl Into<String>, constructor: F) -> Option<ScmConstructor>
+    where
+        F: Fn() -> Arc<dyn ScmOperations> + Send + Sync + 'static,
+    {
+        registry()
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(name.into().to_lowercase(), Arc::new(constructor))
+    }
+
+    /// Identifiers of all registered SCM implementations, sorted
+    pub fn registered_names() -> Vec<String> {
+        let mut names: Vec<String> = registry()
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .keys()
+            .cloned()
+            .collect();
+        names.sort();
+        names
+    }
+
+    /// Create an SCM operations instance for the given identifier
+    pub fn create_scm_by_name(name: &str) -> Result<Arc<dyn ScmOperations>, ScmError> {
+        let constructor = registry()
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&name.to_lowercase())
+            .cloned()
+            .ok_or_else(|| ScmError::UnknownScm {
+                name: name.to_string(),
+            })?;
+        Ok(constructor())
+    }
+
+    /// Create the SCM operations instance for a repository URL
+    ///
+    /// A URL whose scheme is the identifier of a registered custom SCM
+    /// (`<name>://...`) goes through that implementation. Any other URL,
+    /// including schemes named after built-in types such as `git://` or
+    /// `https://`, uses the implementation for `scm_type`.
+    pub fn create_scm_for_url(
+        scm_type: ScmType,
+        url: &str,
+    ) -> Result<Arc<dyn ScmOperations>, ScmError> {
+        if let Some((scheme, _)) = url.split_once("://") {
+            if scheme.parse::<ScmType>().is_err() {
+                if let Ok(scm) = Self::create_scm_by_name(scheme) {
+                    return Ok(scm);
+                }
+            }
+        }
+        Self::create_scm(scm_type)
+    }
+
     /// Create an SCM operations instance for the given SCM type
     pub fn create_scm(scm_type: ScmType) -> Result<Arc<dyn ScmOperations>, ScmError> {
         match scm_type {
-            ScmType::Git => Ok(Arc::new(GitScm::new())),
-            ScmType::Svn => Ok(Arc::new(SvnScm::new())),
-            ScmType::P4 => Ok(Arc::new(P4Scm::new())),
-            ScmType::Hg => Ok(Arc::new(HgScm::new())),
+            ScmType::Git | ScmType::Svn | ScmType::P4 | ScmType::Hg => {
+                Self::create_scm_by_name(&scm_type.to_string())
+            }
             ScmType::Http => Err(ScmError::UnsupportedOperation {
                 scm_type: ScmType::Http,
                 operation: "create_scm: HTTP downloads are handled directly, not via SCM interface".to_string(),
@@ -97,28 +183,29 @@ impl ScmFactory {
                 if let Some(git_scm) = scm.as_any().downcast_ref::<GitScm>() {
                     git_scm.check_availability().await.map(|_| true).or(Ok(false))
                 } else {
-                    Ok(false)
+                    // A registered replacement manages its own tooling
+                    Ok(true)
                 }
             }
             ScmType::Svn => {
                 if let Some(svn_scm) = scm.as_any().downcast_ref::<SvnScm>() {
                     svn_scm.check_availability().await.map(|_| true).or(Ok(false))
                 } else {
-                    Ok(false)
+                    Ok(true)
                 }
             }
             ScmType::P4 => {
                 if let Some(p4_scm) = scm.as_any().downcast_ref::<P4Scm>() {
                     p4_scm.check_availability().await.map(|_| true).or(Ok(false))
                 } else {
-                    Ok(false)
+                    Ok(true)
                 }
             }
             ScmType::Hg => {
                 if let Some(hg_scm) = scm.as_any().downcast_ref::<HgScm>() {
                     hg_scm.check_availability().await.map(|_| true).or(Ok(false))
                 } else {
-                    Ok(false)
+                    Ok(true)
                 }
             }
             ScmType::Http | ScmType::Symlink | ScmType::S3 | ScmType::GDrive => unreachable!(),
@@ -160,6 +247,8 @@ impl ScmFactory {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::infrastructure::scm::scm_interface::{CloneOptions, StatusResult, SyncOptions};
+    use std::path::Path;
 
     #[test]
     fn test_create_scm_instances() {
@@ -194,9 +283,88 @@ mod tests {
 
     #[test]
     fn test_detect_scm_type() {
-        use std::path::Path;
-
         let non_existent_path = Path::new("/non/existent/path");
         assert_eq!(ScmFactory::detect_scm_type(non_existent_path), None);
     }
+
+    /// SCM that "clones" by writing a marker file into the destination
+    struct DummyScm;
+
+    impl crate::infrastructure::scm::scm_interface::AsAny for DummyScm {
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl ScmOperations for DummyScm {
+        async fn clone_repository(
+            &self,
+            url: &str,
+            dest_path: &Path,
+            _options: &CloneOptions,
+        ) -> Result<(), ScmError> {
+            std::fs::create_dir_all(dest_path)?;
+            std::fs::write(dest_path.join(".dummy"), url)?;
+            Ok(())
+        }
+
+        async fn sync_repository(
+            &self,
+            _repo_path: &Path,
+            _options: &SyncOptions,
+        ) -> Result<(), ScmError> {
+            Ok(())
+        }
+
+        async fn fetch(&self, _repo_path: &Path) -> Result<(), ScmError> {
+            Ok(())
+        }
+
+        async fn get_status(&self, _repo_path: &Path) -> Result<StatusResult, ScmError> {
+            Err(ScmError::status_failed("not supported"))
+        }
+
+        fn is_repository(&self, path: &Path) -> bool {
+            path.join(".dummy").exists()
+        }
+
+        fn scm_type(&self) -> ScmType {
+            ScmType::Git
+        }
+
+        async fn get_current_revision(&self, _repo_path: &Path) -> Result<String, ScmError> {
+            Ok("dummy".to_string())
+        }
+
+        async fn has_changes(&self, _repo_path: &Path) -> Result<bool, ScmError> {
+            Ok(false)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_register_custom_scm() {
+        assert!(matches!(
+            ScmFactory::create_scm_by_name("dummy"),
+            Err(ScmError::UnknownScm { .. })
+        ));
+        assert!(ScmFactory::registered_names().contains(&"git".to_string()));
+
+        let previous = ScmFactory::register("Dummy", || Arc::new(DummyScm));
+        assert!(previous.is_none());
+        assert!(ScmFactory::registered_names().contains(&"dummy".to_string()));
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let dest = temp_dir.path().join("repo");
+        let scm = ScmFactory::create_scm_by_name("DUMMY").unwrap();
+        scm.clone_repository("dummy://example/repo", &dest, &CloneOptions::default())
+            .await
+            .unwrap();
+
+        assert!(scm.is_repository(&dest));
+        assert_eq!(
+            std::fs::read_to_string(dest.join(".dummy")).unwrap(),
+            "dummy://example/repo"
+        );
+    }
 }
@@ -1,7 +1,7 @@
 use crate::domain::value_objects::scm_type::ScmType;
 use async_trait::async_trait;
 use std::any::Any;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// Helper trait to enable downcasting
 pub trait AsAny {
@@ -26,6 +26,13 @@ pub trait ScmOperations: AsAny + Send + Sync {
         options: &SyncOptions,
     ) -> Result<(), ScmError>;
 
+    /// Download new revisions from the remote without touching the working copy
+    ///
+    /// Refreshes what the status compares against (remote-tracking refs for
+    /// Git, the local history for Mercurial). SCMs whose working copy is their
+    /// only view of the remote have nothing to refresh and do nothing.
+    async fn fetch(&self, repo_path: &Path) -> Result<(), ScmError>;
+
     /// Get the status of a repository
     async fn get_status(&self, repo_path: &Path) -> Result<StatusResult, ScmError>;
 
@@ -40,6 +47,18 @@ pub trait ScmOperations: AsAny + Send + Sync {
 
     /// Check if the repository has uncommitted changes
     async fn has_changes(&self, repo_path: &Path) -> Result<bool, ScmError>;
+
+    /// Get the revision at the tip of the remote branch without fetching
+    ///
+    /// `branch` defaults to the current branch. Returns `None` when the SCM
+    /// cannot determine the remote revision cheaply.
+    async fn get_remote_revision(
+        &self,
+        _repo_path: &Path,
+        _branch: Option<&str>,
+    ) -> Result<Option<String>, ScmError> {
+        Ok(None)
+    }
 }
 
 /// Options for cloning repositories
@@ -67,6 +86,12 @@ pub struct CloneOptions {
     pub stream: Option<String>,
     /// Additional SCM-specific options
     pub extra_options: Vec<String>,
+    /// Extra arguments passed to the clone command before the URL (Git only)
+    pub clone_args: Vec<String>,
+    /// Partial clone filter such as `blob:none` (Git only)
+    pub filter: Option<String>,
+    /// Local repository to borrow objects from via `--reference-if-able` (Git only)
+    pub reference: Option<PathBuf>,
 }
 
 impl Default for CloneOptions {
@@ -83,6 +108,9 @@ impl Default for CloneOptions {
             client: None,
             stream: None,
             extra_options: Vec::new(),
+            clone_args: Vec::new(),
+            filter: None,
+            reference: None,
         }
     }
 }
@@ -134,6 +162,10 @@ pub struct StatusResult {
     pub has_changes: bool,
     /// Whether there are untracked files
     pub has_untracked: bool,
+    /// Untracked file paths relative to the repository root
+    ///
+    /// Empty when the SCM only reports whether untracked files exist.
+    pub untracked_files: Vec<String>,
     /// Number of files ahead of remote (Git only)
     pub ahead_count: Option<usize>,
     /// Number of files behind remote (Git only)
@@ -178,6 +210,9 @@ pub enum ScmError {
     #[error("Invalid URL format: {url}")]
     InvalidUrl { url: String },
 
+    #[error("No SCM implementation registered for '{name}'")]
+    UnknownScm { name: String },
+
     #[error("IO error: {source}")]
     IoError {
         #[from]
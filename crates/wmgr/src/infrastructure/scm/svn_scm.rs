@@ -201,6 +201,11 @@ impl ScmOperations for SvnScm {
         Ok(())
     }
 
+    async fn fetch(&self, _repo_path: &Path) -> Result<(), ScmError> {
+        // A Subversion working copy holds no remote history apart from its checkout
+        Ok(())
+    }
+
     async fn get_status(&self, repo_path: &Path) -> Result<StatusResult, ScmError> {
         // Get current revision using svn info
         let info_output = self
@@ -261,6 +266,7 @@ impl ScmOperations for SvnScm {
             current_branch,
             has_changes,
             has_untracked,
+            untracked_files: Vec::new(),
             ahead_count: None, // SVN doesn't have ahead/behind concept
             behind_count: None,
             extra_info,